@@ -56,6 +56,13 @@ pub struct Command {
         default_value = "./data/public-data.json"
     )]
     public_data: PathBuf,
+
+    #[structopt(
+        long = "schema",
+        help = "Path to the JSON Schema file to write, describing the witness and public data",
+        default_value = "./build/schema.json"
+    )]
+    schema: PathBuf,
 }
 
 #[derive(Debug, Fail)]
@@ -91,6 +98,7 @@ impl Command {
             self.verbosity,
             &self.witness,
             &self.public_data,
+            Some(&self.schema),
             &self.circuit,
             &source_file_paths,
         )
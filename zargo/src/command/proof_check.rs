@@ -74,6 +74,13 @@ pub struct Command {
         default_value = "./data/verifying-key.txt"
     )]
     verifying_key: PathBuf,
+
+    #[structopt(
+        long = "circuit-hash",
+        help = "Path to the circuit hash file",
+        default_value = "./data/circuit-hash.txt"
+    )]
+    circuit_hash: PathBuf,
 }
 
 #[derive(Debug, Fail)]
@@ -115,6 +122,7 @@ impl Command {
             self.verbosity,
             &self.witness,
             &self.public_data,
+            None,
             &self.circuit,
             &source_file_paths,
         )
@@ -133,6 +141,7 @@ impl Command {
             &self.circuit,
             &self.proving_key,
             &self.verifying_key,
+            &self.circuit_hash,
         )
         .map_err(Error::VirtualMachineSetup)?;
 
@@ -143,6 +152,7 @@ impl Command {
             &self.public_data,
             &self.proving_key,
             &self.verifying_key,
+            &self.circuit_hash,
         )
         .map_err(Error::VirtualMachineProveAndVerify)?;
 
@@ -47,6 +47,13 @@ pub struct Command {
         default_value = "./data/public-data.json"
     )]
     public_data: PathBuf,
+
+    #[structopt(
+        long = "circuit-hash",
+        help = "Path to the circuit hash file, checked if it exists",
+        default_value = "./data/circuit-hash.txt"
+    )]
+    circuit_hash: PathBuf,
 }
 
 #[derive(Debug, Fail)]
@@ -63,6 +70,7 @@ impl Command {
             &self.proving_key,
             &self.witness,
             &self.public_data,
+            &self.circuit_hash,
         )
         .map_err(Error::VirtualMachine)?;
 
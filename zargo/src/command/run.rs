@@ -95,6 +95,7 @@ impl Command {
             self.verbosity,
             &self.witness,
             &self.public_data,
+            None,
             &self.circuit,
             &source_file_paths,
         )
@@ -40,6 +40,13 @@ pub struct Command {
         default_value = "./data/verifying-key.txt"
     )]
     verifying_key: PathBuf,
+
+    #[structopt(
+        long = "circuit-hash",
+        help = "Path to the circuit hash file to generate",
+        default_value = "./data/circuit-hash.txt"
+    )]
+    circuit_hash: PathBuf,
 }
 
 #[derive(Debug, Fail)]
@@ -55,6 +62,7 @@ impl Command {
             &self.circuit,
             &self.proving_key,
             &self.verifying_key,
+            &self.circuit_hash,
         )
         .map_err(Error::VirtualMachine)?;
 
@@ -28,15 +28,21 @@ impl Compiler {
         verbosity: usize,
         witness_path: &PathBuf,
         public_data_path: &PathBuf,
+        schema_path: Option<&PathBuf>,
         circuit_path: &PathBuf,
         source_file_paths: &[PathBuf],
     ) -> Result<(), Error> {
-        let mut child = process::Command::new(BINARY_NAME_DEFAULT)
+        let mut command = process::Command::new(BINARY_NAME_DEFAULT);
+        command
             .args(vec!["-v"; verbosity])
             .arg("--witness")
             .arg(witness_path)
             .arg("--public-data")
-            .arg(public_data_path)
+            .arg(public_data_path);
+        if let Some(schema_path) = schema_path {
+            command.arg("--schema").arg(schema_path);
+        }
+        let mut child = command
             .arg("--output")
             .arg(circuit_path)
             .args(source_file_paths)
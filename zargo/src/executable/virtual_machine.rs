@@ -62,6 +62,7 @@ impl VirtualMachine {
         circuit_path: &PathBuf,
         proving_key_path: &PathBuf,
         verifying_key_path: &PathBuf,
+        circuit_hash_path: &PathBuf,
     ) -> Result<(), Error> {
         let mut process = process::Command::new(BINARY_NAME_DEFAULT)
             .args(vec!["-v"; verbosity])
@@ -72,6 +73,8 @@ impl VirtualMachine {
             .arg(&proving_key_path)
             .arg("--verifying-key")
             .arg(&verifying_key_path)
+            .arg("--circuit-hash")
+            .arg(&circuit_hash_path)
             .spawn()
             .map_err(Error::Spawning)?;
 
@@ -90,8 +93,10 @@ impl VirtualMachine {
         proving_key_path: &PathBuf,
         witness_path: &PathBuf,
         public_data_path: &PathBuf,
+        circuit_hash_path: &PathBuf,
     ) -> Result<(), Error> {
-        let mut child = process::Command::new(BINARY_NAME_DEFAULT)
+        let mut command = process::Command::new(BINARY_NAME_DEFAULT);
+        command
             .args(vec!["-v"; verbosity])
             .arg("prove")
             .arg("--circuit")
@@ -101,9 +106,12 @@ impl VirtualMachine {
             .arg("--witness")
             .arg(&witness_path)
             .arg("--public-data")
-            .arg(&public_data_path)
-            .spawn()
-            .map_err(Error::Spawning)?;
+            .arg(&public_data_path);
+        // The keys generated before the circuit hash was introduced have no hash file
+        if circuit_hash_path.exists() {
+            command.arg("--circuit-hash").arg(&circuit_hash_path);
+        }
+        let mut child = command.spawn().map_err(Error::Spawning)?;
 
         let status = child.wait().map_err(Error::Waiting)?;
 
@@ -141,6 +149,33 @@ impl VirtualMachine {
         Ok(())
     }
 
+    pub fn export_verifier(
+        verbosity: usize,
+        verifying_key_path: &PathBuf,
+        output_path: &PathBuf,
+        abi_path: &PathBuf,
+    ) -> Result<(), Error> {
+        let mut child = process::Command::new(BINARY_NAME_DEFAULT)
+            .args(vec!["-v"; verbosity])
+            .arg("export-verifier")
+            .arg("--verifying-key")
+            .arg(&verifying_key_path)
+            .arg("--output")
+            .arg(&output_path)
+            .arg("--abi")
+            .arg(&abi_path)
+            .spawn()
+            .map_err(Error::Spawning)?;
+
+        let status = child.wait().map_err(Error::Waiting)?;
+
+        if !status.success() {
+            return Err(Error::Failure(status));
+        }
+
+        Ok(())
+    }
+
     pub fn prove_and_verify(
         verbosity: usize,
         circuit_path: &PathBuf,
@@ -148,6 +183,7 @@ impl VirtualMachine {
         public_data_path: &PathBuf,
         proving_key_path: &PathBuf,
         verifying_key_path: &PathBuf,
+        circuit_hash_path: &PathBuf,
     ) -> Result<(), Error> {
         let prover_output = process::Command::new(BINARY_NAME_DEFAULT)
             .args(vec!["-v"; verbosity])
@@ -160,6 +196,8 @@ impl VirtualMachine {
             .arg(&witness_path)
             .arg("--public-data")
             .arg(&public_data_path)
+            .arg("--circuit-hash")
+            .arg(&circuit_hash_path)
             .output()
             .map_err(Error::Spawning)?;
 
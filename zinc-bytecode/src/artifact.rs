@@ -0,0 +1,286 @@
+use crate::program::Program;
+use crate::source_map::SourceMap;
+use bincode::Options;
+use sha2::{Digest, Sha256};
+
+/// The build artifact starts with the magic bytes followed by the format version,
+/// and ends with the SHA-256 checksum of everything before it.
+///
+/// The files without the header are decoded as bare programs.
+const ARTIFACT_FORMAT_MAGIC: &[u8] = b"ZNA";
+const ARTIFACT_FORMAT_VERSION: u8 = 1;
+const CHECKSUM_SIZE: usize = 32;
+
+/// The program bytes, the source hash and the source map.
+///
+/// The program keeps its own versioned encoding, so the programs of the older format
+/// versions are migrated by `Program::from_bytes` when the artifact is decoded.
+type ArtifactContent = (Vec<u8>, String, Option<SourceMap>);
+
+/// The single-file build artifact written by the compiler.
+///
+/// Besides the program with its input and output types, it records the hash of the
+/// source code the program has been compiled from, so the artifact can be matched
+/// against the sources, and optionally the instruction source map.
+/// The same sources always produce the same artifact bytes.
+#[derive(Debug)]
+pub struct Artifact {
+    pub program: Program,
+    /// The hex-encoded SHA-256 hash of the source files, empty if unknown.
+    pub source_hash: String,
+    pub source_map: Option<SourceMap>,
+    /// Whether the artifact or the program inside it has been decoded from an older format.
+    is_migrated: bool,
+}
+
+impl Artifact {
+    /// The format version written by `to_bytes`.
+    pub const FORMAT_VERSION: u8 = ARTIFACT_FORMAT_VERSION;
+
+    pub fn new(program: Program, source_hash: String) -> Self {
+        Self {
+            program,
+            source_hash,
+            source_map: None,
+            is_migrated: false,
+        }
+    }
+
+    /// Embeds the source map built from the program markers.
+    pub fn with_source_map(mut self) -> Self {
+        self.source_map = Some(SourceMap::new(self.program.bytecode.as_slice()));
+        self
+    }
+
+    /// Returns the embedded source map, or builds it from the program markers.
+    pub fn source_map(&self) -> SourceMap {
+        match self.source_map {
+            Some(ref source_map) => source_map.clone(),
+            None => SourceMap::new(self.program.bytecode.as_slice()),
+        }
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = ARTIFACT_FORMAT_MAGIC.to_vec();
+        bytes.push(ARTIFACT_FORMAT_VERSION);
+        bincode::DefaultOptions::new()
+            .serialize_into(
+                &mut bytes,
+                &(self.program.to_bytes(), &self.source_hash, &self.source_map),
+            )
+            .expect("Failed to serialize artifact");
+        let checksum = Sha256::digest(bytes.as_slice());
+        bytes.extend_from_slice(checksum.as_slice());
+        bytes
+    }
+
+    /// Decodes the artifact, verifying its checksum.
+    ///
+    /// The bare programs written by the older compilers and the assembler are
+    /// accepted as well, having neither the source hash nor the source map.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        if !bytes.starts_with(ARTIFACT_FORMAT_MAGIC) {
+            let program = Program::from_bytes(bytes)?;
+            return Ok(Self {
+                is_migrated: true,
+                ..Self::new(program, String::new())
+            });
+        }
+
+        if bytes.len() < ARTIFACT_FORMAT_MAGIC.len() + 1 + CHECKSUM_SIZE {
+            return Err("artifact is truncated".to_owned());
+        }
+        let (content, checksum) = bytes.split_at(bytes.len() - CHECKSUM_SIZE);
+        if Sha256::digest(content).as_slice() != checksum {
+            return Err("artifact checksum mismatch, the file is corrupted".to_owned());
+        }
+
+        let body = &content[ARTIFACT_FORMAT_MAGIC.len() + 1..];
+        let (program, source_hash, source_map) = match content[ARTIFACT_FORMAT_MAGIC.len()] {
+            ARTIFACT_FORMAT_VERSION => bincode::DefaultOptions::new()
+                .deserialize::<ArtifactContent>(body)
+                .map_err(|e| format!("{:?}", e))?,
+            version => {
+                return Err(format!(
+                    "artifact format version {} is newer than the supported version {}, upgrade the toolchain",
+                    version,
+                    Self::FORMAT_VERSION
+                ))
+            }
+        };
+
+        Ok(Self {
+            is_migrated: Program::format_version(program.as_slice())? < Program::FORMAT_VERSION,
+            program: Program::from_bytes(program.as_slice())?,
+            source_hash,
+            source_map,
+        })
+    }
+
+    /// Whether the artifact has been written by an older toolchain and migrated on decoding,
+    /// which is the case for the bare programs and the older programs inside the artifacts.
+    pub fn is_migrated(&self) -> bool {
+        self.is_migrated
+    }
+
+    /// Reads the format version from the artifact header.
+    ///
+    /// Returns `None` for the bare programs, which have been written before the
+    /// artifacts were introduced.
+    pub fn format_version(bytes: &[u8]) -> Option<u8> {
+        if !bytes.starts_with(ARTIFACT_FORMAT_MAGIC) {
+            return None;
+        }
+
+        bytes.get(ARTIFACT_FORMAT_MAGIC.len()).copied()
+    }
+
+    /// The hex-encoded SHA-256 hash of the source code, in the order the files
+    /// have been compiled.
+    pub fn hash_sources<'a, I>(sources: I) -> String
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        let mut hasher = Sha256::new();
+        for source in sources.into_iter() {
+            hasher.input(Sha256::digest(source.as_bytes()));
+        }
+        hex::encode(hasher.result())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::data::types::DataType;
+    use crate::instructions::{Exit, FileMarker, LineMarker, NoOperation};
+    use crate::{Instruction, InstructionInfo};
+
+    fn artifact() -> Artifact {
+        let program = Program::new(
+            DataType::Unit,
+            DataType::Unit,
+            vec![
+                FileMarker::new("main.zn".to_owned()).wrap(),
+                LineMarker::new(1).wrap(),
+                NoOperation.wrap(),
+                Exit::new(0).wrap(),
+            ],
+        );
+        Artifact::new(program, Artifact::hash_sources(vec!["fn main() {}"])).with_source_map()
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let artifact = artifact();
+        let bytes = artifact.to_bytes();
+        assert_eq!(bytes, artifact.to_bytes());
+
+        let decoded = Artifact::from_bytes(bytes.as_slice()).expect("valid artifact");
+        assert!(!decoded.is_migrated());
+        assert_eq!(decoded.program.bytecode, artifact.program.bytecode);
+        assert_eq!(decoded.source_hash, artifact.source_hash);
+        assert_eq!(decoded.source_map, artifact.source_map);
+        assert!(decoded.source_map().get(2).is_some());
+    }
+
+    /// Wraps the program bytes written by an older toolchain into an artifact.
+    fn wrap(program: Vec<u8>) -> Vec<u8> {
+        let mut bytes = ARTIFACT_FORMAT_MAGIC.to_vec();
+        bytes.push(ARTIFACT_FORMAT_VERSION);
+        bincode::DefaultOptions::new()
+            .serialize_into(&mut bytes, &(program, String::new(), None::<SourceMap>))
+            .expect("valid artifact");
+        let checksum = Sha256::digest(bytes.as_slice());
+        bytes.extend_from_slice(checksum.as_slice());
+        bytes
+    }
+
+    /// The programs compiled from `tests/fixtures/assert.zn` by the toolchains writing
+    /// the program versions 0 and 1, which have the legacy `assert` instruction layout.
+    const LEGACY_FIXTURES: [&[u8]; 2] = [
+        include_bytes!("../tests/fixtures/assert_v0.znb"),
+        include_bytes!("../tests/fixtures/assert_v1.znb"),
+    ];
+
+    #[test]
+    fn test_legacy_program() {
+        for legacy in LEGACY_FIXTURES.iter() {
+            let program = Program::from_bytes(legacy).expect("valid program");
+            assert!(program
+                .bytecode
+                .iter()
+                .any(|instruction| matches!(instruction, Instruction::Assert(_))));
+
+            let decoded =
+                Artifact::from_bytes(wrap(legacy.to_vec()).as_slice()).expect("valid artifact");
+            assert!(decoded.is_migrated());
+            assert_eq!(decoded.program.bytecode, program.bytecode);
+            assert!(decoded.program.public_input.is_empty());
+
+            let bytes = decoded.to_bytes();
+            let decoded = Artifact::from_bytes(bytes.as_slice()).expect("valid artifact");
+            assert!(!decoded.is_migrated());
+            assert_eq!(decoded.program.bytecode, program.bytecode);
+            assert_eq!(bytes, decoded.to_bytes());
+        }
+    }
+
+    #[test]
+    fn test_legacy_bare_program() {
+        for legacy in LEGACY_FIXTURES.iter() {
+            let decoded = Artifact::from_bytes(legacy).expect("valid program");
+            assert!(decoded.is_migrated());
+            assert_eq!(
+                decoded.program.bytecode,
+                Program::from_bytes(legacy).expect("valid program").bytecode
+            );
+        }
+    }
+
+    #[test]
+    fn test_corrupted() {
+        let mut bytes = artifact().to_bytes();
+        let last = bytes.len() - CHECKSUM_SIZE - 1;
+        bytes[last] ^= 0xff;
+        assert!(Artifact::from_bytes(bytes.as_slice()).is_err());
+
+        let bytes = artifact().to_bytes();
+        assert!(Artifact::from_bytes(&bytes[..ARTIFACT_FORMAT_MAGIC.len() + 1]).is_err());
+    }
+
+    #[test]
+    fn test_bare_program() {
+        let program = artifact().program;
+        let decoded = Artifact::from_bytes(program.to_bytes().as_slice()).expect("valid program");
+        assert!(decoded.is_migrated());
+        assert_eq!(decoded.program.bytecode, program.bytecode);
+        assert!(decoded.source_hash.is_empty());
+        assert_eq!(decoded.source_map, None);
+        assert_eq!(
+            decoded.source_map(),
+            SourceMap::new(program.bytecode.as_slice())
+        );
+    }
+
+    #[test]
+    fn test_format_version() {
+        let artifact = artifact();
+        assert_eq!(
+            Artifact::format_version(artifact.to_bytes().as_slice()),
+            Some(Artifact::FORMAT_VERSION)
+        );
+        assert_eq!(
+            Artifact::format_version(artifact.program.to_bytes().as_slice()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_hash_sources() {
+        assert_ne!(
+            Artifact::hash_sources(vec!["mod a;", "fn main() {}"]),
+            Artifact::hash_sources(vec!["mod a;fn main() {}"])
+        );
+    }
+}
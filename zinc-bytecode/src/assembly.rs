@@ -0,0 +1,764 @@
+//!
+//! The bytecode assembly.
+//!
+//! The disassembler prints a program with the instruction indices and the source code
+//! locations, and the assembler reads the same text back, so the targeted VM test programs
+//! can be written by hand:
+//!
+//! ```text
+//! .input (u8, u8)
+//! .output u8
+//!         call sum 2
+//!         exit 1
+//! sum:
+//!         load 0
+//!         load 1
+//!         add
+//!         ret 1          ; a comment
+//! ```
+//!
+//! The leading instruction indices are ignored, and the labels can be used as the `call`
+//! addresses. The optional `.public` line lists the public input structure fields.
+//!
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use failure::Fail;
+use num_bigint::BigInt;
+
+use crate::builtins::BuiltinIdentifier;
+use crate::data::types::DataType;
+use crate::instructions::*;
+use crate::program::Program;
+use crate::scalar::{IntegerType, ScalarType};
+use crate::{dispatch_instruction, Instruction, InstructionInfo};
+
+/// The column the source code location comments are aligned to.
+const COMMENT_COLUMN: usize = 40;
+
+#[derive(Debug, Fail, PartialEq)]
+#[fail(display = "line {}: {}", line, message)]
+pub struct AssemblyError {
+    pub line: usize,
+    pub message: String,
+}
+
+///
+/// Prints the program as assembly, with the instruction indices and the source code locations
+/// known from the markers.
+///
+pub fn disassemble(program: &Program) -> String {
+    let mut result = format!(".input {}\n.output {}\n", program.input, program.output);
+    if !program.public_input.is_empty() {
+        result.push_str(&format!(".public {}\n", program.public_input.join(" ")));
+    }
+
+    // the location of the previous function is not carried into the next one,
+    // so the instructions are left without location until the line marker
+    let mut file = None;
+    let mut function = None;
+    let mut line = None;
+    let mut column = 0;
+    for (index, instruction) in program.bytecode.iter().enumerate() {
+        let assembly = dispatch_instruction!(instruction => instruction.to_assembly());
+
+        let location = match instruction {
+            Instruction::FileMarker(marker) => {
+                file = Some(marker.file.as_str());
+                line = None;
+                column = 0;
+                None
+            }
+            Instruction::FunctionMarker(marker) => {
+                function = Some(marker.function.as_str());
+                line = None;
+                column = 0;
+                None
+            }
+            Instruction::LineMarker(marker) => {
+                line = Some(marker.line);
+                None
+            }
+            Instruction::ColumnMarker(marker) => {
+                column = marker.column;
+                None
+            }
+            _ => match (file, line, function) {
+                (Some(file), Some(line), Some(function)) => {
+                    Some(format!("{}:{}:{} in {}", file, line, column, function))
+                }
+                (Some(file), Some(line), None) => Some(format!("{}:{}:{}", file, line, column)),
+                _ => None,
+            },
+        };
+
+        match location {
+            Some(location) => result.push_str(&format!(
+                "{:>6}  {:<width$} ; {}\n",
+                index,
+                assembly,
+                location,
+                width = COMMENT_COLUMN
+            )),
+            None => result.push_str(&format!("{:>6}  {}\n", index, assembly)),
+        }
+    }
+
+    result
+}
+
+///
+/// Reads the program from assembly.
+///
+pub fn assemble(source: &str) -> Result<Program, AssemblyError> {
+    let mut input = DataType::Unit;
+    let mut output = DataType::Unit;
+    let mut public_input = Vec::new();
+    let mut labels = HashMap::new();
+    let mut statements = Vec::new();
+
+    for (index, text) in source.lines().enumerate() {
+        let mut parser = Parser::new(text, index + 1);
+        parser.index();
+        if parser.is_end() {
+            continue;
+        }
+
+        if let Some(label) = parser.label() {
+            if labels.insert(label, statements.len()).is_some() {
+                return Err(parser.error(format!("duplicate label `{}`", label)));
+            }
+            continue;
+        }
+
+        if parser.keyword(".input") {
+            input = parser.data_type()?;
+            parser.end()?;
+        } else if parser.keyword(".output") {
+            output = parser.data_type()?;
+            parser.end()?;
+        } else if parser.keyword(".public") {
+            while !parser.is_end() {
+                public_input.push(parser.word("public input field")?.to_owned());
+            }
+        } else {
+            statements.push(parser);
+        }
+    }
+
+    let bytecode = statements
+        .into_iter()
+        .map(|mut parser| parser.instruction(&labels))
+        .collect::<Result<Vec<Instruction>, AssemblyError>>()?;
+
+    Ok(Program::new(input, output, bytecode).with_public_input(public_input))
+}
+
+/// The single line parser.
+struct Parser<'a> {
+    rest: &'a str,
+    line: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(text: &'a str, line: usize) -> Self {
+        Self { rest: text, line }
+    }
+
+    fn instruction(&mut self, labels: &HashMap<&str, usize>) -> Result<Instruction, AssemblyError> {
+        let mnemonic = self.word("instruction")?;
+        let instruction = match mnemonic {
+            "noop" => NoOperation.wrap(),
+
+            "push" => {
+                let value = self.number::<BigInt>("constant")?;
+                if !self.keyword("as") {
+                    return Err(self.error("expected `as`".to_owned()));
+                }
+                PushConst::new(value, self.scalar_type()?).wrap()
+            }
+            "pop" => Pop::new(self.number("count")?).wrap(),
+            "slice" => Slice::new(self.number("array size")?, self.number("slice size")?).wrap(),
+            "swap" => Swap.wrap(),
+            "tee" => Tee.wrap(),
+
+            "load" => Load::new(self.number("address")?).wrap(),
+            "load_array" => LoadSequence::new(self.number("address")?, self.number("size")?).wrap(),
+            "load_by_index" => {
+                LoadByIndex::new(self.number("address")?, self.number("size")?).wrap()
+            }
+            "load_array_by_index" => LoadSequenceByIndex::new(
+                self.number("address")?,
+                self.number("array size")?,
+                self.number("value size")?,
+            )
+            .wrap(),
+            "store" => Store::new(self.number("index")?).wrap(),
+            "store_array" => {
+                StoreSequence::new(self.number("address")?, self.number("size")?).wrap()
+            }
+            "store_by_index" => {
+                StoreByIndex::new(self.number("address")?, self.number("size")?).wrap()
+            }
+            "store_array_by_index" => StoreSequenceByIndex::new(
+                self.number("address")?,
+                self.number("array size")?,
+                self.number("value size")?,
+            )
+            .wrap(),
+
+            "load_global" => LoadGlobal::new(self.number("address")?).wrap(),
+            "load_array_global" => {
+                LoadSequenceGlobal::new(self.number("address")?, self.number("size")?).wrap()
+            }
+            "load_by_index_global" => {
+                LoadByIndexGlobal::new(self.number("address")?, self.number("size")?).wrap()
+            }
+            "load_array_by_index_global" => LoadSequenceByIndexGlobal::new(
+                self.number("address")?,
+                self.number("array size")?,
+                self.number("value size")?,
+            )
+            .wrap(),
+            "store_global" => StoreGlobal::new(self.number("address")?).wrap(),
+            "store_sequence_global" => {
+                StoreSequenceGlobal::new(self.number("address")?, self.number("size")?).wrap()
+            }
+
+            "add" => Add.wrap(),
+            "sub" => Sub.wrap(),
+            "mul" => Mul.wrap(),
+            "div" => Div.wrap(),
+            "rem" => Rem.wrap(),
+            "neg" => Neg.wrap(),
+
+            "not" => Not.wrap(),
+            "and" => And.wrap(),
+            "or" => Or.wrap(),
+            "xor" => Xor.wrap(),
+
+            "lt" => Lt.wrap(),
+            "le" => Le.wrap(),
+            "eq" => Eq.wrap(),
+            "ne" => Ne.wrap(),
+            "ge" => Ge.wrap(),
+            "gt" => Gt.wrap(),
+
+            "bit_shift_left" => BitShiftLeft.wrap(),
+            "bit_shift_right" => BitShiftRight.wrap(),
+            "bit_and" => BitAnd.wrap(),
+            "bit_or" => BitOr.wrap(),
+            "bit_xor" => BitXor.wrap(),
+            "bit_not" => BitNot.wrap(),
+
+            "cast" => Cast::new(self.scalar_type()?).wrap(),
+
+            "if" => If.wrap(),
+            "else" => Else.wrap(),
+            "endif" => EndIf.wrap(),
+            "loop_begin" => LoopBegin::new(self.number("iterations count")?).wrap(),
+            "loop_end" => LoopEnd.wrap(),
+            "call" => {
+                let address = match self.peek() {
+                    Some(c) if c.is_ascii_digit() => self.number("address")?,
+                    _ => {
+                        let label = self.word("label")?;
+                        *labels
+                            .get(label)
+                            .ok_or_else(|| self.error(format!("unknown label `{}`", label)))?
+                    }
+                };
+                Call::new(address, self.number("inputs count")?).wrap()
+            }
+            "ret" => Return::new(self.number("outputs count")?).wrap(),
+
+            "call_builtin" => {
+                let identifier = self.word("built-in function")?;
+                let identifier = BuiltinIdentifier::from_str(identifier)
+                    .map_err(|message| self.error(message))?;
+                self.expect("(")?;
+                let inputs_count = self.number("inputs count")?;
+                self.expect(")")?;
+                self.expect("->")?;
+                CallBuiltin::new(identifier, inputs_count, self.number("outputs count")?).wrap()
+            }
+            "call_extension" => {
+                let identifier = self.string()?;
+                self.expect("(")?;
+                let inputs_count = self.number("inputs count")?;
+                self.expect(")")?;
+                self.expect("->")?;
+                CallExtension::new(identifier, inputs_count, self.number("outputs count")?).wrap()
+            }
+            "call_host" => {
+                let identifier = self.string()?;
+                self.expect("(")?;
+                let inputs_count = self.number("inputs count")?;
+                self.expect(")")?;
+                self.expect("->")?;
+                self.expect("(")?;
+                let output = self.data_type()?;
+                self.expect(")")?;
+                CallHost::new(identifier, inputs_count, output).wrap()
+            }
+
+            "assert" => {
+                let code = if self.keyword("code") {
+                    Some(self.number("error code")?)
+                } else {
+                    None
+                };
+                let message = if self.is_end() {
+                    None
+                } else {
+                    Some(self.string()?)
+                };
+                let arg_types = if message.is_some() && !self.is_end() {
+                    self.data_types("(", ")")?
+                } else {
+                    vec![]
+                };
+                Assert::new_formatted(message, arg_types, code).wrap()
+            }
+            "dbg" => {
+                let format = self.string()?;
+                Dbg::new(format, self.data_types("(", ")")?).wrap()
+            }
+            "expose" => {
+                let name = self.string()?;
+                self.expect("(")?;
+                let data_type = self.data_type()?;
+                self.expect(")")?;
+                Expose::new(name, data_type).wrap()
+            }
+
+            "exit" => Exit::new(self.number("outputs count")?).wrap(),
+
+            "marker" => {
+                self.expect(":")?;
+                let kind = self.word("marker kind")?;
+                self.expect("=")?;
+                let value = self.string()?;
+                let number = |parser: &Self| {
+                    value
+                        .parse::<usize>()
+                        .map_err(|_| parser.error(format!("invalid {} `{}`", kind, value)))
+                };
+                match kind {
+                    "file" => FileMarker::new(value.clone()).wrap(),
+                    "function" => FunctionMarker::new(value.clone()).wrap(),
+                    "line" => LineMarker::new(number(self)?).wrap(),
+                    "column" => ColumnMarker::new(number(self)?).wrap(),
+                    kind => return Err(self.error(format!("unknown marker `{}`", kind))),
+                }
+            }
+
+            mnemonic => return Err(self.error(format!("unknown instruction `{}`", mnemonic))),
+        };
+        self.end()?;
+
+        Ok(instruction)
+    }
+
+    fn data_type(&mut self) -> Result<DataType, AssemblyError> {
+        match self.peek() {
+            Some('(') => {
+                self.expect("(")?;
+                let mut fields = Vec::new();
+                let mut is_tuple = false;
+                while !self.eat(")") {
+                    if !fields.is_empty() {
+                        self.expect(",")?;
+                        is_tuple = true;
+                        if self.eat(")") {
+                            break;
+                        }
+                    }
+                    fields.push(self.data_type()?);
+                }
+                Ok(match fields.len() {
+                    0 => DataType::Unit,
+                    1 if !is_tuple => fields.remove(0),
+                    _ => DataType::Tuple(fields),
+                })
+            }
+            Some('[') => {
+                self.expect("[")?;
+                let element_type = self.data_type()?;
+                self.expect(";")?;
+                let size = self.number("array size")?;
+                self.expect("]")?;
+                Ok(DataType::Array(Box::new(element_type), size))
+            }
+            Some('{') => {
+                self.expect("{")?;
+                let mut fields = Vec::new();
+                while !self.eat("}") {
+                    if !fields.is_empty() {
+                        self.expect(",")?;
+                    }
+                    let name = self.word("field name")?.to_owned();
+                    self.expect(":")?;
+                    fields.push((name, self.data_type()?));
+                }
+                Ok(DataType::Struct(fields))
+            }
+            _ if self.keyword("enum") => Ok(DataType::Enum),
+            _ => Ok(DataType::Scalar(self.scalar_type()?)),
+        }
+    }
+
+    ///
+    /// Parses the comma-separated types between the `open` and `close` tokens.
+    ///
+    fn data_types(&mut self, open: &str, close: &str) -> Result<Vec<DataType>, AssemblyError> {
+        self.expect(open)?;
+        let mut types = Vec::new();
+        while !self.eat(close) {
+            if !types.is_empty() {
+                self.expect(",")?;
+            }
+            types.push(self.data_type()?);
+        }
+        Ok(types)
+    }
+
+    fn scalar_type(&mut self) -> Result<ScalarType, AssemblyError> {
+        let word = self.word("type")?;
+        let integer = |is_signed: bool, bitlength: &str| {
+            bitlength.parse().ok().map(|bitlength| {
+                ScalarType::Integer(IntegerType {
+                    is_signed,
+                    bitlength,
+                })
+            })
+        };
+        let scalar_type = match word {
+            "field" => Some(ScalarType::Field),
+            "bool" => Some(ScalarType::Boolean),
+            _ if word.starts_with('u') => integer(false, &word[1..]),
+            _ if word.starts_with('i') => integer(true, &word[1..]),
+            _ => None,
+        };
+        scalar_type.ok_or_else(|| self.error(format!("unknown type `{}`", word)))
+    }
+
+    fn string(&mut self) -> Result<String, AssemblyError> {
+        self.expect("\"")?;
+        let rest = self.rest;
+        let mut result = String::new();
+        let mut chars = rest.char_indices();
+        while let Some((index, c)) = chars.next() {
+            let c = match c {
+                '"' => {
+                    self.rest = &rest[index + 1..];
+                    return Ok(result);
+                }
+                '\\' => match chars.next().map(|(_index, c)| c) {
+                    Some('n') => '\n',
+                    Some('r') => '\r',
+                    Some('t') => '\t',
+                    Some('0') => '\0',
+                    Some(c @ '\\') | Some(c @ '"') | Some(c @ '\'') => c,
+                    Some('u') => {
+                        let code: String = chars
+                            .by_ref()
+                            .map(|(_index, c)| c)
+                            .skip_while(|c| *c == '{')
+                            .take_while(|c| *c != '}')
+                            .collect();
+                        u32::from_str_radix(code.as_str(), 16)
+                            .ok()
+                            .and_then(std::char::from_u32)
+                            .ok_or_else(|| self.error(format!("invalid escape `{}`", code)))?
+                    }
+                    _ => return Err(self.error("invalid escape sequence".to_owned())),
+                },
+                c => c,
+            };
+            result.push(c);
+        }
+        Err(self.error("unterminated string".to_owned()))
+    }
+
+    fn number<T: FromStr>(&mut self, what: &str) -> Result<T, AssemblyError> {
+        self.skip_whitespace();
+        let length = self
+            .rest
+            .char_indices()
+            .find(|(index, c)| !(c.is_ascii_digit() || (*index == 0 && *c == '-')))
+            .map_or(self.rest.len(), |(index, _c)| index);
+        let (number, rest) = self.rest.split_at(length);
+        let number = number
+            .parse()
+            .map_err(|_| self.error(format!("expected {}", what)))?;
+        self.rest = rest;
+        Ok(number)
+    }
+
+    fn word(&mut self, what: &str) -> Result<&'a str, AssemblyError> {
+        self.skip_whitespace();
+        let length = self
+            .rest
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '.'))
+            .unwrap_or(self.rest.len());
+        if length == 0 {
+            return Err(self.error(format!("expected {}", what)));
+        }
+        let (word, rest) = self.rest.split_at(length);
+        self.rest = rest;
+        Ok(word)
+    }
+
+    ///
+    /// Consumes the word if it is equal to `keyword`.
+    ///
+    fn keyword(&mut self, keyword: &str) -> bool {
+        let rest = self.rest;
+        match self.word(keyword) {
+            Ok(word) if word == keyword => true,
+            _ => {
+                self.rest = rest;
+                false
+            }
+        }
+    }
+
+    ///
+    /// Consumes the label definition, which is a word followed by a colon on a separate line.
+    ///
+    fn label(&mut self) -> Option<&'a str> {
+        let rest = self.rest;
+        if let Ok(label) = self.word("label") {
+            if self.eat(":") && self.is_end() {
+                return Some(label);
+            }
+        }
+        self.rest = rest;
+        None
+    }
+
+    ///
+    /// Skips the instruction index printed by the disassembler.
+    ///
+    fn index(&mut self) {
+        self.skip_whitespace();
+        self.rest = self.rest.trim_start_matches(|c: char| c.is_ascii_digit());
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+        if self.rest.starts_with(token) {
+            self.rest = &self.rest[token.len()..];
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, token: &str) -> Result<(), AssemblyError> {
+        if self.eat(token) {
+            Ok(())
+        } else {
+            Err(self.error(format!("expected `{}`", token)))
+        }
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.skip_whitespace();
+        self.rest.chars().next()
+    }
+
+    fn is_end(&mut self) -> bool {
+        matches!(self.peek(), None | Some(';'))
+    }
+
+    fn end(&mut self) -> Result<(), AssemblyError> {
+        if self.is_end() {
+            Ok(())
+        } else {
+            Err(self.error(format!("unexpected `{}`", self.rest.trim_end())))
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        self.rest = self.rest.trim_start();
+    }
+
+    fn error(&self, message: String) -> AssemblyError {
+        AssemblyError {
+            line: self.line,
+            message,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    static SOURCE: &str = r#"
+.input (u8, [field; 2])
+.output { a: u8, b: (bool, i16) }
+        call sum 3              ; the entry
+        exit 3
+sum:
+        marker: file = "main.zn"
+        marker: line = "2"
+        push -5 as i16
+        dbg "{} \"{}\"" (u8, [field; 2])
+        assert "a; b"
+        assert code 42 "x = {}" (u8)
+        call_builtin CryptoSha256(8) -> 256
+        ret 3
+"#;
+
+    #[test]
+    fn test_assemble() {
+        let program = assemble(SOURCE).expect("Valid assembly");
+
+        assert_eq!(
+            program.output,
+            DataType::Struct(vec![
+                ("a".to_owned(), DataType::Scalar(IntegerType::U8.into())),
+                (
+                    "b".to_owned(),
+                    DataType::Tuple(vec![
+                        DataType::Scalar(ScalarType::Boolean),
+                        DataType::Scalar(IntegerType::I16.into()),
+                    ])
+                ),
+            ])
+        );
+        assert_eq!(program.bytecode[0], Call::new(2, 3).wrap());
+        assert_eq!(
+            program.bytecode[4],
+            PushConst::new((-5).into(), IntegerType::I16.into()).wrap()
+        );
+        assert_eq!(
+            program.bytecode[5],
+            Dbg::new(
+                "{} \"{}\"".to_owned(),
+                vec![
+                    DataType::Scalar(IntegerType::U8.into()),
+                    DataType::Array(Box::new(DataType::Scalar(ScalarType::Field)), 2),
+                ]
+            )
+            .wrap()
+        );
+        assert_eq!(
+            program.bytecode[6],
+            Assert::new(Some("a; b".to_owned())).wrap()
+        );
+        assert_eq!(
+            program.bytecode[7],
+            Assert::new_formatted(
+                Some("x = {}".to_owned()),
+                vec![DataType::Scalar(IntegerType::U8.into())],
+                Some(42)
+            )
+            .wrap()
+        );
+    }
+
+    #[test]
+    fn test_disassembly_roundtrip() {
+        let program = assemble(SOURCE).expect("Valid assembly");
+        let text = disassemble(&program);
+        assert!(text.contains("; main.zn:2:0"));
+
+        let reassembled = assemble(text.as_str()).expect("Valid disassembly");
+        assert_eq!(reassembled.input, program.input);
+        assert_eq!(reassembled.output, program.output);
+        assert_eq!(reassembled.bytecode, program.bytecode);
+    }
+
+    #[test]
+    fn test_disassembly_location_reset() {
+        let source = r#"
+        marker: file = "main.zn"
+        marker: function = "main"
+        marker: line = "7"
+        marker: column = "1"
+        ret 0
+        marker: file = "main.zn"
+        marker: function = "it_sums"
+        push 1 as u8
+        marker: line = "12"
+        ret 0
+"#;
+        let program = assemble(source).expect("Valid assembly");
+        let text = disassemble(&program);
+        let lines: Vec<&str> = text.lines().collect();
+
+        assert!(lines[6].ends_with("; main.zn:7:1 in main"));
+        assert!(lines[9].ends_with("push 1 as u8"));
+        assert!(lines[11].ends_with("; main.zn:12:0 in it_sums"));
+    }
+
+    #[test]
+    fn test_public_input() {
+        let source = ".input { root: field, preimage: u8 }\n.public root\n        exit 0\n";
+        let program = assemble(source).expect("Valid assembly");
+        assert_eq!(program.public_input, vec!["root".to_owned()]);
+
+        let text = disassemble(&program);
+        assert!(text.contains(".public root\n"));
+        assert_eq!(
+            assemble(text.as_str())
+                .expect("Valid disassembly")
+                .public_input,
+            program.public_input
+        );
+    }
+
+    #[test]
+    fn test_expose() {
+        let source = "push 1 as u8\nexpose \"root\" (u8)\npush 2 as u8\nexit 1\n";
+        let program = assemble(source).expect("Valid assembly");
+        let expose = Expose::new("root".to_owned(), DataType::Scalar(IntegerType::U8.into()));
+        assert_eq!(program.bytecode[1], expose.clone().wrap());
+        assert_eq!(expose.to_assembly(), "expose \"root\" (u8)");
+    }
+
+    #[test]
+    fn test_call_extension() {
+        let source = "push 1 as field\ncall_extension \"poseidon\"(1) -> 1\nexit 1\n";
+        let program = assemble(source).expect("Valid assembly");
+        let call = CallExtension::new("poseidon".to_owned(), 1, 1);
+        assert_eq!(program.bytecode[1], call.clone().wrap());
+        assert_eq!(call.to_assembly(), "call_extension \"poseidon\"(1) -> 1");
+    }
+
+    #[test]
+    fn test_call_host() {
+        let source = "push 1 as u16\ncall_host \"lookup\"(1) -> (u16)\nexit 1\n";
+        let program = assemble(source).expect("Valid assembly");
+        let call = CallHost::new(
+            "lookup".to_owned(),
+            1,
+            DataType::Scalar(IntegerType::U16.into()),
+        );
+        assert_eq!(program.bytecode[1], call.clone().wrap());
+        assert_eq!(call.to_assembly(), "call_host \"lookup\"(1) -> (u16)");
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            assemble("push 1 as u8\njump 42").err(),
+            Some(AssemblyError {
+                line: 2,
+                message: "unknown instruction `jump`".to_owned(),
+            })
+        );
+        assert_eq!(
+            assemble("call missing 0").err(),
+            Some(AssemblyError {
+                line: 1,
+                message: "unknown label `missing`".to_owned(),
+            })
+        );
+    }
+}
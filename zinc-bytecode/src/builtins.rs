@@ -1,5 +1,6 @@
 use serde_derive::{Deserialize, Serialize};
 use std::fmt::Debug;
+use std::str::FromStr;
 
 /// Built-in function identifier.
 #[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
@@ -17,4 +18,79 @@ pub enum BuiltinIdentifier {
     FieldInverse,
     CryptoBlake2s,
     CryptoBlake2sMultiInput,
+    EthAbiEncode,
+    EthAbiDecode,
+    MathOverflowingAdd,
+    MathOverflowingSub,
+    MathOverflowingMul,
+    MathFixedMul,
+    MathFixedDiv,
+    MathSelect,
+    MathSign,
+    ArraySort,
+    TableLookup,
+    AssertInRange,
+    BitsCountOnes,
+    BitsLeadingZeros,
+    BitsRotateLeft,
+    BitsRotateRight,
+    MathAddMod32,
+    MathSubMod32,
+    MathMulMod32,
+    MathAddMod64,
+    MathSubMod64,
+    MathMulMod64,
+}
+
+impl BuiltinIdentifier {
+    pub const ALL: [BuiltinIdentifier; 35] = [
+        BuiltinIdentifier::CryptoSha256,
+        BuiltinIdentifier::CryptoPedersen,
+        BuiltinIdentifier::ToBits,
+        BuiltinIdentifier::UnsignedFromBits,
+        BuiltinIdentifier::SignedFromBits,
+        BuiltinIdentifier::FieldFromBits,
+        BuiltinIdentifier::ArrayReverse,
+        BuiltinIdentifier::ArrayTruncate,
+        BuiltinIdentifier::ArrayPad,
+        BuiltinIdentifier::CryptoSchnorrSignatureVerify,
+        BuiltinIdentifier::FieldInverse,
+        BuiltinIdentifier::CryptoBlake2s,
+        BuiltinIdentifier::CryptoBlake2sMultiInput,
+        BuiltinIdentifier::EthAbiEncode,
+        BuiltinIdentifier::EthAbiDecode,
+        BuiltinIdentifier::MathOverflowingAdd,
+        BuiltinIdentifier::MathOverflowingSub,
+        BuiltinIdentifier::MathOverflowingMul,
+        BuiltinIdentifier::MathFixedMul,
+        BuiltinIdentifier::MathFixedDiv,
+        BuiltinIdentifier::MathSelect,
+        BuiltinIdentifier::MathSign,
+        BuiltinIdentifier::ArraySort,
+        BuiltinIdentifier::TableLookup,
+        BuiltinIdentifier::AssertInRange,
+        BuiltinIdentifier::BitsCountOnes,
+        BuiltinIdentifier::BitsLeadingZeros,
+        BuiltinIdentifier::BitsRotateLeft,
+        BuiltinIdentifier::BitsRotateRight,
+        BuiltinIdentifier::MathAddMod32,
+        BuiltinIdentifier::MathSubMod32,
+        BuiltinIdentifier::MathMulMod32,
+        BuiltinIdentifier::MathAddMod64,
+        BuiltinIdentifier::MathSubMod64,
+        BuiltinIdentifier::MathMulMod64,
+    ];
+}
+
+/// Parses the identifier written as in the bytecode assembly, e.g. `CryptoSha256`.
+impl FromStr for BuiltinIdentifier {
+    type Err = String;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .iter()
+            .find(|identifier| format!("{:?}", identifier) == string)
+            .copied()
+            .ok_or_else(|| format!("unknown built-in function `{}`", string))
+    }
 }
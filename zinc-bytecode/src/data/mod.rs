@@ -1,2 +1,4 @@
+pub mod schema;
 pub mod types;
 pub mod values;
+pub mod witness;
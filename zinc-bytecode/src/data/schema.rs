@@ -0,0 +1,143 @@
+use serde_json as json;
+use serde_json::json;
+
+use crate::data::types::{DataType, ScalarType};
+
+/// The JSON Schema draft the schemas are written against.
+pub const JSON_SCHEMA_DRAFT: &str = "http://json-schema.org/draft-07/schema#";
+
+/// The number strings accepted for the fields and unsigned integers.
+const PATTERN_UNSIGNED: &str = "^(0x[0-9a-fA-F]+|[0-9]+)$";
+
+/// The number strings accepted for the signed integers.
+const PATTERN_SIGNED: &str = "^(0x[0-9a-fA-F]+|-?[0-9]+)$";
+
+impl DataType {
+    /// Describes the JSON representation of the type, which is read by
+    /// `Value::from_typed_json` and written by `Value::to_json`.
+    ///
+    /// The numbers are strings, since they do not fit into the JSON numbers, so the
+    /// scalar type name is kept in the `description` of the number schemas.
+    pub fn to_json_schema(&self) -> json::Value {
+        match self {
+            DataType::Unit => json!({ "const": "unit" }),
+            DataType::Scalar(ScalarType::Boolean) => json!({ "type": "boolean" }),
+            DataType::Scalar(scalar_type) => json!({
+                "type": "string",
+                "pattern": if scalar_type.is_signed() {
+                    PATTERN_SIGNED
+                } else {
+                    PATTERN_UNSIGNED
+                },
+                "description": scalar_type.to_string(),
+            }),
+            DataType::Enum => json!({
+                "type": "string",
+                "pattern": PATTERN_UNSIGNED,
+                "description": "enum",
+            }),
+            DataType::Struct(fields) => {
+                let properties = fields
+                    .iter()
+                    .map(|(name, field_type)| (name.to_owned(), field_type.to_json_schema()))
+                    .collect::<json::Map<String, json::Value>>();
+                let required = fields
+                    .iter()
+                    .map(|(name, _)| name.to_owned())
+                    .collect::<Vec<String>>();
+
+                json!({
+                    "type": "object",
+                    "properties": properties,
+                    "required": required,
+                    "additionalProperties": false,
+                })
+            }
+            DataType::Tuple(fields) => json!({
+                "type": "array",
+                "items": fields.iter().map(DataType::to_json_schema).collect::<Vec<json::Value>>(),
+                "additionalItems": false,
+                "minItems": fields.len(),
+                "maxItems": fields.len(),
+            }),
+            DataType::Array(element_type, size) => json!({
+                "type": "array",
+                "items": element_type.to_json_schema(),
+                "minItems": size,
+                "maxItems": size,
+            }),
+        }
+    }
+
+    /// Wraps the schema of the type into a standalone document with the `title`.
+    pub fn to_json_schema_document(&self, title: &str) -> json::Value {
+        let mut schema = self.to_json_schema();
+        if let json::Value::Object(ref mut object) = schema {
+            object.insert("$schema".to_owned(), json!(JSON_SCHEMA_DRAFT));
+            object.insert("title".to_owned(), json!(title));
+        }
+        schema
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::data::types::IntegerType;
+
+    #[test]
+    fn test_scalars() {
+        assert_eq!(
+            DataType::Scalar(ScalarType::Boolean).to_json_schema(),
+            json!({ "type": "boolean" })
+        );
+        assert_eq!(
+            DataType::Scalar(ScalarType::Integer(IntegerType::I8)).to_json_schema(),
+            json!({ "type": "string", "pattern": PATTERN_SIGNED, "description": "i8" })
+        );
+        assert_eq!(
+            DataType::Scalar(ScalarType::Field).to_json_schema(),
+            json!({ "type": "string", "pattern": PATTERN_UNSIGNED, "description": "field" })
+        );
+    }
+
+    #[test]
+    fn test_composites() {
+        let data_type = DataType::Struct(vec![
+            (
+                "bytes".to_owned(),
+                DataType::Array(Box::new(DataType::Scalar(IntegerType::U8.into())), 2),
+            ),
+            (
+                "pair".to_owned(),
+                DataType::Tuple(vec![DataType::Scalar(ScalarType::Boolean), DataType::Unit]),
+            ),
+        ]);
+
+        assert_eq!(
+            data_type.to_json_schema_document("witness"),
+            json!({
+                "$schema": JSON_SCHEMA_DRAFT,
+                "title": "witness",
+                "type": "object",
+                "properties": {
+                    "bytes": {
+                        "type": "array",
+                        "items": { "type": "string", "pattern": PATTERN_UNSIGNED, "description": "u8" },
+                        "minItems": 2,
+                        "maxItems": 2,
+                    },
+                    "pair": {
+                        "type": "array",
+                        "items": [{ "type": "boolean" }, { "const": "unit" }],
+                        "additionalItems": false,
+                        "minItems": 2,
+                        "maxItems": 2,
+                    },
+                },
+                "required": ["bytes", "pair"],
+                "additionalProperties": false,
+            })
+        );
+    }
+}
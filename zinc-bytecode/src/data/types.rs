@@ -1,5 +1,6 @@
 pub use crate::scalar::{IntegerType, ScalarType};
 use serde_derive::{Deserialize, Serialize};
+use std::fmt;
 
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub enum DataType {
@@ -23,4 +24,55 @@ impl DataType {
             DataType::Array(element_type, array_size) => element_type.size() * *array_size,
         }
     }
+
+    /// Returns the types of the scalars the value of the type consists of, in the order
+    /// they are stored in memory.
+    pub fn scalar_types(&self) -> Vec<ScalarType> {
+        match self {
+            DataType::Unit => vec![],
+            DataType::Scalar(scalar_type) => vec![*scalar_type],
+            DataType::Enum => vec![ScalarType::Field],
+            DataType::Struct(fields) => fields
+                .iter()
+                .flat_map(|(_, field)| field.scalar_types())
+                .collect(),
+            DataType::Tuple(fields) => fields.iter().flat_map(DataType::scalar_types).collect(),
+            DataType::Array(element_type, array_size) => {
+                let element_types = element_type.scalar_types();
+                (0..*array_size)
+                    .flat_map(|_| element_types.iter().copied())
+                    .collect()
+            }
+        }
+    }
+}
+
+impl fmt::Display for DataType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DataType::Unit => write!(f, "()"),
+            DataType::Scalar(scalar_type) => write!(f, "{}", scalar_type),
+            DataType::Enum => write!(f, "enum"),
+            DataType::Struct(fields) => write!(
+                f,
+                "{{ {} }}",
+                fields
+                    .iter()
+                    .map(|(name, field)| format!("{}: {}", name, field))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            DataType::Tuple(fields) if fields.len() == 1 => write!(f, "({},)", fields[0]),
+            DataType::Tuple(fields) => write!(
+                f,
+                "({})",
+                fields
+                    .iter()
+                    .map(DataType::to_string)
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            DataType::Array(element_type, size) => write!(f, "[{}; {}]", element_type, size),
+        }
+    }
 }
@@ -0,0 +1,363 @@
+use std::path::Path;
+use std::str::FromStr;
+
+use bincode::Options;
+use failure::Fail;
+use num_bigint::BigInt;
+use num_bigint::Sign;
+use num_traits::Num;
+use num_traits::Zero;
+use serde_derive::{Deserialize, Serialize};
+
+use crate::data::types::{DataType, ScalarType};
+use crate::data::values::{ScalarValue, StructField, Value};
+
+/// The binary witness starts with the magic bytes followed by the format version.
+const BINARY_FORMAT_MAGIC: &[u8] = b"ZNW";
+const BINARY_FORMAT_VERSION: u8 = 1;
+
+/// The field elements are not checked against the curve modulus here, since the
+/// bytecode is curve-agnostic, but they must fit into the field bitlength.
+const FIELD_BITLENGTH: usize = 254;
+
+/// The witness file format.
+///
+/// The JSON witness mirrors the input structure, which makes the large arrays slow to
+/// parse and huge. The CSV and binary witnesses store the flat scalar values in the
+/// order they are loaded onto the stack, and are validated against the program input type.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WitnessFormat {
+    Json,
+    Csv,
+    Binary,
+}
+
+impl WitnessFormat {
+    /// Detects the format by the file extension, defaulting to JSON.
+    pub fn from_path(path: &Path) -> Self {
+        path.extension()
+            .and_then(|extension| extension.to_str())
+            .and_then(|extension| extension.parse().ok())
+            .unwrap_or(WitnessFormat::Json)
+    }
+}
+
+impl FromStr for WitnessFormat {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "json" => Ok(WitnessFormat::Json),
+            "csv" => Ok(WitnessFormat::Csv),
+            "bin" => Ok(WitnessFormat::Binary),
+            another => Err(format!("unknown witness format \"{}\"", another)),
+        }
+    }
+}
+
+#[derive(Debug, Fail, PartialEq)]
+pub enum WitnessError {
+    #[fail(
+        display = "line {}: failed to parse \"{}\": expected a decimal or hexadecimal number, or a boolean",
+        line, value
+    )]
+    InvalidValueFormat { line: usize, value: String },
+
+    #[fail(
+        display = "expected {} values for the input type, got {}",
+        expected, actual
+    )]
+    UnexpectedSize { expected: usize, actual: usize },
+
+    #[fail(
+        display = "value #{} ({}) is out of range of {}",
+        index, value, scalar_type
+    )]
+    OutOfRange {
+        index: usize,
+        value: BigInt,
+        scalar_type: ScalarType,
+    },
+
+    #[fail(
+        display = "the witness is encoded for the input {}, but the program input is {}",
+        expected, actual
+    )]
+    InputTypeMismatch {
+        expected: DataType,
+        actual: DataType,
+    },
+
+    #[fail(display = "failed to decode binary witness: {}", _0)]
+    BinaryDecoding(String),
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct BinaryWitness {
+    input: DataType,
+    values: Vec<BigInt>,
+}
+
+impl Value {
+    /// Parses the CSV witness, which is the list of the flat scalar values separated by
+    /// commas or line breaks. Everything after `#` till the end of line is a comment.
+    ///
+    /// The booleans are written as `true` and `false`, or `1` and `0`.
+    pub fn from_csv(text: &str, data_type: &DataType) -> Result<Self, WitnessError> {
+        let mut values = Vec::new();
+        for (index, line) in text.lines().enumerate() {
+            let line = match line.find('#') {
+                Some(position) => &line[..position],
+                None => line,
+            };
+
+            for value in line
+                .split(',')
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+            {
+                let parsed =
+                    parse_csv_value(value).ok_or_else(|| WitnessError::InvalidValueFormat {
+                        line: index + 1,
+                        value: value.to_owned(),
+                    })?;
+                values.push(parsed);
+            }
+        }
+
+        Self::from_checked_flat_values(data_type, values)
+    }
+
+    /// Writes the CSV witness with a single scalar value per line.
+    pub fn to_csv(&self) -> String {
+        let mut scalars = Vec::new();
+        self.collect_scalars(&mut scalars);
+
+        let mut result = String::new();
+        for scalar in scalars.into_iter() {
+            match scalar {
+                ScalarValue::Bool(value) => result.push_str(&value.to_string()),
+                ScalarValue::Field(value) | ScalarValue::Integer(value, _) => {
+                    result.push_str(&value.to_str_radix(10))
+                }
+            }
+            result.push('\n');
+        }
+        result
+    }
+
+    /// Decodes the binary witness, checking it is encoded for the `data_type` input.
+    pub fn from_binary(bytes: &[u8], data_type: &DataType) -> Result<Self, WitnessError> {
+        if !bytes.starts_with(BINARY_FORMAT_MAGIC) {
+            return Err(WitnessError::BinaryDecoding(
+                "the magic bytes are missing".to_owned(),
+            ));
+        }
+
+        let witness: BinaryWitness = match bytes.get(BINARY_FORMAT_MAGIC.len()) {
+            Some(&BINARY_FORMAT_VERSION) => bincode::DefaultOptions::new()
+                .deserialize(&bytes[BINARY_FORMAT_MAGIC.len() + 1..])
+                .map_err(|error| WitnessError::BinaryDecoding(error.to_string()))?,
+            Some(version) => {
+                return Err(WitnessError::BinaryDecoding(format!(
+                    "unsupported format version {}",
+                    version
+                )))
+            }
+            None => {
+                return Err(WitnessError::BinaryDecoding(
+                    "the format version is missing".to_owned(),
+                ))
+            }
+        };
+
+        if &witness.input != data_type {
+            return Err(WitnessError::InputTypeMismatch {
+                expected: witness.input,
+                actual: data_type.to_owned(),
+            });
+        }
+
+        Self::from_checked_flat_values(data_type, witness.values)
+    }
+
+    /// Encodes the binary witness along with its input type, using the variable-length
+    /// integer encoding.
+    pub fn to_binary(&self, data_type: &DataType) -> Vec<u8> {
+        let witness = BinaryWitness {
+            input: data_type.to_owned(),
+            values: self.to_flat_values(),
+        };
+
+        let mut bytes = BINARY_FORMAT_MAGIC.to_vec();
+        bytes.push(BINARY_FORMAT_VERSION);
+        bincode::DefaultOptions::new()
+            .serialize_into(&mut bytes, &witness)
+            .expect("Failed to serialize witness");
+        bytes
+    }
+
+    fn from_checked_flat_values(
+        data_type: &DataType,
+        values: Vec<BigInt>,
+    ) -> Result<Self, WitnessError> {
+        let scalar_types = data_type.scalar_types();
+        if values.len() != scalar_types.len() {
+            return Err(WitnessError::UnexpectedSize {
+                expected: scalar_types.len(),
+                actual: values.len(),
+            });
+        }
+
+        for (index, (value, scalar_type)) in values.iter().zip(scalar_types).enumerate() {
+            if !is_in_range(value, &scalar_type) {
+                return Err(WitnessError::OutOfRange {
+                    index,
+                    value: value.to_owned(),
+                    scalar_type,
+                });
+            }
+        }
+
+        Ok(Self::from_flat_values(data_type, values.as_slice())
+            .expect("The number of values is checked above"))
+    }
+
+    fn collect_scalars<'a>(&'a self, scalars: &mut Vec<&'a ScalarValue>) {
+        match self {
+            Value::Unit => {}
+            Value::Scalar(scalar) => scalars.push(scalar),
+            Value::Struct(fields) => {
+                for StructField { value, .. } in fields.iter() {
+                    value.collect_scalars(scalars);
+                }
+            }
+            Value::Array(values) => {
+                for value in values.iter() {
+                    value.collect_scalars(scalars);
+                }
+            }
+        }
+    }
+}
+
+fn parse_csv_value(value: &str) -> Option<BigInt> {
+    match value {
+        "true" => Some(BigInt::from(1)),
+        "false" => Some(BigInt::from(0)),
+        value if value.starts_with("0x") => BigInt::from_str_radix(&value[2..], 16).ok(),
+        value => BigInt::from_str_radix(value, 10).ok(),
+    }
+}
+
+fn is_in_range(value: &BigInt, scalar_type: &ScalarType) -> bool {
+    match scalar_type {
+        ScalarType::Boolean => value.is_zero() || value == &BigInt::from(1),
+        ScalarType::Integer(integer_type) => {
+            value >= &integer_type.min() && value <= &integer_type.max()
+        }
+        ScalarType::Field => value.sign() != Sign::Minus && value.bits() <= FIELD_BITLENGTH,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::scalar::IntegerType;
+
+    fn input_type() -> DataType {
+        DataType::Struct(vec![
+            (
+                "amounts".to_owned(),
+                DataType::Array(Box::new(DataType::Scalar(IntegerType::U8.into())), 3),
+            ),
+            ("flag".to_owned(), DataType::Scalar(ScalarType::Boolean)),
+            ("delta".to_owned(), DataType::Scalar(IntegerType::I8.into())),
+        ])
+    }
+
+    #[test]
+    fn test_csv() {
+        let text = "# amounts\n1, 2, 0xff\ntrue # flag\n-128\n";
+
+        let value = Value::from_csv(text, &input_type()).expect("valid witness");
+
+        assert_eq!(
+            value.to_flat_values(),
+            vec![1, 2, 255, 1, -128]
+                .into_iter()
+                .map(BigInt::from)
+                .collect::<Vec<BigInt>>()
+        );
+        assert_eq!(value.to_csv(), "1\n2\n255\ntrue\n-128\n");
+    }
+
+    #[test]
+    fn test_csv_errors() {
+        assert_eq!(
+            Value::from_csv("1, 2, 3\nyes\n0", &input_type()).err(),
+            Some(WitnessError::InvalidValueFormat {
+                line: 2,
+                value: "yes".to_owned()
+            })
+        );
+        assert_eq!(
+            Value::from_csv("1, 2, 3, true", &input_type()).err(),
+            Some(WitnessError::UnexpectedSize {
+                expected: 5,
+                actual: 4
+            })
+        );
+        assert_eq!(
+            Value::from_csv("1, 256, 3, true, 0", &input_type()).err(),
+            Some(WitnessError::OutOfRange {
+                index: 1,
+                value: BigInt::from(256),
+                scalar_type: IntegerType::U8.into(),
+            })
+        );
+        assert_eq!(
+            Value::from_csv("1, 2, 3, 2, 0", &input_type()).err(),
+            Some(WitnessError::OutOfRange {
+                index: 3,
+                value: BigInt::from(2),
+                scalar_type: ScalarType::Boolean,
+            })
+        );
+    }
+
+    #[test]
+    fn test_binary() {
+        let value = Value::from_csv("1, 2, 3, false, -1", &input_type()).expect("valid witness");
+        let bytes = value.to_binary(&input_type());
+
+        let decoded = Value::from_binary(bytes.as_slice(), &input_type()).expect("valid witness");
+        assert_eq!(decoded.to_flat_values(), value.to_flat_values());
+
+        let another_type = DataType::Array(Box::new(DataType::Scalar(ScalarType::Field)), 5);
+        assert_eq!(
+            Value::from_binary(bytes.as_slice(), &another_type).err(),
+            Some(WitnessError::InputTypeMismatch {
+                expected: input_type(),
+                actual: another_type,
+            })
+        );
+        assert!(Value::from_binary(&bytes[1..], &input_type()).is_err());
+    }
+
+    #[test]
+    fn test_format_from_path() {
+        assert_eq!(
+            WitnessFormat::from_path(Path::new("data/witness.csv")),
+            WitnessFormat::Csv
+        );
+        assert_eq!(
+            WitnessFormat::from_path(Path::new("data/witness.bin")),
+            WitnessFormat::Binary
+        );
+        assert_eq!(
+            WitnessFormat::from_path(Path::new("data/witness.json")),
+            WitnessFormat::Json
+        );
+    }
+}
@@ -1,23 +1,59 @@
+use crate::data::types::DataType;
 use crate::{Instruction, InstructionInfo};
 use serde_derive::{Deserialize, Serialize};
 
 #[derive(Debug, PartialEq, Default, Clone, Serialize, Deserialize)]
 pub struct Assert {
     pub message: Option<String>,
+    pub arg_types: Vec<DataType>,
+    pub code: Option<u64>,
 }
 
 impl Assert {
     pub fn new(message: Option<String>) -> Self {
-        Self { message }
+        Self::new_formatted(message, vec![], None)
+    }
+
+    ///
+    /// Creates an assertion with the message format string, which `{}` placeholders are
+    /// replaced with the arguments of `arg_types`, and the optional error `code`.
+    ///
+    pub fn new_formatted(
+        message: Option<String>,
+        arg_types: Vec<DataType>,
+        code: Option<u64>,
+    ) -> Self {
+        Self {
+            message,
+            arg_types,
+            code,
+        }
     }
 }
 
 impl InstructionInfo for Assert {
     fn to_assembly(&self) -> String {
-        match &self.message {
-            None => "assert".to_owned(),
-            Some(text) => format!("assert \"{}\"", text),
+        let mut assembly = "assert".to_owned();
+        if let Some(code) = self.code {
+            assembly.push_str(format!(" code {}", code).as_str());
+        }
+        if let Some(message) = &self.message {
+            assembly.push_str(format!(" {:?}", message).as_str());
+        }
+        if !self.arg_types.is_empty() {
+            assembly.push_str(
+                format!(
+                    " ({})",
+                    self.arg_types
+                        .iter()
+                        .map(DataType::to_string)
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                )
+                .as_str(),
+            );
         }
+        assembly
     }
 
     fn wrap(&self) -> Instruction {
@@ -0,0 +1,34 @@
+use crate::{Instruction, InstructionInfo};
+use serde_derive::{Deserialize, Serialize};
+
+/// Calls the native gadget registered in the VM by a downstream crate, which is
+/// declared to the compiler as a `std::ext` function with the same `identifier`.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct CallExtension {
+    pub identifier: String,
+    pub inputs_count: usize,
+    pub outputs_count: usize,
+}
+
+impl CallExtension {
+    pub fn new(identifier: String, inputs_count: usize, outputs_count: usize) -> Self {
+        Self {
+            identifier,
+            inputs_count,
+            outputs_count,
+        }
+    }
+}
+
+impl InstructionInfo for CallExtension {
+    fn to_assembly(&self) -> String {
+        format!(
+            "call_extension {:?}({}) -> {}",
+            self.identifier, self.inputs_count, self.outputs_count
+        )
+    }
+
+    fn wrap(&self) -> Instruction {
+        Instruction::CallExtension((*self).clone())
+    }
+}
@@ -0,0 +1,37 @@
+use crate::data::types::DataType;
+use crate::{Instruction, InstructionInfo};
+use serde_derive::{Deserialize, Serialize};
+
+/// Calls the host function declared with `extern "host" fn`, whose result is
+/// supplied by the callback registered in the VM while generating the witness.
+///
+/// The result is allocated as a witness of `output` and range-checked in the circuit.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct CallHost {
+    pub identifier: String,
+    pub inputs_count: usize,
+    pub output: DataType,
+}
+
+impl CallHost {
+    pub fn new(identifier: String, inputs_count: usize, output: DataType) -> Self {
+        Self {
+            identifier,
+            inputs_count,
+            output,
+        }
+    }
+}
+
+impl InstructionInfo for CallHost {
+    fn to_assembly(&self) -> String {
+        format!(
+            "call_host {:?}({}) -> ({})",
+            self.identifier, self.inputs_count, self.output
+        )
+    }
+
+    fn wrap(&self) -> Instruction {
+        Instruction::CallHost((*self).clone())
+    }
+}
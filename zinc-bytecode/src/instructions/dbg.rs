@@ -16,7 +16,15 @@ impl Dbg {
 
 impl InstructionInfo for Dbg {
     fn to_assembly(&self) -> String {
-        "dbg".into()
+        format!(
+            "dbg {:?} ({})",
+            self.format,
+            self.arg_types
+                .iter()
+                .map(DataType::to_string)
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
     }
 
     fn wrap(&self) -> Instruction {
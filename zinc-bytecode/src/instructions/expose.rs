@@ -0,0 +1,27 @@
+use crate::data::types::DataType;
+use crate::{Instruction, InstructionInfo};
+use serde_derive::{Deserialize, Serialize};
+
+/// Pops the value of `data_type` and makes it the named public output of the circuit,
+/// which precedes the values returned from `main`.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct Expose {
+    pub name: String,
+    pub data_type: DataType,
+}
+
+impl Expose {
+    pub fn new(name: String, data_type: DataType) -> Self {
+        Self { name, data_type }
+    }
+}
+
+impl InstructionInfo for Expose {
+    fn to_assembly(&self) -> String {
+        format!("expose {:?} ({})", self.name, self.data_type)
+    }
+
+    fn wrap(&self) -> Instruction {
+        Instruction::Expose((*self).clone())
+    }
+}
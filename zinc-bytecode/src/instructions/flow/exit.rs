@@ -15,7 +15,7 @@ impl Exit {
 
 impl InstructionInfo for Exit {
     fn to_assembly(&self) -> String {
-        "exit".into()
+        format!("exit {}", self.outputs_count)
     }
 
     fn wrap(&self) -> Instruction {
@@ -14,7 +14,7 @@ impl FunctionMarker {
 
 impl InstructionInfo for FunctionMarker {
     fn to_assembly(&self) -> String {
-        format!("marker: function = \"{}\"", self.function)
+        format!("marker: function = {:?}", self.function)
     }
 
     fn wrap(&self) -> Instruction {
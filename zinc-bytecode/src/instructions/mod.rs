@@ -10,12 +10,18 @@ pub use memory::*;
 
 mod assert;
 mod call_buitin;
+mod call_extension;
+mod call_host;
 mod cast;
 mod dbg;
+mod expose;
 mod noop;
 
 pub use assert::Assert;
 pub use call_buitin::CallBuiltin;
+pub use call_extension::CallExtension;
+pub use call_host::CallHost;
 pub use cast::Cast;
 pub use dbg::Dbg;
+pub use expose::Expose;
 pub use noop::NoOperation;
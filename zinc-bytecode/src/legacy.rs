@@ -0,0 +1,123 @@
+//!
+//! The instruction set of the program format versions 0 and 1.
+//!
+//! The instructions are serialized with their variant index and fields, so the programs
+//! written by the older toolchains are decoded with the instruction set frozen at their
+//! format version and converted to the current one. The instructions whose layout has
+//! not changed since are shared with the current instruction set.
+//!
+
+use serde_derive::Deserialize;
+
+use crate::instructions::*;
+
+/// The `assert` instruction of the versions 0 and 1, which only has the message.
+#[derive(Debug, PartialEq, Deserialize)]
+pub struct LegacyAssert {
+    pub message: Option<String>,
+}
+
+impl From<LegacyAssert> for Assert {
+    fn from(instruction: LegacyAssert) -> Self {
+        Self::new(instruction.message)
+    }
+}
+
+/// Declares the legacy instruction set and its conversion to the current one.
+///
+/// The variants must be listed in the order of their serialized indexes, and must never
+/// be reordered or changed, otherwise the programs of the version will not be decoded.
+macro_rules! legacy_instruction_set {
+    ($($variant:ident($instruction:ty),)*) => {
+        #[derive(Debug, PartialEq, Deserialize)]
+        pub enum LegacyInstruction {
+            $($variant($instruction),)*
+        }
+
+        impl From<LegacyInstruction> for crate::Instruction {
+            fn from(instruction: LegacyInstruction) -> Self {
+                match instruction {
+                    $(LegacyInstruction::$variant(instruction) => {
+                        crate::Instruction::$variant(instruction.into())
+                    })*
+                }
+            }
+        }
+    };
+}
+
+legacy_instruction_set! {
+    NoOperation(NoOperation),
+
+    PushConst(PushConst),
+    Pop(Pop),
+    Slice(Slice),
+    Swap(Swap),
+    Tee(Tee),
+
+    Load(Load),
+    LoadSequence(LoadSequence),
+    LoadByIndex(LoadByIndex),
+    LoadSequenceByIndex(LoadSequenceByIndex),
+
+    Store(Store),
+    StoreSequence(StoreSequence),
+    StoreByIndex(StoreByIndex),
+    StoreSequenceByIndex(StoreSequenceByIndex),
+
+    LoadGlobal(LoadGlobal),
+    LoadSequenceGlobal(LoadSequenceGlobal),
+    LoadByIndexGlobal(LoadByIndexGlobal),
+    LoadSequenceByIndexGlobal(LoadSequenceByIndexGlobal),
+
+    StoreGlobal(StoreGlobal),
+    StoreSequenceGlobal(StoreSequenceGlobal),
+
+    Add(Add),
+    Sub(Sub),
+    Mul(Mul),
+    Div(Div),
+    Rem(Rem),
+    Neg(Neg),
+
+    Not(Not),
+    And(And),
+    Or(Or),
+    Xor(Xor),
+
+    Lt(Lt),
+    Le(Le),
+    Eq(Eq),
+    Ne(Ne),
+    Ge(Ge),
+    Gt(Gt),
+
+    BitShiftLeft(BitShiftLeft),
+    BitShiftRight(BitShiftRight),
+    BitAnd(BitAnd),
+    BitOr(BitOr),
+    BitXor(BitXor),
+    BitNot(BitNot),
+
+    Cast(Cast),
+
+    If(If),
+    Else(Else),
+    EndIf(EndIf),
+    LoopBegin(LoopBegin),
+    LoopEnd(LoopEnd),
+    Call(Call),
+    Return(Return),
+
+    CallBuiltin(CallBuiltin),
+
+    Assert(LegacyAssert),
+    Dbg(Dbg),
+
+    Exit(Exit),
+
+    FileMarker(FileMarker),
+    FunctionMarker(FunctionMarker),
+    LineMarker(LineMarker),
+    ColumnMarker(ColumnMarker),
+}
@@ -1,14 +1,25 @@
 pub mod logger;
 pub mod scalar;
 
+pub mod artifact;
+pub mod assembly;
 pub mod builtins;
 pub mod data;
 pub mod instructions;
+mod legacy;
+pub mod library;
 pub mod program;
+pub mod proof;
+pub mod source_map;
+pub mod validation;
 pub mod vlq;
 
+pub use artifact::Artifact;
 pub use instructions::*;
+pub use library::Library;
 pub use program::*;
+pub use source_map::{SourceLocation, SourceMap};
+pub use validation::{stack_effect, validate, ValidationError};
 
 use crate::instructions::FileMarker;
 use serde_derive::{Deserialize, Serialize};
@@ -110,6 +121,12 @@ pub enum Instruction {
     FunctionMarker(FunctionMarker),
     LineMarker(LineMarker),
     ColumnMarker(ColumnMarker),
+
+    // Public outputs
+    Expose(Expose),
+
+    CallExtension(CallExtension),
+    CallHost(CallHost),
 }
 
 /// Useful macro to avoid duplicating `match` constructions.
@@ -197,6 +214,11 @@ macro_rules! dispatch_instruction {
             Instruction::FunctionMarker($pattern) => $expression,
             Instruction::LineMarker($pattern) => $expression,
             Instruction::ColumnMarker($pattern) => $expression,
+
+            Instruction::Expose($pattern) => $expression,
+
+            Instruction::CallExtension($pattern) => $expression,
+            Instruction::CallHost($pattern) => $expression,
         }
     };
 }
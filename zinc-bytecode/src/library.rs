@@ -0,0 +1,224 @@
+use crate::data::types::DataType;
+use crate::Instruction;
+use bincode::Options;
+use serde_derive::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// The library artifact starts with the magic bytes followed by the format version,
+/// and ends with the SHA-256 checksum of everything before it, like the build artifact.
+const LIBRARY_FORMAT_MAGIC: &[u8] = b"ZNL";
+const LIBRARY_FORMAT_VERSION: u8 = 1;
+const CHECKSUM_SIZE: usize = 32;
+const SYMBOL_VERSION_SIZE: usize = 8;
+
+/// The precompiled library, which is linked into the programs depending on it
+/// without compiling its sources again.
+///
+/// The bytecode contains the library functions along with the functions of its own
+/// dependencies, so the library is self-contained. The call addresses are relative
+/// to the beginning of the bytecode.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Library {
+    pub name: String,
+    pub version: String,
+    pub symbols: Vec<Symbol>,
+    pub bytecode: Vec<Instruction>,
+}
+
+/// The function exported by the library.
+///
+/// The symbol version is the fingerprint of the function signature, which is checked
+/// when the library is linked, so a program never calls a function whose interface
+/// differs from the one it has been type-checked against.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Symbol {
+    pub name: String,
+    pub address: usize,
+    pub arguments: Vec<(String, DataType)>,
+    pub output: DataType,
+    pub version: String,
+}
+
+impl Symbol {
+    pub fn new(
+        name: String,
+        address: usize,
+        arguments: Vec<(String, DataType)>,
+        output: DataType,
+    ) -> Self {
+        let mut symbol = Self {
+            name,
+            address,
+            arguments,
+            output,
+            version: String::new(),
+        };
+        symbol.version = symbol.fingerprint();
+        symbol
+    }
+
+    /// The function signature, e.g. `fn square(value: u8) -> u8`.
+    pub fn signature(&self) -> String {
+        format!(
+            "fn {}({}) -> {}",
+            self.name,
+            self.arguments
+                .iter()
+                .map(|(name, r#type)| format!("{}: {}", name, r#type))
+                .collect::<Vec<String>>()
+                .join(", "),
+            self.output
+        )
+    }
+
+    /// The hex-encoded prefix of the signature SHA-256 hash.
+    pub fn fingerprint(&self) -> String {
+        let hash = Sha256::digest(self.signature().as_bytes());
+        hex::encode(&hash[..SYMBOL_VERSION_SIZE])
+    }
+
+    /// Checks that the symbol version matches its signature.
+    pub fn is_version_valid(&self) -> bool {
+        self.version == self.fingerprint()
+    }
+
+    pub fn input_size(&self) -> usize {
+        self.arguments
+            .iter()
+            .map(|(_name, r#type)| r#type.size())
+            .sum()
+    }
+}
+
+impl Library {
+    /// The format version written by `to_bytes`.
+    pub const FORMAT_VERSION: u8 = LIBRARY_FORMAT_VERSION;
+    pub const FILE_EXTENSION: &'static str = "znlib";
+
+    pub fn new(
+        name: String,
+        version: String,
+        symbols: Vec<Symbol>,
+        bytecode: Vec<Instruction>,
+    ) -> Self {
+        Self {
+            name,
+            version,
+            symbols,
+            bytecode,
+        }
+    }
+
+    pub fn symbol(&self, name: &str) -> Option<&Symbol> {
+        self.symbols.iter().find(|symbol| symbol.name == name)
+    }
+
+    /// Returns the bytecode moved to the `offset`, with the call addresses shifted.
+    pub fn relocated(&self, offset: usize) -> Vec<Instruction> {
+        self.bytecode
+            .iter()
+            .cloned()
+            .map(|instruction| match instruction {
+                Instruction::Call(mut call) => {
+                    call.address += offset;
+                    Instruction::Call(call)
+                }
+                instruction => instruction,
+            })
+            .collect()
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = LIBRARY_FORMAT_MAGIC.to_vec();
+        bytes.push(LIBRARY_FORMAT_VERSION);
+        bincode::DefaultOptions::new()
+            .serialize_into(&mut bytes, self)
+            .expect("Failed to serialize library");
+        let checksum = Sha256::digest(bytes.as_slice());
+        bytes.extend_from_slice(checksum.as_slice());
+        bytes
+    }
+
+    /// Decodes the library, verifying its checksum and format version.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        if !bytes.starts_with(LIBRARY_FORMAT_MAGIC) {
+            return Err("not a library artifact".to_owned());
+        }
+
+        if bytes.len() < LIBRARY_FORMAT_MAGIC.len() + 1 + CHECKSUM_SIZE {
+            return Err("library is truncated".to_owned());
+        }
+        let (content, checksum) = bytes.split_at(bytes.len() - CHECKSUM_SIZE);
+        if Sha256::digest(content).as_slice() != checksum {
+            return Err("library checksum mismatch, the file is corrupted".to_owned());
+        }
+
+        let body = &content[LIBRARY_FORMAT_MAGIC.len() + 1..];
+        match content[LIBRARY_FORMAT_MAGIC.len()] {
+            LIBRARY_FORMAT_VERSION => bincode::DefaultOptions::new()
+                .deserialize(body)
+                .map_err(|e| format!("{:?}", e)),
+            version => Err(format!(
+                "library format version {} is not supported, expected version {}",
+                version,
+                Self::FORMAT_VERSION
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::data::types::ScalarType;
+    use crate::instructions::{Call, Mul, Return};
+    use crate::InstructionInfo;
+
+    fn library() -> Library {
+        let symbol = Symbol::new(
+            "square".to_owned(),
+            0,
+            vec![("value".to_owned(), DataType::Scalar(ScalarType::Field))],
+            DataType::Scalar(ScalarType::Field),
+        );
+        Library::new(
+            "math".to_owned(),
+            "0.1.0".to_owned(),
+            vec![symbol],
+            vec![Mul.wrap(), Call::new(0, 1).wrap(), Return::new(1).wrap()],
+        )
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let library = library();
+        let decoded = Library::from_bytes(library.to_bytes().as_slice()).expect("valid library");
+        assert_eq!(decoded, library);
+    }
+
+    #[test]
+    fn test_corrupted() {
+        let mut bytes = library().to_bytes();
+        let last = bytes.len() - CHECKSUM_SIZE - 1;
+        bytes[last] ^= 0xff;
+        assert!(Library::from_bytes(bytes.as_slice()).is_err());
+
+        let program = crate::Program::new(DataType::Unit, DataType::Unit, vec![]);
+        assert!(Library::from_bytes(program.to_bytes().as_slice()).is_err());
+    }
+
+    #[test]
+    fn test_symbol_version() {
+        let mut symbol = library().symbols.remove(0);
+        assert_eq!(symbol.signature(), "fn square(value: field) -> field");
+        assert!(symbol.is_version_valid());
+
+        symbol.output = DataType::Unit;
+        assert!(!symbol.is_version_valid());
+    }
+
+    #[test]
+    fn test_relocated() {
+        assert_eq!(library().relocated(10)[1], Call::new(10, 1).wrap());
+    }
+}
@@ -1,28 +1,423 @@
 use crate::data::types::DataType;
+use crate::data::values::{StructField, Value};
+use crate::legacy::LegacyInstruction;
 use crate::Instruction;
+use bincode::Options;
 use serde_derive::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// The compact program format starts with the magic bytes followed by the format version.
+///
+/// The programs without the header are decoded as the legacy fixed-width encoding.
+const COMPACT_FORMAT_MAGIC: &[u8] = b"ZNB";
+const COMPACT_FORMAT_VERSION: u8 = 2;
+/// The version 1 and legacy programs have no public input arguments.
+const COMPACT_FORMAT_VERSION_WITHOUT_PUBLIC_INPUT: u8 = 1;
+/// The legacy programs have no header, so their version is implied.
+const LEGACY_FORMAT_VERSION: u8 = 0;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Program {
     pub input: DataType,
     pub output: DataType,
     pub bytecode: Vec<Instruction>,
+    /// The names of the input structure fields, which are the public inputs of the circuit
+    /// along with the output. The other input fields are the private witness.
+    pub public_input: Vec<String>,
+}
+
+/// The program of the versions 0 and 1, which have no public input arguments and are
+/// written with the legacy instruction set.
+#[derive(Deserialize)]
+struct ProgramWithoutPublicInput {
+    input: DataType,
+    output: DataType,
+    bytecode: Vec<LegacyInstruction>,
+}
+
+impl From<ProgramWithoutPublicInput> for Program {
+    fn from(program: ProgramWithoutPublicInput) -> Self {
+        Self::new(
+            program.input,
+            program.output,
+            program
+                .bytecode
+                .into_iter()
+                .map(Instruction::from)
+                .collect(),
+        )
+    }
 }
 
 impl Program {
+    /// The format version written by `to_bytes`.
+    pub const FORMAT_VERSION: u8 = COMPACT_FORMAT_VERSION;
+
     pub fn new(input: DataType, output: DataType, bytecode: Vec<Instruction>) -> Self {
         Self {
             input,
             output,
             bytecode,
+            public_input: vec![],
         }
     }
 
+    pub fn with_public_input(mut self, public_input: Vec<String>) -> Self {
+        self.public_input = public_input;
+        self
+    }
+
+    /// Serializes the program using the variable-length integer encoding,
+    /// which shrinks the instruction tags, operands and constants.
     pub fn to_bytes(&self) -> Vec<u8> {
-        bincode::serialize(self).expect("Failed to serialize program")
+        let mut bytes = COMPACT_FORMAT_MAGIC.to_vec();
+        bytes.push(COMPACT_FORMAT_VERSION);
+        bincode::DefaultOptions::new()
+            .serialize_into(&mut bytes, self)
+            .expect("Failed to serialize program");
+        bytes
     }
 
+    /// Decodes the program, migrating the older format versions to the current one.
+    ///
+    /// The versions newer than the current one are rejected, since they may contain
+    /// the instructions this VM does not know.
     pub fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
-        bincode::deserialize(bytes).map_err(|e| format!("{:?}", e))
+        let version = Self::format_version(bytes)?;
+        let body = bytes
+            .get(COMPACT_FORMAT_MAGIC.len() + 1..)
+            .unwrap_or_default();
+        let result = match version {
+            LEGACY_FORMAT_VERSION => {
+                bincode::deserialize::<ProgramWithoutPublicInput>(bytes).map(Self::from)
+            }
+            COMPACT_FORMAT_VERSION_WITHOUT_PUBLIC_INPUT => bincode::DefaultOptions::new()
+                .deserialize::<ProgramWithoutPublicInput>(body)
+                .map(Self::from),
+            COMPACT_FORMAT_VERSION => bincode::DefaultOptions::new().deserialize(body),
+            version => {
+                return Err(format!(
+                    "program format version {} is newer than the supported version {}, upgrade the toolchain",
+                    version,
+                    Self::FORMAT_VERSION
+                ))
+            }
+        };
+
+        result
+            .map_err(|error| format!("program format version {} is malformed: {}", version, error))
+    }
+
+    /// Reads the format version from the program header.
+    ///
+    /// The legacy programs without the header have the version 0.
+    pub fn format_version(bytes: &[u8]) -> Result<u8, String> {
+        if !bytes.starts_with(COMPACT_FORMAT_MAGIC) {
+            return Ok(LEGACY_FORMAT_VERSION);
+        }
+
+        bytes
+            .get(COMPACT_FORMAT_MAGIC.len())
+            .copied()
+            .ok_or_else(|| "program format version is missing".to_owned())
+    }
+
+    /// The hex-encoded SHA-256 hash of the serialized program.
+    ///
+    /// The constraint system is fully determined by the bytecode, so the hash
+    /// identifies the circuit the proving and verifying keys are generated for.
+    pub fn hash(&self) -> String {
+        hex::encode(Sha256::digest(self.to_bytes().as_slice()))
+    }
+
+    /// The structure of the public input fields, in the order of the input structure.
+    pub fn public_input_type(&self) -> DataType {
+        let fields = match self.input {
+            DataType::Struct(ref fields) => fields
+                .iter()
+                .filter(|(name, _)| self.public_input.contains(name))
+                .cloned()
+                .collect(),
+            _ => vec![],
+        };
+        DataType::Struct(fields)
+    }
+
+    /// Whether each scalar of the flat input is a public input.
+    pub fn public_input_mask(&self) -> Vec<bool> {
+        match self.input {
+            DataType::Struct(ref fields) => fields
+                .iter()
+                .flat_map(|(name, field)| vec![self.public_input.contains(name); field.size()])
+                .collect(),
+            ref input => vec![false; input.size()],
+        }
+    }
+
+    /// The structure of the values made public with the `expose` instructions, in the
+    /// order of the bytecode, which is the order they are exposed at runtime.
+    pub fn exposed_type(&self) -> DataType {
+        DataType::Struct(
+            self.bytecode
+                .iter()
+                .filter_map(|instruction| match instruction {
+                    Instruction::Expose(expose) => {
+                        Some((expose.name.clone(), expose.data_type.clone()))
+                    }
+                    _ => None,
+                })
+                .collect(),
+        )
+    }
+
+    /// The type of the data passed to the verifier.
+    ///
+    /// It is the output if there are no public input fields and exposed values, which
+    /// keeps the existing public data files valid. Otherwise, the public input and the
+    /// exposed values are followed by the output, which is the order of the circuit
+    /// public inputs.
+    pub fn public_data_type(&self) -> DataType {
+        if self.public_input.is_empty() && !self.has_exposed() {
+            return self.output.clone();
+        }
+
+        let mut fields = Vec::with_capacity(3);
+        if !self.public_input.is_empty() {
+            fields.push(("input".to_owned(), self.public_input_type()));
+        }
+        if self.has_exposed() {
+            fields.push(("exposed".to_owned(), self.exposed_type()));
+        }
+        fields.push(("output".to_owned(), self.output.clone()));
+        DataType::Struct(fields)
+    }
+
+    /// Extracts the public data from the program `input`, `exposed` and `output` values.
+    pub fn public_data(&self, input: &Value, exposed: Value, output: Value) -> Value {
+        if self.public_input.is_empty() && !self.has_exposed() {
+            return output;
+        }
+
+        let mut fields = Vec::with_capacity(3);
+        if !self.public_input.is_empty() {
+            let public_input = match input {
+                Value::Struct(fields) => fields
+                    .iter()
+                    .filter(|field| self.public_input.contains(&field.field))
+                    .cloned()
+                    .collect(),
+                _ => vec![],
+            };
+            fields.push(StructField {
+                field: "input".to_owned(),
+                value: Value::Struct(public_input),
+            });
+        }
+        if self.has_exposed() {
+            fields.push(StructField {
+                field: "exposed".to_owned(),
+                value: exposed,
+            });
+        }
+        fields.push(StructField {
+            field: "output".to_owned(),
+            value: output,
+        });
+        Value::Struct(fields)
+    }
+
+    fn has_exposed(&self) -> bool {
+        self.bytecode
+            .iter()
+            .any(|instruction| matches!(instruction, Instruction::Expose(_)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::scalar::IntegerType;
+    use crate::{Assert, Exit, Expose, InstructionInfo, PushConst};
+    use num_bigint::BigInt;
+
+    fn program(value: u64) -> Program {
+        Program::new(
+            DataType::Unit,
+            DataType::Unit,
+            vec![PushConst::new(value.into(), IntegerType::U8.into()).wrap()],
+        )
+    }
+
+    #[test]
+    fn test_compact_roundtrip() {
+        let program = program(42);
+        let bytes = program.to_bytes();
+
+        assert!(bytes.starts_with(COMPACT_FORMAT_MAGIC));
+        assert!(bytes.len() < bincode::serialize(&program).unwrap().len());
+        assert_eq!(
+            Program::from_bytes(bytes.as_slice()).unwrap().hash(),
+            program.hash()
+        );
+    }
+
+    #[test]
+    fn test_legacy_decoding() {
+        let program = program(42);
+        let legacy = bincode::serialize(&program).unwrap();
+
+        assert_eq!(
+            Program::from_bytes(legacy.as_slice()).unwrap().hash(),
+            program.hash()
+        );
+    }
+
+    /// The programs compiled from `tests/fixtures/assert.zn` by the toolchains writing
+    /// the versions 0 and 1, which have the `assert` instruction without the format
+    /// arguments and the error code.
+    const LEGACY_ASSERT_FIXTURES: [&[u8]; 2] = [
+        include_bytes!("../tests/fixtures/assert_v0.znb"),
+        include_bytes!("../tests/fixtures/assert_v1.znb"),
+    ];
+
+    #[test]
+    fn test_legacy_assert() {
+        for (version, bytes) in LEGACY_ASSERT_FIXTURES.iter().enumerate() {
+            assert_eq!(Program::format_version(bytes), Ok(version as u8));
+
+            let program = Program::from_bytes(bytes).expect("valid program");
+            let asserts: Vec<&Assert> = program
+                .bytecode
+                .iter()
+                .filter_map(|instruction| match instruction {
+                    Instruction::Assert(assert) => Some(assert),
+                    _ => None,
+                })
+                .collect();
+            assert_eq!(
+                asserts,
+                vec![
+                    &Assert::new(Some("a must be less than b".to_owned())),
+                    &Assert::new(None),
+                ]
+            );
+
+            let migrated =
+                Program::from_bytes(program.to_bytes().as_slice()).expect("valid program");
+            assert_eq!(migrated.bytecode, program.bytecode);
+        }
+    }
+
+    #[test]
+    fn test_version_without_public_input() {
+        let program = program(42);
+        let mut bytes = COMPACT_FORMAT_MAGIC.to_vec();
+        bytes.push(COMPACT_FORMAT_VERSION_WITHOUT_PUBLIC_INPUT);
+        bincode::DefaultOptions::new()
+            .serialize_into(
+                &mut bytes,
+                &(&program.input, &program.output, &program.bytecode),
+            )
+            .unwrap();
+
+        let decoded = Program::from_bytes(bytes.as_slice()).unwrap();
+        assert_eq!(decoded.bytecode, program.bytecode);
+        assert!(decoded.public_input.is_empty());
+    }
+
+    #[test]
+    fn test_format_version() {
+        let program = program(42);
+
+        assert_eq!(
+            Program::format_version(program.to_bytes().as_slice()),
+            Ok(Program::FORMAT_VERSION)
+        );
+        assert_eq!(
+            Program::format_version(bincode::serialize(&program).unwrap().as_slice()),
+            Ok(LEGACY_FORMAT_VERSION)
+        );
+        assert!(Program::format_version(COMPACT_FORMAT_MAGIC).is_err());
+    }
+
+    #[test]
+    fn test_newer_version() {
+        let mut bytes = program(42).to_bytes();
+        bytes[COMPACT_FORMAT_MAGIC.len()] = Program::FORMAT_VERSION + 1;
+
+        let error = Program::from_bytes(bytes.as_slice()).unwrap_err();
+        assert!(error.contains("newer than the supported version"));
+    }
+
+    #[test]
+    fn test_public_data() {
+        let program = Program::new(
+            DataType::Struct(vec![
+                (
+                    "root".to_owned(),
+                    DataType::Scalar(crate::scalar::ScalarType::Field),
+                ),
+                (
+                    "preimage".to_owned(),
+                    DataType::Array(Box::new(DataType::Scalar(IntegerType::U8.into())), 2),
+                ),
+            ]),
+            DataType::Scalar(IntegerType::U8.into()),
+            vec![],
+        )
+        .with_public_input(vec!["root".to_owned()]);
+
+        assert_eq!(program.public_input_mask(), vec![true, false, false]);
+        assert_eq!(
+            program.public_data_type().to_string(),
+            "{ input: { root: field }, output: u8 }"
+        );
+
+        let input =
+            Value::from_flat_values(&program.input, &[1.into(), 2.into(), 3.into()]).unwrap();
+        let output = Value::from_flat_values(&program.output, &[4.into()]).unwrap();
+        let public_data = program.public_data(&input, Value::Unit, output);
+        assert_eq!(
+            public_data.to_flat_values(),
+            vec![BigInt::from(1), BigInt::from(4)]
+        );
+
+        let program = program.with_public_input(vec![]);
+        assert_eq!(program.public_data_type(), program.output);
+    }
+
+    #[test]
+    fn test_public_data_exposed() {
+        let program = Program::new(
+            DataType::Unit,
+            DataType::Scalar(IntegerType::U8.into()),
+            vec![
+                PushConst::new(1.into(), IntegerType::U8.into()).wrap(),
+                Expose::new("sum".to_owned(), DataType::Scalar(IntegerType::U8.into())).wrap(),
+                PushConst::new(2.into(), IntegerType::U8.into()).wrap(),
+                Exit::new(1).wrap(),
+            ],
+        );
+
+        assert_eq!(
+            program.public_data_type().to_string(),
+            "{ exposed: { sum: u8 }, output: u8 }"
+        );
+
+        let exposed = Value::from_flat_values(&program.exposed_type(), &[1.into()]).unwrap();
+        let output = Value::from_flat_values(&program.output, &[2.into()]).unwrap();
+        let public_data = program.public_data(&Value::Unit, exposed, output);
+        assert_eq!(
+            public_data.to_flat_values(),
+            vec![BigInt::from(1), BigInt::from(2)]
+        );
+    }
+
+    #[test]
+    fn test_hash() {
+        let hash = program(1).hash();
+        assert_eq!(hash.len(), 64);
+        assert_eq!(hash, program(1).hash());
+        assert_ne!(hash, program(2).hash());
     }
 }
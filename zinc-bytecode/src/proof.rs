@@ -0,0 +1,183 @@
+use std::fmt;
+use std::str::FromStr;
+
+use failure::Fail;
+use serde_derive::{Deserialize, Serialize};
+
+/// The current version of the proof envelope format.
+pub const PROOF_FORMAT_VERSION: u32 = 1;
+
+/// The elliptic curve the proof is created over.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Curve {
+    Bn256,
+}
+
+/// The proving system the proof is created with.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProvingSystem {
+    Groth16,
+}
+
+/// The proof envelope, which is exchanged between the prover and the verifier.
+///
+/// The `data` field contains the proving system specific proof bytes, and the
+/// public inputs are the flattened public data values as decimal strings.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Proof {
+    pub version: u32,
+    pub curve: Curve,
+    pub proving_system: ProvingSystem,
+    pub public_inputs: Vec<String>,
+    #[serde(with = "hex_bytes")]
+    pub data: Vec<u8>,
+}
+
+#[derive(Debug, Fail)]
+pub enum ProofError {
+    #[fail(display = "unsupported proof format version {}", _0)]
+    UnsupportedVersion(u32),
+
+    #[fail(display = "invalid hex encoding: {}", _0)]
+    Hex(hex::FromHexError),
+
+    #[fail(display = "invalid binary encoding: {}", _0)]
+    Binary(String),
+
+    #[fail(display = "invalid JSON encoding: {}", _0)]
+    Json(serde_json::Error),
+}
+
+impl Proof {
+    pub fn new(
+        curve: Curve,
+        proving_system: ProvingSystem,
+        public_inputs: Vec<String>,
+        data: Vec<u8>,
+    ) -> Self {
+        Self {
+            version: PROOF_FORMAT_VERSION,
+            curve,
+            proving_system,
+            public_inputs,
+            data,
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("Failed to serialize proof")
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, ProofError> {
+        let proof: Self = serde_json::from_str(json).map_err(ProofError::Json)?;
+        proof.check_version()
+    }
+
+    /// Encodes the envelope as a hex string of its binary representation.
+    pub fn to_hex(&self) -> String {
+        hex::encode(bincode::serialize(self).expect("Failed to serialize proof"))
+    }
+
+    pub fn from_hex(string: &str) -> Result<Self, ProofError> {
+        let bytes = hex::decode(string.trim()).map_err(ProofError::Hex)?;
+        let proof: Self = bincode::deserialize(bytes.as_slice())
+            .map_err(|e| ProofError::Binary(e.to_string()))?;
+        proof.check_version()
+    }
+
+    fn check_version(self) -> Result<Self, ProofError> {
+        if self.version != PROOF_FORMAT_VERSION {
+            return Err(ProofError::UnsupportedVersion(self.version));
+        }
+        Ok(self)
+    }
+}
+
+impl FromStr for Proof {
+    type Err = ProofError;
+
+    /// Accepts both the JSON and the hex encodings.
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        if string.trim_start().starts_with('{') {
+            Self::from_json(string)
+        } else {
+            Self::from_hex(string)
+        }
+    }
+}
+
+impl fmt::Display for Proof {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_json())
+    }
+}
+
+mod hex_bytes {
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&hex::encode(bytes))
+        } else {
+            serializer.serialize_bytes(bytes)
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            let string = String::deserialize(deserializer)?;
+            hex::decode(string).map_err(D::Error::custom)
+        } else {
+            Vec::<u8>::deserialize(deserializer)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn proof() -> Proof {
+        Proof::new(
+            Curve::Bn256,
+            ProvingSystem::Groth16,
+            vec!["42".to_owned(), "1".to_owned()],
+            vec![0xde, 0xad, 0xbe, 0xef],
+        )
+    }
+
+    #[test]
+    fn test_json_roundtrip() {
+        let json = proof().to_json();
+        assert!(json.contains("\"data\": \"deadbeef\""));
+        assert!(json.contains("\"proving_system\": \"groth16\""));
+        assert_eq!(json.parse::<Proof>().unwrap(), proof());
+    }
+
+    #[test]
+    fn test_hex_roundtrip() {
+        let hex = proof().to_hex();
+        assert_eq!(hex.parse::<Proof>().unwrap(), proof());
+    }
+
+    #[test]
+    fn test_unsupported_version() {
+        let mut proof = proof();
+        proof.version = PROOF_FORMAT_VERSION + 1;
+        match Proof::from_json(&proof.to_json()) {
+            Err(ProofError::UnsupportedVersion(version)) => {
+                assert_eq!(version, PROOF_FORMAT_VERSION + 1)
+            }
+            _ => panic!("the version must be checked"),
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use crate::Instruction;
+use serde_derive::{Deserialize, Serialize};
+
+/// The source line an instruction has been generated from.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct SourceLocation {
+    /// The file is unknown if the program has been compiled from a string.
+    pub file: Option<String>,
+    pub line: usize,
+}
+
+/// Maps the instruction addresses to the source lines, using the bytecode markers.
+///
+/// The file marker starts every function and the line marker precedes the instructions
+/// of every new line, so an instruction belongs to the line set by the markers written
+/// before it. The markers themselves and the instructions before any line marker,
+/// e.g. the entry call, have no location.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SourceMap {
+    pub locations: Vec<Option<SourceLocation>>,
+}
+
+impl SourceMap {
+    pub fn new(bytecode: &[Instruction]) -> Self {
+        let mut file = None;
+        let mut line = None;
+
+        let locations = bytecode
+            .iter()
+            .map(|instruction| match instruction {
+                Instruction::FileMarker(marker) => {
+                    file = Some(marker.file.clone()).filter(|file| !file.is_empty());
+                    line = None;
+                    None
+                }
+                Instruction::LineMarker(marker) => {
+                    line = Some(marker.line);
+                    None
+                }
+                Instruction::FunctionMarker(_) | Instruction::ColumnMarker(_) => None,
+                _ => line.map(|line| SourceLocation {
+                    file: file.clone(),
+                    line,
+                }),
+            })
+            .collect();
+
+        Self { locations }
+    }
+
+    pub fn get(&self, address: usize) -> Option<&SourceLocation> {
+        self.locations.get(address).and_then(Option::as_ref)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::instructions::{FileMarker, FunctionMarker, LineMarker, Load, Pop, Return};
+    use crate::InstructionInfo;
+
+    fn location(file: &str, line: usize) -> Option<SourceLocation> {
+        Some(SourceLocation {
+            file: Some(file.to_owned()),
+            line,
+        })
+    }
+
+    #[test]
+    fn test_locations() {
+        let bytecode = vec![
+            FileMarker::new("main.zn".to_owned()).wrap(),
+            FunctionMarker::new("main".to_owned()).wrap(),
+            Load::new(0).wrap(),
+            LineMarker::new(2).wrap(),
+            Load::new(1).wrap(),
+            LineMarker::new(3).wrap(),
+            Pop::new(1).wrap(),
+            Return::new(1).wrap(),
+            FileMarker::new("util.zn".to_owned()).wrap(),
+            FunctionMarker::new("double".to_owned()).wrap(),
+            LineMarker::new(7).wrap(),
+            Return::new(1).wrap(),
+        ];
+
+        let source_map = SourceMap::new(bytecode.as_slice());
+
+        assert_eq!(
+            source_map.locations,
+            vec![
+                None,
+                None,
+                None,
+                None,
+                location("main.zn", 2),
+                None,
+                location("main.zn", 3),
+                location("main.zn", 3),
+                None,
+                None,
+                None,
+                location("util.zn", 7),
+            ]
+        );
+        assert_eq!(source_map.get(100), None);
+    }
+
+    #[test]
+    fn test_unknown_file() {
+        let bytecode = vec![
+            FileMarker::new(String::new()).wrap(),
+            LineMarker::new(1).wrap(),
+            Load::new(0).wrap(),
+        ];
+
+        let source_map = SourceMap::new(bytecode.as_slice());
+
+        assert_eq!(
+            source_map.get(2),
+            Some(&SourceLocation {
+                file: None,
+                line: 1
+            })
+        );
+    }
+}
@@ -0,0 +1,727 @@
+//!
+//! The static bytecode verifier.
+//!
+//! Checks a program before it is executed, so that malformed bytecode is rejected
+//! with a structured error instead of failing somewhere in the middle of the
+//! constraint synthesis.
+//!
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use failure::Fail;
+use num_bigint::BigInt;
+use num_traits::Signed;
+
+use crate::program::Program;
+use crate::scalar::ScalarType;
+use crate::Instruction;
+
+/// The maximal integer type bitlength supported by the VM.
+pub const INTEGER_BITLENGTH_MAX: usize = 248;
+
+#[derive(Debug, Fail, PartialEq)]
+pub enum ValidationError {
+    #[fail(
+        display = "instruction {}: calls address {}, which is out of the program",
+        address, target
+    )]
+    CallOutOfRange { address: usize, target: usize },
+
+    #[fail(display = "function at {}: missing `return` instruction", address)]
+    MissingReturn { address: usize },
+
+    #[fail(
+        display = "instruction {}: expected {} return values, got {}",
+        address, expected, actual
+    )]
+    ReturnCountMismatch {
+        address: usize,
+        expected: usize,
+        actual: usize,
+    },
+
+    #[fail(
+        display = "instruction {}: expected {} output values, got {}",
+        address, expected, actual
+    )]
+    OutputCountMismatch {
+        address: usize,
+        expected: usize,
+        actual: usize,
+    },
+
+    #[fail(display = "instruction {}: unexpected `else` instruction", address)]
+    UnexpectedElse { address: usize },
+
+    #[fail(display = "instruction {}: unexpected `end_if` instruction", address)]
+    UnexpectedEndIf { address: usize },
+
+    #[fail(display = "instruction {}: unexpected `loop_end` instruction", address)]
+    UnexpectedLoopEnd { address: usize },
+
+    #[fail(display = "instruction {}: the function has unclosed blocks", address)]
+    UnclosedBlock { address: usize },
+
+    #[fail(display = "instruction {}: loop with zero iterations", address)]
+    EmptyLoop { address: usize },
+
+    #[fail(
+        display = "instruction {}: stack underflow: {} values required, {} available",
+        address, required, available
+    )]
+    StackUnderflow {
+        address: usize,
+        required: usize,
+        available: usize,
+    },
+
+    #[fail(
+        display = "instruction {}: conditional branches produce {} and {} values",
+        address, then_size, else_size
+    )]
+    BranchStacksDoNotMatch {
+        address: usize,
+        then_size: usize,
+        else_size: usize,
+    },
+
+    #[fail(
+        display = "instruction {}: the block changes the stack size from {} to {}",
+        address, expected, actual
+    )]
+    UnbalancedBlock {
+        address: usize,
+        expected: usize,
+        actual: usize,
+    },
+
+    #[fail(
+        display = "instruction {}: invalid integer bitlength {}",
+        address, bitlength
+    )]
+    InvalidBitlength { address: usize, bitlength: usize },
+
+    #[fail(
+        display = "instruction {}: constant {} does not fit into type {}",
+        address, value, scalar_type
+    )]
+    ConstantOutOfRange {
+        address: usize,
+        value: BigInt,
+        scalar_type: ScalarType,
+    },
+
+    #[fail(
+        display = "instruction {}: {} values cannot be accessed in an array of size {}",
+        address, len, array_len
+    )]
+    IndexOutOfBounds {
+        address: usize,
+        len: usize,
+        array_len: usize,
+    },
+
+    #[fail(
+        display = "instruction {}: the public output \"{}\" is exposed more than once",
+        address, name
+    )]
+    DuplicateExpose { address: usize, name: String },
+
+    #[fail(
+        display = "instruction {}: the public output \"{}\" is exposed in a conditional or loop block",
+        address, name
+    )]
+    ExposeInBlock { address: usize, name: String },
+}
+
+///
+/// Checks the call targets, the block nesting, the stack balance, the constant types
+/// and the array access bounds of every function reachable from the entry point.
+///
+pub fn validate(program: &Program) -> Result<(), ValidationError> {
+    Validator::new(program).validate()
+}
+
+/// A block which is open at the current instruction.
+enum Block {
+    /// The `if` block with the stack size before the condition, and the size of the
+    /// `then` branch results if the `else` part has been reached.
+    Branch {
+        outer: usize,
+        then_size: Option<usize>,
+    },
+    /// The loop with the stack size at its beginning.
+    Loop { size: usize },
+}
+
+struct Validator<'a> {
+    program: &'a Program,
+    /// The return values count of the called functions.
+    outputs: HashMap<usize, usize>,
+    visited: HashSet<usize>,
+    pending: Vec<usize>,
+}
+
+impl<'a> Validator<'a> {
+    fn new(program: &'a Program) -> Self {
+        Self {
+            program,
+            outputs: HashMap::new(),
+            visited: HashSet::new(),
+            pending: Vec::new(),
+        }
+    }
+
+    fn validate(mut self) -> Result<(), ValidationError> {
+        let mut exposed = HashSet::new();
+        for (address, instruction) in self.program.bytecode.iter().enumerate() {
+            if let Instruction::Expose(instruction) = instruction {
+                if !exposed.insert(instruction.name.as_str()) {
+                    return Err(ValidationError::DuplicateExpose {
+                        address,
+                        name: instruction.name.clone(),
+                    });
+                }
+            }
+        }
+
+        self.function(0, self.program.input.size())?;
+        while let Some(address) = self.pending.pop() {
+            self.function(address, 0)?;
+        }
+        Ok(())
+    }
+
+    ///
+    /// Walks the function from `start` to its `return` or `exit` instruction.
+    ///
+    /// The evaluation stack is forked by `if`, so `size` is the number of values
+    /// available in the current branch only.
+    ///
+    fn function(&mut self, start: usize, mut size: usize) -> Result<(), ValidationError> {
+        let mut blocks = Vec::new();
+
+        for (address, instruction) in self.program.bytecode.iter().enumerate().skip(start) {
+            let (inputs, outputs) = match instruction {
+                Instruction::If(_) => {
+                    pop(address, &mut size, 1)?;
+                    blocks.push(Block::Branch {
+                        outer: size,
+                        then_size: None,
+                    });
+                    size = 0;
+                    continue;
+                }
+                Instruction::Else(_) => {
+                    match blocks.last_mut() {
+                        Some(Block::Branch { then_size, .. }) if then_size.is_none() => {
+                            *then_size = Some(size)
+                        }
+                        _ => return Err(ValidationError::UnexpectedElse { address }),
+                    }
+                    size = 0;
+                    continue;
+                }
+                Instruction::EndIf(_) => {
+                    size = match blocks.pop() {
+                        Some(Block::Branch {
+                            outer,
+                            then_size: Some(then_size),
+                        }) => {
+                            if then_size != size {
+                                return Err(ValidationError::BranchStacksDoNotMatch {
+                                    address,
+                                    then_size,
+                                    else_size: size,
+                                });
+                            }
+                            outer + size
+                        }
+                        Some(Block::Branch {
+                            outer,
+                            then_size: None,
+                        }) => {
+                            if size != 0 {
+                                return Err(ValidationError::UnbalancedBlock {
+                                    address,
+                                    expected: 0,
+                                    actual: size,
+                                });
+                            }
+                            outer
+                        }
+                        _ => return Err(ValidationError::UnexpectedEndIf { address }),
+                    };
+                    continue;
+                }
+                Instruction::LoopBegin(instruction) => {
+                    if instruction.iterations == 0 {
+                        return Err(ValidationError::EmptyLoop { address });
+                    }
+                    blocks.push(Block::Loop { size });
+                    continue;
+                }
+                Instruction::LoopEnd(_) => {
+                    match blocks.pop() {
+                        Some(Block::Loop { size: expected }) if expected == size => {}
+                        Some(Block::Loop { size: expected }) => {
+                            return Err(ValidationError::UnbalancedBlock {
+                                address,
+                                expected,
+                                actual: size,
+                            })
+                        }
+                        _ => return Err(ValidationError::UnexpectedLoopEnd { address }),
+                    }
+                    continue;
+                }
+                Instruction::Return(instruction) => {
+                    if !blocks.is_empty() {
+                        return Err(ValidationError::UnclosedBlock { address });
+                    }
+                    pop(address, &mut size, instruction.outputs_count)?;
+                    let expected = self.outputs(start)?;
+                    if instruction.outputs_count != expected {
+                        return Err(ValidationError::ReturnCountMismatch {
+                            address,
+                            expected,
+                            actual: instruction.outputs_count,
+                        });
+                    }
+                    return Ok(());
+                }
+                Instruction::Exit(instruction) => {
+                    if !blocks.is_empty() {
+                        return Err(ValidationError::UnclosedBlock { address });
+                    }
+                    pop(address, &mut size, instruction.outputs_count)?;
+                    let expected = self.program.output.size();
+                    if instruction.outputs_count != expected {
+                        return Err(ValidationError::OutputCountMismatch {
+                            address,
+                            expected,
+                            actual: instruction.outputs_count,
+                        });
+                    }
+                    return Ok(());
+                }
+                Instruction::Call(instruction) => {
+                    if instruction.address >= self.program.bytecode.len() {
+                        return Err(ValidationError::CallOutOfRange {
+                            address,
+                            target: instruction.address,
+                        });
+                    }
+                    if self.visited.insert(instruction.address) {
+                        self.pending.push(instruction.address);
+                    }
+                    (instruction.inputs_count, self.outputs(instruction.address)?)
+                }
+                Instruction::Expose(instruction) if !blocks.is_empty() => {
+                    return Err(ValidationError::ExposeInBlock {
+                        address,
+                        name: instruction.name.clone(),
+                    });
+                }
+                instruction => stack_effect(address, instruction)?,
+            };
+
+            pop(address, &mut size, inputs)?;
+            size += outputs;
+        }
+
+        Err(ValidationError::MissingReturn { address: start })
+    }
+
+    ///
+    /// Returns the return values count of the function starting at `address`.
+    ///
+    /// The count is taken from the first `return` instruction, and the other ones
+    /// are checked against it when the function is walked.
+    ///
+    fn outputs(&mut self, address: usize) -> Result<usize, ValidationError> {
+        if let Some(outputs) = self.outputs.get(&address) {
+            return Ok(*outputs);
+        }
+
+        let outputs = self.program.bytecode[address..]
+            .iter()
+            .find_map(|instruction| match instruction {
+                Instruction::Return(instruction) => Some(instruction.outputs_count),
+                _ => None,
+            })
+            .ok_or(ValidationError::MissingReturn { address })?;
+        self.outputs.insert(address, outputs);
+        Ok(outputs)
+    }
+}
+
+///
+/// Returns the number of values the instruction pops from and pushes onto the
+/// evaluation stack, checking its operands on the way.
+///
+/// The flow control instructions must be handled by the caller, since their effect depends
+/// on the rest of the program.
+///
+pub fn stack_effect(
+    address: usize,
+    instruction: &Instruction,
+) -> Result<(usize, usize), ValidationError> {
+    let effect = match instruction {
+        Instruction::NoOperation(_)
+        | Instruction::FileMarker(_)
+        | Instruction::FunctionMarker(_)
+        | Instruction::LineMarker(_)
+        | Instruction::ColumnMarker(_) => (0, 0),
+
+        Instruction::PushConst(instruction) => {
+            check_constant(address, &instruction.value, instruction.scalar_type)?;
+            (0, 1)
+        }
+        Instruction::Pop(instruction) => (instruction.count, 0),
+        Instruction::Slice(instruction) => {
+            check_bounds(address, instruction.slice_len, instruction.array_len)?;
+            (instruction.array_len + 1, instruction.slice_len)
+        }
+        Instruction::Swap(_) => (2, 2),
+        Instruction::Tee(_) => (1, 2),
+
+        Instruction::Load(_) | Instruction::LoadGlobal(_) => (0, 1),
+        Instruction::LoadSequence(instruction) => (0, instruction.len),
+        Instruction::LoadSequenceGlobal(instruction) => (0, instruction.len),
+        Instruction::LoadByIndex(instruction) => {
+            check_bounds(address, 1, instruction.len)?;
+            (1, 1)
+        }
+        Instruction::LoadByIndexGlobal(instruction) => {
+            check_bounds(address, 1, instruction.len)?;
+            (1, 1)
+        }
+        Instruction::LoadSequenceByIndex(instruction) => {
+            check_bounds(address, instruction.value_len, instruction.array_len)?;
+            (1, instruction.value_len)
+        }
+        Instruction::LoadSequenceByIndexGlobal(instruction) => {
+            check_bounds(address, instruction.value_len, instruction.array_len)?;
+            (1, instruction.value_len)
+        }
+
+        Instruction::Store(_) | Instruction::StoreGlobal(_) => (1, 0),
+        Instruction::StoreSequence(instruction) => (instruction.len, 0),
+        Instruction::StoreSequenceGlobal(instruction) => (instruction.len, 0),
+        Instruction::StoreByIndex(instruction) => {
+            check_bounds(address, 1, instruction.len)?;
+            (2, 0)
+        }
+        Instruction::StoreSequenceByIndex(instruction) => {
+            check_bounds(address, instruction.value_len, instruction.array_len)?;
+            (instruction.value_len + 1, 0)
+        }
+
+        Instruction::Add(_)
+        | Instruction::Sub(_)
+        | Instruction::Mul(_)
+        | Instruction::Div(_)
+        | Instruction::Rem(_)
+        | Instruction::And(_)
+        | Instruction::Or(_)
+        | Instruction::Xor(_)
+        | Instruction::Lt(_)
+        | Instruction::Le(_)
+        | Instruction::Eq(_)
+        | Instruction::Ne(_)
+        | Instruction::Ge(_)
+        | Instruction::Gt(_)
+        | Instruction::BitShiftLeft(_)
+        | Instruction::BitShiftRight(_)
+        | Instruction::BitAnd(_)
+        | Instruction::BitOr(_)
+        | Instruction::BitXor(_) => (2, 1),
+
+        Instruction::Neg(_) | Instruction::Not(_) | Instruction::BitNot(_) => (1, 1),
+        Instruction::Cast(instruction) => {
+            check_type(address, instruction.scalar_type)?;
+            (1, 1)
+        }
+
+        Instruction::CallBuiltin(instruction) => {
+            (instruction.inputs_count, instruction.outputs_count)
+        }
+        Instruction::CallExtension(instruction) => {
+            (instruction.inputs_count, instruction.outputs_count)
+        }
+        Instruction::CallHost(instruction) => (instruction.inputs_count, instruction.output.size()),
+        Instruction::Assert(instruction) => (
+            1 + instruction
+                .arg_types
+                .iter()
+                .map(|arg_type| arg_type.size())
+                .sum::<usize>(),
+            0,
+        ),
+        Instruction::Dbg(instruction) => (
+            instruction
+                .arg_types
+                .iter()
+                .map(|arg_type| arg_type.size())
+                .sum(),
+            0,
+        ),
+        Instruction::Expose(instruction) => (instruction.data_type.size(), 0),
+
+        Instruction::If(_)
+        | Instruction::Else(_)
+        | Instruction::EndIf(_)
+        | Instruction::LoopBegin(_)
+        | Instruction::LoopEnd(_)
+        | Instruction::Call(_)
+        | Instruction::Return(_)
+        | Instruction::Exit(_) => unreachable!("Flow control is checked by the caller"),
+    };
+
+    Ok(effect)
+}
+
+fn pop(address: usize, size: &mut usize, count: usize) -> Result<(), ValidationError> {
+    if *size < count {
+        return Err(ValidationError::StackUnderflow {
+            address,
+            required: count,
+            available: *size,
+        });
+    }
+    *size -= count;
+    Ok(())
+}
+
+fn check_type(address: usize, scalar_type: ScalarType) -> Result<(), ValidationError> {
+    match scalar_type {
+        ScalarType::Integer(integer_type)
+            if integer_type.bitlength == 0 || integer_type.bitlength > INTEGER_BITLENGTH_MAX =>
+        {
+            Err(ValidationError::InvalidBitlength {
+                address,
+                bitlength: integer_type.bitlength,
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
+fn check_constant(
+    address: usize,
+    value: &BigInt,
+    scalar_type: ScalarType,
+) -> Result<(), ValidationError> {
+    check_type(address, scalar_type)?;
+
+    let fits = match scalar_type {
+        ScalarType::Field => !value.is_negative(),
+        ScalarType::Boolean => *value == BigInt::from(0) || *value == BigInt::from(1),
+        ScalarType::Integer(integer_type) => {
+            *value >= integer_type.min() && *value <= integer_type.max()
+        }
+    };
+
+    if !fits {
+        return Err(ValidationError::ConstantOutOfRange {
+            address,
+            value: value.to_owned(),
+            scalar_type,
+        });
+    }
+    Ok(())
+}
+
+fn check_bounds(address: usize, len: usize, array_len: usize) -> Result<(), ValidationError> {
+    if len == 0 || len > array_len {
+        return Err(ValidationError::IndexOutOfBounds {
+            address,
+            len,
+            array_len,
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::data::types::DataType;
+    use crate::scalar::IntegerType;
+    use crate::*;
+
+    fn program(bytecode: Vec<Instruction>) -> Program {
+        Program::new(
+            DataType::Unit,
+            DataType::Scalar(ScalarType::Field),
+            bytecode,
+        )
+    }
+
+    fn push(value: i64) -> Instruction {
+        PushConst::new(value.into(), IntegerType::U8.into()).wrap()
+    }
+
+    #[test]
+    fn test_valid() {
+        let program = program(vec![
+            Call::new(2, 0).wrap(),
+            Exit::new(1).wrap(),
+            push(1),
+            push(2),
+            If.wrap(),
+            push(3),
+            Else.wrap(),
+            push(4),
+            EndIf.wrap(),
+            LoopBegin::new(2).wrap(),
+            push(1),
+            Add.wrap(),
+            LoopEnd.wrap(),
+            Return::new(1).wrap(),
+        ]);
+
+        assert_eq!(validate(&program), Ok(()));
+    }
+
+    #[test]
+    fn test_call_out_of_range() {
+        let program = program(vec![Call::new(42, 0).wrap(), Exit::new(1).wrap()]);
+
+        assert_eq!(
+            validate(&program),
+            Err(ValidationError::CallOutOfRange {
+                address: 0,
+                target: 42
+            })
+        );
+    }
+
+    #[test]
+    fn test_stack_underflow() {
+        let program = program(vec![
+            Call::new(2, 0).wrap(),
+            Exit::new(1).wrap(),
+            push(1),
+            Add.wrap(),
+            Return::new(1).wrap(),
+        ]);
+
+        assert_eq!(
+            validate(&program),
+            Err(ValidationError::StackUnderflow {
+                address: 3,
+                required: 2,
+                available: 1,
+            })
+        );
+    }
+
+    #[test]
+    fn test_branch_stacks_do_not_match() {
+        let program = program(vec![
+            Call::new(2, 0).wrap(),
+            Exit::new(1).wrap(),
+            push(1),
+            If.wrap(),
+            push(2),
+            push(3),
+            Else.wrap(),
+            push(4),
+            EndIf.wrap(),
+            Return::new(1).wrap(),
+        ]);
+
+        assert_eq!(
+            validate(&program),
+            Err(ValidationError::BranchStacksDoNotMatch {
+                address: 8,
+                then_size: 2,
+                else_size: 1,
+            })
+        );
+    }
+
+    #[test]
+    fn test_constant_out_of_range() {
+        let program = program(vec![push(256), Exit::new(1).wrap()]);
+
+        assert_eq!(
+            validate(&program),
+            Err(ValidationError::ConstantOutOfRange {
+                address: 0,
+                value: 256.into(),
+                scalar_type: IntegerType::U8.into(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_index_out_of_bounds() {
+        let program = program(vec![
+            push(0),
+            LoadSequenceByIndex::new(0, 2, 3).wrap(),
+            Exit::new(1).wrap(),
+        ]);
+
+        assert_eq!(
+            validate(&program),
+            Err(ValidationError::IndexOutOfBounds {
+                address: 1,
+                len: 3,
+                array_len: 2,
+            })
+        );
+    }
+
+    #[test]
+    fn test_expose() {
+        let expose = |name: &str| {
+            Expose::new(name.to_owned(), DataType::Scalar(IntegerType::U8.into())).wrap()
+        };
+
+        let valid = program(vec![
+            push(1),
+            expose("a"),
+            push(2),
+            expose("b"),
+            push(3),
+            Exit::new(1).wrap(),
+        ]);
+        assert_eq!(validate(&valid), Ok(()));
+
+        let duplicate = program(vec![
+            push(1),
+            expose("a"),
+            push(2),
+            expose("a"),
+            push(3),
+            Exit::new(1).wrap(),
+        ]);
+        assert_eq!(
+            validate(&duplicate),
+            Err(ValidationError::DuplicateExpose {
+                address: 3,
+                name: "a".to_owned(),
+            })
+        );
+
+        let in_loop = program(vec![
+            LoopBegin::new(2).wrap(),
+            push(1),
+            expose("a"),
+            LoopEnd.wrap(),
+            push(3),
+            Exit::new(1).wrap(),
+        ]);
+        assert_eq!(
+            validate(&in_loop),
+            Err(ValidationError::ExposeInBlock {
+                address: 2,
+                name: "a".to_owned(),
+            })
+        );
+    }
+}
@@ -0,0 +1,1159 @@
+//!
+//! The English message catalog.
+//!
+
+use crate::catalog::Entry;
+
+///
+/// The default messages, which are also used as the fallback for translations.
+///
+/// The `E` codes identify the diagnostics, and the `H` codes identify the hints passed
+/// by the syntax analyzer, which are only used as the help of a diagnostic.
+///
+pub static ENTRIES: &[Entry] = &[
+    Entry::new(
+        "E0001",
+        "unterminated block comment",
+        None,
+    ),
+    Entry::new(
+        "E0002",
+        "unterminated double quote string",
+        None,
+    ),
+    Entry::new(
+        "E0003",
+        "expected one of binary symbols {0} or '_', found `{1}`",
+        None,
+    ),
+    Entry::new(
+        "E0004",
+        "expected one of octal symbols {0} or '_', found `{1}`",
+        None,
+    ),
+    Entry::new(
+        "E0005",
+        "expected one of decimal symbols {0} or '_', found `{1}`",
+        None,
+    ),
+    Entry::new(
+        "E0006",
+        "expected one of hexadecimal symbols {0} or '_', found `{1}`",
+        None,
+    ),
+    Entry::new(
+        "E0007",
+        "invalid character `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0008",
+        "unexpected end of input",
+        None,
+    ),
+    Entry::new(
+        "E0009",
+        "expected one of {0}, found `{1}`",
+        None,
+    ),
+    Entry::new(
+        "E0010",
+        "expected one of {0} or an operator, found `{1}`",
+        None,
+    ),
+    Entry::new(
+        "E0011",
+        "expected identifier, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0012",
+        "expected `mut` or identifier, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0013",
+        "expected field identifier, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0014",
+        "expected type, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0015",
+        "expected `:` with type or `=` with value, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0016",
+        "expected `=` with value, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0017",
+        "expected expression or operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0018",
+        "expected integer literal, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0019",
+        "expected identifier or `_`, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0020",
+        "expected identifier, boolean or integer literal, path, or `_`, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0021",
+        "the assignment operator `=` expected a memory place as the first operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0022",
+        "the assignment operator `=` expected a value as the second operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0023",
+        "the assignment bitwise OR operator `|=` expected a memory place as the first operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0024",
+        "the assignment bitwise OR operator `|=` expected a constant as the second operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0025",
+        "the assignment bitwise XOR operator `^=` expected a memory place as the first operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0026",
+        "the assignment bitwise XOR operator `^=` expected a constant as the second operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0027",
+        "the assignment bitwise AND operator `&=` expected a memory place as the first operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0028",
+        "the assignment bitwise AND operator `&=` expected a constant as the second operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0029",
+        "the assignment bitwise shift left operator `<<=` expected a memory place as the first operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0030",
+        "the assignment bitwise shift left operator `<<=` expected a constant as the second operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0031",
+        "the assignment bitwise shift right operator `>>=` expected a memory place as the first operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0032",
+        "the assignment bitwise shift right operator `>>=` expected a constant as the second operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0033",
+        "the assignment operator `+=` expected a memory place as the first operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0034",
+        "the assignment operator `+=` expected a value as the second operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0035",
+        "the assignment operator `-=` expected a memory place as the first operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0036",
+        "the assignment operator `-=` expected a value as the second operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0037",
+        "the assignment operator `*=` expected a memory place as the first operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0038",
+        "the assignment operator `*=` expected a value as the second operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0039",
+        "the assignment operator `/=` expected a memory place as the first operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0040",
+        "the assignment operator `/=` expected a value as the second operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0041",
+        "the assignment operator `%=` expected a memory place as the first operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0042",
+        "the assignment operator `%=` expected a value as the second operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0043",
+        "the inclusive range operator `..=` expected an integer constant as the first operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0044",
+        "the inclusive range operator `..=` expected an integer constant as the second operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0045",
+        "the range operator `..` expected an integer constant as the first operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0046",
+        "the range operator `..` expected an integer constant as the second operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0047",
+        "the OR operator `||` expected a boolean as the first operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0048",
+        "the OR operator `||` expected a boolean as the second operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0049",
+        "the XOR operator `^^` expected a boolean as the first operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0050",
+        "the XOR operator `^^` expected a boolean as the second operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0051",
+        "the AND operator `&&` expected a boolean as the first operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0052",
+        "the AND operator `&&` expected a boolean as the second operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0053",
+        "the equals operator `==` expected a unit, boolean, integer, array, tuple or structure as the first operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0054",
+        "the equals operator `==` expected a unit, boolean, integer, array, tuple or structure as the second operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0055",
+        "the not equals operator `!=` expected a unit, boolean, integer, array, tuple or structure as the first operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0056",
+        "the not equals operator `!=` expected a unit, boolean, integer, array, tuple or structure as the second operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0057",
+        "the greater equals operator `>=` expected an integer as the first operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0058",
+        "the greater equals operator `>=` expected an integer as the second operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0059",
+        "the lesser equals operator `<=` expected an integer as the first operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0060",
+        "the lesser equals operator `<=` expected an integer as the second operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0061",
+        "the greater operator `>` expected an integer as the first operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0062",
+        "the greater operator `>` expected an integer as the second operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0063",
+        "the lesser operator `<` expected an integer as the first operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0064",
+        "the lesser operator `<` expected an integer as the second operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0065",
+        "the bitwise OR operator `|` expected an integer constant as the first operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0066",
+        "the bitwise OR operator `|` expected an integer constant as the second operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0067",
+        "the bitwise XOR operator `^` expected an integer constant as the first operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0068",
+        "the bitwise XOR operator `^` expected an integer constant as the second operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0069",
+        "the bitwise AND operator `&` expected an integer constant as the first operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0070",
+        "the bitwise AND operator `&` expected an integer constant as the second operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0071",
+        "the bitwise shift left operator `<<` expected an integer constant as the first operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0072",
+        "the bitwise shift left operator `<<` expected an unsigned integer constant as the second operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0073",
+        "the bitwise shift right operator `>>` expected an integer constant as the first operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0074",
+        "the bitwise shift right operator `>>` expected an unsigned integer constant as the second operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0075",
+        "the addition operator `+` expected an integer or array as the first operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0076",
+        "the addition operator `+` expected an integer as the second operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0077",
+        "the subtraction operator `-` expected an integer as the first operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0078",
+        "the subtraction operator `-` expected an integer as the second operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0079",
+        "the multiplication operator `*` expected an integer as the first operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0080",
+        "the multiplication operator `*` expected an integer as the second operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0081",
+        "the division operator `/` expected an integer as the first operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0082",
+        "the division operator `/` expected an integer as the second operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0083",
+        "the remainder operator `%` expected an integer as the first operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0084",
+        "the remainder operator `%` expected an integer as the second operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0085",
+        "the casting operator `as` expected a value as the first operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0086",
+        "the casting operator `as` expected a type as the second operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0087",
+        "cannot cast from `{0}` to `{1}`",
+        Some("only integer values can be casted to greater or equal bitlength"),
+    ),
+    Entry::new(
+        "E0088",
+        "the NOT operator `!` expected a boolean, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0089",
+        "the bitwise NOT operator `~` expected an integer, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0090",
+        "the negation operator `-` expected an integer, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0091",
+        "the index operator `[]` expected an array as the first operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0092",
+        "the index operator `[]` expected an integer or range as the second operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0093",
+        "the field access operator `.` expected a tuple or structure as the first operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0094",
+        "the field access operator `.` expected a tuple or structure field identifier as the second operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0095",
+        "the path resolution operator `::` expected an item identifier as the first operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0096",
+        "the path resolution operator `::` expected an item identifier as the second operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0097",
+        "expected `{0}`, found `{1}`",
+        None,
+    ),
+    Entry::new(
+        "E0098",
+        "left slice bound `{0}` is negative",
+        Some("slice range bounds must be within the array size"),
+    ),
+    Entry::new(
+        "E0099",
+        "right slice bound `{0}` is out of range of the array of size {1}",
+        Some("slice range bounds must be within the array size"),
+    ),
+    Entry::new(
+        "E0100",
+        "left slice bound `{0}` is greater than right slice bound `{1}`",
+        Some("left slice range bound must be lesser or equal to the right one"),
+    ),
+    Entry::new(
+        "E0101",
+        "tuple `{0}` has no field with index `{1}`",
+        None,
+    ),
+    Entry::new(
+        "E0102",
+        "field `{0}` does not exist in structure `{1}`",
+        None,
+    ),
+    Entry::new(
+        "E0103",
+        "cannot assign twice to immutable variable `{0}`",
+        Some("make this variable mutable: `mut {1}`"),
+    ),
+    Entry::new(
+        "E0104",
+        "structure `{0}` expected field `{1}` at position {2}, found `{3}`",
+        None,
+    ),
+    Entry::new(
+        "E0105",
+        "field `{0}` of structure `{1}` expected type `{2}`, found `{3}`",
+        None,
+    ),
+    Entry::new(
+        "E0106",
+        "structure `{0}` expected {1} fields, found {2}",
+        None,
+    ),
+    Entry::new(
+        "E0107",
+        "the equals operator `==` expected two integers of the same type, found `{0}` and `{1}`",
+        None,
+    ),
+    Entry::new(
+        "E0108",
+        "the not equals operator `!=` expected two integers of the same type, found `{0}` and `{1}`",
+        None,
+    ),
+    Entry::new(
+        "E0109",
+        "the greater equals operator `>=` expected two integers of the same type, found `{0}` and `{1}`",
+        None,
+    ),
+    Entry::new(
+        "E0110",
+        "the lesser equals operator `<=` expected two integers of the same type, found `{0}` and `{1}`",
+        None,
+    ),
+    Entry::new(
+        "E0111",
+        "the greater operator `>` expected two integers of the same type, found `{0}` and `{1}`",
+        None,
+    ),
+    Entry::new(
+        "E0112",
+        "the lesser operator `<` expected two integers of the same type, found `{0}` and `{1}`",
+        None,
+    ),
+    Entry::new(
+        "E0113",
+        "the bitwise OR operator `|` expected two integers of the same type, found `{0}` and `{1}`",
+        None,
+    ),
+    Entry::new(
+        "E0114",
+        "the bitwise XOR operator `^` expected two integers of the same type, found `{0}` and `{1}`",
+        None,
+    ),
+    Entry::new(
+        "E0115",
+        "the bitwise AND operator `&` expected two integers of the same type, found `{0}` and `{1}`",
+        None,
+    ),
+    Entry::new(
+        "E0116",
+        "the addition operator `+` expected two integers of the same type, found `{0}` and `{1}`",
+        None,
+    ),
+    Entry::new(
+        "E0117",
+        "the subtraction operator `-` expected two integers of the same type, found `{0}` and `{1}`",
+        None,
+    ),
+    Entry::new(
+        "E0118",
+        "the multiplication operator `*` expected two integers of the same type, found `{0}` and `{1}`",
+        None,
+    ),
+    Entry::new(
+        "E0119",
+        "the division operator `/` expected two integers of the same type, found `{0}` and `{1}`",
+        None,
+    ),
+    Entry::new(
+        "E0120",
+        "the remainder operator `%` expected two integers of the same type, found `{0}` and `{1}`",
+        None,
+    ),
+    Entry::new(
+        "E0121",
+        "the addition operator `+` overflow, as the value `{0}` cannot be represeneted by type `{1}`",
+        None,
+    ),
+    Entry::new(
+        "E0122",
+        "the subtraction operator `-` overflow, as the value `{0}` cannot be represeneted by type `{1}`",
+        None,
+    ),
+    Entry::new(
+        "E0123",
+        "the multiplication operator `*` overflow, as the value `{0}` cannot be represeneted by type `{1}`",
+        None,
+    ),
+    Entry::new(
+        "E0124",
+        "the division operator `/` overflow, as the value `{0}` cannot be represeneted by type `{1}`",
+        None,
+    ),
+    Entry::new(
+        "E0125",
+        "the remainder operator `%` overflow, as the value `{0}` cannot be represeneted by type `{1}`",
+        None,
+    ),
+    Entry::new(
+        "E0126",
+        "the casting operator `as` overflow, as the value `{0}` cannot be represeneted by type `{1}`",
+        None,
+    ),
+    Entry::new(
+        "E0127",
+        "the negation operator `-` overflow, as the value `{0}` cannot be represeneted by type `{1}`",
+        None,
+    ),
+    Entry::new(
+        "E0128",
+        "the division operator `/` is forbidden for the `field` type",
+        Some("for inversion consider using `std::ff::invert`"),
+    ),
+    Entry::new(
+        "E0129",
+        "the remainder operator `%` is forbidden for the `field` type",
+        Some("`field` type values cannot be used to get a remainder"),
+    ),
+    Entry::new(
+        "E0130",
+        "the bitwise operators are forbidden for the `field` type",
+        None,
+    ),
+    Entry::new(
+        "E0131",
+        "the negation operator `-` is forbidden for the `field` type",
+        Some("`field` type values cannot be negative"),
+    ),
+    Entry::new(
+        "E0132",
+        "division by zero",
+        None,
+    ),
+    Entry::new(
+        "E0133",
+        "remainder of division by zero",
+        None,
+    ),
+    Entry::new(
+        "E0134",
+        "integer `{0}` is larger than `{1}` bits",
+        None,
+    ),
+    Entry::new(
+        "E0135",
+        "found a negative value `{0}` of unsigned type `{1}`",
+        None,
+    ),
+    Entry::new(
+        "E0136",
+        "expected structure type, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0137",
+        "item `{0}` already declared here",
+        Some("consider giving the latter item another name"),
+    ),
+    Entry::new(
+        "E0138",
+        "cannot find item `{0}` in this scope",
+        None,
+    ),
+    Entry::new(
+        "E0139",
+        "item `{0}` is not a namespace",
+        Some("only modules, structures, and enumerations can contain items within their namespaces"),
+    ),
+    Entry::new(
+        "E0140",
+        "function `{0}` expected {1} arguments, found {2}",
+        None,
+    ),
+    Entry::new(
+        "E0141",
+        "function `{0}` expected type `{1}` as the argument `{2}` (#{3}), found `{4}`",
+        None,
+    ),
+    Entry::new(
+        "E0142",
+        "function `{0}` expected a constant as the argument `{1}` (#{2}), found a non-constant of type `{3}`",
+        None,
+    ),
+    Entry::new(
+        "E0143",
+        "function `{0}` expected a value as the argument #{1}, found `{2}`",
+        None,
+    ),
+    Entry::new(
+        "E0144",
+        "function `{0}` must return a value of type `{1}`, found `{2}`",
+        None,
+    ),
+    Entry::new(
+        "E0145",
+        "attempt to call a non-callable item `{0}`",
+        Some("only functions may be called"),
+    ),
+    Entry::new(
+        "E0146",
+        "method `{0}` expected the `{1}` binding to be at the first position, but found at the position #`{2}`",
+        Some("consider moving the `{3}` binding to the first place"),
+    ),
+    Entry::new(
+        "E0147",
+        "attempt to call a non-builtin function `{0}` with `!` specifier",
+        Some("only built-in functions require the `!` symbol after the function name"),
+    ),
+    Entry::new(
+        "E0148",
+        "attempt to call a builtin function `{0}` without `!` specifier",
+        Some("built-in functions require the `!` symbol after the function name"),
+    ),
+    Entry::new(
+        "E0149",
+        "the `dbg!` function expected {0} arguments, but got {1}",
+        Some("the number of `dbg!` arguments after the format string must be equal to the number of placeholders, e.g. `dbg!(\"{2}, {3}\", a, b)`"),
+    ),
+    Entry::new(
+        "E0150",
+        "the `assert!` function expected {0} arguments, but got {1}",
+        Some("the number of `assert!` arguments after the message must be equal to the number of placeholders, optionally followed by the error code, e.g. `assert!(a < b, \"{2} < {3}\", a, b, 42)`"),
+    ),
+    Entry::new(
+        "E0151",
+        "attempt to truncate an array from size `{0}` to bigger size `{1}`",
+        Some("consider truncating the array to a smaller size"),
+    ),
+    Entry::new(
+        "E0152",
+        "attempt to pad an array from size `{0}` to lesser size `{1}`",
+        Some("consider padding the array to a bigger size"),
+    ),
+    Entry::new(
+        "E0153",
+        "new array length `{0}` cannot act as an index",
+        Some("array indexes cannot be greater than maximum of `u64`"),
+    ),
+    Entry::new(
+        "E0154",
+        "structure `{0}` has a duplicate field `{1}`",
+        Some("consider giving the field a unique name"),
+    ),
+    Entry::new(
+        "E0155",
+        "match scrutinee expected a boolean or integer expression, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0156",
+        "match expression must be exhaustive",
+        Some("ensure that all possible cases are being handled, possibly by adding wildcards or more match arms"),
+    ),
+    Entry::new(
+        "E0157",
+        "match expression must have at least two branches",
+        Some("consider adding some branches to make the expression useful"),
+    ),
+    Entry::new(
+        "E0158",
+        "match expression branch is unreachable",
+        Some("consider removing the branch or moving it above the branch with a wildcard or irrefutable binding"),
+    ),
+    Entry::new(
+        "E0159",
+        "expected path to a constant, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0160",
+        "expected `{0}`, found `{1}`",
+        Some("all branch patterns must be compatible with the type of the expression being matched"),
+    ),
+    Entry::new(
+        "E0161",
+        "expected `{0}`, found `{1}`",
+        Some("all branches must return the type returned by the first branch"),
+    ),
+    Entry::new(
+        "E0162",
+        "match expression contains a duplicate branch pattern",
+        Some("each pattern may occur only once"),
+    ),
+    Entry::new(
+        "E0163",
+        "expected `bool`, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0164",
+        "expected a constant range expression, found `{0}`",
+        Some("only constant ranges allowed, e.g. `for i in 0..42 { ... }`"),
+    ),
+    Entry::new(
+        "E0165",
+        "if and else branches return incompatible types `{0}` and `{1}`",
+        None,
+    ),
+    Entry::new(
+        "E0166",
+        "function `main` is missing",
+        Some("create the `main` function in the entry point file `main.zn`"),
+    ),
+    Entry::new(
+        "E0167",
+        "file not found for module `{0}`",
+        Some("create a file called `{1}.zn` inside the `src` directory"),
+    ),
+    Entry::new(
+        "E0168",
+        "`use` expected an item path, but got `{0}`",
+        Some("consider specifying a valid path to an item to import"),
+    ),
+    Entry::new(
+        "E0169",
+        "`impl` expected a type with namespace, found `{0}`",
+        Some("only structures and enumerations can have an implementation"),
+    ),
+    Entry::new(
+        "E0170",
+        "attempt to use a non-constant value `{0}` in a constant expression",
+        None,
+    ),
+    Entry::new(
+        "E0171",
+        "unknown attribute `{0}`",
+        Some("only `#[inline]`, `#[inline(always)]`, `#[inline(never)]`, and `#[test]` are supported"),
+    ),
+    Entry::new(
+        "E0172",
+        "test function `{0}` has an invalid signature",
+        Some("test functions must have no arguments and return `()`, e.g. `#[test] fn it_works() {}`"),
+    ),
+    Entry::new(
+        "E0173",
+        "expression or type is nested deeper than {0} levels",
+        Some("consider splitting the expression with intermediate `let` bindings"),
+    ),
+    Entry::new(
+        "E0174",
+        "function `{0}` cannot have `pub` arguments",
+        Some("only the `main` function arguments can be the public inputs of the circuit"),
+    ),
+    Entry::new(
+        "E0175",
+        "the `expose!` function cannot be called in function `{0}`",
+        Some("only the `main` function can expose the public outputs of the circuit"),
+    ),
+    Entry::new(
+        "E0176",
+        "the value `{0}` is exposed in a conditional or loop block",
+        Some("the public outputs must be the same for every witness, consider exposing the value after the block"),
+    ),
+    Entry::new(
+        "E0177",
+        "the value `{0}` is exposed more than once",
+        Some("each public output must have a unique name"),
+    ),
+    Entry::new(
+        "E0178",
+        "unsupported ABI `{0}`",
+        Some("only the host functions are supported, e.g. `extern \"host\" fn lookup(x: u64) -> u64;`"),
+    ),
+    Entry::new(
+        "E0179",
+        "the host function `{0}` cannot pass a value of type `{1}`",
+        Some("only scalars, and arrays, tuples, and structures of them can be passed to and from the host"),
+    ),
+    Entry::new(
+        "E0180",
+        "the value `{1}` is not a variant of the enumeration `{0}`",
+        Some("the value must be equal to one of the enumeration variant values"),
+    ),
+    Entry::new(
+        "E0181",
+        "the equals operator `==` expected two operands of the same type, found `{0}` and `{1}`",
+        Some("arrays, tuples, and structures are compared element by element, so their types must be equal"),
+    ),
+    Entry::new(
+        "E0182",
+        "the not equals operator `!=` expected two operands of the same type, found `{0}` and `{1}`",
+        Some("arrays, tuples, and structures are compared element by element, so their types must be equal"),
+    ),
+    Entry::new(
+        "E0183",
+        "the addition operator `+` expected an array as the second operand, found `{0}`",
+        Some("an array can only be concatenated with another array of the same element type"),
+    ),
+    Entry::new(
+        "E0184",
+        "the const parameter `{1}` of the function `{0}` cannot be inferred from the arguments",
+        Some("every const parameter must be used as an array size in the argument types, e.g. `values: [u8; N]`"),
+    ),
+    Entry::new(
+        "E0185",
+        "the const parameter `{1}` of the function `{0}` expected an integer type, found `{2}`",
+        None,
+    ),
+    Entry::new(
+        "E0186",
+        "the function `{0}` instantiates itself with the same const parameter values",
+        Some("a generic function cannot call itself with the arguments of the same types"),
+    ),
+    Entry::new(
+        "E0187",
+        "index `{0}` is out of range of the array of size {1}",
+        Some("array indexes must be within the array size"),
+    ),
+    Entry::new(
+        "E0188",
+        "the `if let` pattern always matches",
+        Some("bind the value with a `let` statement instead, e.g. `let value = expression;`"),
+    ),
+    Entry::new(
+        "E0189",
+        "`{0}` fractional bits do not fit into the {1}-bit integer",
+        Some("the number of fractional bits cannot exceed the integer bitlength"),
+    ),
+    Entry::new(
+        "E0190",
+        "the exponentiation operator `**` expected an integer as the first operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0191",
+        "the exponentiation operator `**` expected an unsigned integer constant as the second operand, found `{0}`",
+        None,
+    ),
+    Entry::new(
+        "E0192",
+        "the exponentiation operator `**` overflow, as the value `{0}` cannot be represented by type `{1}`",
+        None,
+    ),
+    Entry::new(
+        "E0193",
+        "index `{0}` is out of range of the table of size {1}",
+        Some("table indexes must be within the table size"),
+    ),
+    Entry::new(
+        "E0194",
+        "the range `{0}..={1}` is empty",
+        Some("the lower bound must not exceed the upper bound"),
+    ),
+    Entry::new(
+        "E0195",
+        "`{0}` is out of range `{1}`",
+        None,
+    ),
+    Entry::new(
+        "E0196",
+        "the decimal type `Decimal<{0}, {1}>` has an invalid bitlength",
+        Some("the integer bits must be positive, and the sum of the integer and fractional bits must be a multiple of 8 up to 120"),
+    ),
+    Entry::new(
+        "E0197",
+        "the remainder operator `%` is forbidden for the decimal types",
+        None,
+    ),
+    Entry::new(
+        "E0198",
+        "the bitwise operators are forbidden for the decimal types",
+        None,
+    ),
+    Entry::new(
+        "E0199",
+        "the negation operator `-` is forbidden for the decimal types",
+        Some("decimal type values cannot be negative"),
+    ),
+    Entry::new(
+        "E0200",
+        "the exponentiation operator `**` is forbidden for the decimal types",
+        Some("the decimal values can only be multiplied with the `*` operator"),
+    ),
+    Entry::new(
+        "H0001",
+        "attribute must have an identifier, e.g. `#[inline]` or `#[inline(never)]`",
+        None,
+    ),
+    Entry::new(
+        "H0002",
+        "structure field must have an identifier, e.g. `Data { a: 42 }`",
+        None,
+    ),
+    Entry::new(
+        "H0003",
+        "structure field must be initialized, e.g. `Data { a: 42 }`",
+        None,
+    ),
+    Entry::new(
+        "H0004",
+        "structure field must have an identifier, e.g. `a: u8`",
+        None,
+    ),
+    Entry::new(
+        "H0005",
+        "structure field must have a type, e.g. `a: u8`",
+        None,
+    ),
+    Entry::new(
+        "H0006",
+        "function argument must have a type, e.g. `fn sum(a: u8, b: u8) {}`",
+        None,
+    ),
+    Entry::new(
+        "H0007",
+        "constant must have an identifier, e.g. `const DATA: u8 = 42;`",
+        None,
+    ),
+    Entry::new(
+        "H0008",
+        "constant must have a type, e.g. `const DATA: u8 = 42;`",
+        None,
+    ),
+    Entry::new(
+        "H0009",
+        "constant must be initialized, e.g. `const DATA: u8 = 42;`",
+        None,
+    ),
+    Entry::new(
+        "H0010",
+        "enumeration type must have an identifier, e.g. `enum List { ... }`",
+        None,
+    ),
+    Entry::new(
+        "H0011",
+        "function must have an identifier, e.g. `fn sum(...) { ... }`",
+        None,
+    ),
+    Entry::new(
+        "H0012",
+        "function must have the argument list, e.g. `fn sum(a: u8, b: u8) { ... }`",
+        None,
+    ),
+    Entry::new(
+        "H0013",
+        "for-loops must have the index identifier, e.g. `for i in 0..10 { ... }`",
+        None,
+    ),
+    Entry::new(
+        "H0014",
+        "type implementation must have an identifier, e.g. `impl Data { ... }`",
+        None,
+    ),
+    Entry::new(
+        "H0015",
+        "variable must have an identifier, e.g. `let value: u8 = 42;`",
+        None,
+    ),
+    Entry::new(
+        "H0016",
+        "variable must be initialized, e.g. `let value: u8 = 42;`",
+        None,
+    ),
+    Entry::new(
+        "H0017",
+        "only constants and functions may be declared within a type implementation",
+        None,
+    ),
+    Entry::new(
+        "H0018",
+        "only constants, types, functions, and type implementations may be declared at the module root",
+        None,
+    ),
+    Entry::new(
+        "H0019",
+        "structure type must have an identifier, e.g. `struct Data { ... }`",
+        None,
+    ),
+    Entry::new(
+        "H0020",
+        "type alias must have an identifier, e.g. `type Complex = (u8, field);`",
+        None,
+    ),
+    Entry::new(
+        "H0021",
+        "type alias must be initialized, e.g. `type Complex = (u8, field);`",
+        None,
+    ),
+    Entry::new(
+        "H0022",
+        "enumeration variant must have an identifier, e.g. `Value = 42`",
+        None,
+    ),
+    Entry::new(
+        "H0023",
+        "enumeration variant must be initialized, e.g. `Value = 42`",
+        None,
+    ),
+    Entry::new(
+        "H0024",
+        "conditional compilation attribute must have a predicate, e.g. `#[cfg(test)]`",
+        None,
+    ),
+    Entry::new(
+        "H0025",
+        "only conditional compilation attributes are allowed here, e.g. `#[cfg(debug)]`",
+        None,
+    ),
+    Entry::new(
+        "H0026",
+        "conditionally compiled expression must be terminated with a semicolon",
+        None,
+    ),
+    Entry::new(
+        "H0027",
+        "external function must specify its ABI, e.g. `extern \"host\" fn lookup(x: u64) -> u64;`",
+        None,
+    ),
+    Entry::new(
+        "H0028",
+        "array comprehensions must have the index identifier, e.g. `[i * 2 for i in 0..10]`",
+        None,
+    ),
+    Entry::new(
+        "H0029",
+        "const parameters must be declared with the `const` keyword, e.g. `fn sum<const N: u64>(values: [u8; N])`",
+        None,
+    ),
+];
@@ -0,0 +1,38 @@
+//!
+//! The message catalog error.
+//!
+
+use std::fmt;
+
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    Parsing(String),
+    ExpectedObject,
+    ExpectedEntryObject(String),
+    ExpectedString { code: String, field: String },
+    UnknownCode(String),
+    UnknownField { code: String, field: String },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Parsing(inner) => write!(f, "parsing: {}", inner),
+            Self::ExpectedObject => write!(f, "the catalog must be an object"),
+            Self::ExpectedEntryObject(code) => {
+                write!(f, "the entry `{}` must be an object", code)
+            }
+            Self::ExpectedString { code, field } => {
+                write!(
+                    f,
+                    "the field `{}` of entry `{}` must be a string",
+                    field, code
+                )
+            }
+            Self::UnknownCode(code) => write!(f, "unknown message code `{}`", code),
+            Self::UnknownField { code, field } => {
+                write!(f, "unknown field `{}` of entry `{}`", field, code)
+            }
+        }
+    }
+}
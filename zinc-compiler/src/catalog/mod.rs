@@ -0,0 +1,200 @@
+//!
+//! The diagnostic message catalog.
+//!
+
+mod tests;
+
+pub mod english;
+pub mod error;
+
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use lazy_static::lazy_static;
+use serde_json::Value as JsonValue;
+
+use self::error::Error;
+
+lazy_static! {
+    pub static ref CATALOG: RwLock<Catalog> = RwLock::new(Catalog::default());
+}
+
+///
+/// The built-in catalog entry.
+///
+pub struct Entry {
+    pub code: &'static str,
+    pub message: &'static str,
+    pub help: Option<&'static str>,
+}
+
+impl Entry {
+    pub const fn new(
+        code: &'static str,
+        message: &'static str,
+        help: Option<&'static str>,
+    ) -> Self {
+        Self {
+            code,
+            message,
+            help,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Template {
+    message: String,
+    help: Option<String>,
+}
+
+///
+/// The message catalog, which maps the diagnostic codes to the message templates.
+///
+/// The templates refer to the diagnostic arguments by their positions, e.g. `{0}`, so
+/// a translation may reorder them if the grammar of its language requires so.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Catalog {
+    templates: HashMap<String, Template>,
+}
+
+impl Default for Catalog {
+    fn default() -> Self {
+        let templates = english::ENTRIES
+            .iter()
+            .map(|entry| {
+                (
+                    entry.code.to_owned(),
+                    Template {
+                        message: entry.message.to_owned(),
+                        help: entry.help.map(ToOwned::to_owned),
+                    },
+                )
+            })
+            .collect();
+
+        Self { templates }
+    }
+}
+
+impl Catalog {
+    pub const FIELD_MESSAGE: &'static str = "message";
+    pub const FIELD_HELP: &'static str = "help";
+
+    ///
+    /// Loads a translation from the JSON object of the form
+    /// `{ "E0001": { "message": "...", "help": "..." }, ... }`.
+    ///
+    /// The messages missing from the translation fall back to English.
+    ///
+    pub fn from_json(input: &str) -> Result<Self, Error> {
+        let value: JsonValue =
+            serde_json::from_str(input).map_err(|error| Error::Parsing(error.to_string()))?;
+        let entries = match value {
+            JsonValue::Object(entries) => entries,
+            _ => return Err(Error::ExpectedObject),
+        };
+
+        let mut catalog = Self::default();
+        for (code, entry) in entries.into_iter() {
+            let template = match catalog.templates.get_mut(&code) {
+                Some(template) => template,
+                None => return Err(Error::UnknownCode(code)),
+            };
+
+            let fields = match entry {
+                JsonValue::Object(fields) => fields,
+                _ => return Err(Error::ExpectedEntryObject(code)),
+            };
+
+            for (field, value) in fields.into_iter() {
+                let value = match value {
+                    JsonValue::String(value) => value,
+                    _ => return Err(Error::ExpectedString { code, field }),
+                };
+
+                match field.as_str() {
+                    Self::FIELD_MESSAGE => template.message = value,
+                    Self::FIELD_HELP => template.help = Some(value),
+                    _ => return Err(Error::UnknownField { code, field }),
+                }
+            }
+        }
+
+        Ok(catalog)
+    }
+
+    ///
+    /// Replaces the catalog used by the compiler diagnostics.
+    ///
+    pub fn install(self) {
+        *CATALOG.write().expect(crate::PANIC_MUTEX_SYNC) = self;
+    }
+
+    ///
+    /// Returns the message template with the `code`, e.g. for the external tooling.
+    ///
+    pub fn get(&self, code: &str) -> Option<&str> {
+        self.templates
+            .get(code)
+            .map(|template| template.message.as_str())
+    }
+
+    ///
+    /// Renders the message with the `code`, or the code itself if it is not in the catalog.
+    ///
+    pub fn message(&self, code: &str, arguments: &[String]) -> String {
+        match self.templates.get(code) {
+            Some(template) => Self::interpolate(template.message.as_str(), arguments),
+            None => code.to_owned(),
+        }
+    }
+
+    ///
+    /// Renders the help of the message with the `code`, if there is any.
+    ///
+    pub fn help(&self, code: &str, arguments: &[String]) -> Option<String> {
+        self.templates
+            .get(code)
+            .and_then(|template| template.help.as_ref())
+            .map(|help| Self::interpolate(help.as_str(), arguments))
+    }
+
+    ///
+    /// Replaces the `{N}` placeholders with the arguments.
+    ///
+    /// The placeholders whose position is out of the argument list are left as is.
+    ///
+    fn interpolate(template: &str, arguments: &[String]) -> String {
+        let mut result = String::with_capacity(template.len());
+
+        let mut rest = template;
+        while let Some(start) = rest.find('{') {
+            result.push_str(&rest[..start]);
+            rest = &rest[start..];
+
+            let argument = rest.find('}').and_then(|end| {
+                rest[1..end]
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|index| arguments.get(index))
+                    .map(|argument| (argument, end))
+            });
+
+            match argument {
+                Some((argument, end)) => {
+                    result.push_str(argument.as_str());
+                    rest = &rest[end + 1..];
+                }
+                None => {
+                    result.push('{');
+                    rest = &rest[1..];
+                }
+            }
+        }
+        result.push_str(rest);
+
+        result
+    }
+}
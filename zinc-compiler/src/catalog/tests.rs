@@ -0,0 +1,93 @@
+//!
+//! The message catalog tests.
+//!
+
+#![cfg(test)]
+
+use std::collections::HashSet;
+
+use crate::catalog::english;
+use crate::catalog::error::Error;
+use crate::catalog::Catalog;
+
+#[test]
+fn ok_english_codes_unique() {
+    let mut codes = HashSet::new();
+    for entry in english::ENTRIES.iter() {
+        assert!(codes.insert(entry.code), "duplicate code {}", entry.code);
+    }
+}
+
+#[test]
+fn ok_interpolation() {
+    let catalog = Catalog::default();
+
+    assert_eq!(
+        catalog.message("E0003", &["0 or 1".to_owned(), "2".to_owned()]),
+        "expected one of binary symbols 0 or 1 or '_', found `2`"
+    );
+}
+
+#[test]
+fn ok_interpolation_out_of_range() {
+    let catalog = Catalog::default();
+
+    assert_eq!(
+        catalog.message("E0003", &["0 or 1".to_owned()]),
+        "expected one of binary symbols 0 or 1 or '_', found `{1}`"
+    );
+}
+
+#[test]
+fn ok_translation() {
+    let input = r#"{
+    "E0003": { "message": "attendu {0} ou '_', trouvé `{1}`" },
+    "E0166": { "help": "créez la fonction `main`" }
+}"#;
+
+    let catalog = Catalog::from_json(input).expect("Valid catalog");
+
+    assert_eq!(
+        catalog.message("E0003", &["0 ou 1".to_owned(), "2".to_owned()]),
+        "attendu 0 ou 1 ou '_', trouvé `2`"
+    );
+    assert_eq!(
+        catalog.message("E0166", &[]),
+        Catalog::default().message("E0166", &[])
+    );
+    assert_eq!(
+        catalog.help("E0166", &[]),
+        Some("créez la fonction `main`".to_owned())
+    );
+}
+
+#[test]
+fn ok_unknown_code_rendered_as_is() {
+    let catalog = Catalog::default();
+
+    assert_eq!(catalog.get("E9999"), None);
+    assert_eq!(catalog.message("E9999", &[]), "E9999");
+}
+
+#[test]
+fn error_unknown_code() {
+    let input = r#"{ "E9999": { "message": "" } }"#;
+
+    assert_eq!(
+        Catalog::from_json(input),
+        Err(Error::UnknownCode("E9999".to_owned()))
+    );
+}
+
+#[test]
+fn error_unknown_field() {
+    let input = r#"{ "E0001": { "text": "" } }"#;
+
+    assert_eq!(
+        Catalog::from_json(input),
+        Err(Error::UnknownField {
+            code: "E0001".to_owned(),
+            field: "text".to_owned(),
+        })
+    );
+}
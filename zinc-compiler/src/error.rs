@@ -4,6 +4,7 @@
 
 use colored::Colorize;
 
+use crate::catalog::CATALOG;
 use crate::file::error::Error as FileError;
 use crate::lexical::error::Error as LexicalError;
 use crate::lexical::token::lexeme::keyword::Keyword;
@@ -41,42 +42,30 @@ impl Error {
             Self::File(inner) => inner.to_string(),
 
             Self::Lexical(LexicalError::UnterminatedBlockComment { start, end }) => {
-                Self::format_range(context, "unterminated block comment", start, end, None)
+                Self::format_range(context, "E0001", &[], start, end, None)
             }
             Self::Lexical(LexicalError::UnterminatedDoubleQuoteString { start, end }) => {
-                Self::format_range(
-                    context,
-                    "unterminated double quote string",
-                    start,
-                    end,
-                    None,
-                )
+                Self::format_range(context, "E0002", &[], start, end, None)
             }
             Self::Lexical(LexicalError::ExpectedOneOfBinary {
-                              location,
-                              expected,
-                              found,
-                          }) => Self::format_line(
+                location,
+                expected,
+                found,
+            }) => Self::format_line(
                 context,
-                format!(
-                    "expected one of binary symbols {} or '_', found `{}`",
-                    expected, found
-                )
-                    .as_str(),
+                "E0003",
+                &[expected.to_string(), found.to_string()],
                 location,
                 None,
             ),
             Self::Lexical(LexicalError::ExpectedOneOfOctal {
-                              location,
-                              expected,
-                              found,
-                          }) => Self::format_line(
+                location,
+                expected,
+                found,
+            }) => Self::format_line(
                 context,
-                format!(
-                    "expected one of octal symbols {} or '_', found `{}`",
-                    expected, found
-                )
-                    .as_str(),
+                "E0004",
+                &[expected.to_string(), found.to_string()],
                 location,
                 None,
             ),
@@ -86,11 +75,8 @@ impl Error {
                 found,
             }) => Self::format_line(
                 context,
-                format!(
-                    "expected one of decimal symbols {} or '_', found `{}`",
-                    expected, found
-                )
-                .as_str(),
+                "E0005",
+                &[expected.to_string(), found.to_string()],
                 location,
                 None,
             ),
@@ -100,22 +86,16 @@ impl Error {
                 found,
             }) => Self::format_line(
                 context,
-                format!(
-                    "expected one of hexadecimal symbols {} or '_', found `{}`",
-                    expected, found
-                )
-                .as_str(),
-                location,
-                None,
-            ),
-            Self::Lexical(LexicalError::InvalidCharacter { location, found }) => Self::format_line(
-                context,
-                format!("invalid character `{}`", found).as_str(),
+                "E0006",
+                &[expected.to_string(), found.to_string()],
                 location,
                 None,
             ),
+            Self::Lexical(LexicalError::InvalidCharacter { location, found }) => {
+                Self::format_line(context, "E0007", &[found.to_string()], location, None)
+            }
             Self::Lexical(LexicalError::UnexpectedEnd { location }) => {
-                Self::format_line(context, "unexpected end of input", location, None)
+                Self::format_line(context, "E0008", &[], location, None)
             }
 
             Self::Syntax(SyntaxError::ExpectedOneOf {
@@ -125,7 +105,8 @@ impl Error {
                 help,
             }) => Self::format_line(
                 context,
-                format!("expected one of {}, found `{}`", expected, found).as_str(),
+                "E0009",
+                &[expected.to_string(), found.to_string()],
                 location,
                 help,
             ),
@@ -136,11 +117,8 @@ impl Error {
                 help,
             }) => Self::format_line(
                 context,
-                format!(
-                    "expected one of {} or an operator, found `{}`",
-                    expected, found
-                )
-                .as_str(),
+                "E0010",
+                &[expected.to_string(), found.to_string()],
                 location,
                 help,
             ),
@@ -148,1988 +126,2215 @@ impl Error {
                 location,
                 found,
                 help,
-            }) => Self::format_line(
-                context,
-                format!("expected identifier, found `{}`", found).as_str(),
-                location,
-                help,
-            ),
+            }) => Self::format_line(context, "E0011", &[found.to_string()], location, help),
             Self::Syntax(SyntaxError::ExpectedMutOrIdentifier {
                 location,
                 found,
                 help,
-            }) => Self::format_line(
-                context,
-                format!("expected `mut` or identifier, found `{}`", found).as_str(),
-                location,
-                help,
-            ),
+            }) => Self::format_line(context, "E0012", &[found.to_string()], location, help),
             Self::Syntax(SyntaxError::ExpectedFieldIdentifier {
                 location,
                 found,
                 help,
-            }) => Self::format_line(
-                context,
-                format!("expected field identifier, found `{}`", found).as_str(),
-                location,
-                help,
-            ),
+            }) => Self::format_line(context, "E0013", &[found.to_string()], location, help),
             Self::Syntax(SyntaxError::ExpectedType {
                 location,
                 found,
                 help,
-            }) => Self::format_line(
-                context,
-                format!("expected type, found `{}`", found).as_str(),
-                location,
-                help,
-            ),
+            }) => Self::format_line(context, "E0014", &[found.to_string()], location, help),
             Self::Syntax(SyntaxError::ExpectedTypeOrValue {
                 location,
                 found,
                 help,
-            }) => Self::format_line(
-                context,
-                format!(
-                    "expected `:` with type or `=` with value, found `{}`",
-                    found
-                )
-                .as_str(),
-                location,
-                help,
-            ),
+            }) => Self::format_line(context, "E0015", &[found.to_string()], location, help),
             Self::Syntax(SyntaxError::ExpectedValue {
                 location,
                 found,
                 help,
-            }) => Self::format_line(
-                context,
-                format!("expected `=` with value, found `{}`", found).as_str(),
-                location,
-                help,
-            ),
+            }) => Self::format_line(context, "E0016", &[found.to_string()], location, help),
             Self::Syntax(SyntaxError::ExpectedExpressionOrOperand { location, found }) => {
-                Self::format_line(
-                    context,
-                    format!("expected expression or operand, found `{}`", found).as_str(),
-                    location,
-                    None,
-                )
+                Self::format_line(context, "E0017", &[found.to_string()], location, None)
             }
             Self::Syntax(SyntaxError::ExpectedIntegerLiteral { location, found }) => {
-                Self::format_line(
-                    context,
-                    format!("expected integer literal, found `{}`", found).as_str(),
-                    location,
-                    None,
-                )
+                Self::format_line(context, "E0018", &[found.to_string()], location, None)
             }
             Self::Syntax(SyntaxError::ExpectedBindingPattern { location, found }) => {
-                Self::format_line(
-                    context,
-                    format!("expected identifier or `_`, found `{}`", found).as_str(),
-                    location,
-                    None,
-                )
+                Self::format_line(context, "E0019", &[found.to_string()], location, None)
             }
             Self::Syntax(SyntaxError::ExpectedMatchPattern { location, found }) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "expected identifier, boolean or integer literal, path, or `_`, found `{}`",
-                        found
-                    )
-                    .as_str(),
-                    location,
-                    None,
-                )
-            }
-
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentFirstOperandExpectedPlace{ found })) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the assignment operator `=` expected a memory place as the first operand, found `{}`",
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentSecondOperandExpectedEvaluable{ found })) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the assignment operator `=` expected a value as the second operand, found `{}`",
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentBitwiseOrFirstOperandExpectedPlace{ found })) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the assignment bitwise OR operator `|=` expected a memory place as the first operand, found `{}`",
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentBitwiseOrSecondOperandExpectedEvaluable{ found })) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the assignment bitwise OR operator `|=` expected a constant as the second operand, found `{}`", // TODO: constant -> value
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentBitwiseXorFirstOperandExpectedPlace{ found })) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the assignment bitwise XOR operator `^=` expected a memory place as the first operand, found `{}`",
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentBitwiseXorSecondOperandExpectedEvaluable{ found })) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the assignment bitwise XOR operator `^=` expected a constant as the second operand, found `{}`", // TODO: constant -> value
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentBitwiseAndFirstOperandExpectedPlace{ found })) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the assignment bitwise AND operator `&=` expected a memory place as the first operand, found `{}`",
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentBitwiseAndSecondOperandExpectedEvaluable{ found })) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the assignment bitwise AND operator `&=` expected a constant as the second operand, found `{}`", // TODO: constant -> value
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentBitwiseShiftLeftFirstOperandExpectedPlace{ found })) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the assignment bitwise shift left operator `<<=` expected a memory place as the first operand, found `{}`",
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
+                Self::format_line(context, "E0020", &[found.to_string()], location, None)
             }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentBitwiseShiftLeftSecondOperandExpectedEvaluable{ found })) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the assignment bitwise shift left operator `<<=` expected a constant as the second operand, found `{}`", // TODO: constant -> value
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentBitwiseShiftRightFirstOperandExpectedPlace{ found })) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the assignment bitwise shift right operator `>>=` expected a memory place as the first operand, found `{}`",
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentBitwiseShiftRightSecondOperandExpectedEvaluable{ found })) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the assignment bitwise shift right operator `>>=` expected a constant as the second operand, found `{}`", // TODO: constant -> value
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentAdditionFirstOperandExpectedPlace{ found })) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the assignment operator `+=` expected a memory place as the first operand, found `{}`",
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentAdditionSecondOperandExpectedEvaluable{ found })) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the assignment operator `+=` expected a value as the second operand, found `{}`",
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentSubtractionFirstOperandExpectedPlace{ found })) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the assignment operator `-=` expected a memory place as the first operand, found `{}`",
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentSubtractionSecondOperandExpectedEvaluable{ found })) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the assignment operator `-=` expected a value as the second operand, found `{}`",
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
+            Self::Syntax(SyntaxError::NestingTooDeep { location, limit }) => {
+                Self::format_line(context, "E0173", &[limit.to_string()], location, None)
             }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentMultiplicationFirstOperandExpectedPlace{ found })) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the assignment operator `*=` expected a memory place as the first operand, found `{}`",
+
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorAssignmentFirstOperandExpectedPlace { found },
+            )) => Self::format_line(context, "E0021", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorAssignmentSecondOperandExpectedEvaluable { found },
+            )) => Self::format_line(context, "E0022", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorAssignmentBitwiseOrFirstOperandExpectedPlace { found },
+            )) => Self::format_line(context, "E0023", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorAssignmentBitwiseOrSecondOperandExpectedEvaluable { found },
+            )) => Self::format_line(context, "E0024", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorAssignmentBitwiseXorFirstOperandExpectedPlace { found },
+            )) => Self::format_line(context, "E0025", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorAssignmentBitwiseXorSecondOperandExpectedEvaluable { found },
+            )) => Self::format_line(context, "E0026", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorAssignmentBitwiseAndFirstOperandExpectedPlace { found },
+            )) => Self::format_line(context, "E0027", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorAssignmentBitwiseAndSecondOperandExpectedEvaluable { found },
+            )) => Self::format_line(context, "E0028", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorAssignmentBitwiseShiftLeftFirstOperandExpectedPlace { found },
+            )) => Self::format_line(context, "E0029", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorAssignmentBitwiseShiftLeftSecondOperandExpectedEvaluable {
+                    found,
+                },
+            )) => Self::format_line(context, "E0030", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorAssignmentBitwiseShiftRightFirstOperandExpectedPlace {
+                    found,
+                },
+            )) => Self::format_line(context, "E0031", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorAssignmentBitwiseShiftRightSecondOperandExpectedEvaluable {
+                    found,
+                },
+            )) => Self::format_line(context, "E0032", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorAssignmentAdditionFirstOperandExpectedPlace { found },
+            )) => Self::format_line(context, "E0033", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorAssignmentAdditionSecondOperandExpectedEvaluable { found },
+            )) => Self::format_line(context, "E0034", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorAssignmentSubtractionFirstOperandExpectedPlace { found },
+            )) => Self::format_line(context, "E0035", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorAssignmentSubtractionSecondOperandExpectedEvaluable { found },
+            )) => Self::format_line(context, "E0036", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorAssignmentMultiplicationFirstOperandExpectedPlace { found },
+            )) => Self::format_line(context, "E0037", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorAssignmentMultiplicationSecondOperandExpectedEvaluable {
+                    found,
+                },
+            )) => Self::format_line(context, "E0038", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorAssignmentDivisionFirstOperandExpectedPlace { found },
+            )) => Self::format_line(context, "E0039", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorAssignmentDivisionSecondOperandExpectedEvaluable { found },
+            )) => Self::format_line(context, "E0040", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorAssignmentRemainderFirstOperandExpectedPlace { found },
+            )) => Self::format_line(context, "E0041", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorAssignmentRemainderSecondOperandExpectedEvaluable { found },
+            )) => Self::format_line(context, "E0042", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorRangeInclusiveFirstOperandExpectedConstant { found },
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(
+                    ConstantError::OperatorRangeInclusiveFirstOperandExpectedInteger { found },
+                ),
+            )) => Self::format_line(context, "E0043", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorRangeInclusiveSecondOperandExpectedConstant { found },
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(
+                    ConstantError::OperatorRangeInclusiveSecondOperandExpectedInteger { found },
+                ),
+            )) => Self::format_line(context, "E0044", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorRangeFirstOperandExpectedConstant { found },
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(ConstantError::OperatorRangeFirstOperandExpectedInteger {
+                    found,
+                }),
+            )) => Self::format_line(context, "E0045", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorRangeSecondOperandExpectedConstant { found },
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(ConstantError::OperatorRangeSecondOperandExpectedInteger {
+                    found,
+                }),
+            )) => Self::format_line(context, "E0046", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorOrFirstOperandExpectedEvaluable { found },
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::OperatorOrFirstOperandExpectedBoolean { found }),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(ConstantError::OperatorOrFirstOperandExpectedBoolean {
+                    found,
+                }),
+            )) => Self::format_line(context, "E0047", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorOrSecondOperandExpectedEvaluable { found },
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::OperatorOrSecondOperandExpectedBoolean { found }),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(ConstantError::OperatorOrSecondOperandExpectedBoolean {
+                    found,
+                }),
+            )) => Self::format_line(context, "E0048", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorXorFirstOperandExpectedEvaluable { found },
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::OperatorXorFirstOperandExpectedBoolean { found }),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(ConstantError::OperatorXorFirstOperandExpectedBoolean {
+                    found,
+                }),
+            )) => Self::format_line(context, "E0049", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorXorSecondOperandExpectedEvaluable { found },
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::OperatorXorSecondOperandExpectedBoolean { found }),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(ConstantError::OperatorXorSecondOperandExpectedBoolean {
+                    found,
+                }),
+            )) => Self::format_line(context, "E0050", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorAndFirstOperandExpectedEvaluable { found },
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::OperatorAndFirstOperandExpectedBoolean { found }),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(ConstantError::OperatorAndFirstOperandExpectedBoolean {
+                    found,
+                }),
+            )) => Self::format_line(context, "E0051", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorAndSecondOperandExpectedEvaluable { found },
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::OperatorAndSecondOperandExpectedBoolean { found }),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(ConstantError::OperatorAndSecondOperandExpectedBoolean {
+                    found,
+                }),
+            )) => Self::format_line(context, "E0052", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorEqualsFirstOperandExpectedEvaluable { found },
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(
+                    ConstantError::OperatorEqualsFirstOperandExpectedPrimitiveType { found },
+                ),
+            )) => Self::format_line(context, "E0053", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorEqualsSecondOperandExpectedEvaluable { found },
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::OperatorEqualsSecondOperandExpectedUnit { found }),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::OperatorEqualsSecondOperandExpectedBoolean {
+                    found,
+                }),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::OperatorEqualsSecondOperandExpectedInteger {
+                    found,
+                }),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(ConstantError::OperatorEqualsSecondOperandExpectedUnit {
+                    found,
+                }),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(ConstantError::OperatorEqualsSecondOperandExpectedBoolean {
+                    found,
+                }),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(ConstantError::OperatorEqualsSecondOperandExpectedInteger {
+                    found,
+                }),
+            )) => Self::format_line(context, "E0054", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorNotEqualsFirstOperandExpectedEvaluable { found },
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(
+                    ConstantError::OperatorNotEqualsFirstOperandExpectedPrimitiveType { found },
+                ),
+            )) => Self::format_line(context, "E0055", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorNotEqualsSecondOperandExpectedEvaluable { found },
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::OperatorNotEqualsSecondOperandExpectedUnit {
+                    found,
+                }),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::OperatorNotEqualsSecondOperandExpectedBoolean {
+                    found,
+                }),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::OperatorNotEqualsSecondOperandExpectedInteger {
+                    found,
+                }),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(ConstantError::OperatorNotEqualsSecondOperandExpectedUnit {
+                    found,
+                }),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(
+                    ConstantError::OperatorNotEqualsSecondOperandExpectedBoolean { found },
+                ),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(
+                    ConstantError::OperatorNotEqualsSecondOperandExpectedInteger { found },
+                ),
+            )) => Self::format_line(context, "E0056", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::OperatorEqualsTypesMismatch { first, second }),
+            )) => Self::format_line(
+                context,
+                "E0181",
+                &[first.to_string(), second.to_string()],
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::OperatorNotEqualsTypesMismatch { first, second }),
+            )) => Self::format_line(
+                context,
+                "E0182",
+                &[first.to_string(), second.to_string()],
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorGreaterEqualsFirstOperandExpectedEvaluable { found },
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::OperatorGreaterEqualsFirstOperandExpectedInteger {
+                    found,
+                }),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(
+                    ConstantError::OperatorGreaterEqualsFirstOperandExpectedInteger { found },
+                ),
+            )) => Self::format_line(context, "E0057", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorGreaterEqualsSecondOperandExpectedEvaluable { found },
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(
+                    ValueError::OperatorGreaterEqualsSecondOperandExpectedInteger { found },
+                ),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(
+                    ConstantError::OperatorGreaterEqualsSecondOperandExpectedInteger { found },
+                ),
+            )) => Self::format_line(context, "E0058", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorLesserEqualsFirstOperandExpectedEvaluable { found },
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::OperatorLesserEqualsFirstOperandExpectedInteger {
+                    found,
+                }),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(
+                    ConstantError::OperatorLesserEqualsFirstOperandExpectedInteger { found },
+                ),
+            )) => Self::format_line(context, "E0059", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorLesserEqualsSecondOperandExpectedEvaluable { found },
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::OperatorLesserEqualsSecondOperandExpectedInteger {
+                    found,
+                }),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(
+                    ConstantError::OperatorLesserEqualsSecondOperandExpectedInteger { found },
+                ),
+            )) => Self::format_line(context, "E0060", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorGreaterFirstOperandExpectedEvaluable { found },
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::OperatorGreaterFirstOperandExpectedInteger {
+                    found,
+                }),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(ConstantError::OperatorGreaterFirstOperandExpectedInteger {
+                    found,
+                }),
+            )) => Self::format_line(context, "E0061", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorGreaterSecondOperandExpectedEvaluable { found },
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::OperatorGreaterSecondOperandExpectedInteger {
+                    found,
+                }),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(
+                    ConstantError::OperatorGreaterSecondOperandExpectedInteger { found },
+                ),
+            )) => Self::format_line(context, "E0062", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorLesserFirstOperandExpectedEvaluable { found },
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::OperatorLesserFirstOperandExpectedInteger {
+                    found,
+                }),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(ConstantError::OperatorLesserFirstOperandExpectedInteger {
+                    found,
+                }),
+            )) => Self::format_line(context, "E0063", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorLesserSecondOperandExpectedEvaluable { found },
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::OperatorLesserSecondOperandExpectedInteger {
+                    found,
+                }),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(ConstantError::OperatorLesserSecondOperandExpectedInteger {
+                    found,
+                }),
+            )) => Self::format_line(context, "E0064", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorBitwiseOrFirstOperandExpectedEvaluable { found },
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::OperatorBitwiseOrFirstOperandExpectedInteger {
+                    found,
+                }),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(
+                    ConstantError::OperatorBitwiseOrFirstOperandExpectedInteger { found },
+                ),
+            )) => Self::format_line(context, "E0065", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorBitwiseOrSecondOperandExpectedEvaluable { found },
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::OperatorBitwiseOrSecondOperandExpectedInteger {
+                    found,
+                }),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(
+                    ConstantError::OperatorBitwiseOrSecondOperandExpectedInteger { found },
+                ),
+            )) => Self::format_line(context, "E0066", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorBitwiseXorFirstOperandExpectedEvaluable { found },
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::OperatorBitwiseXorFirstOperandExpectedInteger {
+                    found,
+                }),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(
+                    ConstantError::OperatorBitwiseXorFirstOperandExpectedInteger { found },
+                ),
+            )) => Self::format_line(context, "E0067", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorBitwiseXorSecondOperandExpectedEvaluable { found },
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::OperatorBitwiseXorSecondOperandExpectedInteger {
+                    found,
+                }),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(
+                    ConstantError::OperatorBitwiseXorSecondOperandExpectedInteger { found },
+                ),
+            )) => Self::format_line(context, "E0068", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorBitwiseAndFirstOperandExpectedEvaluable { found },
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::OperatorBitwiseAndFirstOperandExpectedInteger {
+                    found,
+                }),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(
+                    ConstantError::OperatorBitwiseAndFirstOperandExpectedInteger { found },
+                ),
+            )) => Self::format_line(context, "E0069", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorBitwiseAndSecondOperandExpectedEvaluable { found },
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::OperatorBitwiseAndSecondOperandExpectedInteger {
+                    found,
+                }),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(
+                    ConstantError::OperatorBitwiseAndSecondOperandExpectedInteger { found },
+                ),
+            )) => Self::format_line(context, "E0070", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorBitwiseShiftLeftFirstOperandExpectedEvaluable { found },
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(
+                    ValueError::OperatorBitwiseShiftLeftFirstOperandExpectedInteger { found },
+                ),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(
+                    ConstantError::OperatorBitwiseShiftLeftFirstOperandExpectedInteger { found },
+                ),
+            )) => Self::format_line(context, "E0071", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorBitwiseShiftLeftSecondOperandExpectedConstant { found },
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(
+                    ValueError::OperatorBitwiseShiftLeftSecondOperandExpectedInteger { found },
+                ),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::Integer(
+                    IntegerValueError::OperatorBitwiseShiftLeftSecondOperatorExpectedUnsigned {
                         found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentMultiplicationSecondOperandExpectedEvaluable{ found })) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the assignment operator `*=` expected a value as the second operand, found `{}`",
+                    },
+                )),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(
+                    ConstantError::OperatorBitwiseShiftLeftSecondOperandExpectedInteger { found },
+                ),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(ConstantError::Integer(
+                    IntegerConstantError::OperatorBitwiseShiftLeftSecondOperatorExpectedUnsigned {
                         found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentDivisionFirstOperandExpectedPlace{ found })) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the assignment operator `/=` expected a memory place as the first operand, found `{}`",
+                    },
+                )),
+            )) => Self::format_line(context, "E0072", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorBitwiseShiftRightFirstOperandExpectedEvaluable { found },
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(
+                    ValueError::OperatorBitwiseShiftRightFirstOperandExpectedInteger { found },
+                ),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(
+                    ConstantError::OperatorBitwiseShiftRightFirstOperandExpectedInteger { found },
+                ),
+            )) => Self::format_line(context, "E0073", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorBitwiseShiftRightSecondOperandExpectedConstant { found },
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(
+                    ValueError::OperatorBitwiseShiftRightSecondOperandExpectedInteger { found },
+                ),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::Integer(
+                    IntegerValueError::OperatorBitwiseShiftRightSecondOperatorExpectedUnsigned {
                         found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentDivisionSecondOperandExpectedEvaluable{ found })) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the assignment operator `/=` expected a value as the second operand, found `{}`",
+                    },
+                )),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(
+                    ConstantError::OperatorBitwiseShiftRightSecondOperandExpectedInteger { found },
+                ),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(ConstantError::Integer(
+                    IntegerConstantError::OperatorBitwiseShiftRightSecondOperatorExpectedUnsigned {
                         found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentRemainderFirstOperandExpectedPlace{ found })) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the assignment operator `%=` expected a memory place as the first operand, found `{}`",
+                    },
+                )),
+            )) => Self::format_line(context, "E0074", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorAdditionFirstOperandExpectedEvaluable { found },
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::OperatorAdditionFirstOperandExpectedInteger {
+                    found,
+                }),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(
+                    ConstantError::OperatorAdditionFirstOperandExpectedInteger { found },
+                ),
+            )) => Self::format_line(context, "E0075", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorAdditionSecondOperandExpectedEvaluable { found },
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::OperatorAdditionSecondOperandExpectedInteger {
+                    found,
+                }),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(
+                    ConstantError::OperatorAdditionSecondOperandExpectedInteger { found },
+                ),
+            )) => Self::format_line(context, "E0076", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::OperatorAdditionSecondOperandExpectedArray {
+                    found,
+                }),
+            )) => Self::format_line(context, "E0183", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorSubtractionFirstOperandExpectedEvaluable { found },
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::OperatorSubtractionFirstOperandExpectedInteger {
+                    found,
+                }),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(
+                    ConstantError::OperatorSubtractionFirstOperandExpectedInteger { found },
+                ),
+            )) => Self::format_line(context, "E0077", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorSubtractionSecondOperandExpectedEvaluable { found },
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::OperatorSubtractionSecondOperandExpectedInteger {
+                    found,
+                }),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(
+                    ConstantError::OperatorSubtractionSecondOperandExpectedInteger { found },
+                ),
+            )) => Self::format_line(context, "E0078", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorMultiplicationFirstOperandExpectedEvaluable { found },
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(
+                    ValueError::OperatorMultiplicationFirstOperandExpectedInteger { found },
+                ),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(
+                    ConstantError::OperatorMultiplicationFirstOperandExpectedInteger { found },
+                ),
+            )) => Self::format_line(context, "E0079", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorMultiplicationSecondOperandExpectedEvaluable { found },
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(
+                    ValueError::OperatorMultiplicationSecondOperandExpectedInteger { found },
+                ),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(
+                    ConstantError::OperatorMultiplicationSecondOperandExpectedInteger { found },
+                ),
+            )) => Self::format_line(context, "E0080", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorDivisionFirstOperandExpectedEvaluable { found },
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::OperatorDivisionFirstOperandExpectedInteger {
+                    found,
+                }),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(
+                    ConstantError::OperatorDivisionFirstOperandExpectedInteger { found },
+                ),
+            )) => Self::format_line(context, "E0081", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorDivisionSecondOperandExpectedEvaluable { found },
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::OperatorDivisionSecondOperandExpectedInteger {
+                    found,
+                }),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(
+                    ConstantError::OperatorDivisionSecondOperandExpectedInteger { found },
+                ),
+            )) => Self::format_line(context, "E0082", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorRemainderFirstOperandExpectedEvaluable { found },
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::OperatorRemainderFirstOperandExpectedInteger {
+                    found,
+                }),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(
+                    ConstantError::OperatorRemainderFirstOperandExpectedInteger { found },
+                ),
+            )) => Self::format_line(context, "E0083", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorRemainderSecondOperandExpectedEvaluable { found },
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::OperatorRemainderSecondOperandExpectedInteger {
+                    found,
+                }),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(
+                    ConstantError::OperatorRemainderSecondOperandExpectedInteger { found },
+                ),
+            )) => Self::format_line(context, "E0084", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorExponentiationFirstOperandExpectedEvaluable { found },
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(
+                    ValueError::OperatorExponentiationFirstOperandExpectedInteger { found },
+                ),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(
+                    ConstantError::OperatorExponentiationFirstOperandExpectedInteger { found },
+                ),
+            )) => Self::format_line(context, "E0190", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorExponentiationSecondOperandExpectedConstant { found },
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(
+                    ValueError::OperatorExponentiationSecondOperandExpectedInteger { found },
+                ),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::Integer(
+                    IntegerValueError::OperatorExponentiationSecondOperatorExpectedUnsigned {
                         found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentRemainderSecondOperandExpectedEvaluable{ found })) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the assignment operator `%=` expected a value as the second operand, found `{}`",
+                    },
+                )),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(
+                    ConstantError::OperatorExponentiationSecondOperandExpectedInteger { found },
+                ),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(ConstantError::Integer(
+                    IntegerConstantError::OperatorExponentiationSecondOperatorExpectedUnsigned {
                         found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorRangeInclusiveFirstOperandExpectedConstant{ found })) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorRangeInclusiveFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the inclusive range operator `..=` expected an integer constant as the first operand, found `{}`",
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorRangeInclusiveSecondOperandExpectedConstant{ found })) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorRangeInclusiveSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the inclusive range operator `..=` expected an integer constant as the second operand, found `{}`",
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorRangeFirstOperandExpectedConstant{ found })) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorRangeFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the range operator `..` expected an integer constant as the first operand, found `{}`",
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorRangeSecondOperandExpectedConstant{ found })) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorRangeSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the range operator `..` expected an integer constant as the second operand, found `{}`",
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorOrFirstOperandExpectedEvaluable{ found })) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorOrFirstOperandExpectedBoolean{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorOrFirstOperandExpectedBoolean{ found }))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the OR operator `||` expected a boolean as the first operand, found `{}`",
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorOrSecondOperandExpectedEvaluable{ found })) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorOrSecondOperandExpectedBoolean{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorOrSecondOperandExpectedBoolean{ found }))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the OR operator `||` expected a boolean as the second operand, found `{}`",
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorXorFirstOperandExpectedEvaluable{ found })) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorXorFirstOperandExpectedBoolean{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorXorFirstOperandExpectedBoolean{ found }))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the XOR operator `^^` expected a boolean as the first operand, found `{}`",
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorXorSecondOperandExpectedEvaluable{ found })) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorXorSecondOperandExpectedBoolean{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorXorSecondOperandExpectedBoolean{ found }))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the XOR operator `^^` expected a boolean as the second operand, found `{}`",
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorAndFirstOperandExpectedEvaluable{ found })) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorAndFirstOperandExpectedBoolean{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorAndFirstOperandExpectedBoolean{ found }))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the AND operator `&&` expected a boolean as the first operand, found `{}`",
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorAndSecondOperandExpectedEvaluable{ found })) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorAndSecondOperandExpectedBoolean{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorAndSecondOperandExpectedBoolean{ found }))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the AND operator `&&` expected a boolean as the second operand, found `{}`",
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorEqualsFirstOperandExpectedEvaluable{ found })) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorEqualsFirstOperandExpectedPrimitiveType{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorEqualsFirstOperandExpectedPrimitiveType{ found }))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the equals operator `==` expected a unit, boolean or integer as the first operand, found `{}`",
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorEqualsSecondOperandExpectedEvaluable{ found })) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorEqualsSecondOperandExpectedUnit{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorEqualsSecondOperandExpectedBoolean{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorEqualsSecondOperandExpectedInteger{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorEqualsSecondOperandExpectedUnit{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorEqualsSecondOperandExpectedBoolean{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorEqualsSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the equals operator `==` expected a unit, boolean or integer as the second operand, found `{}`",
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorNotEqualsFirstOperandExpectedEvaluable{ found })) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorNotEqualsFirstOperandExpectedPrimitiveType{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorNotEqualsFirstOperandExpectedPrimitiveType{ found }))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the not equals operator `!=` expected a boolean or integer as the first operand, found `{}`",
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorNotEqualsSecondOperandExpectedEvaluable{ found })) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorNotEqualsSecondOperandExpectedUnit{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorNotEqualsSecondOperandExpectedBoolean{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorNotEqualsSecondOperandExpectedInteger{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorNotEqualsSecondOperandExpectedUnit{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorNotEqualsSecondOperandExpectedBoolean{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorNotEqualsSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the not equals operator `!=` expected a boolean or integer as the second operand, found `{}`",
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorGreaterEqualsFirstOperandExpectedEvaluable{ found })) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorGreaterEqualsFirstOperandExpectedInteger{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorGreaterEqualsFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the greater equals operator `>=` expected an integer as the first operand, found `{}`",
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorGreaterEqualsSecondOperandExpectedEvaluable{ found })) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorGreaterEqualsSecondOperandExpectedInteger{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorGreaterEqualsSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the greater equals operator `>=` expected an integer as the second operand, found `{}`",
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorLesserEqualsFirstOperandExpectedEvaluable{ found })) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorLesserEqualsFirstOperandExpectedInteger{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorLesserEqualsFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the lesser equals operator `<=` expected an integer as the first operand, found `{}`",
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorLesserEqualsSecondOperandExpectedEvaluable{ found })) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorLesserEqualsSecondOperandExpectedInteger{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorLesserEqualsSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the lesser equals operator `<=` expected an integer as the second operand, found `{}`",
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorGreaterFirstOperandExpectedEvaluable{ found })) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorGreaterFirstOperandExpectedInteger{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorGreaterFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the greater operator `>` expected an integer as the first operand, found `{}`",
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorGreaterSecondOperandExpectedEvaluable{ found })) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorGreaterSecondOperandExpectedInteger{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorGreaterSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the greater operator `>` expected an integer as the second operand, found `{}`",
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorLesserFirstOperandExpectedEvaluable{ found })) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorLesserFirstOperandExpectedInteger{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorLesserFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the lesser operator `<` expected an integer as the first operand, found `{}`",
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorLesserSecondOperandExpectedEvaluable{ found })) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorLesserSecondOperandExpectedInteger{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorLesserSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the lesser operator `<` expected an integer as the second operand, found `{}`",
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorBitwiseOrFirstOperandExpectedEvaluable{ found })) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorBitwiseOrFirstOperandExpectedInteger{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorBitwiseOrFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the bitwise OR operator `|` expected an integer constant as the first operand, found `{}`", // TODO: constant -> ''
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorBitwiseOrSecondOperandExpectedEvaluable{ found })) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorBitwiseOrSecondOperandExpectedInteger{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorBitwiseOrSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the bitwise OR operator `|` expected an integer constant as the second operand, found `{}`", // TODO: constant -> ''
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorBitwiseXorFirstOperandExpectedEvaluable{ found })) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorBitwiseXorFirstOperandExpectedInteger{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorBitwiseXorFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the bitwise XOR operator `^` expected an integer constant as the first operand, found `{}`", // TODO: constant -> ''
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorBitwiseXorSecondOperandExpectedEvaluable{ found })) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorBitwiseXorSecondOperandExpectedInteger{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorBitwiseXorSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the bitwise XOR operator `^` expected an integer constant as the second operand, found `{}`", // TODO: constant -> ''
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorBitwiseAndFirstOperandExpectedEvaluable{ found })) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorBitwiseAndFirstOperandExpectedInteger{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorBitwiseAndFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the bitwise AND operator `&` expected an integer constant as the first operand, found `{}`", // TODO: constant -> ''
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorBitwiseAndSecondOperandExpectedEvaluable{ found })) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorBitwiseAndSecondOperandExpectedInteger{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorBitwiseAndSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the bitwise AND operator `&` expected an integer constant as the second operand, found `{}`", // TODO: constant -> ''
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorBitwiseShiftLeftFirstOperandExpectedEvaluable{ found })) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorBitwiseShiftLeftFirstOperandExpectedInteger{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorBitwiseShiftLeftFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the bitwise shift left operator `<<` expected an integer constant as the first operand, found `{}`", // TODO: constant -> ''
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorBitwiseShiftLeftSecondOperandExpectedConstant{ found })) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorBitwiseShiftLeftSecondOperandExpectedInteger{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::OperatorBitwiseShiftLeftSecondOperatorExpectedUnsigned { found })))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorBitwiseShiftLeftSecondOperandExpectedInteger{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::OperatorBitwiseShiftLeftSecondOperatorExpectedUnsigned { found })))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the bitwise shift left operator `<<` expected an unsigned integer constant as the second operand, found `{}`",
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorBitwiseShiftRightFirstOperandExpectedEvaluable{ found })) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorBitwiseShiftRightFirstOperandExpectedInteger{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorBitwiseShiftRightFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the bitwise shift right operator `>>` expected an integer constant as the first operand, found `{}`", // TODO: constant -> ''
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorBitwiseShiftRightSecondOperandExpectedConstant{ found })) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorBitwiseShiftRightSecondOperandExpectedInteger{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::OperatorBitwiseShiftRightSecondOperatorExpectedUnsigned { found })))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorBitwiseShiftRightSecondOperandExpectedInteger{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::OperatorBitwiseShiftRightSecondOperatorExpectedUnsigned { found })))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the bitwise shift right operator `>>` expected an unsigned integer constant as the second operand, found `{}`",
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorAdditionFirstOperandExpectedEvaluable{ found })) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorAdditionFirstOperandExpectedInteger{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorAdditionFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the addition operator `+` expected an integer as the first operand, found `{}`",
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorAdditionSecondOperandExpectedEvaluable{ found })) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorAdditionSecondOperandExpectedInteger{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorAdditionSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the addition operator `+` expected an integer as the second operand, found `{}`",
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorSubtractionFirstOperandExpectedEvaluable{ found })) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorSubtractionFirstOperandExpectedInteger{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorSubtractionFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the subtraction operator `-` expected an integer as the first operand, found `{}`",
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorSubtractionSecondOperandExpectedEvaluable{ found })) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorSubtractionSecondOperandExpectedInteger{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorSubtractionSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the subtraction operator `-` expected an integer as the second operand, found `{}`",
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorMultiplicationFirstOperandExpectedEvaluable{ found })) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorMultiplicationFirstOperandExpectedInteger{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorMultiplicationFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the multiplication operator `*` expected an integer as the first operand, found `{}`",
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorMultiplicationSecondOperandExpectedEvaluable{ found })) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorMultiplicationSecondOperandExpectedInteger{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorMultiplicationSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the multiplication operator `*` expected an integer as the second operand, found `{}`",
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorDivisionFirstOperandExpectedEvaluable{ found })) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorDivisionFirstOperandExpectedInteger{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorDivisionFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the division operator `/` expected an integer as the first operand, found `{}`",
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorDivisionSecondOperandExpectedEvaluable{ found })) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorDivisionSecondOperandExpectedInteger{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorDivisionSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the division operator `/` expected an integer as the second operand, found `{}`",
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorRemainderFirstOperandExpectedEvaluable{ found })) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorRemainderFirstOperandExpectedInteger{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorRemainderFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the remainder operator `%` expected an integer as the first operand, found `{}`",
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorRemainderSecondOperandExpectedEvaluable{ found })) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorRemainderSecondOperandExpectedInteger{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorRemainderSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the remainder operator `%` expected an integer as the second operand, found `{}`",
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorCastingFirstOperandExpectedEvaluable{ found })) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the casting operator `as` expected a value as the first operand, found `{}`",
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorCastingSecondOperandExpectedType{ found })) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the casting operator `as` expected a type as the second operand, found `{}`",
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Casting(CastingError::CastingFromInvalidType { from, to })))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Casting(CastingError::CastingToInvalidType { from, to })))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Casting(CastingError::CastingFromInvalidType { from, to })))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Casting(CastingError::CastingToInvalidType { from, to })))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "cannot cast from `{}` to `{}`",
-                        from, to,
-                    )
-                        .as_str(),
-                    location,
-                    Some("only integer values can be casted to greater or equal bitlength"),
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorNotExpectedEvaluable{ found })) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorNotExpectedBoolean{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorNotExpectedBoolean{ found }))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the NOT operator `!` expected a boolean, found `{}`",
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorBitwiseNotExpectedEvaluable{ found })) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorBitwiseNotExpectedInteger{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorBitwiseNotExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the bitwise NOT operator `~` expected an integer, found `{}`",
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorNegationExpectedEvaluable{ found })) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorNegationExpectedInteger{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorNegationExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the negation operator `-` expected an integer, found `{}`",
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorIndexFirstOperandExpectedPlaceOrEvaluable{ found })) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Place(PlaceError::OperatorIndexFirstOperandExpectedArray{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorIndexFirstOperandExpectedArray{ found }))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the index operator `[]` expected an array as the first operand, found `{}`",
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorIndexSecondOperandExpectedEvaluable{ found })) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Place(PlaceError::OperatorIndexSecondOperandExpectedIntegerOrRange{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorIndexSecondOperandExpectedIntegerOrRange{ found }))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the index operator `[]` expected an integer or range as the second operand, found `{}`",
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorFieldFirstOperandExpectedPlaceOrEvaluable{ found })) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Place(PlaceError::OperatorFieldFirstOperandExpectedTuple{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Place(PlaceError::OperatorFieldFirstOperandExpectedStructure{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorFieldFirstOperandExpectedTuple{ found }))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorFieldFirstOperandExpectedStructure{ found }))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the field access operator `.` expected a tuple or structure as the first operand, found `{}`",
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorFieldSecondOperandExpectedIdentifier { found })) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the field access operator `.` expected a tuple or structure field identifier as the second operand, found `{}`",
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorPathFirstOperandExpectedPath{ found })) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the path resolution operator `::` expected an item identifier as the first operand, found `{}`",
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorPathSecondOperandExpectedIdentifier { found })) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the path resolution operator `::` expected an item identifier as the second operand, found `{}`",
-                        found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
+                    },
+                )),
+            )) => Self::format_line(context, "E0191", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorCastingFirstOperandExpectedEvaluable { found },
+            )) => Self::format_line(context, "E0085", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorCastingSecondOperandExpectedType { found },
+            )) => Self::format_line(context, "E0086", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::Casting(CastingError::CastingFromInvalidType {
+                    from,
+                    to,
+                })),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::Casting(CastingError::CastingToInvalidType {
+                    from,
+                    to,
+                })),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(ConstantError::Casting(
+                    CastingError::CastingFromInvalidType { from, to },
+                )),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(ConstantError::Casting(
+                    CastingError::CastingToInvalidType { from, to },
+                )),
+            )) => Self::format_line(
+                context,
+                "E0087",
+                &[from.to_string(), to.to_string()],
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorNotExpectedEvaluable { found },
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::OperatorNotExpectedBoolean { found }),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(ConstantError::OperatorNotExpectedBoolean { found }),
+            )) => Self::format_line(context, "E0088", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorBitwiseNotExpectedEvaluable { found },
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::OperatorBitwiseNotExpectedInteger { found }),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(ConstantError::OperatorBitwiseNotExpectedInteger { found }),
+            )) => Self::format_line(context, "E0089", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorNegationExpectedEvaluable { found },
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::OperatorNegationExpectedInteger { found }),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(ConstantError::OperatorNegationExpectedInteger { found }),
+            )) => Self::format_line(context, "E0090", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorIndexFirstOperandExpectedPlaceOrEvaluable { found },
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Place(PlaceError::OperatorIndexFirstOperandExpectedArray { found }),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::OperatorIndexFirstOperandExpectedArray { found }),
+            )) => Self::format_line(context, "E0091", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorIndexSecondOperandExpectedEvaluable { found },
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Place(PlaceError::OperatorIndexSecondOperandExpectedIntegerOrRange {
+                    found,
+                }),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::OperatorIndexSecondOperandExpectedIntegerOrRange {
+                    found,
+                }),
+            )) => Self::format_line(context, "E0092", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorFieldFirstOperandExpectedPlaceOrEvaluable { found },
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Place(PlaceError::OperatorFieldFirstOperandExpectedTuple { found }),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Place(PlaceError::OperatorFieldFirstOperandExpectedStructure {
+                    found,
+                }),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::OperatorFieldFirstOperandExpectedTuple { found }),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::OperatorFieldFirstOperandExpectedStructure {
+                    found,
+                }),
+            )) => Self::format_line(context, "E0093", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorFieldSecondOperandExpectedIdentifier { found },
+            )) => Self::format_line(context, "E0094", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorPathFirstOperandExpectedPath { found },
+            )) => Self::format_line(context, "E0095", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::OperatorPathSecondOperandExpectedIdentifier { found },
+            )) => Self::format_line(context, "E0096", &[found.to_string()], location, None),
 
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Array(ArrayValueError::PushingInvalidType { expected, found })))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "expected `{}`, found `{}`",
-                        expected, found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Array(ArrayValueError::SliceStartOutOfRange { start })))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Place(PlaceError::ArraySliceStartOutOfRange { start }))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "left slice bound `{}` is negative",
-                        start,
-                    )
-                        .as_str(),
-                    location,
-                    Some("slice range bounds must be within the array size"),
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Array(ArrayValueError::SliceEndOutOfRange { end, size })))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Place(PlaceError::ArraySliceEndOutOfRange { end, size }))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "right slice bound `{}` is out of range of the array of size {}",
-                        end, size,
-                    )
-                        .as_str(),
-                    location,
-                    Some("slice range bounds must be within the array size"),
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Array(ArrayValueError::SliceEndLesserThanStart { start, end })))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Place(PlaceError::ArraySliceEndLesserThanStart { start, end }))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "left slice bound `{}` is greater than right slice bound `{}`",
-                        start, end,
-                    )
-                        .as_str(),
-                    location,
-                    Some("left slice range bound must be lesser or equal to the right one"),
-                )
-            }
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::Array(ArrayValueError::PushingInvalidType {
+                    expected,
+                    found,
+                })),
+            )) => Self::format_line(
+                context,
+                "E0097",
+                &[expected.to_string(), found.to_string()],
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Place(PlaceError::ArrayIndexOutOfRange { index, size }),
+            )) => Self::format_line(
+                context,
+                "E0187",
+                &[index.to_string(), size.to_string()],
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::Array(ArrayValueError::SliceStartOutOfRange {
+                    start,
+                })),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Place(PlaceError::ArraySliceStartOutOfRange { start }),
+            )) => Self::format_line(context, "E0098", &[start.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::Array(ArrayValueError::SliceEndOutOfRange {
+                    end,
+                    size,
+                })),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Place(PlaceError::ArraySliceEndOutOfRange { end, size }),
+            )) => Self::format_line(
+                context,
+                "E0099",
+                &[end.to_string(), size.to_string()],
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::Array(ArrayValueError::SliceEndLesserThanStart {
+                    start,
+                    end,
+                })),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Place(PlaceError::ArraySliceEndLesserThanStart { start, end }),
+            )) => Self::format_line(
+                context,
+                "E0100",
+                &[start.to_string(), end.to_string()],
+                location,
+                None,
+            ),
 
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Tuple(TupleValueError::FieldDoesNotExist { type_identifier, field_index })))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Place(PlaceError::TupleFieldDoesNotExist { type_identifier, field_index }))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "tuple `{}` has no field with index `{}`",
-                        type_identifier, field_index,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::Tuple(TupleValueError::FieldDoesNotExist {
+                    type_identifier,
+                    field_index,
+                })),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Place(PlaceError::TupleFieldDoesNotExist {
+                    type_identifier,
+                    field_index,
+                }),
+            )) => Self::format_line(
+                context,
+                "E0101",
+                &[type_identifier.to_string(), field_index.to_string()],
+                location,
+                None,
+            ),
 
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Structure(StructureValueError::FieldDoesNotExist { type_identifier, field_name })))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Place(PlaceError::StructureFieldDoesNotExist { type_identifier, field_name }))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "field `{}` does not exist in structure `{}`",
-                        field_name, type_identifier,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::Place(PlaceError::MutatingWithDifferentType { expected, found }))) => {
-                Self::format_line(
-                    context,
-                    format!("expected `{}`, found `{}`", expected, found).as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::Place(PlaceError::MutatingImmutableMemory { name, reference }))) => {
-                Self::format_line_with_reference(
-                    context,
-                    format!("cannot assign twice to immutable variable `{}`", name).as_str(),
-                    location,
-                    reference,
-                    Some(format!("make this variable mutable: `mut {}`", name).as_str()),
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Structure(StructureValueError::FieldExpected { type_identifier, position, expected, found })))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "structure `{}` expected field `{}` at position {}, found `{}`",
-                        type_identifier, expected, position, found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Structure(StructureValueError::FieldInvalidType { type_identifier, field_name, expected, found })))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "field `{}` of structure `{}` expected type `{}`, found `{}`",
-                        field_name, type_identifier, expected, found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Structure(StructureValueError::FieldOutOfRange { type_identifier, expected, found })))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "structure `{}` expected {} fields, found {}",
-                        type_identifier, expected, found,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchEquals{ first, second })))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchEquals{ first, second })))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the equals operator `==` expected two integers of the same type, found `{}` and `{}`",
-                        first, second,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchNotEquals{ first, second })))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchNotEquals{ first, second })))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the not equals operator `!=` expected two integers of the same type, found `{}` and `{}`",
-                        first, second,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchGreaterEquals{ first, second })))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchGreaterEquals{ first, second })))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the greater equals operator `>=` expected two integers of the same type, found `{}` and `{}`",
-                        first, second,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchLesserEquals{ first, second })))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchLesserEquals{ first, second })))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the lesser equals operator `<=` expected two integers of the same type, found `{}` and `{}`",
-                        first, second,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchGreater{ first, second })))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchGreater{ first, second })))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the greater operator `>` expected two integers of the same type, found `{}` and `{}`",
-                        first, second,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchLesser{ first, second })))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchLesser{ first, second })))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the lesser operator `<` expected two integers of the same type, found `{}` and `{}`",
-                        first, second,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchBitwiseOr{ first, second })))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchBitwiseOr{ first, second })))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the bitwise OR operator `|` expected two integers of the same type, found `{}` and `{}`",
-                        first, second,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchBitwiseXor{ first, second })))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchBitwiseXor{ first, second })))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the bitwise XOR operator `^` expected two integers of the same type, found `{}` and `{}`",
-                        first, second,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchBitwiseAnd{ first, second })))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchBitwiseAnd{ first, second })))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the bitwise AND operator `&` expected two integers of the same type, found `{}` and `{}`",
-                        first, second,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchAddition{ first, second })))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchAddition{ first, second })))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the addition operator `+` expected two integers of the same type, found `{}` and `{}`",
-                        first, second,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchSubtraction{ first, second })))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchSubtraction{ first, second })))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the subtraction operator `-` expected two integers of the same type, found `{}` and `{}`",
-                        first, second,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchMultiplication{ first, second })))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchMultiplication{ first, second })))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the multiplication operator `*` expected two integers of the same type, found `{}` and `{}`",
-                        first, second,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchDivision{ first, second })))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchDivision{ first, second })))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the division operator `/` expected two integers of the same type, found `{}` and `{}`",
-                        first, second,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchRemainder{ first, second })))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchRemainder{ first, second })))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the remainder operator `%` expected two integers of the same type, found `{}` and `{}`",
-                        first, second,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::OverflowAddition { value, r#type })))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the addition operator `+` overflow, as the value `{}` cannot be represeneted by type `{}`",
-                        value, r#type,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::OverflowSubtraction { value, r#type })))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the subtraction operator `-` overflow, as the value `{}` cannot be represeneted by type `{}`",
-                        value, r#type,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::OverflowMultiplication { value, r#type })))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the multiplication operator `*` overflow, as the value `{}` cannot be represeneted by type `{}`",
-                        value, r#type,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::OverflowDivision { value, r#type })))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the division operator `/` overflow, as the value `{}` cannot be represeneted by type `{}`",
-                        value, r#type,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::OverflowRemainder { value, r#type })))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the remainder operator `%` overflow, as the value `{}` cannot be represeneted by type `{}`",
-                        value, r#type,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::OverflowCasting { value, r#type })))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the casting operator `as` overflow, as the value `{}` cannot be represeneted by type `{}`",
-                        value, r#type,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::OverflowNegation { value, r#type })))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the negation operator `-` overflow, as the value `{}` cannot be represeneted by type `{}`",
-                        value, r#type,
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::ForbiddenFieldDivision)))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::ForbiddenFieldDivision)))) => {
-                Self::format_line(
-                    context,
-                    "the division operator `/` is forbidden for the `field` type",
-                    location,
-                    Some("for inversion consider using `std::ff::invert`"),
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::ForbiddenFieldRemainder)))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::ForbiddenFieldRemainder)))) => {
-                Self::format_line(
-                    context,
-                    "the remainder operator `%` is forbidden for the `field` type",
-                    location,
-                    Some("`field` type values cannot be used to get a remainder"),
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::ForbiddenFieldBitwise)))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::ForbiddenFieldBitwise)))) => {
-                Self::format_line(
-                    context,
-                    "the bitwise operators are forbidden for the `field` type",
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::ForbiddenFieldNegation)))) |
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::ForbiddenFieldNegation)))) => {
-                Self::format_line(
-                    context,
-                    "the negation operator `-` is forbidden for the `field` type",
-                    location,
-                    Some("`field` type values cannot be negative"),
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::ZeroDivision)))) => {
-                Self::format_line(
-                    context,
-                    "division by zero",
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::ZeroRemainder)))) => {
-                Self::format_line(
-                    context,
-                    "remainder of division by zero",
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::IntegerTooLarge { value, bitlength })))) => {
-                Self::format_line(
-                    context,
-                    format!("integer `{}` is larger than `{}` bits", value, bitlength).as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::UnsignedNegative { value, r#type })))) => {
-                Self::format_line(
-                    context,
-                    format!("found a negative value `{}` of unsigned type `{}`", value, r#type).as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::AliasDoesNotPointToType { found }))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "expected type, found `{}`",
-                        found
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::AliasDoesNotPointToStructure { found }))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "expected structure type, found `{}`",
-                        found
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::Structure(
+                    StructureValueError::FieldDoesNotExist {
+                        type_identifier,
+                        field_name,
+                    },
+                )),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Place(PlaceError::StructureFieldDoesNotExist {
+                    type_identifier,
+                    field_name,
+                }),
+            )) => Self::format_line(
+                context,
+                "E0102",
+                &[field_name.to_string(), type_identifier.to_string()],
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Place(PlaceError::MutatingWithDifferentType { expected, found }),
+            )) => Self::format_line(
+                context,
+                "E0097",
+                &[expected.to_string(), found.to_string()],
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Place(PlaceError::MutatingImmutableMemory { name, reference }),
+            )) => Self::format_line_with_reference(
+                context,
+                "E0103",
+                &[name.to_string(), name.to_string()],
+                location,
+                reference,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::Structure(StructureValueError::FieldExpected {
+                    type_identifier,
+                    position,
+                    expected,
+                    found,
+                })),
+            )) => Self::format_line(
+                context,
+                "E0104",
+                &[
+                    type_identifier.to_string(),
+                    expected.to_string(),
+                    position.to_string(),
+                    found.to_string(),
+                ],
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::Structure(StructureValueError::FieldInvalidType {
+                    type_identifier,
+                    field_name,
+                    expected,
+                    found,
+                })),
+            )) => Self::format_line(
+                context,
+                "E0105",
+                &[
+                    field_name.to_string(),
+                    type_identifier.to_string(),
+                    expected.to_string(),
+                    found.to_string(),
+                ],
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::Structure(StructureValueError::FieldOutOfRange {
+                    type_identifier,
+                    expected,
+                    found,
+                })),
+            )) => Self::format_line(
+                context,
+                "E0106",
+                &[
+                    type_identifier.to_string(),
+                    expected.to_string(),
+                    found.to_string(),
+                ],
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchEquals {
+                    first,
+                    second,
+                })),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(ConstantError::Integer(
+                    IntegerConstantError::TypesMismatchEquals { first, second },
+                )),
+            )) => Self::format_line(
+                context,
+                "E0107",
+                &[first.to_string(), second.to_string()],
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::Integer(
+                    IntegerValueError::TypesMismatchNotEquals { first, second },
+                )),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(ConstantError::Integer(
+                    IntegerConstantError::TypesMismatchNotEquals { first, second },
+                )),
+            )) => Self::format_line(
+                context,
+                "E0108",
+                &[first.to_string(), second.to_string()],
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::Integer(
+                    IntegerValueError::TypesMismatchGreaterEquals { first, second },
+                )),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(ConstantError::Integer(
+                    IntegerConstantError::TypesMismatchGreaterEquals { first, second },
+                )),
+            )) => Self::format_line(
+                context,
+                "E0109",
+                &[first.to_string(), second.to_string()],
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::Integer(
+                    IntegerValueError::TypesMismatchLesserEquals { first, second },
+                )),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(ConstantError::Integer(
+                    IntegerConstantError::TypesMismatchLesserEquals { first, second },
+                )),
+            )) => Self::format_line(
+                context,
+                "E0110",
+                &[first.to_string(), second.to_string()],
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchGreater {
+                    first,
+                    second,
+                })),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(ConstantError::Integer(
+                    IntegerConstantError::TypesMismatchGreater { first, second },
+                )),
+            )) => Self::format_line(
+                context,
+                "E0111",
+                &[first.to_string(), second.to_string()],
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchLesser {
+                    first,
+                    second,
+                })),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(ConstantError::Integer(
+                    IntegerConstantError::TypesMismatchLesser { first, second },
+                )),
+            )) => Self::format_line(
+                context,
+                "E0112",
+                &[first.to_string(), second.to_string()],
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::Integer(
+                    IntegerValueError::TypesMismatchBitwiseOr { first, second },
+                )),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(ConstantError::Integer(
+                    IntegerConstantError::TypesMismatchBitwiseOr { first, second },
+                )),
+            )) => Self::format_line(
+                context,
+                "E0113",
+                &[first.to_string(), second.to_string()],
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::Integer(
+                    IntegerValueError::TypesMismatchBitwiseXor { first, second },
+                )),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(ConstantError::Integer(
+                    IntegerConstantError::TypesMismatchBitwiseXor { first, second },
+                )),
+            )) => Self::format_line(
+                context,
+                "E0114",
+                &[first.to_string(), second.to_string()],
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::Integer(
+                    IntegerValueError::TypesMismatchBitwiseAnd { first, second },
+                )),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(ConstantError::Integer(
+                    IntegerConstantError::TypesMismatchBitwiseAnd { first, second },
+                )),
+            )) => Self::format_line(
+                context,
+                "E0115",
+                &[first.to_string(), second.to_string()],
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::Integer(
+                    IntegerValueError::TypesMismatchAddition { first, second },
+                )),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(ConstantError::Integer(
+                    IntegerConstantError::TypesMismatchAddition { first, second },
+                )),
+            )) => Self::format_line(
+                context,
+                "E0116",
+                &[first.to_string(), second.to_string()],
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::Integer(
+                    IntegerValueError::TypesMismatchSubtraction { first, second },
+                )),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(ConstantError::Integer(
+                    IntegerConstantError::TypesMismatchSubtraction { first, second },
+                )),
+            )) => Self::format_line(
+                context,
+                "E0117",
+                &[first.to_string(), second.to_string()],
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::Integer(
+                    IntegerValueError::TypesMismatchMultiplication { first, second },
+                )),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(ConstantError::Integer(
+                    IntegerConstantError::TypesMismatchMultiplication { first, second },
+                )),
+            )) => Self::format_line(
+                context,
+                "E0118",
+                &[first.to_string(), second.to_string()],
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::Integer(
+                    IntegerValueError::TypesMismatchDivision { first, second },
+                )),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(ConstantError::Integer(
+                    IntegerConstantError::TypesMismatchDivision { first, second },
+                )),
+            )) => Self::format_line(
+                context,
+                "E0119",
+                &[first.to_string(), second.to_string()],
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::Integer(
+                    IntegerValueError::TypesMismatchRemainder { first, second },
+                )),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(ConstantError::Integer(
+                    IntegerConstantError::TypesMismatchRemainder { first, second },
+                )),
+            )) => Self::format_line(
+                context,
+                "E0120",
+                &[first.to_string(), second.to_string()],
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(ConstantError::Integer(
+                    IntegerConstantError::OverflowAddition { value, r#type },
+                )),
+            )) => Self::format_line(
+                context,
+                "E0121",
+                &[value.to_string(), r#type.to_string()],
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(ConstantError::Integer(
+                    IntegerConstantError::OverflowSubtraction { value, r#type },
+                )),
+            )) => Self::format_line(
+                context,
+                "E0122",
+                &[value.to_string(), r#type.to_string()],
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(ConstantError::Integer(
+                    IntegerConstantError::OverflowMultiplication { value, r#type },
+                )),
+            )) => Self::format_line(
+                context,
+                "E0123",
+                &[value.to_string(), r#type.to_string()],
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(ConstantError::Integer(
+                    IntegerConstantError::OverflowDivision { value, r#type },
+                )),
+            )) => Self::format_line(
+                context,
+                "E0124",
+                &[value.to_string(), r#type.to_string()],
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(ConstantError::Integer(
+                    IntegerConstantError::OverflowRemainder { value, r#type },
+                )),
+            )) => Self::format_line(
+                context,
+                "E0125",
+                &[value.to_string(), r#type.to_string()],
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(ConstantError::Integer(
+                    IntegerConstantError::OverflowExponentiation { value, r#type },
+                )),
+            )) => Self::format_line(
+                context,
+                "E0192",
+                &[value.to_string(), r#type.to_string()],
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(ConstantError::Integer(
+                    IntegerConstantError::OverflowCasting { value, r#type },
+                )),
+            )) => Self::format_line(
+                context,
+                "E0126",
+                &[value.to_string(), r#type.to_string()],
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(ConstantError::Integer(
+                    IntegerConstantError::OverflowNegation { value, r#type },
+                )),
+            )) => Self::format_line(
+                context,
+                "E0127",
+                &[value.to_string(), r#type.to_string()],
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::Integer(IntegerValueError::ForbiddenFieldDivision)),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(ConstantError::Integer(
+                    IntegerConstantError::ForbiddenFieldDivision,
+                )),
+            )) => Self::format_line(context, "E0128", &[], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::Integer(
+                    IntegerValueError::ForbiddenFieldRemainder,
+                )),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(ConstantError::Integer(
+                    IntegerConstantError::ForbiddenFieldRemainder,
+                )),
+            )) => Self::format_line(context, "E0129", &[], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::Integer(IntegerValueError::ForbiddenFieldBitwise)),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(ConstantError::Integer(
+                    IntegerConstantError::ForbiddenFieldBitwise,
+                )),
+            )) => Self::format_line(context, "E0130", &[], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::Integer(IntegerValueError::ForbiddenFieldNegation)),
+            ))
+            | Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(ConstantError::Integer(
+                    IntegerConstantError::ForbiddenFieldNegation,
+                )),
+            )) => Self::format_line(context, "E0131", &[], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::Integer(
+                    IntegerValueError::ForbiddenDecimalRemainder,
+                )),
+            )) => Self::format_line(context, "E0197", &[], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::Integer(
+                    IntegerValueError::ForbiddenDecimalBitwise,
+                )),
+            )) => Self::format_line(context, "E0198", &[], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::Integer(
+                    IntegerValueError::ForbiddenDecimalNegation,
+                )),
+            )) => Self::format_line(context, "E0199", &[], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Value(ValueError::Integer(
+                    IntegerValueError::ForbiddenDecimalExponentiation,
+                )),
+            )) => Self::format_line(context, "E0200", &[], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(ConstantError::Integer(IntegerConstantError::ZeroDivision)),
+            )) => Self::format_line(context, "E0132", &[], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(ConstantError::Integer(IntegerConstantError::ZeroRemainder)),
+            )) => Self::format_line(context, "E0133", &[], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(ConstantError::Integer(
+                    IntegerConstantError::IntegerTooLarge { value, bitlength },
+                )),
+            )) => Self::format_line(
+                context,
+                "E0134",
+                &[value.to_string(), bitlength.to_string()],
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Constant(ConstantError::Integer(
+                    IntegerConstantError::UnsignedNegative { value, r#type },
+                )),
+            )) => Self::format_line(
+                context,
+                "E0135",
+                &[value.to_string(), r#type.to_string()],
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Type(TypeError::AliasDoesNotPointToType { found }),
+            )) => Self::format_line(context, "E0014", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Type(TypeError::AliasDoesNotPointToStructure { found }),
+            )) => Self::format_line(context, "E0136", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Type(TypeError::DecimalBitlengthInvalid {
+                    integer_bits,
+                    fraction_bits,
+                }),
+            )) => Self::format_line(
+                context,
+                "E0196",
+                &[integer_bits.to_string(), fraction_bits.to_string()],
+                location,
+                None,
+            ),
 
-            Self::Semantic(SemanticError::Scope(ScopeError::ItemRedeclared { location, name, reference })) => {
-                Self::format_line_with_reference(
-                    context,
-                    format!(
-                        "item `{}` already declared here",
-                        name
-                    )
-                        .as_str(),
-                    location,
-                    reference,
-                    Some("consider giving the latter item another name"),
-                )
-            }
+            Self::Semantic(SemanticError::Scope(ScopeError::ItemRedeclared {
+                location,
+                name,
+                reference,
+            })) => Self::format_line_with_reference(
+                context,
+                "E0137",
+                &[name.to_string()],
+                location,
+                reference,
+                None,
+            ),
             Self::Semantic(SemanticError::Scope(ScopeError::ItemUndeclared { location, name })) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "cannot find item `{}` in this scope",
-                        name
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Scope(ScopeError::ItemIsNotNamespace { location, name })) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "item `{}` is not a namespace",
-                        name
-                    )
-                        .as_str(),
-                    location,
-                    Some("only modules, structures, and enumerations can contain items within their namespaces"),
-                )
+                Self::format_line(context, "E0138", &[name.to_string()], location, None)
             }
+            Self::Semantic(SemanticError::Scope(ScopeError::ItemIsNotNamespace {
+                location,
+                name,
+            })) => Self::format_line(context, "E0139", &[name.to_string()], location, None),
 
-            Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Function(FunctionTypeError::ArgumentCount { function, expected, found })))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "function `{}` expected {} arguments, found {}",
-                        function, expected, found
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Function(FunctionTypeError::ArgumentType { function, name, position, expected, found })))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "function `{}` expected type `{}` as the argument `{}` (#{}), found `{}`",
-                        function, expected, name, position, found
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Function(FunctionTypeError::ArgumentConstantness { function, name, position, found })))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "function `{}` expected a constant as the argument `{}` (#{}), found a non-constant of type `{}`",
-                        function, name, position, found
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Function(FunctionTypeError::ArgumentNotEvaluable { function, position, found })))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "function `{}` expected a value as the argument #{}, found `{}`",
-                        function, position, found
-                    )
-                        .as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Function(FunctionTypeError::ReturnType { function, expected, found, reference })))) => {
-                Self::format_line_with_reference(
-                    context,
-                    format!(
-                        "function `{}` must return a value of type `{}`, found `{}`",
-                        function, expected, found
-                    )
-                        .as_str(),
-                    location,
-                    Some(reference),
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Function(FunctionTypeError::NonCallable { name })))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "attempt to call a non-callable item `{}`",
-                        name
-                    )
-                        .as_str(),
-                    location,
-                    Some("only functions may be called"),
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Function(FunctionTypeError::FunctionMethodSelfNotFirst { function, position, reference })))) => {
-                Self::format_line_with_reference(
-                    context,
-                    format!(
-                        "method `{}` expected the `{}` binding to be at the first position, but found at the position #`{}`",
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Type(TypeError::Function(FunctionTypeError::ArgumentCount {
+                    function,
+                    expected,
+                    found,
+                })),
+            )) => Self::format_line(
+                context,
+                "E0140",
+                &[
+                    function.to_string(),
+                    expected.to_string(),
+                    found.to_string(),
+                ],
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Type(TypeError::Function(FunctionTypeError::ArgumentType {
+                    function,
+                    name,
+                    position,
+                    expected,
+                    found,
+                })),
+            )) => Self::format_line(
+                context,
+                "E0141",
+                &[
+                    function.to_string(),
+                    expected.to_string(),
+                    name.to_string(),
+                    position.to_string(),
+                    found.to_string(),
+                ],
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Type(TypeError::Function(FunctionTypeError::ArgumentConstantness {
+                    function,
+                    name,
+                    position,
+                    found,
+                })),
+            )) => Self::format_line(
+                context,
+                "E0142",
+                &[
+                    function.to_string(),
+                    name.to_string(),
+                    position.to_string(),
+                    found.to_string(),
+                ],
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Type(TypeError::Function(FunctionTypeError::ArgumentNotEvaluable {
+                    function,
+                    position,
+                    found,
+                })),
+            )) => Self::format_line(
+                context,
+                "E0143",
+                &[
+                    function.to_string(),
+                    position.to_string(),
+                    found.to_string(),
+                ],
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Type(TypeError::Function(FunctionTypeError::ReturnType {
+                    function,
+                    expected,
+                    found,
+                    reference,
+                })),
+            )) => Self::format_line_with_reference(
+                context,
+                "E0144",
+                &[
+                    function.to_string(),
+                    expected.to_string(),
+                    found.to_string(),
+                ],
+                location,
+                Some(reference),
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Type(TypeError::Function(FunctionTypeError::NonCallable { name })),
+            )) => Self::format_line(context, "E0145", &[name.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Type(TypeError::Function(
+                    FunctionTypeError::FunctionMethodSelfNotFirst {
                         function,
-                        Keyword::SelfLowercase.to_string(),
                         position,
-                    )
-                        .as_str(),
-                    location,
-                    Some(reference),
-                    Some(format!("consider moving the `{}` binding to the first place", Keyword::SelfLowercase.to_string()).as_str()),
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Function(FunctionTypeError::BuiltIn(BuiltInFunctionTypeError::Unknown { function }))))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "attempt to call a non-builtin function `{}` with `!` specifier",
-                        function
-                    )
-                        .as_str(),
-                    location,
-                    Some("only built-in functions require the `!` symbol after the function name"),
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Function(FunctionTypeError::BuiltIn(BuiltInFunctionTypeError::SpecifierMissing { function }))))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "attempt to call a builtin function `{}` without `!` specifier",
-                        function
-                    )
-                        .as_str(),
-                    location,
-                    Some("built-in functions require the `!` symbol after the function name"),
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Function(FunctionTypeError::BuiltIn(BuiltInFunctionTypeError::DebugArgumentCount { expected, found }))))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "the `dbg!` function expected {} arguments, but got {}",
-                        expected, found,
-                    )
-                        .as_str(),
-                    location,
-                    Some("the number of `dbg!` arguments after the format string must be equal to the number of placeholders, e.g. `dbg!(\"{}, {}\", a, b)`"),
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Function(FunctionTypeError::StandardLibrary(StandardLibraryFunctionTypeError::ArrayTruncatingToBiggerSize { from, to }))))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "attempt to truncate an array from size `{}` to bigger size `{}`",
-                        from, to,
-                    )
-                        .as_str(),
-                    location,
-                    Some("consider truncating the array to a smaller size"),
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Function(FunctionTypeError::StandardLibrary(StandardLibraryFunctionTypeError::ArrayPaddingToLesserSize { from, to }))))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "attempt to pad an array from size `{}` to lesser size `{}`",
-                        from, to,
-                    )
-                        .as_str(),
-                    location,
-                    Some("consider padding the array to a bigger size"),
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Function(FunctionTypeError::StandardLibrary(StandardLibraryFunctionTypeError::ArrayNewLengthInvalid { value }))))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "new array length `{}` cannot act as an index",
+                        reference,
+                    },
+                )),
+            )) => Self::format_line_with_reference(
+                context,
+                "E0146",
+                &[
+                    function.to_string(),
+                    Keyword::SelfLowercase.to_string(),
+                    position.to_string(),
+                    Keyword::SelfLowercase.to_string(),
+                ],
+                location,
+                Some(reference),
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Type(TypeError::Function(
+                    FunctionTypeError::EnumerationValueInvalid { enumeration, value },
+                )),
+            )) => Self::format_line(
+                context,
+                "E0180",
+                &[enumeration.to_string(), value.to_string()],
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Type(TypeError::Function(
+                    FunctionTypeError::ConstParameterNotInferred { function, name },
+                )),
+            )) => Self::format_line(
+                context,
+                "E0184",
+                &[function.to_string(), name.to_string()],
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Type(TypeError::Function(
+                    FunctionTypeError::ConstParameterExpectedInteger {
+                        function,
+                        name,
+                        found,
+                    },
+                )),
+            )) => Self::format_line(
+                context,
+                "E0185",
+                &[function.to_string(), name.to_string(), found.to_string()],
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Type(TypeError::Function(
+                    FunctionTypeError::ConstParameterRecursiveInstance { function },
+                )),
+            )) => Self::format_line(context, "E0186", &[function.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Type(TypeError::Function(FunctionTypeError::BuiltIn(
+                    BuiltInFunctionTypeError::Unknown { function },
+                ))),
+            )) => Self::format_line(context, "E0147", &[function.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Type(TypeError::Function(FunctionTypeError::BuiltIn(
+                    BuiltInFunctionTypeError::SpecifierMissing { function },
+                ))),
+            )) => Self::format_line(context, "E0148", &[function.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Type(TypeError::Function(FunctionTypeError::BuiltIn(
+                    BuiltInFunctionTypeError::DebugArgumentCount { expected, found },
+                ))),
+            )) => Self::format_line(
+                context,
+                "E0149",
+                &[expected.to_string(), found.to_string()],
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Type(TypeError::Function(FunctionTypeError::BuiltIn(
+                    BuiltInFunctionTypeError::AssertArgumentCount { expected, found },
+                ))),
+            )) => Self::format_line(
+                context,
+                "E0150",
+                &[expected.to_string(), found.to_string()],
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Type(TypeError::Function(FunctionTypeError::BuiltIn(
+                    BuiltInFunctionTypeError::ExposeOutsideMain { function },
+                ))),
+            )) => Self::format_line(context, "E0175", &[function.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Type(TypeError::Function(FunctionTypeError::BuiltIn(
+                    BuiltInFunctionTypeError::ExposeConditional { name },
+                ))),
+            )) => Self::format_line(context, "E0176", &[name.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Type(TypeError::Function(FunctionTypeError::BuiltIn(
+                    BuiltInFunctionTypeError::ExposeDuplicate { name },
+                ))),
+            )) => Self::format_line(context, "E0177", &[name.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Type(TypeError::Function(FunctionTypeError::StandardLibrary(
+                    StandardLibraryFunctionTypeError::ArrayTruncatingToBiggerSize { from, to },
+                ))),
+            )) => Self::format_line(
+                context,
+                "E0151",
+                &[from.to_string(), to.to_string()],
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Type(TypeError::Function(FunctionTypeError::StandardLibrary(
+                    StandardLibraryFunctionTypeError::ArrayPaddingToLesserSize { from, to },
+                ))),
+            )) => Self::format_line(
+                context,
+                "E0152",
+                &[from.to_string(), to.to_string()],
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Type(TypeError::Function(FunctionTypeError::StandardLibrary(
+                    StandardLibraryFunctionTypeError::ArrayNewLengthInvalid { value },
+                ))),
+            )) => Self::format_line(context, "E0153", &[value.to_string()], location, None),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Type(TypeError::Function(FunctionTypeError::StandardLibrary(
+                    StandardLibraryFunctionTypeError::MathFractionalBitsInvalid {
                         value,
-                    )
-                        .as_str(),
-                    location,
-                    Some("array indexes cannot be greater than maximum of `u64`"),
-                )
-            }
-            Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Structure(StructureTypeError::DuplicateField { type_identifier, field_name })))) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "structure `{}` has a duplicate field `{}`",
-                        type_identifier, field_name,
-                    )
-                        .as_str(),
-                    location,
-                    Some("consider giving the field a unique name"),
-                )
-            }
+                        bitlength,
+                    },
+                ))),
+            )) => Self::format_line(
+                context,
+                "E0189",
+                &[value.to_string(), bitlength.to_string()],
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Type(TypeError::Function(FunctionTypeError::StandardLibrary(
+                    StandardLibraryFunctionTypeError::TableIndexOutOfRange { index, size },
+                ))),
+            )) => Self::format_line(
+                context,
+                "E0193",
+                &[index.to_string(), size.to_string()],
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Type(TypeError::Function(FunctionTypeError::StandardLibrary(
+                    StandardLibraryFunctionTypeError::AssertRangeEmpty { low, high },
+                ))),
+            )) => Self::format_line(
+                context,
+                "E0194",
+                &[low.to_string(), high.to_string()],
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Type(TypeError::Function(FunctionTypeError::StandardLibrary(
+                    StandardLibraryFunctionTypeError::AssertValueOutOfRange { value, range },
+                ))),
+            )) => Self::format_line(
+                context,
+                "E0195",
+                &[value.to_string(), range.to_string()],
+                location,
+                None,
+            ),
+            Self::Semantic(SemanticError::Element(
+                location,
+                ElementError::Type(TypeError::Structure(StructureTypeError::DuplicateField {
+                    type_identifier,
+                    field_name,
+                })),
+            )) => Self::format_line(
+                context,
+                "E0154",
+                &[type_identifier.to_string(), field_name.to_string()],
+                location,
+                None,
+            ),
 
             Self::Semantic(SemanticError::MatchScrutineeInvalidType { location, found }) => {
-                Self::format_line(
-                    context,
-                    format!("match scrutinee expected a boolean or integer expression, found `{}`", found).as_str(),
-                    location,
-                    None,
-                )
+                Self::format_line(context, "E0155", &[found.to_string()], location, None)
             }
             Self::Semantic(SemanticError::MatchNotExhausted { location }) => {
-                Self::format_line(
-                    context,
-                    "match expression must be exhaustive",
-                    location,
-                    Some("ensure that all possible cases are being handled, possibly by adding wildcards or more match arms"),
-                )
+                Self::format_line(context, "E0156", &[], location, None)
             }
             Self::Semantic(SemanticError::MatchLessThanTwoBranches { location }) => {
-                Self::format_line(
-                    context,
-                    "match expression must have at least two branches",
-                    location,
-                    Some("consider adding some branches to make the expression useful"),
-                )
+                Self::format_line(context, "E0157", &[], location, None)
             }
             Self::Semantic(SemanticError::MatchBranchUnreachable { location }) => {
-                Self::format_line(
-                    context,
-                    "match expression branch is unreachable",
-                    location,
-                    Some("consider removing the branch or moving it above the branch with a wildcard or irrefutable binding"),
-                )
-            }
-            Self::Semantic(SemanticError::MatchBranchPatternPathExpectedConstant { location, found }) => {
-                Self::format_line(
-                    context,
-                    format!("expected path to a constant, found `{}`", found).as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::MatchBranchPatternInvalidType { location, expected, found, reference }) => {
-                Self::format_line_with_reference(
-                    context,
-                    format!("expected `{}`, found `{}`", expected, found).as_str(),
-                    location,
-                    Some(reference),
-                    Some("all branch patterns must be compatible with the type of the expression being matched"),
-                )
-            }
-            Self::Semantic(SemanticError::MatchBranchExpressionInvalidType { location, expected, found, reference }) => {
-                Self::format_line_with_reference(
-                    context,
-                    format!("expected `{}`, found `{}`", expected, found).as_str(),
-                    location,
-                    Some(reference),
-                    Some("all branches must return the type returned by the first branch"),
-                )
-            }
-            Self::Semantic(SemanticError::MatchBranchDuplicate { location, reference }) => {
-                Self::format_line_with_reference(
-                    context,
-                    "match expression contains a duplicate branch pattern",
-                    location,
-                    Some(reference),
-                    Some("each pattern may occur only once"),
-                )
+                Self::format_line(context, "E0158", &[], location, None)
             }
+            Self::Semantic(SemanticError::MatchBranchPatternPathExpectedConstant {
+                location,
+                found,
+            }) => Self::format_line(context, "E0159", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::MatchBranchPatternInvalidType {
+                location,
+                expected,
+                found,
+                reference,
+            }) => Self::format_line_with_reference(
+                context,
+                "E0160",
+                &[expected.to_string(), found.to_string()],
+                location,
+                Some(reference),
+                None,
+            ),
+            Self::Semantic(SemanticError::MatchBranchExpressionInvalidType {
+                location,
+                expected,
+                found,
+                reference,
+            }) => Self::format_line_with_reference(
+                context,
+                "E0161",
+                &[expected.to_string(), found.to_string()],
+                location,
+                Some(reference),
+                None,
+            ),
+            Self::Semantic(SemanticError::MatchBranchDuplicate {
+                location,
+                reference,
+            }) => Self::format_line_with_reference(
+                context,
+                "E0162",
+                &[],
+                location,
+                Some(reference),
+                None,
+            ),
 
-            Self::Semantic(SemanticError::LoopWhileExpectedBooleanCondition { location, found }) => {
-                Self::format_line(
-                    context,
-                    format!("expected `bool`, found `{}`", found).as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::LoopBoundsExpectedConstantRangeExpression { location, found }) => {
-                Self::format_line(
-                    context,
-                    format!("expected a constant range expression, found `{}`", found).as_str(),
-                    location,
-                    Some("only constant ranges allowed, e.g. `for i in 0..42 { ... }`"),
-                )
-            }
+            Self::Semantic(SemanticError::LoopWhileExpectedBooleanCondition {
+                location,
+                found,
+            }) => Self::format_line(context, "E0163", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::LoopBoundsExpectedConstantRangeExpression {
+                location,
+                found,
+            }) => Self::format_line(context, "E0164", &[found.to_string()], location, None),
 
-            Self::Semantic(SemanticError::ConditionalExpectedBooleanCondition { location, found }) => {
-                Self::format_line(
-                    context,
-                    format!("expected `bool`, found `{}`", found).as_str(),
-                    location,
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::ConditionalBranchTypesMismatch { location, expected, found, reference }) => {
-                Self::format_line_with_reference(
-                    context,
-                    format!("if and else branches return incompatible types `{}` and `{}`", expected, found).as_str(),
-                    location,
-                    Some(reference),
-                    None,
-                )
-            }
-            Self::Semantic(SemanticError::EntryPointMissing) => {
-                Self::format_message(
-                    "function `main` is missing",
-                    Some("create the `main` function in the entry point file `main.zn`"),
-                )
-            }
-            Self::Semantic(SemanticError::ModuleNotFound { location, name }) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "file not found for module `{}`",
-                        name
-                    )
-                        .as_str(),
-                    location,
-                    Some(format!("create a file called `{}.zn` inside the `src` directory", name).as_str()),
-                )
+            Self::Semantic(SemanticError::ConditionalExpectedBooleanCondition {
+                location,
+                found,
+            }) => Self::format_line(context, "E0163", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::ConditionalBranchTypesMismatch {
+                location,
+                expected,
+                found,
+                reference,
+            }) => Self::format_line_with_reference(
+                context,
+                "E0165",
+                &[expected.to_string(), found.to_string()],
+                location,
+                Some(reference),
+                None,
+            ),
+            Self::Semantic(SemanticError::ConditionalPatternIrrefutable { location }) => {
+                Self::format_line(context, "E0188", &[], location, None)
             }
+            Self::Semantic(SemanticError::EntryPointMissing) => Self::format_message("E0166", &[]),
+            Self::Semantic(SemanticError::ModuleNotFound { location, name }) => Self::format_line(
+                context,
+                "E0167",
+                &[name.to_string(), name.to_string()],
+                location,
+                None,
+            ),
             Self::Semantic(SemanticError::UseExpectedPath { location, found }) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "`use` expected an item path, but got `{}`",
-                        found
-                    )
-                        .as_str(),
-                    location,
-                    Some("consider specifying a valid path to an item to import"),
-                )
+                Self::format_line(context, "E0168", &[found.to_string()], location, None)
+            }
+            Self::Semantic(SemanticError::ImplStatementExpectedStructureOrEnumeration {
+                location,
+                found,
+            }) => Self::format_line(context, "E0169", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::ConstantExpressionHasNonConstantElement {
+                location,
+                found,
+            }) => Self::format_line(context, "E0170", &[found.to_string()], location, None),
+            Self::Semantic(SemanticError::AttributeUnknown { location, found }) => {
+                Self::format_line(context, "E0171", &[found.to_string()], location, None)
             }
-            Self::Semantic(SemanticError::ImplStatementExpectedStructureOrEnumeration { location, found }) => {
-                Self::format_line(
-                    context,
-                    format!(
-                        "`impl` expected a type with namespace, found `{}`",
-                        found
-                    )
-                        .as_str(),
-                    location,
-                    Some("only structures and enumerations can have an implementation"),
-                )
+            Self::Semantic(SemanticError::TestFunctionInvalidSignature { location, function }) => {
+                Self::format_line(context, "E0172", &[function.to_string()], location, None)
             }
-            Self::Semantic(SemanticError::ConstantExpressionHasNonConstantElement { location, found }) => {
-                Self::format_line(
-                    context,
-                    format!("attempt to use a non-constant value `{}` in a constant expression", found).as_str(),
-                    location,
-                    None,
-                )
+            Self::Semantic(SemanticError::FunctionArgumentPublicOutsideMain {
+                location,
+                function,
+            }) => Self::format_line(context, "E0174", &[function.to_string()], location, None),
+            Self::Semantic(SemanticError::ExternAbiUnsupported { location, abi }) => {
+                Self::format_line(context, "E0178", &[abi.to_string()], location, None)
             }
+            Self::Semantic(SemanticError::ExternFunctionTypeUnsupported {
+                location,
+                function,
+                found,
+            }) => Self::format_line(
+                context,
+                "E0179",
+                &[function.to_string(), found.to_string()],
+                location,
+                None,
+            ),
         }
     }
 
-    fn format_message(message: &str, help: Option<&str>) -> String {
+    ///
+    /// Looks up the message and help of the diagnostic `code` in the message catalog.
+    ///
+    /// If the catalog entry has no help of its own, the `hint` code passed by the
+    /// syntax analyzer is looked up instead.
+    ///
+    fn describe(code: &str, arguments: &[String], hint: Option<&str>) -> (String, Option<String>) {
+        let catalog = CATALOG.read().expect(crate::PANIC_MUTEX_SYNC);
+
+        let message = catalog.message(code, arguments);
+        let help = catalog
+            .help(code, arguments)
+            .or_else(|| hint.map(|hint| catalog.message(hint, &[])));
+
+        (message, help)
+    }
+
+    fn format_message(code: &str, arguments: &[String]) -> String {
+        let (message, help) = Self::describe(code, arguments, None);
+
         let mut strings = Vec::with_capacity(8);
         strings.push(String::new());
         strings.push(format!(
             "{}: {}",
-            "error".bright_red(),
+            format!("error[{}]", code).bright_red(),
             message.bright_white()
         ));
         if let Some(help) = help {
@@ -2141,17 +2346,19 @@ impl Error {
 
     fn format_line(
         context: &[&str],
-        message: &str,
+        code: &str,
+        arguments: &[String],
         location: Location,
-        help: Option<&str>,
+        hint: Option<&str>,
     ) -> String {
+        let (message, help) = Self::describe(code, arguments, hint);
         let line_number_length = location.line.to_string().len();
 
         let mut strings = Vec::with_capacity(8);
         strings.push(String::new());
         strings.push(format!(
             "{}: {}",
-            "error".bright_red(),
+            format!("error[{}]", code).bright_red(),
             message.bright_white()
         ));
         strings.push(format!(" {} {}", "-->".bright_cyan(), location));
@@ -2183,18 +2390,20 @@ impl Error {
 
     fn format_line_with_reference(
         context: &[&str],
-        message: &str,
+        code: &str,
+        arguments: &[String],
         location: Location,
         reference: Option<Location>,
-        help: Option<&str>,
+        hint: Option<&str>,
     ) -> String {
+        let (message, help) = Self::describe(code, arguments, hint);
         let line_number_length = location.line.to_string().len();
 
         let mut strings = Vec::with_capacity(11);
         strings.push(String::new());
         strings.push(format!(
             "{}: {}",
-            "error".bright_red(),
+            format!("error[{}]", code).bright_red(),
             message.bright_white()
         ));
 
@@ -2252,18 +2461,20 @@ impl Error {
 
     fn format_range(
         context: &[&str],
-        message: &'static str,
+        code: &str,
+        arguments: &[String],
         start: Location,
         end: Location,
-        help: Option<&str>,
+        hint: Option<&str>,
     ) -> String {
+        let (message, help) = Self::describe(code, arguments, hint);
         let line_number_length = end.line.to_string().len();
 
         let mut strings = Vec::with_capacity(8 + end.line - start.line);
         strings.push(String::new());
         strings.push(format!(
             "{}: {}",
-            "error".bright_red(),
+            format!("error[{}]", code).bright_red(),
             message.bright_white()
         ));
         strings.push(format!(" {} {}", "-->".bright_cyan(), start));
@@ -0,0 +1,31 @@
+//!
+//! The extension registration error.
+//!
+
+use std::fmt;
+
+use zinc_bytecode::data::types::DataType;
+
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    InvalidIdentifier(String),
+    UnsupportedType {
+        identifier: String,
+        r#type: DataType,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidIdentifier(identifier) => {
+                write!(f, "`{}` is not a valid function identifier", identifier)
+            }
+            Self::UnsupportedType { identifier, r#type } => write!(
+                f,
+                "the extension `{}` signature contains the type `{}`, but only the scalars, arrays, and tuples are supported",
+                identifier, r#type
+            ),
+        }
+    }
+}
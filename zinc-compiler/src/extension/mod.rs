@@ -0,0 +1,145 @@
+//!
+//! The native gadget extensions.
+//!
+
+mod tests;
+
+pub mod error;
+
+use std::sync::RwLock;
+
+use lazy_static::lazy_static;
+
+use zinc_bytecode::data::types::DataType;
+use zinc_bytecode::data::types::ScalarType;
+
+use crate::semantic::element::r#type::Type;
+
+use self::error::Error;
+
+lazy_static! {
+    static ref EXTENSIONS: RwLock<Vec<Extension>> = RwLock::new(Vec::new());
+}
+
+///
+/// The signature of a native gadget implemented outside of the compiler.
+///
+/// The registered extensions are declared as the `std::ext` module functions, and their
+/// calls are compiled into the `call_extension` instructions. The gadget itself must be
+/// registered in the VM with the same identifier.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Extension {
+    pub identifier: String,
+    pub arguments: Vec<(String, DataType)>,
+    pub output: DataType,
+}
+
+impl Extension {
+    pub fn new(identifier: String, arguments: Vec<(String, DataType)>, output: DataType) -> Self {
+        Self {
+            identifier,
+            arguments,
+            output,
+        }
+    }
+
+    ///
+    /// Declares the extension for the circuits compiled afterwards, replacing the one
+    /// with the same identifier if it exists.
+    ///
+    pub fn register(self) -> Result<(), Error> {
+        let is_identifier_valid = self
+            .identifier
+            .chars()
+            .next()
+            .map(|first| first.is_ascii_alphabetic() || first == '_')
+            .unwrap_or_default()
+            && self
+                .identifier
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_');
+        if !is_identifier_valid {
+            return Err(Error::InvalidIdentifier(self.identifier));
+        }
+
+        for r#type in self
+            .arguments
+            .iter()
+            .map(|(_name, r#type)| r#type)
+            .chain(std::iter::once(&self.output))
+        {
+            if Self::semantic_type(r#type).is_none() {
+                return Err(Error::UnsupportedType {
+                    identifier: self.identifier,
+                    r#type: r#type.to_owned(),
+                });
+            }
+        }
+
+        let mut extensions = EXTENSIONS.write().expect(crate::PANIC_MUTEX_SYNC);
+        extensions.retain(|extension| extension.identifier != self.identifier);
+        extensions.push(self);
+        Ok(())
+    }
+
+    ///
+    /// Removes all the registered extensions.
+    ///
+    pub fn clear() {
+        EXTENSIONS.write().expect(crate::PANIC_MUTEX_SYNC).clear();
+    }
+
+    ///
+    /// Returns the registered extensions in the order of registration.
+    ///
+    pub fn all() -> Vec<Self> {
+        EXTENSIONS.read().expect(crate::PANIC_MUTEX_SYNC).clone()
+    }
+
+    ///
+    /// Returns the argument names and types and the return type of the extension function.
+    ///
+    pub(crate) fn signature(&self) -> (Vec<(String, Type)>, Type) {
+        let arguments = self
+            .arguments
+            .iter()
+            .map(|(name, r#type)| {
+                (
+                    name.to_owned(),
+                    Self::semantic_type(r#type).expect(crate::PANIC_EXTENSION_TYPE),
+                )
+            })
+            .collect();
+        let output = Self::semantic_type(&self.output).expect(crate::PANIC_EXTENSION_TYPE);
+
+        (arguments, output)
+    }
+
+    ///
+    /// The structures and enumerations are declared in the circuit scope, so they cannot
+    /// be used in the extension signatures, as well as the integers of invalid bitlength.
+    ///
+    fn semantic_type(r#type: &DataType) -> Option<Type> {
+        Some(match r#type {
+            DataType::Unit => Type::unit(),
+            DataType::Scalar(ScalarType::Boolean) => Type::boolean(),
+            DataType::Scalar(ScalarType::Field) => Type::field(),
+            DataType::Scalar(ScalarType::Integer(integer_type))
+                if integer_type.bitlength > 0
+                    && integer_type.bitlength <= crate::BITLENGTH_MAX_INT
+                    && integer_type.bitlength % crate::BITLENGTH_BYTE == 0 =>
+            {
+                Type::integer(integer_type.is_signed, integer_type.bitlength)
+            }
+            DataType::Array(r#type, size) => Type::array(Self::semantic_type(r#type)?, *size),
+            DataType::Tuple(types) => Type::tuple(
+                types
+                    .iter()
+                    .map(Self::semantic_type)
+                    .collect::<Option<Vec<Type>>>()?,
+            ),
+            _ => return None,
+        })
+    }
+}
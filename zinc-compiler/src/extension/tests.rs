@@ -0,0 +1,153 @@
+//!
+//! The extension tests.
+//!
+
+#![cfg(test)]
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use zinc_bytecode::data::types::DataType;
+use zinc_bytecode::data::types::ScalarType;
+use zinc_bytecode::Artifact;
+use zinc_bytecode::CallExtension;
+use zinc_bytecode::Instruction;
+use zinc_bytecode::InstructionInfo;
+
+use crate::error::Error;
+use crate::extension::error::Error as ExtensionError;
+use crate::extension::Extension;
+use crate::generator::bytecode::Bytecode;
+use crate::lexical::token::location::Location;
+use crate::semantic::analyzer::entry::Analyzer as EntryAnalyzer;
+use crate::semantic::element::error::Error as ElementError;
+use crate::semantic::element::r#type::error::Error as TypeError;
+use crate::semantic::element::r#type::function::error::Error as FunctionTypeError;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::error::Error as SemanticError;
+use crate::Parser;
+
+fn register_hash(identifier: &str) {
+    Extension::new(
+        identifier.to_owned(),
+        vec![(
+            "preimage".to_owned(),
+            DataType::Array(Box::new(DataType::Scalar(ScalarType::Field)), 2),
+        )],
+        DataType::Scalar(ScalarType::Field),
+    )
+    .register()
+    .expect(crate::semantic::tests::PANIC_TEST_DATA);
+}
+
+#[test]
+fn ok_call() {
+    register_hash("ok_call_hash");
+
+    let input = r#"
+fn main(a: field, b: field) -> field {
+    std::ext::ok_call_hash([a, b])
+}
+"#;
+
+    let intermediate = EntryAnalyzer::default()
+        .compile(
+            Parser::default()
+                .parse(input, None)
+                .expect(crate::semantic::tests::PANIC_TEST_DATA),
+            HashMap::new(),
+        )
+        .expect(crate::semantic::tests::PANIC_TEST_DATA);
+    let bytecode = Rc::new(RefCell::new(Bytecode::new()));
+    intermediate.write_all_to_bytecode(bytecode.clone());
+    let bytecode = Rc::try_unwrap(bytecode)
+        .expect(crate::PANIC_LAST_SHARED_REFERENCE)
+        .into_inner();
+
+    let program = Artifact::from_bytes(bytecode.into_bytes().as_slice())
+        .expect(crate::semantic::tests::PANIC_TEST_DATA)
+        .program;
+    assert!(program
+        .bytecode
+        .contains(&CallExtension::new("ok_call_hash".to_owned(), 2, 1).wrap()));
+    assert!(!program
+        .bytecode
+        .iter()
+        .any(|instruction| matches!(instruction, Instruction::CallBuiltin(_))));
+}
+
+#[test]
+fn error_argument_type() {
+    register_hash("error_argument_type_hash");
+
+    let input = r#"
+fn main(a: field) -> field {
+    std::ext::error_argument_type_hash([a, a, a])
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 39),
+        ElementError::Type(TypeError::Function(FunctionTypeError::argument_type(
+            "error_argument_type_hash".to_owned(),
+            "preimage".to_owned(),
+            1,
+            Type::array(Type::field(), 2).to_string(),
+            Type::array(Type::field(), 3).to_string(),
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_argument_count() {
+    register_hash("error_argument_count_hash");
+
+    let input = r#"
+fn main(a: field) -> field {
+    std::ext::error_argument_count_hash()
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 40),
+        ElementError::Type(TypeError::Function(FunctionTypeError::argument_count(
+            "error_argument_count_hash".to_owned(),
+            1,
+            0,
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_invalid_identifier() {
+    let result = Extension::new("1hash".to_owned(), vec![], DataType::Unit).register();
+
+    assert_eq!(
+        result,
+        Err(ExtensionError::InvalidIdentifier("1hash".to_owned()))
+    );
+}
+
+#[test]
+fn error_unsupported_type() {
+    let r#type = DataType::Struct(vec![("x".to_owned(), DataType::Scalar(ScalarType::Field))]);
+
+    let result = Extension::new("point".to_owned(), vec![], r#type.clone()).register();
+
+    assert_eq!(
+        result,
+        Err(ExtensionError::UnsupportedType {
+            identifier: "point".to_owned(),
+            r#type,
+        })
+    );
+}
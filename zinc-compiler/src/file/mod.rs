@@ -16,6 +16,7 @@ use crate::generator::bytecode::Bytecode;
 use crate::semantic::analyzer::entry::Analyzer as EntryAnalyzer;
 use crate::semantic::analyzer::module::Analyzer as ModuleAnalyzer;
 use crate::semantic::scope::Scope;
+use crate::syntax::filter::Filter;
 use crate::syntax::parser::Parser;
 use crate::syntax::tree::statement::local_mod::Statement;
 
@@ -46,6 +47,8 @@ impl File {
             .expect(crate::PANIC_MUTEX_SYNC)
             .push(self.path);
 
+        bytecode.borrow_mut().hash_source(&self.code);
+
         let syntax_tree = Parser::default()
             .parse(&self.code, Some(next_file_id))
             .map_err(|error| error.format(&lines))?;
@@ -60,6 +63,7 @@ impl File {
 
     pub fn try_into_module(
         self,
+        name: String,
         bytecode: Rc<RefCell<Bytecode>>,
         dependencies: HashMap<String, Rc<RefCell<Scope>>>,
     ) -> Result<Rc<RefCell<Scope>>, String> {
@@ -71,11 +75,13 @@ impl File {
             .expect(crate::PANIC_MUTEX_SYNC)
             .push(self.path);
 
+        bytecode.borrow_mut().hash_source(&self.code);
+
         let syntax_tree = Parser::default()
             .parse(&self.code, Some(next_file_id))
             .map_err(|error| error.format(&lines))?;
 
-        let (scope, intermediate) = ModuleAnalyzer::new()
+        let (scope, intermediate) = ModuleAnalyzer::new(name)
             .compile(syntax_tree, dependencies)
             .map_err(|error| error.format(&lines))?;
 
@@ -96,6 +102,7 @@ impl File {
         let syntax_tree = Parser::default()
             .parse(&self.code, Some(next_file_id))
             .map_err(|error| error.format(&lines))?;
+        let syntax_tree = Filter::default().filter(syntax_tree);
 
         Ok(syntax_tree
             .statements
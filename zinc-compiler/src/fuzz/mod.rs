@@ -0,0 +1,76 @@
+//!
+//! The fuzzing entry points.
+//!
+//! The functions never panic on invalid input, returning the formatted compiler error
+//! instead, so any panic found by a fuzzer is a compiler bug.
+//!
+
+mod tests;
+
+use std::collections::HashMap;
+use std::panic;
+use std::thread;
+
+use crate::semantic::analyzer::entry::Analyzer as EntryAnalyzer;
+use crate::syntax::parser::Parser;
+
+///
+/// The stack size of the analysis thread.
+///
+/// The parser limits the nesting depth, but the debug builds, which the fuzzers usually
+/// run, need much more stack for the maximal depth than the default thread stack.
+///
+const STACK_SIZE: usize = 256 * 1024 * 1024;
+
+static PANIC_THREAD_SPAWN: &str = "The analysis thread spawning never fails";
+
+///
+/// Runs the lexical and syntax analysis of the `input`.
+///
+pub fn parse_source(input: &str) -> Result<(), String> {
+    let input = input.to_owned();
+    run(move || {
+        let lines = input.lines().collect::<Vec<&str>>();
+
+        Parser::default()
+            .parse(input.as_str(), None)
+            .map(|_tree| ())
+            .map_err(|error| error.format(&lines))
+    })
+}
+
+///
+/// Runs the lexical, syntax, and semantic analysis of the `input` as the entry file.
+///
+pub fn analyze_source(input: &str) -> Result<(), String> {
+    let input = input.to_owned();
+    run(move || {
+        let lines = input.lines().collect::<Vec<&str>>();
+
+        let syntax_tree = Parser::default()
+            .parse(input.as_str(), None)
+            .map_err(|error| error.format(&lines))?;
+
+        EntryAnalyzer::new()
+            .compile(syntax_tree, HashMap::new())
+            .map(|_intermediate| ())
+            .map_err(|error| error.format(&lines))
+    })
+}
+
+///
+/// Runs the `analysis` on a thread with the large stack.
+///
+/// The panics are resumed on the calling thread, so the fuzzer reports them as usual.
+///
+fn run<F>(analysis: F) -> Result<(), String>
+where
+    F: FnOnce() -> Result<(), String> + Send + 'static,
+{
+    thread::Builder::new()
+        .stack_size(STACK_SIZE)
+        .spawn(analysis)
+        .expect(PANIC_THREAD_SPAWN)
+        .join()
+        .unwrap_or_else(|payload| panic::resume_unwind(payload))
+}
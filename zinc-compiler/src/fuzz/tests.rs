@@ -0,0 +1,98 @@
+//!
+//! The fuzzing entry points tests.
+//!
+
+#![cfg(test)]
+
+use crate::fuzz;
+use crate::syntax::parser::nesting::Nesting;
+
+///
+/// Wraps the `expression` into the `main` function.
+///
+fn program(expression: &str) -> String {
+    format!("fn main() -> field {{ {} }}", expression)
+}
+
+#[test]
+fn ok_parse() {
+    let input = program("42 as field");
+
+    assert_eq!(fuzz::parse_source(input.as_str()), Ok(()));
+}
+
+#[test]
+fn ok_analyze() {
+    let input = program("42 as field");
+
+    assert_eq!(fuzz::analyze_source(input.as_str()), Ok(()));
+}
+
+#[test]
+fn ok_nesting_within_limit() {
+    let depth = Nesting::LIMIT - 2;
+    let input = program(format!("{}42 as field{}", "(".repeat(depth), ")".repeat(depth)).as_str());
+
+    assert_eq!(fuzz::analyze_source(input.as_str()), Ok(()));
+}
+
+#[test]
+fn error_parse() {
+    let input = "fn main() -> field { 42 as field";
+
+    assert!(fuzz::parse_source(input).is_err());
+}
+
+#[test]
+fn error_analyze() {
+    let input = "fn helper() {}";
+
+    assert!(fuzz::parse_source(input).is_ok());
+    assert!(fuzz::analyze_source(input).is_err());
+}
+
+#[test]
+fn error_nesting_parentheses() {
+    let input = program(format!("{}42{}", "(".repeat(10_000), ")".repeat(10_000)).as_str());
+
+    let result = fuzz::parse_source(input.as_str());
+
+    assert!(result.expect_err("Nesting error").contains("E0173"));
+}
+
+#[test]
+fn error_nesting_unary() {
+    let input = program(format!("{}42", "-".repeat(10_000)).as_str());
+
+    let result = fuzz::parse_source(input.as_str());
+
+    assert!(result.expect_err("Nesting error").contains("E0173"));
+}
+
+#[test]
+fn error_nesting_type() {
+    let input = format!(
+        "type Deep = {}u8{};",
+        "[".repeat(10_000),
+        "; 1]".repeat(10_000)
+    );
+
+    let result = fuzz::parse_source(input.as_str());
+
+    assert!(result.expect_err("Nesting error").contains("E0173"));
+}
+
+#[test]
+fn error_nesting_conditional() {
+    let input = program(
+        format!(
+            "if true {{ 0 }}{} else {{ 0 }}",
+            " else if true { 0 }".repeat(10_000)
+        )
+        .as_str(),
+    );
+
+    let result = fuzz::parse_source(input.as_str());
+
+    assert!(result.expect_err("Nesting error").contains("E0173"));
+}
@@ -4,13 +4,26 @@
 
 use std::collections::HashMap;
 
+use num_bigint::BigInt;
+use num_traits::Zero;
+
 use zinc_bytecode::data::types::DataType;
+use zinc_bytecode::data::types::ScalarType;
 use zinc_bytecode::data::values::Value as TemplateValue;
+use zinc_bytecode::library::Symbol;
+use zinc_bytecode::Artifact;
 use zinc_bytecode::Instruction;
+use zinc_bytecode::Library;
 use zinc_bytecode::Program;
 
+use crate::generator::inliner::Hint as InlineHint;
+use crate::generator::inliner::Inliner;
+use crate::generator::manifest::Manifest as TestManifest;
+use crate::generator::manifest::Test;
+use crate::generator::peephole::Optimizer;
 use crate::generator::r#type::Type;
 use crate::lexical::token::location::Location;
+use crate::prelude::Prelude;
 
 static PANIC_JSON_TEMPLATE_SERIALIZATION: &str =
     "JSON templates serialization must be always successful: ";
@@ -21,15 +34,25 @@ static PANIC_JSON_TEMPLATE_SERIALIZATION: &str =
 #[derive(Debug, PartialEq)]
 pub struct Bytecode {
     input_fields: Vec<(String, Type)>,
+    public_input: Vec<String>,
     output_type: Type,
     instructions: Vec<Instruction>,
 
     data_stack_pointer: usize,
     variable_addresses: HashMap<String, usize>,
+    /// The variable addresses of the enclosing blocks, restored when the inner blocks end.
+    outer_variable_addresses: Vec<HashMap<String, usize>>,
     function_addresses: HashMap<usize, usize>,
+    unresolved_calls: Vec<(usize, usize)>,
+    test_functions: Vec<(String, usize)>,
+    inliner: Inliner,
+    optimization_level: usize,
 
     current_file: String,
-    current_location: Location,
+    /// The location set by the last markers, which is unset at the start of every function,
+    /// so its first instruction is always preceded by the markers, even on the first line.
+    current_location: Option<Location>,
+    sources: Vec<String>,
 }
 
 impl Default for Bytecode {
@@ -42,6 +65,7 @@ impl Bytecode {
     const INSTRUCTION_VECTOR_INITIAL_SIZE: usize = 1024;
     const FUNCTION_ADDRESSES_HASHMAP_INITIAL_SIZE: usize = 16;
     const VARIABLE_ADDRESSES_HASHMAP_INITIAL_SIZE: usize = 16;
+    const ENTRY_INSTRUCTIONS_COUNT: usize = 2;
 
     pub fn new() -> Self {
         let mut instructions = Vec::with_capacity(Self::INSTRUCTION_VECTOR_INITIAL_SIZE);
@@ -50,6 +74,7 @@ impl Bytecode {
 
         Self {
             input_fields: vec![],
+            public_input: vec![],
             output_type: Type::structure(vec![]),
             instructions,
 
@@ -57,19 +82,36 @@ impl Bytecode {
             variable_addresses: HashMap::with_capacity(
                 Self::VARIABLE_ADDRESSES_HASHMAP_INITIAL_SIZE,
             ),
+            outer_variable_addresses: Vec::new(),
             function_addresses: HashMap::with_capacity(
                 Self::FUNCTION_ADDRESSES_HASHMAP_INITIAL_SIZE,
             ),
+            unresolved_calls: Vec::new(),
+            test_functions: Vec::new(),
+            inliner: Inliner::new(),
+            optimization_level: Optimizer::LEVEL_NONE,
 
             current_file: String::new(),
-            current_location: Location::new_beginning(None),
+            current_location: None,
+            sources: Vec::new(),
         }
     }
 
+    pub fn set_optimization_level(&mut self, level: usize) {
+        self.optimization_level = level;
+    }
+
     pub fn start_new_file(&mut self, name: &str) {
         self.current_file = name.to_owned();
     }
 
+    ///
+    /// Adds the source code of the compiled file to the artifact source hash.
+    ///
+    pub fn hash_source(&mut self, code: &str) {
+        self.sources.push(code.to_owned());
+    }
+
     pub fn start_function(&mut self, unique_id: usize, identifier: String) {
         let address = self.instructions.len();
         self.function_addresses.insert(unique_id, address);
@@ -81,12 +123,21 @@ impl Bytecode {
         self.instructions.push(Instruction::FunctionMarker(
             zinc_bytecode::FunctionMarker::new(identifier),
         ));
+        self.current_location = None;
+    }
+
+    ///
+    /// Adds the function to the unit test manifest.
+    ///
+    pub fn declare_test_function(&mut self, name: String, unique_id: usize) {
+        self.test_functions.push((name, unique_id));
     }
 
     pub fn start_main_function(
         &mut self,
         unique_id: usize,
         input_arguments: Vec<(String, Type)>,
+        public_arguments: Vec<String>,
         output_type: Option<Type>,
     ) {
         let input_size = input_arguments
@@ -99,6 +150,7 @@ impl Bytecode {
             .unwrap_or(0);
 
         self.input_fields = input_arguments;
+        self.public_input = public_arguments;
         self.output_type = output_type.unwrap_or_else(|| Type::structure(vec![]));
 
         let address = self.instructions.len();
@@ -116,6 +168,26 @@ impl Bytecode {
                     .to_owned(),
             ),
         ));
+        self.current_location = None;
+    }
+
+    ///
+    /// Passes the body of the function written last to the inliner.
+    ///
+    /// Must be called right after the function `Return` instruction is written.
+    ///
+    pub fn end_function(&mut self, unique_id: usize, hint: InlineHint) {
+        if self.optimization_level < Optimizer::LEVEL_PEEPHOLE {
+            return;
+        }
+
+        let address = match self.function_addresses.get(&unique_id) {
+            Some(address) => *address,
+            None => return,
+        };
+        let body = &self.instructions[address + 2..self.instructions.len() - 1];
+        self.inliner
+            .record(unique_id, body, self.data_stack_pointer, hint);
     }
 
     pub fn declare_variable(&mut self, identifier: Option<String>, r#type: Type) -> usize {
@@ -128,26 +200,166 @@ impl Bytecode {
         start_address
     }
 
+    ///
+    /// Binds the `identifier` to the variable declared at the `address`.
+    ///
+    /// The variables are bound after their initializers are written, since the initializer
+    /// may refer to the variable being shadowed, e.g. `let x = x + 1;`.
+    ///
+    pub fn bind_variable(&mut self, identifier: String, address: usize) {
+        self.variable_addresses.insert(identifier, address);
+    }
+
+    ///
+    /// Starts a block, whose variables may shadow the ones declared outside it.
+    ///
+    pub fn start_block(&mut self) {
+        self.outer_variable_addresses
+            .push(self.variable_addresses.clone());
+    }
+
+    ///
+    /// Ends the block started last, making the shadowed variables visible again.
+    ///
+    pub fn end_block(&mut self) {
+        if let Some(variable_addresses) = self.outer_variable_addresses.pop() {
+            self.variable_addresses = variable_addresses;
+        }
+    }
+
     pub fn push_instruction(&mut self, instruction: Instruction, location: Option<Location>) {
+        if let Instruction::Cast(ref cast) = instruction {
+            if self.merge_constant_cast(cast.scalar_type) {
+                return;
+            }
+        }
+
         if let Some(location) = location {
-            if self.current_location != location {
-                if self.current_location.line != location.line {
-                    self.instructions.push(Instruction::LineMarker(
-                        zinc_bytecode::LineMarker::new(location.line),
-                    ));
-                }
-                if self.current_location.column != location.column {
-                    self.instructions.push(Instruction::ColumnMarker(
-                        zinc_bytecode::ColumnMarker::new(location.column),
-                    ));
-                }
-                self.current_location = location;
+            let current = self.current_location;
+            if current.map(|current| current.line) != Some(location.line) {
+                self.instructions.push(Instruction::LineMarker(
+                    zinc_bytecode::LineMarker::new(location.line),
+                ));
+            }
+            if current.map(|current| current.column) != Some(location.column) {
+                self.instructions.push(Instruction::ColumnMarker(
+                    zinc_bytecode::ColumnMarker::new(location.column),
+                ));
             }
+            self.current_location = Some(location);
         }
 
         self.instructions.push(instruction)
     }
 
+    ///
+    /// Turns the constant pushed right before the cast into the constant of the target type,
+    /// so the cast and its type check are not executed at runtime.
+    ///
+    /// Returns `true` if the cast has been merged and must not be written.
+    ///
+    fn merge_constant_cast(&mut self, target: ScalarType) -> bool {
+        let previous = self.instructions.iter_mut().rev().find(|instruction| {
+            !matches!(
+                instruction,
+                Instruction::LineMarker(_) | Instruction::ColumnMarker(_)
+            )
+        });
+        let constant = match previous {
+            Some(Instruction::PushConst(constant)) => constant,
+            _ => return false,
+        };
+
+        let is_value_valid = match target {
+            ScalarType::Field => constant.value >= BigInt::zero(),
+            ScalarType::Boolean => false,
+            ScalarType::Integer(integer_type) => {
+                constant.value >= integer_type.min() && constant.value <= integer_type.max()
+            }
+        };
+        if !is_value_valid {
+            return false;
+        }
+
+        constant.scalar_type = target;
+        true
+    }
+
+    ///
+    /// Writes the function call, or the function body if the function is inlined.
+    ///
+    /// The prelude functions are not written until the end of the program, so the calls
+    /// to them are resolved when the prelude is linked, along with the calls to the functions
+    /// written later.
+    ///
+    pub fn push_call(&mut self, unique_id: usize, input_size: usize, location: Location) {
+        let base = self.data_stack_pointer;
+        if let Some((instructions, frame_size)) = self.inliner.expand(unique_id, base, input_size)
+        {
+            self.data_stack_pointer += frame_size;
+            for instruction in instructions.into_iter() {
+                self.push_instruction(instruction, Some(location));
+            }
+            return;
+        }
+
+        let address = self.get_function_address(unique_id);
+        self.push_instruction(
+            Instruction::Call(zinc_bytecode::Call::new(
+                address.unwrap_or_default(),
+                input_size,
+            )),
+            Some(location),
+        );
+        if address.is_none() {
+            self.unresolved_calls
+                .push((self.instructions.len() - 1, unique_id));
+        }
+    }
+
+    ///
+    /// Appends the precompiled library bytecode and declares its functions, which are
+    /// the library symbols with the `unique_ids` the semantic analyzer knows them by.
+    ///
+    pub fn link_library(&mut self, library: &Library, unique_ids: &[(usize, &Symbol)]) {
+        let offset = self.instructions.len();
+        self.instructions.extend(library.relocated(offset));
+        for (unique_id, symbol) in unique_ids.iter() {
+            self.function_addresses
+                .insert(*unique_id, offset + symbol.address);
+        }
+
+        // the markers must be written again for the code following the library
+        self.current_location = None;
+    }
+
+    ///
+    /// Resolves the calls written before their callees, linking the prelude if any of its
+    /// functions are called.
+    ///
+    /// Besides the prelude functions, the callees may be the functions declared after the
+    /// generic function instances calling them.
+    ///
+    fn link_prelude(&mut self) {
+        let is_prelude_called = self
+            .unresolved_calls
+            .iter()
+            .any(|(_index, unique_id)| !self.function_addresses.contains_key(unique_id));
+        if is_prelude_called {
+            let (_scope, unique_ids) = Prelude::declare();
+            self.link_library(Prelude::library(), unique_ids.as_slice());
+        }
+
+        for (index, unique_id) in std::mem::take(&mut self.unresolved_calls).into_iter() {
+            let address = self
+                .get_function_address(unique_id)
+                .expect(crate::PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS);
+            if let Instruction::Call(ref mut call) = self.instructions[index] {
+                call.address = address;
+            }
+        }
+    }
+
     pub fn get_function_address(&self, unique_id: usize) -> Option<usize> {
         self.function_addresses.get(&unique_id).copied()
     }
@@ -168,8 +380,7 @@ impl Bytecode {
     }
 
     pub fn output_template_bytes(&self) -> Vec<u8> {
-        let output_bytecode_type = self.output_type.to_owned().into();
-        let output_value_template = TemplateValue::default_from_type(&output_bytecode_type);
+        let output_value_template = TemplateValue::default_from_type(&self.public_data_type());
         match serde_json::to_string_pretty(&output_value_template.to_json()) {
             Ok(json) => (json + "\n").into_bytes(),
             Err(error) => {
@@ -178,7 +389,88 @@ impl Bytecode {
         }
     }
 
-    pub fn into_bytes(self) -> Vec<u8> {
+    ///
+    /// Describes the witness and public data JSON files with the JSON Schema, so the
+    /// frontends can generate the input forms and validate the data before proving.
+    ///
+    pub fn schema_bytes(&self) -> Vec<u8> {
+        let schema = serde_json::json!({
+            "input": self.input_types_as_struct().to_json_schema_document("witness"),
+            "output": self.public_data_type().to_json_schema_document("public data"),
+        });
+        match serde_json::to_string_pretty(&schema) {
+            Ok(json) => (json + "\n").into_bytes(),
+            Err(error) => {
+                panic!("{}{}", PANIC_JSON_TEMPLATE_SERIALIZATION, error)
+            }
+        }
+    }
+
+    ///
+    /// Runs the optimizer over the instructions according to the optimization level.
+    ///
+    pub fn optimize(&mut self) {
+        self.link_prelude();
+
+        let instructions = std::mem::take(&mut self.instructions);
+        let (instructions, addresses) = Optimizer::optimize(instructions, self.optimization_level);
+        self.instructions = instructions;
+
+        for address in self.function_addresses.values_mut() {
+            *address = addresses[*address];
+        }
+    }
+
+    ///
+    /// Lists the `#[test]` functions with their addresses.
+    ///
+    /// Must be called after the optimization, which shifts the function addresses.
+    ///
+    pub fn test_manifest(&mut self) -> TestManifest {
+        self.link_prelude();
+
+        let tests = self
+            .test_functions
+            .iter()
+            .filter_map(|(name, unique_id)| {
+                self.get_function_address(*unique_id).map(|address| Test {
+                    name: name.to_owned(),
+                    address,
+                })
+            })
+            .collect();
+
+        TestManifest { tests }
+    }
+
+    ///
+    /// Returns the program which calls the test function at `address` instead of `main`.
+    ///
+    /// The calls written before their callees are resolved first, since the test programs
+    /// may be written without the optimization.
+    ///
+    pub fn test_bytes(&mut self, address: usize) -> Vec<u8> {
+        self.link_prelude();
+
+        let mut instructions = self.instructions.clone();
+        instructions[0] = Instruction::Call(zinc_bytecode::Call::new(address, 0));
+        instructions[1] = Instruction::Exit(zinc_bytecode::Exit::new(0));
+
+        let program = Program::new(
+            Type::structure(vec![]).into(),
+            Type::structure(vec![]).into(),
+            instructions,
+        );
+
+        program.to_bytes()
+    }
+
+    ///
+    /// Writes the build artifact with the program, the source hash, and the source map.
+    ///
+    pub fn into_bytes(mut self) -> Vec<u8> {
+        self.link_prelude();
+
         for (index, instruction) in self.instructions.iter().enumerate() {
             log::debug!("{:03} {:?}", index, instruction)
         }
@@ -187,9 +479,67 @@ impl Bytecode {
             self.input_types_as_struct(),
             self.output_type.into(),
             self.instructions,
-        );
+        )
+        .with_public_input(self.public_input);
 
-        program.to_bytes()
+        let source_hash = Artifact::hash_sources(self.sources.iter().map(String::as_str));
+        Artifact::new(program, source_hash)
+            .with_source_map()
+            .to_bytes()
+    }
+
+    ///
+    /// Writes the library with the `symbols`, whose addresses are the function addresses
+    /// of this bytecode.
+    ///
+    /// The libraries have no entry, so the instructions calling `main` are left out.
+    ///
+    pub fn into_library(mut self, name: String, version: String, symbols: Vec<Symbol>) -> Library {
+        self.link_prelude();
+
+        let offset = Self::ENTRY_INSTRUCTIONS_COUNT;
+        let instructions = self
+            .instructions
+            .into_iter()
+            .skip(offset)
+            .map(|instruction| match instruction {
+                Instruction::Call(mut call) => {
+                    call.address -= offset;
+                    Instruction::Call(call)
+                }
+                instruction => instruction,
+            })
+            .collect();
+        let symbols = symbols
+            .into_iter()
+            .map(|mut symbol| {
+                symbol.address -= offset;
+                symbol
+            })
+            .collect();
+
+        Library::new(name, version, symbols, instructions)
+    }
+
+    ///
+    /// The public data is the output, preceded by the `pub` arguments of `main` and the
+    /// values exposed with `expose!` if any.
+    ///
+    fn public_data_type(&self) -> DataType {
+        let exposed = self
+            .instructions
+            .iter()
+            .filter(|instruction| matches!(instruction, Instruction::Expose(_)))
+            .cloned()
+            .collect();
+
+        Program::new(
+            self.input_types_as_struct(),
+            self.output_type.to_owned().into(),
+            exposed,
+        )
+        .with_public_input(self.public_input.clone())
+        .public_data_type()
     }
 
     fn input_types_as_struct(&self) -> DataType {
@@ -203,7 +553,8 @@ impl Bytecode {
 }
 
 impl Into<Vec<Instruction>> for Bytecode {
-    fn into(self) -> Vec<Instruction> {
+    fn into(mut self) -> Vec<Instruction> {
+        self.link_prelude();
         self.instructions
     }
 }
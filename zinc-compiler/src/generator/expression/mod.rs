@@ -10,16 +10,22 @@ use std::cell::RefCell;
 use std::rc::Rc;
 
 use num_bigint::BigInt;
+use num_traits::One;
+use num_traits::Zero;
 
 use zinc_bytecode::builtins::BuiltinIdentifier;
 use zinc_bytecode::data::types::DataType;
+use zinc_bytecode::data::types::IntegerType;
 use zinc_bytecode::data::types::ScalarType;
 use zinc_bytecode::Instruction;
 
 use crate::generator::bytecode::Bytecode;
 use crate::generator::expression::operand::constant::Constant;
 use crate::generator::expression::operand::place::Place;
+use crate::generator::r#type::Type;
 use crate::lexical::token::location::Location;
+use crate::semantic::element::r#type::function::stdlib::math_fixed::Function as MathFixedFunction;
+use crate::semantic::scope::builtin::BuiltInItems;
 
 use self::element::Element;
 use self::operand::Operand;
@@ -50,6 +56,23 @@ impl Expression {
         self.elements.push(Element::Operator { location, operator })
     }
 
+    ///
+    /// Inserts the `operator` at `index`, e.g. to cast the first operand of a binary operator
+    /// after the second one has been written.
+    ///
+    pub fn insert_operator(&mut self, index: usize, location: Location, operator: Operator) {
+        self.elements
+            .insert(index, Element::Operator { location, operator })
+    }
+
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
     pub fn write_all_to_bytecode(self, bytecode: Rc<RefCell<Bytecode>>) {
         for element in self.elements.into_iter() {
             match element {
@@ -151,6 +174,28 @@ impl Expression {
                             location,
                         )
                     }
+                    Operator::AssignmentDecimalMultiplication {
+                        place,
+                        expression,
+                        fraction_bits,
+                    } => Self::assignment_with_operations(
+                        bytecode.clone(),
+                        place,
+                        expression,
+                        Self::decimal_operation(BuiltinIdentifier::MathFixedMul, fraction_bits),
+                        location,
+                    ),
+                    Operator::AssignmentDecimalDivision {
+                        place,
+                        expression,
+                        fraction_bits,
+                    } => Self::assignment_with_operations(
+                        bytecode.clone(),
+                        place,
+                        expression,
+                        Self::decimal_operation(BuiltinIdentifier::MathFixedDiv, fraction_bits),
+                        location,
+                    ),
 
                     Operator::Or => Self::binary(
                         bytecode.clone(),
@@ -178,6 +223,17 @@ impl Expression {
                         Instruction::Ne(zinc_bytecode::Ne),
                         location,
                     ),
+                    Operator::EqualsComposite { size } => {
+                        Self::equals_composite(bytecode.clone(), size, location)
+                    }
+                    Operator::NotEqualsComposite { size } => {
+                        Self::equals_composite(bytecode.clone(), size, location);
+                        Self::unary(
+                            bytecode.clone(),
+                            Instruction::Not(zinc_bytecode::Not),
+                            location,
+                        );
+                    }
                     Operator::GreaterEquals => Self::binary(
                         bytecode.clone(),
                         Instruction::Ge(zinc_bytecode::Ge),
@@ -230,6 +286,7 @@ impl Expression {
                         Instruction::Add(zinc_bytecode::Add),
                         location,
                     ),
+                    Operator::Concatenation => {}
                     Operator::Subtraction => Self::binary(
                         bytecode.clone(),
                         Instruction::Sub(zinc_bytecode::Sub),
@@ -250,6 +307,23 @@ impl Expression {
                         Instruction::Rem(zinc_bytecode::Rem),
                         location,
                     ),
+                    Operator::DecimalMultiplication { fraction_bits } => {
+                        for instruction in
+                            Self::decimal_operation(BuiltinIdentifier::MathFixedMul, fraction_bits)
+                        {
+                            Self::binary(bytecode.clone(), instruction, location);
+                        }
+                    }
+                    Operator::DecimalDivision { fraction_bits } => {
+                        for instruction in
+                            Self::decimal_operation(BuiltinIdentifier::MathFixedDiv, fraction_bits)
+                        {
+                            Self::binary(bytecode.clone(), instruction, location);
+                        }
+                    }
+                    Operator::Exponentiation { exponent, r#type } => {
+                        Self::exponentiation(bytecode.clone(), exponent, r#type, location)
+                    }
 
                     Operator::Casting { r#type } => {
                         if let Some(scalar_type) = r#type.into() {
@@ -260,6 +334,56 @@ impl Expression {
                             )
                         }
                     }
+                    Operator::CastingToDecimal {
+                        r#type,
+                        fraction_bits,
+                    } => {
+                        if let Some(scalar_type) = r#type.into() {
+                            Self::unary(
+                                bytecode.clone(),
+                                Instruction::Cast(zinc_bytecode::Cast::new(scalar_type)),
+                                location,
+                            );
+                            Self::decimal_scale(
+                                bytecode.clone(),
+                                scalar_type,
+                                fraction_bits,
+                                location,
+                            );
+                            Self::binary(
+                                bytecode.clone(),
+                                Instruction::Mul(zinc_bytecode::Mul),
+                                location,
+                            );
+                        }
+                    }
+                    Operator::CastingFromDecimal {
+                        r#type,
+                        bitlength,
+                        fraction_bits,
+                    } => {
+                        Self::decimal_scale(
+                            bytecode.clone(),
+                            ScalarType::Integer(IntegerType {
+                                is_signed: false,
+                                bitlength,
+                            }),
+                            fraction_bits,
+                            location,
+                        );
+                        Self::binary(
+                            bytecode.clone(),
+                            Instruction::Div(zinc_bytecode::Div),
+                            location,
+                        );
+                        if let Some(scalar_type) = r#type.into() {
+                            Self::unary(
+                                bytecode.clone(),
+                                Instruction::Cast(zinc_bytecode::Cast::new(scalar_type)),
+                                location,
+                            );
+                        }
+                    }
 
                     Operator::Not => Self::unary(
                         bytecode.clone(),
@@ -323,20 +447,81 @@ impl Expression {
                             .collect(),
                         location,
                     ),
-                    Operator::CallAssert { message } => {
-                        Self::call_assert(bytecode.clone(), message, location)
+                    Operator::CallAssert {
+                        message,
+                        argument_types,
+                        code,
+                    } => Self::call_assert(
+                        bytecode.clone(),
+                        message,
+                        argument_types
+                            .into_iter()
+                            .map(|r#type| r#type.into())
+                            .collect(),
+                        code,
+                        location,
+                    ),
+                    Operator::CallExpose { name, r#type } => {
+                        Self::call_expose(bytecode.clone(), name, r#type.into(), location)
                     }
                     Operator::CallStandardLibrary {
                         identifier,
                         input_size,
                         output_size,
+                        template,
                     } => Self::call_standard_library(
+                        bytecode.clone(),
+                        identifier,
+                        input_size,
+                        output_size,
+                        template,
+                        location,
+                    ),
+                    Operator::CallExtension {
+                        identifier,
+                        input_size,
+                        output_size,
+                    } => Self::call_extension(
                         bytecode.clone(),
                         identifier,
                         input_size,
                         output_size,
                         location,
                     ),
+                    Operator::CallHost {
+                        identifier,
+                        input_size,
+                        output_type,
+                    } => Self::call_host(
+                        bytecode.clone(),
+                        identifier,
+                        input_size,
+                        output_type,
+                        location,
+                    ),
+                    Operator::CallEnumerationFromValue {
+                        identifier,
+                        values,
+                        bitlength,
+                    } => Self::call_enumeration_from_value(
+                        bytecode.clone(),
+                        identifier,
+                        values,
+                        bitlength,
+                        location,
+                    ),
+                    Operator::CallEnumerationTryFrom { values, bitlength } => {
+                        Self::call_enumeration_try_from(
+                            bytecode.clone(),
+                            values,
+                            bitlength,
+                            location,
+                        )
+                    }
+                    Operator::CallEnumerationVariantCount { count, bitlength } => {
+                        Constant::new_integer(BigInt::from(count), false, bitlength)
+                            .write_all_to_bytecode(bytecode.clone())
+                    }
                 },
             }
         }
@@ -382,6 +567,16 @@ impl Expression {
         expression: Self,
         operation: Instruction,
         location: Location,
+    ) {
+        Self::assignment_with_operations(bytecode, place, expression, vec![operation], location)
+    }
+
+    fn assignment_with_operations(
+        bytecode: Rc<RefCell<Bytecode>>,
+        place: Place,
+        expression: Self,
+        operations: Vec<Instruction>,
+        location: Location,
     ) {
         let is_place_indexed = !place.elements.is_empty();
         let address = bytecode
@@ -413,9 +608,11 @@ impl Expression {
 
         expression.write_all_to_bytecode(bytecode.clone());
 
-        bytecode
-            .borrow_mut()
-            .push_instruction(operation, Some(location));
+        for operation in operations.into_iter() {
+            bytecode
+                .borrow_mut()
+                .push_instruction(operation, Some(location));
+        }
 
         bytecode.borrow_mut().push_instruction(
             if is_place_indexed {
@@ -437,6 +634,163 @@ impl Expression {
             .push_instruction(instruction, Some(location));
     }
 
+    ///
+    /// The decimal multiplication and division are the fixed-point built-in functions, which
+    /// take the fractional bits and the rounding mode after the operands.
+    ///
+    fn decimal_operation(identifier: BuiltinIdentifier, fraction_bits: usize) -> Vec<Instruction> {
+        vec![
+            Instruction::PushConst(zinc_bytecode::PushConst::new(
+                BigInt::from(fraction_bits),
+                ScalarType::Integer(IntegerType::U8),
+            )),
+            Instruction::PushConst(zinc_bytecode::PushConst::new(
+                BigInt::from(BuiltInItems::STD_MATH_ROUNDING_FLOOR),
+                ScalarType::Integer(IntegerType::U8),
+            )),
+            Instruction::CallBuiltin(zinc_bytecode::CallBuiltin::new(
+                identifier,
+                MathFixedFunction::ARGUMENT_COUNT,
+                1,
+            )),
+        ]
+    }
+
+    ///
+    /// Pushes the scale `2^fraction_bits` of the decimal represented as `scalar_type`.
+    ///
+    fn decimal_scale(
+        bytecode: Rc<RefCell<Bytecode>>,
+        scalar_type: ScalarType,
+        fraction_bits: usize,
+        location: Location,
+    ) {
+        bytecode.borrow_mut().push_instruction(
+            Instruction::PushConst(zinc_bytecode::PushConst::new(
+                BigInt::one() << fraction_bits,
+                scalar_type,
+            )),
+            Some(location),
+        );
+    }
+
+    ///
+    /// Compares the arrays, tuples, or structures of `size` fields on the top of the stack
+    /// element by element, leaving the conjunction of the comparisons on the stack.
+    ///
+    fn equals_composite(bytecode: Rc<RefCell<Bytecode>>, size: usize, location: Location) {
+        let address_2 = bytecode
+            .borrow_mut()
+            .declare_variable(None, Type::array(Type::field(), size));
+        bytecode.borrow_mut().push_instruction(
+            Instruction::StoreSequence(zinc_bytecode::StoreSequence::new(address_2, size)),
+            Some(location),
+        );
+        let address_1 = bytecode
+            .borrow_mut()
+            .declare_variable(None, Type::array(Type::field(), size));
+        bytecode.borrow_mut().push_instruction(
+            Instruction::StoreSequence(zinc_bytecode::StoreSequence::new(address_1, size)),
+            Some(location),
+        );
+
+        if size == 0 {
+            Constant::new_boolean(true).write_all_to_bytecode(bytecode.clone());
+        }
+        for index in 0..size {
+            bytecode.borrow_mut().push_instruction(
+                Instruction::LoadSequence(zinc_bytecode::LoadSequence::new(address_1 + index, 1)),
+                Some(location),
+            );
+            bytecode.borrow_mut().push_instruction(
+                Instruction::LoadSequence(zinc_bytecode::LoadSequence::new(address_2 + index, 1)),
+                Some(location),
+            );
+            bytecode
+                .borrow_mut()
+                .push_instruction(Instruction::Eq(zinc_bytecode::Eq), Some(location));
+            if index > 0 {
+                bytecode
+                    .borrow_mut()
+                    .push_instruction(Instruction::And(zinc_bytecode::And), Some(location));
+            }
+        }
+    }
+
+    ///
+    /// Raises the base on the top of the stack to the constant `exponent` power.
+    ///
+    /// The base is stored and squared once for each bit of the exponent except the highest one,
+    /// and the squares at the set bits are multiplied into the result. So none of the
+    /// intermediate values exceeds the result, and each multiplication overflows only if
+    /// the result does.
+    ///
+    fn exponentiation(
+        bytecode: Rc<RefCell<Bytecode>>,
+        exponent: BigInt,
+        r#type: Type,
+        location: Location,
+    ) {
+        if exponent.is_zero() {
+            bytecode
+                .borrow_mut()
+                .push_instruction(Instruction::Pop(zinc_bytecode::Pop::new(1)), Some(location));
+            if let Some(scalar_type) = r#type.into() {
+                bytecode.borrow_mut().push_instruction(
+                    Instruction::PushConst(zinc_bytecode::PushConst::new(
+                        BigInt::one(),
+                        scalar_type,
+                    )),
+                    Some(location),
+                );
+            }
+            return;
+        }
+        if exponent.is_one() {
+            return;
+        }
+
+        let address = bytecode.borrow_mut().declare_variable(None, r#type);
+        bytecode.borrow_mut().push_instruction(
+            Instruction::StoreSequence(zinc_bytecode::StoreSequence::new(address, 1)),
+            Some(location),
+        );
+
+        let bits = exponent.bits();
+        let mut is_result_pushed = false;
+        for index in 0..bits {
+            if !((&exponent >> index) & BigInt::one()).is_zero() {
+                bytecode.borrow_mut().push_instruction(
+                    Instruction::LoadSequence(zinc_bytecode::LoadSequence::new(address, 1)),
+                    Some(location),
+                );
+                if is_result_pushed {
+                    bytecode
+                        .borrow_mut()
+                        .push_instruction(Instruction::Mul(zinc_bytecode::Mul), Some(location));
+                }
+                is_result_pushed = true;
+            }
+
+            if index + 1 < bits {
+                bytecode.borrow_mut().push_instruction(
+                    Instruction::LoadSequence(zinc_bytecode::LoadSequence::new(address, 1)),
+                    Some(location),
+                );
+                bytecode
+                    .borrow_mut()
+                    .push_instruction(Instruction::Tee(zinc_bytecode::Tee), Some(location));
+                bytecode
+                    .borrow_mut()
+                    .push_instruction(Instruction::Mul(zinc_bytecode::Mul), Some(location));
+                bytecode.borrow_mut().push_instruction(
+                    Instruction::StoreSequence(zinc_bytecode::StoreSequence::new(address, 1)),
+                    Some(location),
+                );
+            }
+        }
+    }
+
     fn unary(bytecode: Rc<RefCell<Bytecode>>, instruction: Instruction, location: Location) {
         bytecode
             .borrow_mut()
@@ -449,32 +803,56 @@ impl Expression {
         input_size: usize,
         location: Location,
     ) {
-        let address = bytecode
-            .borrow()
-            .get_function_address(unique_id)
-            .expect(crate::PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS);
+        bytecode
+            .borrow_mut()
+            .push_call(unique_id, input_size, location);
+    }
 
+    fn call_debug(
+        bytecode: Rc<RefCell<Bytecode>>,
+        format: String,
+        input_types: Vec<DataType>,
+        location: Location,
+    ) {
         bytecode.borrow_mut().push_instruction(
-            Instruction::Call(zinc_bytecode::Call::new(address, input_size)),
+            Instruction::Dbg(zinc_bytecode::Dbg::new(format, input_types)),
             Some(location),
         );
     }
 
-    fn call_debug(
+    fn call_assert(
         bytecode: Rc<RefCell<Bytecode>>,
-        format: String,
+        message: Option<String>,
         input_types: Vec<DataType>,
+        code: Option<u64>,
         location: Location,
     ) {
+        if code.is_some() {
+            // the error code constant is a part of the instruction, so it is dropped from the stack
+            bytecode.borrow_mut().push_instruction(
+                Instruction::Pop(zinc_bytecode::Pop::new(1)),
+                Some(location),
+            );
+        }
+
         bytecode.borrow_mut().push_instruction(
-            Instruction::Dbg(zinc_bytecode::Dbg::new(format, input_types)),
+            Instruction::Assert(zinc_bytecode::Assert::new_formatted(
+                message,
+                input_types,
+                code,
+            )),
             Some(location),
         );
     }
 
-    fn call_assert(bytecode: Rc<RefCell<Bytecode>>, message: Option<String>, location: Location) {
+    fn call_expose(
+        bytecode: Rc<RefCell<Bytecode>>,
+        name: String,
+        r#type: DataType,
+        location: Location,
+    ) {
         bytecode.borrow_mut().push_instruction(
-            Instruction::Assert(zinc_bytecode::Assert::new(message)),
+            Instruction::Expose(zinc_bytecode::Expose::new(name, r#type)),
             Some(location),
         );
     }
@@ -482,10 +860,25 @@ impl Expression {
     fn call_standard_library(
         bytecode: Rc<RefCell<Bytecode>>,
         identifier: BuiltinIdentifier,
-        input_size: usize,
+        mut input_size: usize,
         output_size: usize,
+        template: Option<Type>,
         location: Location,
     ) {
+        if let Some(template) = template {
+            let template: DataType = template.into();
+            for scalar_type in template.scalar_types().into_iter() {
+                bytecode.borrow_mut().push_instruction(
+                    Instruction::PushConst(zinc_bytecode::PushConst::new(
+                        BigInt::from(0),
+                        scalar_type,
+                    )),
+                    Some(location),
+                );
+                input_size += 1;
+            }
+        }
+
         bytecode.borrow_mut().push_instruction(
             Instruction::CallBuiltin(zinc_bytecode::CallBuiltin::new(
                 identifier,
@@ -495,4 +888,159 @@ impl Expression {
             Some(location),
         );
     }
+
+    fn call_extension(
+        bytecode: Rc<RefCell<Bytecode>>,
+        identifier: String,
+        input_size: usize,
+        output_size: usize,
+        location: Location,
+    ) {
+        bytecode.borrow_mut().push_instruction(
+            Instruction::CallExtension(zinc_bytecode::CallExtension::new(
+                identifier,
+                input_size,
+                output_size,
+            )),
+            Some(location),
+        );
+    }
+
+    fn call_host(
+        bytecode: Rc<RefCell<Bytecode>>,
+        identifier: String,
+        input_size: usize,
+        output_type: Type,
+        location: Location,
+    ) {
+        bytecode.borrow_mut().push_instruction(
+            Instruction::CallHost(zinc_bytecode::CallHost::new(
+                identifier,
+                input_size,
+                output_type.into(),
+            )),
+            Some(location),
+        );
+    }
+
+    fn call_enumeration_from_value(
+        bytecode: Rc<RefCell<Bytecode>>,
+        identifier: String,
+        values: Vec<BigInt>,
+        bitlength: usize,
+        location: Location,
+    ) {
+        let address = Self::enumeration_membership(bytecode.clone(), values, location);
+        bytecode.borrow_mut().push_instruction(
+            Instruction::Assert(zinc_bytecode::Assert::new(Some(format!(
+                "the value is not a variant of the enumeration `{}`",
+                identifier
+            )))),
+            Some(location),
+        );
+
+        bytecode.borrow_mut().push_instruction(
+            Instruction::LoadSequence(zinc_bytecode::LoadSequence::new(address, 1)),
+            Some(location),
+        );
+        Self::enumeration_cast(bytecode, bitlength, location);
+    }
+
+    fn call_enumeration_try_from(
+        bytecode: Rc<RefCell<Bytecode>>,
+        values: Vec<BigInt>,
+        bitlength: usize,
+        location: Location,
+    ) {
+        let fallback = values.first().cloned().unwrap_or_default();
+
+        let address = Self::enumeration_membership(bytecode.clone(), values, location);
+        let is_valid_address = bytecode
+            .borrow_mut()
+            .declare_variable(None, Type::boolean());
+        bytecode.borrow_mut().push_instruction(
+            Instruction::StoreSequence(zinc_bytecode::StoreSequence::new(is_valid_address, 1)),
+            Some(location),
+        );
+
+        // the invalid value is replaced with the first variant, so the cast never fails
+        bytecode.borrow_mut().push_instruction(
+            Instruction::LoadSequence(zinc_bytecode::LoadSequence::new(is_valid_address, 1)),
+            Some(location),
+        );
+        bytecode
+            .borrow_mut()
+            .push_instruction(Instruction::If(zinc_bytecode::If), Some(location));
+        bytecode.borrow_mut().push_instruction(
+            Instruction::LoadSequence(zinc_bytecode::LoadSequence::new(address, 1)),
+            Some(location),
+        );
+        bytecode
+            .borrow_mut()
+            .push_instruction(Instruction::Else(zinc_bytecode::Else), Some(location));
+        Constant::new_integer(fallback, false, crate::BITLENGTH_FIELD)
+            .write_all_to_bytecode(bytecode.clone());
+        bytecode
+            .borrow_mut()
+            .push_instruction(Instruction::EndIf(zinc_bytecode::EndIf), Some(location));
+        Self::enumeration_cast(bytecode.clone(), bitlength, location);
+
+        bytecode.borrow_mut().push_instruction(
+            Instruction::LoadSequence(zinc_bytecode::LoadSequence::new(is_valid_address, 1)),
+            Some(location),
+        );
+    }
+
+    ///
+    /// Stores the value on the top of the stack as a field element and checks whether it
+    /// equals any of the enumeration variant `values`.
+    ///
+    /// Leaves the check result on the stack and returns the address of the stored value.
+    ///
+    fn enumeration_membership(
+        bytecode: Rc<RefCell<Bytecode>>,
+        values: Vec<BigInt>,
+        location: Location,
+    ) -> usize {
+        let address = bytecode.borrow_mut().declare_variable(None, Type::field());
+        bytecode.borrow_mut().push_instruction(
+            Instruction::Cast(zinc_bytecode::Cast::new(ScalarType::Field)),
+            Some(location),
+        );
+        bytecode.borrow_mut().push_instruction(
+            Instruction::StoreSequence(zinc_bytecode::StoreSequence::new(address, 1)),
+            Some(location),
+        );
+
+        if values.is_empty() {
+            Constant::new_boolean(false).write_all_to_bytecode(bytecode.clone());
+        }
+        for (index, value) in values.into_iter().enumerate() {
+            bytecode.borrow_mut().push_instruction(
+                Instruction::LoadSequence(zinc_bytecode::LoadSequence::new(address, 1)),
+                Some(location),
+            );
+            Constant::new_integer(value, false, crate::BITLENGTH_FIELD)
+                .write_all_to_bytecode(bytecode.clone());
+            bytecode
+                .borrow_mut()
+                .push_instruction(Instruction::Eq(zinc_bytecode::Eq), Some(location));
+            if index > 0 {
+                bytecode
+                    .borrow_mut()
+                    .push_instruction(Instruction::Or(zinc_bytecode::Or), Some(location));
+            }
+        }
+
+        address
+    }
+
+    fn enumeration_cast(bytecode: Rc<RefCell<Bytecode>>, bitlength: usize, location: Location) {
+        if let Some(scalar_type) = Type::integer_unsigned(bitlength).into() {
+            bytecode.borrow_mut().push_instruction(
+                Instruction::Cast(zinc_bytecode::Cast::new(scalar_type)),
+                Some(location),
+            );
+        }
+    }
 }
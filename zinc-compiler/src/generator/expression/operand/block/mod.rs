@@ -29,11 +29,13 @@ impl Expression {
     }
 
     pub fn write_all_to_bytecode(self, bytecode: Rc<RefCell<Bytecode>>) {
+        bytecode.borrow_mut().start_block();
         for statement in self.statements.into_iter() {
             statement.write_all_to_bytecode(bytecode.clone());
         }
         if let Some(expression) = self.expression {
-            expression.write_all_to_bytecode(bytecode);
+            expression.write_all_to_bytecode(bytecode.clone());
         }
+        bytecode.borrow_mut().end_block();
     }
 }
@@ -8,7 +8,12 @@ use std::cell::RefCell;
 use std::rc::Rc;
 
 use crate::generator::bytecode::Bytecode;
+use crate::generator::expression::operand::constant::Constant;
+use crate::generator::expression::operand::Operand as GeneratorExpressionOperand;
+use crate::generator::expression::operator::Operator as GeneratorExpressionOperator;
 use crate::generator::expression::Expression as GeneratorExpression;
+use crate::lexical::token::location::Location;
+use crate::semantic::element::r#type::Type as SemanticType;
 
 ///
 /// The list expression which is translated to Zinc VM data.
@@ -23,6 +28,37 @@ impl Expression {
         Self { expressions }
     }
 
+    ///
+    /// Casts the result of the expression at `index` to `r#type`.
+    ///
+    pub fn cast(&mut self, index: usize, r#type: &SemanticType, location: Location) {
+        if let (Some(expression), Some(operator)) = (
+            self.expressions.get_mut(index),
+            GeneratorExpressionOperator::casting(r#type),
+        ) {
+            expression.push_operator(location, operator);
+        }
+    }
+
+    ///
+    /// Appends the constant passed after the arguments, e.g. the `std::math::min` operation.
+    ///
+    pub fn push_constant(&mut self, constant: Constant) {
+        let mut expression = GeneratorExpression::new();
+        expression.push_operand(GeneratorExpressionOperand::Constant(constant));
+        self.expressions.push(expression);
+    }
+
+    ///
+    /// Inserts the constant at `index`, e.g. the fractional bits of the `std::math::fixed_mul`
+    /// decimal operands.
+    ///
+    pub fn insert_constant(&mut self, index: usize, constant: Constant) {
+        let mut expression = GeneratorExpression::new();
+        expression.push_operand(GeneratorExpressionOperand::Constant(constant));
+        self.expressions.insert(index, expression);
+    }
+
     pub fn write_all_to_bytecode(self, bytecode: Rc<RefCell<Bytecode>>) {
         for expression in self.expressions.into_iter() {
             expression.write_all_to_bytecode(bytecode.clone());
@@ -51,14 +51,9 @@ impl Expression {
         let branch_count = self.branches.len();
         let scrutinee_size = self.scrutinee_type.size();
 
-        let (binding_branch, binding_name) = match self.binding_branch {
-            Some((binding_branch, binding_name)) => (Some(binding_branch), Some(binding_name)),
-            None => (None, None),
-        };
-
         let scrutinee_address = bytecode
             .borrow_mut()
-            .declare_variable(binding_name, self.scrutinee_type);
+            .declare_variable(None, self.scrutinee_type);
 
         self.scrutinee.write_all_to_bytecode(bytecode.clone());
         bytecode.borrow_mut().push_instruction(
@@ -90,8 +85,13 @@ impl Expression {
                 .push_instruction(Instruction::Else(zinc_bytecode::Else), Some(self.location));
         }
 
-        if let Some(binding_branch) = binding_branch {
+        if let Some((binding_branch, binding_name)) = self.binding_branch {
+            bytecode.borrow_mut().start_block();
+            bytecode
+                .borrow_mut()
+                .bind_variable(binding_name, scrutinee_address);
             binding_branch.write_all_to_bytecode(bytecode.clone());
+            bytecode.borrow_mut().end_block();
         } else if let Some(wildcard_branch) = self.wildcard_branch {
             wildcard_branch.write_all_to_bytecode(bytecode.clone());
         }
@@ -13,6 +13,7 @@ use zinc_bytecode::Instruction;
 
 use crate::generator::bytecode::Bytecode;
 use crate::generator::expression::operand::constant::Constant;
+use crate::lexical::token::location::Location;
 use crate::semantic::element::constant::Constant as SemanticConstant;
 use crate::semantic::element::place::element::Element as SemanticPlaceElement;
 use crate::semantic::element::place::Place as SemanticPlace;
@@ -63,6 +64,13 @@ impl Place {
                         Instruction::Cast(zinc_bytecode::Cast::new(ScalarType::Field)),
                         Some(self.identifier.location),
                     );
+                    if access.total_size < self.total_size && access.element_size > 0 {
+                        Self::check_bounds(
+                            bytecode.clone(),
+                            access.total_size / access.element_size,
+                            self.identifier.location,
+                        );
+                    }
                     Constant::new_integer(
                         BigInt::from(access.element_size),
                         false,
@@ -117,6 +125,29 @@ impl Place {
             }
         }
     }
+
+    ///
+    /// Checks the runtime index of a nested array on the top of the stack.
+    ///
+    /// The VM only checks the whole variable bounds, so an index out of range of a nested
+    /// array would silently access its neighbours.
+    ///
+    fn check_bounds(bytecode: Rc<RefCell<Bytecode>>, length: usize, location: Location) {
+        bytecode
+            .borrow_mut()
+            .push_instruction(Instruction::Tee(zinc_bytecode::Tee), Some(location));
+        Constant::new_integer(BigInt::from(length), false, crate::BITLENGTH_FIELD)
+            .write_all_to_bytecode(bytecode.clone());
+        bytecode
+            .borrow_mut()
+            .push_instruction(Instruction::Lt(zinc_bytecode::Lt), Some(location));
+        bytecode.borrow_mut().push_instruction(
+            Instruction::Assert(zinc_bytecode::Assert::new(Some(
+                "index out of bounds".to_owned(),
+            ))),
+            Some(location),
+        );
+    }
 }
 
 impl From<SemanticPlace> for Place {
@@ -2,6 +2,8 @@
 //! The generator expression operator.
 //!
 
+use num_bigint::BigInt;
+
 use zinc_bytecode::builtins::BuiltinIdentifier;
 
 use crate::generator::expression::operand::place::Place;
@@ -9,6 +11,8 @@ use crate::generator::expression::Expression;
 use crate::generator::r#type::Type;
 use crate::semantic::element::access::Field as FieldAccess;
 use crate::semantic::element::access::Index as IndexAccess;
+use crate::semantic::element::r#type::enumeration::Enumeration as SemanticEnumeration;
+use crate::semantic::element::r#type::function::enumeration::Function as SemanticEnumerationFunction;
 use crate::semantic::element::r#type::Type as SemanticType;
 
 ///
@@ -61,6 +65,16 @@ pub enum Operator {
         place: Place,
         expression: Expression,
     },
+    AssignmentDecimalMultiplication {
+        place: Place,
+        expression: Expression,
+        fraction_bits: usize,
+    },
+    AssignmentDecimalDivision {
+        place: Place,
+        expression: Expression,
+        fraction_bits: usize,
+    },
 
     // binary logical
     Or,
@@ -70,6 +84,12 @@ pub enum Operator {
     // binary comparison
     Equals,
     NotEquals,
+    EqualsComposite {
+        size: usize,
+    },
+    NotEqualsComposite {
+        size: usize,
+    },
     GreaterEquals,
     LesserEquals,
     Greater,
@@ -84,15 +104,41 @@ pub enum Operator {
 
     // binary arithmetic
     Addition,
+    Concatenation,
     Subtraction,
     Multiplication,
     Division,
     Remainder,
+    /// the product is scaled down by `2^fraction_bits` and rounded down
+    DecimalMultiplication {
+        fraction_bits: usize,
+    },
+    /// the dividend is scaled up by `2^fraction_bits` and the quotient is rounded down
+    DecimalDivision {
+        fraction_bits: usize,
+    },
+    /// the base is multiplied by itself with the square-and-multiply method
+    Exponentiation {
+        exponent: BigInt,
+        r#type: Type,
+    },
 
     // type casting
     Casting {
         r#type: Type,
     },
+    /// the integer is cast to the decimal underlying type and scaled up by `2^fraction_bits`
+    CastingToDecimal {
+        r#type: Type,
+        fraction_bits: usize,
+    },
+    /// the `bitlength`-bit decimal is scaled down by `2^fraction_bits`, dropping the fraction,
+    /// and cast to `r#type`
+    CastingFromDecimal {
+        r#type: Type,
+        bitlength: usize,
+        fraction_bits: usize,
+    },
 
     // unary logical
     Not,
@@ -125,19 +171,162 @@ pub enum Operator {
     },
     CallAssert {
         message: Option<String>,
+        argument_types: Vec<Type>,
+        code: Option<u64>,
+    },
+    CallExpose {
+        name: String,
+        r#type: Type,
     },
     CallStandardLibrary {
         identifier: BuiltinIdentifier,
         input_size: usize,
         output_size: usize,
+        /// the type whose zero value is passed after the arguments, which tells the VM
+        /// the scalar types of the result, e.g. for `std::eth::abi_decode`
+        template: Option<Type>,
+    },
+    CallExtension {
+        identifier: String,
+        input_size: usize,
+        output_size: usize,
+    },
+    CallHost {
+        identifier: String,
+        input_size: usize,
+        output_type: Type,
+    },
+    /// the value is checked against the variant `values` and cast to the enumeration type
+    CallEnumerationFromValue {
+        identifier: String,
+        values: Vec<BigInt>,
+        bitlength: usize,
+    },
+    /// the value is checked against the variant `values` and the check result is returned
+    /// along with the value, replaced with the first variant if the check fails
+    CallEnumerationTryFrom {
+        values: Vec<BigInt>,
+        bitlength: usize,
+    },
+    CallEnumerationVariantCount {
+        count: usize,
+        bitlength: usize,
     },
 }
 
 impl Operator {
+    pub fn equals(r#type: &SemanticType) -> Self {
+        match r#type {
+            SemanticType::Array { .. }
+            | SemanticType::Tuple { .. }
+            | SemanticType::Structure(_) => Self::EqualsComposite {
+                size: r#type.size(),
+            },
+            _ => Self::Equals,
+        }
+    }
+
+    pub fn not_equals(r#type: &SemanticType) -> Self {
+        match r#type {
+            SemanticType::Array { .. }
+            | SemanticType::Tuple { .. }
+            | SemanticType::Structure(_) => Self::NotEqualsComposite {
+                size: r#type.size(),
+            },
+            _ => Self::NotEquals,
+        }
+    }
+
+    pub fn addition(r#type: &SemanticType) -> Self {
+        match r#type {
+            SemanticType::Array { .. } => Self::Concatenation,
+            _ => Self::Addition,
+        }
+    }
+
+    pub fn multiplication(r#type: &SemanticType) -> Self {
+        match r#type {
+            SemanticType::Decimal { fraction_bits, .. } => Self::DecimalMultiplication {
+                fraction_bits: *fraction_bits,
+            },
+            _ => Self::Multiplication,
+        }
+    }
+
+    pub fn division(r#type: &SemanticType) -> Self {
+        match r#type {
+            SemanticType::Decimal { fraction_bits, .. } => Self::DecimalDivision {
+                fraction_bits: *fraction_bits,
+            },
+            _ => Self::Division,
+        }
+    }
+
+    pub fn assignment_multiplication(
+        r#type: &SemanticType,
+        place: Place,
+        expression: Expression,
+    ) -> Self {
+        match r#type {
+            SemanticType::Decimal { fraction_bits, .. } => Self::AssignmentDecimalMultiplication {
+                place,
+                expression,
+                fraction_bits: *fraction_bits,
+            },
+            _ => Self::AssignmentMultiplication { place, expression },
+        }
+    }
+
+    pub fn assignment_division(
+        r#type: &SemanticType,
+        place: Place,
+        expression: Expression,
+    ) -> Self {
+        match r#type {
+            SemanticType::Decimal { fraction_bits, .. } => Self::AssignmentDecimalDivision {
+                place,
+                expression,
+                fraction_bits: *fraction_bits,
+            },
+            _ => Self::AssignmentDivision { place, expression },
+        }
+    }
+
+    pub fn exponentiation(r#type: &SemanticType, exponent: BigInt) -> Option<Self> {
+        Type::try_from_semantic(r#type).map(|r#type| Self::Exponentiation { exponent, r#type })
+    }
+
     pub fn casting(r#type: &SemanticType) -> Option<Self> {
         Type::try_from_semantic(r#type).map(|r#type| Self::Casting { r#type })
     }
 
+    ///
+    /// Casts the value of type `from` to `to`, scaling it if either of them is a decimal.
+    ///
+    pub fn casting_from(from: &SemanticType, to: &SemanticType) -> Option<Self> {
+        match (from, to) {
+            (SemanticType::Decimal { .. }, SemanticType::Decimal { .. }) => Self::casting(to),
+            (_, SemanticType::Decimal { fraction_bits, .. }) => {
+                Type::try_from_semantic(to).map(|r#type| Self::CastingToDecimal {
+                    r#type,
+                    fraction_bits: *fraction_bits,
+                })
+            }
+            (
+                SemanticType::Decimal {
+                    integer_bits,
+                    fraction_bits,
+                },
+                _,
+            ) => Type::try_from_semantic(to).map(|r#type| Self::CastingFromDecimal {
+                r#type,
+                bitlength: integer_bits + fraction_bits,
+                fraction_bits: *fraction_bits,
+            }),
+            _ => Self::casting(to),
+        }
+    }
+
     pub fn index(expression: Expression, access: IndexAccess) -> Self {
         Self::Index { expression, access }
     }
@@ -164,8 +353,27 @@ impl Operator {
         }
     }
 
-    pub fn call_assert(message: Option<String>) -> Self {
-        Self::CallAssert { message }
+    pub fn call_assert(
+        message: Option<String>,
+        argument_types: Vec<SemanticType>,
+        code: Option<u64>,
+    ) -> Self {
+        Self::CallAssert {
+            message,
+            argument_types: argument_types
+                .as_slice()
+                .iter()
+                .filter_map(Type::try_from_semantic)
+                .collect(),
+            code,
+        }
+    }
+
+    pub fn call_expose(name: String, r#type: &SemanticType) -> Self {
+        Self::CallExpose {
+            name,
+            r#type: Type::try_from_semantic(r#type).unwrap_or_else(Type::unit),
+        }
     }
 
     pub fn call_std(identifier: BuiltinIdentifier, input_size: usize, output_size: usize) -> Self {
@@ -173,6 +381,58 @@ impl Operator {
             identifier,
             input_size,
             output_size,
+            template: None,
+        }
+    }
+
+    pub fn call_extension(identifier: String, input_size: usize, output_size: usize) -> Self {
+        Self::CallExtension {
+            identifier,
+            input_size,
+            output_size,
+        }
+    }
+
+    pub fn call_host(identifier: String, input_size: usize, output_type: &SemanticType) -> Self {
+        Self::CallHost {
+            identifier,
+            input_size,
+            output_type: Type::try_from_semantic(output_type).unwrap_or_else(Type::unit),
+        }
+    }
+
+    pub fn call_enumeration_from_value(enumeration: &SemanticEnumeration) -> Self {
+        Self::CallEnumerationFromValue {
+            identifier: enumeration.identifier.to_owned(),
+            values: enumeration.values.to_owned(),
+            bitlength: enumeration.bitlength,
+        }
+    }
+
+    pub fn call_enumeration_try_from(enumeration: &SemanticEnumeration) -> Self {
+        Self::CallEnumerationTryFrom {
+            values: enumeration.values.to_owned(),
+            bitlength: enumeration.bitlength,
+        }
+    }
+
+    pub fn call_enumeration_variant_count(enumeration: &SemanticEnumeration) -> Self {
+        Self::CallEnumerationVariantCount {
+            count: enumeration.values.len(),
+            bitlength: SemanticEnumerationFunction::variant_count_bitlength(enumeration),
+        }
+    }
+
+    pub fn call_std_with_template(
+        identifier: BuiltinIdentifier,
+        input_size: usize,
+        template: &SemanticType,
+    ) -> Self {
+        Self::CallStandardLibrary {
+            identifier,
+            input_size,
+            output_size: template.size(),
+            template: Type::try_from_semantic(template),
         }
     }
 }
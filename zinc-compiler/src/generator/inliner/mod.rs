@@ -0,0 +1,164 @@
+//!
+//! The function inliner.
+//!
+
+mod tests;
+
+use std::collections::HashMap;
+
+use zinc_bytecode::Instruction;
+
+///
+/// The function inlining hint, set with the `#[inline]` and `#[inline(never)]` attributes.
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Hint {
+    /// The function is inlined if it is small enough.
+    Auto,
+    /// The function is always inlined.
+    Always,
+    /// The function is never inlined.
+    Never,
+}
+
+///
+/// The function body ready to be inlined.
+///
+#[derive(Debug, Clone, PartialEq)]
+struct Function {
+    instructions: Vec<Instruction>,
+    frame_size: usize,
+}
+
+///
+/// The function inliner, which keeps the bodies of the functions to be inlined.
+///
+/// The inlined body uses the caller data stack frame, starting from the first free address,
+/// so all the frame-relative addresses are shifted by that address. The arguments are moved
+/// from the evaluation stack to the data stack the same way the `Call` instruction does.
+///
+/// The location markers of the inlined body are dropped, so the inlined instructions are
+/// reported at the call site location.
+///
+#[derive(Debug, Default, PartialEq)]
+pub struct Inliner {
+    functions: HashMap<usize, Function>,
+}
+
+impl Inliner {
+    /// The maximal number of instructions in a function inlined without the `#[inline]` attribute.
+    pub const SIZE_THRESHOLD: usize = 8;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    ///
+    /// Records the function body if it must be inlined according to the `hint`.
+    ///
+    /// The `instructions` are the function body without the function markers and
+    /// the final `Return` instruction.
+    ///
+    pub fn record(
+        &mut self,
+        unique_id: usize,
+        instructions: &[Instruction],
+        frame_size: usize,
+        hint: Hint,
+    ) {
+        let instructions: Vec<Instruction> = instructions
+            .iter()
+            .filter(|instruction| {
+                !matches!(
+                    instruction,
+                    Instruction::LineMarker(_) | Instruction::ColumnMarker(_)
+                )
+            })
+            .cloned()
+            .collect();
+
+        let is_inlined = match hint {
+            Hint::Auto => instructions.len() <= Self::SIZE_THRESHOLD,
+            Hint::Always => true,
+            Hint::Never => false,
+        };
+        if !is_inlined {
+            return;
+        }
+
+        self.functions.insert(
+            unique_id,
+            Function {
+                instructions,
+                frame_size,
+            },
+        );
+    }
+
+    ///
+    /// Returns the inlined body of the function relocated to the `base` data stack address
+    /// and the data stack size it requires, if the function must be inlined.
+    ///
+    pub fn expand(
+        &self,
+        unique_id: usize,
+        base: usize,
+        input_size: usize,
+    ) -> Option<(Vec<Instruction>, usize)> {
+        let function = self.functions.get(&unique_id)?;
+
+        let mut instructions = Vec::with_capacity(function.instructions.len() + 1);
+        if input_size > 0 {
+            instructions.push(Instruction::StoreSequence(
+                zinc_bytecode::StoreSequence::new(base, input_size),
+            ));
+        }
+        instructions.extend(
+            function
+                .instructions
+                .iter()
+                .cloned()
+                .map(|instruction| Self::relocate(instruction, base)),
+        );
+
+        Some((instructions, std::cmp::max(function.frame_size, input_size)))
+    }
+
+    fn relocate(instruction: Instruction, base: usize) -> Instruction {
+        match instruction {
+            Instruction::Load(mut inner) => {
+                inner.address += base;
+                Instruction::Load(inner)
+            }
+            Instruction::LoadSequence(mut inner) => {
+                inner.address += base;
+                Instruction::LoadSequence(inner)
+            }
+            Instruction::LoadByIndex(mut inner) => {
+                inner.address += base;
+                Instruction::LoadByIndex(inner)
+            }
+            Instruction::LoadSequenceByIndex(mut inner) => {
+                inner.address += base;
+                Instruction::LoadSequenceByIndex(inner)
+            }
+            Instruction::Store(mut inner) => {
+                inner.index += base;
+                Instruction::Store(inner)
+            }
+            Instruction::StoreSequence(mut inner) => {
+                inner.address += base;
+                Instruction::StoreSequence(inner)
+            }
+            Instruction::StoreByIndex(mut inner) => {
+                inner.address += base;
+                Instruction::StoreByIndex(inner)
+            }
+            Instruction::StoreSequenceByIndex(mut inner) => {
+                inner.address += base;
+                Instruction::StoreSequenceByIndex(inner)
+            }
+            instruction => instruction,
+        }
+    }
+}
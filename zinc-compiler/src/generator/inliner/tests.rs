@@ -0,0 +1,75 @@
+//!
+//! The function inliner tests.
+//!
+
+#![cfg(test)]
+
+use num_bigint::BigInt;
+
+use zinc_bytecode::data::types::ScalarType;
+use zinc_bytecode::Instruction;
+
+use crate::generator::inliner::Hint;
+use crate::generator::inliner::Inliner;
+
+fn body() -> Vec<Instruction> {
+    vec![
+        Instruction::Load(zinc_bytecode::Load::new(0)),
+        Instruction::LineMarker(zinc_bytecode::LineMarker::new(3)),
+        Instruction::LoadSequence(zinc_bytecode::LoadSequence::new(1, 2)),
+        Instruction::Store(zinc_bytecode::Store::new(3)),
+        Instruction::LoadGlobal(zinc_bytecode::LoadGlobal::new(0)),
+    ]
+}
+
+#[test]
+fn ok_relocated() {
+    let mut inliner = Inliner::new();
+    inliner.record(42, body().as_slice(), 4, Hint::Auto);
+
+    let expected = Some((
+        vec![
+            Instruction::StoreSequence(zinc_bytecode::StoreSequence::new(10, 3)),
+            Instruction::Load(zinc_bytecode::Load::new(10)),
+            Instruction::LoadSequence(zinc_bytecode::LoadSequence::new(11, 2)),
+            Instruction::Store(zinc_bytecode::Store::new(13)),
+            Instruction::LoadGlobal(zinc_bytecode::LoadGlobal::new(0)),
+        ],
+        4,
+    ));
+
+    let result = inliner.expand(42, 10, 3);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_hint_never() {
+    let mut inliner = Inliner::new();
+    inliner.record(42, body().as_slice(), 4, Hint::Never);
+
+    assert_eq!(inliner.expand(42, 0, 3), None);
+}
+
+#[test]
+fn ok_size_threshold() {
+    let body = vec![
+        Instruction::PushConst(zinc_bytecode::PushConst::new(
+            BigInt::from(42),
+            ScalarType::Field,
+        ));
+        Inliner::SIZE_THRESHOLD + 1
+    ];
+
+    let mut inliner = Inliner::new();
+    inliner.record(1, body.as_slice(), 0, Hint::Auto);
+    inliner.record(2, body.as_slice(), 0, Hint::Always);
+
+    assert_eq!(inliner.expand(1, 0, 0), None);
+    assert_eq!(
+        inliner
+            .expand(2, 0, 0)
+            .map(|(instructions, _)| instructions.len()),
+        Some(Inliner::SIZE_THRESHOLD + 1)
+    );
+}
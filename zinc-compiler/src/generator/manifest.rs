@@ -0,0 +1,51 @@
+//!
+//! The unit test manifest.
+//!
+
+use serde_json::json;
+use serde_json::Value as JsonValue;
+
+static PANIC_JSON_MANIFEST_SERIALIZATION: &str =
+    "JSON manifest serialization must be always successful: ";
+
+///
+/// The `#[test]` function, which is called instead of `main` to run the test.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Test {
+    pub name: String,
+    pub address: usize,
+}
+
+///
+/// The unit test manifest, which lists the `#[test]` functions of the program.
+///
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Manifest {
+    pub tests: Vec<Test>,
+}
+
+impl Manifest {
+    ///
+    /// Converts the manifest into JSON, e.g. for the external test runners.
+    ///
+    pub fn to_json(&self) -> JsonValue {
+        json!({
+            "tests": self
+                .tests
+                .iter()
+                .map(|test| json!({
+                    "name": test.name,
+                    "address": test.address,
+                }))
+                .collect::<Vec<JsonValue>>(),
+        })
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        match serde_json::to_string_pretty(&self.to_json()) {
+            Ok(json) => (json + "\n").into_bytes(),
+            Err(error) => panic!("{}{}", PANIC_JSON_MANIFEST_SERIALIZATION, error),
+        }
+    }
+}
@@ -2,10 +2,16 @@
 //! The intermediate representation for Zinc VM bytecode generating.
 //!
 
+mod tests;
+
 pub mod bytecode;
 pub mod expression;
+pub mod inliner;
+pub mod manifest;
+pub mod peephole;
 pub mod statement;
 pub mod r#type;
+pub mod ssa;
 
 use std::cell::RefCell;
 use std::rc::Rc;
@@ -0,0 +1,166 @@
+//!
+//! The bytecode peephole optimizer.
+//!
+
+mod tests;
+
+use zinc_bytecode::data::types::ScalarType;
+use zinc_bytecode::Instruction;
+
+///
+/// The peephole optimizer, which rewrites pairs of adjacent instructions.
+///
+/// The location markers are skipped when looking for the adjacent instruction, so they never
+/// prevent an optimization. The function markers are never removed, so the call addresses
+/// are only shifted by the number of instructions removed before them.
+///
+pub struct Optimizer {}
+
+///
+/// The result of matching a pair of adjacent instructions.
+///
+enum Rewrite {
+    /// The pair is left as is.
+    Keep,
+    /// Both instructions are removed.
+    RemoveBoth,
+    /// The first instruction is removed.
+    RemoveFirst,
+    /// The first instruction is removed and the second one is replaced.
+    ReplaceBoth(Instruction),
+}
+
+impl Optimizer {
+    /// The optimizations are disabled, which is the default.
+    pub const LEVEL_NONE: usize = 0;
+    /// The peephole optimizations are enabled.
+    pub const LEVEL_PEEPHOLE: usize = 1;
+
+    ///
+    /// Optimizes the `instructions` according to the optimization `level`.
+    ///
+    /// Returns the optimized instructions and the old-to-new address map.
+    ///
+    pub fn optimize(
+        instructions: Vec<Instruction>,
+        level: usize,
+    ) -> (Vec<Instruction>, Vec<usize>) {
+        if level < Self::LEVEL_PEEPHOLE {
+            let addresses = (0..instructions.len()).collect();
+            return (instructions, addresses);
+        }
+
+        let mut slots: Vec<Option<Instruction>> = instructions.into_iter().map(Some).collect();
+        let mut live: Vec<usize> = Vec::with_capacity(slots.len());
+
+        for index in 0..slots.len() {
+            if Self::is_location_marker(slots[index].as_ref()) {
+                continue;
+            }
+
+            while let Some(&previous) = live.last() {
+                let rewrite = match (slots[previous].as_ref(), slots[index].as_ref()) {
+                    (Some(first), Some(second)) => Self::rewrite(first, second),
+                    _ => Rewrite::Keep,
+                };
+
+                match rewrite {
+                    Rewrite::Keep => break,
+                    Rewrite::RemoveBoth => {
+                        slots[previous] = None;
+                        slots[index] = None;
+                        live.pop();
+                        break;
+                    }
+                    Rewrite::RemoveFirst => {
+                        slots[previous] = None;
+                        live.pop();
+                    }
+                    Rewrite::ReplaceBoth(instruction) => {
+                        slots[previous] = None;
+                        slots[index] = Some(instruction);
+                        live.pop();
+                    }
+                }
+            }
+
+            if slots[index].is_some() {
+                live.push(index);
+            }
+        }
+
+        let mut addresses = Vec::with_capacity(slots.len());
+        let mut count = 0;
+        for slot in slots.iter() {
+            addresses.push(count);
+            if slot.is_some() {
+                count += 1;
+            }
+        }
+
+        let instructions = slots
+            .into_iter()
+            .flatten()
+            .map(|instruction| match instruction {
+                Instruction::Call(mut call) => {
+                    call.address = addresses[call.address];
+                    Instruction::Call(call)
+                }
+                instruction => instruction,
+            })
+            .collect();
+
+        (instructions, addresses)
+    }
+
+    fn rewrite(first: &Instruction, second: &Instruction) -> Rewrite {
+        match (first, second) {
+            (Instruction::PushConst(_), Instruction::Pop(pop)) => {
+                if pop.count > 1 {
+                    Rewrite::ReplaceBoth(Instruction::Pop(zinc_bytecode::Pop::new(pop.count - 1)))
+                } else {
+                    Rewrite::RemoveBoth
+                }
+            }
+            (Instruction::Load(load), Instruction::Store(store)) if load.address == store.index => {
+                Rewrite::RemoveBoth
+            }
+            (Instruction::LoadSequence(load), Instruction::StoreSequence(store))
+                if load.address == store.address && load.len == store.len =>
+            {
+                Rewrite::RemoveBoth
+            }
+            (Instruction::Swap(_), Instruction::Swap(_)) => Rewrite::RemoveBoth,
+            (Instruction::Cast(first), Instruction::Cast(second))
+                if Self::is_cast_redundant(first.scalar_type, second.scalar_type) =>
+            {
+                Rewrite::RemoveFirst
+            }
+            _ => Rewrite::Keep,
+        }
+    }
+
+    ///
+    /// Checks if the `first` cast may be dropped when it is followed by the `second` one.
+    ///
+    /// The field cast only changes the type of the value, so the next cast overrides it.
+    /// An integer cast is redundant if the next one has the same sign and does not accept
+    /// more values, since the value is interpreted according to the target type sign.
+    ///
+    fn is_cast_redundant(first: ScalarType, second: ScalarType) -> bool {
+        match (first, second) {
+            (ScalarType::Field, _) => true,
+            (ScalarType::Integer(first), ScalarType::Integer(second)) => {
+                first.is_signed == second.is_signed && first.bitlength >= second.bitlength
+            }
+            (first, second) => first == second,
+        }
+    }
+
+    fn is_location_marker(instruction: Option<&Instruction>) -> bool {
+        matches!(
+            instruction,
+            Some(Instruction::LineMarker(_)) | Some(Instruction::ColumnMarker(_))
+        )
+    }
+}
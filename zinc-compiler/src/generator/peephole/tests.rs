@@ -0,0 +1,122 @@
+//!
+//! The bytecode peephole optimizer tests.
+//!
+
+#![cfg(test)]
+
+use num_bigint::BigInt;
+
+use zinc_bytecode::data::types::IntegerType;
+use zinc_bytecode::data::types::ScalarType;
+use zinc_bytecode::Instruction;
+
+use crate::generator::peephole::Optimizer;
+
+fn push_const() -> Instruction {
+    Instruction::PushConst(zinc_bytecode::PushConst::new(
+        BigInt::from(42),
+        ScalarType::Field,
+    ))
+}
+
+fn cast(is_signed: bool, bitlength: usize) -> Instruction {
+    Instruction::Cast(zinc_bytecode::Cast::new(ScalarType::Integer(IntegerType {
+        is_signed,
+        bitlength,
+    })))
+}
+
+#[test]
+fn ok_push_pop_removed() {
+    let input = vec![
+        push_const(),
+        Instruction::LineMarker(zinc_bytecode::LineMarker::new(2)),
+        Instruction::Pop(zinc_bytecode::Pop::new(1)),
+        push_const(),
+        Instruction::Pop(zinc_bytecode::Pop::new(3)),
+    ];
+
+    let expected = vec![
+        Instruction::LineMarker(zinc_bytecode::LineMarker::new(2)),
+        Instruction::Pop(zinc_bytecode::Pop::new(2)),
+    ];
+
+    let (result, _addresses) = Optimizer::optimize(input, Optimizer::LEVEL_PEEPHOLE);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_load_store_removed_after_nested_removal() {
+    let input = vec![
+        Instruction::Load(zinc_bytecode::Load::new(4)),
+        Instruction::Swap(zinc_bytecode::Swap),
+        Instruction::Swap(zinc_bytecode::Swap),
+        Instruction::Store(zinc_bytecode::Store::new(4)),
+        Instruction::LoadSequence(zinc_bytecode::LoadSequence::new(0, 2)),
+        Instruction::StoreSequence(zinc_bytecode::StoreSequence::new(0, 3)),
+    ];
+
+    let expected = vec![
+        Instruction::LoadSequence(zinc_bytecode::LoadSequence::new(0, 2)),
+        Instruction::StoreSequence(zinc_bytecode::StoreSequence::new(0, 3)),
+    ];
+
+    let (result, _addresses) = Optimizer::optimize(input, Optimizer::LEVEL_PEEPHOLE);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_cast_chain_merged() {
+    let input = vec![
+        Instruction::Cast(zinc_bytecode::Cast::new(ScalarType::Field)),
+        cast(false, 64),
+        cast(false, 8),
+        cast(true, 8),
+        cast(true, 16),
+    ];
+
+    let expected = vec![cast(false, 8), cast(true, 8), cast(true, 16)];
+
+    let (result, _addresses) = Optimizer::optimize(input, Optimizer::LEVEL_PEEPHOLE);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_call_addresses_shifted() {
+    let input = vec![
+        Instruction::Call(zinc_bytecode::Call::new(4, 0)),
+        Instruction::Exit(zinc_bytecode::Exit::new(0)),
+        push_const(),
+        Instruction::Pop(zinc_bytecode::Pop::new(1)),
+        Instruction::FileMarker(zinc_bytecode::instructions::FileMarker::new(
+            "main.zn".to_owned(),
+        )),
+        Instruction::Return(zinc_bytecode::Return::new(0)),
+    ];
+
+    let expected = vec![
+        Instruction::Call(zinc_bytecode::Call::new(2, 0)),
+        Instruction::Exit(zinc_bytecode::Exit::new(0)),
+        Instruction::FileMarker(zinc_bytecode::instructions::FileMarker::new(
+            "main.zn".to_owned(),
+        )),
+        Instruction::Return(zinc_bytecode::Return::new(0)),
+    ];
+
+    let (result, addresses) = Optimizer::optimize(input, Optimizer::LEVEL_PEEPHOLE);
+
+    assert_eq!(result, expected);
+    assert_eq!(addresses[4], 2);
+}
+
+#[test]
+fn ok_level_none_unchanged() {
+    let input = vec![push_const(), Instruction::Pop(zinc_bytecode::Pop::new(1))];
+
+    let (result, _addresses) = Optimizer::optimize(input.clone(), 0);
+
+    assert_eq!(result, input);
+}
@@ -0,0 +1,96 @@
+//!
+//! The bytecode to SSA lifting.
+//!
+
+use zinc_bytecode::Instruction;
+use zinc_bytecode::ValidationError;
+
+use crate::generator::ssa::Block;
+use crate::generator::ssa::Operation;
+use crate::generator::ssa::Value;
+
+///
+/// The block lifter, which simulates the evaluation stack with the values.
+///
+#[derive(Default)]
+pub struct Lifter {
+    block: Block,
+    stack: Vec<Value>,
+}
+
+impl Lifter {
+    ///
+    /// Lifts a non-flow-control instruction at `address`.
+    ///
+    /// The stack shuffling instructions only rearrange the simulated stack, so they leave
+    /// no operations behind.
+    ///
+    pub fn lift(
+        &mut self,
+        address: usize,
+        instruction: &Instruction,
+    ) -> Result<(), ValidationError> {
+        match instruction {
+            Instruction::Swap(_) => {
+                let top = self.pop();
+                let below = self.pop();
+                self.stack.push(top);
+                self.stack.push(below);
+            }
+            Instruction::Tee(_) => {
+                let value = self.pop();
+                self.stack.push(value);
+                self.stack.push(value);
+            }
+            Instruction::Pop(instruction) => {
+                for _ in 0..instruction.count {
+                    self.pop();
+                }
+            }
+            instruction => {
+                let (inputs_count, outputs_count) =
+                    zinc_bytecode::stack_effect(address, instruction)?;
+
+                let mut inputs: Vec<Value> = (0..inputs_count).map(|_| self.pop()).collect();
+                inputs.reverse();
+
+                let outputs: Vec<Value> =
+                    (0..outputs_count).map(|_| self.block.allocate()).collect();
+                self.stack.extend(outputs.iter().copied());
+
+                self.block.operations.push(Operation {
+                    instruction: instruction.to_owned(),
+                    inputs,
+                    outputs,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Finishes the block, leaving the simulated stack as its results.
+    ///
+    pub fn finish(mut self) -> Block {
+        self.block.results = self.stack;
+        self.block
+    }
+
+    ///
+    /// Pops a value from the simulated stack.
+    ///
+    /// If the stack is empty, the value comes from the previous blocks, so it becomes the
+    /// deepest parameter seen so far.
+    ///
+    fn pop(&mut self) -> Value {
+        match self.stack.pop() {
+            Some(value) => value,
+            None => {
+                let value = self.block.allocate();
+                self.block.parameters.insert(0, value);
+                value
+            }
+        }
+    }
+}
@@ -0,0 +1,191 @@
+//!
+//! The SSA to bytecode lowering.
+//!
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use zinc_bytecode::Instruction;
+
+use crate::generator::ssa::Block;
+use crate::generator::ssa::Value;
+
+///
+/// The lowering state of a single scheduling attempt.
+///
+struct Scheduler<'a> {
+    uses: &'a HashMap<Value, usize>,
+    spilled: &'a HashSet<Value>,
+    slots: HashMap<Value, usize>,
+    stack: Vec<Value>,
+    missing: HashSet<Value>,
+    instructions: Vec<Instruction>,
+}
+
+impl Block {
+    ///
+    /// Lowers the block to the stack instructions.
+    ///
+    /// A value is kept on the stack if it is used once and is on the stack top by the time
+    /// of its use. Other values are spilled to the scratch memory right after they are defined
+    /// and loaded at each use. The spilled set only grows, so the scheduling reaches the
+    /// fixpoint in at most as many attempts as there are values.
+    ///
+    pub fn lower(&self, scratch: usize, instructions: &mut Vec<Instruction>) {
+        let uses = self.uses();
+        let mut spilled: HashSet<Value> = uses
+            .iter()
+            .filter(|(_, count)| **count > 1)
+            .map(|(value, _)| *value)
+            .collect();
+
+        loop {
+            let scheduler = Scheduler::new(self, scratch, &uses, &spilled).schedule(self);
+
+            if scheduler.missing.is_empty() {
+                instructions.extend(scheduler.instructions);
+                return;
+            }
+
+            spilled.extend(scheduler.missing);
+        }
+    }
+
+    ///
+    /// The values in the order of their definition.
+    ///
+    fn definitions(&self) -> impl Iterator<Item = &Value> {
+        self.parameters.iter().chain(
+            self.operations
+                .iter()
+                .flat_map(|operation| operation.outputs.iter()),
+        )
+    }
+}
+
+impl<'a> Scheduler<'a> {
+    fn new(
+        block: &Block,
+        scratch: usize,
+        uses: &'a HashMap<Value, usize>,
+        spilled: &'a HashSet<Value>,
+    ) -> Self {
+        let slots = block
+            .definitions()
+            .filter(|value| spilled.contains(value))
+            .enumerate()
+            .map(|(index, value)| (*value, scratch + index))
+            .collect();
+
+        Self {
+            uses,
+            spilled,
+            slots,
+            stack: Vec::new(),
+            missing: HashSet::new(),
+            instructions: Vec::new(),
+        }
+    }
+
+    fn schedule(mut self, block: &Block) -> Self {
+        self.define(block.parameters.as_slice());
+
+        for operation in block.operations.iter() {
+            self.consume(operation.inputs.as_slice(), false);
+            self.instructions.push(operation.instruction.to_owned());
+            self.define(operation.outputs.as_slice());
+        }
+
+        self.consume(block.results.as_slice(), true);
+
+        self
+    }
+
+    ///
+    /// Handles the values just pushed onto the stack.
+    ///
+    /// The unused values on the top are popped. If any of the rest must be spilled, all of
+    /// them are, since only the top of the stack can be stored.
+    ///
+    fn define(&mut self, values: &[Value]) {
+        let mut kept = values.len();
+        while kept > 0 && !self.uses.contains_key(&values[kept - 1]) {
+            kept -= 1;
+        }
+        if kept < values.len() {
+            self.instructions
+                .push(Instruction::Pop(zinc_bytecode::Pop::new(
+                    values.len() - kept,
+                )));
+        }
+        let values = &values[..kept];
+
+        let is_spilled = values
+            .iter()
+            .any(|value| self.spilled.contains(value) || !self.uses.contains_key(value));
+        if !is_spilled {
+            self.stack.extend(values.iter().copied());
+            return;
+        }
+
+        for value in values.iter().rev() {
+            self.require_spilled(*value);
+            let address = self.slot(*value);
+            self.instructions
+                .push(Instruction::Store(zinc_bytecode::Store::new(address)));
+        }
+    }
+
+    ///
+    /// Makes the `values` the top of the stack.
+    ///
+    /// The longest prefix of the values already on the stack top is taken from there, and the
+    /// rest is loaded from the scratch memory. If `is_exact` is set, the stack must consist
+    /// of the values only, which is the case for the block results.
+    ///
+    fn consume(&mut self, values: &[Value], is_exact: bool) {
+        let mut kept = values.len();
+        while kept > 0 {
+            let prefix = &values[..kept];
+            let is_on_top = if is_exact {
+                self.stack.as_slice() == prefix
+            } else {
+                self.stack.ends_with(prefix)
+            };
+            if is_on_top && prefix.iter().all(|value| !self.spilled.contains(value)) {
+                break;
+            }
+            kept -= 1;
+        }
+
+        let remaining = self.stack.len() - kept;
+        if is_exact && remaining > 0 {
+            let stranded: Vec<Value> = self.stack.drain(..remaining).collect();
+            for value in stranded.into_iter() {
+                self.require_spilled(value);
+            }
+        }
+        self.stack.truncate(self.stack.len() - kept);
+
+        for value in values[kept..].iter() {
+            self.require_spilled(*value);
+            let address = self.slot(*value);
+            self.instructions
+                .push(Instruction::Load(zinc_bytecode::Load::new(address)));
+        }
+    }
+
+    fn require_spilled(&mut self, value: Value) {
+        if !self.spilled.contains(&value) {
+            self.missing.insert(value);
+        }
+    }
+
+    ///
+    /// The scratch address of the value, which is only meaningful in the attempt
+    /// where no spills are missing.
+    ///
+    fn slot(&self, value: Value) -> usize {
+        self.slots.get(&value).copied().unwrap_or_default()
+    }
+}
@@ -0,0 +1,165 @@
+//!
+//! The SSA-style middle-level intermediate representation.
+//!
+//! The bytecode is lifted into straight-line blocks, where every evaluation stack slot becomes
+//! a value defined exactly once, and lowered back to the stack instructions after the passes
+//! are done. The flow control instructions are kept as is and delimit the blocks, so the
+//! passes are local to a block.
+//!
+//! The representation is experimental and is not used by the default pipeline yet.
+//!
+
+mod lift;
+mod lower;
+mod tests;
+
+use std::collections::HashMap;
+
+use zinc_bytecode::Instruction;
+use zinc_bytecode::ValidationError;
+
+use self::lift::Lifter;
+
+///
+/// The value, which is defined exactly once within its block.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Value(pub usize);
+
+///
+/// The non-flow-control instruction with its stack operands made explicit.
+///
+/// The inputs and outputs are ordered from the bottom of the stack to its top.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Operation {
+    pub instruction: Instruction,
+    pub inputs: Vec<Value>,
+    pub outputs: Vec<Value>,
+}
+
+///
+/// The straight-line sequence of operations.
+///
+/// The parameters are the values taken from the stack left by the previous blocks, and
+/// the results are the values left on the stack for the next ones, both ordered from the
+/// bottom of the stack to its top. The operations are kept in their original order, since
+/// most of them have side effects on the memory or the constraint system.
+///
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Block {
+    pub parameters: Vec<Value>,
+    pub operations: Vec<Operation>,
+    pub results: Vec<Value>,
+    values_count: usize,
+}
+
+impl Block {
+    ///
+    /// Allocates a new value, e.g. for an operation created by a pass.
+    ///
+    pub fn allocate(&mut self) -> Value {
+        let value = Value(self.values_count);
+        self.values_count += 1;
+        value
+    }
+
+    ///
+    /// Counts the uses of every value, including the uses as the block results.
+    ///
+    pub fn uses(&self) -> HashMap<Value, usize> {
+        let mut uses = HashMap::with_capacity(self.values_count);
+        for value in self
+            .operations
+            .iter()
+            .flat_map(|operation| operation.inputs.iter())
+            .chain(self.results.iter())
+        {
+            *uses.entry(*value).or_insert(0) += 1;
+        }
+        uses
+    }
+}
+
+///
+/// The program item.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum Item {
+    Block(Block),
+    Control(Instruction),
+}
+
+///
+/// The program in the SSA form.
+///
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Program {
+    pub items: Vec<Item>,
+}
+
+impl Program {
+    ///
+    /// Lifts the bytecode into the SSA form.
+    ///
+    /// Fails if an instruction has invalid operands, e.g. a constant out of its type range.
+    ///
+    pub fn from_instructions(instructions: &[Instruction]) -> Result<Self, ValidationError> {
+        let mut items = Vec::new();
+        let mut block: Option<Lifter> = None;
+
+        for (address, instruction) in instructions.iter().enumerate() {
+            if Self::is_control(instruction) {
+                if let Some(block) = block.take() {
+                    items.push(Item::Block(block.finish()));
+                }
+                items.push(Item::Control(instruction.to_owned()));
+                continue;
+            }
+
+            block
+                .get_or_insert_with(Lifter::default)
+                .lift(address, instruction)?;
+        }
+
+        if let Some(block) = block.take() {
+            items.push(Item::Block(block.finish()));
+        }
+
+        Ok(Self { items })
+    }
+
+    ///
+    /// Lowers the program back to the bytecode.
+    ///
+    /// The values which cannot be kept on the stack are spilled to the temporary memory
+    /// cells starting at the `scratch` address, which must not be used by the function
+    /// the program belongs to. The cells are reused by every block.
+    ///
+    pub fn into_instructions(self, scratch: usize) -> Vec<Instruction> {
+        let mut instructions = Vec::new();
+
+        for item in self.items.into_iter() {
+            match item {
+                Item::Block(block) => block.lower(scratch, &mut instructions),
+                Item::Control(instruction) => instructions.push(instruction),
+            }
+        }
+
+        instructions
+    }
+
+    fn is_control(instruction: &Instruction) -> bool {
+        matches!(
+            instruction,
+            Instruction::If(_)
+                | Instruction::Else(_)
+                | Instruction::EndIf(_)
+                | Instruction::LoopBegin(_)
+                | Instruction::LoopEnd(_)
+                | Instruction::Call(_)
+                | Instruction::Return(_)
+                | Instruction::Exit(_)
+        )
+    }
+}
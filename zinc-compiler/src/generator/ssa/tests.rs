@@ -0,0 +1,311 @@
+//!
+//! The SSA intermediate representation tests.
+//!
+
+#![cfg(test)]
+
+use std::collections::HashMap;
+
+use num_bigint::BigInt;
+use num_traits::ToPrimitive;
+
+use zinc_bytecode::data::types::ScalarType;
+use zinc_bytecode::Instruction;
+
+use crate::generator::ssa::Item;
+use crate::generator::ssa::Program;
+use crate::generator::ssa::Value;
+
+const SCRATCH: usize = 100;
+
+fn push_const(value: i64) -> Instruction {
+    Instruction::PushConst(zinc_bytecode::PushConst::new(
+        BigInt::from(value),
+        ScalarType::Field,
+    ))
+}
+
+fn load(address: usize) -> Instruction {
+    Instruction::Load(zinc_bytecode::Load::new(address))
+}
+
+fn store(address: usize) -> Instruction {
+    Instruction::Store(zinc_bytecode::Store::new(address))
+}
+
+fn pop(count: usize) -> Instruction {
+    Instruction::Pop(zinc_bytecode::Pop::new(count))
+}
+
+///
+/// Evaluates the straight-line `instructions` on the `stack` and the `memory`.
+///
+fn evaluate(instructions: &[Instruction], stack: &mut Vec<i64>, memory: &mut HashMap<usize, i64>) {
+    for instruction in instructions.iter() {
+        match instruction {
+            Instruction::PushConst(instruction) => {
+                stack.push(instruction.value.to_i64().expect("Test constant"))
+            }
+            Instruction::Load(instruction) => stack.push(memory[&instruction.address]),
+            Instruction::Store(instruction) => {
+                let value = stack.pop().expect("Stack underflow");
+                memory.insert(instruction.index, value);
+            }
+            Instruction::Pop(instruction) => {
+                for _ in 0..instruction.count {
+                    stack.pop().expect("Stack underflow");
+                }
+            }
+            Instruction::Swap(_) => {
+                let top = stack.pop().expect("Stack underflow");
+                let below = stack.pop().expect("Stack underflow");
+                stack.push(top);
+                stack.push(below);
+            }
+            Instruction::Tee(_) => {
+                let value = *stack.last().expect("Stack underflow");
+                stack.push(value);
+            }
+            Instruction::Add(_) | Instruction::Sub(_) | Instruction::Mul(_) => {
+                let right = stack.pop().expect("Stack underflow");
+                let left = stack.pop().expect("Stack underflow");
+                stack.push(match instruction {
+                    Instruction::Add(_) => left + right,
+                    Instruction::Sub(_) => left - right,
+                    _ => left * right,
+                });
+            }
+            Instruction::LineMarker(_) => {}
+            instruction => panic!("Unsupported test instruction: {:?}", instruction),
+        }
+    }
+}
+
+///
+/// Checks that the lifted and lowered `instructions` behave exactly as the original ones.
+///
+fn assert_equivalent(instructions: Vec<Instruction>, stack: Vec<i64>) -> Vec<Instruction> {
+    let memory: HashMap<usize, i64> = (0..4)
+        .map(|address| (address, address as i64 + 7))
+        .collect();
+
+    let mut expected_stack = stack.clone();
+    let mut expected_memory = memory.clone();
+    evaluate(
+        instructions.as_slice(),
+        &mut expected_stack,
+        &mut expected_memory,
+    );
+
+    let lowered = Program::from_instructions(instructions.as_slice())
+        .expect("Valid instructions")
+        .into_instructions(SCRATCH);
+
+    let mut result_stack = stack;
+    let mut result_memory = memory;
+    evaluate(lowered.as_slice(), &mut result_stack, &mut result_memory);
+    result_memory.retain(|address, _| *address < SCRATCH);
+
+    assert_eq!(result_stack, expected_stack);
+    assert_eq!(result_memory, expected_memory);
+
+    lowered
+}
+
+#[test]
+fn ok_lift_operations() {
+    let input = vec![
+        load(0),
+        push_const(2),
+        Instruction::Add(zinc_bytecode::Add),
+        store(1),
+    ];
+
+    let program = Program::from_instructions(input.as_slice()).expect("Valid instructions");
+
+    let block = match program.items.as_slice() {
+        [Item::Block(block)] => block,
+        items => panic!("Expected a single block, found {:?}", items),
+    };
+    assert!(block.parameters.is_empty());
+    assert!(block.results.is_empty());
+    assert_eq!(block.operations[2].inputs, vec![Value(0), Value(1)]);
+    assert_eq!(block.operations[2].outputs, vec![Value(2)]);
+    assert_eq!(block.operations[3].inputs, vec![Value(2)]);
+}
+
+#[test]
+fn ok_lift_parameters_and_results() {
+    let input = vec![Instruction::Sub(zinc_bytecode::Sub), load(0)];
+
+    let program = Program::from_instructions(input.as_slice()).expect("Valid instructions");
+
+    let block = match program.items.as_slice() {
+        [Item::Block(block)] => block,
+        items => panic!("Expected a single block, found {:?}", items),
+    };
+    assert_eq!(block.parameters, vec![Value(1), Value(0)]);
+    assert_eq!(block.operations[0].inputs, vec![Value(1), Value(0)]);
+    assert_eq!(block.results, vec![Value(2), Value(3)]);
+}
+
+#[test]
+fn ok_lift_control_delimits_blocks() {
+    let input = vec![
+        load(0),
+        Instruction::If(zinc_bytecode::If),
+        push_const(1),
+        Instruction::Else(zinc_bytecode::Else),
+        push_const(2),
+        Instruction::EndIf(zinc_bytecode::EndIf),
+        store(1),
+    ];
+
+    let program = Program::from_instructions(input.as_slice()).expect("Valid instructions");
+
+    let kinds: Vec<bool> = program
+        .items
+        .iter()
+        .map(|item| match item {
+            Item::Block(_) => true,
+            Item::Control(_) => false,
+        })
+        .collect();
+    assert_eq!(kinds, vec![true, false, true, false, true, false, true]);
+    assert_eq!(program.into_instructions(SCRATCH), input);
+}
+
+#[test]
+fn ok_roundtrip_without_shuffles() {
+    let input = vec![
+        Instruction::LineMarker(zinc_bytecode::LineMarker::new(1)),
+        load(0),
+        load(1),
+        push_const(3),
+        Instruction::Mul(zinc_bytecode::Mul),
+        Instruction::Add(zinc_bytecode::Add),
+        store(2),
+        push_const(5),
+        pop(1),
+    ];
+
+    let result = assert_equivalent(input.clone(), vec![]);
+
+    assert_eq!(result, input);
+}
+
+#[test]
+fn ok_roundtrip_parameters() {
+    let input = vec![
+        push_const(3),
+        Instruction::Mul(zinc_bytecode::Mul),
+        Instruction::Add(zinc_bytecode::Add),
+    ];
+
+    let result = assert_equivalent(input.clone(), vec![2, 5]);
+
+    assert_eq!(result, input);
+}
+
+#[test]
+fn ok_lower_tee() {
+    let input = vec![
+        load(0),
+        Instruction::Tee(zinc_bytecode::Tee),
+        Instruction::Mul(zinc_bytecode::Mul),
+        store(1),
+    ];
+
+    let result = assert_equivalent(input, vec![]);
+
+    assert_eq!(
+        result,
+        vec![
+            load(0),
+            store(SCRATCH),
+            load(SCRATCH),
+            load(SCRATCH),
+            Instruction::Mul(zinc_bytecode::Mul),
+            store(1),
+        ]
+    );
+}
+
+#[test]
+fn ok_lower_swap() {
+    let input = vec![
+        load(0),
+        load(1),
+        Instruction::Swap(zinc_bytecode::Swap),
+        Instruction::Sub(zinc_bytecode::Sub),
+        store(2),
+    ];
+
+    let result = assert_equivalent(input, vec![]);
+
+    assert_eq!(
+        result,
+        vec![
+            load(0),
+            store(SCRATCH),
+            load(1),
+            load(SCRATCH),
+            Instruction::Sub(zinc_bytecode::Sub),
+            store(2),
+        ]
+    );
+}
+
+#[test]
+fn ok_lower_unused_below_used() {
+    let input = vec![
+        load(0),
+        load(1),
+        Instruction::Swap(zinc_bytecode::Swap),
+        pop(1),
+        load(2),
+        Instruction::Add(zinc_bytecode::Add),
+        store(3),
+    ];
+
+    assert_equivalent(input, vec![]);
+}
+
+#[test]
+fn ok_lower_shuffled_parameters_and_results() {
+    let input = vec![
+        Instruction::Swap(zinc_bytecode::Swap),
+        Instruction::Tee(zinc_bytecode::Tee),
+        store(0),
+        load(1),
+        Instruction::Swap(zinc_bytecode::Swap),
+    ];
+
+    assert_equivalent(input, vec![4, 9, 6]);
+}
+
+#[test]
+fn ok_lower_after_pass() {
+    let input = vec![
+        load(0),
+        push_const(0),
+        Instruction::Add(zinc_bytecode::Add),
+        store(1),
+    ];
+
+    let mut program = Program::from_instructions(input.as_slice()).expect("Valid instructions");
+    if let Item::Block(block) = &mut program.items[0] {
+        let sum = block.operations[2].outputs[0];
+        let left = block.operations[2].inputs[0];
+        block.operations.drain(1..3);
+        for operation in block.operations.iter_mut() {
+            for input in operation.inputs.iter_mut() {
+                if *input == sum {
+                    *input = left;
+                }
+            }
+        }
+    }
+
+    assert_eq!(program.into_instructions(SCRATCH), vec![load(0), store(1)]);
+}
@@ -43,7 +43,7 @@ impl Statement {
         let size = self.r#type.size();
         let address = bytecode
             .borrow_mut()
-            .declare_variable(Some(self.name), self.r#type.clone());
+            .declare_variable(None, self.r#type.clone());
 
         self.expression.write_all_to_bytecode(bytecode.clone());
 
@@ -57,5 +57,6 @@ impl Statement {
             Instruction::StoreSequence(zinc_bytecode::StoreSequence::new(address, size)),
             Some(self.location),
         );
+        bytecode.borrow_mut().bind_variable(self.name, address);
     }
 }
@@ -8,6 +8,7 @@ use std::rc::Rc;
 use zinc_bytecode::Instruction;
 
 use crate::generator::bytecode::Bytecode;
+use crate::generator::inliner::Hint as InlineHint;
 use crate::generator::expression::operand::block::Expression;
 use crate::generator::r#type::Type;
 use crate::lexical::token::location::Location;
@@ -21,10 +22,13 @@ pub struct Statement {
     pub location: Location,
     pub identifier: String,
     pub input_arguments: Vec<(String, Type)>,
+    pub public_arguments: Vec<String>,
     pub body: Expression,
     pub output_type: Option<Type>,
     pub unique_id: usize,
     pub is_main: bool,
+    pub test_name: Option<String>,
+    pub inline: InlineHint,
 }
 
 impl Statement {
@@ -32,10 +36,13 @@ impl Statement {
         location: Location,
         identifier: String,
         input_arguments: Vec<(String, SemanticType)>,
+        public_arguments: Vec<String>,
         body: Expression,
         output_type: SemanticType,
         unique_id: usize,
         is_main: bool,
+        test_name: Option<String>,
+        inline: InlineHint,
     ) -> Self {
         let input_arguments = input_arguments
             .into_iter()
@@ -51,10 +58,13 @@ impl Statement {
             location,
             identifier,
             input_arguments,
+            public_arguments,
             body,
             output_type,
             unique_id,
             is_main,
+            test_name,
+            inline,
         }
     }
 
@@ -69,6 +79,7 @@ impl Statement {
             bytecode.borrow_mut().start_main_function(
                 self.unique_id,
                 self.input_arguments.clone(),
+                self.public_arguments,
                 self.output_type,
             );
         } else {
@@ -77,6 +88,12 @@ impl Statement {
                 .start_function(self.unique_id, self.identifier);
         }
 
+        if let Some(test_name) = self.test_name {
+            bytecode
+                .borrow_mut()
+                .declare_test_function(test_name, self.unique_id);
+        }
+
         for (argument_name, argument_type) in self.input_arguments.into_iter() {
             bytecode
                 .borrow_mut()
@@ -89,5 +106,11 @@ impl Statement {
             Instruction::Return(zinc_bytecode::Return::new(output_size)),
             Some(self.location),
         );
+
+        if !self.is_main {
+            bytecode
+                .borrow_mut()
+                .end_function(self.unique_id, self.inline);
+        }
     }
 }
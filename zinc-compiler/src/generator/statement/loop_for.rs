@@ -62,6 +62,7 @@ impl Statement {
         let index_type =
             Type::integer(self.index_variable_is_signed, self.index_variable_bitlength);
         let index_size = index_type.size();
+        bytecode.borrow_mut().start_block();
         let index_address = bytecode
             .borrow_mut()
             .declare_variable(Some(self.index_variable_name), index_type);
@@ -214,5 +215,6 @@ impl Statement {
             Instruction::LoopEnd(zinc_bytecode::LoopEnd),
             Some(self.location),
         );
+        bytecode.borrow_mut().end_block();
     }
 }
@@ -0,0 +1,188 @@
+//!
+//! The bytecode generator tests.
+//!
+
+#![cfg(test)]
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use num_bigint::BigInt;
+
+use zinc_bytecode::builtins::BuiltinIdentifier;
+use zinc_bytecode::data::types::IntegerType;
+use zinc_bytecode::data::types::ScalarType;
+use zinc_bytecode::CallBuiltin;
+use zinc_bytecode::Instruction;
+use zinc_bytecode::Program;
+use zinc_bytecode::PushConst;
+use zinc_bytecode::SourceMap;
+
+use crate::generator::bytecode::Bytecode;
+use crate::semantic::analyzer::entry::Analyzer as EntryAnalyzer;
+use crate::semantic::scope::builtin::BuiltInItems;
+use crate::syntax::filter::Filter;
+use crate::syntax::parser::Parser;
+
+static PANIC_TEST_DATA: &str = "Test data is always valid";
+
+fn compile(input: &str) -> Vec<Instruction> {
+    compile_bytecode(input).into()
+}
+
+fn compile_bytecode(input: &str) -> Bytecode {
+    let bytecode = Rc::new(RefCell::new(Bytecode::new()));
+    EntryAnalyzer::new()
+        .compile(
+            Parser::default().parse(input, None).expect(PANIC_TEST_DATA),
+            HashMap::new(),
+        )
+        .expect(PANIC_TEST_DATA)
+        .write_all_to_bytecode(bytecode.clone());
+
+    Rc::try_unwrap(bytecode)
+        .expect(crate::PANIC_LAST_SHARED_REFERENCE)
+        .into_inner()
+}
+
+/// The line of the first instruction matching `predicate`.
+fn line<P>(instructions: &[Instruction], predicate: P) -> Option<usize>
+where
+    P: Fn(&Instruction) -> bool,
+{
+    let address = instructions.iter().position(predicate)?;
+    SourceMap::new(instructions)
+        .get(address)
+        .map(|location| location.line)
+}
+
+#[test]
+fn ok_line_markers_first_line() {
+    let input = "fn main(value: u8) -> u8 { value * 2 }";
+
+    let instructions = compile(input);
+
+    assert_eq!(
+        line(instructions.as_slice(), |instruction| matches!(
+            instruction,
+            Instruction::Mul(_)
+        )),
+        Some(1)
+    );
+}
+
+#[test]
+fn ok_line_markers_every_function() {
+    let input = r#"fn double(value: u8) -> u8 { value * 2 }
+fn main(value: u8) -> u8 { double(value) + 1 }
+"#;
+
+    let instructions = compile(input);
+
+    assert_eq!(
+        line(instructions.as_slice(), |instruction| matches!(
+            instruction,
+            Instruction::Mul(_)
+        )),
+        Some(1)
+    );
+    assert_eq!(
+        line(instructions.as_slice(), |instruction| matches!(
+            instruction,
+            Instruction::Add(_)
+        )),
+        Some(2)
+    );
+}
+
+#[test]
+fn ok_test_calls_generic_function() {
+    let input = r#"
+fn sum<const N: u64>(values: [u8; N]) -> u8 {
+    let mut result: u8 = 0;
+    for i in 0..N {
+        result += values[i];
+    }
+    result
+}
+
+#[test]
+fn it_sums() {
+    let values: [u8; 3] = [1, 2, 3];
+    assert!(sum(values) == 6);
+}
+
+fn main() {}
+"#;
+
+    Filter::enable_features(vec![Filter::FEATURE_TEST.to_owned()]);
+
+    let mut bytecode = compile_bytecode(input);
+    let tests = bytecode.test_manifest().tests;
+    assert_eq!(tests.len(), 1);
+
+    let program = Program::from_bytes(bytecode.test_bytes(tests[0].address).as_slice())
+        .expect(PANIC_TEST_DATA);
+    let assembly = zinc_bytecode::assembly::disassemble(&program);
+    let lines: Vec<Vec<&str>> = assembly
+        .lines()
+        .map(|line| line.split_whitespace().collect())
+        .collect();
+
+    // the callee starts with the file marker preceding its function marker
+    let callee = lines
+        .iter()
+        .position(|line| line.ends_with(&["function", "=", "\"sum<3>\""]))
+        .map(|index| lines[index - 1][0])
+        .expect(PANIC_TEST_DATA);
+    let calls: Vec<&str> = lines
+        .iter()
+        .filter(|line| line.get(1) == Some(&"call"))
+        .map(|line| line[2])
+        .collect();
+    assert_eq!(calls, vec!["2", callee]);
+}
+
+#[test]
+fn ok_decimal_multiplication_passes_fraction_bits() {
+    let input = r#"
+fn main(a: Decimal<32, 16>, b: Decimal<32, 16>) -> (Decimal<32, 16>, Decimal<32, 16>) {
+    (a * b, std::math::fixed_div(a, b, std::math::Rounding::Nearest))
+}
+"#;
+
+    let instructions: Vec<Instruction> = compile(input)
+        .into_iter()
+        .filter(|instruction| {
+            matches!(
+                instruction,
+                Instruction::PushConst(_) | Instruction::CallBuiltin(_)
+            )
+        })
+        .collect();
+
+    assert_eq!(
+        instructions,
+        vec![
+            Instruction::PushConst(PushConst::new(
+                BigInt::from(16),
+                ScalarType::Integer(IntegerType::U8),
+            )),
+            Instruction::PushConst(PushConst::new(
+                BigInt::from(BuiltInItems::STD_MATH_ROUNDING_FLOOR),
+                ScalarType::Integer(IntegerType::U8),
+            )),
+            Instruction::CallBuiltin(CallBuiltin::new(BuiltinIdentifier::MathFixedMul, 4, 1)),
+            Instruction::PushConst(PushConst::new(
+                BigInt::from(16),
+                ScalarType::Integer(IntegerType::U8),
+            )),
+            Instruction::PushConst(PushConst::new(
+                BigInt::from(BuiltInItems::STD_MATH_ROUNDING_NEAREST),
+                ScalarType::Integer(IntegerType::U8),
+            )),
+            Instruction::CallBuiltin(CallBuiltin::new(BuiltinIdentifier::MathFixedDiv, 4, 1)),
+        ]
+    );
+}
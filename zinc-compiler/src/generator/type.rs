@@ -87,6 +87,10 @@ impl Type {
             SemanticType::IntegerUnsigned { bitlength } => Some(Self::integer_unsigned(*bitlength)),
             SemanticType::IntegerSigned { bitlength } => Some(Self::integer_signed(*bitlength)),
             SemanticType::Field => Some(Self::field()),
+            SemanticType::Decimal {
+                integer_bits,
+                fraction_bits,
+            } => Some(Self::integer_unsigned(integer_bits + fraction_bits)),
             SemanticType::Array { r#type, size } => {
                 Self::try_from_semantic(r#type).map(|r#type| Self::array(r#type, *size))
             }
@@ -52,6 +52,7 @@ pub fn parse(input: &str) -> Result<(usize, Symbol), Error> {
                 Some(',') => return Ok((size + 1, Symbol::Comma)),
 
                 Some('~') => return Ok((size + 1, Symbol::Tilde)),
+                Some('#') => return Ok((size + 1, Symbol::NumberSign)),
 
                 Some('+') => {
                     size += 1;
@@ -162,6 +163,7 @@ pub fn parse(input: &str) -> Result<(usize, Symbol), Error> {
             State::Asterisk => {
                 return match character {
                     Some('=') => Ok((size + 1, Symbol::AsteriskEquals)),
+                    Some('*') => Ok((size + 1, Symbol::DoubleAsterisk)),
                     _ => Ok((size, Symbol::Asterisk)),
                 }
             }
@@ -249,9 +251,9 @@ mod tests {
 
     #[test]
     fn error_invalid_character() {
-        let input = "#";
+        let input = "@";
         let expected = Err(Error::InvalidCharacter {
-            found: '#',
+            found: '@',
             offset: 0,
         });
         let result = parse(input);
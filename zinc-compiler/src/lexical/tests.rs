@@ -164,9 +164,9 @@ fn error_expected_one_of_hexadecimal() {
 
 #[test]
 fn error_invalid_character() {
-    let input = "#";
+    let input = "@";
 
-    let expected: Result<Token, Error> = Err(Error::invalid_character(Location::new(1, 1), '#'));
+    let expected: Result<Token, Error> = Err(Error::invalid_character(Location::new(1, 1), '@'));
 
     let result = TokenStream::new(input).next();
 
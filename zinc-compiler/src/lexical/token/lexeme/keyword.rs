@@ -34,6 +34,7 @@ pub enum Keyword {
     IntegerUnsigned { bitlength: usize },
     IntegerSigned { bitlength: usize },
     Field,
+    Decimal,
 
     // literals
     True,
@@ -105,6 +106,7 @@ impl TryFrom<&str> for Keyword {
 
             "bool" => return Ok(Self::Bool),
             "field" => return Ok(Self::Field),
+            "Decimal" => return Ok(Self::Decimal),
 
             "true" => return Ok(Self::True),
             "false" => return Ok(Self::False),
@@ -203,6 +205,7 @@ impl fmt::Display for Keyword {
             Self::IntegerUnsigned { bitlength } => write!(f, "u{}", bitlength),
             Self::IntegerSigned { bitlength } => write!(f, "i{}", bitlength),
             Self::Field => write!(f, "field"),
+            Self::Decimal => write!(f, "Decimal"),
 
             Self::True => write!(f, "true"),
             Self::False => write!(f, "false"),
@@ -31,6 +31,7 @@ pub enum Symbol {
     ExclamationMark,
     Lesser,
     Greater,
+    NumberSign,
 
     // two chars
     PlusEquals,
@@ -51,6 +52,7 @@ pub enum Symbol {
     DoubleCircumflex,
     DoubleLesser,
     DoubleGreater,
+    DoubleAsterisk,
     DoubleDot,
     EqualsGreater,
     MinusGreater,
@@ -88,6 +90,7 @@ impl fmt::Display for Symbol {
             Self::ExclamationMark => write!(f, "!"),
             Self::Lesser => write!(f, "<"),
             Self::Greater => write!(f, ">"),
+            Self::NumberSign => write!(f, "#"),
 
             Self::PlusEquals => write!(f, "+="),
             Self::MinusEquals => write!(f, "-="),
@@ -107,6 +110,7 @@ impl fmt::Display for Symbol {
             Self::DoubleCircumflex => write!(f, "^^"),
             Self::DoubleLesser => write!(f, "<<"),
             Self::DoubleGreater => write!(f, ">>"),
+            Self::DoubleAsterisk => write!(f, "**"),
             Self::DoubleDot => write!(f, ".."),
             Self::EqualsGreater => write!(f, "=>"),
             Self::MinusGreater => write!(f, "->"),
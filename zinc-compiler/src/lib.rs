@@ -7,19 +7,35 @@
 #![allow(clippy::should_implement_trait)]
 #![allow(clippy::too_many_arguments)]
 
+pub(crate) mod catalog;
 pub(crate) mod error;
+pub(crate) mod extension;
 pub(crate) mod file;
+pub mod fuzz;
 pub(crate) mod generator;
 pub(crate) mod lexical;
+pub(crate) mod prelude;
 pub(crate) mod semantic;
+pub(crate) mod source;
 pub(crate) mod syntax;
 
+pub use self::catalog::error::Error as CatalogError;
+pub use self::catalog::Catalog;
 pub use self::error::Error;
+pub use self::extension::error::Error as ExtensionError;
+pub use self::extension::Extension;
 pub use self::file::File;
 pub use self::generator::bytecode::Bytecode;
+pub use self::generator::manifest::Manifest as TestManifest;
+pub use self::generator::manifest::Test as TestFunction;
+pub use self::generator::ssa::Program as SsaProgram;
 pub use self::semantic::analyzer::entry::Analyzer as EntryAnalyzer;
+pub use self::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
 pub use self::semantic::analyzer::module::Analyzer as ModuleAnalyzer;
 pub use self::semantic::scope::Scope;
+pub use self::source::error::Error as SourceError;
+pub use self::source::Source;
+pub use self::syntax::filter::Filter;
 pub use self::syntax::parser::Parser;
 pub use self::syntax::tree::Tree;
 
@@ -43,7 +59,10 @@ pub const LIMIT_SCHNORR_MESSAGE_BITS: usize = LIMIT_SCHNORR_MESSAGE_BYTES * BITL
 pub static PANIC_VALIDATED_DURING_LEXICAL_ANALYSIS: &str = "Validated during lexical analysis";
 pub static PANIC_VALIDATED_DURING_SYNTAX_ANALYSIS: &str = "Validated during syntax analysis";
 pub static PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS: &str = "Validated during semantic analysis";
+pub static PANIC_FILTERED_BEFORE_SEMANTIC_ANALYSIS: &str =
+    "Conditional statements are filtered before semantic analysis";
 pub static PANIC_LAST_SHARED_REFERENCE: &str = "There are no other references at this point";
 pub static PANIC_MUTEX_SYNC: &str = "Mutexes never panic";
 pub static PANIC_FILE_INDEX: &str = "File record always exists";
 pub static PANIC_BUILDER_REQUIRES_VALUE: &str = "The builder requires a value: ";
+pub static PANIC_EXTENSION_TYPE: &str = "Extension types are validated during registration";
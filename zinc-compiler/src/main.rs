@@ -3,9 +3,6 @@
 //!
 
 use std::cell::RefCell;
-use std::collections::{HashMap, VecDeque};
-use std::convert::TryFrom;
-use std::ffi::OsString;
 use std::fs::File;
 use std::io::Write;
 use std::path::PathBuf;
@@ -13,19 +10,22 @@ use std::process;
 use std::rc::Rc;
 
 use failure::Fail;
-use log::debug;
 use structopt::StructOpt;
 
-use crate::Error::Compiler;
 use zinc_compiler::Bytecode;
-use zinc_compiler::File as ZincFile;
+use zinc_compiler::Catalog;
+use zinc_compiler::CatalogError;
+use zinc_compiler::ExpressionAnalyzer;
+use zinc_compiler::Filter;
 use zinc_compiler::Scope;
-
-static ZINC_SOURCE_FILE_EXTENSION: &str = "zn";
+use zinc_compiler::Source;
+use zinc_compiler::SourceError;
 
 const EXIT_CODE_SUCCESS: i32 = 0;
 const EXIT_CODE_FAILURE: i32 = 1;
 
+static PANIC_VALIDATED_BY_ARGUMENTS: &str = "Validated by the argument parser";
+
 #[derive(Debug, StructOpt)]
 #[structopt(name = "znc", about = "The Zinc compiler")]
 struct Arguments {
@@ -38,50 +38,117 @@ struct Arguments {
     #[structopt(
         long = "witness",
         parse(from_os_str),
+        required_unless = "library-output",
         help = "The witness template output path"
     )]
-    witness_template_path: PathBuf,
+    witness_template_path: Option<PathBuf>,
     #[structopt(
         long = "public-data",
         parse(from_os_str),
+        required_unless = "library-output",
         help = "The public data template output path"
     )]
-    public_data_template_path: PathBuf,
+    public_data_template_path: Option<PathBuf>,
+    #[structopt(
+        long = "schema",
+        parse(from_os_str),
+        help = "The JSON Schema output path, describing the witness and public data"
+    )]
+    schema_path: Option<PathBuf>,
     #[structopt(
         short = "o",
         long = "output",
         parse(from_os_str),
+        required_unless = "library-output",
         help = "The *.znb bytecode output path"
     )]
-    bytecode_output_path: PathBuf,
+    bytecode_output_path: Option<PathBuf>,
+    #[structopt(
+        name = "library-output",
+        long = "library-output",
+        parse(from_os_str),
+        requires = "manifest-path",
+        help = "Compiles the `lib.zn` library and writes the *.znlib precompiled library to the path"
+    )]
+    library_output_path: Option<PathBuf>,
+    #[structopt(
+        short = "O",
+        long = "optimization-level",
+        default_value = "0",
+        help = "The optimization level: 0 disables the optimizations, 1 enables the peephole optimizer and function inlining"
+    )]
+    optimization_level: usize,
+    #[structopt(
+        long = "messages",
+        parse(from_os_str),
+        help = "The JSON message catalog path to translate the diagnostics"
+    )]
+    messages_path: Option<PathBuf>,
+    #[structopt(
+        long = "features",
+        use_delimiter = true,
+        help = "The conditional compilation features to enable, e.g. `--features test,debug`"
+    )]
+    features: Vec<String>,
+    #[structopt(
+        long = "warn-shadowing",
+        help = "Warns about the variables shadowing other variables"
+    )]
+    warn_shadowing: bool,
+    #[structopt(
+        long = "warn-widening",
+        help = "Warns about the integer operands implicitly widened to a greater bitlength"
+    )]
+    warn_widening: bool,
+    #[structopt(
+        long = "test-manifest",
+        parse(from_os_str),
+        help = "Compiles the `#[test]` functions and writes the unit test manifest to the path"
+    )]
+    test_manifest_path: Option<PathBuf>,
+    #[structopt(
+        name = "manifest-path",
+        long = "manifest-path",
+        parse(from_os_str),
+        help = "The Zinc.toml project manifest path, declaring the library dependencies"
+    )]
+    manifest_path: Option<PathBuf>,
+    #[structopt(
+        long = "registry-path",
+        parse(from_os_str),
+        help = "The library registry path, `$ZINC_REGISTRY_PATH` or `~/.zinc/registry` by default"
+    )]
+    registry_path: Option<PathBuf>,
     #[structopt(parse(from_os_str), help = "The *.zn source file names")]
     source_files: Vec<PathBuf>,
 }
 
 #[derive(Debug, Fail)]
 enum Error {
-    #[fail(display = "source file: {}", _0)]
-    SourceFile(FileError),
     #[fail(display = "{}", _0)]
-    Compiler(String),
+    Source(SourceError),
     #[fail(display = "witness template output: {}", _0)]
     WitnessTemplateOutput(OutputError),
     #[fail(display = "public data template output: {}", _0)]
     PublicDataTemplateOutput(OutputError),
+    #[fail(display = "schema output: {}", _0)]
+    SchemaOutput(OutputError),
     #[fail(display = "bytecode output: {}", _0)]
     BytecodeOutput(OutputError),
-    #[fail(display = "the 'main.zn' source file is missing")]
-    EntrySourceFileNotFound,
+    #[fail(display = "test manifest output: {}", _0)]
+    TestManifestOutput(OutputError),
+    #[fail(display = "library output: {}", _0)]
+    LibraryOutput(OutputError),
+    #[fail(display = "message catalog: {}", _0)]
+    MessageCatalog(MessageCatalogError),
 }
 
 #[derive(Debug, Fail)]
-enum FileError {
-    #[fail(display = "file extension not found")]
-    ExtensionNotFound,
-    #[fail(display = "file extension is invalid")]
-    ExtensionInvalid(OsString),
-    #[fail(display = "file name not found")]
-    StemNotFound,
+enum MessageCatalogError {
+    #[fail(display = "reading: {}", _0)]
+    Reading(std::io::Error),
+    #[fail(display = "loading: {}", _0)]
+    Loading(CatalogError),
 }
 
 #[derive(Debug, Fail)]
@@ -104,211 +171,97 @@ fn main() {
     })
 }
 
-// Implementation of intermodule dependencies for Zinc. 
-
-// IMPORTANT NOTE ABOUT THE UPDATE
-// In original Zinc, intermodule dependencies are not supported.
-// Dependencies can only used in the main module of the project. 
-// With this update, we enable modules depending on other modules 
-// by topologically sorting source files based on their mod 
-// statements before starting compilation. This works, as long as 
-// there are no cyclic dependencies. If there are, compilation 
-// fails.
-
-// Topologically sort the module path into L
-
-// L ← Empty list that will contain the sorted nodes
-// while exists nodes without a permanent mark do
-//     select an unmarked node n
-//     visit(n)
-//
-// function visit(node n)
-//     if n has a permanent mark then
-//         return
-//     if n has a temporary mark then
-//         stop   (not a DAG)
-//
-//     mark n with a temporary mark
-//
-//     for each node m with an edge from n to m do
-//         visit(m)
-//
-//     remove temporary mark from n
-//     mark n with a permanent mark
-//     add n to head of L
-fn visit(
-    n: PathBuf,
-    L: &mut VecDeque<PathBuf>,
-    temp_marks: &mut Vec<PathBuf>,
-) -> Result<(), Error> {
-    debug!("Visiting module {}", n.display());
-    // if n has a permanent mark then
-    //         return
-    if L.contains(&n) {
-        debug!("Already sorted: {}", n.display());
-
-        return Ok(());
-    } // already in sorted list
+fn main_inner(args: Arguments) -> Result<(), Error> {
+    zinc_bytecode::logger::init_logger("znc", args.verbosity);
 
-    // if n has a temporary mark then
-    //         stop   (not a DAG)
-    debug!("TEMP MARK - CHECK : {}", n.display());
-    if temp_marks.contains(&n) {
-        debug!("TEMP MARK - CHECK : {}", "FOUND!");
-        return Err(Error::Compiler(format!(
-            "Cyclic module dependencies are not supported. Found in: {}",
-            n.display()
-        )));
+    if let Some(messages_path) = args.messages_path {
+        let messages = std::fs::read_to_string(messages_path)
+            .map_err(MessageCatalogError::Reading)
+            .map_err(Error::MessageCatalog)?;
+        Catalog::from_json(messages.as_str())
+            .map_err(MessageCatalogError::Loading)
+            .map_err(Error::MessageCatalog)?
+            .install();
     }
 
-    // mark n with a temporary mark
-    debug!("TEMP MARK - ADD   : {}", n.display());
-    temp_marks.push(n.clone());
-
-    //  for each node m with an edge from n to m do
-    //         visit(m)
-    let found_modules = ZincFile::try_from(n.clone())
-        .map_err(Error::Compiler)?
-        .find_modules()
-        .map_err(Error::Compiler)?;
-
-    debug!("Found # modules: {}", found_modules.len());
-
-    found_modules
-        .into_iter()
-        .try_for_each(|m| {
-            // We assume that all modules are in the root path, next main.zn.
-            // File name equals: <module name>.zn
-            let module_path = n.with_file_name(m + ".zn");
-            visit(module_path, L, temp_marks)
-        })
-        .map_err(|e| {
-            Compiler(format!(
-                "Compilation failed during module graph ordering:\n{}",
-                e
-            ))
-        })?;
-
-    //     remove temporary mark from n
-    debug!("TEMP MARK - REMOVE: {}", n.display());
-    if let Some(pos) = temp_marks.iter().position(|x| *x == n) {
-        temp_marks.remove(pos);
+    Filter::enable_features(args.features);
+    if args.test_manifest_path.is_some() {
+        Filter::enable_features(vec![Filter::FEATURE_TEST.to_owned()]);
     }
-
-    //     mark n with a permanent mark. In our case, that is same as adding to head of L
-    //     add n to head of L
-    debug!("Adding to sorted list: {}", n.display());
-    L.push_back(PathBuf::from(&n));
-
-    Ok(())
-}
-
-fn ordered_source_files(source_files: Vec<PathBuf>) -> Result<VecDeque<PathBuf>, Error> {
-    let mut L = VecDeque::<PathBuf>::new();
-    let mut temp_marks = Vec::<PathBuf>::new();
-
-    for source_file_path in source_files.into_iter() {
-        let source_file_extension = source_file_path
-            .extension()
-            .ok_or(FileError::ExtensionNotFound)
-            .map_err(Error::SourceFile)?;
-        if source_file_extension != ZINC_SOURCE_FILE_EXTENSION {
-            return Err(FileError::ExtensionInvalid(
-                source_file_extension.to_owned(),
-            ))
-            .map_err(Error::SourceFile);
-        }
-
-        visit(source_file_path, &mut L, &mut temp_marks).map_err(|e| {
-            Compiler(format!(
-                "Compilation failed during module graph ordering:\n{}",
-                e
-            ))
-        })?;
+    if args.warn_shadowing {
+        Scope::enable_shadowing_warning();
+    }
+    if args.warn_widening {
+        ExpressionAnalyzer::enable_widening_warning();
     }
-    Ok(L)
-}
-
-fn main_inner(args: Arguments) -> Result<(), Error> {
-    zinc_bytecode::logger::init_logger("znc", args.verbosity);
-
-    let ordered_source_files = ordered_source_files(args.source_files).map_err(|e| {
-        Error::Compiler(format!("Could not determine ordered source files:\n{}", e))
-    })?;
-
-    ordered_source_files
-        .iter()
-        .for_each(|file| debug!("Ordered file: {}", file.display()));
-
-    let bytecode = Rc::new(RefCell::new(Bytecode::new()));
-
-    let mut modules = HashMap::<String, Rc<RefCell<Scope>>>::new();
-    let mut entry_file_path = None;
-
-    for source_file_path in ordered_source_files.into_iter() {
-        let source_file_extension = source_file_path
-            .extension()
-            .ok_or(FileError::ExtensionNotFound)
-            .map_err(Error::SourceFile)?;
-        if source_file_extension != ZINC_SOURCE_FILE_EXTENSION {
-            return Err(FileError::ExtensionInvalid(
-                source_file_extension.to_owned(),
-            ))
-            .map_err(Error::SourceFile);
-        }
-
-        let source_file_stem = source_file_path
-            .file_stem()
-            .ok_or(FileError::StemNotFound)
-            .map_err(Error::SourceFile)?;
-        if source_file_stem == "main" {
-            entry_file_path = Some(source_file_path);
-            continue;
-        }
 
-        let module_name = source_file_stem.to_string_lossy().to_string();
+    if let Some(library_output_path) = args.library_output_path {
+        let manifest_path = args.manifest_path.expect(PANIC_VALIDATED_BY_ARGUMENTS);
+        let source = Source::try_library_from_manifest(
+            &manifest_path,
+            args.source_files,
+            args.registry_path
+                .unwrap_or_else(Source::default_registry_path),
+        )
+        .map_err(Error::Source)?;
+
+        let bytecode = Rc::new(RefCell::new(Bytecode::new()));
         bytecode
             .borrow_mut()
-            .start_new_file(source_file_path.to_string_lossy().as_ref());
+            .set_optimization_level(args.optimization_level);
 
-        log::info!("Compiling {:?}", source_file_path);
-        let module = ZincFile::try_from(source_file_path)
-            .map_err(Error::Compiler)?
-            .try_into_module(bytecode.clone(), modules.clone())
-            .map_err(Error::Compiler)?;
+        let library = source.compile_library(bytecode).map_err(Error::Source)?;
 
-        modules.insert(module_name, module);
-    }
+        File::create(&library_output_path)
+            .map_err(OutputError::Creating)
+            .map_err(Error::LibraryOutput)?
+            .write_all(library.to_bytes().as_slice())
+            .map_err(OutputError::Writing)
+            .map_err(Error::LibraryOutput)?;
+        log::info!("Library written to {:?}", library_output_path);
 
-    match entry_file_path.take() {
-        Some(entry_file_path) => {
-            bytecode
-                .borrow_mut()
-                .start_new_file(entry_file_path.to_string_lossy().as_ref());
+        return Ok(());
+    }
 
-            log::info!("Compiling {:?}", entry_file_path);
-            ZincFile::try_from(entry_file_path)
-                .map_err(Error::Compiler)?
-                .try_into_entry(bytecode.clone(), modules)
-                .map_err(Error::Compiler)?;
-        }
-        None => return Err(Error::EntrySourceFileNotFound),
+    let witness_template_path = args
+        .witness_template_path
+        .expect(PANIC_VALIDATED_BY_ARGUMENTS);
+    let public_data_template_path = args
+        .public_data_template_path
+        .expect(PANIC_VALIDATED_BY_ARGUMENTS);
+    let bytecode_output_path = args
+        .bytecode_output_path
+        .expect(PANIC_VALIDATED_BY_ARGUMENTS);
+
+    let source = match args.manifest_path {
+        Some(manifest_path) => Source::try_from_manifest(
+            &manifest_path,
+            args.source_files,
+            args.registry_path
+                .unwrap_or_else(Source::default_registry_path),
+        ),
+        None => Source::try_from_paths(args.source_files),
     }
+    .map_err(Error::Source)?;
+
+    let bytecode = Rc::new(RefCell::new(Bytecode::new()));
+    bytecode
+        .borrow_mut()
+        .set_optimization_level(args.optimization_level);
 
-    if !args.witness_template_path.exists() {
-        File::create(&args.witness_template_path)
+    source.compile(bytecode.clone()).map_err(Error::Source)?;
+
+    if !witness_template_path.exists() {
+        File::create(&witness_template_path)
             .map_err(OutputError::Creating)
             .map_err(Error::WitnessTemplateOutput)?
             .write_all(bytecode.borrow().input_template_bytes().as_slice())
             .map_err(OutputError::Writing)
             .map_err(Error::WitnessTemplateOutput)?;
-        log::info!(
-            "Witness template written to {:?}",
-            args.witness_template_path
-        );
+        log::info!("Witness template written to {:?}", witness_template_path);
     }
 
-    File::create(&args.public_data_template_path)
+    File::create(&public_data_template_path)
         .map_err(OutputError::Creating)
         .map_err(Error::PublicDataTemplateOutput)?
         .write_all(bytecode.borrow().output_template_bytes().as_slice())
@@ -316,20 +269,41 @@ fn main_inner(args: Arguments) -> Result<(), Error> {
         .map_err(Error::PublicDataTemplateOutput)?;
     log::info!(
         "Public data template written to {:?}",
-        args.public_data_template_path
+        public_data_template_path
     );
 
-    let bytecode = Rc::try_unwrap(bytecode)
+    if let Some(schema_path) = args.schema_path {
+        File::create(&schema_path)
+            .map_err(OutputError::Creating)
+            .map_err(Error::SchemaOutput)?
+            .write_all(bytecode.borrow().schema_bytes().as_slice())
+            .map_err(OutputError::Writing)
+            .map_err(Error::SchemaOutput)?;
+        log::info!("Schema written to {:?}", schema_path);
+    }
+
+    let mut bytecode = Rc::try_unwrap(bytecode)
         .expect(zinc_compiler::PANIC_LAST_SHARED_REFERENCE)
         .into_inner();
+    bytecode.optimize();
+
+    if let Some(test_manifest_path) = args.test_manifest_path {
+        File::create(&test_manifest_path)
+            .map_err(OutputError::Creating)
+            .map_err(Error::TestManifestOutput)?
+            .write_all(bytecode.test_manifest().to_bytes().as_slice())
+            .map_err(OutputError::Writing)
+            .map_err(Error::TestManifestOutput)?;
+        log::info!("Test manifest written to {:?}", test_manifest_path);
+    }
 
-    File::create(&args.bytecode_output_path)
+    File::create(&bytecode_output_path)
         .map_err(OutputError::Creating)
         .map_err(Error::BytecodeOutput)?
         .write_all(bytecode.into_bytes().as_slice())
         .map_err(OutputError::Writing)
         .map_err(Error::BytecodeOutput)?;
-    log::info!("Compiled to {:?}", args.bytecode_output_path);
+    log::info!("Compiled to {:?}", bytecode_output_path);
 
     Ok(())
 }
@@ -0,0 +1,180 @@
+//!
+//! The standard library prelude written in Zinc.
+//!
+
+mod tests;
+
+use std::cell::Cell;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use lazy_static::lazy_static;
+
+use zinc_bytecode::Library as BytecodeLibrary;
+
+use crate::generator::bytecode::Bytecode;
+use crate::lexical::token::location::Location;
+use crate::semantic::analyzer::module::Analyzer as ModuleAnalyzer;
+use crate::semantic::scope::item::variant::Variant as ScopeItemVariant;
+use crate::semantic::scope::item::Item as ScopeItem;
+use crate::semantic::scope::Scope;
+use crate::source::linker;
+use crate::source::linker::Declaration;
+use crate::syntax::parser::Parser;
+use crate::syntax::tree::expression::tree::node::operand::Operand as ExpressionOperand;
+use crate::syntax::tree::expression::tree::node::operator::Operator as ExpressionOperator;
+use crate::syntax::tree::expression::tree::node::Node as ExpressionTreeNode;
+use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
+use crate::syntax::tree::identifier::Identifier;
+use crate::syntax::tree::statement::local_mod::Statement as ModuleLocalStatement;
+
+static PANIC_PRELUDE: &str = "The prelude is always valid";
+
+lazy_static! {
+    static ref LIBRARY: BytecodeLibrary = Prelude::compile();
+    static ref REEXPORTS: Vec<Vec<String>> = Prelude::reexports();
+}
+
+thread_local! {
+    static IS_COMPILING: Cell<bool> = const { Cell::new(false) };
+}
+
+///
+/// The prelude is the Zinc source shipped with the compiler, which declares the helpers
+/// written on top of the built-in functions.
+///
+/// The prelude is compiled once into a library, and its code is only linked into
+/// the programs calling its functions. The standard library items it imports with `use`
+/// are re-exported, e.g. `std::prelude::min` is `std::math::min`.
+///
+pub struct Prelude {}
+
+impl Prelude {
+    pub const SOURCE: &'static str = include_str!("prelude.zn");
+    pub const FILE_NAME: &'static str = "std/prelude.zn";
+    pub const LIBRARY_NAME: &'static str = "std::prelude";
+
+    ///
+    /// The compiled prelude.
+    ///
+    pub fn library() -> &'static BytecodeLibrary {
+        &LIBRARY
+    }
+
+    ///
+    /// The `std::prelude` module scope, which is not available to the prelude itself.
+    ///
+    /// The re-exported items are resolved in `std_scope`, which is the `std` module scope.
+    ///
+    pub fn scope(std_scope: &Scope) -> Option<Rc<RefCell<Scope>>> {
+        if IS_COMPILING.with(Cell::get) {
+            return None;
+        }
+
+        let scope = Self::declare().0;
+        for path in REEXPORTS.iter() {
+            let identifier = Identifier::new(
+                Location::new_beginning(None),
+                path.last().expect(PANIC_PRELUDE).to_owned(),
+            );
+            let item = Self::resolve(std_scope, path.as_slice()).expect(PANIC_PRELUDE);
+            Scope::declare_item(scope.clone(), identifier, item).expect(PANIC_PRELUDE);
+        }
+        Some(scope)
+    }
+
+    ///
+    /// The prelude functions with the unique IDs the semantic analyzer knows them by.
+    ///
+    pub fn declare() -> Declaration<'static> {
+        linker::declare(Self::library()).expect(PANIC_PRELUDE)
+    }
+
+    ///
+    /// The paths of the standard library items imported by the prelude, e.g. `std::array::pad`.
+    ///
+    fn reexports() -> Vec<Vec<String>> {
+        Parser::default()
+            .parse(Self::SOURCE, None)
+            .expect(PANIC_PRELUDE)
+            .statements
+            .into_iter()
+            .filter_map(|statement| match statement {
+                ModuleLocalStatement::Use(statement) => Some(Self::path(&statement.path)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn path(tree: &ExpressionTree) -> Vec<String> {
+        match (&*tree.value, &tree.left, &tree.right) {
+            (ExpressionTreeNode::Operand(ExpressionOperand::Identifier(identifier)), _, _) => {
+                vec![identifier.name.to_owned()]
+            }
+            (ExpressionTreeNode::Operator(ExpressionOperator::Path), Some(left), Some(right)) => {
+                let mut path = Self::path(left);
+                path.extend(Self::path(right));
+                path
+            }
+            _ => panic!("{}", PANIC_PRELUDE),
+        }
+    }
+
+    ///
+    /// Resolves the `std::`-prefixed `path` in the `std` module scope.
+    ///
+    fn resolve(std_scope: &Scope, path: &[String]) -> Option<ScopeItem> {
+        let mut names = path.iter();
+        if names.next().map(String::as_str) != Some("std") {
+            return None;
+        }
+
+        let find = |scope: &Scope, name: &str| {
+            scope
+                .items()
+                .find(|(identifier, _item)| identifier.as_str() == name)
+                .map(|(_identifier, item)| item.to_owned())
+        };
+
+        let mut item = find(std_scope, names.next()?)?;
+        for name in names {
+            let next = match item.variant {
+                ScopeItemVariant::Module(ref scope) => find(&scope.borrow(), name)?,
+                _ => return None,
+            };
+            item = next;
+        }
+        Some(item)
+    }
+
+    fn compile() -> BytecodeLibrary {
+        IS_COMPILING.with(|is_compiling| is_compiling.set(true));
+
+        let syntax_tree = Parser::default()
+            .parse(Self::SOURCE, None)
+            .expect(PANIC_PRELUDE);
+        let (scope, intermediate) = ModuleAnalyzer::new(Self::LIBRARY_NAME.to_owned())
+            .compile(syntax_tree, HashMap::new())
+            .expect(PANIC_PRELUDE);
+
+        let bytecode = Rc::new(RefCell::new(Bytecode::new()));
+        bytecode.borrow_mut().start_new_file(Self::FILE_NAME);
+        intermediate.write_all_to_bytecode(bytecode.clone());
+
+        IS_COMPILING.with(|is_compiling| is_compiling.set(false));
+
+        let mut bytecode = Rc::try_unwrap(bytecode)
+            .expect(crate::PANIC_LAST_SHARED_REFERENCE)
+            .into_inner();
+        bytecode.optimize();
+
+        let scope = scope.borrow();
+        linker::export(
+            Self::LIBRARY_NAME.to_owned(),
+            env!("CARGO_PKG_VERSION").to_owned(),
+            &scope,
+            bytecode,
+        )
+    }
+}
@@ -0,0 +1,119 @@
+//!
+//! The prelude tests.
+//!
+
+#![cfg(test)]
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use zinc_bytecode::builtins::BuiltinIdentifier;
+use zinc_bytecode::Instruction;
+
+use crate::generator::bytecode::Bytecode;
+use crate::prelude::Prelude;
+use crate::semantic::analyzer::entry::Analyzer as EntryAnalyzer;
+use crate::syntax::parser::Parser;
+
+static PANIC_TEST_DATA: &str = "Test data is always valid";
+
+fn compile(input: &str) -> Vec<Instruction> {
+    let bytecode = Rc::new(RefCell::new(Bytecode::new()));
+    EntryAnalyzer::new()
+        .compile(
+            Parser::default().parse(input, None).expect(PANIC_TEST_DATA),
+            HashMap::new(),
+        )
+        .expect(PANIC_TEST_DATA)
+        .write_all_to_bytecode(bytecode.clone());
+
+    Rc::try_unwrap(bytecode)
+        .expect(crate::PANIC_LAST_SHARED_REFERENCE)
+        .into_inner()
+        .into()
+}
+
+#[test]
+fn ok_reexported_math() {
+    let input = r#"
+fn main(a: i8, b: i8) -> (i8, i8, i8, i8) {
+    (
+        std::prelude::min(a, b),
+        std::prelude::max(a, b),
+        std::prelude::clamp(a, -10, 10),
+        std::prelude::abs(a),
+    )
+}
+"#;
+
+    let instructions = compile(input);
+
+    // the operation is passed after the arguments
+    let inputs_counts: Vec<usize> = instructions
+        .iter()
+        .filter_map(|instruction| match instruction {
+            Instruction::CallBuiltin(call) if call.identifier == BuiltinIdentifier::MathSelect => {
+                Some(call.inputs_count)
+            }
+            _ => None,
+        })
+        .collect();
+    assert_eq!(inputs_counts, vec![3, 3, 4, 2]);
+}
+
+#[test]
+fn ok_reexported_builtins() {
+    let input = r#"
+fn main(value: [u8; 4]) -> [u8; 2] {
+    let padded = std::prelude::pad(value, 6, 0);
+    std::prelude::truncate(std::prelude::reverse(padded), 2)
+}
+"#;
+
+    let instructions = compile(input);
+
+    assert!(instructions
+        .iter()
+        .any(|instruction| matches!(instruction, Instruction::CallBuiltin(_))));
+}
+
+#[test]
+fn ok_linked_functions() {
+    let input = r#"
+fn main(base: field, value: u64) -> (field, u64, u8) {
+    (
+        std::prelude::pow(base, value),
+        std::prelude::sqrt(value),
+        std::prelude::log2(value),
+    )
+}
+"#;
+
+    let instructions = compile(input);
+
+    let functions: Vec<&str> = instructions
+        .iter()
+        .filter_map(|instruction| match instruction {
+            Instruction::FunctionMarker(marker) => Some(marker.function.as_str()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(functions, vec!["main", "pow", "sqrt", "log2"]);
+}
+
+#[test]
+fn ok_not_linked_if_not_called() {
+    let input = r#"
+fn main(a: u8, b: u8) -> u8 {
+    std::prelude::min(a, b)
+}
+"#;
+
+    let instructions = compile(input);
+
+    assert!(!instructions.iter().any(|instruction| matches!(
+        instruction,
+        Instruction::FileMarker(marker) if marker.file == Prelude::FILE_NAME
+    )));
+}
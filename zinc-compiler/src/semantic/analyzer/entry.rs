@@ -7,11 +7,14 @@ use std::collections::HashMap;
 use std::rc::Rc;
 
 use crate::error::Error as CompilerError;
+use crate::generator::statement::Statement as GeneratorStatement;
 use crate::generator::Tree;
+use crate::semantic::analyzer::instances::Instances;
 use crate::semantic::analyzer::statement::Analyzer as StatementAnalyzer;
 use crate::semantic::error::Error;
 use crate::semantic::scope::stack::Stack as ScopeStack;
 use crate::semantic::scope::Scope;
+use crate::syntax::filter::Filter;
 use crate::syntax::tree::Tree as SyntaxTree;
 
 ///
@@ -41,9 +44,12 @@ impl Analyzer {
         program: SyntaxTree,
         dependencies: HashMap<String, Rc<RefCell<Scope>>>,
     ) -> Result<Tree, CompilerError> {
+        let program = Filter::default().filter(program);
+
         let mut intermediate = Tree::new();
 
-        let mut analyzer = StatementAnalyzer::new(self.scope_stack.top(), dependencies);
+        let instances = Instances::enter_file();
+        let mut analyzer = StatementAnalyzer::new(self.scope_stack.top(), dependencies, Vec::new());
         for statement in program.statements.into_iter() {
             if let Some(statement) = analyzer
                 .local_mod(statement)
@@ -52,6 +58,12 @@ impl Analyzer {
                 intermediate.statements.push(statement);
             }
         }
+        intermediate.statements.extend(
+            instances
+                .take()
+                .into_iter()
+                .map(GeneratorStatement::Function),
+        );
 
         if !self.scope_stack.top().borrow().is_main_function_declared() {
             return Err(CompilerError::Semantic(Error::EntryPointMissing));
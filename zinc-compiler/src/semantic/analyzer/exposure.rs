@@ -0,0 +1,115 @@
+//!
+//! The semantic analyzer `expose!` context.
+//!
+
+use std::cell::RefCell;
+
+use crate::semantic::element::r#type::function::builtin::error::Error as BuiltInFunctionTypeError;
+
+thread_local! {
+    static CONTEXT: RefCell<Context> = const { RefCell::new(Context::new()) };
+}
+
+#[derive(Debug, Default)]
+struct Context {
+    /// The function being analyzed.
+    function: Option<String>,
+    /// Whether the function being analyzed is `main`.
+    is_main: bool,
+    /// The number of the conditional, `match`, and loop blocks being analyzed.
+    branches: usize,
+    /// The names of the values already exposed in `main`.
+    names: Vec<String>,
+}
+
+impl Context {
+    const fn new() -> Self {
+        Self {
+            function: None,
+            is_main: false,
+            branches: 0,
+            names: Vec::new(),
+        }
+    }
+}
+
+///
+/// The guard of a function body analysis.
+///
+/// The public inputs of the circuit must be the same for every witness, so a value can
+/// only be exposed once, in `main`, and outside the conditional and loop blocks. The
+/// analyzers hold the guards while analyzing the blocks, and `expose!` is checked against
+/// them.
+///
+pub struct Exposure {
+    /// The context of the enclosing function, restored when the guard is dropped.
+    outer: Option<Context>,
+}
+
+impl Exposure {
+    ///
+    /// Enters the body of the `function`.
+    ///
+    pub fn enter_function(function: String, is_main: bool) -> Self {
+        let inner = Context {
+            function: Some(function),
+            is_main,
+            ..Context::default()
+        };
+        let outer = CONTEXT.with(|context| context.replace(inner));
+        Self { outer: Some(outer) }
+    }
+
+    ///
+    /// Enters a conditional, `match`, or loop block.
+    ///
+    pub fn enter_branch() -> Branch {
+        CONTEXT.with(|context| context.borrow_mut().branches += 1);
+        Branch
+    }
+
+    ///
+    /// Checks whether the value can be exposed with the `name`, and records the name.
+    ///
+    pub fn expose(name: &str) -> Result<(), BuiltInFunctionTypeError> {
+        CONTEXT.with(|context| {
+            let mut context = context.borrow_mut();
+
+            if !context.is_main {
+                return Err(BuiltInFunctionTypeError::expose_outside_main(
+                    context.function.clone().unwrap_or_default(),
+                ));
+            }
+            if context.branches > 0 {
+                return Err(BuiltInFunctionTypeError::expose_conditional(
+                    name.to_owned(),
+                ));
+            }
+            if context.names.iter().any(|exposed| exposed == name) {
+                return Err(BuiltInFunctionTypeError::expose_duplicate(name.to_owned()));
+            }
+
+            context.names.push(name.to_owned());
+            Ok(())
+        })
+    }
+}
+
+impl Drop for Exposure {
+    fn drop(&mut self) {
+        if let Some(outer) = self.outer.take() {
+            CONTEXT.with(|context| context.replace(outer));
+        }
+    }
+}
+
+///
+/// The guard of a conditional, `match`, or loop block analysis.
+///
+pub struct Branch;
+
+impl Drop for Branch {
+    fn drop(&mut self) {
+        CONTEXT.with(|context| context.borrow_mut().branches -= 1);
+    }
+}
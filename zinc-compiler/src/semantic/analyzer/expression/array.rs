@@ -5,11 +5,17 @@
 use std::cell::RefCell;
 use std::rc::Rc;
 
+use num_bigint::BigInt;
+use num_traits::One;
+use num_traits::ToPrimitive;
+
 use crate::generator::expression::operand::array::builder::Builder as GeneratorArrayExpressionBuilder;
 use crate::generator::expression::operand::Operand as GeneratorExpressionOperand;
 use crate::semantic::analyzer::expression::hint::Hint as TranslationHint;
 use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
 use crate::semantic::element::constant::error::Error as ConstantError;
+use crate::semantic::element::constant::integer::error::Error as IntegerConstantError;
+use crate::semantic::element::constant::integer::Integer as IntegerConstant;
 use crate::semantic::element::constant::Constant;
 use crate::semantic::element::error::Error as ElementError;
 use crate::semantic::element::r#type::Type;
@@ -28,6 +34,9 @@ impl Analyzer {
     ///
     /// Analyzes the array literal expression.
     ///
+    /// The array comprehension is unrolled, that is, its expression is analyzed once for each
+    /// value of the constant index range.
+    ///
     /// Returns the semantic element and the intermediate representation.
     ///
     pub fn analyze(
@@ -94,6 +103,94 @@ impl Analyzer {
                 builder.push_expression(expression);
                 builder.set_size(size);
             }
+            ArrayExpressionVariant::Comprehension {
+                expression,
+                index_identifier,
+                bounds_expression,
+            } => {
+                let expression_location = expression.location;
+                let bounds_expression_location = bounds_expression.location;
+
+                let (start, end, is_signed, bitlength, is_inclusive) =
+                    match ExpressionAnalyzer::new(scope.clone())
+                        .analyze(bounds_expression, TranslationHint::Value)?
+                    {
+                        (Element::Constant(Constant::RangeInclusive(range)), _intermediate) => (
+                            range.start,
+                            range.end,
+                            range.is_signed,
+                            range.bitlength,
+                            true,
+                        ),
+                        (Element::Constant(Constant::Range(range)), _intermediate) => (
+                            range.start,
+                            range.end,
+                            range.is_signed,
+                            range.bitlength,
+                            false,
+                        ),
+                        (element, _intermediate) => {
+                            return Err(Error::LoopBoundsExpectedConstantRangeExpression {
+                                location: bounds_expression_location,
+                                found: element.to_string(),
+                            });
+                        }
+                    };
+
+                let is_reversed = start > end;
+                let mut count = if is_reversed {
+                    start.clone() - end
+                } else {
+                    end - start.clone()
+                };
+                if is_inclusive {
+                    count += BigInt::one();
+                }
+                let count = count.to_usize().ok_or_else(|| {
+                    Error::Element(
+                        bounds_expression_location,
+                        ElementError::Constant(ConstantError::Integer(
+                            IntegerConstantError::IntegerTooLarge {
+                                value: count.clone(),
+                                bitlength,
+                            },
+                        )),
+                    )
+                })?;
+
+                let mut index = start;
+                for _ in 0..count {
+                    let scope = Scope::new_child(scope.clone());
+                    Scope::declare_constant(
+                        scope.clone(),
+                        index_identifier.clone(),
+                        Constant::Integer(IntegerConstant::new(
+                            index.clone(),
+                            is_signed,
+                            bitlength,
+                        )),
+                    )
+                    .map_err(Error::Scope)?;
+
+                    let (element, expression) = ExpressionAnalyzer::new(scope.clone())
+                        .analyze(expression.clone(), TranslationHint::Value)?;
+                    let element_type = Type::from_element(&element, scope)?;
+                    result.push(element_type).map_err(|error| {
+                        Error::Element(
+                            expression_location,
+                            ElementError::Value(ValueError::Array(error)),
+                        )
+                    })?;
+
+                    builder.push_expression(expression);
+
+                    if is_reversed {
+                        index -= BigInt::one();
+                    } else {
+                        index += BigInt::one();
+                    }
+                }
+            }
         }
 
         let intermediate = GeneratorExpressionOperand::Array(builder.finish());
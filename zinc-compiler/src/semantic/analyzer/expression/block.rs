@@ -37,7 +37,8 @@ impl Analyzer {
 
         for statement in block.statements.into_iter() {
             if let Some(statement) =
-                StatementAnalyzer::new(scope_stack.top(), HashMap::new()).local_fn(statement)?
+                StatementAnalyzer::new(scope_stack.top(), HashMap::new(), Vec::new())
+                    .local_fn(statement)?
             {
                 builder.push_statement(statement);
             }
@@ -8,13 +8,25 @@ use std::cell::RefCell;
 use std::convert::TryFrom;
 use std::rc::Rc;
 
+use num_bigint::BigInt;
+
+use zinc_bytecode::builtins::BuiltinIdentifier;
+
+use crate::generator::expression::operand::constant::Constant as GeneratorConstant;
+use crate::generator::expression::operand::list::Expression as GeneratorListExpression;
 use crate::generator::expression::operator::Operator as GeneratorExpressionOperator;
 use crate::lexical::token::location::Location;
+use crate::semantic::analyzer::exposure::Exposure;
+use crate::semantic::analyzer::statement::Analyzer as StatementAnalyzer;
+use crate::semantic::element::constant::Constant;
 use crate::semantic::element::error::Error as ElementError;
 use crate::semantic::element::r#type::error::Error as TypeError;
 use crate::semantic::element::r#type::function::builtin::error::Error as BuiltInFunctionTypeError;
 use crate::semantic::element::r#type::function::builtin::Function as BuiltInFunctionType;
+use crate::semantic::element::r#type::function::enumeration::Function as EnumerationFunctionType;
 use crate::semantic::element::r#type::function::error::Error as FunctionTypeError;
+use crate::semantic::element::r#type::function::stdlib::math_fixed::Function as MathFixedFunction;
+use crate::semantic::element::r#type::function::stdlib::Function as StandardLibraryFunctionType;
 use crate::semantic::element::r#type::function::Function as FunctionType;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::element::value::Value;
@@ -38,6 +50,7 @@ impl Analyzer {
         operand_1: Element,
         operand_2: Element,
         mut call_type: CallType,
+        arguments: &mut GeneratorListExpression,
         location: Location,
     ) -> Result<(Element, GeneratorExpressionOperator), Error> {
         let function = match operand_1 {
@@ -67,15 +80,24 @@ impl Analyzer {
             Element::ArgumentList(values) => values,
             _ => panic!(crate::PANIC_VALIDATED_DURING_SYNTAX_ANALYSIS),
         };
+        let mut arguments_offset = 0;
         match call_type.take() {
-            CallType::Method { instance } => argument_elements.insert(0, instance),
+            CallType::Method { instance } => {
+                argument_elements.insert(0, instance);
+                arguments_offset = 1;
+            }
             another => call_type = another,
         }
         let mut input_size = 0;
         for element in argument_elements.iter() {
+            if let Element::Type(_) = element {
+                // the type arguments are compile-time only and take no place on the stack
+                continue;
+            }
             input_size += Type::from_element(element, scope.clone())?.size();
         }
 
+        let mut constant = None;
         let (return_type, intermediate) = match function {
             FunctionType::BuiltInFunction(function) => {
                 match call_type {
@@ -106,7 +128,21 @@ impl Analyzer {
                         (return_type, intermediate)
                     }
                     BuiltInFunctionType::Assert(function) => {
-                        let (return_type, message) =
+                        let (return_type, message, argument_types, code) =
+                            function.call(argument_elements).map_err(|error| {
+                                Error::Element(
+                                    location,
+                                    ElementError::Type(TypeError::Function(error)),
+                                )
+                            })?;
+
+                        let intermediate =
+                            GeneratorExpressionOperator::call_assert(message, argument_types, code);
+
+                        (return_type, intermediate)
+                    }
+                    BuiltInFunctionType::Expose(function) => {
+                        let (return_type, name, r#type) =
                             function.call(argument_elements).map_err(|error| {
                                 Error::Element(
                                     location,
@@ -114,7 +150,16 @@ impl Analyzer {
                                 )
                             })?;
 
-                        let intermediate = GeneratorExpressionOperator::call_assert(message);
+                        Exposure::expose(name.as_str()).map_err(|error| {
+                            Error::Element(
+                                location,
+                                ElementError::Type(TypeError::Function(
+                                    FunctionTypeError::BuiltIn(error),
+                                )),
+                            )
+                        })?;
+
+                        let intermediate = GeneratorExpressionOperator::call_expose(name, &r#type);
 
                         (return_type, intermediate)
                     }
@@ -132,15 +177,91 @@ impl Analyzer {
 
                 let builtin_identifier = function.builtin_identifier();
 
+                let operand_names: &[&str] = match function {
+                    StandardLibraryFunctionType::MathOverflowing(_)
+                    | StandardLibraryFunctionType::MathFixed(_)
+                    | StandardLibraryFunctionType::MathWrapping(_) => &["a", "b"],
+                    StandardLibraryFunctionType::MathSelect(ref inner) => inner.argument_names(),
+                    StandardLibraryFunctionType::AssertInRange(_) => &["value", "low", "high"],
+                    _ => &[],
+                };
+                if !operand_names.is_empty() {
+                    let operand_type = match function {
+                        StandardLibraryFunctionType::MathWrapping(ref inner) => {
+                            Some(inner.operand_type())
+                        }
+                        _ => argument_elements.iter().find_map(|element| match element {
+                            Element::Value(value) => Some(value.r#type()),
+                            _ => None,
+                        }),
+                    };
+                    if let Some(operand_type) = operand_type {
+                        Self::coerce_constants(
+                            operand_names
+                                .iter()
+                                .map(|name| ((*name).to_owned(), operand_type.clone()))
+                                .collect::<Vec<(String, Type)>>()
+                                .as_slice(),
+                            argument_elements.as_mut_slice(),
+                            arguments,
+                            arguments_offset,
+                            location,
+                        );
+                    }
+                }
+
+                // the sign of a constant is known at compile time, e.g. in a `const` item
+                if let (
+                    BuiltinIdentifier::MathSign,
+                    Some(Element::Constant(Constant::Integer(integer))),
+                ) = (builtin_identifier, argument_elements.first())
+                {
+                    constant = Some(Constant::Integer(integer.sign()));
+                }
+
+                // the select functions share the built-in function taking the operation last
+                if let StandardLibraryFunctionType::MathSelect(ref inner) = function {
+                    arguments.push_constant(GeneratorConstant::new_integer(
+                        BigInt::from(inner.operation() as usize),
+                        false,
+                        crate::BITLENGTH_BYTE,
+                    ));
+                    input_size += 1;
+                }
+
+                let is_math_fixed = matches!(function, StandardLibraryFunctionType::MathFixed(_));
+
                 let return_type = function.call(argument_elements).map_err(|error| {
                     Error::Element(location, ElementError::Type(TypeError::Function(error)))
                 })?;
 
-                let intermediate = GeneratorExpressionOperator::call_std(
-                    builtin_identifier,
-                    input_size,
-                    return_type.size(),
-                );
+                // the decimals carry their fractional bits, which the built-in function expects
+                if let (true, Type::Decimal { fraction_bits, .. }) = (is_math_fixed, &return_type) {
+                    arguments.insert_constant(
+                        MathFixedFunction::ARGUMENT_INDEX_FRAC_BITS - arguments_offset,
+                        GeneratorConstant::new_integer(
+                            BigInt::from(*fraction_bits),
+                            false,
+                            crate::BITLENGTH_BYTE,
+                        ),
+                    );
+                    input_size += 1;
+                }
+
+                let intermediate = match builtin_identifier {
+                    BuiltinIdentifier::EthAbiDecode => {
+                        GeneratorExpressionOperator::call_std_with_template(
+                            builtin_identifier,
+                            input_size,
+                            &return_type,
+                        )
+                    }
+                    builtin_identifier => GeneratorExpressionOperator::call_std(
+                        builtin_identifier,
+                        input_size,
+                        return_type.size(),
+                    ),
+                };
 
                 (return_type, intermediate)
             }
@@ -156,22 +277,183 @@ impl Analyzer {
 
                 let unique_id = function.unique_id();
 
+                Self::coerce_constants(
+                    function.formal_params(),
+                    argument_elements.as_mut_slice(),
+                    arguments,
+                    arguments_offset,
+                    location,
+                );
                 let return_type = function.call(argument_elements).map_err(|error| {
                     Error::Element(location, ElementError::Type(TypeError::Function(error)))
                 })?;
 
                 let intermediate = GeneratorExpressionOperator::call(unique_id, input_size);
 
+                (return_type, intermediate)
+            }
+            FunctionType::Generic(function) => {
+                if let CallType::BuiltIn = call_type {
+                    return Err(Error::Element(
+                        location,
+                        ElementError::Type(TypeError::Function(FunctionTypeError::BuiltIn(
+                            BuiltInFunctionTypeError::unknown(function.identifier().to_owned()),
+                        ))),
+                    ));
+                }
+
+                let values = function
+                    .infer(argument_elements.as_slice())
+                    .map_err(|error| {
+                        Error::Element(location, ElementError::Type(TypeError::Function(error)))
+                    })?;
+                let function = StatementAnalyzer::instantiate(&function, values, location)?;
+                let unique_id = function.unique_id();
+
+                Self::coerce_constants(
+                    function.formal_params(),
+                    argument_elements.as_mut_slice(),
+                    arguments,
+                    arguments_offset,
+                    location,
+                );
+                let return_type = function.call(argument_elements).map_err(|error| {
+                    Error::Element(location, ElementError::Type(TypeError::Function(error)))
+                })?;
+
+                let intermediate = GeneratorExpressionOperator::call(unique_id, input_size);
+
+                (return_type, intermediate)
+            }
+            FunctionType::Extension(function) => {
+                if let CallType::BuiltIn = call_type {
+                    return Err(Error::Element(
+                        location,
+                        ElementError::Type(TypeError::Function(FunctionTypeError::BuiltIn(
+                            BuiltInFunctionTypeError::unknown(function.identifier().to_owned()),
+                        ))),
+                    ));
+                }
+
+                let identifier = function.identifier().to_owned();
+
+                let return_type = function.call(argument_elements).map_err(|error| {
+                    Error::Element(location, ElementError::Type(TypeError::Function(error)))
+                })?;
+
+                let intermediate = GeneratorExpressionOperator::call_extension(
+                    identifier,
+                    input_size,
+                    return_type.size(),
+                );
+
+                (return_type, intermediate)
+            }
+            FunctionType::Host(function) => {
+                if let CallType::BuiltIn = call_type {
+                    return Err(Error::Element(
+                        location,
+                        ElementError::Type(TypeError::Function(FunctionTypeError::BuiltIn(
+                            BuiltInFunctionTypeError::unknown(function.identifier().to_owned()),
+                        ))),
+                    ));
+                }
+
+                let identifier = function.identifier().to_owned();
+
+                let return_type = function.call(argument_elements).map_err(|error| {
+                    Error::Element(location, ElementError::Type(TypeError::Function(error)))
+                })?;
+
+                let intermediate =
+                    GeneratorExpressionOperator::call_host(identifier, input_size, &return_type);
+
+                (return_type, intermediate)
+            }
+            FunctionType::Enumeration(function) => {
+                if let CallType::BuiltIn = call_type {
+                    return Err(Error::Element(
+                        location,
+                        ElementError::Type(TypeError::Function(FunctionTypeError::BuiltIn(
+                            BuiltInFunctionTypeError::unknown(function.identifier().to_owned()),
+                        ))),
+                    ));
+                }
+
+                let intermediate = match function {
+                    EnumerationFunctionType::FromValue(ref enumeration) => {
+                        GeneratorExpressionOperator::call_enumeration_from_value(enumeration)
+                    }
+                    EnumerationFunctionType::TryFrom(ref enumeration) => {
+                        GeneratorExpressionOperator::call_enumeration_try_from(enumeration)
+                    }
+                    EnumerationFunctionType::VariantCount(ref enumeration) => {
+                        GeneratorExpressionOperator::call_enumeration_variant_count(enumeration)
+                    }
+                };
+
+                let return_type = function.call(argument_elements).map_err(|error| {
+                    Error::Element(location, ElementError::Type(TypeError::Function(error)))
+                })?;
+
                 (return_type, intermediate)
             }
         };
 
-        let element = Element::Value(
-            Value::try_from(&return_type)
-                .map_err(ElementError::Value)
-                .map_err(|error| Error::Element(location, error))?,
-        );
+        let element = match constant {
+            Some(constant) => Element::Constant(constant),
+            None => Element::Value(
+                Value::try_from(&return_type)
+                    .map_err(ElementError::Value)
+                    .map_err(|error| Error::Element(location, error))?,
+            ),
+        };
 
         Ok((element, intermediate))
     }
+
+    ///
+    /// Casts the integer constant arguments to the wider integer types of the formal
+    /// parameters, so the literals adopt the expected type without an explicit `as`,
+    /// e.g. `foo(5)` where `fn foo(value: u64)`.
+    ///
+    /// The `arguments` IR does not contain the method instance, which is the first element
+    /// if `offset` is 1.
+    ///
+    fn coerce_constants(
+        formal_params: &[(String, Type)],
+        elements: &mut [Element],
+        arguments: &mut GeneratorListExpression,
+        offset: usize,
+        location: Location,
+    ) {
+        for (index, ((_name, expected), element)) in
+            formal_params.iter().zip(elements.iter_mut()).enumerate()
+        {
+            let constant = match element {
+                Element::Constant(Constant::Integer(integer)) if integer.enumeration.is_none() => {
+                    integer
+                }
+                _ => continue,
+            };
+
+            let is_wider = match expected {
+                Type::IntegerUnsigned { bitlength } | Type::IntegerSigned { bitlength } => {
+                    *bitlength >= constant.bitlength
+                }
+                Type::Field => true,
+                _ => false,
+            };
+            if !is_wider || constant.r#type() == *expected {
+                continue;
+            }
+
+            if let Ok(casted) = Constant::Integer(constant.to_owned()).cast(expected.to_owned()) {
+                *element = Element::Constant(casted);
+                if index >= offset {
+                    arguments.cast(index - offset, expected, location);
+                }
+            }
+        }
+    }
 }
@@ -9,15 +9,25 @@ use std::rc::Rc;
 
 use crate::generator::expression::operand::conditional::builder::Builder as GeneratorConditionalExpressionBuilder;
 use crate::generator::expression::operand::Operand as GeneratorExpressionOperand;
+use crate::semantic::analyzer::exposure::Exposure;
 use crate::semantic::analyzer::expression::block::Analyzer as BlockAnalyzer;
 use crate::semantic::analyzer::expression::hint::Hint as TranslationHint;
+use crate::semantic::analyzer::expression::r#match::Analyzer as MatchAnalyzer;
 use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
+use crate::semantic::element::constant::Constant;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::element::Element;
 use crate::semantic::error::Error;
 use crate::semantic::scope::stack::Stack as ScopeStack;
 use crate::semantic::scope::Scope;
+use crate::syntax::tree::expression::block::Expression as BlockExpression;
 use crate::syntax::tree::expression::conditional::Expression as ConditionalExpression;
+use crate::syntax::tree::expression::r#match::Expression as MatchExpression;
+use crate::syntax::tree::expression::tree::node::operand::Operand as ExpressionOperand;
+use crate::syntax::tree::expression::tree::node::Node as ExpressionTreeNode;
+use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
+use crate::syntax::tree::pattern_match::variant::Variant as MatchPatternVariant;
+use crate::syntax::tree::pattern_match::Pattern as MatchPattern;
 
 pub struct Analyzer {}
 
@@ -29,8 +39,12 @@ impl Analyzer {
     ///
     pub fn analyze(
         scope: Rc<RefCell<Scope>>,
-        conditional: ConditionalExpression,
+        mut conditional: ConditionalExpression,
     ) -> Result<(Element, GeneratorExpressionOperand), Error> {
+        if let Some(pattern) = conditional.pattern.take() {
+            return Self::let_pattern(scope, pattern, conditional);
+        }
+
         let condition_location = conditional.condition.location;
 
         let main_expression_location = conditional
@@ -59,6 +73,10 @@ impl Analyzer {
 
         let (condition_result, condition) = ExpressionAnalyzer::new(scope_stack.top())
             .analyze(*conditional.condition, TranslationHint::Value)?;
+        let constant_condition = match condition_result {
+            Element::Constant(Constant::Boolean(ref boolean)) => Some(boolean.inner),
+            _ => None,
+        };
         match Type::from_element(&condition_result, scope_stack.top())? {
             Type::Boolean => {}
             r#type => {
@@ -70,6 +88,8 @@ impl Analyzer {
         }
         builder.set_condition(condition);
 
+        let _branch = Exposure::enter_branch();
+
         scope_stack.push();
         let (main_result, main_block) =
             BlockAnalyzer::analyze(scope_stack.top(), conditional.main_block)?;
@@ -77,16 +97,16 @@ impl Analyzer {
         scope_stack.pop();
         builder.set_main_block(main_block);
 
-        let else_type = if let Some(else_block) = conditional.else_block {
+        let (else_result, else_type) = if let Some(else_block) = conditional.else_block {
             scope_stack.push();
             let (else_result, else_block) = BlockAnalyzer::analyze(scope_stack.top(), else_block)?;
             let else_type = Type::from_element(&else_result, scope_stack.top())?;
             scope_stack.pop();
             builder.set_else_block(else_block);
 
-            else_type
+            (else_result, else_type)
         } else {
-            Type::Unit
+            (Element::Constant(Constant::Unit), Type::Unit)
         };
 
         // check if the two branches return equals types
@@ -99,9 +119,62 @@ impl Analyzer {
             });
         }
 
-        let element = main_result;
+        // the branch is only known at compile time if the condition is constant
+        let element = match constant_condition {
+            Some(true) => main_result,
+            Some(false) => else_result,
+            None => main_result.into_runtime(),
+        };
         let intermediate = GeneratorExpressionOperand::Conditional(builder.finish());
 
         Ok((element, intermediate))
     }
+
+    ///
+    /// Analyzes the `if let` expression, which is desugared into a match expression with
+    /// the `pattern` branch and the wildcard one for the `else` block.
+    ///
+    fn let_pattern(
+        scope: Rc<RefCell<Scope>>,
+        pattern: MatchPattern,
+        conditional: ConditionalExpression,
+    ) -> Result<(Element, GeneratorExpressionOperand), Error> {
+        match pattern.variant {
+            MatchPatternVariant::Binding(_) | MatchPatternVariant::Wildcard => {
+                return Err(Error::ConditionalPatternIrrefutable {
+                    location: pattern.location,
+                });
+            }
+            _ => {}
+        }
+
+        let location = conditional.location;
+        let main_block = conditional.main_block;
+        let else_block = conditional
+            .else_block
+            .unwrap_or_else(|| BlockExpression::new(location, vec![], None));
+
+        let r#match = MatchExpression::new(
+            location,
+            *conditional.condition,
+            vec![
+                (
+                    pattern,
+                    ExpressionTree::new(
+                        main_block.location,
+                        ExpressionTreeNode::operand(ExpressionOperand::Block(main_block)),
+                    ),
+                ),
+                (
+                    MatchPattern::new(else_block.location, MatchPatternVariant::new_wildcard()),
+                    ExpressionTree::new(
+                        else_block.location,
+                        ExpressionTreeNode::operand(ExpressionOperand::Block(else_block)),
+                    ),
+                ),
+            ],
+        );
+
+        MatchAnalyzer::analyze(scope, r#match)
+    }
 }
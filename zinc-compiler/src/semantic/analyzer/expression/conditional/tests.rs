@@ -4,9 +4,13 @@
 
 #![cfg(test)]
 
+use std::convert::TryFrom;
+
 use crate::error::Error;
 use crate::lexical::token::location::Location;
 use crate::semantic::element::r#type::Type;
+use crate::semantic::element::value::Value;
+use crate::semantic::element::Element;
 use crate::semantic::error::Error as SemanticError;
 
 #[test]
@@ -64,6 +68,45 @@ fn main() -> u8 {
     assert!(crate::semantic::tests::compile_entry(input).is_ok());
 }
 
+#[test]
+fn ok_let_pattern() {
+    let input = r#"
+enum Color {
+    Red = 1,
+    Green = 2,
+}
+
+fn main(color: Color) -> u8 {
+    let mut result = if let Color::Red = color {
+        42
+    } else {
+        69
+    };
+
+    if let 69 = result {
+        result = 25;
+    };
+
+    result
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_constant_condition_array_size() {
+    let input = r#"
+const WIDE: bool = false;
+
+fn main() -> [u8; if WIDE { 4 } else { 2 }] {
+    [1; 2]
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
 #[test]
 fn error_branch_types_mismatch() {
     let input = r#"
@@ -105,3 +148,50 @@ fn main() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn error_let_pattern_irrefutable() {
+    let input = r#"
+fn main(value: u8) -> u8 {
+    if let other = value {
+        other
+    } else {
+        0
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::ConditionalPatternIrrefutable {
+            location: Location::new(3, 12),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_runtime_condition_non_constant() {
+    let input = r#"
+fn main(value: u8) {
+    const SIZE: u8 = if value > 0 { 1 } else { 2 };
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::ConstantExpressionHasNonConstantElement {
+            location: Location::new(3, 22),
+            found: Element::Value(
+                Value::try_from(&Type::integer_unsigned(crate::BITLENGTH_BYTE))
+                    .expect(crate::semantic::tests::PANIC_TEST_DATA),
+            )
+            .to_string(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
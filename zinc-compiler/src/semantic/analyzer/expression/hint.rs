@@ -67,6 +67,7 @@ impl Hint {
             ExpressionOperator::Multiplication => Self::Value,
             ExpressionOperator::Division => Self::Value,
             ExpressionOperator::Remainder => Self::Value,
+            ExpressionOperator::Exponentiation => Self::Value,
 
             ExpressionOperator::Casting => Self::Value,
 
@@ -126,6 +127,7 @@ impl Hint {
             ExpressionOperator::Multiplication => Self::Value,
             ExpressionOperator::Division => Self::Value,
             ExpressionOperator::Remainder => Self::Value,
+            ExpressionOperator::Exponentiation => Self::Value,
 
             ExpressionOperator::Casting => Self::Type,
 
@@ -14,6 +14,7 @@ use crate::generator::expression::operand::constant::Constant as GeneratorConsta
 use crate::generator::expression::operand::r#match::builder::Builder as GeneratorMatchExpressionBuilder;
 use crate::generator::expression::operand::Operand as GeneratorExpressionOperand;
 use crate::generator::r#type::Type as GeneratorType;
+use crate::semantic::analyzer::exposure::Exposure;
 use crate::semantic::analyzer::expression::hint::Hint as TranslationHint;
 use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
 use crate::semantic::element::constant::boolean::Boolean as BooleanConstant;
@@ -79,6 +80,7 @@ impl Analyzer {
         let mut exhausting_data = ExhaustingData::new();
         let mut branch_results = Vec::with_capacity(r#match.branches.len());
 
+        let _branch = Exposure::enter_branch();
         for (pattern, expression) in r#match.branches.into_iter() {
             let pattern_location = pattern.location;
             let expression_location = expression.location;
@@ -265,7 +267,7 @@ impl Analyzer {
         }
 
         let element = match branch_results.pop() {
-            Some(result) => result,
+            Some(result) => result.into_runtime(),
             None => Element::Constant(Constant::Unit),
         };
         let intermediate = GeneratorExpressionOperand::Match(builder.finish());
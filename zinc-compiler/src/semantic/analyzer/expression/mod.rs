@@ -22,7 +22,10 @@ pub mod tuple;
 pub mod r#type;
 
 use std::cell::RefCell;
+use std::cmp;
 use std::rc::Rc;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
 
 use crate::generator::expression::operand::constant::Constant as GeneratorExpressionConstant;
 use crate::generator::expression::operand::Operand as GeneratorExpressionOperand;
@@ -65,6 +68,11 @@ use self::stack::Stack as EvaluationStack;
 use self::structure::Analyzer as StructureAnalyzer;
 use self::tuple::Analyzer as TupleAnalyzer;
 
+///
+/// Whether the implicitly widened integer operands are reported with a warning.
+///
+static WARN_WIDENING: AtomicBool = AtomicBool::new(false);
+
 ///
 /// The expression semantic analyzer.
 ///
@@ -90,6 +98,13 @@ impl Analyzer {
         }
     }
 
+    ///
+    /// Enables the implicit integer widening warning for all the files compiled afterwards.
+    ///
+    pub fn enable_widening_warning() {
+        WARN_WIDENING.store(true, Ordering::Relaxed);
+    }
+
     ///
     /// Analyzes the expression `tree`, producing a semantic element and the IR tree.
     ///
@@ -144,7 +159,9 @@ impl Analyzer {
                 ExpressionOperator::Assignment => {
                     self.left_global(tree.left, operator)?;
                     let expression = self.right_global(tree.right, operator)?;
-                    let place = self.assignment(Element::assign, tree.location)?.into();
+                    let place = self
+                        .assignment(Element::assign, None, tree.location)?
+                        .into();
                     self.intermediate.push_operator(
                         tree.location,
                         GeneratorExpressionOperator::Assignment { place, expression },
@@ -152,9 +169,13 @@ impl Analyzer {
                 }
                 ExpressionOperator::AssignmentBitwiseOr => {
                     self.left_global(tree.left, operator)?;
-                    let expression = self.right_global(tree.right, operator)?;
+                    let mut expression = self.right_global(tree.right, operator)?;
                     let place = self
-                        .assignment(Element::assign_bitwise_or, tree.location)?
+                        .assignment(
+                            Element::assign_bitwise_or,
+                            Some(&mut expression),
+                            tree.location,
+                        )?
                         .into();
                     self.intermediate.push_operator(
                         tree.location,
@@ -163,9 +184,13 @@ impl Analyzer {
                 }
                 ExpressionOperator::AssignmentBitwiseXor => {
                     self.left_global(tree.left, operator)?;
-                    let expression = self.right_global(tree.right, operator)?;
+                    let mut expression = self.right_global(tree.right, operator)?;
                     let place = self
-                        .assignment(Element::assign_bitwise_xor, tree.location)?
+                        .assignment(
+                            Element::assign_bitwise_xor,
+                            Some(&mut expression),
+                            tree.location,
+                        )?
                         .into();
                     self.intermediate.push_operator(
                         tree.location,
@@ -174,9 +199,13 @@ impl Analyzer {
                 }
                 ExpressionOperator::AssignmentBitwiseAnd => {
                     self.left_global(tree.left, operator)?;
-                    let expression = self.right_global(tree.right, operator)?;
+                    let mut expression = self.right_global(tree.right, operator)?;
                     let place = self
-                        .assignment(Element::assign_bitwise_and, tree.location)?
+                        .assignment(
+                            Element::assign_bitwise_and,
+                            Some(&mut expression),
+                            tree.location,
+                        )?
                         .into();
                     self.intermediate.push_operator(
                         tree.location,
@@ -187,7 +216,7 @@ impl Analyzer {
                     self.left_global(tree.left, operator)?;
                     let expression = self.right_global(tree.right, operator)?;
                     let place = self
-                        .assignment(Element::assign_bitwise_shift_left, tree.location)?
+                        .assignment(Element::assign_bitwise_shift_left, None, tree.location)?
                         .into();
                     self.intermediate.push_operator(
                         tree.location,
@@ -201,7 +230,7 @@ impl Analyzer {
                     self.left_global(tree.left, operator)?;
                     let expression = self.right_global(tree.right, operator)?;
                     let place = self
-                        .assignment(Element::assign_bitwise_shift_right, tree.location)?
+                        .assignment(Element::assign_bitwise_shift_right, None, tree.location)?
                         .into();
                     self.intermediate.push_operator(
                         tree.location,
@@ -213,8 +242,10 @@ impl Analyzer {
                 }
                 ExpressionOperator::AssignmentAddition => {
                     self.left_global(tree.left, operator)?;
-                    let expression = self.right_global(tree.right, operator)?;
-                    let place = self.assignment(Element::assign_add, tree.location)?.into();
+                    let mut expression = self.right_global(tree.right, operator)?;
+                    let place = self
+                        .assignment(Element::assign_add, Some(&mut expression), tree.location)?
+                        .into();
                     self.intermediate.push_operator(
                         tree.location,
                         GeneratorExpressionOperator::AssignmentAddition { place, expression },
@@ -222,9 +253,13 @@ impl Analyzer {
                 }
                 ExpressionOperator::AssignmentSubtraction => {
                     self.left_global(tree.left, operator)?;
-                    let expression = self.right_global(tree.right, operator)?;
+                    let mut expression = self.right_global(tree.right, operator)?;
                     let place = self
-                        .assignment(Element::assign_subtract, tree.location)?
+                        .assignment(
+                            Element::assign_subtract,
+                            Some(&mut expression),
+                            tree.location,
+                        )?
                         .into();
                     self.intermediate.push_operator(
                         tree.location,
@@ -233,31 +268,47 @@ impl Analyzer {
                 }
                 ExpressionOperator::AssignmentMultiplication => {
                     self.left_global(tree.left, operator)?;
-                    let expression = self.right_global(tree.right, operator)?;
-                    let place = self
-                        .assignment(Element::assign_multiply, tree.location)?
-                        .into();
+                    let mut expression = self.right_global(tree.right, operator)?;
+                    let place = self.assignment(
+                        Element::assign_multiply,
+                        Some(&mut expression),
+                        tree.location,
+                    )?;
                     self.intermediate.push_operator(
                         tree.location,
-                        GeneratorExpressionOperator::AssignmentMultiplication { place, expression },
+                        GeneratorExpressionOperator::assignment_multiplication(
+                            &place.r#type.to_owned(),
+                            place.into(),
+                            expression,
+                        ),
                     );
                 }
                 ExpressionOperator::AssignmentDivision => {
                     self.left_global(tree.left, operator)?;
-                    let expression = self.right_global(tree.right, operator)?;
-                    let place = self
-                        .assignment(Element::assign_divide, tree.location)?
-                        .into();
+                    let mut expression = self.right_global(tree.right, operator)?;
+                    let place = self.assignment(
+                        Element::assign_divide,
+                        Some(&mut expression),
+                        tree.location,
+                    )?;
                     self.intermediate.push_operator(
                         tree.location,
-                        GeneratorExpressionOperator::AssignmentDivision { place, expression },
+                        GeneratorExpressionOperator::assignment_division(
+                            &place.r#type.to_owned(),
+                            place.into(),
+                            expression,
+                        ),
                     );
                 }
                 ExpressionOperator::AssignmentRemainder => {
                     self.left_global(tree.left, operator)?;
-                    let expression = self.right_global(tree.right, operator)?;
+                    let mut expression = self.right_global(tree.right, operator)?;
                     let place = self
-                        .assignment(Element::assign_remainder, tree.location)?
+                        .assignment(
+                            Element::assign_remainder,
+                            Some(&mut expression),
+                            tree.location,
+                        )?
                         .into();
                     self.intermediate.push_operator(
                         tree.location,
@@ -311,65 +362,77 @@ impl Analyzer {
 
                 ExpressionOperator::Equals => {
                     self.left_local(tree.left, operator)?;
+                    let left_end = self.intermediate.len();
                     self.right_local(tree.right, operator)?;
-                    self.binary(Element::equals, tree.location)?;
+                    let r#type = self.binary_widening(Element::equals, left_end, tree.location)?;
                     self.intermediate
-                        .push_operator(tree.location, GeneratorExpressionOperator::Equals);
+                        .push_operator(tree.location, GeneratorExpressionOperator::equals(&r#type));
                 }
                 ExpressionOperator::NotEquals => {
                     self.left_local(tree.left, operator)?;
+                    let left_end = self.intermediate.len();
                     self.right_local(tree.right, operator)?;
-                    self.binary(Element::not_equals, tree.location)?;
-                    self.intermediate
-                        .push_operator(tree.location, GeneratorExpressionOperator::NotEquals);
+                    let r#type =
+                        self.binary_widening(Element::not_equals, left_end, tree.location)?;
+                    self.intermediate.push_operator(
+                        tree.location,
+                        GeneratorExpressionOperator::not_equals(&r#type),
+                    );
                 }
                 ExpressionOperator::GreaterEquals => {
                     self.left_local(tree.left, operator)?;
+                    let left_end = self.intermediate.len();
                     self.right_local(tree.right, operator)?;
-                    self.binary(Element::greater_equals, tree.location)?;
+                    self.binary_widening(Element::greater_equals, left_end, tree.location)?;
                     self.intermediate
                         .push_operator(tree.location, GeneratorExpressionOperator::GreaterEquals);
                 }
                 ExpressionOperator::LesserEquals => {
                     self.left_local(tree.left, operator)?;
+                    let left_end = self.intermediate.len();
                     self.right_local(tree.right, operator)?;
-                    self.binary(Element::lesser_equals, tree.location)?;
+                    self.binary_widening(Element::lesser_equals, left_end, tree.location)?;
                     self.intermediate
                         .push_operator(tree.location, GeneratorExpressionOperator::LesserEquals);
                 }
                 ExpressionOperator::Greater => {
                     self.left_local(tree.left, operator)?;
+                    let left_end = self.intermediate.len();
                     self.right_local(tree.right, operator)?;
-                    self.binary(Element::greater, tree.location)?;
+                    self.binary_widening(Element::greater, left_end, tree.location)?;
                     self.intermediate
                         .push_operator(tree.location, GeneratorExpressionOperator::Greater);
                 }
                 ExpressionOperator::Lesser => {
                     self.left_local(tree.left, operator)?;
+                    let left_end = self.intermediate.len();
                     self.right_local(tree.right, operator)?;
-                    self.binary(Element::lesser, tree.location)?;
+                    self.binary_widening(Element::lesser, left_end, tree.location)?;
                     self.intermediate
                         .push_operator(tree.location, GeneratorExpressionOperator::Lesser);
                 }
 
                 ExpressionOperator::BitwiseOr => {
                     self.left_local(tree.left, operator)?;
+                    let left_end = self.intermediate.len();
                     self.right_local(tree.right, operator)?;
-                    self.binary(Element::bitwise_or, tree.location)?;
+                    self.binary_widening(Element::bitwise_or, left_end, tree.location)?;
                     self.intermediate
                         .push_operator(tree.location, GeneratorExpressionOperator::BitwiseOr);
                 }
                 ExpressionOperator::BitwiseXor => {
                     self.left_local(tree.left, operator)?;
+                    let left_end = self.intermediate.len();
                     self.right_local(tree.right, operator)?;
-                    self.binary(Element::bitwise_xor, tree.location)?;
+                    self.binary_widening(Element::bitwise_xor, left_end, tree.location)?;
                     self.intermediate
                         .push_operator(tree.location, GeneratorExpressionOperator::BitwiseXor);
                 }
                 ExpressionOperator::BitwiseAnd => {
                     self.left_local(tree.left, operator)?;
+                    let left_end = self.intermediate.len();
                     self.right_local(tree.right, operator)?;
-                    self.binary(Element::bitwise_and, tree.location)?;
+                    self.binary_widening(Element::bitwise_and, left_end, tree.location)?;
                     self.intermediate
                         .push_operator(tree.location, GeneratorExpressionOperator::BitwiseAnd);
                 }
@@ -394,39 +457,59 @@ impl Analyzer {
 
                 ExpressionOperator::Addition => {
                     self.left_local(tree.left, operator)?;
+                    let left_end = self.intermediate.len();
                     self.right_local(tree.right, operator)?;
-                    self.binary(Element::add, tree.location)?;
-                    self.intermediate
-                        .push_operator(tree.location, GeneratorExpressionOperator::Addition);
+                    let r#type = self.binary_widening(Element::add, left_end, tree.location)?;
+                    self.intermediate.push_operator(
+                        tree.location,
+                        GeneratorExpressionOperator::addition(&r#type),
+                    );
                 }
                 ExpressionOperator::Subtraction => {
                     self.left_local(tree.left, operator)?;
+                    let left_end = self.intermediate.len();
                     self.right_local(tree.right, operator)?;
-                    self.binary(Element::subtract, tree.location)?;
+                    self.binary_widening(Element::subtract, left_end, tree.location)?;
                     self.intermediate
                         .push_operator(tree.location, GeneratorExpressionOperator::Subtraction);
                 }
                 ExpressionOperator::Multiplication => {
                     self.left_local(tree.left, operator)?;
+                    let left_end = self.intermediate.len();
                     self.right_local(tree.right, operator)?;
-                    self.binary(Element::multiply, tree.location)?;
-                    self.intermediate
-                        .push_operator(tree.location, GeneratorExpressionOperator::Multiplication);
+                    let r#type =
+                        self.binary_widening(Element::multiply, left_end, tree.location)?;
+                    self.intermediate.push_operator(
+                        tree.location,
+                        GeneratorExpressionOperator::multiplication(&r#type),
+                    );
                 }
                 ExpressionOperator::Division => {
                     self.left_local(tree.left, operator)?;
+                    let left_end = self.intermediate.len();
                     self.right_local(tree.right, operator)?;
-                    self.binary(Element::divide, tree.location)?;
-                    self.intermediate
-                        .push_operator(tree.location, GeneratorExpressionOperator::Division);
+                    let r#type = self.binary_widening(Element::divide, left_end, tree.location)?;
+                    self.intermediate.push_operator(
+                        tree.location,
+                        GeneratorExpressionOperator::division(&r#type),
+                    );
                 }
                 ExpressionOperator::Remainder => {
                     self.left_local(tree.left, operator)?;
+                    let left_end = self.intermediate.len();
                     self.right_local(tree.right, operator)?;
-                    self.binary(Element::remainder, tree.location)?;
+                    self.binary_widening(Element::remainder, left_end, tree.location)?;
                     self.intermediate
                         .push_operator(tree.location, GeneratorExpressionOperator::Remainder);
                 }
+                ExpressionOperator::Exponentiation => {
+                    self.left_local(tree.left, operator)?;
+                    // the exponent is a constant, which is not written to the IR
+                    self.right_global(tree.right, operator)?;
+                    if let Some(operator) = self.exponentiation(tree.location)? {
+                        self.intermediate.push_operator(tree.location, operator);
+                    }
+                }
 
                 ExpressionOperator::Casting => {
                     self.left_local(tree.left, operator)?;
@@ -477,8 +560,16 @@ impl Analyzer {
 
                 ExpressionOperator::Call => {
                     self.left_local(tree.left, operator)?;
-                    self.right_local(tree.right, operator)?;
-                    let operator = self.call(tree.location)?;
+                    let arguments = match tree.right {
+                        Some(right) => {
+                            let hint = TranslationHint::second(operator);
+                            let (element, intermediate) = self.traverse(*right, hint)?;
+                            self.evaluation_stack.push(StackElement::Evaluated(element));
+                            intermediate
+                        }
+                        None => panic!("{}", crate::PANIC_VALIDATED_DURING_SYNTAX_ANALYSIS),
+                    };
+                    let operator = self.call(arguments, tree.location)?;
                     self.intermediate.push_operator(tree.location, operator);
                 }
                 ExpressionOperator::CallBuiltIn => {
@@ -590,7 +681,15 @@ impl Analyzer {
     ///
     /// Analyzes the assignment operation.
     ///
-    fn assignment<F>(&mut self, callback: F, location: Location) -> Result<Place, Error>
+    /// The second operand of a compound assignment like `+=` is passed as `expression`, which
+    /// is implicitly widened to the place type the same way as the binary operator operands.
+    ///
+    fn assignment<F>(
+        &mut self,
+        callback: F,
+        expression: Option<&mut GeneratorExpression>,
+        location: Location,
+    ) -> Result<Place, Error>
     where
         F: FnOnce(Element, Element) -> Result<Place, ElementError>,
     {
@@ -606,6 +705,7 @@ impl Analyzer {
         )?;
 
         let r#type = Type::from_element(&operand_2, self.scope_stack.top())?;
+        let is_constant = matches!(operand_2, Element::Constant(_));
         let place =
             callback(operand_1, operand_2).map_err(|error| Error::Element(location, error))?;
 
@@ -621,12 +721,39 @@ impl Analyzer {
                 }),
             ));
         }
-        if place.r#type != r#type {
+        let is_widened = match (expression, &r#type, &place.r#type) {
+            (
+                Some(expression),
+                Type::IntegerUnsigned { bitlength: b1 },
+                Type::IntegerUnsigned { bitlength: b2 },
+            )
+            | (
+                Some(expression),
+                Type::IntegerSigned { bitlength: b1 },
+                Type::IntegerSigned { bitlength: b2 },
+            ) if b1 < b2 => {
+                if !is_constant && WARN_WIDENING.load(Ordering::Relaxed) {
+                    log::warn!(
+                        "Operand of type `{}` at {} is implicitly widened to `{}`",
+                        r#type,
+                        location,
+                        place.r#type
+                    );
+                }
+
+                let operator = GeneratorExpressionOperator::casting(&place.r#type)
+                    .expect(crate::PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS);
+                expression.push_operator(location, operator);
+                true
+            }
+            _ => false,
+        };
+        if place.r#type != r#type && !is_widened {
             return Err(Error::Element(
                 location,
                 ElementError::Place(PlaceError::MutatingWithDifferentType {
-                    expected: r#type.to_string(),
-                    found: place.r#type.to_string(),
+                    expected: place.r#type.to_string(),
+                    found: r#type.to_string(),
                 }),
             ));
         }
@@ -640,7 +767,10 @@ impl Analyzer {
     ///
     /// Analyzes the binary operation, which can be logical, comparison, bitwise or arithmetic.
     ///
-    fn binary<F>(&mut self, callback: F, location: Location) -> Result<(), Error>
+    /// Returns the first operand type, which the operators on arrays, tuples, and structures
+    /// are lowered according to.
+    ///
+    fn binary<F>(&mut self, callback: F, location: Location) -> Result<Type, Error>
     where
         F: FnOnce(Element, Element) -> Result<Element, ElementError>,
     {
@@ -655,11 +785,98 @@ impl Analyzer {
             TranslationHint::Value,
         )?;
 
+        let operand_type = match operand_1 {
+            Element::Value(ref value) => Some(value.r#type()),
+            Element::Constant(ref constant) => Some(constant.r#type()),
+            _ => None,
+        };
+
         let result =
             callback(operand_1, operand_2).map_err(|error| Error::Element(location, error))?;
         self.evaluation_stack.push(StackElement::Evaluated(result));
 
-        Ok(())
+        Ok(operand_type.expect(crate::PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS))
+    }
+
+    ///
+    /// Analyzes the binary operation, whose integer operands of the same sign and different
+    /// bitlengths are implicitly widened to the wider type, e.g. `u8` and `u64` to `u64`.
+    ///
+    /// The first operand ends at `left_end` in the IR, where it is cast if it is the narrower one.
+    ///
+    /// The widened constants like integer literals are not reported with the warning, since
+    /// their types are only the minimal ones to fit the value.
+    ///
+    /// Returns the operand type after widening.
+    ///
+    fn binary_widening<F>(
+        &mut self,
+        callback: F,
+        left_end: usize,
+        location: Location,
+    ) -> Result<Type, Error>
+    where
+        F: FnOnce(Element, Element) -> Result<Element, ElementError>,
+    {
+        let (operand_2, _) = Self::evaluate(
+            self.scope_stack.top(),
+            self.evaluation_stack.pop(),
+            TranslationHint::Value,
+        )?;
+        let (operand_1, _) = Self::evaluate(
+            self.scope_stack.top(),
+            self.evaluation_stack.pop(),
+            TranslationHint::Value,
+        )?;
+
+        let operand_type = |operand: &Element| match operand {
+            Element::Value(ref value) => Some(value.r#type()),
+            Element::Constant(ref constant) => Some(constant.r#type()),
+            _ => None,
+        };
+        let type_1 = operand_type(&operand_1);
+        let type_2 = operand_type(&operand_2);
+        let is_constant_1 = matches!(operand_1, Element::Constant(_));
+        let is_constant_2 = matches!(operand_2, Element::Constant(_));
+
+        let result =
+            callback(operand_1, operand_2).map_err(|error| Error::Element(location, error))?;
+        self.evaluation_stack.push(StackElement::Evaluated(result));
+
+        let type_1 = type_1.expect(crate::PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS);
+        let type_2 = type_2.expect(crate::PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS);
+        let (bitlength_1, bitlength_2) = match (&type_1, &type_2) {
+            (Type::IntegerUnsigned { bitlength: b1 }, Type::IntegerUnsigned { bitlength: b2 })
+            | (Type::IntegerSigned { bitlength: b1 }, Type::IntegerSigned { bitlength: b2 }) => {
+                (*b1, *b2)
+            }
+            _ => return Ok(type_1),
+        };
+        let (narrower, wider, is_first, is_constant) = match bitlength_1.cmp(&bitlength_2) {
+            cmp::Ordering::Less => (type_1, type_2, true, is_constant_1),
+            cmp::Ordering::Greater => (type_2, type_1, false, is_constant_2),
+            cmp::Ordering::Equal => return Ok(type_1),
+        };
+
+        if !is_constant && WARN_WIDENING.load(Ordering::Relaxed) {
+            log::warn!(
+                "Operand of type `{}` at {} is implicitly widened to `{}`",
+                narrower,
+                location,
+                wider
+            );
+        }
+
+        let operator = GeneratorExpressionOperator::casting(&wider)
+            .expect(crate::PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS);
+        if is_first {
+            self.intermediate
+                .insert_operator(left_end, location, operator);
+        } else {
+            self.intermediate.push_operator(location, operator);
+        }
+
+        Ok(wider)
     }
 
     ///
@@ -718,8 +935,14 @@ impl Analyzer {
             TranslationHint::Value,
         )?;
 
-        let operator = match operand_2 {
-            Element::Type(ref r#type) => GeneratorExpressionOperator::casting(r#type),
+        let operator = match (&operand_1, &operand_2) {
+            (Element::Value(ref value), Element::Type(ref r#type)) => {
+                GeneratorExpressionOperator::casting_from(&value.r#type(), r#type)
+            }
+            (Element::Constant(ref constant), Element::Type(ref r#type)) => {
+                GeneratorExpressionOperator::casting_from(&constant.r#type(), r#type)
+            }
+            (_, Element::Type(ref r#type)) => GeneratorExpressionOperator::casting(r#type),
             _ => None,
         };
 
@@ -730,6 +953,44 @@ impl Analyzer {
         Ok(operator)
     }
 
+    ///
+    /// Analyzes the exponentiation operation, whose exponent must be a constant.
+    ///
+    fn exponentiation(
+        &mut self,
+        location: Location,
+    ) -> Result<Option<GeneratorExpressionOperator>, Error> {
+        let (operand_2, _) = Self::evaluate(
+            self.scope_stack.top(),
+            self.evaluation_stack.pop(),
+            TranslationHint::Value,
+        )?;
+        let (operand_1, _) = Self::evaluate(
+            self.scope_stack.top(),
+            self.evaluation_stack.pop(),
+            TranslationHint::Value,
+        )?;
+
+        let operator = match (&operand_1, &operand_2) {
+            (Element::Value(value), Element::Constant(Constant::Integer(exponent))) => {
+                GeneratorExpressionOperator::exponentiation(&value.r#type(), exponent.to_bigint())
+            }
+            (Element::Constant(constant), Element::Constant(Constant::Integer(exponent))) => {
+                GeneratorExpressionOperator::exponentiation(
+                    &constant.r#type(),
+                    exponent.to_bigint(),
+                )
+            }
+            _ => None,
+        };
+
+        let result = Element::exponentiate(operand_1, operand_2)
+            .map_err(|error| Error::Element(location, error))?;
+        self.evaluation_stack.push(StackElement::Evaluated(result));
+
+        Ok(operator)
+    }
+
     ///
     /// Analyzes the unary operation.
     ///
@@ -856,6 +1117,14 @@ impl Analyzer {
                         self.intermediate.push_operand(instance);
                     }
 
+                    instance
+                } else if let Element::Constant(ref constant) = instance {
+                    let intermediate = GeneratorExpressionConstant::try_from_semantic(constant)
+                        .map(GeneratorExpressionOperand::Constant);
+                    if let Some(instance) = intermediate {
+                        self.intermediate.push_operand(instance);
+                    }
+
                     instance
                 } else {
                     instance
@@ -872,8 +1141,19 @@ impl Analyzer {
     ///
     /// Analyzes the function call operation.
     ///
-    fn call(&mut self, location: Location) -> Result<GeneratorExpressionOperator, Error> {
+    /// The `arguments` IR is written after the call is analyzed, since the integer constant
+    /// arguments are cast to the types of the formal parameters.
+    ///
+    fn call(
+        &mut self,
+        arguments: Option<GeneratorExpressionOperand>,
+        location: Location,
+    ) -> Result<GeneratorExpressionOperator, Error> {
         let call_type = self.next_call_type.take();
+        let mut arguments = match arguments {
+            Some(GeneratorExpressionOperand::List(arguments)) => arguments,
+            _ => panic!("{}", crate::PANIC_VALIDATED_DURING_SYNTAX_ANALYSIS),
+        };
 
         let (operand_2, _intermediate_2) = Self::evaluate(
             self.scope_stack.top(),
@@ -891,10 +1171,13 @@ impl Analyzer {
             operand_1,
             operand_2,
             call_type,
+            &mut arguments,
             location,
         )?;
 
         self.evaluation_stack.push(StackElement::Evaluated(element));
+        self.intermediate
+            .push_operand(GeneratorExpressionOperand::List(arguments));
 
         Ok(operator)
     }
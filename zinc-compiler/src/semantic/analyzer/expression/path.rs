@@ -16,6 +16,7 @@ use crate::semantic::element::place::Place;
 use crate::semantic::element::value::Value;
 use crate::semantic::element::Element;
 use crate::semantic::error::Error;
+use crate::semantic::scope::item::variant::variable::Variable as ScopeVariableItem;
 use crate::semantic::scope::item::variant::Variant as ScopeItemVariant;
 use crate::semantic::scope::Scope;
 use crate::syntax::tree::identifier::Identifier;
@@ -52,6 +53,16 @@ impl Translator {
                 }
             },
             TranslationHint::Value => match Scope::resolve_path(scope, &path)?.variant {
+                ScopeItemVariant::Variable(ScopeVariableItem {
+                    constant: Some(constant),
+                    ..
+                })
+                | ScopeItemVariant::Constant(constant) => {
+                    let intermediate = GeneratorConstant::try_from_semantic(&constant)
+                        .map(GeneratorExpressionOperand::Constant);
+                    let element = Element::Constant(constant);
+                    Ok((element, intermediate))
+                }
                 ScopeItemVariant::Variable(variable) => {
                     let value = Value::try_from(&variable.r#type)
                         .map_err(ElementError::Value)
@@ -65,12 +76,6 @@ impl Translator {
                     let element = Element::Value(value);
                     Ok((element, intermediate))
                 }
-                ScopeItemVariant::Constant(constant) => {
-                    let intermediate = GeneratorConstant::try_from_semantic(&constant)
-                        .map(GeneratorExpressionOperand::Constant);
-                    let element = Element::Constant(constant);
-                    Ok((element, intermediate))
-                }
                 ScopeItemVariant::Type(r#type) => Ok((Element::Type(r#type), None)),
                 ScopeItemVariant::Module(_) => {
                     Ok((Element::Module(path_last_identifier.name), None))
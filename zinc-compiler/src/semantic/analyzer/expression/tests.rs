@@ -17,7 +17,7 @@ use crate::semantic::error::Error as SemanticError;
 fn error_const_expression_has_non_const_element() {
     let input = r#"
 fn main() {
-    let variable = 42;
+    let mut variable = 42;
     const CONSTANT: u8 = variable;
 }
 "#;
@@ -37,3 +37,40 @@ fn main() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn ok_array_comprehension() {
+    let input = r#"
+fn main(offset: u8) {
+    let squares: [u8; 4] = [i * i + offset for i in 0..4];
+    let reversed: [u8; 3] = [i for i in 3..=1];
+    let nested: [[u8; 2]; 2] = [[j, j + 1] for j in 0..2];
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_array_comprehension_bounds_expected_constant_range_expression() {
+    let input = r#"
+fn main(size: u8) {
+    let array = [i for i in size];
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::LoopBoundsExpectedConstantRangeExpression {
+            location: Location::new(3, 29),
+            found: Element::Value(
+                Value::try_from(&Type::integer_unsigned(crate::BITLENGTH_BYTE))
+                    .expect(crate::semantic::tests::PANIC_TEST_DATA),
+            )
+            .to_string(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,55 @@
+//!
+//! The semantic analyzer generic function instances.
+//!
+
+use std::cell::RefCell;
+
+use crate::generator::statement::function::Statement as GeneratorFunctionStatement;
+
+thread_local! {
+    static INSTANCES: RefCell<Vec<GeneratorFunctionStatement>> = const { RefCell::new(Vec::new()) };
+}
+
+///
+/// The guard of a file analysis.
+///
+/// A generic function is instantiated where it is called for the first time with the const
+/// parameter values, which may be another file than the one it is declared in. So the instances
+/// are collected while the file is analyzed and written along with the file IR.
+///
+pub struct Instances {
+    /// The instances of the enclosing file analysis, restored when the guard is dropped.
+    outer: Option<Vec<GeneratorFunctionStatement>>,
+}
+
+impl Instances {
+    ///
+    /// Enters the analysis of a file.
+    ///
+    pub fn enter_file() -> Self {
+        let outer = INSTANCES.with(|instances| instances.replace(Vec::new()));
+        Self { outer: Some(outer) }
+    }
+
+    ///
+    /// Records the IR of an analyzed instance.
+    ///
+    pub fn push(statement: GeneratorFunctionStatement) {
+        INSTANCES.with(|instances| instances.borrow_mut().push(statement));
+    }
+
+    ///
+    /// Leaves the file analysis, returning the instances analyzed within it.
+    ///
+    pub fn take(self) -> Vec<GeneratorFunctionStatement> {
+        INSTANCES.with(|instances| instances.replace(Vec::new()))
+    }
+}
+
+impl Drop for Instances {
+    fn drop(&mut self) {
+        if let Some(outer) = self.outer.take() {
+            INSTANCES.with(|instances| instances.replace(outer));
+        }
+    }
+}
@@ -3,6 +3,8 @@
 //!
 
 pub mod entry;
+pub mod exposure;
 pub mod expression;
+pub mod instances;
 pub mod module;
 pub mod statement;
@@ -7,10 +7,13 @@ use std::collections::HashMap;
 use std::rc::Rc;
 
 use crate::error::Error as CompilerError;
+use crate::generator::statement::Statement as GeneratorStatement;
 use crate::generator::Tree;
+use crate::semantic::analyzer::instances::Instances;
 use crate::semantic::analyzer::statement::Analyzer as StatementAnalyzer;
 use crate::semantic::scope::stack::Stack as ScopeStack;
 use crate::semantic::scope::Scope;
+use crate::syntax::filter::Filter;
 use crate::syntax::tree::Tree as SyntaxTree;
 
 ///
@@ -20,18 +23,14 @@ use crate::syntax::tree::Tree as SyntaxTree;
 ///
 pub struct Analyzer {
     scope_stack: ScopeStack,
-}
-
-impl Default for Analyzer {
-    fn default() -> Self {
-        Self::new()
-    }
+    name: String,
 }
 
 impl Analyzer {
-    pub fn new() -> Self {
+    pub fn new(name: String) -> Self {
         Self {
             scope_stack: ScopeStack::new_global(),
+            name,
         }
     }
 
@@ -40,9 +39,13 @@ impl Analyzer {
         program: SyntaxTree,
         dependencies: HashMap<String, Rc<RefCell<Scope>>>,
     ) -> Result<(Rc<RefCell<Scope>>, Tree), CompilerError> {
+        let program = Filter::default().filter(program);
+
         let mut intermediate = Tree::new();
 
-        let mut analyzer = StatementAnalyzer::new(self.scope_stack.top(), dependencies);
+        let instances = Instances::enter_file();
+        let mut analyzer =
+            StatementAnalyzer::new(self.scope_stack.top(), dependencies, vec![self.name]);
         for statement in program.statements.into_iter() {
             if let Some(statement) = analyzer
                 .local_mod(statement)
@@ -51,6 +54,12 @@ impl Analyzer {
                 intermediate.statements.push(statement);
             }
         }
+        intermediate.statements.extend(
+            instances
+                .take()
+                .into_iter()
+                .map(GeneratorStatement::Function),
+        );
 
         Ok((self.scope_stack.top(), intermediate))
     }
@@ -9,33 +9,43 @@ use std::cmp;
 use std::collections::HashMap;
 use std::rc::Rc;
 
+use num_bigint::BigInt;
 use num_traits::ToPrimitive;
 
+use crate::generator::expression::operator::Operator as GeneratorExpressionOperator;
+use crate::generator::inliner::Hint as InlineHint;
 use crate::generator::statement::declaration::Statement as GeneratorDeclarationStatement;
 use crate::generator::statement::function::Statement as GeneratorFunctionStatement;
 use crate::generator::statement::loop_for::Statement as GeneratorForLoopStatement;
 use crate::generator::statement::Statement as GeneratorStatement;
 use crate::lexical::token::lexeme::keyword::Keyword;
+use crate::lexical::token::location::Location;
+use crate::semantic::analyzer::exposure::Exposure;
 use crate::semantic::analyzer::expression::block::Analyzer as BlockAnalyzer;
 use crate::semantic::analyzer::expression::hint::Hint as TranslationHint;
 use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
+use crate::semantic::analyzer::instances::Instances;
 use crate::semantic::element::constant::error::Error as ConstantError;
 use crate::semantic::element::constant::integer::error::Error as IntegerConstantError;
+use crate::semantic::element::constant::integer::Integer as IntegerConstant;
 use crate::semantic::element::constant::Constant;
 use crate::semantic::element::error::Error as ElementError;
+use crate::semantic::element::r#type;
 use crate::semantic::element::r#type::error::Error as TypeError;
 use crate::semantic::element::r#type::function::error::Error as FunctionTypeError;
+use crate::semantic::element::r#type::function::generic::Function as GenericFunctionType;
+use crate::semantic::element::r#type::function::host::Function as HostFunctionType;
 use crate::semantic::element::r#type::function::user::Function as UserDefinedFunctionType;
 use crate::semantic::element::r#type::function::Function as FunctionType;
 use crate::semantic::element::r#type::structure::error::Error as StructureTypeError;
 use crate::semantic::element::r#type::Type;
-use crate::semantic::element::r#type::INDEX as TYPE_INDEX;
 use crate::semantic::element::Element;
 use crate::semantic::error::Error;
 use crate::semantic::scope::item::variant::variable::Variable as ScopeVariableItem;
 use crate::semantic::scope::item::variant::Variant as ScopeItemVariant;
 use crate::semantic::scope::stack::Stack as ScopeStack;
 use crate::semantic::scope::Scope;
+use crate::syntax::tree::attribute::Attribute;
 use crate::syntax::tree::identifier::Identifier;
 use crate::syntax::tree::pattern_binding::variant::Variant as BindingPatternVariant;
 use crate::syntax::tree::statement::local_fn::Statement as FunctionLocalStatement;
@@ -44,6 +54,7 @@ use crate::syntax::tree::statement::local_mod::Statement as ModuleLocalStatement
 use crate::syntax::tree::statement::module::Statement as ModStatement;
 use crate::syntax::tree::statement::r#const::Statement as ConstStatement;
 use crate::syntax::tree::statement::r#enum::Statement as EnumStatement;
+use crate::syntax::tree::statement::r#extern::Statement as ExternStatement;
 use crate::syntax::tree::statement::r#fn::Statement as FnStatement;
 use crate::syntax::tree::statement::r#for::Statement as ForStatement;
 use crate::syntax::tree::statement::r#impl::Statement as ImplStatement;
@@ -58,19 +69,25 @@ use crate::syntax::tree::statement::r#use::Statement as UseStatement;
 /// An analyzer instance can be reused to analyze statements located in the same item, e.g. in the
 /// same module, function, or implementation.
 ///
+/// The `path` consists of the module and implementation names and is used to derive the unique
+/// IDs of the declared types.
+///
 pub struct Analyzer {
     scope_stack: ScopeStack,
     dependencies: HashMap<String, Rc<RefCell<Scope>>>,
+    path: Vec<String>,
 }
 
 impl Analyzer {
     pub fn new(
         scope: Rc<RefCell<Scope>>,
         dependencies: HashMap<String, Rc<RefCell<Scope>>>,
+        path: Vec<String>,
     ) -> Self {
         Self {
             scope_stack: ScopeStack::new(scope),
             dependencies,
+            path,
         }
     }
 
@@ -100,10 +117,18 @@ impl Analyzer {
                 self.r#enum(statement)?;
                 Ok(None)
             }
+            ModuleLocalStatement::Fn(statement) if statement.is_generic() => {
+                self.generic_fn(statement)?;
+                Ok(None)
+            }
             ModuleLocalStatement::Fn(statement) => {
                 let intermediate = GeneratorStatement::Function(self.r#fn(statement)?);
                 Ok(Some(intermediate))
             }
+            ModuleLocalStatement::Extern(statement) => {
+                self.r#extern(statement)?;
+                Ok(None)
+            }
             ModuleLocalStatement::Mod(statement) => {
                 self.r#mod(statement)?;
                 Ok(None)
@@ -117,6 +142,9 @@ impl Analyzer {
                 Ok(Some(intermediate))
             }
             ModuleLocalStatement::Empty(_location) => Ok(None),
+            ModuleLocalStatement::Cfg(..) => {
+                panic!("{}", crate::PANIC_FILTERED_BEFORE_SEMANTIC_ANALYSIS)
+            }
         }
     }
 
@@ -147,6 +175,9 @@ impl Analyzer {
                 Ok(Some(intermediate))
             }
             FunctionLocalStatement::Empty(_location) => Ok(None),
+            FunctionLocalStatement::Cfg(..) => {
+                panic!("{}", crate::PANIC_FILTERED_BEFORE_SEMANTIC_ANALYSIS)
+            }
         }
     }
 
@@ -164,22 +195,114 @@ impl Analyzer {
                 self.r#const(statement)?;
                 Ok(None)
             }
+            ImplementationLocalStatement::Fn(statement) if statement.is_generic() => {
+                self.generic_fn(statement)?;
+                Ok(None)
+            }
             ImplementationLocalStatement::Fn(statement) => {
                 let intermediate = GeneratorStatement::Function(self.r#fn(statement)?);
                 Ok(Some(intermediate))
             }
             ImplementationLocalStatement::Empty(_location) => Ok(None),
+            ImplementationLocalStatement::Cfg(..) => {
+                panic!("{}", crate::PANIC_FILTERED_BEFORE_SEMANTIC_ANALYSIS)
+            }
         }
     }
 
+    ///
+    /// Analyzes an extern statement and declares the host function, whose calls are
+    /// resolved by the VM while generating the witness.
+    ///
+    fn r#extern(&mut self, statement: ExternStatement) -> Result<(), Error> {
+        if statement.abi.as_str() != crate::semantic::element::r#type::function::host::HOST_ABI {
+            return Err(Error::ExternAbiUnsupported {
+                location: statement.location,
+                abi: statement.abi,
+            });
+        }
+
+        let mut arguments = Vec::with_capacity(statement.argument_bindings.len());
+        for argument_binding in statement.argument_bindings.iter() {
+            if argument_binding.is_public {
+                return Err(Error::FunctionArgumentPublicOutsideMain {
+                    location: argument_binding.location,
+                    function: statement.identifier.name,
+                });
+            }
+
+            let identifier = match argument_binding.variant {
+                BindingPatternVariant::Binding { ref identifier, .. } => identifier.name.to_owned(),
+                BindingPatternVariant::Wildcard => "_".to_owned(),
+                BindingPatternVariant::SelfAlias { .. } => Keyword::SelfLowercase.to_string(),
+            };
+            let r#type =
+                Type::from_type_variant(&argument_binding.r#type.variant, self.scope_stack.top())?;
+            if !HostFunctionType::is_passable(&r#type) {
+                return Err(Error::ExternFunctionTypeUnsupported {
+                    location: argument_binding.r#type.location,
+                    function: statement.identifier.name,
+                    found: r#type.to_string(),
+                });
+            }
+            arguments.push((identifier, r#type));
+        }
+
+        let return_type = match statement.return_type {
+            Some(ref r#type) => {
+                let return_type = Type::from_type_variant(&r#type.variant, self.scope_stack.top())?;
+                if !HostFunctionType::is_passable(&return_type) {
+                    return Err(Error::ExternFunctionTypeUnsupported {
+                        location: r#type.location,
+                        function: statement.identifier.name,
+                        found: return_type.to_string(),
+                    });
+                }
+                return_type
+            }
+            None => Type::unit(),
+        };
+
+        let r#type = Type::Function(FunctionType::new_host(
+            statement.identifier.name.clone(),
+            arguments,
+            return_type,
+        ));
+
+        Scope::declare_type(self.scope_stack.top(), statement.identifier, r#type)
+            .map_err(Error::Scope)?;
+
+        Ok(())
+    }
+
     ///
     /// Analyzes a function statement and returns its IR for the next compiler phase.
     ///
     fn r#fn(&mut self, statement: FnStatement) -> Result<GeneratorFunctionStatement, Error> {
         let location = statement.location;
+        let inline = Self::inline_hint(statement.attributes.as_slice())?;
+        let is_test = statement.attributes.iter().any(Attribute::is_test);
+        if is_test && (!statement.argument_bindings.is_empty() || statement.return_type.is_some())
+        {
+            return Err(Error::TestFunctionInvalidSignature {
+                location: statement.identifier.location,
+                function: statement.identifier.name,
+            });
+        }
+
+        let is_main = statement.identifier.name.as_str()
+            == crate::semantic::element::r#type::function::user::FUNCTION_MAIN_IDENTIFIER;
 
         let mut arguments = Vec::with_capacity(statement.argument_bindings.len());
+        let mut public_arguments = Vec::new();
         for (index, argument_binding) in statement.argument_bindings.iter().enumerate() {
+            if argument_binding.is_public && !is_main {
+                return Err(Error::FunctionArgumentPublicOutsideMain {
+                    location: argument_binding.location,
+                    function: statement.identifier.name,
+                });
+            }
+
             let identifier = match argument_binding.variant {
                 BindingPatternVariant::Binding { ref identifier, .. } => identifier.name.to_owned(),
                 BindingPatternVariant::Wildcard => continue,
@@ -200,6 +323,9 @@ impl Analyzer {
                     Keyword::SelfLowercase.to_string()
                 }
             };
+            if argument_binding.is_public {
+                public_arguments.push(identifier.clone());
+            }
             arguments.push((
                 identifier,
                 Type::from_type_variant(&argument_binding.r#type.variant, self.scope_stack.top())?,
@@ -210,7 +336,19 @@ impl Analyzer {
             None => Type::unit(),
         };
 
-        let unique_id = TYPE_INDEX.read().expect(crate::PANIC_MUTEX_SYNC).len();
+        let signature = format!(
+            "fn({}) -> {}",
+            arguments
+                .iter()
+                .map(|(_identifier, r#type)| r#type.to_string())
+                .collect::<Vec<String>>()
+                .join(", "),
+            expected_type
+        );
+        let unique_id = r#type::unique_id(
+            self.path(statement.identifier.name.as_str()).as_slice(),
+            signature.as_str(),
+        );
         let function_type = UserDefinedFunctionType::new(
             statement.identifier.name.clone(),
             unique_id,
@@ -219,10 +357,6 @@ impl Analyzer {
         );
         let r#type = Type::Function(FunctionType::UserDefined(function_type));
 
-        TYPE_INDEX
-            .write()
-            .expect(crate::PANIC_MUTEX_SYNC)
-            .insert(unique_id, r#type.to_string());
         Scope::declare_type(self.scope_stack.top(), statement.identifier.clone(), r#type)
             .map_err(|error| Error::Scope(error))?;
 
@@ -278,7 +412,9 @@ impl Analyzer {
                 .map(|statement| statement.location())
                 .unwrap_or(statement.location),
         };
+        let exposure = Exposure::enter_function(statement.identifier.name.clone(), is_main);
         let (result, body) = BlockAnalyzer::analyze(self.scope_stack.top(), statement.body)?;
+        drop(exposure);
         self.scope_stack.pop();
 
         let result_type = Type::from_element(&result, self.scope_stack.top())?;
@@ -297,20 +433,180 @@ impl Analyzer {
             ));
         }
 
-        let is_main = statement.identifier.name.as_str()
-            == crate::semantic::element::r#type::function::user::FUNCTION_MAIN_IDENTIFIER;
+        let test_name = if is_test {
+            Some(self.path(statement.identifier.name.as_str()).join("::"))
+        } else {
+            None
+        };
 
         Ok(GeneratorFunctionStatement::new(
             location,
             statement.identifier.name,
             arguments,
+            public_arguments,
             body,
             expected_type,
             unique_id,
             is_main,
+            test_name,
+            inline,
         ))
     }
 
+    ///
+    /// Declares a function with const parameters, which is analyzed once its parameters are
+    /// inferred at a call site.
+    ///
+    fn generic_fn(&mut self, statement: FnStatement) -> Result<(), Error> {
+        let mut const_parameters = Vec::with_capacity(statement.const_parameters.len());
+        for parameter in statement.const_parameters.iter() {
+            let r#type =
+                Type::from_type_variant(&parameter.r#type.variant, self.scope_stack.top())?;
+            match r#type {
+                Type::IntegerUnsigned { .. } | Type::IntegerSigned { .. } | Type::Field => {}
+                r#type => {
+                    return Err(Error::Element(
+                        parameter.r#type.location,
+                        ElementError::Type(TypeError::Function(
+                            FunctionTypeError::const_parameter_expected_integer(
+                                statement.identifier.name.to_owned(),
+                                parameter.identifier.name.to_owned(),
+                                r#type.to_string(),
+                            ),
+                        )),
+                    ))
+                }
+            }
+            const_parameters.push((parameter.identifier.clone(), r#type));
+        }
+
+        let identifier = statement.identifier.clone();
+        let r#type = Type::Function(FunctionType::Generic(GenericFunctionType::new(
+            statement,
+            self.scope_stack.top(),
+            self.path.clone(),
+            const_parameters,
+        )));
+
+        Scope::declare_type(self.scope_stack.top(), identifier, r#type)
+            .map_err(|error| Error::Scope(error))?;
+
+        Ok(())
+    }
+
+    ///
+    /// Instantiates the generic `function` with the const parameter `values`, analyzing it
+    /// as an ordinar function in a child of its scope, where the parameters are constants.
+    ///
+    /// Each instance is analyzed only once, and its IR is written along with the file being
+    /// analyzed.
+    ///
+    pub fn instantiate(
+        function: &GenericFunctionType,
+        values: Vec<BigInt>,
+        location: Location,
+    ) -> Result<UserDefinedFunctionType, Error> {
+        match function.instance(values.as_slice()) {
+            Some(Some(instance)) => return Ok(instance),
+            Some(None) => {
+                return Err(Error::Element(
+                    location,
+                    ElementError::Type(TypeError::Function(
+                        FunctionTypeError::const_parameter_recursive_instance(
+                            function.identifier().to_owned(),
+                        ),
+                    )),
+                ))
+            }
+            None => function.begin_instance(values.clone()),
+        }
+
+        let scope = Scope::new_child(function.scope());
+        for ((identifier, r#type), value) in function.const_parameters().iter().zip(values.iter())
+        {
+            let (is_signed, bitlength) = match r#type {
+                Type::IntegerUnsigned { bitlength } => (false, *bitlength),
+                Type::IntegerSigned { bitlength } => (true, *bitlength),
+                _ => (false, crate::BITLENGTH_FIELD),
+            };
+            match IntegerConstant::minimal_bitlength(value, is_signed) {
+                Ok(minimal_bitlength) if minimal_bitlength <= bitlength => {}
+                _ => {
+                    return Err(Error::Element(
+                        location,
+                        ElementError::Constant(ConstantError::Integer(
+                            IntegerConstantError::IntegerTooLarge {
+                                value: value.to_owned(),
+                                bitlength,
+                            },
+                        )),
+                    ))
+                }
+            }
+
+            Scope::declare_constant(
+                scope.clone(),
+                identifier.to_owned(),
+                Constant::Integer(IntegerConstant::new(value.to_owned(), is_signed, bitlength)),
+            )?;
+        }
+
+        let mut statement = function.statement().to_owned();
+        statement.identifier.name = format!(
+            "{}<{}>",
+            function.identifier(),
+            values
+                .iter()
+                .map(BigInt::to_string)
+                .collect::<Vec<String>>()
+                .join(", ")
+        );
+        statement.const_parameters.clear();
+        let identifier = statement.identifier.clone();
+
+        let intermediate =
+            Self::new(scope.clone(), HashMap::new(), function.path().to_owned()).r#fn(statement)?;
+        let instance = match Scope::resolve_item(scope, &identifier)?.variant {
+            ScopeItemVariant::Type(Type::Function(FunctionType::UserDefined(instance))) => instance,
+            _ => panic!("{}", crate::PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS),
+        };
+
+        function.finish_instance(values, instance.clone());
+        Instances::push(intermediate);
+
+        Ok(instance)
+    }
+
+    ///
+    /// Converts the function attributes into the inlining hint.
+    ///
+    /// Only `#[inline]`, `#[inline(always)]`, and `#[inline(never)]` are supported, besides
+    /// `#[test]`, which does not affect the inlining.
+    ///
+    fn inline_hint(attributes: &[Attribute]) -> Result<InlineHint, Error> {
+        let mut hint = InlineHint::Auto;
+        for attribute in attributes.iter() {
+            let arguments: Vec<&str> = attribute
+                .arguments
+                .iter()
+                .map(|argument| argument.name.as_str())
+                .collect();
+
+            hint = match (attribute.identifier.name.as_str(), arguments.as_slice()) {
+                ("inline", []) | ("inline", ["always"]) => InlineHint::Always,
+                ("inline", ["never"]) => InlineHint::Never,
+                (Attribute::TEST, []) => hint,
+                _ => {
+                    return Err(Error::AttributeUnknown {
+                        location: attribute.location,
+                        found: attribute.to_string(),
+                    })
+                }
+            };
+        }
+        Ok(hint)
+    }
+
     ///
     /// Analyzes an implementation statement and returns its IR for the next compiler phase.
     ///
@@ -335,11 +631,13 @@ impl Analyzer {
             };
 
         self.scope_stack.push_scope(structure_scope);
+        self.path.push(statement.identifier.name);
         for statement in statement.statements.into_iter() {
             if let Some(statement) = self.local_impl(statement)? {
                 intermediate.push(statement);
             }
         }
+        self.path.pop();
         self.scope_stack.pop();
 
         Ok(intermediate)
@@ -354,24 +652,46 @@ impl Analyzer {
     ) -> Result<Option<GeneratorDeclarationStatement>, Error> {
         let location = statement.location;
 
-        let (element, expression) = ExpressionAnalyzer::new(self.scope_stack.top())
+        let (element, mut expression) = ExpressionAnalyzer::new(self.scope_stack.top())
             .analyze(statement.expression, TranslationHint::Value)?;
 
-        let r#type = if let Some(r#type) = statement.r#type {
+        let (r#type, element) = if let Some(r#type) = statement.r#type {
             let type_location = r#type.location;
             let r#type = Type::from_type_variant(&r#type.variant, self.scope_stack.top())?;
-            element
+
+            // the decimals are scaled, so they are not just cast by the declaration
+            let element_type = Type::from_element(&element, self.scope_stack.top())?;
+            let is_decimal = matches!(element_type, Type::Decimal { .. })
+                || matches!(r#type, Type::Decimal { .. });
+            if is_decimal && element_type != r#type {
+                if let Some(operator) =
+                    GeneratorExpressionOperator::casting_from(&element_type, &r#type)
+                {
+                    expression.push_operator(type_location, operator);
+                }
+            }
+
+            let element = element
                 .cast(Element::Type(r#type.clone()))
                 .map_err(|error| Error::Element(type_location, error))?;
-            r#type
+            (r#type, element)
         } else {
-            Type::from_element(&element, self.scope_stack.top())?
+            (
+                Type::from_element(&element, self.scope_stack.top())?,
+                element,
+            )
         };
 
+        let variable = match element {
+            Element::Constant(constant) if !statement.is_mutable => {
+                ScopeVariableItem::new_constant(constant)
+            }
+            _ => ScopeVariableItem::new(statement.is_mutable, r#type.clone()),
+        };
         Scope::declare_variable(
             self.scope_stack.top(),
             statement.identifier.clone(),
-            ScopeVariableItem::new(statement.is_mutable, r#type.clone()),
+            variable,
         )
         .map_err(|error| Error::Scope(error))?;
 
@@ -447,7 +767,9 @@ impl Analyzer {
             None
         };
 
+        let branch = Exposure::enter_branch();
         let (_result, body) = BlockAnalyzer::analyze(self.scope_stack.top(), statement.block)?;
+        drop(branch);
 
         self.scope_stack.pop();
 
@@ -560,7 +882,18 @@ impl Analyzer {
             ));
         }
 
-        let unique_id = TYPE_INDEX.read().expect(crate::PANIC_MUTEX_SYNC).len();
+        let signature = format!(
+            "struct {{ {} }}",
+            fields
+                .iter()
+                .map(|(identifier, r#type)| format!("{}: {}", identifier, r#type))
+                .collect::<Vec<String>>()
+                .join(", ")
+        );
+        let unique_id = r#type::unique_id(
+            self.path(statement.identifier.name.as_str()).as_slice(),
+            signature.as_str(),
+        );
         let r#type = Type::structure(
             statement.identifier.name.clone(),
             unique_id,
@@ -568,10 +901,6 @@ impl Analyzer {
             Some(self.scope_stack.top()),
         );
 
-        TYPE_INDEX
-            .write()
-            .expect(crate::PANIC_MUTEX_SYNC)
-            .insert(unique_id, r#type.to_string());
         Scope::declare_type(self.scope_stack.top(), statement.identifier, r#type)
             .map_err(|error| Error::Scope(error))?;
 
@@ -582,7 +911,19 @@ impl Analyzer {
     /// Analyzes a compile time only enumeration declaration statement.
     ///
     fn r#enum(&mut self, statement: EnumStatement) -> Result<(), Error> {
-        let unique_id = TYPE_INDEX.read().expect(crate::PANIC_MUTEX_SYNC).len();
+        let signature = format!(
+            "enum {{ {} }}",
+            statement
+                .variants
+                .iter()
+                .map(|variant| variant.identifier.name.as_str())
+                .collect::<Vec<&str>>()
+                .join(", ")
+        );
+        let unique_id = r#type::unique_id(
+            self.path(statement.identifier.name.as_str()).as_slice(),
+            signature.as_str(),
+        );
         let r#type = Type::enumeration(
             statement.identifier.clone(),
             unique_id,
@@ -590,10 +931,6 @@ impl Analyzer {
             Some(self.scope_stack.top()),
         )?;
 
-        TYPE_INDEX
-            .write()
-            .expect(crate::PANIC_MUTEX_SYNC)
-            .insert(unique_id, r#type.to_string());
         Scope::declare_type(self.scope_stack.top(), statement.identifier, r#type)
             .map_err(|error| Error::Scope(error))?;
 
@@ -649,4 +986,13 @@ impl Analyzer {
 
         Ok(())
     }
+
+    ///
+    /// Returns the full path of the item declared in the current module or implementation.
+    ///
+    fn path(&self, identifier: &str) -> Vec<String> {
+        let mut path = self.path.clone();
+        path.push(identifier.to_owned());
+        path
+    }
 }
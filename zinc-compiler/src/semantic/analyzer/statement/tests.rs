@@ -16,6 +16,7 @@ use crate::semantic::element::r#type::error::Error as TypeError;
 use crate::semantic::element::r#type::structure::error::Error as StructureTypeError;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::error::Error as SemanticError;
+use crate::syntax::filter::Filter;
 
 #[test]
 fn error_for_bounds_expected_constant_range_expression() {
@@ -63,6 +64,24 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn ok_let_constant_propagated() {
+    let input = r#"
+fn main() -> u8 {
+    let size = 2 + 2;
+    let array = [1; size];
+
+    let mut sum = 0;
+    for i in 0..size {
+        sum += array[i];
+    }
+    sum
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
 #[test]
 fn error_structure_duplicate_field() {
     let input = r#"
@@ -149,3 +168,164 @@ fn main() {}
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn ok_attribute_inline() {
+    let input = r#"
+#[inline]
+fn one() -> u8 { 1 }
+
+#[inline(never)]
+fn two() -> u8 { 2 }
+
+fn main() -> u8 {
+    one() + two()
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_attribute_unknown() {
+    let input = r#"
+#[inline(sometimes)]
+fn one() -> u8 { 1 }
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::AttributeUnknown {
+        location: Location::new(2, 1),
+        found: "#[inline(sometimes)]".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_attribute_test() {
+    let input = r#"
+#[test]
+fn it_works() {
+    assert!(true);
+}
+
+fn main() {}
+"#;
+
+    Filter::enable_features(vec![Filter::FEATURE_TEST.to_owned()]);
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_test_function_invalid_signature() {
+    let input = r#"
+#[test]
+fn it_works(value: u8) -> u8 { value }
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::TestFunctionInvalidSignature {
+            location: Location::new(3, 4),
+            function: "it_works".to_owned(),
+        },
+    ));
+
+    Filter::enable_features(vec![Filter::FEATURE_TEST.to_owned()]);
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_main_public_argument() {
+    let input = r#"
+fn main(pub root: field, preimage: [u8; 4]) -> field {
+    root
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_function_argument_public_outside_main() {
+    let input = r#"
+fn hash(pub value: u8) -> u8 { value }
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::FunctionArgumentPublicOutsideMain {
+            location: Location::new(2, 9),
+            function: "hash".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_extern_host() {
+    let input = r#"
+extern "host" fn lookup(key: u64) -> (u64, bool);
+
+fn main(key: u64) -> u64 {
+    let result = lookup(key);
+    if result.1 { result.0 } else { 0 as u64 }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_extern_abi_unsupported() {
+    let input = r#"
+extern "C" fn lookup(key: u64) -> u64;
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::ExternAbiUnsupported {
+        location: Location::new(2, 1),
+        abi: "C".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_extern_function_type_unsupported() {
+    let input = r#"
+fn hash(value: u64) -> u64 { value }
+
+extern "host" fn lookup(callback: hash) -> u64;
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::ExternFunctionTypeUnsupported {
+            location: Location::new(4, 35),
+            function: "lookup".to_owned(),
+            found: "fn hash(value: u64) -> u64".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
@@ -20,15 +20,18 @@ impl Caster {
     /// u<b1> -> u<b2>
     /// u<b1> -> i<b2>
     /// u<b1> -> field
+    /// u<b1> -> Decimal<i, f>
     /// i<b1> -> i<b2>
     /// i<b1> -> u<b2>
     /// i<b1> -> field
     /// enum<b1> -> i<b2>
     /// enum<b1> -> u<b2>
     /// enum<b1> -> field
+    /// Decimal<i, f> -> u<b2>
+    /// Decimal<i, f> -> field
     /// T -> T (no effect, no errors)
     ///
-    /// `b1` and `b2` are bitlengths
+    /// `b1` and `b2` are bitlengths, `i` and `f` are the decimal integer and fractional bits
     /// `T` is any type
     ///
     /// For more information on type semantics, see the official Zinc book.
@@ -38,6 +41,7 @@ impl Caster {
             (Type::IntegerUnsigned { .. }, Type::IntegerUnsigned { .. }) => Ok(()),
             (Type::IntegerUnsigned { .. }, Type::IntegerSigned { .. }) => Ok(()),
             (Type::IntegerUnsigned { .. }, Type::Field) => Ok(()),
+            (Type::IntegerUnsigned { .. }, Type::Decimal { .. }) => Ok(()),
             (from @ Type::IntegerUnsigned { .. }, to) => {
                 Err(Error::casting_to_invalid_type(from, to))
             }
@@ -50,6 +54,8 @@ impl Caster {
             (Type::Enumeration(_), Type::IntegerSigned { .. }) => Ok(()),
             (Type::Enumeration(_), Type::IntegerUnsigned { .. }) => Ok(()),
             (Type::Enumeration(_), Type::Field) => Ok(()),
+            (Type::Decimal { .. }, Type::IntegerUnsigned { .. }) => Ok(()),
+            (Type::Decimal { .. }, Type::Field) => Ok(()),
             (from, to) => {
                 if from == to {
                     Ok(())
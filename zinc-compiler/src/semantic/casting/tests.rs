@@ -188,7 +188,7 @@ fn main() {
 fn error_casting_from_invalid_type() {
     let input = r#"
 fn main() {
-    let value: field = 0;
+    let mut value: field = 0;
     let result = value as u8;
 }
 "#;
@@ -212,7 +212,7 @@ fn main() {
 fn error_casting_to_invalid_type() {
     let input = r#"
 fn main() {
-    let value: u8 = 0;
+    let mut value: u8 = 0;
     let result = value as bool;
 }
 "#;
@@ -234,7 +234,7 @@ fn main() {
 fn error_casting_to_invalid_type_let_implicit() {
     let input = r#"
 fn main() {
-    let value = 42;
+    let mut value = 42;
     let result: bool = value;
 }
 "#;
@@ -251,3 +251,51 @@ fn main() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn ok_decimal_to_integer() {
+    let input = r#"
+fn main(decimal: Decimal<32, 16>) -> (u32, field) {
+    (decimal as u32, decimal as field)
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, Ok(()));
+}
+
+#[test]
+fn ok_integer_to_decimal() {
+    let input = r#"
+fn main(integer: u32) -> Decimal<32, 16> {
+    integer as Decimal<32, 16>
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, Ok(()));
+}
+
+#[test]
+fn error_casting_to_invalid_type_decimal_signed() {
+    let input = r#"
+fn main() {
+    let mut value: i32 = 0;
+    let result = value as Decimal<32, 16>;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(4, 24),
+        ElementError::Value(ValueError::Casting(CastingError::casting_to_invalid_type(
+            &Type::integer_signed(crate::BITLENGTH_BYTE * 4),
+            &Type::decimal(crate::BITLENGTH_BYTE * 4, crate::BITLENGTH_BYTE * 2),
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
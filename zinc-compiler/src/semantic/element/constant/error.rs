@@ -73,6 +73,9 @@ pub enum Error {
     OperatorRemainderFirstOperandExpectedInteger { found: String },
     OperatorRemainderSecondOperandExpectedInteger { found: String },
 
+    OperatorExponentiationFirstOperandExpectedInteger { found: String },
+    OperatorExponentiationSecondOperandExpectedInteger { found: String },
+
     OperatorNotExpectedBoolean { found: String },
 
     OperatorBitwiseNotExpectedInteger { found: String },
@@ -23,12 +23,14 @@ pub enum Error {
 
     OperatorBitwiseShiftLeftSecondOperatorExpectedUnsigned { found: String },
     OperatorBitwiseShiftRightSecondOperatorExpectedUnsigned { found: String },
+    OperatorExponentiationSecondOperatorExpectedUnsigned { found: String },
 
     OverflowAddition { value: BigInt, r#type: String },
     OverflowSubtraction { value: BigInt, r#type: String },
     OverflowMultiplication { value: BigInt, r#type: String },
     OverflowDivision { value: BigInt, r#type: String },
     OverflowRemainder { value: BigInt, r#type: String },
+    OverflowExponentiation { value: String, r#type: String },
     OverflowCasting { value: BigInt, r#type: String },
     OverflowNegation { value: BigInt, r#type: String },
 
@@ -12,10 +12,12 @@ use std::fmt;
 
 use num_bigint::BigInt;
 use num_traits::Num;
+use num_traits::One;
 use num_traits::Signed;
 use num_traits::ToPrimitive;
+use num_traits::Zero;
 
-use zinc_utils::euclidean;
+use zinc_utils::truncated;
 
 use crate::lexical::token::lexeme::literal::integer::Integer as LexicalIntegerLiteral;
 use crate::semantic::element::constant::boolean::Boolean as BooleanConstant;
@@ -75,6 +77,36 @@ impl Integer {
             }
     }
 
+    ///
+    /// Implicitly widens the narrower operand to the type of the wider one, which is allowed
+    /// for the integers of the same sign, e.g. `u8` to `u64` or `i8` to `i64`.
+    ///
+    /// Returns whether the operands have the same type afterwards.
+    ///
+    pub fn widen(&mut self, other: &mut Self) -> bool {
+        if self.is_widenable_to(other) {
+            self.bitlength = other.bitlength;
+        } else if other.is_widenable_to(self) {
+            other.bitlength = self.bitlength;
+        }
+
+        self.has_the_same_type_as(other)
+    }
+
+    ///
+    /// Checks if the integer can be implicitly widened to the type of `other`.
+    ///
+    /// The enumeration values and fields are never widened, since their types
+    /// are not just integers of a greater bitlength.
+    ///
+    pub fn is_widenable_to(&self, other: &Self) -> bool {
+        self.enumeration.is_none()
+            && other.enumeration.is_none()
+            && self.is_signed == other.is_signed
+            && self.bitlength < other.bitlength
+            && other.bitlength != crate::BITLENGTH_FIELD
+    }
+
     pub fn range_inclusive(self, other: Self) -> Result<RangeInclusive, Error> {
         let is_signed = self.is_signed || other.is_signed;
         let bitlength = cmp::max(
@@ -103,8 +135,8 @@ impl Integer {
         ))
     }
 
-    pub fn equals(self, other: Self) -> Result<BooleanConstant, Error> {
-        if !self.has_the_same_type_as(&other) {
+    pub fn equals(mut self, mut other: Self) -> Result<BooleanConstant, Error> {
+        if !self.widen(&mut other) {
             return Err(Error::TypesMismatchEquals {
                 first: self.r#type().to_string(),
                 second: other.r#type().to_string(),
@@ -115,8 +147,8 @@ impl Integer {
         Ok(BooleanConstant::new(result))
     }
 
-    pub fn not_equals(self, other: Self) -> Result<BooleanConstant, Error> {
-        if !self.has_the_same_type_as(&other) {
+    pub fn not_equals(mut self, mut other: Self) -> Result<BooleanConstant, Error> {
+        if !self.widen(&mut other) {
             return Err(Error::TypesMismatchNotEquals {
                 first: self.r#type().to_string(),
                 second: other.r#type().to_string(),
@@ -127,8 +159,8 @@ impl Integer {
         Ok(BooleanConstant::new(result))
     }
 
-    pub fn greater_equals(self, other: Self) -> Result<BooleanConstant, Error> {
-        if !self.has_the_same_type_as(&other) {
+    pub fn greater_equals(mut self, mut other: Self) -> Result<BooleanConstant, Error> {
+        if !self.widen(&mut other) {
             return Err(Error::TypesMismatchGreaterEquals {
                 first: self.r#type().to_string(),
                 second: other.r#type().to_string(),
@@ -139,8 +171,8 @@ impl Integer {
         Ok(BooleanConstant::new(result))
     }
 
-    pub fn lesser_equals(self, other: Self) -> Result<BooleanConstant, Error> {
-        if !self.has_the_same_type_as(&other) {
+    pub fn lesser_equals(mut self, mut other: Self) -> Result<BooleanConstant, Error> {
+        if !self.widen(&mut other) {
             return Err(Error::TypesMismatchLesserEquals {
                 first: self.r#type().to_string(),
                 second: other.r#type().to_string(),
@@ -151,8 +183,8 @@ impl Integer {
         Ok(BooleanConstant::new(result))
     }
 
-    pub fn greater(self, other: Self) -> Result<BooleanConstant, Error> {
-        if !self.has_the_same_type_as(&other) {
+    pub fn greater(mut self, mut other: Self) -> Result<BooleanConstant, Error> {
+        if !self.widen(&mut other) {
             return Err(Error::TypesMismatchGreater {
                 first: self.r#type().to_string(),
                 second: other.r#type().to_string(),
@@ -163,8 +195,8 @@ impl Integer {
         Ok(BooleanConstant::new(result))
     }
 
-    pub fn lesser(self, other: Self) -> Result<BooleanConstant, Error> {
-        if !self.has_the_same_type_as(&other) {
+    pub fn lesser(mut self, mut other: Self) -> Result<BooleanConstant, Error> {
+        if !self.widen(&mut other) {
             return Err(Error::TypesMismatchLesser {
                 first: self.r#type().to_string(),
                 second: other.r#type().to_string(),
@@ -175,8 +207,8 @@ impl Integer {
         Ok(BooleanConstant::new(result))
     }
 
-    pub fn bitwise_or(self, other: Self) -> Result<Self, Error> {
-        if !self.has_the_same_type_as(&other) {
+    pub fn bitwise_or(mut self, mut other: Self) -> Result<Self, Error> {
+        if !self.widen(&mut other) {
             return Err(Error::TypesMismatchBitwiseOr {
                 first: self.r#type().to_string(),
                 second: other.r#type().to_string(),
@@ -197,8 +229,8 @@ impl Integer {
         })
     }
 
-    pub fn bitwise_xor(self, other: Self) -> Result<Self, Error> {
-        if !self.has_the_same_type_as(&other) {
+    pub fn bitwise_xor(mut self, mut other: Self) -> Result<Self, Error> {
+        if !self.widen(&mut other) {
             return Err(Error::TypesMismatchBitwiseXor {
                 first: self.r#type().to_string(),
                 second: other.r#type().to_string(),
@@ -219,8 +251,8 @@ impl Integer {
         })
     }
 
-    pub fn bitwise_and(self, other: Self) -> Result<Self, Error> {
-        if !self.has_the_same_type_as(&other) {
+    pub fn bitwise_and(mut self, mut other: Self) -> Result<Self, Error> {
+        if !self.widen(&mut other) {
             return Err(Error::TypesMismatchBitwiseAnd {
                 first: self.r#type().to_string(),
                 second: other.r#type().to_string(),
@@ -301,8 +333,8 @@ impl Integer {
         })
     }
 
-    pub fn add(self, other: Self) -> Result<Self, Error> {
-        if !self.has_the_same_type_as(&other) {
+    pub fn add(mut self, mut other: Self) -> Result<Self, Error> {
+        if !self.widen(&mut other) {
             return Err(Error::TypesMismatchAddition {
                 first: self.r#type().to_string(),
                 second: other.r#type().to_string(),
@@ -332,8 +364,8 @@ impl Integer {
         })
     }
 
-    pub fn subtract(self, other: Self) -> Result<Self, Error> {
-        if !self.has_the_same_type_as(&other) {
+    pub fn subtract(mut self, mut other: Self) -> Result<Self, Error> {
+        if !self.widen(&mut other) {
             return Err(Error::TypesMismatchSubtraction {
                 first: self.r#type().to_string(),
                 second: other.r#type().to_string(),
@@ -363,8 +395,8 @@ impl Integer {
         })
     }
 
-    pub fn multiply(self, other: Self) -> Result<Self, Error> {
-        if !self.has_the_same_type_as(&other) {
+    pub fn multiply(mut self, mut other: Self) -> Result<Self, Error> {
+        if !self.widen(&mut other) {
             return Err(Error::TypesMismatchMultiplication {
                 first: self.r#type().to_string(),
                 second: other.r#type().to_string(),
@@ -394,8 +426,8 @@ impl Integer {
         })
     }
 
-    pub fn divide(self, other: Self) -> Result<Self, Error> {
-        if !self.has_the_same_type_as(&other) {
+    pub fn divide(mut self, mut other: Self) -> Result<Self, Error> {
+        if !self.widen(&mut other) {
             return Err(Error::TypesMismatchDivision {
                 first: self.r#type().to_string(),
                 second: other.r#type().to_string(),
@@ -407,7 +439,7 @@ impl Integer {
         }
 
         let (result, _remainder) =
-            euclidean::div_rem(&self.value, &other.value).ok_or(Error::ZeroDivision)?;
+            truncated::div_rem(&self.value, &other.value).ok_or(Error::ZeroDivision)?;
         if result.is_negative() && !self.is_signed {
             return Err(Error::OverflowDivision {
                 value: result,
@@ -430,8 +462,8 @@ impl Integer {
         })
     }
 
-    pub fn remainder(self, other: Self) -> Result<Self, Error> {
-        if !self.has_the_same_type_as(&other) {
+    pub fn remainder(mut self, mut other: Self) -> Result<Self, Error> {
+        if !self.widen(&mut other) {
             return Err(Error::TypesMismatchRemainder {
                 first: self.r#type().to_string(),
                 second: other.r#type().to_string(),
@@ -443,7 +475,7 @@ impl Integer {
         }
 
         let (_quotient, result) =
-            euclidean::div_rem(&self.value, &other.value).ok_or(Error::ZeroRemainder)?;
+            truncated::div_rem(&self.value, &other.value).ok_or(Error::ZeroRemainder)?;
         if result.is_negative() && !self.is_signed {
             return Err(Error::OverflowRemainder {
                 value: result,
@@ -466,6 +498,49 @@ impl Integer {
         })
     }
 
+    pub fn exponentiate(self, other: Self) -> Result<Self, Error> {
+        if other.is_signed {
+            return Err(
+                Error::OperatorExponentiationSecondOperatorExpectedUnsigned {
+                    found: other.to_string(),
+                },
+            );
+        }
+
+        let overflow = || Error::OverflowExponentiation {
+            value: format!("{} ** {}", self.value, other.value),
+            r#type: Type::integer(self.is_signed, self.bitlength).to_string(),
+        };
+
+        // the powers of `0`, `1`, and `-1` depend only on the exponent parity, and the powers
+        // of other bases overflow if the exponent exceeds the bitlength
+        let exponent = if self.value.abs() <= BigInt::one() && !other.value.is_zero() {
+            if (&other.value % BigInt::from(2)).is_zero() {
+                2
+            } else {
+                1
+            }
+        } else {
+            match other.value.to_usize() {
+                Some(exponent) if exponent <= self.bitlength => exponent,
+                _ => return Err(overflow()),
+            }
+        };
+        let result = num_traits::pow(self.value.to_owned(), exponent);
+
+        match Self::minimal_bitlength(&result, self.is_signed) {
+            Ok(bitlength) if bitlength <= self.bitlength => {}
+            _ => return Err(overflow()),
+        }
+
+        Ok(Self {
+            value: result,
+            is_signed: self.is_signed,
+            bitlength: self.bitlength,
+            enumeration: self.enumeration,
+        })
+    }
+
     pub fn cast(mut self, is_signed: bool, bitlength: usize) -> Result<Self, Error> {
         if self.value.is_negative() && !is_signed {
             return Err(Error::OverflowCasting {
@@ -526,6 +601,14 @@ impl Integer {
         })
     }
 
+    ///
+    /// Returns `-1`, `0`, or `1` depending on the sign, typed as `i8` like the result of
+    /// `std::math::sign`.
+    ///
+    pub fn sign(&self) -> Self {
+        Self::new(self.value.signum(), true, crate::BITLENGTH_BYTE)
+    }
+
     pub fn to_usize(&self) -> Result<usize, Error> {
         self.value.to_usize().ok_or_else(|| Error::IntegerTooLarge {
             value: self.value.to_owned(),
@@ -17,6 +17,44 @@ use crate::semantic::element::error::Error as ElementError;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::error::Error as SemanticError;
 
+#[test]
+fn ok_widening() {
+    let input = r#"
+const VALUE: u16 = 42 + 300;
+
+fn main() -> u16 {
+    VALUE
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, Ok(()));
+}
+
+#[test]
+fn error_overflow_addition_widened() {
+    let input = r#"
+fn main() {
+    let value = 255 + 65535;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 21),
+        ElementError::Constant(ConstantError::Integer(
+            IntegerConstantError::OverflowAddition {
+                value: BigInt::from(65790),
+                r#type: Type::integer_unsigned(crate::BITLENGTH_BYTE * 2).to_string(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn ok_minimal_bitlength() {
     assert_eq!(
@@ -195,7 +233,7 @@ fn main() {
 fn error_types_mismatch_greater_equals() {
     let input = r#"
 fn main() {
-    let value = 42 as u64 >= 69 as u128;
+    let value = 42 as u64 >= 69 as i128;
 }
 "#;
 
@@ -204,7 +242,7 @@ fn main() {
         ElementError::Constant(ConstantError::Integer(
             IntegerConstantError::TypesMismatchGreaterEquals {
                 first: Type::integer_unsigned(crate::BITLENGTH_BYTE * 8).to_string(),
-                second: Type::integer_unsigned(crate::BITLENGTH_BYTE * 16).to_string(),
+                second: Type::integer_signed(crate::BITLENGTH_BYTE * 16).to_string(),
             },
         )),
     )));
@@ -276,7 +314,7 @@ fn main() {
 fn error_types_mismatch_lesser_equals() {
     let input = r#"
 fn main() {
-    let value = 42 as u64 <= 69 as u128;
+    let value = 42 as u64 <= 69 as i128;
 }
 "#;
 
@@ -285,7 +323,7 @@ fn main() {
         ElementError::Constant(ConstantError::Integer(
             IntegerConstantError::TypesMismatchLesserEquals {
                 first: Type::integer_unsigned(crate::BITLENGTH_BYTE * 8).to_string(),
-                second: Type::integer_unsigned(crate::BITLENGTH_BYTE * 16).to_string(),
+                second: Type::integer_signed(crate::BITLENGTH_BYTE * 16).to_string(),
             },
         )),
     )));
@@ -357,7 +395,7 @@ fn main() {
 fn error_types_mismatch_greater() {
     let input = r#"
 fn main() {
-    let value = 42 as u64 > 69 as u128;
+    let value = 42 as u64 > 69 as i128;
 }
 "#;
 
@@ -366,7 +404,7 @@ fn main() {
         ElementError::Constant(ConstantError::Integer(
             IntegerConstantError::TypesMismatchGreater {
                 first: Type::integer_unsigned(crate::BITLENGTH_BYTE * 8).to_string(),
-                second: Type::integer_unsigned(crate::BITLENGTH_BYTE * 16).to_string(),
+                second: Type::integer_signed(crate::BITLENGTH_BYTE * 16).to_string(),
             },
         )),
     )));
@@ -438,7 +476,7 @@ fn main() {
 fn error_types_mismatch_lesser() {
     let input = r#"
 fn main() {
-    let value = 42 as u64 < 69 as u128;
+    let value = 42 as u64 < 69 as i128;
 }
 "#;
 
@@ -447,7 +485,7 @@ fn main() {
         ElementError::Constant(ConstantError::Integer(
             IntegerConstantError::TypesMismatchLesser {
                 first: Type::integer_unsigned(crate::BITLENGTH_BYTE * 8).to_string(),
-                second: Type::integer_unsigned(crate::BITLENGTH_BYTE * 16).to_string(),
+                second: Type::integer_signed(crate::BITLENGTH_BYTE * 16).to_string(),
             },
         )),
     )));
@@ -519,7 +557,7 @@ fn main() {
 fn error_types_mismatch_bitwise_or() {
     let input = r#"
 fn main() {
-    let value = 42 as u64 | 69 as u128;
+    let value = 42 as u64 | 69 as i128;
 }
 "#;
 
@@ -528,7 +566,7 @@ fn main() {
         ElementError::Constant(ConstantError::Integer(
             IntegerConstantError::TypesMismatchBitwiseOr {
                 first: Type::integer_unsigned(crate::BITLENGTH_BYTE * 8).to_string(),
-                second: Type::integer_unsigned(crate::BITLENGTH_BYTE * 16).to_string(),
+                second: Type::integer_signed(crate::BITLENGTH_BYTE * 16).to_string(),
             },
         )),
     )));
@@ -600,7 +638,7 @@ fn main() {
 fn error_types_mismatch_bitwise_xor() {
     let input = r#"
 fn main() {
-    let value = 42 as u64 ^ 69 as u128;
+    let value = 42 as u64 ^ 69 as i128;
 }
 "#;
 
@@ -609,7 +647,7 @@ fn main() {
         ElementError::Constant(ConstantError::Integer(
             IntegerConstantError::TypesMismatchBitwiseXor {
                 first: Type::integer_unsigned(crate::BITLENGTH_BYTE * 8).to_string(),
-                second: Type::integer_unsigned(crate::BITLENGTH_BYTE * 16).to_string(),
+                second: Type::integer_signed(crate::BITLENGTH_BYTE * 16).to_string(),
             },
         )),
     )));
@@ -681,7 +719,7 @@ fn main() {
 fn error_types_mismatch_bitwise_and() {
     let input = r#"
 fn main() {
-    let value = 42 as u64 & 69 as u128;
+    let value = 42 as u64 & 69 as i128;
 }
 "#;
 
@@ -690,7 +728,7 @@ fn main() {
         ElementError::Constant(ConstantError::Integer(
             IntegerConstantError::TypesMismatchBitwiseAnd {
                 first: Type::integer_unsigned(crate::BITLENGTH_BYTE * 8).to_string(),
-                second: Type::integer_unsigned(crate::BITLENGTH_BYTE * 16).to_string(),
+                second: Type::integer_signed(crate::BITLENGTH_BYTE * 16).to_string(),
             },
         )),
     )));
@@ -762,7 +800,7 @@ fn main() {
 fn error_types_mismatch_addition() {
     let input = r#"
 fn main() {
-    let value = 42 as u64 + 69 as u128;
+    let value = 42 as u64 + 69 as i128;
 }
 "#;
 
@@ -771,7 +809,7 @@ fn main() {
         ElementError::Constant(ConstantError::Integer(
             IntegerConstantError::TypesMismatchAddition {
                 first: Type::integer_unsigned(crate::BITLENGTH_BYTE * 8).to_string(),
-                second: Type::integer_unsigned(crate::BITLENGTH_BYTE * 16).to_string(),
+                second: Type::integer_signed(crate::BITLENGTH_BYTE * 16).to_string(),
             },
         )),
     )));
@@ -843,7 +881,7 @@ fn main() {
 fn error_types_mismatch_subtraction() {
     let input = r#"
 fn main() {
-    let value = 42 as u64 - 69 as u128;
+    let value = 42 as u64 - 69 as i128;
 }
 "#;
 
@@ -852,7 +890,7 @@ fn main() {
         ElementError::Constant(ConstantError::Integer(
             IntegerConstantError::TypesMismatchSubtraction {
                 first: Type::integer_unsigned(crate::BITLENGTH_BYTE * 8).to_string(),
-                second: Type::integer_unsigned(crate::BITLENGTH_BYTE * 16).to_string(),
+                second: Type::integer_signed(crate::BITLENGTH_BYTE * 16).to_string(),
             },
         )),
     )));
@@ -924,7 +962,7 @@ fn main() {
 fn error_types_mismatch_multiplication() {
     let input = r#"
 fn main() {
-    let value = 42 as u64 * 69 as u128;
+    let value = 42 as u64 * 69 as i128;
 }
 "#;
 
@@ -933,7 +971,7 @@ fn main() {
         ElementError::Constant(ConstantError::Integer(
             IntegerConstantError::TypesMismatchMultiplication {
                 first: Type::integer_unsigned(crate::BITLENGTH_BYTE * 8).to_string(),
-                second: Type::integer_unsigned(crate::BITLENGTH_BYTE * 16).to_string(),
+                second: Type::integer_signed(crate::BITLENGTH_BYTE * 16).to_string(),
             },
         )),
     )));
@@ -1005,7 +1043,7 @@ fn main() {
 fn error_types_mismatch_division() {
     let input = r#"
 fn main() {
-    let value = 42 as u64 / 69 as u128;
+    let value = 42 as u64 / 69 as i128;
 }
 "#;
 
@@ -1014,7 +1052,7 @@ fn main() {
         ElementError::Constant(ConstantError::Integer(
             IntegerConstantError::TypesMismatchDivision {
                 first: Type::integer_unsigned(crate::BITLENGTH_BYTE * 8).to_string(),
-                second: Type::integer_unsigned(crate::BITLENGTH_BYTE * 16).to_string(),
+                second: Type::integer_signed(crate::BITLENGTH_BYTE * 16).to_string(),
             },
         )),
     )));
@@ -1086,7 +1124,7 @@ fn main() {
 fn error_types_mismatch_remainder() {
     let input = r#"
 fn main() {
-    let value = 42 as u64 % 69 as u128;
+    let value = 42 as u64 % 69 as i128;
 }
 "#;
 
@@ -1095,7 +1133,7 @@ fn main() {
         ElementError::Constant(ConstantError::Integer(
             IntegerConstantError::TypesMismatchRemainder {
                 first: Type::integer_unsigned(crate::BITLENGTH_BYTE * 8).to_string(),
-                second: Type::integer_unsigned(crate::BITLENGTH_BYTE * 16).to_string(),
+                second: Type::integer_signed(crate::BITLENGTH_BYTE * 16).to_string(),
             },
         )),
     )));
@@ -1186,6 +1224,29 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn error_operator_exponentiation_2nd_operand_expected_unsigned() {
+    let input = r#"
+fn main() {
+    let value = 42 ** -2;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 20),
+        ElementError::Constant(ConstantError::Integer(
+            IntegerConstantError::OperatorExponentiationSecondOperatorExpectedUnsigned {
+                found: IntegerConstant::new(BigInt::from(-2), true, crate::BITLENGTH_BYTE)
+                    .to_string(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_operator_bitwise_shift_right_2nd_operand_expected_unsigned() {
     let input = r#"
@@ -1416,6 +1477,114 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn ok_division_truncated() {
+    let operands = |a: i8, b: i8| {
+        (
+            IntegerConstant::new(BigInt::from(a), true, crate::BITLENGTH_BYTE),
+            IntegerConstant::new(BigInt::from(b), true, crate::BITLENGTH_BYTE),
+        )
+    };
+
+    for (a, b, quotient, remainder) in [
+        (9, 4, 2, 1),
+        (9, -4, -2, 1),
+        (-9, 4, -2, -1),
+        (-9, -4, 2, -1),
+        (-45, 7, -6, -3),
+    ]
+    .iter()
+    {
+        let (left, right) = operands(*a, *b);
+        assert_eq!(
+            left.divide(right).map(|result| result.value),
+            Ok(BigInt::from(*quotient))
+        );
+
+        let (left, right) = operands(*a, *b);
+        assert_eq!(
+            left.remainder(right).map(|result| result.value),
+            Ok(BigInt::from(*remainder))
+        );
+    }
+}
+
+#[test]
+fn ok_exponentiation() {
+    let operands = |base: i16, exponent: u16| {
+        (
+            IntegerConstant::new(BigInt::from(base), true, crate::BITLENGTH_BYTE * 2),
+            IntegerConstant::new(BigInt::from(exponent), false, crate::BITLENGTH_BYTE * 2),
+        )
+    };
+
+    for (base, exponent, result) in [
+        (0, 0, 1),
+        (0, 5, 0),
+        (1, 1000, 1),
+        (-1, 1000, 1),
+        (-1, 1001, -1),
+        (7, 0, 1),
+        (3, 5, 243),
+        (-2, 15, -32768),
+        (2, 14, 16384),
+    ]
+    .iter()
+    {
+        let (base, exponent) = operands(*base, *exponent);
+        assert_eq!(
+            base.exponentiate(exponent).map(|result| result.value),
+            Ok(BigInt::from(*result))
+        );
+    }
+}
+
+#[test]
+fn error_overflow_exponentiation() {
+    let input = r#"
+fn main() {
+    let value = 2 ** 8;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 19),
+        ElementError::Constant(ConstantError::Integer(
+            IntegerConstantError::OverflowExponentiation {
+                value: "2 ** 8".to_owned(),
+                r#type: Type::integer(false, crate::BITLENGTH_BYTE).to_string(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_overflow_exponentiation_large_exponent() {
+    let input = r#"
+fn main() {
+    let value = (2 as i64) ** 1000000;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 28),
+        ElementError::Constant(ConstantError::Integer(
+            IntegerConstantError::OverflowExponentiation {
+                value: "2 ** 1000000".to_owned(),
+                r#type: Type::integer(true, crate::BITLENGTH_BYTE * 8).to_string(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_overflow_division_signed_positive() {
     let input = r#"
@@ -1746,3 +1915,14 @@ fn main() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn ok_sign() {
+    for (value, result) in [(-100, -1), (-1, -1), (0, 0), (1, 1), (127, 1)].iter() {
+        let value = IntegerConstant::new(BigInt::from(*value), true, crate::BITLENGTH_BYTE * 4);
+        assert_eq!(
+            value.sign(),
+            IntegerConstant::new(BigInt::from(*result), true, crate::BITLENGTH_BYTE)
+        );
+    }
+}
@@ -12,6 +12,7 @@ pub mod range_inclusive;
 
 use std::fmt;
 
+use crate::semantic::casting::error::Error as CastingError;
 use crate::semantic::casting::Caster;
 use crate::semantic::element::r#type::Type;
 
@@ -437,6 +438,23 @@ impl Constant {
         }
     }
 
+    pub fn exponentiate(self, other: Self) -> Result<Self, Error> {
+        match self {
+            Self::Integer(integer_1) => match other {
+                Self::Integer(integer_2) => integer_1
+                    .exponentiate(integer_2)
+                    .map(Self::Integer)
+                    .map_err(Error::Integer),
+                value => Err(Error::OperatorExponentiationSecondOperandExpectedInteger {
+                    found: value.to_string(),
+                }),
+            },
+            value => Err(Error::OperatorExponentiationFirstOperandExpectedInteger {
+                found: value.to_string(),
+            }),
+        }
+    }
+
     pub fn not(self) -> Result<Self, Error> {
         match self {
             Self::Boolean(value) => {
@@ -470,8 +488,17 @@ impl Constant {
         }
     }
 
+    ///
+    /// The decimals are never constant, since they are scaled at runtime, so the constants
+    /// are cast to decimals as values.
+    ///
     pub fn cast(self, to: Type) -> Result<Self, Error> {
         let from = self.r#type();
+        if let Type::Decimal { .. } = to {
+            return Err(Error::Casting(CastingError::casting_to_invalid_type(
+                &from, &to,
+            )));
+        }
         Caster::cast(&from, &to).map_err(Error::Casting)?;
 
         let (is_signed, bitlength) = match to {
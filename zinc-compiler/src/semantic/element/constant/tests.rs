@@ -1065,6 +1065,28 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn error_operator_casting_to_invalid_type_const_decimal() {
+    let input = r#"
+fn main() {
+    const VALUE: u8 = 42;
+    const RESULT: Decimal<32, 16> = VALUE;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(4, 19),
+        ElementError::Constant(ConstantError::Casting(CastingError::CastingToInvalidType {
+            from: Type::integer_unsigned(crate::BITLENGTH_BYTE).to_string(),
+            to: Type::decimal(crate::BITLENGTH_BYTE * 4, crate::BITLENGTH_BYTE * 2).to_string(),
+        })),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_operator_not_expected_boolean() {
     let input = r#"
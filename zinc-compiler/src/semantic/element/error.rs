@@ -94,6 +94,9 @@ pub enum Error {
     OperatorRemainderFirstOperandExpectedEvaluable { found: String },
     OperatorRemainderSecondOperandExpectedEvaluable { found: String },
 
+    OperatorExponentiationFirstOperandExpectedEvaluable { found: String },
+    OperatorExponentiationSecondOperandExpectedConstant { found: String },
+
     OperatorCastingFirstOperandExpectedEvaluable { found: String },
     OperatorCastingSecondOperandExpectedType { found: String },
 
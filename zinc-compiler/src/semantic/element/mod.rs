@@ -1095,6 +1095,46 @@ impl Element {
         }
     }
 
+    pub fn exponentiate(self, other: Self) -> Result<Self, Error> {
+        match (self, other) {
+            (Element::Value(value_1), Element::Constant(value_2)) => value_1
+                .exponentiate(value_2)
+                .map(Self::Value)
+                .map_err(Error::Value),
+            (Element::Value(_), element_2) => {
+                Err(Error::OperatorExponentiationSecondOperandExpectedConstant {
+                    found: element_2.to_string(),
+                })
+            }
+            (Element::Constant(value_1), Element::Constant(value_2)) => value_1
+                .exponentiate(value_2)
+                .map(Self::Constant)
+                .map_err(Error::Constant),
+            (Element::Constant(_), element_2) => {
+                Err(Error::OperatorExponentiationSecondOperandExpectedConstant {
+                    found: element_2.to_string(),
+                })
+            }
+            (element_1, _) => Err(Error::OperatorExponentiationFirstOperandExpectedEvaluable {
+                found: element_1.to_string(),
+            }),
+        }
+    }
+
+    ///
+    /// Converts the boolean and integer constants into the values of the same type, which is
+    /// required where one of several constants is selected at runtime, e.g. by a conditional.
+    ///
+    pub fn into_runtime(self) -> Self {
+        match self {
+            Self::Constant(constant @ Constant::Boolean(_))
+            | Self::Constant(constant @ Constant::Integer(_)) => Self::Value(
+                Value::try_from(constant).expect(crate::PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS),
+            ),
+            element => element,
+        }
+    }
+
     pub fn cast(self, other: Self) -> Result<Self, Error> {
         let r#type = match other {
             Self::Type(r#type) => r#type,
@@ -1107,6 +1147,12 @@ impl Element {
 
         match self {
             Element::Value(value) => value.cast(r#type).map(Self::Value).map_err(Error::Value),
+            Element::Constant(constant) if matches!(r#type, Type::Decimal { .. }) => {
+                Value::try_from(constant)
+                    .and_then(|value| value.cast(r#type))
+                    .map(Self::Value)
+                    .map_err(Error::Value)
+            }
             Element::Constant(constant) => constant
                 .cast(r#type)
                 .map(Self::Constant)
@@ -1198,25 +1244,12 @@ impl Element {
                         (Element::Place(place), FieldAccessVariant::Field(access))
                     })
                     .map_err(Error::Place),
-                Self::Identifier(identifier) => match place.r#type {
-                    Type::Structure(ref structure) => {
-                        match Scope::resolve_item(structure.scope.to_owned(), &identifier) {
-                            Ok(ScopeItem {
-                                variant: ScopeItemVariant::Type(r#type @ Type::Function(_)),
-                                ..
-                            }) => Ok((
-                                Element::Type(r#type),
-                                FieldAccessVariant::Method(Self::Place(place)),
-                            )),
-                            _ => place
-                                .field_structure(identifier.name)
-                                .map(|(place, access)| {
-                                    (Element::Place(place), FieldAccessVariant::Field(access))
-                                })
-                                .map_err(Error::Place),
-                        }
-                    }
-                    _ => place
+                Self::Identifier(identifier) => match Self::method(&place.r#type, &identifier) {
+                    Some(r#type) => Ok((
+                        Element::Type(r#type),
+                        FieldAccessVariant::Method(Self::Place(place)),
+                    )),
+                    None => place
                         .field_structure(identifier.name)
                         .map(|(place, access)| {
                             (Element::Place(place), FieldAccessVariant::Field(access))
@@ -1234,25 +1267,12 @@ impl Element {
                         (Element::Value(value), FieldAccessVariant::Field(access))
                     })
                     .map_err(Error::Value),
-                Self::Identifier(identifier) => match value.r#type() {
-                    Type::Structure(ref structure) => {
-                        match Scope::resolve_item(structure.scope.to_owned(), &identifier) {
-                            Ok(ScopeItem {
-                                variant: ScopeItemVariant::Type(r#type @ Type::Function(_)),
-                                ..
-                            }) => Ok((
-                                Element::Type(r#type),
-                                FieldAccessVariant::Method(Self::Value(value)),
-                            )),
-                            _ => value
-                                .field_structure(identifier.name)
-                                .map(|(value, access)| {
-                                    (Element::Value(value), FieldAccessVariant::Field(access))
-                                })
-                                .map_err(Error::Value),
-                        }
-                    }
-                    _ => value
+                Self::Identifier(identifier) => match Self::method(&value.r#type(), &identifier) {
+                    Some(r#type) => Ok((
+                        Element::Type(r#type),
+                        FieldAccessVariant::Method(Self::Value(value)),
+                    )),
+                    None => value
                         .field_structure(identifier.name)
                         .map(|(value, access)| {
                             (Element::Value(value), FieldAccessVariant::Field(access))
@@ -1263,12 +1283,47 @@ impl Element {
                     found: element.to_string(),
                 }),
             },
+            Self::Constant(constant) => match other {
+                Self::Identifier(identifier) => {
+                    match Self::method(&constant.r#type(), &identifier) {
+                        Some(r#type) => Ok((
+                            Element::Type(r#type),
+                            FieldAccessVariant::Method(Self::Constant(constant)),
+                        )),
+                        None => Err(Error::OperatorFieldFirstOperandExpectedPlaceOrEvaluable {
+                            found: Self::Constant(constant).to_string(),
+                        }),
+                    }
+                }
+                _ => Err(Error::OperatorFieldFirstOperandExpectedPlaceOrEvaluable {
+                    found: Self::Constant(constant).to_string(),
+                }),
+            },
             element => Err(Error::OperatorFieldFirstOperandExpectedPlaceOrEvaluable {
                 found: element.to_string(),
             }),
         }
     }
 
+    ///
+    /// Resolves the method `identifier` in the implementation of a structure or enumeration.
+    ///
+    fn method(r#type: &Type, identifier: &Identifier) -> Option<Type> {
+        let scope = match r#type {
+            Type::Structure(structure) => structure.scope.to_owned(),
+            Type::Enumeration(enumeration) => enumeration.scope.to_owned(),
+            _ => return None,
+        };
+
+        match Scope::resolve_item(scope, identifier) {
+            Ok(ScopeItem {
+                variant: ScopeItemVariant::Type(r#type @ Type::Function(_)),
+                ..
+            }) => Some(r#type),
+            _ => None,
+        }
+    }
+
     pub fn path(self, other: Self) -> Result<Self, Error> {
         let mut path = match self {
             Self::Path(path) => path,
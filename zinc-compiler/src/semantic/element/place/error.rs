@@ -28,6 +28,10 @@ pub enum Error {
         found: String,
     },
 
+    ArrayIndexOutOfRange {
+        index: String,
+        size: usize,
+    },
     ArraySliceStartOutOfRange {
         start: String,
     },
@@ -51,8 +51,10 @@ impl Place {
     }
 
     pub fn index(mut self, index_value: Element) -> Result<(Self, IndexAccess), Error> {
-        let (inner_type, array_size) = match self.r#type {
-            Type::Array { ref r#type, size } => (r#type.deref().to_owned(), r#type.size() * size),
+        let (inner_type, array_length, array_size) = match self.r#type {
+            Type::Array { ref r#type, size } => {
+                (r#type.deref().to_owned(), size, r#type.size() * size)
+            }
             ref r#type => {
                 return Err(Error::OperatorIndexFirstOperandExpectedArray {
                     found: r#type.to_string(),
@@ -69,7 +71,14 @@ impl Place {
 
                 Ok((self, access))
             }
-            Element::Constant(Constant::Integer(_integer)) => {
+            Element::Constant(Constant::Integer(integer)) => {
+                if integer.value.is_negative() || integer.value >= BigInt::from(array_length) {
+                    return Err(Error::ArrayIndexOutOfRange {
+                        index: integer.value.to_string(),
+                        size: array_length,
+                    });
+                }
+
                 self.r#type = inner_type;
 
                 let access = IndexAccess::new(inner_type_size, array_size);
@@ -83,10 +92,10 @@ impl Place {
                     });
                 }
 
-                if end > BigInt::from(array_size) {
+                if end > BigInt::from(array_length) {
                     return Err(Error::ArraySliceEndOutOfRange {
                         end: end.to_string(),
-                        size: array_size,
+                        size: array_length,
                     });
                 }
 
@@ -123,10 +132,10 @@ impl Place {
                     });
                 }
 
-                if end >= BigInt::from(array_size) {
+                if end >= BigInt::from(array_length) {
                     return Err(Error::ArraySliceEndOutOfRange {
                         end: end.to_string(),
-                        size: array_size,
+                        size: array_length,
                     });
                 }
 
@@ -165,6 +165,46 @@ fn main() {
     assert!(crate::semantic::tests::compile_entry(input).is_ok());
 }
 
+#[test]
+fn ok_mutating_nested_element() {
+    let input = r#"
+struct Inner {
+    values: [(u8, bool); 3],
+}
+
+struct Data {
+    inners: [Inner; 2],
+    flag: bool,
+}
+
+fn main(index: u8, value: u8) {
+    let mut result = [Data {
+        inners: [Inner { values: [(0, false); 3] }; 2],
+        flag: false,
+    }; 4];
+
+    result[index].inners[1].values[index].0 = value;
+    result[2].inners[index].values[1..3][1].1 = true;
+    result[index].inners[index].values[2].0 += value;
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_mutating_with_narrower_type_compound() {
+    let input = r#"
+fn main(value: u8) {
+    let mut result: u64 = 42;
+    result += value;
+    result *= value;
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
 #[test]
 fn error_mutating_immutable_memory() {
     let input = r#"
@@ -199,8 +239,30 @@ fn main() {
     let expected = Err(Error::Semantic(SemanticError::Element(
         Location::new(4, 12),
         ElementError::Place(PlaceError::MutatingWithDifferentType {
-            expected: Type::boolean().to_string(),
-            found: Type::integer_unsigned(crate::BITLENGTH_BYTE).to_string(),
+            expected: Type::integer_unsigned(crate::BITLENGTH_BYTE).to_string(),
+            found: Type::boolean().to_string(),
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_mutating_with_wider_type_compound() {
+    let input = r#"
+fn main(value: u64) {
+    let mut result: u8 = 42;
+    result += value;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(4, 12),
+        ElementError::Place(PlaceError::MutatingWithDifferentType {
+            expected: Type::integer_unsigned(crate::BITLENGTH_BYTE).to_string(),
+            found: Type::integer_unsigned(crate::BITLENGTH_BYTE * 8).to_string(),
         }),
     )));
 
@@ -310,6 +372,28 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn error_array_index_out_of_range() {
+    let input = r#"
+fn main() {
+    let mut array = [(1, [2, 3]); 4];
+    array[1].1[2] = 42;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(4, 15),
+        ElementError::Place(PlaceError::ArrayIndexOutOfRange {
+            index: BigInt::from(2).to_string(),
+            size: 2,
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_array_slice_start_out_of_range() {
     let input = r#"
@@ -353,6 +437,28 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn error_array_slice_end_out_of_range_composite() {
+    let input = r#"
+fn main() {
+    let array = [(1, 2); 3];
+    let slice = array[1 .. 4];
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(4, 22),
+        ElementError::Place(PlaceError::ArraySliceEndOutOfRange {
+            end: BigInt::from(4).to_string(),
+            size: 3,
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_array_slice_end_lesser_than_start() {
     let input = r#"
@@ -550,7 +550,7 @@ fn main() {
 fn error_operator_range_1st_operand_expected_constant() {
     let input = r#"
 fn main() {
-    let a = 0;
+    let mut a = 0;
     a .. 42
 }
 "#;
@@ -573,7 +573,7 @@ fn main() {
 fn error_operator_range_2nd_operand_expected_constant() {
     let input = r#"
 fn main() {
-    let b = 42;
+    let mut b = 42;
     0 .. b
 }
 "#;
@@ -596,7 +596,7 @@ fn main() {
 fn error_operator_range_inclusive_1st_operand_expected_constant() {
     let input = r#"
 fn main() {
-    let a = 0;
+    let mut a = 0;
     a ..= 42
 }
 "#;
@@ -619,7 +619,7 @@ fn main() {
 fn error_operator_range_inclusive_2nd_operand_expected_constant() {
     let input = r#"
 fn main() {
-    let b = 42;
+    let mut b = 42;
     0 ..= b
 }
 "#;
@@ -1192,7 +1192,7 @@ fn main() {
 fn error_operator_bitwise_shift_left_2nd_operand_expected_constant() {
     let input = r#"
 fn main() {
-    let offset = 2;
+    let mut offset = 2;
     let value = 42 << offset;
 }
 "#;
@@ -1237,7 +1237,7 @@ fn main() {
 fn error_operator_bitwise_shift_right_2nd_operand_expected_constant() {
     let input = r#"
 fn main() {
-    let offset = 2;
+    let mut offset = 2;
     let value = 42 >> offset;
 }
 "#;
@@ -1476,6 +1476,51 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn error_operator_exponentiation_1st_operand_expected_evaluable() {
+    let input = r#"
+type X = u8;
+
+fn main() {
+    let value = X ** 2;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(5, 19),
+        ElementError::OperatorExponentiationFirstOperandExpectedEvaluable {
+            found: Element::Type(Type::integer_unsigned(crate::BITLENGTH_BYTE)).to_string(),
+        },
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_operator_exponentiation_2nd_operand_expected_constant() {
+    let input = r#"
+fn main() {
+    let mut exponent = 2;
+    let value = 42 ** exponent;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(4, 20),
+        ElementError::OperatorExponentiationSecondOperandExpectedConstant {
+            found: Value::try_from(&Type::integer_unsigned(crate::BITLENGTH_BYTE))
+                .expect(crate::semantic::tests::PANIC_TEST_DATA)
+                .to_string(),
+        },
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_operator_casting_1st_operand_expected_evaluable() {
     let input = r#"
@@ -13,6 +13,7 @@ use crate::semantic::element::constant::error::Error as ConstantError;
 use crate::semantic::element::constant::integer::Integer as IntegerConstant;
 use crate::semantic::element::constant::Constant;
 use crate::semantic::element::error::Error as ElementError;
+use crate::semantic::element::r#type::function::Function;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::error::Error;
 use crate::semantic::scope::Scope;
@@ -23,8 +24,8 @@ use crate::syntax::tree::variant::Variant;
 /// Describes an enumeration type.
 ///
 /// Consists of the local enumeration `identifier` within its scope, global `unique_id`,
-/// and the implementation `scope`, which contains the enumeration variants and
-/// reference to its parent scope.
+/// and the implementation `scope`, which contains the enumeration variants, the `from_value`,
+/// `try_from`, and `variant_count` functions, and reference to its parent scope.
 ///
 #[derive(Debug, Clone)]
 pub struct Enumeration {
@@ -43,6 +44,7 @@ impl Enumeration {
         scope_parent: Option<Rc<RefCell<Scope>>>,
     ) -> Result<Self, Error> {
         let scope = Rc::new(RefCell::new(Scope::new(scope_parent)));
+        let location = identifier.location;
 
         let mut variants_bigint = Vec::with_capacity(variants.len());
         for variant in variants.into_iter() {
@@ -87,11 +89,24 @@ impl Enumeration {
                 .map_err(|error| Error::Scope(error))?;
         }
 
+        enumeration.values.sort();
+
         scope
             .borrow_mut()
             .declare_self(Type::Enumeration(enumeration.clone()));
 
-        enumeration.values.sort();
+        for function in vec![
+            Function::new_enumeration_from_value(enumeration.clone()),
+            Function::new_enumeration_try_from(enumeration.clone()),
+            Function::new_enumeration_variant_count(enumeration.clone()),
+        ]
+        .into_iter()
+        {
+            scope.borrow_mut().declare_associated_type(
+                Identifier::new(location, function.identifier().to_owned()),
+                Type::Function(function),
+            );
+        }
 
         Ok(enumeration)
     }
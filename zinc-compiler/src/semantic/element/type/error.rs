@@ -7,8 +7,16 @@ use crate::semantic::element::r#type::structure::error::Error as StructureTypeEr
 
 #[derive(Debug, PartialEq)]
 pub enum Error {
-    AliasDoesNotPointToType { found: String },
-    AliasDoesNotPointToStructure { found: String },
+    AliasDoesNotPointToType {
+        found: String,
+    },
+    AliasDoesNotPointToStructure {
+        found: String,
+    },
+    DecimalBitlengthInvalid {
+        integer_bits: usize,
+        fraction_bits: usize,
+    },
 
     Function(FunctionTypeError),
     Structure(StructureTypeError),
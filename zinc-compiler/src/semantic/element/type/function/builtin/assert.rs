@@ -4,11 +4,20 @@
 
 use std::fmt;
 
+use num_traits::ToPrimitive;
+
 use crate::semantic::element::constant::Constant;
+use crate::semantic::element::r#type::function::builtin::error::Error as BuiltInFunctionTypeError;
 use crate::semantic::element::r#type::function::error::Error;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::element::Element;
 
+///
+/// The checked call: the result type, the message format string, the format argument types,
+/// and the error code.
+///
+type Checked = (Type, Option<String>, Vec<Type>, Option<u64>);
+
 #[derive(Debug, Default, Clone)]
 pub struct Function {
     identifier: &'static str,
@@ -17,8 +26,8 @@ pub struct Function {
 impl Function {
     pub const ARGUMENT_INDEX_CONDITION: usize = 0;
     pub const ARGUMENT_INDEX_MESSAGE: usize = 1;
+    pub const ARGUMENT_INDEX_VALUES: usize = 2;
     pub const ARGUMENT_COUNT_MANDATORY: usize = 1;
-    pub const ARGUMENT_COUNT_OPTIONAL: usize = 2;
 
     pub fn new() -> Self {
         Self {
@@ -30,13 +39,26 @@ impl Function {
         self.identifier
     }
 
-    pub fn call(self, actual_elements: Vec<Element>) -> Result<(Type, Option<String>), Error> {
+    ///
+    /// Checks the arguments and returns the result type, the message format string,
+    /// the format argument types, and the error code.
+    ///
+    /// The error code is an optional integer constant after the format arguments.
+    ///
+    pub fn call(self, actual_elements: Vec<Element>) -> Result<Checked, Error> {
         let mut actual_params = Vec::with_capacity(actual_elements.len());
         for (index, element) in actual_elements.into_iter().enumerate() {
-            let (r#type, is_constant, string) = match element {
-                Element::Value(value) => (value.r#type(), false, None),
-                Element::Constant(Constant::String(string)) => (Type::string(), true, Some(string)),
-                Element::Constant(constant) => (constant.r#type(), true, None),
+            let (r#type, is_constant, string, code) = match element {
+                Element::Value(value) => (value.r#type(), false, None, None),
+                Element::Constant(Constant::String(string)) => {
+                    (Type::string(), true, Some(string), None)
+                }
+                Element::Constant(Constant::Integer(integer)) => {
+                    let code = integer.value.to_u64();
+                    let r#type = Constant::Integer(integer).r#type();
+                    (r#type, true, None, code)
+                }
+                Element::Constant(constant) => (constant.r#type(), true, None, None),
                 element => {
                     return Err(Error::argument_not_evaluable(
                         self.identifier.to_owned(),
@@ -45,12 +67,12 @@ impl Function {
                     ))
                 }
             };
-            actual_params.push((r#type, is_constant, string));
+            actual_params.push((r#type, is_constant, string, code));
         }
 
         match actual_params.get(Self::ARGUMENT_INDEX_CONDITION) {
-            Some((Type::Boolean, _is_constant, _string)) => {}
-            Some((r#type, _is_constant, _string)) => {
+            Some((Type::Boolean, _is_constant, _string, _code)) => {}
+            Some((r#type, _is_constant, _string, _code)) => {
                 return Err(Error::argument_type(
                     self.identifier.to_owned(),
                     "condition".to_owned(),
@@ -69,8 +91,8 @@ impl Function {
         }
 
         let string = match actual_params.get(Self::ARGUMENT_INDEX_MESSAGE) {
-            Some((Type::String, true, string)) => string.to_owned(),
-            Some((r#type, true, _string)) => {
+            Some((Type::String, true, string, _code)) => string.to_owned(),
+            Some((r#type, true, _string, _code)) => {
                 return Err(Error::argument_type(
                     self.identifier.to_owned(),
                     "message".to_owned(),
@@ -79,7 +101,7 @@ impl Function {
                     r#type.to_string(),
                 ))
             }
-            Some((r#type, false, _string)) => {
+            Some((r#type, false, _string, _code)) => {
                 return Err(Error::argument_constantness(
                     self.identifier.to_owned(),
                     "message".to_owned(),
@@ -90,20 +112,63 @@ impl Function {
             None => None,
         };
 
-        if actual_params.len() > Self::ARGUMENT_COUNT_OPTIONAL {
-            return Err(Error::argument_count(
-                self.identifier.to_owned(),
-                Self::ARGUMENT_COUNT_OPTIONAL,
-                actual_params.len(),
+        let arguments_expected_count = string
+            .as_ref()
+            .map(|string| string.matches("{}").count())
+            .unwrap_or_default();
+        let arguments_count = actual_params
+            .len()
+            .saturating_sub(Self::ARGUMENT_INDEX_VALUES);
+        let code = if arguments_count == arguments_expected_count {
+            None
+        } else if arguments_count == arguments_expected_count + 1 {
+            let position = actual_params.len();
+            match actual_params.pop() {
+                Some((Type::IntegerUnsigned { .. }, true, _string, Some(code))) => Some(code),
+                Some((r#type, true, _string, _code)) => {
+                    return Err(Error::argument_type(
+                        self.identifier.to_owned(),
+                        "code".to_owned(),
+                        position,
+                        Type::integer_unsigned(crate::BITLENGTH_INDEX).to_string(),
+                        r#type.to_string(),
+                    ))
+                }
+                Some((r#type, false, _string, _code)) => {
+                    return Err(Error::argument_constantness(
+                        self.identifier.to_owned(),
+                        "code".to_owned(),
+                        position,
+                        r#type.to_string(),
+                    ))
+                }
+                None => None,
+            }
+        } else {
+            return Err(Error::BuiltIn(
+                BuiltInFunctionTypeError::assert_argument_count(
+                    Self::ARGUMENT_INDEX_VALUES + arguments_expected_count,
+                    actual_params.len(),
+                ),
             ));
-        }
+        };
+
+        let argument_types: Vec<Type> = actual_params
+            .into_iter()
+            .skip(Self::ARGUMENT_INDEX_VALUES)
+            .map(|(r#type, _is_constant, _string, _code)| r#type)
+            .collect();
 
-        Ok((Type::unit(), string))
+        Ok((Type::unit(), string, argument_types, code))
     }
 }
 
 impl fmt::Display for Function {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}!(condition: bool, [message: str])", self.identifier)
+        write!(
+            f,
+            "{}!(condition: bool, [message: str, ...], [code: u64])",
+            self.identifier
+        )
     }
 }
@@ -7,6 +7,10 @@ pub enum Error {
     Unknown { function: String },
     SpecifierMissing { function: &'static str },
     DebugArgumentCount { expected: usize, found: usize },
+    AssertArgumentCount { expected: usize, found: usize },
+    ExposeOutsideMain { function: String },
+    ExposeConditional { name: String },
+    ExposeDuplicate { name: String },
 }
 
 impl Error {
@@ -21,4 +25,20 @@ impl Error {
     pub fn debug_argument_count(expected: usize, found: usize) -> Self {
         Self::DebugArgumentCount { expected, found }
     }
+
+    pub fn assert_argument_count(expected: usize, found: usize) -> Self {
+        Self::AssertArgumentCount { expected, found }
+    }
+
+    pub fn expose_outside_main(function: String) -> Self {
+        Self::ExposeOutsideMain { function }
+    }
+
+    pub fn expose_conditional(name: String) -> Self {
+        Self::ExposeConditional { name }
+    }
+
+    pub fn expose_duplicate(name: String) -> Self {
+        Self::ExposeDuplicate { name }
+    }
 }
@@ -0,0 +1,98 @@
+//!
+//! The semantic analyzer `expose!` built-in function element.
+//!
+
+use std::fmt;
+
+use crate::semantic::element::constant::Constant;
+use crate::semantic::element::r#type::function::error::Error;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+
+#[derive(Debug, Default, Clone)]
+pub struct Function {
+    identifier: &'static str,
+}
+
+impl Function {
+    pub const ARGUMENT_INDEX_NAME: usize = 0;
+    pub const ARGUMENT_INDEX_VALUE: usize = 1;
+    pub const ARGUMENT_COUNT: usize = 2;
+
+    pub fn new() -> Self {
+        Self {
+            identifier: "expose",
+        }
+    }
+
+    pub fn identifier(&self) -> &'static str {
+        self.identifier
+    }
+
+    pub fn call(self, actual_elements: Vec<Element>) -> Result<(Type, String, Type), Error> {
+        let mut actual_params = Vec::with_capacity(actual_elements.len());
+        for (index, element) in actual_elements.into_iter().enumerate() {
+            let (r#type, is_constant, string) = match element {
+                Element::Value(value) => (value.r#type(), false, None),
+                Element::Constant(Constant::String(string)) => (Type::string(), true, Some(string)),
+                Element::Constant(constant) => (constant.r#type(), true, None),
+                element => {
+                    return Err(Error::argument_not_evaluable(
+                        self.identifier.to_owned(),
+                        index + 1,
+                        element.to_string(),
+                    ))
+                }
+            };
+            actual_params.push((r#type, is_constant, string));
+        }
+
+        if actual_params.len() != Self::ARGUMENT_COUNT {
+            return Err(Error::argument_count(
+                self.identifier.to_owned(),
+                Self::ARGUMENT_COUNT,
+                actual_params.len(),
+            ));
+        }
+
+        let name = match actual_params.remove(Self::ARGUMENT_INDEX_NAME) {
+            (Type::String, true, Some(string)) => string,
+            (r#type, true, _string) => {
+                return Err(Error::argument_type(
+                    self.identifier.to_owned(),
+                    "name".to_owned(),
+                    Self::ARGUMENT_INDEX_NAME + 1,
+                    Type::string().to_string(),
+                    r#type.to_string(),
+                ))
+            }
+            (r#type, false, _string) => {
+                return Err(Error::argument_constantness(
+                    self.identifier.to_owned(),
+                    "name".to_owned(),
+                    Self::ARGUMENT_INDEX_NAME + 1,
+                    r#type.to_string(),
+                ))
+            }
+        };
+
+        let (value_type, _is_constant, _string) = actual_params.remove(0);
+        if let Type::String = value_type {
+            return Err(Error::argument_type(
+                self.identifier.to_owned(),
+                "value".to_owned(),
+                Self::ARGUMENT_INDEX_VALUE + 1,
+                "{value}".to_owned(),
+                value_type.to_string(),
+            ));
+        }
+
+        Ok((Type::unit(), name, value_type))
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}!(name: str, value: T)", self.identifier)
+    }
+}
@@ -7,16 +7,19 @@ mod tests;
 pub mod assert;
 pub mod debug;
 pub mod error;
+pub mod expose;
 
 use std::fmt;
 
 use self::assert::Function as AssertFunction;
 use self::debug::Function as DebugFunction;
+use self::expose::Function as ExposeFunction;
 
 #[derive(Debug, Clone)]
 pub enum Function {
     Assert(AssertFunction),
     Debug(DebugFunction),
+    Expose(ExposeFunction),
 }
 
 impl Function {
@@ -28,10 +31,15 @@ impl Function {
         Self::Debug(DebugFunction::new())
     }
 
+    pub fn new_expose() -> Self {
+        Self::Expose(ExposeFunction::new())
+    }
+
     pub fn identifier(&self) -> &'static str {
         match self {
             Self::Assert(inner) => inner.identifier(),
             Self::Debug(inner) => inner.identifier(),
+            Self::Expose(inner) => inner.identifier(),
         }
     }
 }
@@ -41,6 +49,7 @@ impl fmt::Display for Function {
         match self {
             Self::Assert(inner) => write!(f, "{}", inner),
             Self::Debug(inner) => write!(f, "{}", inner),
+            Self::Expose(inner) => write!(f, "{}", inner),
         }
     }
 }
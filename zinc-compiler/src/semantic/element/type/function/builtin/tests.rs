@@ -10,6 +10,7 @@ use crate::semantic::element::r#type::error::Error as TypeError;
 use crate::semantic::element::r#type::function::builtin::assert::Function as BuiltInAssertFunction;
 use crate::semantic::element::r#type::function::builtin::debug::Function as BuiltInDebugFunction;
 use crate::semantic::element::r#type::function::builtin::error::Error as BuiltInFunctionTypeError;
+use crate::semantic::element::r#type::function::builtin::expose::Function as BuiltInExposeFunction;
 use crate::semantic::element::r#type::function::error::Error as FunctionTypeError;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::element::Error as ElementError;
@@ -147,16 +148,17 @@ fn main() {
 fn error_assert_argument_count_greater() {
     let input = r#"
 fn main() {
-    assert!(true, "default", 42);
+    assert!(true, "default", 42, 42);
 }
 "#;
 
     let expected = Err(Error::Semantic(SemanticError::Element(
         Location::new(3, 12),
-        ElementError::Type(TypeError::Function(FunctionTypeError::argument_count(
-            "assert".to_owned(),
-            BuiltInAssertFunction::ARGUMENT_COUNT_OPTIONAL,
-            BuiltInAssertFunction::ARGUMENT_COUNT_OPTIONAL + 1,
+        ElementError::Type(TypeError::Function(FunctionTypeError::BuiltIn(
+            BuiltInFunctionTypeError::assert_argument_count(
+                BuiltInAssertFunction::ARGUMENT_INDEX_VALUES,
+                BuiltInAssertFunction::ARGUMENT_INDEX_VALUES + 2,
+            ),
         ))),
     )));
 
@@ -212,3 +214,187 @@ fn main() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn ok_assert_formatted_with_code() {
+    let input = r#"
+fn main(a: u8, b: u8) {
+    assert!(a < b, "{} is not less than {}", a, b, 42);
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_assert_argument_code_expected_unsigned_integer() {
+    let input = r#"
+fn main() {
+    assert!(true, "default", false);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 12),
+        ElementError::Type(TypeError::Function(FunctionTypeError::argument_type(
+            "assert".to_owned(),
+            "code".to_owned(),
+            BuiltInAssertFunction::ARGUMENT_INDEX_VALUES + 1,
+            Type::integer_unsigned(crate::BITLENGTH_INDEX).to_string(),
+            Type::boolean().to_string(),
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_expose() {
+    let input = r#"
+fn main(a: u8, b: u8) -> u8 {
+    let sum = a + b;
+    expose!("sum", sum);
+    expose!("pair", (a, b));
+    sum * 2
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_expose_argument_count() {
+    let input = r#"
+fn main() {
+    expose!("value");
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 12),
+        ElementError::Type(TypeError::Function(FunctionTypeError::argument_count(
+            "expose".to_owned(),
+            BuiltInExposeFunction::ARGUMENT_COUNT,
+            1,
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_expose_argument_1_name_expected_string() {
+    let input = r#"
+fn main() {
+    expose!(42, 42);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 12),
+        ElementError::Type(TypeError::Function(FunctionTypeError::argument_type(
+            "expose".to_owned(),
+            "name".to_owned(),
+            BuiltInExposeFunction::ARGUMENT_INDEX_NAME + 1,
+            Type::string().to_string(),
+            Type::integer_unsigned(crate::BITLENGTH_BYTE).to_string(),
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_expose_outside_main() {
+    let input = r#"
+fn helper(value: u8) {
+    expose!("value", value);
+}
+
+fn main() {
+    helper(42);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 12),
+        ElementError::Type(TypeError::Function(FunctionTypeError::BuiltIn(
+            BuiltInFunctionTypeError::expose_outside_main("helper".to_owned()),
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_expose_conditional() {
+    let input = r#"
+fn main(flag: bool) {
+    if flag {
+        expose!("value", 42);
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(4, 16),
+        ElementError::Type(TypeError::Function(FunctionTypeError::BuiltIn(
+            BuiltInFunctionTypeError::expose_conditional("value".to_owned()),
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_expose_loop() {
+    let input = r#"
+fn main() {
+    for i in 0..2 {
+        expose!("index", i);
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(4, 16),
+        ElementError::Type(TypeError::Function(FunctionTypeError::BuiltIn(
+            BuiltInFunctionTypeError::expose_conditional("index".to_owned()),
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_expose_duplicate() {
+    let input = r#"
+fn main(a: u8) {
+    expose!("value", a);
+    expose!("value", a + 1);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(4, 12),
+        ElementError::Type(TypeError::Function(FunctionTypeError::BuiltIn(
+            BuiltInFunctionTypeError::expose_duplicate("value".to_owned()),
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
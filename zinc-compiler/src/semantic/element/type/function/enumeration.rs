@@ -0,0 +1,187 @@
+//!
+//! The semantic analyzer enumeration associated function element.
+//!
+
+use std::fmt;
+
+use num_bigint::BigInt;
+
+use crate::semantic::element::constant::integer::Integer as IntegerConstant;
+use crate::semantic::element::constant::Constant;
+use crate::semantic::element::r#type::enumeration::Enumeration;
+use crate::semantic::element::r#type::function::error::Error;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+
+///
+/// The functions declared in the scope of every enumeration, e.g. `Color::from_value(x)`.
+///
+#[derive(Debug, Clone)]
+pub enum Function {
+    /// Converts an integer to the enumeration, failing if the integer is not a variant value
+    FromValue(Enumeration),
+    /// Converts an integer to the enumeration, returning whether the integer is a variant value
+    TryFrom(Enumeration),
+    /// Returns the number of the enumeration variants
+    VariantCount(Enumeration),
+}
+
+impl Function {
+    pub const IDENTIFIER_FROM_VALUE: &'static str = "from_value";
+    pub const IDENTIFIER_TRY_FROM: &'static str = "try_from";
+    pub const IDENTIFIER_VARIANT_COUNT: &'static str = "variant_count";
+
+    pub const ARGUMENT_INDEX_VALUE: usize = 0;
+
+    pub fn new_from_value(enumeration: Enumeration) -> Self {
+        Self::FromValue(enumeration)
+    }
+
+    pub fn new_try_from(enumeration: Enumeration) -> Self {
+        Self::TryFrom(enumeration)
+    }
+
+    pub fn new_variant_count(enumeration: Enumeration) -> Self {
+        Self::VariantCount(enumeration)
+    }
+
+    pub fn identifier(&self) -> &'static str {
+        match self {
+            Self::FromValue(_) => Self::IDENTIFIER_FROM_VALUE,
+            Self::TryFrom(_) => Self::IDENTIFIER_TRY_FROM,
+            Self::VariantCount(_) => Self::IDENTIFIER_VARIANT_COUNT,
+        }
+    }
+
+    pub fn enumeration(&self) -> &Enumeration {
+        match self {
+            Self::FromValue(enumeration) => enumeration,
+            Self::TryFrom(enumeration) => enumeration,
+            Self::VariantCount(enumeration) => enumeration,
+        }
+    }
+
+    ///
+    /// The bitlength of the `variant_count` result, which is the smallest unsigned integer
+    /// able to hold the number of variants.
+    ///
+    pub fn variant_count_bitlength(enumeration: &Enumeration) -> usize {
+        IntegerConstant::minimal_bitlength(&BigInt::from(enumeration.values.len()), false)
+            .unwrap_or(crate::BITLENGTH_FIELD)
+    }
+
+    pub fn call(self, actual_elements: Vec<Element>) -> Result<Type, Error> {
+        let identifier = format!("{}::{}", self.enumeration().identifier, self.identifier());
+
+        let mut actual_params = Vec::with_capacity(actual_elements.len());
+        for (index, element) in actual_elements.into_iter().enumerate() {
+            let r#type = match element {
+                Element::Value(ref value) => value.r#type(),
+                Element::Constant(ref constant) => constant.r#type(),
+                element => {
+                    return Err(Error::argument_not_evaluable(
+                        identifier,
+                        index + 1,
+                        element.to_string(),
+                    ))
+                }
+            };
+            actual_params.push((r#type, element));
+        }
+
+        match self {
+            Self::FromValue(enumeration) => {
+                if let Some(value) = Self::check_value(identifier, actual_params.as_slice())? {
+                    if !enumeration.values.contains(value) {
+                        return Err(Error::enumeration_value_invalid(
+                            enumeration.identifier.to_owned(),
+                            value.to_string(),
+                        ));
+                    }
+                }
+
+                Ok(Type::Enumeration(enumeration))
+            }
+            Self::TryFrom(enumeration) => {
+                Self::check_value(identifier, actual_params.as_slice())?;
+
+                Ok(Type::tuple(vec![
+                    Type::Enumeration(enumeration),
+                    Type::boolean(),
+                ]))
+            }
+            Self::VariantCount(enumeration) => {
+                if !actual_params.is_empty() {
+                    return Err(Error::argument_count(identifier, 0, actual_params.len()));
+                }
+
+                Ok(Type::integer_unsigned(Self::variant_count_bitlength(
+                    &enumeration,
+                )))
+            }
+        }
+    }
+
+    ///
+    /// Checks the only integer argument of `from_value` and `try_from`, returning its value
+    /// if it is a constant.
+    ///
+    fn check_value(
+        identifier: String,
+        actual_params: &[(Type, Element)],
+    ) -> Result<Option<&BigInt>, Error> {
+        let value = match actual_params.get(Self::ARGUMENT_INDEX_VALUE) {
+            Some((Type::IntegerUnsigned { .. }, element))
+            | Some((Type::IntegerSigned { .. }, element))
+            | Some((Type::Field, element)) => match element {
+                Element::Constant(Constant::Integer(integer)) => Some(&integer.value),
+                _ => None,
+            },
+            Some((r#type, _element)) => {
+                return Err(Error::argument_type(
+                    identifier,
+                    "value".to_owned(),
+                    Self::ARGUMENT_INDEX_VALUE + 1,
+                    "{integer}".to_owned(),
+                    r#type.to_string(),
+                ))
+            }
+            None => return Err(Error::argument_count(identifier, 1, actual_params.len())),
+        };
+
+        if actual_params.len() > 1 {
+            return Err(Error::argument_count(identifier, 1, actual_params.len()));
+        }
+
+        Ok(value)
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let enumeration = self.enumeration();
+        match self {
+            Self::FromValue(_) => write!(
+                f,
+                "fn {}::{}(value: {{integer}}) -> {}",
+                enumeration.identifier,
+                self.identifier(),
+                enumeration.identifier,
+            ),
+            Self::TryFrom(_) => write!(
+                f,
+                "fn {}::{}(value: {{integer}}) -> ({}, bool)",
+                enumeration.identifier,
+                self.identifier(),
+                enumeration.identifier,
+            ),
+            Self::VariantCount(_) => write!(
+                f,
+                "fn {}::{}() -> {}",
+                enumeration.identifier,
+                self.identifier(),
+                Type::integer_unsigned(Self::variant_count_bitlength(enumeration)),
+            ),
+        }
+    }
+}
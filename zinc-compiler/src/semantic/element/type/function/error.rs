@@ -45,6 +45,22 @@ pub enum Error {
         position: usize,
         reference: Location,
     },
+    EnumerationValueInvalid {
+        enumeration: String,
+        value: String,
+    },
+    ConstParameterNotInferred {
+        function: String,
+        name: String,
+    },
+    ConstParameterExpectedInteger {
+        function: String,
+        name: String,
+        found: String,
+    },
+    ConstParameterRecursiveInstance {
+        function: String,
+    },
 
     BuiltIn(BuiltInFunctionTypeError),
     StandardLibrary(StandardLibraryFunctionTypeError),
@@ -126,4 +142,24 @@ impl Error {
             reference,
         }
     }
+
+    pub fn enumeration_value_invalid(enumeration: String, value: String) -> Self {
+        Self::EnumerationValueInvalid { enumeration, value }
+    }
+
+    pub fn const_parameter_not_inferred(function: String, name: String) -> Self {
+        Self::ConstParameterNotInferred { function, name }
+    }
+
+    pub fn const_parameter_expected_integer(function: String, name: String, found: String) -> Self {
+        Self::ConstParameterExpectedInteger {
+            function,
+            name,
+            found,
+        }
+    }
+
+    pub fn const_parameter_recursive_instance(function: String) -> Self {
+        Self::ConstParameterRecursiveInstance { function }
+    }
 }
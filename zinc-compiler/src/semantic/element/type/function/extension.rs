@@ -0,0 +1,94 @@
+//!
+//! The semantic analyzer extension function element.
+//!
+
+use std::fmt;
+
+use crate::semantic::element::r#type::function::error::Error;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+
+///
+/// The native gadget function declared in the `std::ext` module by a downstream crate.
+///
+#[derive(Debug, Clone)]
+pub struct Function {
+    identifier: String,
+    formal_params: Vec<(String, Type)>,
+    return_type: Box<Type>,
+}
+
+impl Function {
+    pub fn new(identifier: String, arguments: Vec<(String, Type)>, return_type: Type) -> Self {
+        Self {
+            identifier,
+            formal_params: arguments,
+            return_type: Box::new(return_type),
+        }
+    }
+
+    pub fn identifier(&self) -> &str {
+        self.identifier.as_str()
+    }
+
+    pub fn call(self, actual_elements: Vec<Element>) -> Result<Type, Error> {
+        let mut actual_params = Vec::with_capacity(actual_elements.len());
+        for (index, element) in actual_elements.into_iter().enumerate() {
+            let r#type = match element {
+                Element::Value(value) => value.r#type(),
+                Element::Constant(constant) => constant.r#type(),
+                element => {
+                    return Err(Error::argument_not_evaluable(
+                        self.identifier.to_owned(),
+                        index + 1,
+                        element.to_string(),
+                    ))
+                }
+            };
+            actual_params.push(r#type);
+        }
+
+        if actual_params.len() != self.formal_params.len() {
+            return Err(Error::argument_count(
+                self.identifier.to_owned(),
+                self.formal_params.len(),
+                actual_params.len(),
+            ));
+        }
+
+        for (index, ((name, r#type), actual_type)) in self
+            .formal_params
+            .into_iter()
+            .zip(actual_params)
+            .enumerate()
+        {
+            if actual_type != r#type {
+                return Err(Error::argument_type(
+                    self.identifier.to_owned(),
+                    name,
+                    index + 1,
+                    r#type.to_string(),
+                    actual_type.to_string(),
+                ));
+            }
+        }
+
+        Ok(*self.return_type)
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "fn std::ext::{}({}) -> {}",
+            self.identifier,
+            self.formal_params
+                .iter()
+                .map(|(name, r#type)| format!("{}: {}", name, r#type))
+                .collect::<Vec<String>>()
+                .join(", "),
+            self.return_type,
+        )
+    }
+}
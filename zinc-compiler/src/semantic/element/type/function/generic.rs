@@ -0,0 +1,211 @@
+//!
+//! The semantic analyzer generic function element.
+//!
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::rc::Rc;
+
+use num_bigint::BigInt;
+
+use crate::semantic::element::r#type::function::error::Error;
+use crate::semantic::element::r#type::function::user::Function as UserFunction;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+use crate::semantic::scope::Scope;
+use crate::syntax::tree::expression::tree::node::operand::Operand as ExpressionOperand;
+use crate::syntax::tree::expression::tree::node::Node as ExpressionTreeNode;
+use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
+use crate::syntax::tree::identifier::Identifier;
+use crate::syntax::tree::r#type::variant::Variant as TypeVariant;
+use crate::syntax::tree::statement::r#fn::Statement as FnStatement;
+
+///
+/// The function with const parameters, e.g. `fn sum<const N: u64>(values: [u8; N])`.
+///
+/// The const parameters are inferred from the array sizes of the arguments, and the function
+/// is analyzed as an ordinar user-defined function once for every set of their values.
+///
+#[derive(Debug, Clone)]
+pub struct Function {
+    identifier: String,
+    statement: FnStatement,
+    scope: Rc<RefCell<Scope>>,
+    path: Vec<String>,
+    const_parameters: Vec<(Identifier, Type)>,
+    /// The analyzed instances, where `None` stands for the instance being analyzed.
+    instances: Rc<RefCell<HashMap<Vec<BigInt>, Option<UserFunction>>>>,
+}
+
+impl Function {
+    pub fn new(
+        statement: FnStatement,
+        scope: Rc<RefCell<Scope>>,
+        path: Vec<String>,
+        const_parameters: Vec<(Identifier, Type)>,
+    ) -> Self {
+        Self {
+            identifier: statement.identifier.name.to_owned(),
+            statement,
+            scope,
+            path,
+            const_parameters,
+            instances: Rc::new(RefCell::new(HashMap::new())),
+        }
+    }
+
+    pub fn identifier(&self) -> &str {
+        self.identifier.as_str()
+    }
+
+    pub fn statement(&self) -> &FnStatement {
+        &self.statement
+    }
+
+    ///
+    /// The scope the function is declared in, where its instances are analyzed.
+    ///
+    pub fn scope(&self) -> Rc<RefCell<Scope>> {
+        self.scope.clone()
+    }
+
+    pub fn path(&self) -> &[String] {
+        self.path.as_slice()
+    }
+
+    pub fn const_parameters(&self) -> &[(Identifier, Type)] {
+        self.const_parameters.as_slice()
+    }
+
+    ///
+    /// Returns the instance with the const parameter `values`, which is `Some(None)` if the
+    /// instance is still being analyzed.
+    ///
+    pub fn instance(&self, values: &[BigInt]) -> Option<Option<UserFunction>> {
+        self.instances.borrow().get(values).cloned()
+    }
+
+    ///
+    /// Marks the instance with the const parameter `values` as being analyzed.
+    ///
+    pub fn begin_instance(&self, values: Vec<BigInt>) {
+        self.instances.borrow_mut().insert(values, None);
+    }
+
+    pub fn finish_instance(&self, values: Vec<BigInt>, instance: UserFunction) {
+        self.instances.borrow_mut().insert(values, Some(instance));
+    }
+
+    ///
+    /// Infers the const parameter values from the array sizes of the actual arguments.
+    ///
+    /// The argument types themselves are checked when the instance is called.
+    ///
+    pub fn infer(&self, actual_elements: &[Element]) -> Result<Vec<BigInt>, Error> {
+        let formal_params_length = self.statement.argument_bindings.len();
+        if actual_elements.len() != formal_params_length {
+            return Err(Error::argument_count(
+                self.identifier.to_owned(),
+                formal_params_length,
+                actual_elements.len(),
+            ));
+        }
+
+        let mut values = HashMap::with_capacity(self.const_parameters.len());
+        for (index, (binding, element)) in self
+            .statement
+            .argument_bindings
+            .iter()
+            .zip(actual_elements.iter())
+            .enumerate()
+        {
+            let r#type = match element {
+                Element::Value(value) => value.r#type(),
+                Element::Constant(constant) => constant.r#type(),
+                element => {
+                    return Err(Error::argument_not_evaluable(
+                        self.identifier.to_owned(),
+                        index + 1,
+                        element.to_string(),
+                    ))
+                }
+            };
+
+            self.bind(&binding.r#type.variant, &r#type, &mut values);
+        }
+
+        self.const_parameters
+            .iter()
+            .map(|(identifier, _type)| {
+                values.remove(identifier.name.as_str()).ok_or_else(|| {
+                    Error::const_parameter_not_inferred(
+                        self.identifier.to_owned(),
+                        identifier.name.to_owned(),
+                    )
+                })
+            })
+            .collect()
+    }
+
+    ///
+    /// Binds the const parameters used as the array sizes of the `formal` type to the sizes
+    /// of the `actual` one.
+    ///
+    fn bind(&self, formal: &TypeVariant, actual: &Type, values: &mut HashMap<String, BigInt>) {
+        match (formal, actual) {
+            (
+                TypeVariant::Array { inner, size },
+                Type::Array {
+                    r#type,
+                    size: actual_size,
+                },
+            ) => {
+                if let Some(name) = self.const_parameter_name(size) {
+                    values
+                        .entry(name.to_owned())
+                        .or_insert_with(|| BigInt::from(*actual_size));
+                }
+                self.bind(inner, r#type, values);
+            }
+            (TypeVariant::Tuple { inners }, Type::Tuple { types }) => {
+                for (formal, actual) in inners.iter().zip(types.iter()) {
+                    self.bind(formal, actual, values);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    ///
+    /// Returns the const parameter name if the array size `expression` consists only of it.
+    ///
+    fn const_parameter_name<'a>(&self, expression: &'a ExpressionTree) -> Option<&'a str> {
+        match *expression.value {
+            ExpressionTreeNode::Operand(ExpressionOperand::Identifier(ref identifier))
+                if self
+                    .const_parameters
+                    .iter()
+                    .any(|(parameter, _type)| parameter.name == identifier.name) =>
+            {
+                Some(identifier.name.as_str())
+            }
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "fn {}<{}>",
+            self.identifier,
+            self.const_parameters
+                .iter()
+                .map(|(identifier, r#type)| format!("const {}: {}", identifier.name, r#type))
+                .collect::<Vec<String>>()
+                .join(", "),
+        )
+    }
+}
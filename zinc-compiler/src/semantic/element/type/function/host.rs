@@ -0,0 +1,115 @@
+//!
+//! The semantic analyzer host function element.
+//!
+
+use std::fmt;
+
+use crate::semantic::element::r#type::function::error::Error;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+
+pub static HOST_ABI: &str = "host";
+
+///
+/// The function declared with `extern "host" fn`, whose result is supplied by the host
+/// callback registered in the VM.
+///
+#[derive(Debug, Clone)]
+pub struct Function {
+    identifier: String,
+    formal_params: Vec<(String, Type)>,
+    return_type: Box<Type>,
+}
+
+impl Function {
+    pub fn new(identifier: String, arguments: Vec<(String, Type)>, return_type: Type) -> Self {
+        Self {
+            identifier,
+            formal_params: arguments,
+            return_type: Box::new(return_type),
+        }
+    }
+
+    pub fn identifier(&self) -> &str {
+        self.identifier.as_str()
+    }
+
+    ///
+    /// Checks whether the type can be passed to or returned from the host, that is,
+    /// consists of scalars.
+    ///
+    pub fn is_passable(r#type: &Type) -> bool {
+        match r#type {
+            Type::Unit => true,
+            r#type if r#type.is_scalar() => true,
+            Type::Array { r#type, .. } => Self::is_passable(r#type),
+            Type::Tuple { types } => types.iter().all(Self::is_passable),
+            Type::Structure(structure) => structure
+                .fields
+                .iter()
+                .all(|(_name, r#type)| Self::is_passable(r#type)),
+            _ => false,
+        }
+    }
+
+    pub fn call(self, actual_elements: Vec<Element>) -> Result<Type, Error> {
+        let mut actual_params = Vec::with_capacity(actual_elements.len());
+        for (index, element) in actual_elements.into_iter().enumerate() {
+            let r#type = match element {
+                Element::Value(value) => value.r#type(),
+                Element::Constant(constant) => constant.r#type(),
+                element => {
+                    return Err(Error::argument_not_evaluable(
+                        self.identifier.to_owned(),
+                        index + 1,
+                        element.to_string(),
+                    ))
+                }
+            };
+            actual_params.push(r#type);
+        }
+
+        if actual_params.len() != self.formal_params.len() {
+            return Err(Error::argument_count(
+                self.identifier.to_owned(),
+                self.formal_params.len(),
+                actual_params.len(),
+            ));
+        }
+
+        for (index, ((name, r#type), actual_type)) in self
+            .formal_params
+            .into_iter()
+            .zip(actual_params)
+            .enumerate()
+        {
+            if actual_type != r#type {
+                return Err(Error::argument_type(
+                    self.identifier.to_owned(),
+                    name,
+                    index + 1,
+                    r#type.to_string(),
+                    actual_type.to_string(),
+                ));
+            }
+        }
+
+        Ok(*self.return_type)
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "extern \"host\" fn {}({}) -> {}",
+            self.identifier,
+            self.formal_params
+                .iter()
+                .map(|(name, r#type)| format!("{}: {}", name, r#type))
+                .collect::<Vec<String>>()
+                .join(", "),
+            self.return_type,
+        )
+    }
+}
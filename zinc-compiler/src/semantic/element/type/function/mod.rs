@@ -5,7 +5,11 @@
 mod tests;
 
 pub mod builtin;
+pub mod enumeration;
 pub mod error;
+pub mod extension;
+pub mod generic;
+pub mod host;
 pub mod stdlib;
 pub mod user;
 
@@ -13,9 +17,15 @@ use std::fmt;
 
 use zinc_bytecode::builtins::BuiltinIdentifier;
 
+use crate::semantic::element::r#type::enumeration::Enumeration;
 use crate::semantic::element::r#type::Type;
 
 use self::builtin::Function as BuiltInFunction;
+use self::enumeration::Function as EnumerationFunction;
+use self::extension::Function as ExtensionFunction;
+use self::generic::Function as GenericFunction;
+use self::host::Function as HostFunction;
+use self::stdlib::math_select::Operation as MathSelectOperation;
 use self::stdlib::Function as StandardLibraryFunction;
 use self::user::Function as UserFunction;
 
@@ -33,6 +43,18 @@ pub enum Function {
     /// Ordinar functions declared anywhere within a circuit. There is a special `main` function,
     /// which is also declared by user, but serves as the circuit entry point.
     UserDefined(UserFunction),
+    /// The user-defined functions with const parameters, which are instantiated as ordinar
+    /// user-defined functions for every set of the parameter values they are called with.
+    Generic(GenericFunction),
+    /// The native gadgets declared in the `std::ext` module by the downstream crates and
+    /// implemented in the VM as extension calls.
+    Extension(ExtensionFunction),
+    /// The functions declared with `extern "host" fn`, whose results are supplied by the
+    /// host callbacks while generating the witness.
+    Host(HostFunction),
+    /// The `from_value`, `try_from`, and `variant_count` functions declared in the scope of every
+    /// enumeration, which are translated in place.
+    Enumeration(EnumerationFunction),
 }
 
 impl Function {
@@ -44,10 +66,18 @@ impl Function {
         Self::BuiltInFunction(BuiltInFunction::new_assert())
     }
 
+    pub fn new_expose() -> Self {
+        Self::BuiltInFunction(BuiltInFunction::new_expose())
+    }
+
     pub fn new_std(identifier: BuiltinIdentifier) -> Self {
         Self::StandardLibrary(StandardLibraryFunction::new(identifier))
     }
 
+    pub fn new_std_math_select(operation: MathSelectOperation) -> Self {
+        Self::StandardLibrary(StandardLibraryFunction::new_math_select(operation))
+    }
+
     pub fn new_user_defined(
         identifier: String,
         unique_id: usize,
@@ -62,11 +92,39 @@ impl Function {
         ))
     }
 
+    pub fn new_extension(
+        identifier: String,
+        arguments: Vec<(String, Type)>,
+        return_type: Type,
+    ) -> Self {
+        Self::Extension(ExtensionFunction::new(identifier, arguments, return_type))
+    }
+
+    pub fn new_host(identifier: String, arguments: Vec<(String, Type)>, return_type: Type) -> Self {
+        Self::Host(HostFunction::new(identifier, arguments, return_type))
+    }
+
+    pub fn new_enumeration_from_value(enumeration: Enumeration) -> Self {
+        Self::Enumeration(EnumerationFunction::new_from_value(enumeration))
+    }
+
+    pub fn new_enumeration_try_from(enumeration: Enumeration) -> Self {
+        Self::Enumeration(EnumerationFunction::new_try_from(enumeration))
+    }
+
+    pub fn new_enumeration_variant_count(enumeration: Enumeration) -> Self {
+        Self::Enumeration(EnumerationFunction::new_variant_count(enumeration))
+    }
+
     pub fn identifier(&self) -> String {
         match self {
             Function::BuiltInFunction(inner) => inner.identifier().to_owned(),
             Function::StandardLibrary(inner) => inner.identifier().to_owned(),
             Function::UserDefined(inner) => inner.identifier().to_owned(),
+            Function::Generic(inner) => inner.identifier().to_owned(),
+            Function::Extension(inner) => inner.identifier().to_owned(),
+            Function::Host(inner) => inner.identifier().to_owned(),
+            Function::Enumeration(inner) => inner.identifier().to_owned(),
         }
     }
 }
@@ -77,6 +135,10 @@ impl fmt::Display for Function {
             Self::BuiltInFunction(inner) => write!(f, "{}", inner),
             Self::StandardLibrary(inner) => write!(f, "{}", inner),
             Self::UserDefined(inner) => write!(f, "{}", inner),
+            Self::Generic(inner) => write!(f, "{}", inner),
+            Self::Extension(inner) => write!(f, "{}", inner),
+            Self::Host(inner) => write!(f, "{}", inner),
+            Self::Enumeration(inner) => write!(f, "{}", inner),
         }
     }
 }
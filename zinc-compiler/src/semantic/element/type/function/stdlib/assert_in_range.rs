@@ -0,0 +1,172 @@
+//!
+//! The semantic analyzer standard library `std::assert_in_range` function element.
+//!
+
+use std::fmt;
+
+use num_bigint::BigInt;
+
+use zinc_bytecode::builtins::BuiltinIdentifier;
+
+use crate::semantic::element::constant::Constant;
+use crate::semantic::element::r#type::function::error::Error;
+use crate::semantic::element::r#type::function::stdlib::error::Error as StdlibError;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+
+///
+/// The `assert_in_range` function, which asserts `low <= value <= high` with the bit
+/// decomposition of `value - low` sized after the constant bounds.
+///
+#[derive(Debug, Clone)]
+pub struct Function {
+    builtin_identifier: BuiltinIdentifier,
+    identifier: &'static str,
+}
+
+impl Function {
+    pub const ARGUMENT_INDEX_VALUE: usize = 0;
+    pub const ARGUMENT_INDEX_LOW: usize = 1;
+    pub const ARGUMENT_INDEX_HIGH: usize = 2;
+    pub const ARGUMENT_COUNT: usize = 3;
+
+    pub fn new(builtin_identifier: BuiltinIdentifier) -> Self {
+        Self {
+            builtin_identifier,
+            identifier: "assert_in_range",
+        }
+    }
+
+    pub fn identifier(&self) -> &'static str {
+        self.identifier
+    }
+
+    pub fn builtin_identifier(&self) -> BuiltinIdentifier {
+        self.builtin_identifier
+    }
+
+    pub fn call(self, actual_elements: Vec<Element>) -> Result<Type, Error> {
+        let mut actual_params = Vec::with_capacity(actual_elements.len());
+        for (index, element) in actual_elements.into_iter().enumerate() {
+            let (r#type, number) = match element {
+                Element::Value(value) => (value.r#type(), None),
+                Element::Constant(Constant::Integer(integer)) => {
+                    (integer.r#type(), Some(integer.to_bigint()))
+                }
+                Element::Constant(constant) => (constant.r#type(), None),
+                element => {
+                    return Err(Error::argument_not_evaluable(
+                        self.identifier.to_owned(),
+                        index + 1,
+                        element.to_string(),
+                    ))
+                }
+            };
+            actual_params.push((r#type, number));
+        }
+
+        let (r#type, value) = match actual_params.get(Self::ARGUMENT_INDEX_VALUE) {
+            Some((r#type @ Type::IntegerUnsigned { .. }, number))
+            | Some((r#type @ Type::IntegerSigned { .. }, number)) => (r#type, number),
+            Some((r#type, _number)) => {
+                return Err(Error::argument_type(
+                    self.identifier.to_owned(),
+                    "value".to_owned(),
+                    Self::ARGUMENT_INDEX_VALUE + 1,
+                    "{integer}".to_owned(),
+                    r#type.to_string(),
+                ))
+            }
+            None => {
+                return Err(Error::argument_count(
+                    self.identifier.to_owned(),
+                    Self::ARGUMENT_COUNT,
+                    actual_params.len(),
+                ))
+            }
+        };
+
+        let low = self.bound(
+            actual_params.get(Self::ARGUMENT_INDEX_LOW),
+            "low",
+            Self::ARGUMENT_INDEX_LOW,
+            r#type,
+            actual_params.len(),
+        )?;
+        let high = self.bound(
+            actual_params.get(Self::ARGUMENT_INDEX_HIGH),
+            "high",
+            Self::ARGUMENT_INDEX_HIGH,
+            r#type,
+            actual_params.len(),
+        )?;
+
+        if actual_params.len() > Self::ARGUMENT_COUNT {
+            return Err(Error::argument_count(
+                self.identifier.to_owned(),
+                Self::ARGUMENT_COUNT,
+                actual_params.len(),
+            ));
+        }
+
+        if low > high {
+            return Err(Error::StandardLibrary(StdlibError::assert_range_empty(
+                low.to_string(),
+                high.to_string(),
+            )));
+        }
+
+        if let Some(value) = value {
+            if value < &low || value > &high {
+                return Err(Error::StandardLibrary(
+                    StdlibError::assert_value_out_of_range(
+                        value.to_string(),
+                        format!("{}..={}", low, high),
+                    ),
+                ));
+            }
+        }
+
+        Ok(Type::unit())
+    }
+
+    ///
+    /// Checks the bound to be a constant of the `value` type.
+    ///
+    fn bound(
+        &self,
+        actual_param: Option<&(Type, Option<BigInt>)>,
+        name: &str,
+        index: usize,
+        expected_type: &Type,
+        actual_params_count: usize,
+    ) -> Result<BigInt, Error> {
+        match actual_param {
+            Some((r#type, Some(number))) if r#type == expected_type => Ok(number.to_owned()),
+            Some((r#type, None)) if r#type == expected_type => Err(Error::argument_constantness(
+                self.identifier.to_owned(),
+                name.to_owned(),
+                index + 1,
+                r#type.to_string(),
+            )),
+            Some((r#type, _number)) => Err(Error::argument_type(
+                self.identifier.to_owned(),
+                name.to_owned(),
+                index + 1,
+                expected_type.to_string(),
+                r#type.to_string(),
+            )),
+            None => Err(Error::argument_count(
+                self.identifier.to_owned(),
+                Self::ARGUMENT_COUNT,
+                actual_params_count,
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fn std::{}(value: T, low: T, high: T)", self.identifier)
+    }
+}
@@ -0,0 +1,103 @@
+//!
+//! The semantic analyzer standard library `std::bits::{count_ones, leading_zeros}` function element.
+//!
+
+use std::fmt;
+
+use zinc_bytecode::builtins::BuiltinIdentifier;
+
+use crate::semantic::element::r#type::function::error::Error;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+
+///
+/// The `count_ones` and `leading_zeros` functions, which count the bits of an unsigned integer
+/// and return the number as `u8`.
+///
+#[derive(Debug, Clone)]
+pub struct Function {
+    builtin_identifier: BuiltinIdentifier,
+    identifier: &'static str,
+    return_type: Box<Type>,
+}
+
+impl Function {
+    pub const ARGUMENT_INDEX_VALUE: usize = 0;
+    pub const ARGUMENT_COUNT: usize = 1;
+
+    pub fn new(builtin_identifier: BuiltinIdentifier) -> Self {
+        let identifier = match builtin_identifier {
+            BuiltinIdentifier::BitsCountOnes => "count_ones",
+            BuiltinIdentifier::BitsLeadingZeros => "leading_zeros",
+            _ => panic!("{}", crate::PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS),
+        };
+
+        Self {
+            builtin_identifier,
+            identifier,
+            return_type: Box::new(Type::integer_unsigned(crate::BITLENGTH_BYTE)),
+        }
+    }
+
+    pub fn identifier(&self) -> &'static str {
+        self.identifier
+    }
+
+    pub fn builtin_identifier(&self) -> BuiltinIdentifier {
+        self.builtin_identifier
+    }
+
+    pub fn call(self, actual_elements: Vec<Element>) -> Result<Type, Error> {
+        let mut actual_params = Vec::with_capacity(actual_elements.len());
+        for (index, element) in actual_elements.into_iter().enumerate() {
+            let r#type = match element {
+                Element::Value(value) => value.r#type(),
+                Element::Constant(constant) => constant.r#type(),
+                element => {
+                    return Err(Error::argument_not_evaluable(
+                        self.identifier.to_owned(),
+                        index + 1,
+                        element.to_string(),
+                    ))
+                }
+            };
+            actual_params.push(r#type);
+        }
+
+        match actual_params.get(Self::ARGUMENT_INDEX_VALUE) {
+            Some(Type::IntegerUnsigned { .. }) => {}
+            Some(r#type) => {
+                return Err(Error::argument_type(
+                    self.identifier.to_owned(),
+                    "value".to_owned(),
+                    Self::ARGUMENT_INDEX_VALUE + 1,
+                    "{unsigned integer}".to_owned(),
+                    r#type.to_string(),
+                ))
+            }
+            None => {
+                return Err(Error::argument_count(
+                    self.identifier.to_owned(),
+                    Self::ARGUMENT_COUNT,
+                    actual_params.len(),
+                ))
+            }
+        }
+
+        if actual_params.len() > Self::ARGUMENT_COUNT {
+            return Err(Error::argument_count(
+                self.identifier.to_owned(),
+                Self::ARGUMENT_COUNT,
+                actual_params.len(),
+            ));
+        }
+
+        Ok(*self.return_type)
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fn std::bits::{}(value: u{{N}}) -> u8", self.identifier)
+    }
+}
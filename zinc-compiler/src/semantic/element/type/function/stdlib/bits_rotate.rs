@@ -0,0 +1,134 @@
+//!
+//! The semantic analyzer standard library `std::bits::{rotl, rotr}` function element.
+//!
+
+use std::fmt;
+
+use zinc_bytecode::builtins::BuiltinIdentifier;
+
+use crate::semantic::element::r#type::function::error::Error;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+
+///
+/// The `rotl` and `rotr` functions, which rotate the bits of an unsigned integer by a constant
+/// number of positions, wrapping around the integer bitlength.
+///
+#[derive(Debug, Clone)]
+pub struct Function {
+    builtin_identifier: BuiltinIdentifier,
+    identifier: &'static str,
+}
+
+impl Function {
+    pub const ARGUMENT_INDEX_VALUE: usize = 0;
+    pub const ARGUMENT_INDEX_SHIFT: usize = 1;
+    pub const ARGUMENT_COUNT: usize = 2;
+
+    pub fn new(builtin_identifier: BuiltinIdentifier) -> Self {
+        let identifier = match builtin_identifier {
+            BuiltinIdentifier::BitsRotateLeft => "rotl",
+            BuiltinIdentifier::BitsRotateRight => "rotr",
+            _ => panic!("{}", crate::PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS),
+        };
+
+        Self {
+            builtin_identifier,
+            identifier,
+        }
+    }
+
+    pub fn identifier(&self) -> &'static str {
+        self.identifier
+    }
+
+    pub fn builtin_identifier(&self) -> BuiltinIdentifier {
+        self.builtin_identifier
+    }
+
+    pub fn call(self, actual_elements: Vec<Element>) -> Result<Type, Error> {
+        let mut actual_params = Vec::with_capacity(actual_elements.len());
+        for (index, element) in actual_elements.into_iter().enumerate() {
+            let (r#type, is_constant) = match element {
+                Element::Value(value) => (value.r#type(), false),
+                Element::Constant(constant) => (constant.r#type(), true),
+                element => {
+                    return Err(Error::argument_not_evaluable(
+                        self.identifier.to_owned(),
+                        index + 1,
+                        element.to_string(),
+                    ))
+                }
+            };
+            actual_params.push((r#type, is_constant));
+        }
+
+        let return_type = match actual_params.get(Self::ARGUMENT_INDEX_VALUE) {
+            Some((r#type @ Type::IntegerUnsigned { .. }, _is_constant)) => r#type.to_owned(),
+            Some((r#type, _is_constant)) => {
+                return Err(Error::argument_type(
+                    self.identifier.to_owned(),
+                    "value".to_owned(),
+                    Self::ARGUMENT_INDEX_VALUE + 1,
+                    "{unsigned integer}".to_owned(),
+                    r#type.to_string(),
+                ))
+            }
+            None => {
+                return Err(Error::argument_count(
+                    self.identifier.to_owned(),
+                    Self::ARGUMENT_COUNT,
+                    actual_params.len(),
+                ))
+            }
+        };
+
+        match actual_params.get(Self::ARGUMENT_INDEX_SHIFT) {
+            Some((Type::IntegerUnsigned { .. }, true)) => {}
+            Some((r#type @ Type::IntegerUnsigned { .. }, false)) => {
+                return Err(Error::argument_constantness(
+                    self.identifier.to_owned(),
+                    "shift".to_owned(),
+                    Self::ARGUMENT_INDEX_SHIFT + 1,
+                    r#type.to_string(),
+                ))
+            }
+            Some((r#type, _is_constant)) => {
+                return Err(Error::argument_type(
+                    self.identifier.to_owned(),
+                    "shift".to_owned(),
+                    Self::ARGUMENT_INDEX_SHIFT + 1,
+                    "{unsigned integer}".to_owned(),
+                    r#type.to_string(),
+                ))
+            }
+            None => {
+                return Err(Error::argument_count(
+                    self.identifier.to_owned(),
+                    Self::ARGUMENT_COUNT,
+                    actual_params.len(),
+                ))
+            }
+        }
+
+        if actual_params.len() > Self::ARGUMENT_COUNT {
+            return Err(Error::argument_count(
+                self.identifier.to_owned(),
+                Self::ARGUMENT_COUNT,
+                actual_params.len(),
+            ));
+        }
+
+        Ok(return_type)
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "fn std::bits::{}(value: u{{N}}, shift: u{{M}}) -> u{{N}}",
+            self.identifier
+        )
+    }
+}
@@ -7,6 +7,10 @@ pub enum Error {
     ArrayTruncatingToBiggerSize { from: usize, to: usize },
     ArrayPaddingToLesserSize { from: usize, to: usize },
     ArrayNewLengthInvalid { value: String },
+    MathFractionalBitsInvalid { value: String, bitlength: usize },
+    TableIndexOutOfRange { index: String, size: usize },
+    AssertRangeEmpty { low: String, high: String },
+    AssertValueOutOfRange { value: String, range: String },
 }
 
 impl Error {
@@ -21,4 +25,20 @@ impl Error {
     pub fn array_new_length_invalid(value: String) -> Self {
         Self::ArrayNewLengthInvalid { value }
     }
+
+    pub fn math_fractional_bits_invalid(value: String, bitlength: usize) -> Self {
+        Self::MathFractionalBitsInvalid { value, bitlength }
+    }
+
+    pub fn table_index_out_of_range(index: String, size: usize) -> Self {
+        Self::TableIndexOutOfRange { index, size }
+    }
+
+    pub fn assert_range_empty(low: String, high: String) -> Self {
+        Self::AssertRangeEmpty { low, high }
+    }
+
+    pub fn assert_value_out_of_range(value: String, range: String) -> Self {
+        Self::AssertValueOutOfRange { value, range }
+    }
 }
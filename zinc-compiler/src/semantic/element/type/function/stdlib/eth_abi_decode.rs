@@ -0,0 +1,112 @@
+//!
+//! The semantic analyzer standard library `std::eth::abi_decode` function element.
+//!
+
+use std::fmt;
+use std::ops::Deref;
+
+use zinc_bytecode::builtins::BuiltinIdentifier;
+
+use crate::semantic::element::r#type::function::error::Error;
+use crate::semantic::element::r#type::function::stdlib::eth_abi_encode::Function as AbiEncodeFunction;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+
+#[derive(Debug, Clone)]
+pub struct Function {
+    builtin_identifier: BuiltinIdentifier,
+    identifier: &'static str,
+}
+
+impl Function {
+    pub const ARGUMENT_INDEX_BYTES: usize = 0;
+    pub const ARGUMENT_INDEX_TYPE: usize = 1;
+    pub const ARGUMENT_COUNT: usize = 2;
+
+    pub fn new(builtin_identifier: BuiltinIdentifier) -> Self {
+        Self {
+            builtin_identifier,
+            identifier: "abi_decode",
+        }
+    }
+
+    pub fn identifier(&self) -> &'static str {
+        self.identifier
+    }
+
+    pub fn builtin_identifier(&self) -> BuiltinIdentifier {
+        self.builtin_identifier
+    }
+
+    ///
+    /// The first argument is the encoded bytes and the second one is the type to decode,
+    /// which is only used at compile time.
+    ///
+    pub fn call(self, actual_elements: Vec<Element>) -> Result<Type, Error> {
+        if actual_elements.len() != Self::ARGUMENT_COUNT {
+            return Err(Error::argument_count(
+                self.identifier.to_owned(),
+                Self::ARGUMENT_COUNT,
+                actual_elements.len(),
+            ));
+        }
+
+        let mut actual_elements = actual_elements.into_iter();
+
+        let bytes_type = match actual_elements.next() {
+            Some(Element::Value(value)) => value.r#type(),
+            Some(Element::Constant(constant)) => constant.r#type(),
+            Some(element) => {
+                return Err(Error::argument_not_evaluable(
+                    self.identifier.to_owned(),
+                    Self::ARGUMENT_INDEX_BYTES + 1,
+                    element.to_string(),
+                ))
+            }
+            None => panic!("{}", crate::PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS),
+        };
+
+        let return_type = match actual_elements.next() {
+            Some(Element::Type(r#type)) if AbiEncodeFunction::is_encodable(&r#type) => r#type,
+            Some(element) => {
+                return Err(Error::argument_type(
+                    self.identifier.to_owned(),
+                    "type".to_owned(),
+                    Self::ARGUMENT_INDEX_TYPE + 1,
+                    "{scalar}, or an array, tuple, or structure type of them".to_owned(),
+                    element.to_string(),
+                ))
+            }
+            None => panic!("{}", crate::PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS),
+        };
+
+        let expected_size = AbiEncodeFunction::WORD_SIZE * return_type.size();
+        match bytes_type {
+            Type::Array { ref r#type, size }
+                if r#type.deref() == &Type::integer_unsigned(crate::BITLENGTH_BYTE)
+                    && size == expected_size => {}
+            r#type => {
+                return Err(Error::argument_type(
+                    self.identifier.to_owned(),
+                    "bytes".to_owned(),
+                    Self::ARGUMENT_INDEX_BYTES + 1,
+                    format!("[u8; {}]", expected_size),
+                    r#type.to_string(),
+                ))
+            }
+        }
+
+        Ok(return_type)
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "fn std::eth::{}(bytes: [u8; {} * size(T)], T) -> T",
+            self.identifier,
+            AbiEncodeFunction::WORD_SIZE,
+        )
+    }
+}
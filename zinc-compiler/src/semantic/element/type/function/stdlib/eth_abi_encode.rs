@@ -0,0 +1,120 @@
+//!
+//! The semantic analyzer standard library `std::eth::abi_encode` function element.
+//!
+
+use std::fmt;
+
+use zinc_bytecode::builtins::BuiltinIdentifier;
+
+use crate::semantic::element::r#type::function::error::Error;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+
+#[derive(Debug, Clone)]
+pub struct Function {
+    builtin_identifier: BuiltinIdentifier,
+    identifier: &'static str,
+}
+
+impl Function {
+    pub const ARGUMENT_INDEX_VALUE: usize = 0;
+    pub const ARGUMENT_COUNT: usize = 1;
+
+    /// The Solidity ABI word size, which every scalar is padded to.
+    pub const WORD_SIZE: usize = 32;
+
+    pub fn new(builtin_identifier: BuiltinIdentifier) -> Self {
+        Self {
+            builtin_identifier,
+            identifier: "abi_encode",
+        }
+    }
+
+    pub fn identifier(&self) -> &'static str {
+        self.identifier
+    }
+
+    pub fn builtin_identifier(&self) -> BuiltinIdentifier {
+        self.builtin_identifier
+    }
+
+    pub fn call(self, actual_elements: Vec<Element>) -> Result<Type, Error> {
+        let mut actual_params = Vec::with_capacity(actual_elements.len());
+        for (index, element) in actual_elements.into_iter().enumerate() {
+            let r#type = match element {
+                Element::Value(value) => value.r#type(),
+                Element::Constant(constant) => constant.r#type(),
+                element => {
+                    return Err(Error::argument_not_evaluable(
+                        self.identifier.to_owned(),
+                        index + 1,
+                        element.to_string(),
+                    ))
+                }
+            };
+            actual_params.push(r#type);
+        }
+
+        let return_type = match actual_params.get(Self::ARGUMENT_INDEX_VALUE) {
+            Some(r#type) if Self::is_encodable(r#type) => Type::array(
+                Type::integer_unsigned(crate::BITLENGTH_BYTE),
+                Self::WORD_SIZE * r#type.size(),
+            ),
+            Some(r#type) => {
+                return Err(Error::argument_type(
+                    self.identifier.to_owned(),
+                    "value".to_owned(),
+                    Self::ARGUMENT_INDEX_VALUE + 1,
+                    "{scalar}, or an array, tuple, or structure of them".to_owned(),
+                    r#type.to_string(),
+                ))
+            }
+            None => {
+                return Err(Error::argument_count(
+                    self.identifier.to_owned(),
+                    Self::ARGUMENT_COUNT,
+                    actual_params.len(),
+                ))
+            }
+        };
+
+        if actual_params.len() > Self::ARGUMENT_COUNT {
+            return Err(Error::argument_count(
+                self.identifier.to_owned(),
+                Self::ARGUMENT_COUNT,
+                actual_params.len(),
+            ));
+        }
+
+        Ok(return_type)
+    }
+
+    ///
+    /// Checks whether the type has a static Solidity ABI layout, that is, consists of
+    /// scalars, each of them taking a single 32-byte word.
+    ///
+    pub fn is_encodable(r#type: &Type) -> bool {
+        match r#type {
+            Type::Unit => true,
+            r#type if r#type.is_scalar() => true,
+            Type::Array { r#type, .. } => Self::is_encodable(r#type),
+            Type::Tuple { types } => types.iter().all(Self::is_encodable),
+            Type::Structure(structure) => structure
+                .fields
+                .iter()
+                .all(|(_name, r#type)| Self::is_encodable(r#type)),
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "fn std::eth::{}(value: T) -> [u8; {} * size(T)]",
+            self.identifier,
+            Self::WORD_SIZE,
+        )
+    }
+}
@@ -0,0 +1,236 @@
+//!
+//! The semantic analyzer standard library `std::math::fixed_*` function element.
+//!
+
+use std::fmt;
+
+use zinc_bytecode::builtins::BuiltinIdentifier;
+
+use crate::semantic::element::constant::Constant;
+use crate::semantic::element::r#type::function::error::Error;
+use crate::semantic::element::r#type::function::stdlib::error::Error as StdlibError;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+use crate::semantic::scope::builtin::BuiltInItems;
+
+///
+/// The `fixed_mul` and `fixed_div` functions, which treat the integers as the fixed-point
+/// decimals with `frac_bits` fractional bits, and round the result with the `rounding` mode.
+///
+/// The `Decimal<I, F>` values carry their fractional bits, so the `frac_bits` argument is
+/// omitted for them and passed by the compiler.
+///
+#[derive(Debug, Clone)]
+pub struct Function {
+    builtin_identifier: BuiltinIdentifier,
+    identifier: &'static str,
+}
+
+impl Function {
+    pub const ARGUMENT_INDEX_A: usize = 0;
+    pub const ARGUMENT_INDEX_B: usize = 1;
+    pub const ARGUMENT_INDEX_FRAC_BITS: usize = 2;
+    pub const ARGUMENT_INDEX_ROUNDING: usize = 3;
+    pub const ARGUMENT_COUNT: usize = 4;
+
+    pub fn new(builtin_identifier: BuiltinIdentifier) -> Self {
+        let identifier = match builtin_identifier {
+            BuiltinIdentifier::MathFixedMul => "fixed_mul",
+            BuiltinIdentifier::MathFixedDiv => "fixed_div",
+            _ => panic!("{}", crate::PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS),
+        };
+
+        Self {
+            builtin_identifier,
+            identifier,
+        }
+    }
+
+    pub fn identifier(&self) -> &'static str {
+        self.identifier
+    }
+
+    pub fn builtin_identifier(&self) -> BuiltinIdentifier {
+        self.builtin_identifier
+    }
+
+    pub fn call(self, actual_elements: Vec<Element>) -> Result<Type, Error> {
+        let mut actual_params = Vec::with_capacity(actual_elements.len());
+        for (index, element) in actual_elements.into_iter().enumerate() {
+            let (r#type, is_constant, number) = match element {
+                Element::Value(value) => (value.r#type(), false, None),
+                Element::Constant(Constant::Integer(integer)) => {
+                    let number = integer.to_usize().ok();
+                    (
+                        integer.r#type(),
+                        true,
+                        Some((number, integer.value.to_string())),
+                    )
+                }
+                Element::Constant(constant) => (constant.r#type(), true, None),
+                element => {
+                    return Err(Error::argument_not_evaluable(
+                        self.identifier.to_owned(),
+                        index + 1,
+                        element.to_string(),
+                    ))
+                }
+            };
+            actual_params.push((r#type, is_constant, number));
+        }
+
+        let (r#type, bitlength) = match actual_params.get(Self::ARGUMENT_INDEX_A) {
+            Some((r#type @ Type::IntegerUnsigned { bitlength }, _is_constant, _number))
+            | Some((r#type @ Type::IntegerSigned { bitlength }, _is_constant, _number))
+                if bitlength * 2 < crate::BITLENGTH_FIELD =>
+            {
+                (r#type.to_owned(), *bitlength)
+            }
+            Some((
+                r#type @ Type::Decimal {
+                    integer_bits,
+                    fraction_bits,
+                },
+                _is_constant,
+                _number,
+            )) => (r#type.to_owned(), integer_bits + fraction_bits),
+            Some((r#type, _is_constant, _number)) => {
+                return Err(Error::argument_type(
+                    self.identifier.to_owned(),
+                    "a".to_owned(),
+                    Self::ARGUMENT_INDEX_A + 1,
+                    format!(
+                        "{{integer}}, bitlength * 2 < {}, or {{decimal}}",
+                        crate::BITLENGTH_FIELD
+                    ),
+                    r#type.to_string(),
+                ))
+            }
+            None => {
+                return Err(Error::argument_count(
+                    self.identifier.to_owned(),
+                    Self::ARGUMENT_COUNT,
+                    actual_params.len(),
+                ))
+            }
+        };
+
+        let is_decimal = matches!(r#type, Type::Decimal { .. });
+        let (argument_index_rounding, argument_count) = if is_decimal {
+            (Self::ARGUMENT_INDEX_FRAC_BITS, Self::ARGUMENT_COUNT - 1)
+        } else {
+            (Self::ARGUMENT_INDEX_ROUNDING, Self::ARGUMENT_COUNT)
+        };
+
+        match actual_params.get(Self::ARGUMENT_INDEX_B) {
+            Some((b_type, _is_constant, _number)) if b_type == &r#type => {}
+            Some((b_type, _is_constant, _number)) => {
+                return Err(Error::argument_type(
+                    self.identifier.to_owned(),
+                    "b".to_owned(),
+                    Self::ARGUMENT_INDEX_B + 1,
+                    r#type.to_string(),
+                    b_type.to_string(),
+                ))
+            }
+            None => {
+                return Err(Error::argument_count(
+                    self.identifier.to_owned(),
+                    argument_count,
+                    actual_params.len(),
+                ))
+            }
+        }
+
+        if !is_decimal {
+            match actual_params.get(Self::ARGUMENT_INDEX_FRAC_BITS) {
+                Some((r#type, true, Some((number, value)))) if r#type.is_scalar_unsigned() => {
+                    match number {
+                        Some(number) if *number <= bitlength => {}
+                        _ => {
+                            return Err(Error::StandardLibrary(
+                                StdlibError::math_fractional_bits_invalid(
+                                    value.to_owned(),
+                                    bitlength,
+                                ),
+                            ))
+                        }
+                    }
+                }
+                Some((r#type, true, _number)) => {
+                    return Err(Error::argument_type(
+                        self.identifier.to_owned(),
+                        "frac_bits".to_owned(),
+                        Self::ARGUMENT_INDEX_FRAC_BITS + 1,
+                        "{unsigned integer}".to_owned(),
+                        r#type.to_string(),
+                    ))
+                }
+                Some((r#type, false, _number)) => {
+                    return Err(Error::argument_constantness(
+                        self.identifier.to_owned(),
+                        "frac_bits".to_owned(),
+                        Self::ARGUMENT_INDEX_FRAC_BITS + 1,
+                        r#type.to_string(),
+                    ))
+                }
+                None => {
+                    return Err(Error::argument_count(
+                        self.identifier.to_owned(),
+                        argument_count,
+                        actual_params.len(),
+                    ))
+                }
+            }
+        }
+
+        match actual_params.get(argument_index_rounding) {
+            Some((Type::Enumeration(enumeration), true, _number))
+                if enumeration.unique_id == BuiltInItems::TYPE_ID_STD_MATH_ROUNDING => {}
+            Some((r#type, true, _number)) => {
+                return Err(Error::argument_type(
+                    self.identifier.to_owned(),
+                    "rounding".to_owned(),
+                    argument_index_rounding + 1,
+                    "std::math::Rounding".to_owned(),
+                    r#type.to_string(),
+                ))
+            }
+            Some((r#type, false, _number)) => {
+                return Err(Error::argument_constantness(
+                    self.identifier.to_owned(),
+                    "rounding".to_owned(),
+                    argument_index_rounding + 1,
+                    r#type.to_string(),
+                ))
+            }
+            None => {
+                return Err(Error::argument_count(
+                    self.identifier.to_owned(),
+                    argument_count,
+                    actual_params.len(),
+                ))
+            }
+        }
+
+        if actual_params.len() > argument_count {
+            return Err(Error::argument_count(
+                self.identifier.to_owned(),
+                argument_count,
+                actual_params.len(),
+            ));
+        }
+
+        Ok(r#type)
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "fn std::math::{}(a: T, b: T, frac_bits: N, rounding: std::math::Rounding) -> T",
+            self.identifier
+        )
+    }
+}
@@ -0,0 +1,152 @@
+//!
+//! The semantic analyzer standard library `std::math::overflowing_*` function element.
+//!
+
+use std::fmt;
+
+use zinc_bytecode::builtins::BuiltinIdentifier;
+
+use crate::semantic::element::r#type::function::error::Error;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+
+///
+/// The `overflowing_add`, `overflowing_sub`, and `overflowing_mul` functions, which return
+/// the wrapped result along with the overflow flag instead of failing.
+///
+#[derive(Debug, Clone)]
+pub struct Function {
+    builtin_identifier: BuiltinIdentifier,
+    identifier: &'static str,
+}
+
+impl Function {
+    pub const ARGUMENT_INDEX_A: usize = 0;
+    pub const ARGUMENT_INDEX_B: usize = 1;
+    pub const ARGUMENT_COUNT: usize = 2;
+
+    pub fn new(builtin_identifier: BuiltinIdentifier) -> Self {
+        let identifier = match builtin_identifier {
+            BuiltinIdentifier::MathOverflowingAdd => "overflowing_add",
+            BuiltinIdentifier::MathOverflowingSub => "overflowing_sub",
+            BuiltinIdentifier::MathOverflowingMul => "overflowing_mul",
+            _ => panic!("{}", crate::PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS),
+        };
+
+        Self {
+            builtin_identifier,
+            identifier,
+        }
+    }
+
+    pub fn identifier(&self) -> &'static str {
+        self.identifier
+    }
+
+    pub fn builtin_identifier(&self) -> BuiltinIdentifier {
+        self.builtin_identifier
+    }
+
+    pub fn call(self, actual_elements: Vec<Element>) -> Result<Type, Error> {
+        let mut actual_params = Vec::with_capacity(actual_elements.len());
+        for (index, element) in actual_elements.into_iter().enumerate() {
+            let r#type = match element {
+                Element::Value(value) => value.r#type(),
+                Element::Constant(constant) => constant.r#type(),
+                element => {
+                    return Err(Error::argument_not_evaluable(
+                        self.identifier.to_owned(),
+                        index + 1,
+                        element.to_string(),
+                    ))
+                }
+            };
+            actual_params.push(r#type);
+        }
+
+        let r#type = match actual_params.get(Self::ARGUMENT_INDEX_A) {
+            Some(r#type @ Type::IntegerUnsigned { bitlength })
+            | Some(r#type @ Type::IntegerSigned { bitlength })
+                if self.is_bitlength_supported(*bitlength) =>
+            {
+                r#type.to_owned()
+            }
+            Some(r#type) => {
+                return Err(Error::argument_type(
+                    self.identifier.to_owned(),
+                    "a".to_owned(),
+                    Self::ARGUMENT_INDEX_A + 1,
+                    self.expected_type(),
+                    r#type.to_string(),
+                ))
+            }
+            None => {
+                return Err(Error::argument_count(
+                    self.identifier.to_owned(),
+                    Self::ARGUMENT_COUNT,
+                    actual_params.len(),
+                ))
+            }
+        };
+
+        match actual_params.get(Self::ARGUMENT_INDEX_B) {
+            Some(b_type) if b_type == &r#type => {}
+            Some(b_type) => {
+                return Err(Error::argument_type(
+                    self.identifier.to_owned(),
+                    "b".to_owned(),
+                    Self::ARGUMENT_INDEX_B + 1,
+                    r#type.to_string(),
+                    b_type.to_string(),
+                ))
+            }
+            None => {
+                return Err(Error::argument_count(
+                    self.identifier.to_owned(),
+                    Self::ARGUMENT_COUNT,
+                    actual_params.len(),
+                ))
+            }
+        }
+
+        if actual_params.len() > Self::ARGUMENT_COUNT {
+            return Err(Error::argument_count(
+                self.identifier.to_owned(),
+                Self::ARGUMENT_COUNT,
+                actual_params.len(),
+            ));
+        }
+
+        Ok(Type::tuple(vec![r#type, Type::boolean()]))
+    }
+
+    ///
+    /// The full product of two integers must fit into a field element, so the multiplication
+    /// supports only the integers up to a half of the field bitlength.
+    ///
+    fn is_bitlength_supported(&self, bitlength: usize) -> bool {
+        match self.builtin_identifier {
+            BuiltinIdentifier::MathOverflowingMul => bitlength * 2 < crate::BITLENGTH_FIELD,
+            _ => true,
+        }
+    }
+
+    fn expected_type(&self) -> String {
+        match self.builtin_identifier {
+            BuiltinIdentifier::MathOverflowingMul => {
+                format!("{{integer}}, bitlength * 2 < {}", crate::BITLENGTH_FIELD)
+            }
+            _ => "{integer}".to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "fn std::math::{}(a: T, b: T) -> (T, bool)",
+            self.identifier
+        )
+    }
+}
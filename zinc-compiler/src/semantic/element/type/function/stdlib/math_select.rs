@@ -0,0 +1,169 @@
+//!
+//! The semantic analyzer standard library `std::math::{min, max, clamp, abs}` function element.
+//!
+
+use std::fmt;
+
+use zinc_bytecode::builtins::BuiltinIdentifier;
+
+use crate::semantic::element::r#type::function::error::Error;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+
+///
+/// The select operation, which is passed to the VM as a constant after the arguments.
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Operation {
+    Min = 0,
+    Max = 1,
+    Clamp = 2,
+    Abs = 3,
+}
+
+impl Operation {
+    pub const ALL: [Operation; 4] = [Self::Min, Self::Max, Self::Clamp, Self::Abs];
+}
+
+///
+/// The `min`, `max`, `clamp`, and `abs` functions, which are lowered to conditional selects
+/// instead of the branching user code.
+///
+/// The functions are generic over the scalar types and share the built-in function.
+///
+#[derive(Debug, Clone)]
+pub struct Function {
+    operation: Operation,
+    identifier: &'static str,
+}
+
+impl Function {
+    pub const ARGUMENT_INDEX_VALUE: usize = 0;
+
+    pub fn new(operation: Operation) -> Self {
+        let identifier = match operation {
+            Operation::Min => "min",
+            Operation::Max => "max",
+            Operation::Clamp => "clamp",
+            Operation::Abs => "abs",
+        };
+
+        Self {
+            operation,
+            identifier,
+        }
+    }
+
+    pub fn identifier(&self) -> &'static str {
+        self.identifier
+    }
+
+    pub fn builtin_identifier(&self) -> BuiltinIdentifier {
+        BuiltinIdentifier::MathSelect
+    }
+
+    pub fn operation(&self) -> Operation {
+        self.operation
+    }
+
+    ///
+    /// The argument names, all of which must be of the same type.
+    ///
+    pub fn argument_names(&self) -> &'static [&'static str] {
+        match self.operation {
+            Operation::Min | Operation::Max => &["a", "b"],
+            Operation::Clamp => &["value", "low", "high"],
+            Operation::Abs => &["value"],
+        }
+    }
+
+    pub fn call(self, actual_elements: Vec<Element>) -> Result<Type, Error> {
+        let mut actual_params = Vec::with_capacity(actual_elements.len());
+        for (index, element) in actual_elements.into_iter().enumerate() {
+            let r#type = match element {
+                Element::Value(value) => value.r#type(),
+                Element::Constant(constant) => constant.r#type(),
+                element => {
+                    return Err(Error::argument_not_evaluable(
+                        self.identifier.to_owned(),
+                        index + 1,
+                        element.to_string(),
+                    ))
+                }
+            };
+            actual_params.push(r#type);
+        }
+
+        let argument_names = self.argument_names();
+        if actual_params.len() != argument_names.len() {
+            return Err(Error::argument_count(
+                self.identifier.to_owned(),
+                argument_names.len(),
+                actual_params.len(),
+            ));
+        }
+
+        let r#type = match actual_params.get(Self::ARGUMENT_INDEX_VALUE) {
+            Some(r#type) if self.is_type_supported(r#type) => r#type.to_owned(),
+            Some(r#type) => {
+                return Err(Error::argument_type(
+                    self.identifier.to_owned(),
+                    argument_names[Self::ARGUMENT_INDEX_VALUE].to_owned(),
+                    Self::ARGUMENT_INDEX_VALUE + 1,
+                    self.expected_type(),
+                    r#type.to_string(),
+                ))
+            }
+            None => panic!("{}", crate::PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS),
+        };
+
+        for (index, actual_type) in actual_params.iter().enumerate().skip(1) {
+            if actual_type != &r#type {
+                return Err(Error::argument_type(
+                    self.identifier.to_owned(),
+                    argument_names[index].to_owned(),
+                    index + 1,
+                    r#type.to_string(),
+                    actual_type.to_string(),
+                ));
+            }
+        }
+
+        Ok(r#type)
+    }
+
+    ///
+    /// The absolute value is only meaningful for the signed integers, whereas the comparisons
+    /// also support the field elements.
+    ///
+    fn is_type_supported(&self, r#type: &Type) -> bool {
+        match (self.operation, r#type) {
+            (_, Type::IntegerSigned { .. }) => true,
+            (Operation::Abs, _) => false,
+            (_, Type::IntegerUnsigned { .. }) | (_, Type::Field) => true,
+            _ => false,
+        }
+    }
+
+    fn expected_type(&self) -> String {
+        match self.operation {
+            Operation::Abs => "{signed integer}".to_owned(),
+            _ => "{integer}, field".to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "fn std::math::{}({}) -> T",
+            self.identifier,
+            self.argument_names()
+                .iter()
+                .map(|name| format!("{}: T", name))
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+    }
+}
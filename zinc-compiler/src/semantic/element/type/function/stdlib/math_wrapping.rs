@@ -0,0 +1,122 @@
+//!
+//! The semantic analyzer standard library `std::math::{add, sub, mul}_mod{32, 64}` function element.
+//!
+
+use std::fmt;
+
+use zinc_bytecode::builtins::BuiltinIdentifier;
+
+use crate::semantic::element::r#type::function::error::Error;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+
+///
+/// The `add_mod32`, `sub_mod32`, `mul_mod32` functions and their 64-bit counterparts, which
+/// wrap the result around the integer width instead of failing on overflow.
+///
+#[derive(Debug, Clone)]
+pub struct Function {
+    builtin_identifier: BuiltinIdentifier,
+    identifier: &'static str,
+    bitlength: usize,
+}
+
+impl Function {
+    pub const ARGUMENT_INDEX_A: usize = 0;
+    pub const ARGUMENT_INDEX_B: usize = 1;
+    pub const ARGUMENT_COUNT: usize = 2;
+
+    pub fn new(builtin_identifier: BuiltinIdentifier) -> Self {
+        let (identifier, bitlength) = match builtin_identifier {
+            BuiltinIdentifier::MathAddMod32 => ("add_mod32", crate::BITLENGTH_BYTE * 4),
+            BuiltinIdentifier::MathSubMod32 => ("sub_mod32", crate::BITLENGTH_BYTE * 4),
+            BuiltinIdentifier::MathMulMod32 => ("mul_mod32", crate::BITLENGTH_BYTE * 4),
+            BuiltinIdentifier::MathAddMod64 => ("add_mod64", crate::BITLENGTH_BYTE * 8),
+            BuiltinIdentifier::MathSubMod64 => ("sub_mod64", crate::BITLENGTH_BYTE * 8),
+            BuiltinIdentifier::MathMulMod64 => ("mul_mod64", crate::BITLENGTH_BYTE * 8),
+            _ => panic!("{}", crate::PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS),
+        };
+
+        Self {
+            builtin_identifier,
+            identifier,
+            bitlength,
+        }
+    }
+
+    pub fn identifier(&self) -> &'static str {
+        self.identifier
+    }
+
+    pub fn builtin_identifier(&self) -> BuiltinIdentifier {
+        self.builtin_identifier
+    }
+
+    ///
+    /// The type of both operands and the result, that is, `u32` or `u64`.
+    ///
+    pub fn operand_type(&self) -> Type {
+        Type::integer_unsigned(self.bitlength)
+    }
+
+    pub fn call(self, actual_elements: Vec<Element>) -> Result<Type, Error> {
+        let mut actual_params = Vec::with_capacity(actual_elements.len());
+        for (index, element) in actual_elements.into_iter().enumerate() {
+            let r#type = match element {
+                Element::Value(value) => value.r#type(),
+                Element::Constant(constant) => constant.r#type(),
+                element => {
+                    return Err(Error::argument_not_evaluable(
+                        self.identifier.to_owned(),
+                        index + 1,
+                        element.to_string(),
+                    ))
+                }
+            };
+            actual_params.push(r#type);
+        }
+
+        let operand_type = self.operand_type();
+        for (index, name) in [(Self::ARGUMENT_INDEX_A, "a"), (Self::ARGUMENT_INDEX_B, "b")].iter() {
+            match actual_params.get(*index) {
+                Some(r#type) if r#type == &operand_type => {}
+                Some(r#type) => {
+                    return Err(Error::argument_type(
+                        self.identifier.to_owned(),
+                        (*name).to_owned(),
+                        *index + 1,
+                        operand_type.to_string(),
+                        r#type.to_string(),
+                    ))
+                }
+                None => {
+                    return Err(Error::argument_count(
+                        self.identifier.to_owned(),
+                        Self::ARGUMENT_COUNT,
+                        actual_params.len(),
+                    ))
+                }
+            }
+        }
+
+        if actual_params.len() > Self::ARGUMENT_COUNT {
+            return Err(Error::argument_count(
+                self.identifier.to_owned(),
+                Self::ARGUMENT_COUNT,
+                actual_params.len(),
+            ));
+        }
+
+        Ok(operand_type)
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "fn std::math::{0}(a: u{1}, b: u{1}) -> u{1}",
+            self.identifier, self.bitlength
+        )
+    }
+}
@@ -6,7 +6,11 @@ mod tests;
 
 pub mod array_pad;
 pub mod array_reverse;
+pub mod array_sort;
 pub mod array_truncate;
+pub mod assert_in_range;
+pub mod bits_count;
+pub mod bits_rotate;
 pub mod convert_from_bits_field;
 pub mod convert_from_bits_signed;
 pub mod convert_from_bits_unsigned;
@@ -17,7 +21,15 @@ pub mod crypto_pedersen;
 pub mod crypto_schnorr_signature_verify;
 pub mod crypto_sha256;
 pub mod error;
+pub mod eth_abi_decode;
+pub mod eth_abi_encode;
 pub mod ff_invert;
+pub mod math_fixed;
+pub mod math_overflowing;
+pub mod math_select;
+pub mod math_sign;
+pub mod math_wrapping;
+pub mod table_lookup;
 
 use std::fmt;
 
@@ -29,7 +41,11 @@ use crate::semantic::element::Element;
 
 use self::array_pad::Function as ArrayPadFunction;
 use self::array_reverse::Function as ArrayReverseFunction;
+use self::array_sort::Function as ArraySortFunction;
 use self::array_truncate::Function as ArrayTruncateFunction;
+use self::assert_in_range::Function as AssertInRangeFunction;
+use self::bits_count::Function as BitsCountFunction;
+use self::bits_rotate::Function as BitsRotateFunction;
 use self::convert_from_bits_field::Function as FromBitsFieldFunction;
 use self::convert_from_bits_signed::Function as FromBitsSignedFunction;
 use self::convert_from_bits_unsigned::Function as FromBitsUnsignedFunction;
@@ -39,7 +55,19 @@ use self::crypto_blake2s_multi_input::Function as Blake2sMultiInputFunction;
 use self::crypto_pedersen::Function as PedersenFunction;
 use self::crypto_schnorr_signature_verify::Function as SchnorrSignatureVerifyFunction;
 use self::crypto_sha256::Function as Sha256Function;
+use self::eth_abi_decode::Function as AbiDecodeFunction;
+use self::eth_abi_encode::Function as AbiEncodeFunction;
 use self::ff_invert::Function as FfInvertFunction;
+use self::math_fixed::Function as MathFixedFunction;
+use self::math_overflowing::Function as MathOverflowingFunction;
+use self::math_select::Function as MathSelectFunction;
+use self::math_select::Operation as MathSelectOperation;
+use self::math_sign::Function as MathSignFunction;
+use self::math_wrapping::Function as MathWrappingFunction;
+use self::table_lookup::Function as TableLookupFunction;
+
+static PANIC_MATH_SELECT_BY_OPERATION: &str =
+    "The select functions are only created by the operation";
 
 #[derive(Debug, Clone)]
 pub enum Function {
@@ -54,11 +82,28 @@ pub enum Function {
     ConvertFromBitsSigned(FromBitsSignedFunction),
     ConvertFromBitsField(FromBitsFieldFunction),
 
+    BitsCount(BitsCountFunction),
+    BitsRotate(BitsRotateFunction),
+
     ArrayReverse(ArrayReverseFunction),
     ArrayTruncate(ArrayTruncateFunction),
     ArrayPad(ArrayPadFunction),
+    ArraySort(ArraySortFunction),
 
     FfInvert(FfInvertFunction),
+
+    EthAbiEncode(AbiEncodeFunction),
+    EthAbiDecode(AbiDecodeFunction),
+
+    MathOverflowing(MathOverflowingFunction),
+    MathFixed(MathFixedFunction),
+    MathSelect(MathSelectFunction),
+    MathSign(MathSignFunction),
+    MathWrapping(MathWrappingFunction),
+
+    TableLookup(TableLookupFunction),
+
+    AssertInRange(AssertInRangeFunction),
 }
 
 impl Function {
@@ -89,6 +134,13 @@ impl Function {
                 Self::ConvertFromBitsField(FromBitsFieldFunction::new(identifier))
             }
 
+            BuiltinIdentifier::BitsCountOnes | BuiltinIdentifier::BitsLeadingZeros => {
+                Self::BitsCount(BitsCountFunction::new(identifier))
+            }
+            BuiltinIdentifier::BitsRotateLeft | BuiltinIdentifier::BitsRotateRight => {
+                Self::BitsRotate(BitsRotateFunction::new(identifier))
+            }
+
             BuiltinIdentifier::ArrayReverse => {
                 Self::ArrayReverse(ArrayReverseFunction::new(identifier))
             }
@@ -96,11 +148,56 @@ impl Function {
                 Self::ArrayTruncate(ArrayTruncateFunction::new(identifier))
             }
             BuiltinIdentifier::ArrayPad => Self::ArrayPad(ArrayPadFunction::new(identifier)),
+            BuiltinIdentifier::ArraySort => Self::ArraySort(ArraySortFunction::new(identifier)),
 
             BuiltinIdentifier::FieldInverse => Self::FfInvert(FfInvertFunction::new(identifier)),
+
+            BuiltinIdentifier::EthAbiEncode => {
+                Self::EthAbiEncode(AbiEncodeFunction::new(identifier))
+            }
+            BuiltinIdentifier::EthAbiDecode => {
+                Self::EthAbiDecode(AbiDecodeFunction::new(identifier))
+            }
+
+            BuiltinIdentifier::MathOverflowingAdd
+            | BuiltinIdentifier::MathOverflowingSub
+            | BuiltinIdentifier::MathOverflowingMul => {
+                Self::MathOverflowing(MathOverflowingFunction::new(identifier))
+            }
+            BuiltinIdentifier::MathFixedMul | BuiltinIdentifier::MathFixedDiv => {
+                Self::MathFixed(MathFixedFunction::new(identifier))
+            }
+            BuiltinIdentifier::MathSelect => {
+                panic!("{}", PANIC_MATH_SELECT_BY_OPERATION)
+            }
+            BuiltinIdentifier::MathSign => Self::MathSign(MathSignFunction::new(identifier)),
+            BuiltinIdentifier::MathAddMod32
+            | BuiltinIdentifier::MathSubMod32
+            | BuiltinIdentifier::MathMulMod32
+            | BuiltinIdentifier::MathAddMod64
+            | BuiltinIdentifier::MathSubMod64
+            | BuiltinIdentifier::MathMulMod64 => {
+                Self::MathWrapping(MathWrappingFunction::new(identifier))
+            }
+
+            BuiltinIdentifier::TableLookup => {
+                Self::TableLookup(TableLookupFunction::new(identifier))
+            }
+
+            BuiltinIdentifier::AssertInRange => {
+                Self::AssertInRange(AssertInRangeFunction::new(identifier))
+            }
         }
     }
 
+    ///
+    /// The `std::math` select functions share a built-in function, so they are created
+    /// by the operation instead of the identifier.
+    ///
+    pub fn new_math_select(operation: MathSelectOperation) -> Self {
+        Self::MathSelect(MathSelectFunction::new(operation))
+    }
+
     pub fn call(self, elements: Vec<Element>) -> Result<Type, Error> {
         match self {
             Self::CryptoSha256(inner) => inner.call(elements),
@@ -114,11 +211,28 @@ impl Function {
             Self::ConvertFromBitsSigned(inner) => inner.call(elements),
             Self::ConvertFromBitsField(inner) => inner.call(elements),
 
+            Self::BitsCount(inner) => inner.call(elements),
+            Self::BitsRotate(inner) => inner.call(elements),
+
             Self::ArrayReverse(inner) => inner.call(elements),
             Self::ArrayTruncate(inner) => inner.call(elements),
             Self::ArrayPad(inner) => inner.call(elements),
+            Self::ArraySort(inner) => inner.call(elements),
 
             Self::FfInvert(inner) => inner.call(elements),
+
+            Self::EthAbiEncode(inner) => inner.call(elements),
+            Self::EthAbiDecode(inner) => inner.call(elements),
+
+            Self::MathOverflowing(inner) => inner.call(elements),
+            Self::MathFixed(inner) => inner.call(elements),
+            Self::MathSelect(inner) => inner.call(elements),
+            Self::MathSign(inner) => inner.call(elements),
+            Self::MathWrapping(inner) => inner.call(elements),
+
+            Self::TableLookup(inner) => inner.call(elements),
+
+            Self::AssertInRange(inner) => inner.call(elements),
         }
     }
 
@@ -135,11 +249,28 @@ impl Function {
             Self::ConvertFromBitsSigned(inner) => inner.identifier(),
             Self::ConvertFromBitsField(inner) => inner.identifier(),
 
+            Self::BitsCount(inner) => inner.identifier(),
+            Self::BitsRotate(inner) => inner.identifier(),
+
             Self::ArrayReverse(inner) => inner.identifier(),
             Self::ArrayTruncate(inner) => inner.identifier(),
             Self::ArrayPad(inner) => inner.identifier(),
+            Self::ArraySort(inner) => inner.identifier(),
 
             Self::FfInvert(inner) => inner.identifier(),
+
+            Self::EthAbiEncode(inner) => inner.identifier(),
+            Self::EthAbiDecode(inner) => inner.identifier(),
+
+            Self::MathOverflowing(inner) => inner.identifier(),
+            Self::MathFixed(inner) => inner.identifier(),
+            Self::MathSelect(inner) => inner.identifier(),
+            Self::MathSign(inner) => inner.identifier(),
+            Self::MathWrapping(inner) => inner.identifier(),
+
+            Self::TableLookup(inner) => inner.identifier(),
+
+            Self::AssertInRange(inner) => inner.identifier(),
         }
     }
 
@@ -156,11 +287,28 @@ impl Function {
             Self::ConvertFromBitsSigned(inner) => inner.builtin_identifier(),
             Self::ConvertFromBitsField(inner) => inner.builtin_identifier(),
 
+            Self::BitsCount(inner) => inner.builtin_identifier(),
+            Self::BitsRotate(inner) => inner.builtin_identifier(),
+
             Self::ArrayReverse(inner) => inner.builtin_identifier(),
             Self::ArrayTruncate(inner) => inner.builtin_identifier(),
             Self::ArrayPad(inner) => inner.builtin_identifier(),
+            Self::ArraySort(inner) => inner.builtin_identifier(),
 
             Self::FfInvert(inner) => inner.builtin_identifier(),
+
+            Self::EthAbiEncode(inner) => inner.builtin_identifier(),
+            Self::EthAbiDecode(inner) => inner.builtin_identifier(),
+
+            Self::MathOverflowing(inner) => inner.builtin_identifier(),
+            Self::MathFixed(inner) => inner.builtin_identifier(),
+            Self::MathSelect(inner) => inner.builtin_identifier(),
+            Self::MathSign(inner) => inner.builtin_identifier(),
+            Self::MathWrapping(inner) => inner.builtin_identifier(),
+
+            Self::TableLookup(inner) => inner.builtin_identifier(),
+
+            Self::AssertInRange(inner) => inner.builtin_identifier(),
         }
     }
 }
@@ -179,11 +327,28 @@ impl fmt::Display for Function {
             Self::ConvertFromBitsSigned(inner) => write!(f, "{}", inner),
             Self::ConvertFromBitsField(inner) => write!(f, "{}", inner),
 
+            Self::BitsCount(inner) => write!(f, "{}", inner),
+            Self::BitsRotate(inner) => write!(f, "{}", inner),
+
             Self::ArrayReverse(inner) => write!(f, "{}", inner),
             Self::ArrayTruncate(inner) => write!(f, "{}", inner),
             Self::ArrayPad(inner) => write!(f, "{}", inner),
+            Self::ArraySort(inner) => write!(f, "{}", inner),
 
             Self::FfInvert(inner) => write!(f, "{}", inner),
+
+            Self::EthAbiEncode(inner) => write!(f, "{}", inner),
+            Self::EthAbiDecode(inner) => write!(f, "{}", inner),
+
+            Self::MathOverflowing(inner) => write!(f, "{}", inner),
+            Self::MathFixed(inner) => write!(f, "{}", inner),
+            Self::MathSelect(inner) => write!(f, "{}", inner),
+            Self::MathSign(inner) => write!(f, "{}", inner),
+            Self::MathWrapping(inner) => write!(f, "{}", inner),
+
+            Self::TableLookup(inner) => write!(f, "{}", inner),
+
+            Self::AssertInRange(inner) => write!(f, "{}", inner),
         }
     }
 }
@@ -0,0 +1,137 @@
+//!
+//! The semantic analyzer standard library `std::table::lookup` function element.
+//!
+
+use std::fmt;
+use std::ops::Deref;
+
+use num_bigint::BigInt;
+
+use zinc_bytecode::builtins::BuiltinIdentifier;
+
+use crate::semantic::element::constant::Constant;
+use crate::semantic::element::r#type::function::error::Error;
+use crate::semantic::element::r#type::function::stdlib::error::Error as StdlibError;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+
+///
+/// The `lookup` function, which reads a table element at a witness index with a multiplexer
+/// over the index bits.
+///
+#[derive(Debug, Clone)]
+pub struct Function {
+    builtin_identifier: BuiltinIdentifier,
+    identifier: &'static str,
+}
+
+impl Function {
+    pub const ARGUMENT_INDEX_TABLE: usize = 0;
+    pub const ARGUMENT_INDEX_INDEX: usize = 1;
+    pub const ARGUMENT_COUNT: usize = 2;
+
+    pub fn new(builtin_identifier: BuiltinIdentifier) -> Self {
+        Self {
+            builtin_identifier,
+            identifier: "lookup",
+        }
+    }
+
+    pub fn identifier(&self) -> &'static str {
+        self.identifier
+    }
+
+    pub fn builtin_identifier(&self) -> BuiltinIdentifier {
+        self.builtin_identifier
+    }
+
+    pub fn call(self, actual_elements: Vec<Element>) -> Result<Type, Error> {
+        let mut actual_params = Vec::with_capacity(actual_elements.len());
+        for (index, element) in actual_elements.into_iter().enumerate() {
+            let (r#type, number) = match element {
+                Element::Value(value) => (value.r#type(), None),
+                Element::Constant(Constant::Integer(integer)) => {
+                    (integer.r#type(), Some(integer.to_bigint()))
+                }
+                Element::Constant(constant) => (constant.r#type(), None),
+                element => {
+                    return Err(Error::argument_not_evaluable(
+                        self.identifier.to_owned(),
+                        index + 1,
+                        element.to_string(),
+                    ))
+                }
+            };
+            actual_params.push((r#type, number));
+        }
+
+        let (element_type, table_size) = match actual_params.get(Self::ARGUMENT_INDEX_TABLE) {
+            Some((Type::Array { r#type, size }, _number)) if r#type.is_scalar() && *size > 0 => {
+                (r#type.deref().to_owned(), *size)
+            }
+            Some((r#type, _number)) => {
+                return Err(Error::argument_type(
+                    self.identifier.to_owned(),
+                    "table".to_owned(),
+                    Self::ARGUMENT_INDEX_TABLE + 1,
+                    "[{scalar}; N], N > 0".to_owned(),
+                    r#type.to_string(),
+                ))
+            }
+            None => {
+                return Err(Error::argument_count(
+                    self.identifier.to_owned(),
+                    Self::ARGUMENT_COUNT,
+                    actual_params.len(),
+                ))
+            }
+        };
+
+        match actual_params.get(Self::ARGUMENT_INDEX_INDEX) {
+            Some((r#type, Some(number))) if r#type.is_scalar_unsigned() => {
+                if number >= &BigInt::from(table_size) {
+                    return Err(Error::StandardLibrary(
+                        StdlibError::table_index_out_of_range(number.to_string(), table_size),
+                    ));
+                }
+            }
+            Some((r#type, None)) if r#type.is_scalar_unsigned() => {}
+            Some((r#type, _number)) => {
+                return Err(Error::argument_type(
+                    self.identifier.to_owned(),
+                    "index".to_owned(),
+                    Self::ARGUMENT_INDEX_INDEX + 1,
+                    "{unsigned integer}".to_owned(),
+                    r#type.to_string(),
+                ))
+            }
+            None => {
+                return Err(Error::argument_count(
+                    self.identifier.to_owned(),
+                    Self::ARGUMENT_COUNT,
+                    actual_params.len(),
+                ))
+            }
+        }
+
+        if actual_params.len() > Self::ARGUMENT_COUNT {
+            return Err(Error::argument_count(
+                self.identifier.to_owned(),
+                Self::ARGUMENT_COUNT,
+                actual_params.len(),
+            ));
+        }
+
+        Ok(element_type)
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "fn std::table::{}(table: [T; N], index: u{{N}}) -> T",
+            self.identifier,
+        )
+    }
+}
@@ -15,7 +15,11 @@ use crate::semantic::element::r#type::error::Error as TypeError;
 use crate::semantic::element::r#type::function::error::Error as FunctionTypeError;
 use crate::semantic::element::r#type::function::stdlib::array_pad::Function as ArrayPadFunction;
 use crate::semantic::element::r#type::function::stdlib::array_reverse::Function as ArrayReverseFunction;
+use crate::semantic::element::r#type::function::stdlib::array_sort::Function as ArraySortFunction;
 use crate::semantic::element::r#type::function::stdlib::array_truncate::Function as ArrayTruncateFunction;
+use crate::semantic::element::r#type::function::stdlib::assert_in_range::Function as AssertInRangeFunction;
+use crate::semantic::element::r#type::function::stdlib::bits_count::Function as BitsCountFunction;
+use crate::semantic::element::r#type::function::stdlib::bits_rotate::Function as BitsRotateFunction;
 use crate::semantic::element::r#type::function::stdlib::convert_from_bits_field::Function as ConvertFromBitsFieldFunction;
 use crate::semantic::element::r#type::function::stdlib::convert_from_bits_signed::Function as ConvertFromBitsSignedFunction;
 use crate::semantic::element::r#type::function::stdlib::convert_from_bits_unsigned::Function as ConvertFromBitsUnsignedFunction;
@@ -26,7 +30,15 @@ use crate::semantic::element::r#type::function::stdlib::crypto_pedersen::Functio
 use crate::semantic::element::r#type::function::stdlib::crypto_schnorr_signature_verify::Function as CryptoSchnorrSignatureVerifyFunction;
 use crate::semantic::element::r#type::function::stdlib::crypto_sha256::Function as CryptoSha256Function;
 use crate::semantic::element::r#type::function::stdlib::error::Error as StandardLibraryFunctionTypeError;
+use crate::semantic::element::r#type::function::stdlib::eth_abi_decode::Function as EthAbiDecodeFunction;
+use crate::semantic::element::r#type::function::stdlib::eth_abi_encode::Function as EthAbiEncodeFunction;
 use crate::semantic::element::r#type::function::stdlib::ff_invert::Function as FfInvertFunction;
+use crate::semantic::element::r#type::function::stdlib::math_fixed::Function as MathFixedFunction;
+use crate::semantic::element::r#type::function::stdlib::math_overflowing::Function as MathOverflowingFunction;
+use crate::semantic::element::r#type::function::stdlib::math_select::Function as MathSelectFunction;
+use crate::semantic::element::r#type::function::stdlib::math_sign::Function as MathSignFunction;
+use crate::semantic::element::r#type::function::stdlib::math_wrapping::Function as MathWrappingFunction;
+use crate::semantic::element::r#type::function::stdlib::table_lookup::Function as TableLookupFunction;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::element::Error as ElementError;
 use crate::semantic::error::Error as SemanticError;
@@ -1402,6 +1414,65 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn ok_array_sort() {
+    let input = r#"
+fn main(a: [u8; 5], b: [i32; 7], c: [field; 1]) -> ([u8; 5], [i32; 7], [field; 1]) {
+    (std::array::sort(a), std::array::sort(b), std::array::sort(c))
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, Ok(()));
+}
+
+#[test]
+fn error_array_sort_argument_count_greater() {
+    let input = r#"
+fn main(a: [u8; 5]) {
+    std::array::sort(a, a);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 21),
+        ElementError::Type(TypeError::Function(FunctionTypeError::argument_count(
+            "sort".to_owned(),
+            ArraySortFunction::ARGUMENT_COUNT,
+            ArraySortFunction::ARGUMENT_COUNT + 1,
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_array_sort_argument_1_array_expected_comparable() {
+    let input = r#"
+fn main(a: [bool; 4]) {
+    std::array::sort(a);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 21),
+        ElementError::Type(TypeError::Function(FunctionTypeError::argument_type(
+            "sort".to_owned(),
+            "array".to_owned(),
+            ArraySortFunction::ARGUMENT_INDEX_ARRAY + 1,
+            "[{integer}; N], [field; N]".to_owned(),
+            Type::array(Type::boolean(), 4).to_string(),
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_array_truncate_argument_count_lesser() {
     let input = r#"
@@ -1498,7 +1569,7 @@ fn main() {
 fn error_array_truncate_argument_2_new_length_expected_unsigned_integer_constant() {
     let input = r#"
 fn main() {
-    let new_length = 4;
+    let mut new_length = 4;
     std::array::truncate([true; 8], new_length);
 }
 "#;
@@ -1636,7 +1707,7 @@ fn main() {
 fn error_array_pad_argument_2_new_length_expected_unsigned_integer_constant() {
     let input = r#"
 fn main() {
-    let new_length = 4;
+    let mut new_length = 4;
     std::array::pad([true; 8], new_length, false);
 }
 "#;
@@ -1797,3 +1868,865 @@ fn main() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn ok_eth_abi_encode_decode() {
+    let input = r#"
+struct Data {
+    a: u8,
+    b: [bool; 2],
+}
+
+fn main(data: Data) -> Data {
+    let bytes: [u8; 96] = std::eth::abi_encode(data);
+    std::eth::abi_decode(bytes, Data)
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_eth_abi_encode_argument_count_lesser() {
+    let input = r#"
+fn main() {
+    std::eth::abi_encode();
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 25),
+        ElementError::Type(TypeError::Function(FunctionTypeError::argument_count(
+            "abi_encode".to_owned(),
+            EthAbiEncodeFunction::ARGUMENT_COUNT,
+            EthAbiEncodeFunction::ARGUMENT_COUNT - 1,
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_eth_abi_decode_argument_count_lesser() {
+    let input = r#"
+fn main() {
+    std::eth::abi_decode([0; 32]);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 25),
+        ElementError::Type(TypeError::Function(FunctionTypeError::argument_count(
+            "abi_decode".to_owned(),
+            EthAbiDecodeFunction::ARGUMENT_COUNT,
+            EthAbiDecodeFunction::ARGUMENT_COUNT - 1,
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_eth_abi_decode_argument_1_bytes_expected_size() {
+    let input = r#"
+type Value = u64;
+
+fn main() {
+    std::eth::abi_decode([0; 16], Value);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(5, 25),
+        ElementError::Type(TypeError::Function(FunctionTypeError::argument_type(
+            "abi_decode".to_owned(),
+            "bytes".to_owned(),
+            EthAbiDecodeFunction::ARGUMENT_INDEX_BYTES + 1,
+            format!("[u8; {}]", EthAbiEncodeFunction::WORD_SIZE),
+            Type::array(Type::integer_unsigned(crate::BITLENGTH_BYTE), 16).to_string(),
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_math_overflowing() {
+    let input = r#"
+fn main(a: u64, b: u64, c: i120) -> ((u64, bool), (u64, bool), (i120, bool)) {
+    (
+        std::math::overflowing_add(a, b),
+        std::math::overflowing_sub(a, 1),
+        std::math::overflowing_mul(c, -1),
+    )
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, Ok(()));
+}
+
+#[test]
+fn error_math_overflowing_argument_count_lesser() {
+    let input = r#"
+fn main(a: u8) {
+    std::math::overflowing_add(a);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 31),
+        ElementError::Type(TypeError::Function(FunctionTypeError::argument_count(
+            "overflowing_add".to_owned(),
+            MathOverflowingFunction::ARGUMENT_COUNT,
+            MathOverflowingFunction::ARGUMENT_COUNT - 1,
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_math_overflowing_argument_1_a_expected_integer() {
+    let input = r#"
+fn main(a: field) {
+    std::math::overflowing_sub(a, a);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 31),
+        ElementError::Type(TypeError::Function(FunctionTypeError::argument_type(
+            "overflowing_sub".to_owned(),
+            "a".to_owned(),
+            MathOverflowingFunction::ARGUMENT_INDEX_A + 1,
+            "{integer}".to_owned(),
+            Type::field().to_string(),
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_math_overflowing_argument_1_a_expected_bitlength() {
+    let input = r#"
+fn main(a: u128) {
+    std::math::overflowing_mul(a, a);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 31),
+        ElementError::Type(TypeError::Function(FunctionTypeError::argument_type(
+            "overflowing_mul".to_owned(),
+            "a".to_owned(),
+            MathOverflowingFunction::ARGUMENT_INDEX_A + 1,
+            format!("{{integer}}, bitlength * 2 < {}", crate::BITLENGTH_FIELD),
+            Type::integer_unsigned(crate::BITLENGTH_BYTE * 16).to_string(),
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_math_overflowing_argument_2_b_expected_type() {
+    let input = r#"
+fn main(a: u8, b: u16) {
+    std::math::overflowing_add(a, b);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 31),
+        ElementError::Type(TypeError::Function(FunctionTypeError::argument_type(
+            "overflowing_add".to_owned(),
+            "b".to_owned(),
+            MathOverflowingFunction::ARGUMENT_INDEX_B + 1,
+            Type::integer_unsigned(crate::BITLENGTH_BYTE).to_string(),
+            Type::integer_unsigned(crate::BITLENGTH_BYTE * 2).to_string(),
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_math_fixed() {
+    let input = r#"
+const FRAC_BITS: u8 = 8;
+
+fn main(a: u16, b: i64) -> (u16, i64) {
+    (
+        std::math::fixed_mul(a, 384, FRAC_BITS, std::math::Rounding::Nearest),
+        std::math::fixed_div(b, -1, 64, std::math::Rounding::Floor),
+    )
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, Ok(()));
+}
+
+#[test]
+fn error_math_fixed_argument_count_lesser() {
+    let input = r#"
+fn main(a: u16, b: u16) {
+    std::math::fixed_mul(a, b, 8);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 25),
+        ElementError::Type(TypeError::Function(FunctionTypeError::argument_count(
+            "fixed_mul".to_owned(),
+            MathFixedFunction::ARGUMENT_COUNT,
+            MathFixedFunction::ARGUMENT_COUNT - 1,
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_math_fixed_argument_2_b_expected_type() {
+    let input = r#"
+fn main(a: u16, b: i16) {
+    std::math::fixed_div(a, b, 8, std::math::Rounding::Floor);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 25),
+        ElementError::Type(TypeError::Function(FunctionTypeError::argument_type(
+            "fixed_div".to_owned(),
+            "b".to_owned(),
+            MathFixedFunction::ARGUMENT_INDEX_B + 1,
+            Type::integer_unsigned(crate::BITLENGTH_BYTE * 2).to_string(),
+            Type::integer_signed(crate::BITLENGTH_BYTE * 2).to_string(),
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_math_fixed_argument_3_frac_bits_expected_unsigned_integer_constant() {
+    let input = r#"
+fn main(a: u16, b: u16, frac_bits: u8) {
+    std::math::fixed_mul(a, b, frac_bits, std::math::Rounding::Floor);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 25),
+        ElementError::Type(TypeError::Function(
+            FunctionTypeError::argument_constantness(
+                "fixed_mul".to_owned(),
+                "frac_bits".to_owned(),
+                MathFixedFunction::ARGUMENT_INDEX_FRAC_BITS + 1,
+                Type::integer_unsigned(crate::BITLENGTH_BYTE).to_string(),
+            ),
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_math_fixed_argument_4_rounding_expected_rounding() {
+    let input = r#"
+fn main(a: u16, b: u16) {
+    std::math::fixed_mul(a, b, 8, 0);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 25),
+        ElementError::Type(TypeError::Function(FunctionTypeError::argument_type(
+            "fixed_mul".to_owned(),
+            "rounding".to_owned(),
+            MathFixedFunction::ARGUMENT_INDEX_ROUNDING + 1,
+            "std::math::Rounding".to_owned(),
+            Type::integer_unsigned(crate::BITLENGTH_BYTE).to_string(),
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_math_fixed_fractional_bits_invalid() {
+    let input = r#"
+fn main(a: u16, b: u16) {
+    std::math::fixed_mul(a, b, 17, std::math::Rounding::Floor);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 25),
+        ElementError::Type(TypeError::Function(FunctionTypeError::StandardLibrary(
+            StandardLibraryFunctionTypeError::math_fractional_bits_invalid(
+                "17".to_owned(),
+                crate::BITLENGTH_BYTE * 2,
+            ),
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_math_select() {
+    let input = r#"
+fn main(a: u8, b: u8, c: i16, d: field) -> (u8, u8, i16, i16, field) {
+    (
+        std::math::min(a, b),
+        std::math::max(a, 42),
+        std::math::clamp(c, -10, 10),
+        std::math::abs(c),
+        std::math::max(d, 1 as field),
+    )
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, Ok(()));
+}
+
+#[test]
+fn error_math_select_argument_count_greater() {
+    let input = r#"
+fn main(a: u8) {
+    std::math::min(a, a, a);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 19),
+        ElementError::Type(TypeError::Function(FunctionTypeError::argument_count(
+            "min".to_owned(),
+            2,
+            3,
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_math_select_argument_1_value_expected_signed_integer() {
+    let input = r#"
+fn main(a: u8) {
+    std::math::abs(a);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 19),
+        ElementError::Type(TypeError::Function(FunctionTypeError::argument_type(
+            "abs".to_owned(),
+            "value".to_owned(),
+            MathSelectFunction::ARGUMENT_INDEX_VALUE + 1,
+            "{signed integer}".to_owned(),
+            Type::integer_unsigned(crate::BITLENGTH_BYTE).to_string(),
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_math_select_argument_1_a_expected_scalar() {
+    let input = r#"
+fn main(a: bool) {
+    std::math::max(a, a);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 19),
+        ElementError::Type(TypeError::Function(FunctionTypeError::argument_type(
+            "max".to_owned(),
+            "a".to_owned(),
+            MathSelectFunction::ARGUMENT_INDEX_VALUE + 1,
+            "{integer}, field".to_owned(),
+            Type::boolean().to_string(),
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_math_select_argument_3_high_expected_type() {
+    let input = r#"
+fn main(value: u8, low: u8, high: u16) {
+    std::math::clamp(value, low, high);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 21),
+        ElementError::Type(TypeError::Function(FunctionTypeError::argument_type(
+            "clamp".to_owned(),
+            "high".to_owned(),
+            3,
+            Type::integer_unsigned(crate::BITLENGTH_BYTE).to_string(),
+            Type::integer_unsigned(crate::BITLENGTH_BYTE * 2).to_string(),
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_math_sign() {
+    let input = r#"
+const SIGN: i8 = std::math::sign(-42 as i64);
+
+fn main(a: i16) -> (i8, i8) {
+    (std::math::sign(a), SIGN)
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, Ok(()));
+}
+
+#[test]
+fn error_math_sign_argument_1_value_expected_signed_integer() {
+    let input = r#"
+fn main(a: u16) {
+    std::math::sign(a);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 20),
+        ElementError::Type(TypeError::Function(FunctionTypeError::argument_type(
+            "sign".to_owned(),
+            "value".to_owned(),
+            MathSignFunction::ARGUMENT_INDEX_VALUE + 1,
+            "{signed integer}".to_owned(),
+            Type::integer_unsigned(crate::BITLENGTH_BYTE * 2).to_string(),
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_table_lookup() {
+    let input = r#"
+fn main(index: u8, table: [i16; 8]) -> (field, i16, i16) {
+    (
+        std::table::lookup([1 as field, 2 as field, 4 as field], index),
+        std::table::lookup(table, index),
+        std::table::lookup(table, 7),
+    )
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, Ok(()));
+}
+
+#[test]
+fn error_table_lookup_argument_1_table_expected_non_empty() {
+    let input = r#"
+fn main(index: u8, table: [field; 0]) {
+    std::table::lookup(table, index);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 23),
+        ElementError::Type(TypeError::Function(FunctionTypeError::argument_type(
+            "lookup".to_owned(),
+            "table".to_owned(),
+            TableLookupFunction::ARGUMENT_INDEX_TABLE + 1,
+            "[{scalar}; N], N > 0".to_owned(),
+            Type::array(Type::field(), 0).to_string(),
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_table_lookup_argument_2_index_expected_unsigned_integer() {
+    let input = r#"
+fn main(index: i8, table: [field; 4]) {
+    std::table::lookup(table, index);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 23),
+        ElementError::Type(TypeError::Function(FunctionTypeError::argument_type(
+            "lookup".to_owned(),
+            "index".to_owned(),
+            TableLookupFunction::ARGUMENT_INDEX_INDEX + 1,
+            "{unsigned integer}".to_owned(),
+            Type::integer_signed(crate::BITLENGTH_BYTE).to_string(),
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_table_lookup_index_out_of_range() {
+    let input = r#"
+fn main(table: [field; 4]) {
+    std::table::lookup(table, 4);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 23),
+        ElementError::Type(TypeError::Function(FunctionTypeError::StandardLibrary(
+            StandardLibraryFunctionTypeError::table_index_out_of_range("4".to_owned(), 4),
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_assert_in_range() {
+    let input = r#"
+fn main(value: u8, signed: i16) {
+    std::assert_in_range(value, 10, 200);
+    std::assert_in_range(signed, -1000, 1000);
+    std::assert_in_range(value, 42, 42);
+    std::assert_in_range(5, 0, 10);
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, Ok(()));
+}
+
+#[test]
+fn error_assert_in_range_argument_1_value_expected_integer() {
+    let input = r#"
+fn main(value: field) {
+    std::assert_in_range(value, 0, 10);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 25),
+        ElementError::Type(TypeError::Function(FunctionTypeError::argument_type(
+            "assert_in_range".to_owned(),
+            "value".to_owned(),
+            AssertInRangeFunction::ARGUMENT_INDEX_VALUE + 1,
+            "{integer}".to_owned(),
+            Type::field().to_string(),
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_assert_in_range_argument_2_low_expected_constant() {
+    let input = r#"
+fn main(value: u8, low: u8) {
+    std::assert_in_range(value, low, 10);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 25),
+        ElementError::Type(TypeError::Function(
+            FunctionTypeError::argument_constantness(
+                "assert_in_range".to_owned(),
+                "low".to_owned(),
+                AssertInRangeFunction::ARGUMENT_INDEX_LOW + 1,
+                Type::integer_unsigned(crate::BITLENGTH_BYTE).to_string(),
+            ),
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_assert_in_range_argument_3_high_expected_value_type() {
+    let input = r#"
+fn main(value: u8) {
+    std::assert_in_range(value, 0, true);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 25),
+        ElementError::Type(TypeError::Function(FunctionTypeError::argument_type(
+            "assert_in_range".to_owned(),
+            "high".to_owned(),
+            AssertInRangeFunction::ARGUMENT_INDEX_HIGH + 1,
+            Type::integer_unsigned(crate::BITLENGTH_BYTE).to_string(),
+            Type::boolean().to_string(),
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_assert_in_range_range_empty() {
+    let input = r#"
+fn main(value: u8) {
+    std::assert_in_range(value, 10, 5);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 25),
+        ElementError::Type(TypeError::Function(FunctionTypeError::StandardLibrary(
+            StandardLibraryFunctionTypeError::assert_range_empty("10".to_owned(), "5".to_owned()),
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_assert_in_range_value_out_of_range() {
+    let input = r#"
+fn main() {
+    std::assert_in_range(42, 0, 10);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 25),
+        ElementError::Type(TypeError::Function(FunctionTypeError::StandardLibrary(
+            StandardLibraryFunctionTypeError::assert_value_out_of_range(
+                "42".to_owned(),
+                "0..=10".to_owned(),
+            ),
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_bits() {
+    let input = r#"
+fn main(value: u32) -> (u8, u8, u32, u32) {
+    (
+        std::bits::count_ones(value),
+        std::bits::leading_zeros(value),
+        std::bits::rotl(value, 7),
+        std::bits::rotr(value, 35),
+    )
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, Ok(()));
+}
+
+#[test]
+fn error_bits_count_ones_argument_1_value_expected_unsigned_integer() {
+    let input = r#"
+fn main(value: i32) {
+    std::bits::count_ones(value);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 26),
+        ElementError::Type(TypeError::Function(FunctionTypeError::argument_type(
+            "count_ones".to_owned(),
+            "value".to_owned(),
+            BitsCountFunction::ARGUMENT_INDEX_VALUE + 1,
+            "{unsigned integer}".to_owned(),
+            Type::integer_signed(crate::BITLENGTH_BYTE * 4).to_string(),
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_bits_rotl_argument_1_value_expected_unsigned_integer() {
+    let input = r#"
+fn main(value: field) {
+    std::bits::rotl(value, 1);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 20),
+        ElementError::Type(TypeError::Function(FunctionTypeError::argument_type(
+            "rotl".to_owned(),
+            "value".to_owned(),
+            BitsRotateFunction::ARGUMENT_INDEX_VALUE + 1,
+            "{unsigned integer}".to_owned(),
+            Type::field().to_string(),
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_bits_rotr_argument_2_shift_expected_constant() {
+    let input = r#"
+fn main(value: u32, shift: u8) {
+    std::bits::rotr(value, shift);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 20),
+        ElementError::Type(TypeError::Function(
+            FunctionTypeError::argument_constantness(
+                "rotr".to_owned(),
+                "shift".to_owned(),
+                BitsRotateFunction::ARGUMENT_INDEX_SHIFT + 1,
+                Type::integer_unsigned(crate::BITLENGTH_BYTE).to_string(),
+            ),
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_math_wrapping() {
+    let input = r#"
+fn main(a: u32, b: u64) -> (u32, u32, u32, u64, u64, u64) {
+    (
+        std::math::add_mod32(a, 0xffffffff),
+        std::math::sub_mod32(1, a),
+        std::math::mul_mod32(a, a),
+        std::math::add_mod64(b, b),
+        std::math::sub_mod64(0, 1),
+        std::math::mul_mod64(b, 42),
+    )
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, Ok(()));
+}
+
+#[test]
+fn error_math_wrapping_argument_1_a_expected_operand_type() {
+    let input = r#"
+fn main(a: u64, b: u32) {
+    std::math::add_mod32(a, b);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 25),
+        ElementError::Type(TypeError::Function(FunctionTypeError::argument_type(
+            "add_mod32".to_owned(),
+            "a".to_owned(),
+            MathWrappingFunction::ARGUMENT_INDEX_A + 1,
+            Type::integer_unsigned(crate::BITLENGTH_BYTE * 4).to_string(),
+            Type::integer_unsigned(crate::BITLENGTH_BYTE * 8).to_string(),
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_math_wrapping_argument_2_b_expected_operand_type() {
+    let input = r#"
+fn main(a: u64, b: i64) {
+    std::math::mul_mod64(a, b);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 25),
+        ElementError::Type(TypeError::Function(FunctionTypeError::argument_type(
+            "mul_mod64".to_owned(),
+            "b".to_owned(),
+            MathWrappingFunction::ARGUMENT_INDEX_B + 1,
+            Type::integer_unsigned(crate::BITLENGTH_BYTE * 8).to_string(),
+            Type::integer_signed(crate::BITLENGTH_BYTE * 8).to_string(),
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
@@ -66,12 +66,68 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn ok_argument_constant_coerced() {
+    let input = r#"
+struct Data {
+    value: u64,
+}
+
+impl Data {
+    fn add(self, other: u64) -> u64 {
+        self.value + other
+    }
+}
+
+fn another(a: u64, b: field, c: i16) -> u64 {
+    a
+}
+
+fn main() -> (u64, u64) {
+    let data = Data { value: 42 as u64 };
+    (another(5, 7, -3), data.add(5))
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, Ok(()));
+}
+
+#[test]
+fn error_argument_constant_not_coerced() {
+    let input = r#"
+fn another(x: u8) -> u8 {
+    42
+}
+
+fn main() {
+    let value = another(300);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(7, 24),
+        ElementError::Type(TypeError::Function(FunctionTypeError::argument_type(
+            "another".to_owned(),
+            "x".to_owned(),
+            1,
+            Type::integer_unsigned(crate::BITLENGTH_BYTE).to_string(),
+            Type::integer_unsigned(crate::BITLENGTH_BYTE * 2).to_string(),
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_argument_constantness() {
     let input = r#"
 fn main() -> [u8; 2] {
     let array = [1, 2, 3, 4];
-    let new_length = 2;
+    let mut new_length = 2;
     std::array::truncate(array, new_length)
 }
 "#;
@@ -186,7 +242,7 @@ impl Data {
 }
 
 fn main() {
-    let data = Data { value: 42 };
+    let data = Data { value: 42 as u64 };
 }
 "#;
 
@@ -205,3 +261,237 @@ fn main() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn ok_enumeration_methods() {
+    let input = r#"
+enum Color {
+    Red = 1,
+    Green = 2,
+    Blue = 4,
+}
+
+impl Color {
+    fn is_red(self) -> bool {
+        self == Color::Red
+    }
+}
+
+fn main(value: u8) -> (bool, bool, u8) {
+    let color = Color::from_value(value);
+    (color.is_red(), Color::Green.is_red(), Color::variant_count())
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, Ok(()));
+}
+
+#[test]
+fn ok_enumeration_try_from() {
+    let input = r#"
+enum Color {
+    Red = 1,
+    Green = 2,
+}
+
+fn main(value: u8) -> (Color, bool) {
+    let invalid = Color::try_from(3);
+    Color::try_from(value)
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, Ok(()));
+}
+
+#[test]
+fn error_enumeration_value_invalid() {
+    let input = r#"
+enum Color {
+    Red = 1,
+    Green = 2,
+}
+
+fn main() {
+    let color = Color::from_value(3);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(8, 34),
+        ElementError::Type(TypeError::Function(
+            FunctionTypeError::enumeration_value_invalid("Color".to_owned(), "3".to_owned()),
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_enumeration_from_value_argument_type() {
+    let input = r#"
+enum Color {
+    Red = 1,
+    Green = 2,
+}
+
+fn main() {
+    let color = Color::from_value(true);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(8, 34),
+        ElementError::Type(TypeError::Function(FunctionTypeError::argument_type(
+            "Color::from_value".to_owned(),
+            "value".to_owned(),
+            1,
+            "{integer}".to_owned(),
+            Type::boolean().to_string(),
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_generic_const_parameter_inferred() {
+    let input = r#"
+struct Chunk {
+    value: u8,
+}
+
+impl Chunk {
+    fn hash<const N: u64>(self, data: [(u8, [bool; N]); 2]) -> u8 {
+        self.value + N as u8
+    }
+}
+
+fn sum<const N: u64, const M: u8>(values: [u8; N], flags: [bool; M]) -> u8 {
+    let mut result: u8 = 0;
+    for i in 0..N {
+        result += values[i];
+    }
+    result + M
+}
+
+fn main(data: [u8; 8]) -> (u8, u8, u8) {
+    let chunk = Chunk { value: 1 };
+    let flags = [(1, [true; 3]), (2, [false; 3])];
+    (sum(data[0..4], [true]), sum(data, [false; 2]), chunk.hash(flags))
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, Ok(()));
+}
+
+#[test]
+fn error_generic_const_parameter_not_inferred() {
+    let input = r#"
+fn another<const N: u64>(value: u8) -> u8 {
+    value
+}
+
+fn main() {
+    let value = another(42);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(7, 24),
+        ElementError::Type(TypeError::Function(
+            FunctionTypeError::const_parameter_not_inferred("another".to_owned(), "N".to_owned()),
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_generic_const_parameter_expected_integer() {
+    let input = r#"
+fn another<const N: bool>(values: [u8; N]) -> u8 {
+    42
+}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(2, 21),
+        ElementError::Type(TypeError::Function(
+            FunctionTypeError::const_parameter_expected_integer(
+                "another".to_owned(),
+                "N".to_owned(),
+                Type::boolean().to_string(),
+            ),
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_generic_const_parameter_recursive_instance() {
+    let input = r#"
+fn another<const N: u64>(values: [u8; N]) -> u8 {
+    another(values)
+}
+
+fn main() {
+    let value = another([1, 2, 3]);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 12),
+        ElementError::Type(TypeError::Function(
+            FunctionTypeError::const_parameter_recursive_instance("another".to_owned()),
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_generic_argument_type() {
+    let input = r#"
+fn another<const N: u64>(values: [u8; N]) -> u8 {
+    42
+}
+
+fn main() {
+    let value = another([true, false]);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(7, 24),
+        ElementError::Type(TypeError::Function(FunctionTypeError::argument_type(
+            "another<2>".to_owned(),
+            "values".to_owned(),
+            1,
+            Type::array(Type::integer_unsigned(crate::BITLENGTH_BYTE), 2).to_string(),
+            Type::array(Type::boolean(), 2).to_string(),
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
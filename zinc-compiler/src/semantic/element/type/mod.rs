@@ -10,24 +10,24 @@ pub mod function;
 pub mod structure;
 
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::convert::TryFrom;
 use std::fmt;
 use std::rc::Rc;
-use std::sync::RwLock;
 
-use lazy_static::lazy_static;
+use sha2::Digest;
+use sha2::Sha256;
 
 use zinc_bytecode::builtins::BuiltinIdentifier;
 
 use crate::semantic::analyzer::expression::hint::Hint as TranslationHint;
 use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
 use crate::semantic::element::constant::error::Error as ConstantError;
+use crate::semantic::element::constant::integer::Integer as IntegerConstant;
 use crate::semantic::element::constant::Constant;
 use crate::semantic::element::error::Error as ElementError;
 use crate::semantic::element::r#type::error::Error as TypeError;
 use crate::semantic::element::Element;
 use crate::semantic::error::Error;
-use crate::semantic::scope::builtin::BuiltInItems;
 use crate::semantic::scope::item::variant::Variant as ScopeItemVariant;
 use crate::semantic::scope::Scope;
 use crate::syntax::tree::identifier::Identifier;
@@ -38,19 +38,22 @@ use self::enumeration::Enumeration;
 use self::function::Function;
 use self::structure::Structure;
 
-lazy_static! {
-    pub static ref INDEX: RwLock<HashMap<usize, String>> = {
-        let mut index = HashMap::with_capacity(BuiltInItems::TYPE_ID_FIRST_AVAILABLE);
-        index.insert(
-            BuiltInItems::TYPE_ID_STD_CRYPTO_ECC_POINT,
-            "struct std::crypto::ecc::Point".to_owned(),
-        );
-        index.insert(
-            BuiltInItems::TYPE_ID_STD_CRYPTO_SCHNORR_SIGNATURE,
-            "struct std::crypto::schnorr::Signature".to_owned(),
-        );
-        RwLock::new(index)
-    };
+///
+/// Derives the unique ID of a user-defined type from its `path`, which consists of the module
+/// and implementation names and the type identifier, and its `signature`.
+///
+/// The ID does not depend on the declaration order or any global state, so the same source code
+/// always yields the same IDs.
+///
+pub fn unique_id(path: &[String], signature: &str) -> usize {
+    let mut hasher = Sha256::new();
+    hasher.input(path.join("::").as_bytes());
+    hasher.input(b"\0");
+    hasher.input(signature.as_bytes());
+
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&hasher.result()[..8]);
+    u64::from_le_bytes(bytes) as usize
 }
 
 ///
@@ -68,6 +71,11 @@ pub enum Type {
     IntegerSigned { bitlength: usize },
     /// the `field` type
     Field,
+    /// the `Decimal<I, F>` type, which is an unsigned `I + F`-bit integer scaled by `2^F`
+    Decimal {
+        integer_bits: usize,
+        fraction_bits: usize,
+    },
     /// the compile-time only type used mostly for `dbg!` format strings and `assert!` messages
     String,
     /// the compile-time only type used for loop bounds and array slicing
@@ -121,6 +129,13 @@ impl Type {
         Self::Field
     }
 
+    pub fn decimal(integer_bits: usize, fraction_bits: usize) -> Self {
+        Self::Decimal {
+            integer_bits,
+            fraction_bits,
+        }
+    }
+
     pub fn scalar(is_signed: bool, bitlength: usize) -> Self {
         if is_signed {
             Self::integer_signed(bitlength)
@@ -203,6 +218,7 @@ impl Type {
             Self::IntegerUnsigned { .. } => 1,
             Self::IntegerSigned { .. } => 1,
             Self::Field => 1,
+            Self::Decimal { .. } => 1,
             Self::String { .. } => 0,
             Self::Range { .. } => 0,
             Self::RangeInclusive { .. } => 0,
@@ -224,6 +240,7 @@ impl Type {
             Self::IntegerUnsigned { .. } => true,
             Self::IntegerSigned { .. } => true,
             Self::Field => true,
+            Self::Decimal { .. } => true,
             Self::Enumeration { .. } => true,
             _ => false,
         }
@@ -276,6 +293,46 @@ impl Type {
             TypeVariant::IntegerUnsigned { bitlength } => Self::integer_unsigned(*bitlength),
             TypeVariant::IntegerSigned { bitlength } => Self::integer_signed(*bitlength),
             TypeVariant::Field => Self::field(),
+            TypeVariant::Decimal {
+                integer_bits,
+                fraction_bits,
+            } => {
+                let location = integer_bits.location;
+                let integer_bits = IntegerConstant::try_from(integer_bits)
+                    .and_then(|constant| constant.to_usize())
+                    .map_err(|error| {
+                        Error::Element(
+                            location,
+                            ElementError::Constant(ConstantError::Integer(error)),
+                        )
+                    })?;
+                let fraction_bits = IntegerConstant::try_from(fraction_bits)
+                    .and_then(|constant| constant.to_usize())
+                    .map_err(|error| {
+                        Error::Element(
+                            fraction_bits.location,
+                            ElementError::Constant(ConstantError::Integer(error)),
+                        )
+                    })?;
+
+                // the scale `2^F` must fit into the decimal, and the product of two decimals
+                // must fit into the field before it is scaled down
+                let bitlength = integer_bits.saturating_add(fraction_bits);
+                if integer_bits == 0
+                    || bitlength % crate::BITLENGTH_BYTE != 0
+                    || bitlength >= crate::BITLENGTH_FIELD / 2
+                {
+                    return Err(Error::Element(
+                        location,
+                        ElementError::Type(TypeError::DecimalBitlengthInvalid {
+                            integer_bits,
+                            fraction_bits,
+                        }),
+                    ));
+                }
+
+                Self::decimal(integer_bits, fraction_bits)
+            }
             TypeVariant::Array { inner, size } => {
                 let r#type = Self::from_type_variant(&*inner, scope.clone())?;
 
@@ -356,6 +413,16 @@ impl PartialEq<Type> for Type {
                 b1 == b2
             }
             (Self::Field, Self::Field) => true,
+            (
+                Self::Decimal {
+                    integer_bits: integer_bits_1,
+                    fraction_bits: fraction_bits_1,
+                },
+                Self::Decimal {
+                    integer_bits: integer_bits_2,
+                    fraction_bits: fraction_bits_2,
+                },
+            ) => integer_bits_1 == integer_bits_2 && fraction_bits_1 == fraction_bits_2,
             (Self::String, Self::String) => true,
             (Self::Range { r#type: type_1 }, Self::Range { r#type: type_2 }) => type_1 == type_2,
             (Self::RangeInclusive { r#type: type_1 }, Self::RangeInclusive { r#type: type_2 }) => {
@@ -391,6 +458,10 @@ impl fmt::Display for Type {
             Self::IntegerUnsigned { bitlength } => write!(f, "u{}", bitlength),
             Self::IntegerSigned { bitlength } => write!(f, "i{}", bitlength),
             Self::Field => write!(f, "field"),
+            Self::Decimal {
+                integer_bits,
+                fraction_bits,
+            } => write!(f, "Decimal<{}, {}>", integer_bits, fraction_bits),
             Self::String => write!(f, "str"),
             Self::Range { r#type } => write!(f, "{0}..{0}", r#type),
             Self::RangeInclusive { r#type } => write!(f, "{0}..={0}", r#type),
@@ -65,3 +65,81 @@ fn main() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn ok_unique_id_is_derived_from_path_and_signature() {
+    let path = vec!["module".to_owned(), "Data".to_owned()];
+    let signature = "struct { value: u8 }";
+
+    assert_eq!(
+        super::unique_id(path.as_slice(), signature),
+        super::unique_id(path.as_slice(), signature),
+    );
+    assert_ne!(
+        super::unique_id(path.as_slice(), signature),
+        super::unique_id(&["Data".to_owned()], signature),
+    );
+    assert_ne!(
+        super::unique_id(path.as_slice(), signature),
+        super::unique_id(path.as_slice(), "struct { value: u16 }"),
+    );
+}
+
+#[test]
+fn ok_decimal() {
+    let input = r#"
+fn main(a: Decimal<32, 16>, b: Decimal<32, 16>) -> (Decimal<32, 16>, Decimal<32, 16>) {
+    let one: Decimal<32, 16> = 1;
+    let mut sum = a + b - one;
+    sum *= a;
+    sum /= b;
+
+    (
+        sum,
+        std::math::fixed_mul(a, b, std::math::Rounding::Nearest),
+    )
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, Ok(()));
+}
+
+#[test]
+fn error_decimal_bitlength_invalid() {
+    let input = r#"
+fn main(value: Decimal<120, 16>) {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(2, 24),
+        ElementError::Type(TypeError::DecimalBitlengthInvalid {
+            integer_bits: 120,
+            fraction_bits: 16,
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_decimal_bitlength_integer_bits_zero() {
+    let input = r#"
+fn main(value: Decimal<0, 16>) {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(2, 24),
+        ElementError::Type(TypeError::DecimalBitlengthInvalid {
+            integer_bits: 0,
+            fraction_bits: 16,
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
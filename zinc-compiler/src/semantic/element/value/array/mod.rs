@@ -87,6 +87,18 @@ impl Array {
         Ok(())
     }
 
+    ///
+    /// Appends the `other` array elements, which is what the `+` operator does with arrays.
+    ///
+    pub fn concatenate(mut self, other: Self) -> Result<Self, Error> {
+        if other.is_empty() {
+            return Ok(self);
+        }
+
+        self.extend(other.r#type, other.size)?;
+        Ok(self)
+    }
+
     pub fn slice_single(self) -> (Value, IndexAccess) {
         let access = IndexAccess::new(self.r#type.size(), self.r#type().size());
 
@@ -35,6 +35,27 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn error_concatenating_invalid_type() {
+    let input = r#"
+fn main() {
+    let array = [1, 2] + [true, false];
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 24),
+        ElementError::Value(ValueError::Array(ArrayValueError::PushingInvalidType {
+            expected: Type::integer_unsigned(crate::BITLENGTH_BYTE).to_string(),
+            found: Type::boolean().to_string(),
+        })),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_slice_start_out_of_range() {
     let input = r#"
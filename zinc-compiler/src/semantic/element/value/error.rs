@@ -22,12 +22,12 @@ pub enum Error {
     OperatorEqualsSecondOperandExpectedUnit { found: String },
     OperatorEqualsSecondOperandExpectedBoolean { found: String },
     OperatorEqualsSecondOperandExpectedInteger { found: String },
-    OperatorEqualsFirstOperandExpectedPrimitiveType { found: String },
+    OperatorEqualsTypesMismatch { first: String, second: String },
 
     OperatorNotEqualsSecondOperandExpectedUnit { found: String },
     OperatorNotEqualsSecondOperandExpectedBoolean { found: String },
     OperatorNotEqualsSecondOperandExpectedInteger { found: String },
-    OperatorNotEqualsFirstOperandExpectedPrimitiveType { found: String },
+    OperatorNotEqualsTypesMismatch { first: String, second: String },
 
     OperatorGreaterEqualsFirstOperandExpectedInteger { found: String },
     OperatorGreaterEqualsSecondOperandExpectedInteger { found: String },
@@ -58,6 +58,7 @@ pub enum Error {
 
     OperatorAdditionFirstOperandExpectedInteger { found: String },
     OperatorAdditionSecondOperandExpectedInteger { found: String },
+    OperatorAdditionSecondOperandExpectedArray { found: String },
 
     OperatorSubtractionFirstOperandExpectedInteger { found: String },
     OperatorSubtractionSecondOperandExpectedInteger { found: String },
@@ -71,6 +72,9 @@ pub enum Error {
     OperatorRemainderFirstOperandExpectedInteger { found: String },
     OperatorRemainderSecondOperandExpectedInteger { found: String },
 
+    OperatorExponentiationFirstOperandExpectedInteger { found: String },
+    OperatorExponentiationSecondOperandExpectedInteger { found: String },
+
     OperatorNotExpectedBoolean { found: String },
 
     OperatorBitwiseNotExpectedInteger { found: String },
@@ -21,9 +21,15 @@ pub enum Error {
 
     OperatorBitwiseShiftLeftSecondOperatorExpectedUnsigned { found: String },
     OperatorBitwiseShiftRightSecondOperatorExpectedUnsigned { found: String },
+    OperatorExponentiationSecondOperatorExpectedUnsigned { found: String },
 
     ForbiddenFieldDivision,
     ForbiddenFieldRemainder,
     ForbiddenFieldBitwise,
     ForbiddenFieldNegation,
+
+    ForbiddenDecimalRemainder,
+    ForbiddenDecimalBitwise,
+    ForbiddenDecimalNegation,
+    ForbiddenDecimalExponentiation,
 }
@@ -8,6 +8,7 @@ pub mod error;
 
 use std::fmt;
 
+use crate::semantic::element::constant::integer::Integer as IntegerConstant;
 use crate::semantic::element::r#type::enumeration::Enumeration;
 use crate::semantic::element::r#type::Type;
 
@@ -17,12 +18,14 @@ use self::error::Error;
 /// Integer values consist of the value, sign, and bitlength.
 /// If a value belongs to an enumeration, the enumeration type is stored in `enumeration`.
 /// Enumeration uniquely defines the value type, even if the sign and bitlength are the same.
+/// If a value is a decimal, the number of its fractional bits is stored in `fraction_bits`.
 ///
 #[derive(Debug, Clone, PartialEq)]
 pub struct Integer {
     pub is_signed: bool,
     pub bitlength: usize,
     pub enumeration: Option<Enumeration>,
+    pub fraction_bits: Option<usize>,
 }
 
 impl Integer {
@@ -31,6 +34,7 @@ impl Integer {
             is_signed,
             bitlength,
             enumeration: None,
+            fraction_bits: None,
         }
     }
 
@@ -38,10 +42,17 @@ impl Integer {
         self.enumeration = Some(enumeration);
     }
 
+    pub fn set_fraction_bits(&mut self, fraction_bits: usize) {
+        self.fraction_bits = Some(fraction_bits);
+    }
+
     pub fn r#type(&self) -> Type {
-        match self.enumeration {
-            Some(ref enumeration) => Type::Enumeration(enumeration.to_owned()),
-            None => Type::scalar(self.is_signed, self.bitlength),
+        match (self.enumeration.as_ref(), self.fraction_bits) {
+            (Some(enumeration), _) => Type::Enumeration(enumeration.to_owned()),
+            (None, Some(fraction_bits)) => {
+                Type::decimal(self.bitlength - fraction_bits, fraction_bits)
+            }
+            (None, None) => Type::scalar(self.is_signed, self.bitlength),
         }
     }
 
@@ -53,10 +64,43 @@ impl Integer {
                 (None, None) => true,
                 _ => false,
             }
+            && self.fraction_bits == other.fraction_bits
+    }
+
+    ///
+    /// Implicitly widens the narrower operand to the type of the wider one, which is allowed
+    /// for the integers of the same sign, e.g. `u8` to `u64` or `i8` to `i64`.
+    ///
+    /// Returns whether the operands have the same type afterwards.
+    ///
+    pub fn widen(&mut self, other: &mut Self) -> bool {
+        if self.is_widenable_to(other) {
+            self.bitlength = other.bitlength;
+        } else if other.is_widenable_to(self) {
+            other.bitlength = self.bitlength;
+        }
+
+        self.has_the_same_type_as(other)
     }
 
-    pub fn equals(self, other: Self) -> Result<(), Error> {
-        if !self.has_the_same_type_as(&other) {
+    ///
+    /// Checks if the integer can be implicitly widened to the type of `other`.
+    ///
+    /// The enumeration values, decimals, and fields are never widened, since their types
+    /// are not just integers of a greater bitlength.
+    ///
+    pub fn is_widenable_to(&self, other: &Self) -> bool {
+        self.enumeration.is_none()
+            && other.enumeration.is_none()
+            && self.fraction_bits.is_none()
+            && other.fraction_bits.is_none()
+            && self.is_signed == other.is_signed
+            && self.bitlength < other.bitlength
+            && other.bitlength != crate::BITLENGTH_FIELD
+    }
+
+    pub fn equals(mut self, mut other: Self) -> Result<(), Error> {
+        if !self.widen(&mut other) {
             return Err(Error::TypesMismatchEquals {
                 first: self.r#type().to_string(),
                 second: other.r#type().to_string(),
@@ -66,8 +110,8 @@ impl Integer {
         Ok(())
     }
 
-    pub fn not_equals(self, other: Self) -> Result<(), Error> {
-        if !self.has_the_same_type_as(&other) {
+    pub fn not_equals(mut self, mut other: Self) -> Result<(), Error> {
+        if !self.widen(&mut other) {
             return Err(Error::TypesMismatchNotEquals {
                 first: self.r#type().to_string(),
                 second: other.r#type().to_string(),
@@ -77,8 +121,8 @@ impl Integer {
         Ok(())
     }
 
-    pub fn greater_equals(self, other: Self) -> Result<(), Error> {
-        if !self.has_the_same_type_as(&other) {
+    pub fn greater_equals(mut self, mut other: Self) -> Result<(), Error> {
+        if !self.widen(&mut other) {
             return Err(Error::TypesMismatchGreaterEquals {
                 first: self.r#type().to_string(),
                 second: other.r#type().to_string(),
@@ -88,8 +132,8 @@ impl Integer {
         Ok(())
     }
 
-    pub fn lesser_equals(self, other: Self) -> Result<(), Error> {
-        if !self.has_the_same_type_as(&other) {
+    pub fn lesser_equals(mut self, mut other: Self) -> Result<(), Error> {
+        if !self.widen(&mut other) {
             return Err(Error::TypesMismatchLesserEquals {
                 first: self.r#type().to_string(),
                 second: other.r#type().to_string(),
@@ -99,8 +143,8 @@ impl Integer {
         Ok(())
     }
 
-    pub fn greater(self, other: Self) -> Result<(), Error> {
-        if !self.has_the_same_type_as(&other) {
+    pub fn greater(mut self, mut other: Self) -> Result<(), Error> {
+        if !self.widen(&mut other) {
             return Err(Error::TypesMismatchGreater {
                 first: self.r#type().to_string(),
                 second: other.r#type().to_string(),
@@ -110,8 +154,8 @@ impl Integer {
         Ok(())
     }
 
-    pub fn lesser(self, other: Self) -> Result<(), Error> {
-        if !self.has_the_same_type_as(&other) {
+    pub fn lesser(mut self, mut other: Self) -> Result<(), Error> {
+        if !self.widen(&mut other) {
             return Err(Error::TypesMismatchLesser {
                 first: self.r#type().to_string(),
                 second: other.r#type().to_string(),
@@ -121,8 +165,8 @@ impl Integer {
         Ok(())
     }
 
-    pub fn bitwise_or(self, other: Self) -> Result<Self, Error> {
-        if !self.has_the_same_type_as(&other) {
+    pub fn bitwise_or(mut self, mut other: Self) -> Result<Self, Error> {
+        if !self.widen(&mut other) {
             return Err(Error::TypesMismatchBitwiseOr {
                 first: self.r#type().to_string(),
                 second: other.r#type().to_string(),
@@ -133,11 +177,15 @@ impl Integer {
             return Err(Error::ForbiddenFieldBitwise);
         }
 
+        if self.fraction_bits.is_some() {
+            return Err(Error::ForbiddenDecimalBitwise);
+        }
+
         Ok(self)
     }
 
-    pub fn bitwise_xor(self, other: Self) -> Result<Self, Error> {
-        if !self.has_the_same_type_as(&other) {
+    pub fn bitwise_xor(mut self, mut other: Self) -> Result<Self, Error> {
+        if !self.widen(&mut other) {
             return Err(Error::TypesMismatchBitwiseXor {
                 first: self.r#type().to_string(),
                 second: other.r#type().to_string(),
@@ -148,11 +196,15 @@ impl Integer {
             return Err(Error::ForbiddenFieldBitwise);
         }
 
+        if self.fraction_bits.is_some() {
+            return Err(Error::ForbiddenDecimalBitwise);
+        }
+
         Ok(self)
     }
 
-    pub fn bitwise_and(self, other: Self) -> Result<Self, Error> {
-        if !self.has_the_same_type_as(&other) {
+    pub fn bitwise_and(mut self, mut other: Self) -> Result<Self, Error> {
+        if !self.widen(&mut other) {
             return Err(Error::TypesMismatchBitwiseAnd {
                 first: self.r#type().to_string(),
                 second: other.r#type().to_string(),
@@ -163,6 +215,10 @@ impl Integer {
             return Err(Error::ForbiddenFieldBitwise);
         }
 
+        if self.fraction_bits.is_some() {
+            return Err(Error::ForbiddenDecimalBitwise);
+        }
+
         Ok(self)
     }
 
@@ -171,6 +227,10 @@ impl Integer {
             return Err(Error::ForbiddenFieldBitwise);
         }
 
+        if self.fraction_bits.is_some() {
+            return Err(Error::ForbiddenDecimalBitwise);
+        }
+
         if other.is_signed {
             return Err(
                 Error::OperatorBitwiseShiftRightSecondOperatorExpectedUnsigned {
@@ -187,6 +247,10 @@ impl Integer {
             return Err(Error::ForbiddenFieldBitwise);
         }
 
+        if self.fraction_bits.is_some() {
+            return Err(Error::ForbiddenDecimalBitwise);
+        }
+
         if other.is_signed {
             return Err(
                 Error::OperatorBitwiseShiftRightSecondOperatorExpectedUnsigned {
@@ -198,8 +262,8 @@ impl Integer {
         Ok(self)
     }
 
-    pub fn add(self, other: Self) -> Result<Self, Error> {
-        if !self.has_the_same_type_as(&other) {
+    pub fn add(mut self, mut other: Self) -> Result<Self, Error> {
+        if !self.widen(&mut other) {
             return Err(Error::TypesMismatchAddition {
                 first: self.r#type().to_string(),
                 second: other.r#type().to_string(),
@@ -209,8 +273,8 @@ impl Integer {
         Ok(self)
     }
 
-    pub fn subtract(self, other: Self) -> Result<Self, Error> {
-        if !self.has_the_same_type_as(&other) {
+    pub fn subtract(mut self, mut other: Self) -> Result<Self, Error> {
+        if !self.widen(&mut other) {
             return Err(Error::TypesMismatchSubtraction {
                 first: self.r#type().to_string(),
                 second: other.r#type().to_string(),
@@ -220,8 +284,8 @@ impl Integer {
         Ok(self)
     }
 
-    pub fn multiply(self, other: Self) -> Result<Self, Error> {
-        if !self.has_the_same_type_as(&other) {
+    pub fn multiply(mut self, mut other: Self) -> Result<Self, Error> {
+        if !self.widen(&mut other) {
             return Err(Error::TypesMismatchMultiplication {
                 first: self.r#type().to_string(),
                 second: other.r#type().to_string(),
@@ -231,8 +295,8 @@ impl Integer {
         Ok(self)
     }
 
-    pub fn divide(self, other: Self) -> Result<Self, Error> {
-        if !self.has_the_same_type_as(&other) {
+    pub fn divide(mut self, mut other: Self) -> Result<Self, Error> {
+        if !self.widen(&mut other) {
             return Err(Error::TypesMismatchDivision {
                 first: self.r#type().to_string(),
                 second: other.r#type().to_string(),
@@ -246,8 +310,8 @@ impl Integer {
         Ok(self)
     }
 
-    pub fn remainder(self, other: Self) -> Result<Self, Error> {
-        if !self.has_the_same_type_as(&other) {
+    pub fn remainder(mut self, mut other: Self) -> Result<Self, Error> {
+        if !self.widen(&mut other) {
             return Err(Error::TypesMismatchRemainder {
                 first: self.r#type().to_string(),
                 second: other.r#type().to_string(),
@@ -258,6 +322,26 @@ impl Integer {
             return Err(Error::ForbiddenFieldRemainder);
         }
 
+        if self.fraction_bits.is_some() {
+            return Err(Error::ForbiddenDecimalRemainder);
+        }
+
+        Ok(self)
+    }
+
+    pub fn exponentiate(self, other: IntegerConstant) -> Result<Self, Error> {
+        if self.fraction_bits.is_some() {
+            return Err(Error::ForbiddenDecimalExponentiation);
+        }
+
+        if other.is_signed {
+            return Err(
+                Error::OperatorExponentiationSecondOperatorExpectedUnsigned {
+                    found: other.to_string(),
+                },
+            );
+        }
+
         Ok(self)
     }
 
@@ -265,6 +349,7 @@ impl Integer {
         self.is_signed = is_signed;
         self.bitlength = bitlength;
         self.enumeration = None;
+        self.fraction_bits = None;
 
         Ok(self)
     }
@@ -274,6 +359,10 @@ impl Integer {
             return Err(Error::ForbiddenFieldBitwise);
         }
 
+        if self.fraction_bits.is_some() {
+            return Err(Error::ForbiddenDecimalBitwise);
+        }
+
         Ok(self)
     }
 
@@ -282,6 +371,10 @@ impl Integer {
             return Err(Error::ForbiddenFieldNegation);
         }
 
+        if self.fraction_bits.is_some() {
+            return Err(Error::ForbiddenDecimalNegation);
+        }
+
         self.is_signed = true;
 
         Ok(self)
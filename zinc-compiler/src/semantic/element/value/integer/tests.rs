@@ -4,8 +4,11 @@
 
 #![cfg(test)]
 
+use num_bigint::BigInt;
+
 use crate::error::Error;
 use crate::lexical::token::location::Location;
+use crate::semantic::element::constant::integer::Integer as IntegerConstant;
 use crate::semantic::element::error::Error as ElementError;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::element::value::error::Error as ValueError;
@@ -13,12 +16,39 @@ use crate::semantic::element::value::integer::error::Error as IntegerValueError;
 use crate::semantic::element::value::integer::Integer as IntegerValue;
 use crate::semantic::error::Error as SemanticError;
 
+#[test]
+fn ok_widening() {
+    let input = r#"
+fn main(a: u8, b: u64, c: i8, d: i32) -> (u64, bool, i32, u64) {
+    (a + b, b > a, c * d, b % a)
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, Ok(()));
+}
+
+#[test]
+fn ok_widening_type() {
+    let mut narrow = IntegerValue::new(false, crate::BITLENGTH_BYTE);
+    let mut wide = IntegerValue::new(false, crate::BITLENGTH_BYTE * 8);
+
+    assert!(narrow.is_widenable_to(&wide));
+    assert!(!wide.is_widenable_to(&narrow));
+    assert!(!narrow.is_widenable_to(&IntegerValue::new(true, crate::BITLENGTH_BYTE * 8)));
+    assert!(!narrow.is_widenable_to(&IntegerValue::new(false, crate::BITLENGTH_FIELD)));
+
+    assert!(wide.widen(&mut narrow));
+    assert_eq!(narrow.bitlength, crate::BITLENGTH_BYTE * 8);
+}
+
 #[test]
 fn error_types_mismatch_equals() {
     let input = r#"
 fn main() {
-    let integer_64: u64 = 42;
-    let integer_128: u128 = 69;
+    let mut integer_64: u64 = 42;
+    let mut integer_128: i128 = 69;
     let value = integer_64 == integer_128;
 }
 "#;
@@ -28,7 +58,7 @@ fn main() {
         ElementError::Value(ValueError::Integer(
             IntegerValueError::TypesMismatchEquals {
                 first: Type::integer_unsigned(crate::BITLENGTH_BYTE * 8).to_string(),
-                second: Type::integer_unsigned(crate::BITLENGTH_BYTE * 16).to_string(),
+                second: Type::integer_signed(crate::BITLENGTH_BYTE * 16).to_string(),
             },
         )),
     )));
@@ -42,8 +72,8 @@ fn main() {
 fn error_types_mismatch_not_equals() {
     let input = r#"
 fn main() {
-    let integer_64: u64 = 42;
-    let integer_128: u128 = 69;
+    let mut integer_64: u64 = 42;
+    let mut integer_128: i128 = 69;
     let value = integer_64 != integer_128;
 }
 "#;
@@ -53,7 +83,7 @@ fn main() {
         ElementError::Value(ValueError::Integer(
             IntegerValueError::TypesMismatchNotEquals {
                 first: Type::integer_unsigned(crate::BITLENGTH_BYTE * 8).to_string(),
-                second: Type::integer_unsigned(crate::BITLENGTH_BYTE * 16).to_string(),
+                second: Type::integer_signed(crate::BITLENGTH_BYTE * 16).to_string(),
             },
         )),
     )));
@@ -67,8 +97,8 @@ fn main() {
 fn error_types_mismatch_greater_equals() {
     let input = r#"
 fn main() {
-    let integer_64: u64 = 42;
-    let integer_128: u128 = 69;
+    let mut integer_64: u64 = 42;
+    let mut integer_128: i128 = 69;
     let value = integer_64 >= integer_128;
 }
 "#;
@@ -78,7 +108,7 @@ fn main() {
         ElementError::Value(ValueError::Integer(
             IntegerValueError::TypesMismatchGreaterEquals {
                 first: Type::integer_unsigned(crate::BITLENGTH_BYTE * 8).to_string(),
-                second: Type::integer_unsigned(crate::BITLENGTH_BYTE * 16).to_string(),
+                second: Type::integer_signed(crate::BITLENGTH_BYTE * 16).to_string(),
             },
         )),
     )));
@@ -92,8 +122,8 @@ fn main() {
 fn error_types_mismatch_lesser_equals() {
     let input = r#"
 fn main() {
-    let integer_64: u64 = 42;
-    let integer_128: u128 = 69;
+    let mut integer_64: u64 = 42;
+    let mut integer_128: i128 = 69;
     let value = integer_64 <= integer_128;
 }
 "#;
@@ -103,7 +133,7 @@ fn main() {
         ElementError::Value(ValueError::Integer(
             IntegerValueError::TypesMismatchLesserEquals {
                 first: Type::integer_unsigned(crate::BITLENGTH_BYTE * 8).to_string(),
-                second: Type::integer_unsigned(crate::BITLENGTH_BYTE * 16).to_string(),
+                second: Type::integer_signed(crate::BITLENGTH_BYTE * 16).to_string(),
             },
         )),
     )));
@@ -117,8 +147,8 @@ fn main() {
 fn error_types_mismatch_greater() {
     let input = r#"
 fn main() {
-    let integer_64: u64 = 42;
-    let integer_128: u128 = 69;
+    let mut integer_64: u64 = 42;
+    let mut integer_128: i128 = 69;
     let value = integer_64 > integer_128;
 }
 "#;
@@ -128,7 +158,7 @@ fn main() {
         ElementError::Value(ValueError::Integer(
             IntegerValueError::TypesMismatchGreater {
                 first: Type::integer_unsigned(crate::BITLENGTH_BYTE * 8).to_string(),
-                second: Type::integer_unsigned(crate::BITLENGTH_BYTE * 16).to_string(),
+                second: Type::integer_signed(crate::BITLENGTH_BYTE * 16).to_string(),
             },
         )),
     )));
@@ -142,8 +172,8 @@ fn main() {
 fn error_types_mismatch_lesser() {
     let input = r#"
 fn main() {
-    let integer_64: u64 = 42;
-    let integer_128: u128 = 69;
+    let mut integer_64: u64 = 42;
+    let mut integer_128: i128 = 69;
     let value = integer_64 < integer_128;
 }
 "#;
@@ -153,7 +183,7 @@ fn main() {
         ElementError::Value(ValueError::Integer(
             IntegerValueError::TypesMismatchLesser {
                 first: Type::integer_unsigned(crate::BITLENGTH_BYTE * 8).to_string(),
-                second: Type::integer_unsigned(crate::BITLENGTH_BYTE * 16).to_string(),
+                second: Type::integer_signed(crate::BITLENGTH_BYTE * 16).to_string(),
             },
         )),
     )));
@@ -169,7 +199,7 @@ fn error_types_mismatch_bitwise_or() {
     let input = r#"
 fn main() {
     let integer_64: u64 = 42;
-    let integer_128: u128 = 69;
+    let integer_128: i128 = 69;
     let value = integer_64 | integer_128;
 }
 "#;
@@ -179,7 +209,7 @@ fn main() {
         ElementError::Value(ValueError::Integer(
             IntegerValueError::TypesMismatchBitwiseOr {
                 first: Type::integer_unsigned(crate::BITLENGTH_BYTE * 8).to_string(),
-                second: Type::integer_unsigned(crate::BITLENGTH_BYTE * 16).to_string(),
+                second: Type::integer_signed(crate::BITLENGTH_BYTE * 16).to_string(),
             },
         )),
     )));
@@ -195,7 +225,7 @@ fn error_types_mismatch_bitwise_xor() {
     let input = r#"
 fn main() {
     let integer_64: u64 = 42;
-    let integer_128: u128 = 69;
+    let integer_128: i128 = 69;
     let value = integer_64 ^ integer_128;
 }
 "#;
@@ -205,7 +235,7 @@ fn main() {
         ElementError::Value(ValueError::Integer(
             IntegerValueError::TypesMismatchBitwiseXor {
                 first: Type::integer_unsigned(crate::BITLENGTH_BYTE * 8).to_string(),
-                second: Type::integer_unsigned(crate::BITLENGTH_BYTE * 16).to_string(),
+                second: Type::integer_signed(crate::BITLENGTH_BYTE * 16).to_string(),
             },
         )),
     )));
@@ -221,7 +251,7 @@ fn error_types_mismatch_bitwise_and() {
     let input = r#"
 fn main() {
     let integer_64: u64 = 42;
-    let integer_128: u128 = 69;
+    let integer_128: i128 = 69;
     let value = integer_64 & integer_128;
 }
 "#;
@@ -231,7 +261,7 @@ fn main() {
         ElementError::Value(ValueError::Integer(
             IntegerValueError::TypesMismatchBitwiseAnd {
                 first: Type::integer_unsigned(crate::BITLENGTH_BYTE * 8).to_string(),
-                second: Type::integer_unsigned(crate::BITLENGTH_BYTE * 16).to_string(),
+                second: Type::integer_signed(crate::BITLENGTH_BYTE * 16).to_string(),
             },
         )),
     )));
@@ -245,8 +275,8 @@ fn main() {
 fn error_types_mismatch_addition() {
     let input = r#"
 fn main() {
-    let integer_64: u64 = 42;
-    let integer_128: u128 = 69;
+    let mut integer_64: u64 = 42;
+    let mut integer_128: i128 = 69;
     let value = integer_64 + integer_128;
 }
 "#;
@@ -256,7 +286,7 @@ fn main() {
         ElementError::Value(ValueError::Integer(
             IntegerValueError::TypesMismatchAddition {
                 first: Type::integer_unsigned(crate::BITLENGTH_BYTE * 8).to_string(),
-                second: Type::integer_unsigned(crate::BITLENGTH_BYTE * 16).to_string(),
+                second: Type::integer_signed(crate::BITLENGTH_BYTE * 16).to_string(),
             },
         )),
     )));
@@ -270,8 +300,8 @@ fn main() {
 fn error_types_mismatch_subtraction() {
     let input = r#"
 fn main() {
-    let integer_64: u64 = 42;
-    let integer_128: u128 = 69;
+    let mut integer_64: u64 = 42;
+    let mut integer_128: i128 = 69;
     let value = integer_64 - integer_128;
 }
 "#;
@@ -281,7 +311,7 @@ fn main() {
         ElementError::Value(ValueError::Integer(
             IntegerValueError::TypesMismatchSubtraction {
                 first: Type::integer_unsigned(crate::BITLENGTH_BYTE * 8).to_string(),
-                second: Type::integer_unsigned(crate::BITLENGTH_BYTE * 16).to_string(),
+                second: Type::integer_signed(crate::BITLENGTH_BYTE * 16).to_string(),
             },
         )),
     )));
@@ -295,8 +325,8 @@ fn main() {
 fn error_types_mismatch_multiplication() {
     let input = r#"
 fn main() {
-    let integer_64: u64 = 42;
-    let integer_128: u128 = 69;
+    let mut integer_64: u64 = 42;
+    let mut integer_128: i128 = 69;
     let value = integer_64 * integer_128;
 }
 "#;
@@ -306,7 +336,7 @@ fn main() {
         ElementError::Value(ValueError::Integer(
             IntegerValueError::TypesMismatchMultiplication {
                 first: Type::integer_unsigned(crate::BITLENGTH_BYTE * 8).to_string(),
-                second: Type::integer_unsigned(crate::BITLENGTH_BYTE * 16).to_string(),
+                second: Type::integer_signed(crate::BITLENGTH_BYTE * 16).to_string(),
             },
         )),
     )));
@@ -320,8 +350,8 @@ fn main() {
 fn error_types_mismatch_division() {
     let input = r#"
 fn main() {
-    let integer_64: u64 = 42;
-    let integer_128: u128 = 69;
+    let mut integer_64: u64 = 42;
+    let mut integer_128: i128 = 69;
     let value = integer_64 / integer_128;
 }
 "#;
@@ -331,7 +361,7 @@ fn main() {
         ElementError::Value(ValueError::Integer(
             IntegerValueError::TypesMismatchDivision {
                 first: Type::integer_unsigned(crate::BITLENGTH_BYTE * 8).to_string(),
-                second: Type::integer_unsigned(crate::BITLENGTH_BYTE * 16).to_string(),
+                second: Type::integer_signed(crate::BITLENGTH_BYTE * 16).to_string(),
             },
         )),
     )));
@@ -345,8 +375,8 @@ fn main() {
 fn error_types_mismatch_remainder() {
     let input = r#"
 fn main() {
-    let integer_64: u64 = 42;
-    let integer_128: u128 = 69;
+    let mut integer_64: u64 = 42;
+    let mut integer_128: i128 = 69;
     let value = integer_64 % integer_128;
 }
 "#;
@@ -356,7 +386,7 @@ fn main() {
         ElementError::Value(ValueError::Integer(
             IntegerValueError::TypesMismatchRemainder {
                 first: Type::integer_unsigned(crate::BITLENGTH_BYTE * 8).to_string(),
-                second: Type::integer_unsigned(crate::BITLENGTH_BYTE * 16).to_string(),
+                second: Type::integer_signed(crate::BITLENGTH_BYTE * 16).to_string(),
             },
         )),
     )));
@@ -414,12 +444,35 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn error_operator_exponentiation_2nd_operand_expected_unsigned() {
+    let input = r#"
+fn main(value: u8) -> u8 {
+    value ** -2
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 11),
+        ElementError::Value(ValueError::Integer(
+            IntegerValueError::OperatorExponentiationSecondOperatorExpectedUnsigned {
+                found: IntegerConstant::new(BigInt::from(-2), true, crate::BITLENGTH_BYTE)
+                    .to_string(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_forbidden_field_division() {
     let input = r#"
 fn main() {
-    let field_1: field = 42;
-    let field_2: field = 1;
+    let mut field_1: field = 42;
+    let mut field_2: field = 1;
     let value = field_1 / field_2;
 }
 "#;
@@ -440,8 +493,8 @@ fn main() {
 fn error_forbidden_field_remainder() {
     let input = r#"
 fn main() {
-    let field_1: field = 42;
-    let field_2: field = 1;
+    let mut field_1: field = 42;
+    let mut field_2: field = 1;
     let value = field_1 % field_2;
 }
 "#;
@@ -708,7 +761,7 @@ fn main() {
 fn error_forbidden_field_negation() {
     let input = r#"
 fn main() {
-    let value: field = 42;
+    let mut value: field = 42;
     let value = -value;
 }
 "#;
@@ -724,3 +777,88 @@ fn main() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn error_types_mismatch_decimal_addition() {
+    let input = r#"
+fn main(decimal: Decimal<32, 16>) {
+    let value = decimal + 1;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 25),
+        ElementError::Value(ValueError::Integer(
+            IntegerValueError::TypesMismatchAddition {
+                first: Type::decimal(crate::BITLENGTH_BYTE * 4, crate::BITLENGTH_BYTE * 2)
+                    .to_string(),
+                second: Type::integer_unsigned(crate::BITLENGTH_BYTE).to_string(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_forbidden_decimal_remainder() {
+    let input = r#"
+fn main(a: Decimal<32, 16>, b: Decimal<32, 16>) {
+    let value = a % b;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 19),
+        ElementError::Value(ValueError::Integer(
+            IntegerValueError::ForbiddenDecimalRemainder,
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[ignore]
+#[test]
+fn error_forbidden_decimal_bitwise() {
+    let input = r#"
+fn main(a: Decimal<32, 16>, b: Decimal<32, 16>) {
+    let value = a & b;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 19),
+        ElementError::Value(ValueError::Integer(
+            IntegerValueError::ForbiddenDecimalBitwise,
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_forbidden_decimal_negation() {
+    let input = r#"
+fn main(decimal: Decimal<32, 16>) {
+    let value = -decimal;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 17),
+        ElementError::Value(ValueError::Integer(
+            IntegerValueError::ForbiddenDecimalNegation,
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
@@ -125,9 +125,18 @@ impl Value {
             (Self::Integer(_), value_2) => Err(Error::OperatorEqualsSecondOperandExpectedInteger {
                 found: value_2.r#type().to_string(),
             }),
-            (value_1, _) => Err(Error::OperatorEqualsFirstOperandExpectedPrimitiveType {
-                found: value_1.r#type().to_string(),
-            }),
+            (value_1 @ Self::Array(_), value_2)
+            | (value_1 @ Self::Tuple(_), value_2)
+            | (value_1 @ Self::Structure(_), value_2) => {
+                if value_1.has_the_same_type_as(&value_2) {
+                    Ok(Self::Boolean)
+                } else {
+                    Err(Error::OperatorEqualsTypesMismatch {
+                        first: value_1.r#type().to_string(),
+                        second: value_2.r#type().to_string(),
+                    })
+                }
+            }
         }
     }
 
@@ -150,9 +159,18 @@ impl Value {
                     found: value_2.r#type().to_string(),
                 })
             }
-            (value_1, _) => Err(Error::OperatorNotEqualsFirstOperandExpectedPrimitiveType {
-                found: value_1.r#type().to_string(),
-            }),
+            (value_1 @ Self::Array(_), value_2)
+            | (value_1 @ Self::Tuple(_), value_2)
+            | (value_1 @ Self::Structure(_), value_2) => {
+                if value_1.has_the_same_type_as(&value_2) {
+                    Ok(Self::Boolean)
+                } else {
+                    Err(Error::OperatorNotEqualsTypesMismatch {
+                        first: value_1.r#type().to_string(),
+                        second: value_2.r#type().to_string(),
+                    })
+                }
+            }
         }
     }
 
@@ -326,6 +344,15 @@ impl Value {
                     found: value.r#type().to_string(),
                 }),
             },
+            Self::Array(array_1) => match other {
+                Self::Array(array_2) => array_1
+                    .concatenate(array_2)
+                    .map(Self::Array)
+                    .map_err(Error::Array),
+                value => Err(Error::OperatorAdditionSecondOperandExpectedArray {
+                    found: value.r#type().to_string(),
+                }),
+            },
             value => Err(Error::OperatorAdditionFirstOperandExpectedInteger {
                 found: value.r#type().to_string(),
             }),
@@ -400,22 +427,46 @@ impl Value {
         }
     }
 
+    pub fn exponentiate(self, other: Constant) -> Result<Self, Error> {
+        match self {
+            Self::Integer(integer_1) => match other {
+                Constant::Integer(integer_2) => integer_1
+                    .exponentiate(integer_2)
+                    .map(Self::Integer)
+                    .map_err(Error::Integer),
+                constant => Err(Error::OperatorExponentiationSecondOperandExpectedInteger {
+                    found: constant.to_string(),
+                }),
+            },
+            value => Err(Error::OperatorExponentiationFirstOperandExpectedInteger {
+                found: value.r#type().to_string(),
+            }),
+        }
+    }
+
     pub fn cast(self, to: Type) -> Result<Self, Error> {
         let from = self.r#type();
         Caster::cast(&from, &to).map_err(Error::Casting)?;
 
-        let (is_signed, bitlength) = match to {
-            Type::IntegerUnsigned { bitlength } => (false, bitlength),
-            Type::IntegerSigned { bitlength } => (true, bitlength),
-            Type::Field => (false, crate::BITLENGTH_FIELD),
+        let (is_signed, bitlength, fraction_bits) = match to {
+            Type::IntegerUnsigned { bitlength } => (false, bitlength, None),
+            Type::IntegerSigned { bitlength } => (true, bitlength, None),
+            Type::Field => (false, crate::BITLENGTH_FIELD, None),
+            Type::Decimal {
+                integer_bits,
+                fraction_bits,
+            } => (false, integer_bits + fraction_bits, Some(fraction_bits)),
             _ => return Ok(self),
         };
 
         Ok(match self {
-            Self::Integer(integer) => integer
-                .cast(is_signed, bitlength)
-                .map(Self::Integer)
-                .map_err(Error::Integer)?,
+            Self::Integer(integer) => {
+                let mut integer = integer.cast(is_signed, bitlength).map_err(Error::Integer)?;
+                if let Some(fraction_bits) = fraction_bits {
+                    integer.set_fraction_bits(fraction_bits);
+                }
+                Self::Integer(integer)
+            }
             operand => operand,
         })
     }
@@ -515,6 +566,14 @@ impl TryFrom<&Type> for Value {
             Type::IntegerUnsigned { bitlength } => Self::Integer(Integer::new(false, *bitlength)),
             Type::IntegerSigned { bitlength } => Self::Integer(Integer::new(true, *bitlength)),
             Type::Field => Self::Integer(Integer::new(false, crate::BITLENGTH_FIELD)),
+            Type::Decimal {
+                integer_bits,
+                fraction_bits,
+            } => {
+                let mut integer = Integer::new(false, integer_bits + fraction_bits);
+                integer.set_fraction_bits(*fraction_bits);
+                Self::Integer(integer)
+            }
             Type::Array { r#type, size } => Self::Array(Array::new(*r#type.to_owned(), *size)),
             Type::Tuple { types } => Self::Tuple(Tuple::new(types.to_owned())),
             Type::Structure(structure) => Self::Structure(Structure::new(structure.to_owned())),
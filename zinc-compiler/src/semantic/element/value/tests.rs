@@ -16,12 +16,43 @@ use crate::semantic::element::value::error::Error as ValueError;
 use crate::semantic::element::value::Value;
 use crate::semantic::error::Error as SemanticError;
 
+#[test]
+fn ok_operator_equals_composite() {
+    let input = r#"
+struct Data {
+    a: u8,
+    b: (bool, [u8; 2]),
+}
+
+fn main() {
+    let data = Data { a: 1, b: (true, [2, 3]) };
+    let structures = data == Data { a: 1, b: (true, [2, 3]) };
+    let tuples = (1, [true, false]) != (1, [true, true]);
+    let arrays = [[1, 2], [3, 4]] == [[1, 2], [3, 5]];
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_operator_addition_concatenation() {
+    let input = r#"
+fn main() {
+    let array: [u8; 5] = [1, 2, 3] + [4, 5];
+    let empty: [u8; 2] = array[0..2] + [];
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
 #[test]
 fn error_operator_or_1st_operand_expected_boolean() {
     let input = r#"
 fn main() {
-    let integer = 42;
-    let boolean = true;
+    let mut integer = 42;
+    let mut boolean = true;
     let value = integer || boolean;
 }
 "#;
@@ -42,8 +73,8 @@ fn main() {
 fn error_operator_or_2nd_operand_expected_boolean() {
     let input = r#"
 fn main() {
-    let boolean = true;
-    let integer = 42;
+    let mut boolean = true;
+    let mut integer = 42;
     let value = boolean || integer;
 }
 "#;
@@ -64,8 +95,8 @@ fn main() {
 fn error_operator_xor_1st_operand_expected_boolean() {
     let input = r#"
 fn main() {
-    let integer = 42;
-    let boolean = true;
+    let mut integer = 42;
+    let mut boolean = true;
     let value = integer ^^ boolean;
 }
 "#;
@@ -86,8 +117,8 @@ fn main() {
 fn error_operator_xor_2nd_operand_expected_boolean() {
     let input = r#"
 fn main() {
-    let boolean = true;
-    let integer = 42;
+    let mut boolean = true;
+    let mut integer = 42;
     let value = boolean ^^ integer;
 }
 "#;
@@ -108,8 +139,8 @@ fn main() {
 fn error_operator_and_1st_operand_expected_boolean() {
     let input = r#"
 fn main() {
-    let integer = 42;
-    let boolean = true;
+    let mut integer = 42;
+    let mut boolean = true;
     let value = integer && boolean;
 }
 "#;
@@ -130,8 +161,8 @@ fn main() {
 fn error_operator_and_2nd_operand_expected_boolean() {
     let input = r#"
 fn main() {
-    let boolean = true;
-    let integer = 42;
+    let mut boolean = true;
+    let mut integer = 42;
     let value = boolean && integer;
 }
 "#;
@@ -149,7 +180,7 @@ fn main() {
 }
 
 #[test]
-fn error_operator_equals_1st_operand_expected_primitive() {
+fn error_operator_equals_types_mismatch() {
     let input = r#"
 fn main() {
     let array = [1, 2, 3];
@@ -160,11 +191,31 @@ fn main() {
 
     let expected = Err(Error::Semantic(SemanticError::Element(
         Location::new(5, 23),
-        ElementError::Value(
-            ValueError::OperatorEqualsFirstOperandExpectedPrimitiveType {
-                found: Type::array(Type::integer_unsigned(crate::BITLENGTH_BYTE), 3).to_string(),
-            },
-        ),
+        ElementError::Value(ValueError::OperatorEqualsTypesMismatch {
+            first: Type::array(Type::integer_unsigned(crate::BITLENGTH_BYTE), 3).to_string(),
+            second: Type::integer_unsigned(crate::BITLENGTH_BYTE).to_string(),
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_operator_equals_types_mismatch_array_size() {
+    let input = r#"
+fn main() {
+    let value = [1, 2, 3] == [1, 2];
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 27),
+        ElementError::Value(ValueError::OperatorEqualsTypesMismatch {
+            first: Type::array(Type::integer_unsigned(crate::BITLENGTH_BYTE), 3).to_string(),
+            second: Type::array(Type::integer_unsigned(crate::BITLENGTH_BYTE), 2).to_string(),
+        }),
     )));
 
     let result = crate::semantic::tests::compile_entry(input);
@@ -177,7 +228,7 @@ fn error_operator_equals_2nd_operand_expected_unit() {
     let input = r#"
 fn main() {
     let unit = ();
-    let integer = 42;
+    let mut integer = 42;
     let value = unit == integer;
 }
 "#;
@@ -198,8 +249,8 @@ fn main() {
 fn error_operator_equals_2nd_operand_expected_boolean() {
     let input = r#"
 fn main() {
-    let boolean = true;
-    let integer = 42;
+    let mut boolean = true;
+    let mut integer = 42;
     let value = boolean == integer;
 }
 "#;
@@ -220,8 +271,8 @@ fn main() {
 fn error_operator_equals_2nd_operand_expected_integer() {
     let input = r#"
 fn main() {
-    let integer = 42;
-    let boolean = false;
+    let mut integer = 42;
+    let mut boolean = false;
     let value = integer == boolean;
 }
 "#;
@@ -239,7 +290,7 @@ fn main() {
 }
 
 #[test]
-fn error_operator_not_equals_1st_operand_expected_primitive() {
+fn error_operator_not_equals_types_mismatch() {
     let input = r#"
 fn main() {
     let array = [1, 2, 3];
@@ -250,11 +301,31 @@ fn main() {
 
     let expected = Err(Error::Semantic(SemanticError::Element(
         Location::new(5, 23),
-        ElementError::Value(
-            ValueError::OperatorNotEqualsFirstOperandExpectedPrimitiveType {
-                found: Type::array(Type::integer_unsigned(crate::BITLENGTH_BYTE), 3).to_string(),
-            },
-        ),
+        ElementError::Value(ValueError::OperatorNotEqualsTypesMismatch {
+            first: Type::array(Type::integer_unsigned(crate::BITLENGTH_BYTE), 3).to_string(),
+            second: Type::integer_unsigned(crate::BITLENGTH_BYTE).to_string(),
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_operator_not_equals_types_mismatch_array_size() {
+    let input = r#"
+fn main() {
+    let value = [1, 2, 3] != [1, 2];
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 27),
+        ElementError::Value(ValueError::OperatorNotEqualsTypesMismatch {
+            first: Type::array(Type::integer_unsigned(crate::BITLENGTH_BYTE), 3).to_string(),
+            second: Type::array(Type::integer_unsigned(crate::BITLENGTH_BYTE), 2).to_string(),
+        }),
     )));
 
     let result = crate::semantic::tests::compile_entry(input);
@@ -267,7 +338,7 @@ fn error_operator_not_equals_2nd_operand_expected_unit() {
     let input = r#"
 fn main() {
     let unit = ();
-    let integer = 42;
+    let mut integer = 42;
     let value = unit != integer;
 }
 "#;
@@ -288,8 +359,8 @@ fn main() {
 fn error_operator_not_equals_2nd_operand_expected_boolean() {
     let input = r#"
 fn main() {
-    let boolean = true;
-    let integer = 42;
+    let mut boolean = true;
+    let mut integer = 42;
     let value = boolean != integer;
 }
 "#;
@@ -310,8 +381,8 @@ fn main() {
 fn error_operator_not_equals_2nd_operand_expected_integer() {
     let input = r#"
 fn main() {
-    let integer = 42;
-    let boolean = false;
+    let mut integer = 42;
+    let mut boolean = false;
     let value = integer != boolean;
 }
 "#;
@@ -332,8 +403,8 @@ fn main() {
 fn error_operator_greater_equals_1st_operand_expected_integer() {
     let input = r#"
 fn main() {
-    let boolean = true;
-    let integer = 42;
+    let mut boolean = true;
+    let mut integer = 42;
     let value = boolean >= integer;
 }
 "#;
@@ -356,8 +427,8 @@ fn main() {
 fn error_operator_greater_equals_2nd_operand_expected_integer() {
     let input = r#"
 fn main() {
-    let integer = 42;
-    let boolean = true;
+    let mut integer = 42;
+    let mut boolean = true;
     let value = integer >= boolean;
 }
 "#;
@@ -380,8 +451,8 @@ fn main() {
 fn error_operator_lesser_equals_1st_operand_expected_integer() {
     let input = r#"
 fn main() {
-    let boolean = true;
-    let integer = 42;
+    let mut boolean = true;
+    let mut integer = 42;
     let value = boolean <= integer;
 }
 "#;
@@ -404,8 +475,8 @@ fn main() {
 fn error_operator_lesser_equals_2nd_operand_expected_integer() {
     let input = r#"
 fn main() {
-    let integer = 42;
-    let boolean = true;
+    let mut integer = 42;
+    let mut boolean = true;
     let value = integer <= boolean;
 }
 "#;
@@ -428,8 +499,8 @@ fn main() {
 fn error_operator_greater_1st_operand_expected_integer() {
     let input = r#"
 fn main() {
-    let boolean = true;
-    let integer = 42;
+    let mut boolean = true;
+    let mut integer = 42;
     let value = boolean > integer;
 }
 "#;
@@ -450,8 +521,8 @@ fn main() {
 fn error_operator_greater_2nd_operand_expected_integer() {
     let input = r#"
 fn main() {
-    let integer = 42;
-    let boolean = true;
+    let mut integer = 42;
+    let mut boolean = true;
     let value = integer > boolean;
 }
 "#;
@@ -472,8 +543,8 @@ fn main() {
 fn error_operator_lesser_1st_operand_expected_integer() {
     let input = r#"
 fn main() {
-    let boolean = true;
-    let integer = 42;
+    let mut boolean = true;
+    let mut integer = 42;
     let value = boolean < integer;
 }
 "#;
@@ -494,8 +565,8 @@ fn main() {
 fn error_operator_lesser_2nd_operand_expected_integer() {
     let input = r#"
 fn main() {
-    let integer = 42;
-    let boolean = true;
+    let mut integer = 42;
+    let mut boolean = true;
     let value = integer < boolean;
 }
 "#;
@@ -754,8 +825,8 @@ fn main() {
 fn error_operator_addition_1st_operand_expected_integer() {
     let input = r#"
 fn main() {
-    let boolean = true;
-    let integer = 42;
+    let mut boolean = true;
+    let mut integer = 42;
     let value = boolean + integer;
 }
 "#;
@@ -776,8 +847,8 @@ fn main() {
 fn error_operator_addition_2nd_operand_expected_integer() {
     let input = r#"
 fn main() {
-    let integer = 42;
-    let boolean = true;
+    let mut integer = 42;
+    let mut boolean = true;
     let value = integer + boolean;
 }
 "#;
@@ -795,11 +866,33 @@ fn main() {
 }
 
 #[test]
-fn error_operator_subtraction_1st_operand_expected_integer() {
+fn error_operator_addition_2nd_operand_expected_array() {
     let input = r#"
 fn main() {
-    let boolean = true;
+    let array = [1, 2, 3];
     let integer = 42;
+    let value = array + integer;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(5, 23),
+        ElementError::Value(ValueError::OperatorAdditionSecondOperandExpectedArray {
+            found: Type::integer_unsigned(crate::BITLENGTH_BYTE).to_string(),
+        }),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_operator_subtraction_1st_operand_expected_integer() {
+    let input = r#"
+fn main() {
+    let mut boolean = true;
+    let mut integer = 42;
     let value = boolean - integer;
 }
 "#;
@@ -820,8 +913,8 @@ fn main() {
 fn error_operator_subtraction_2nd_operand_expected_integer() {
     let input = r#"
 fn main() {
-    let integer = 42;
-    let boolean = true;
+    let mut integer = 42;
+    let mut boolean = true;
     let value = integer - boolean;
 }
 "#;
@@ -844,8 +937,8 @@ fn main() {
 fn error_operator_multiplication_1st_operand_expected_integer() {
     let input = r#"
 fn main() {
-    let boolean = true;
-    let integer = 42;
+    let mut boolean = true;
+    let mut integer = 42;
     let value = boolean * integer;
 }
 "#;
@@ -868,8 +961,8 @@ fn main() {
 fn error_operator_multiplication_2nd_operand_expected_integer() {
     let input = r#"
 fn main() {
-    let integer = 42;
-    let boolean = true;
+    let mut integer = 42;
+    let mut boolean = true;
     let value = integer * boolean;
 }
 "#;
@@ -892,8 +985,8 @@ fn main() {
 fn error_operator_division_1st_operand_expected_integer() {
     let input = r#"
 fn main() {
-    let boolean = true;
-    let integer = 42;
+    let mut boolean = true;
+    let mut integer = 42;
     let value = boolean / integer;
 }
 "#;
@@ -914,8 +1007,8 @@ fn main() {
 fn error_operator_division_2nd_operand_expected_integer() {
     let input = r#"
 fn main() {
-    let integer = 42;
-    let boolean = true;
+    let mut integer = 42;
+    let mut boolean = true;
     let value = integer / boolean;
 }
 "#;
@@ -936,8 +1029,8 @@ fn main() {
 fn error_operator_remainder_1st_operand_expected_integer() {
     let input = r#"
 fn main() {
-    let boolean = true;
-    let integer = 42;
+    let mut boolean = true;
+    let mut integer = 42;
     let value = boolean % integer;
 }
 "#;
@@ -958,8 +1051,8 @@ fn main() {
 fn error_operator_remainder_2nd_operand_expected_integer() {
     let input = r#"
 fn main() {
-    let integer = 42;
-    let boolean = true;
+    let mut integer = 42;
+    let mut boolean = true;
     let value = integer % boolean;
 }
 "#;
@@ -980,7 +1073,7 @@ fn main() {
 fn error_operator_not_expected_boolean() {
     let input = r#"
 fn main() {
-    let integer = 42;
+    let mut integer = 42;
     let value = !integer;
 }
 "#;
@@ -1023,7 +1116,7 @@ fn main() {
 fn error_operator_negation_expected_integer() {
     let input = r#"
 fn main() {
-    let boolean = true;
+    let mut boolean = true;
     let value = -boolean;
 }
 "#;
@@ -64,6 +64,9 @@ pub enum Error {
         found: String,
         reference: Location,
     },
+    ConditionalPatternIrrefutable {
+        location: Location,
+    },
 
     EntryPointMissing,
 
@@ -86,6 +89,28 @@ pub enum Error {
         location: Location,
         found: String,
     },
+
+    AttributeUnknown {
+        location: Location,
+        found: String,
+    },
+    TestFunctionInvalidSignature {
+        location: Location,
+        function: String,
+    },
+    FunctionArgumentPublicOutsideMain {
+        location: Location,
+        function: String,
+    },
+    ExternAbiUnsupported {
+        location: Location,
+        abi: String,
+    },
+    ExternFunctionTypeUnsupported {
+        location: Location,
+        function: String,
+        found: String,
+    },
 }
 
 impl From<ScopeError> for Error {
@@ -8,12 +8,23 @@ use std::rc::Rc;
 
 use zinc_bytecode::builtins::BuiltinIdentifier;
 
+use crate::extension::Extension;
+use crate::lexical::token::lexeme::literal::integer::Integer as LexicalIntegerLiteral;
+use crate::lexical::token::location::Location;
+use crate::prelude::Prelude;
+use crate::semantic::element::r#type::enumeration::Enumeration as EnumerationType;
+use crate::semantic::element::r#type::function::stdlib::math_select::Operation as MathSelectOperation;
 use crate::semantic::element::r#type::function::Function as FunctionType;
 use crate::semantic::element::r#type::structure::Structure as StructureType;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::scope::item::variant::Variant as ScopeItemVariant;
 use crate::semantic::scope::item::Item as ScopeItem;
 use crate::semantic::scope::Scope;
+use crate::syntax::tree::identifier::Identifier;
+use crate::syntax::tree::literal::integer::Literal as IntegerLiteral;
+use crate::syntax::tree::variant::Variant;
+
+static PANIC_BUILTIN_ENUMERATION: &str = "The built-in enumerations are always valid";
 
 ///
 /// A built-in items set instance creator.
@@ -26,7 +37,11 @@ pub struct BuiltInItems {}
 impl BuiltInItems {
     pub const TYPE_ID_STD_CRYPTO_ECC_POINT: usize = 0;
     pub const TYPE_ID_STD_CRYPTO_SCHNORR_SIGNATURE: usize = 1;
-    pub const TYPE_ID_FIRST_AVAILABLE: usize = 2;
+    pub const TYPE_ID_STD_MATH_ROUNDING: usize = 2;
+
+    pub const STD_MATH_ROUNDING_FLOOR: usize = 0;
+    pub const STD_MATH_ROUNDING_CEIL: usize = 1;
+    pub const STD_MATH_ROUNDING_NEAREST: usize = 2;
 
     pub fn new_map() -> HashMap<String, ScopeItem> {
         let mut std_crypto_scope = Scope::default();
@@ -169,11 +184,31 @@ impl BuiltInItems {
             ),
         );
 
+        let mut std_bits_scope = Scope::default();
+        for builtin_identifier in [
+            BuiltinIdentifier::BitsCountOnes,
+            BuiltinIdentifier::BitsLeadingZeros,
+            BuiltinIdentifier::BitsRotateLeft,
+            BuiltinIdentifier::BitsRotateRight,
+        ]
+        .iter()
+        {
+            let std_bits_function = FunctionType::new_std(*builtin_identifier);
+            std_bits_scope.items.insert(
+                std_bits_function.identifier(),
+                ScopeItem::new(
+                    ScopeItemVariant::Type(Type::Function(std_bits_function)),
+                    None,
+                ),
+            );
+        }
+
         let mut std_array_scope = Scope::default();
 
         let std_array_reverse = FunctionType::new_std(BuiltinIdentifier::ArrayReverse);
         let std_array_truncate = FunctionType::new_std(BuiltinIdentifier::ArrayTruncate);
         let std_array_pad = FunctionType::new_std(BuiltinIdentifier::ArrayPad);
+        let std_array_sort = FunctionType::new_std(BuiltinIdentifier::ArraySort);
 
         std_array_scope.items.insert(
             std_array_reverse.identifier(),
@@ -193,6 +228,10 @@ impl BuiltInItems {
             std_array_pad.identifier(),
             ScopeItem::new(ScopeItemVariant::Type(Type::Function(std_array_pad)), None),
         );
+        std_array_scope.items.insert(
+            std_array_sort.identifier(),
+            ScopeItem::new(ScopeItemVariant::Type(Type::Function(std_array_sort)), None),
+        );
 
         let mut std_ff_scope = Scope::default();
         let std_ff_invert = FunctionType::new_std(BuiltinIdentifier::FieldInverse);
@@ -201,6 +240,117 @@ impl BuiltInItems {
             ScopeItem::new(ScopeItemVariant::Type(Type::Function(std_ff_invert)), None),
         );
 
+        let mut std_eth_scope = Scope::default();
+        let std_eth_abi_encode = FunctionType::new_std(BuiltinIdentifier::EthAbiEncode);
+        let std_eth_abi_decode = FunctionType::new_std(BuiltinIdentifier::EthAbiDecode);
+        std_eth_scope.items.insert(
+            std_eth_abi_encode.identifier(),
+            ScopeItem::new(
+                ScopeItemVariant::Type(Type::Function(std_eth_abi_encode)),
+                None,
+            ),
+        );
+        std_eth_scope.items.insert(
+            std_eth_abi_decode.identifier(),
+            ScopeItem::new(
+                ScopeItemVariant::Type(Type::Function(std_eth_abi_decode)),
+                None,
+            ),
+        );
+
+        let mut std_math_scope = Scope::default();
+        for builtin_identifier in [
+            BuiltinIdentifier::MathOverflowingAdd,
+            BuiltinIdentifier::MathOverflowingSub,
+            BuiltinIdentifier::MathOverflowingMul,
+            BuiltinIdentifier::MathFixedMul,
+            BuiltinIdentifier::MathFixedDiv,
+            BuiltinIdentifier::MathSign,
+            BuiltinIdentifier::MathAddMod32,
+            BuiltinIdentifier::MathSubMod32,
+            BuiltinIdentifier::MathMulMod32,
+            BuiltinIdentifier::MathAddMod64,
+            BuiltinIdentifier::MathSubMod64,
+            BuiltinIdentifier::MathMulMod64,
+        ]
+        .iter()
+        {
+            let std_math_function = FunctionType::new_std(*builtin_identifier);
+            std_math_scope.items.insert(
+                std_math_function.identifier(),
+                ScopeItem::new(
+                    ScopeItemVariant::Type(Type::Function(std_math_function)),
+                    None,
+                ),
+            );
+        }
+        for operation in MathSelectOperation::ALL.iter() {
+            let std_math_function = FunctionType::new_std_math_select(*operation);
+            std_math_scope.items.insert(
+                std_math_function.identifier(),
+                ScopeItem::new(
+                    ScopeItemVariant::Type(Type::Function(std_math_function)),
+                    None,
+                ),
+            );
+        }
+
+        let location = Location::new_beginning(None);
+        let std_math_rounding = EnumerationType::new(
+            Identifier::new(location, "Rounding".to_owned()),
+            Self::TYPE_ID_STD_MATH_ROUNDING,
+            vec![
+                ("Floor", Self::STD_MATH_ROUNDING_FLOOR),
+                ("Ceil", Self::STD_MATH_ROUNDING_CEIL),
+                ("Nearest", Self::STD_MATH_ROUNDING_NEAREST),
+            ]
+            .into_iter()
+            .map(|(identifier, value)| {
+                Variant::new(
+                    location,
+                    Identifier::new(location, identifier.to_owned()),
+                    IntegerLiteral::new(
+                        location,
+                        LexicalIntegerLiteral::new_decimal(value.to_string()),
+                    ),
+                )
+            })
+            .collect(),
+            None,
+        )
+        .expect(PANIC_BUILTIN_ENUMERATION);
+        std_math_scope.items.insert(
+            "Rounding".to_owned(),
+            ScopeItem::new(
+                ScopeItemVariant::Type(Type::Enumeration(std_math_rounding)),
+                None,
+            ),
+        );
+
+        let mut std_table_scope = Scope::default();
+        let std_table_lookup = FunctionType::new_std(BuiltinIdentifier::TableLookup);
+        std_table_scope.items.insert(
+            std_table_lookup.identifier(),
+            ScopeItem::new(
+                ScopeItemVariant::Type(Type::Function(std_table_lookup)),
+                None,
+            ),
+        );
+
+        let mut std_ext_scope = Scope::default();
+        for extension in Extension::all().into_iter() {
+            let (arguments, return_type) = extension.signature();
+            let std_ext_function =
+                FunctionType::new_extension(extension.identifier, arguments, return_type);
+            std_ext_scope.items.insert(
+                std_ext_function.identifier(),
+                ScopeItem::new(
+                    ScopeItemVariant::Type(Type::Function(std_ext_function)),
+                    None,
+                ),
+            );
+        }
+
         let mut std_scope = Scope::default();
         std_scope.items.insert(
             "crypto".to_owned(),
@@ -216,6 +366,13 @@ impl BuiltInItems {
                 None,
             ),
         );
+        std_scope.items.insert(
+            "bits".to_owned(),
+            ScopeItem::new(
+                ScopeItemVariant::Module(Rc::new(RefCell::new(std_bits_scope))),
+                None,
+            ),
+        );
         std_scope.items.insert(
             "array".to_owned(),
             ScopeItem::new(
@@ -230,10 +387,53 @@ impl BuiltInItems {
                 None,
             ),
         );
+        std_scope.items.insert(
+            "eth".to_owned(),
+            ScopeItem::new(
+                ScopeItemVariant::Module(Rc::new(RefCell::new(std_eth_scope))),
+                None,
+            ),
+        );
+        std_scope.items.insert(
+            "math".to_owned(),
+            ScopeItem::new(
+                ScopeItemVariant::Module(Rc::new(RefCell::new(std_math_scope))),
+                None,
+            ),
+        );
+        std_scope.items.insert(
+            "table".to_owned(),
+            ScopeItem::new(
+                ScopeItemVariant::Module(Rc::new(RefCell::new(std_table_scope))),
+                None,
+            ),
+        );
+        std_scope.items.insert(
+            "ext".to_owned(),
+            ScopeItem::new(
+                ScopeItemVariant::Module(Rc::new(RefCell::new(std_ext_scope))),
+                None,
+            ),
+        );
+        let std_assert_in_range = FunctionType::new_std(BuiltinIdentifier::AssertInRange);
+        std_scope.items.insert(
+            std_assert_in_range.identifier(),
+            ScopeItem::new(
+                ScopeItemVariant::Type(Type::Function(std_assert_in_range)),
+                None,
+            ),
+        );
+        if let Some(std_prelude_scope) = Prelude::scope(&std_scope) {
+            std_scope.items.insert(
+                "prelude".to_owned(),
+                ScopeItem::new(ScopeItemVariant::Module(std_prelude_scope), None),
+            );
+        }
 
-        let mut items = HashMap::with_capacity(3);
+        let mut items = HashMap::with_capacity(4);
         let builtin_function_dbg = FunctionType::new_dbg();
         let builtin_function_assert = FunctionType::new_assert();
+        let builtin_function_expose = FunctionType::new_expose();
         items.insert(
             builtin_function_dbg.identifier(),
             ScopeItem::new(
@@ -248,6 +448,13 @@ impl BuiltInItems {
                 None,
             ),
         );
+        items.insert(
+            builtin_function_expose.identifier(),
+            ScopeItem::new(
+                ScopeItemVariant::Type(Type::Function(builtin_function_expose)),
+                None,
+            ),
+        );
         items.insert(
             "std".to_owned(),
             ScopeItem::new(
@@ -4,6 +4,7 @@
 
 use std::fmt;
 
+use crate::semantic::element::constant::Constant;
 use crate::semantic::element::r#type::Type;
 
 ///
@@ -13,11 +14,26 @@ use crate::semantic::element::r#type::Type;
 pub struct Variable {
     pub is_mutable: bool,
     pub r#type: Type,
+    /// The value of an immutable variable initialized with a constant expression, which
+    /// allows using the variable where a constant is expected, e.g. in array sizes and ranges.
+    pub constant: Option<Constant>,
 }
 
 impl Variable {
     pub fn new(is_mutable: bool, r#type: Type) -> Self {
-        Self { is_mutable, r#type }
+        Self {
+            is_mutable,
+            r#type,
+            constant: None,
+        }
+    }
+
+    pub fn new_constant(constant: Constant) -> Self {
+        Self {
+            is_mutable: false,
+            r#type: constant.r#type(),
+            constant: Some(constant),
+        }
     }
 }
 
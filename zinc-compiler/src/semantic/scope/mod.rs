@@ -13,6 +13,8 @@ use std::cell::RefCell;
 use std::collections::HashMap;
 use std::rc::Rc;
 use std::str;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
 
 use crate::lexical::token::lexeme::keyword::Keyword;
 use crate::semantic::element::constant::Constant;
@@ -27,6 +29,11 @@ use self::item::variant::variable::Variable as VariableItem;
 use self::item::variant::Variant as ItemVariant;
 use self::item::Item;
 
+///
+/// Whether the variables shadowing other variables are reported with a warning.
+///
+static WARN_SHADOWING: AtomicBool = AtomicBool::new(false);
+
 ///
 /// A scope consists of a hashmap of the declared items and a reference to its parent.
 /// The global scope has no parent.
@@ -59,6 +66,13 @@ impl Scope {
         }
     }
 
+    ///
+    /// Enables the shadowing warning for all the files compiled afterwards.
+    ///
+    pub fn enable_shadowing_warning() {
+        WARN_SHADOWING.store(true, Ordering::Relaxed);
+    }
+
     ///
     /// Declares a general item.
     ///
@@ -81,17 +95,36 @@ impl Scope {
     ///
     /// Declares a variable, which is normally a `let` binding or a function actual parameter.
     ///
+    /// A variable may shadow another variable declared in the same or an outer scope, like in
+    /// Rust, but not the other items like types, functions, and constants.
+    ///
     pub fn declare_variable(
         scope: Rc<RefCell<Scope>>,
         identifier: Identifier,
         variable: VariableItem,
     ) -> Result<(), Error> {
         if let Ok(item) = Self::resolve_item(scope.clone(), &identifier) {
-            return Err(Error::ItemRedeclared {
-                location: identifier.location,
-                name: identifier.name,
-                reference: item.location,
-            });
+            match item.variant {
+                ItemVariant::Variable(_) => {
+                    if WARN_SHADOWING.load(Ordering::Relaxed) {
+                        log::warn!(
+                            "Variable `{}` at {} shadows the one declared at {}",
+                            identifier.name,
+                            identifier.location,
+                            item.location
+                                .map(|location| location.to_string())
+                                .unwrap_or_else(|| "<unavailable>".to_owned()),
+                        );
+                    }
+                }
+                _ => {
+                    return Err(Error::ItemRedeclared {
+                        location: identifier.location,
+                        name: identifier.name,
+                        reference: item.location,
+                    })
+                }
+            }
         }
         scope.borrow_mut().items.insert(
             identifier.name,
@@ -178,6 +211,20 @@ impl Scope {
         );
     }
 
+    ///
+    /// Declares a type associated with an implementation, like the built-in functions
+    /// of enumerations.
+    ///
+    /// Like `Self`, the associated types are not checked for being declared in the parent
+    /// scopes, so they do not clash with the items of the same name outside the implementation.
+    ///
+    pub fn declare_associated_type(&mut self, identifier: Identifier, r#type: Type) {
+        self.items.insert(
+            identifier.name,
+            Item::new(ItemVariant::Type(r#type), Some(identifier.location)),
+        );
+    }
+
     ///
     /// Gets an item at the specified path by looking through modules, implementations,
     /// and enumerations along the way.
@@ -244,6 +291,13 @@ impl Scope {
         }
     }
 
+    ///
+    /// Iterates over the items declared in the current scope, not including its parents.
+    ///
+    pub fn items(&self) -> impl Iterator<Item = (&String, &Item)> {
+        self.items.iter()
+    }
+
     ///
     /// Checks whether the `main` function is declared within the current scope hierarchy.
     ///
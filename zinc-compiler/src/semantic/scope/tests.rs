@@ -127,21 +127,50 @@ fn main() {
 }
 
 #[test]
-fn error_item_redeclared() {
+fn ok_variable_shadowed_same_scope() {
     let input = r#"
-fn main() {
+fn main() -> u8 {
     let result = 42;
+    let result = [result; 2];
+    result[1]
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_variable_shadowed_inner_scope() {
+    let input = r#"
+fn main(result: u8) -> u8 {
     {
-        let result = 69;
-    }
+        let result = true;
+    };
+    for result in 0..4 {}
+    result
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_item_redeclared() {
+    let input = r#"
+fn result() -> u8 {
+    42
+}
+
+fn main() {
+    let result = 69;
 }
 "#;
 
     let expected = Err(Error::Semantic(SemanticError::Scope(
         ScopeError::ItemRedeclared {
-            location: Location::new(5, 13),
+            location: Location::new(7, 9),
             name: "result".to_owned(),
-            reference: Some(Location::new(3, 9)),
+            reference: Some(Location::new(2, 4)),
         },
     )));
 
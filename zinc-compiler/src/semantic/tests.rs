@@ -38,7 +38,7 @@ pub(crate) fn compile_entry_with_dependencies(
 }
 
 pub(crate) fn compile_module(input: &str) -> Result<Rc<RefCell<Scope>>, Error> {
-    let (scope, _intermediate) = ModuleAnalyzer::new().compile(
+    let (scope, _intermediate) = ModuleAnalyzer::new("module".to_owned()).compile(
         Parser::default()
             .parse(input, None)
             .expect(PANIC_SYNTAX_ERROR),
@@ -0,0 +1,153 @@
+//!
+//! The multi-file project source error.
+//!
+
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+
+#[derive(Debug)]
+pub enum Error {
+    Reading(io::Error),
+    ExtensionNotFound(PathBuf),
+    ExtensionInvalid(PathBuf),
+    StemNotFound(PathBuf),
+    EntryNotFound,
+    CyclicDependency(PathBuf),
+    Compiler(String),
+    ManifestReading(PathBuf, io::Error),
+    ManifestParsing(PathBuf, toml::de::Error),
+    DependencyInvalid(String),
+    DependencyNotFound {
+        name: String,
+        path: PathBuf,
+    },
+    DependencyConflict {
+        name: String,
+        first: PathBuf,
+        second: PathBuf,
+    },
+    LibraryEntryNotFound(PathBuf),
+    LibraryManifestNotFound,
+    LibraryReading(PathBuf, io::Error),
+    LibraryDecoding(PathBuf, String),
+    LibraryNameMismatch {
+        path: PathBuf,
+        expected: String,
+        found: String,
+    },
+    LibraryVersionMismatch {
+        name: String,
+        expected: String,
+        found: String,
+    },
+    LibrarySymbolVersionMismatch {
+        library: String,
+        symbol: String,
+        expected: String,
+        found: String,
+    },
+    LibrarySymbolUnsupported {
+        library: String,
+        symbol: String,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Reading(inner) => write!(f, "reading: {}", inner),
+            Self::ExtensionNotFound(path) => {
+                write!(f, "file extension not found: {}", path.display())
+            }
+            Self::ExtensionInvalid(path) => {
+                write!(f, "file extension is invalid: {}", path.display())
+            }
+            Self::StemNotFound(path) => write!(f, "file name not found: {}", path.display()),
+            Self::EntryNotFound => write!(f, "the 'main.zn' source file is missing"),
+            Self::CyclicDependency(path) => write!(
+                f,
+                "cyclic module dependencies are not supported, found in: {}",
+                path.display()
+            ),
+            Self::Compiler(inner) => write!(f, "{}", inner),
+            Self::ManifestReading(path, inner) => {
+                write!(f, "manifest {} reading: {}", path.display(), inner)
+            }
+            Self::ManifestParsing(path, inner) => {
+                write!(f, "manifest {} parsing: {}", path.display(), inner)
+            }
+            Self::DependencyInvalid(name) => write!(
+                f,
+                "dependency `{}` must specify either the path, the library, or the version",
+                name
+            ),
+            Self::DependencyNotFound { name, path } => {
+                write!(f, "dependency `{}` not found at {}", name, path.display())
+            }
+            Self::DependencyConflict {
+                name,
+                first,
+                second,
+            } => write!(
+                f,
+                "dependency `{}` is resolved to both {} and {}",
+                name,
+                first.display(),
+                second.display()
+            ),
+            Self::LibraryEntryNotFound(path) => write!(
+                f,
+                "the 'lib.zn' source file is missing in library {}",
+                path.display()
+            ),
+            Self::LibraryManifestNotFound => {
+                write!(
+                    f,
+                    "the library must be compiled with the 'Zinc.toml' manifest"
+                )
+            }
+            Self::LibraryReading(path, inner) => {
+                write!(f, "library {} reading: {}", path.display(), inner)
+            }
+            Self::LibraryDecoding(path, inner) => {
+                write!(f, "library {} decoding: {}", path.display(), inner)
+            }
+            Self::LibraryNameMismatch {
+                path,
+                expected,
+                found,
+            } => write!(
+                f,
+                "library {} is expected to be `{}`, found `{}`",
+                path.display(),
+                expected,
+                found
+            ),
+            Self::LibraryVersionMismatch {
+                name,
+                expected,
+                found,
+            } => write!(
+                f,
+                "library `{}` version {} is required, found {}",
+                name, expected, found
+            ),
+            Self::LibrarySymbolVersionMismatch {
+                library,
+                symbol,
+                expected,
+                found,
+            } => write!(
+                f,
+                "library `{}` symbol `{}` version {} does not match its signature version {}",
+                library, symbol, found, expected
+            ),
+            Self::LibrarySymbolUnsupported { library, symbol } => write!(
+                f,
+                "library `{}` symbol `{}` cannot be linked",
+                library, symbol
+            ),
+        }
+    }
+}
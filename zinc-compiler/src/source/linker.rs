@@ -0,0 +1,197 @@
+//!
+//! The precompiled library linker.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_bytecode::data::types::DataType;
+use zinc_bytecode::data::types::ScalarType;
+use zinc_bytecode::library::Symbol;
+use zinc_bytecode::Library as BytecodeLibrary;
+
+use crate::generator::bytecode::Bytecode;
+use crate::generator::r#type::Type as GeneratorType;
+use crate::lexical::token::location::Location;
+use crate::semantic::element::r#type::function::Function as FunctionType;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::scope::item::variant::Variant as ScopeItemVariant;
+use crate::semantic::scope::Scope;
+use crate::source::error::Error;
+use crate::syntax::tree::identifier::Identifier;
+
+///
+/// The library scope along with the library symbols and their function unique IDs.
+///
+pub type Declaration<'a> = (Rc<RefCell<Scope>>, Vec<(usize, &'a Symbol)>);
+
+///
+/// Exports the functions declared in the library root `scope` into the precompiled library.
+///
+/// Only the functions with the scalar, array, and tuple arguments and result are exported,
+/// since the structures and enumerations cannot be restored from the bytecode types.
+///
+pub fn export(name: String, version: String, scope: &Scope, bytecode: Bytecode) -> BytecodeLibrary {
+    let mut symbols = Vec::new();
+    for (identifier, item) in scope.items() {
+        let function = match item.variant {
+            ScopeItemVariant::Type(Type::Function(FunctionType::UserDefined(ref function))) => {
+                function
+            }
+            _ => continue,
+        };
+
+        let address = match bytecode.get_function_address(function.unique_id()) {
+            Some(address) => address,
+            None => continue,
+        };
+
+        if !function
+            .formal_params()
+            .iter()
+            .all(|(_name, r#type)| is_exportable(r#type))
+            || !is_exportable(function.return_type())
+        {
+            log::warn!(
+                "Function `{}` is not exported from library {}: {}",
+                identifier,
+                name,
+                "only the scalar, array, and tuple types are supported"
+            );
+            continue;
+        }
+
+        let arguments = function
+            .formal_params()
+            .iter()
+            .map(|(name, r#type)| (name.to_owned(), into_data_type(r#type)))
+            .collect();
+        let output = into_data_type(function.return_type());
+        symbols.push(Symbol::new(
+            identifier.to_owned(),
+            address,
+            arguments,
+            output,
+        ));
+    }
+    symbols.sort_by(|a, b| a.name.cmp(&b.name));
+
+    bytecode.into_library(name, version, symbols)
+}
+
+///
+/// Links the precompiled library into the `bytecode`, returning the scope with its functions.
+///
+pub fn link(
+    library: &BytecodeLibrary,
+    bytecode: &Rc<RefCell<Bytecode>>,
+) -> Result<Rc<RefCell<Scope>>, Error> {
+    let (scope, unique_ids) = declare(library)?;
+    bytecode
+        .borrow_mut()
+        .link_library(library, unique_ids.as_slice());
+
+    Ok(scope)
+}
+
+///
+/// Declares the library functions in a new scope, returning it along with the symbols
+/// and the unique IDs of their functions.
+///
+/// The symbol versions are checked against their signatures, so the functions are never
+/// called with the arguments of other types than the library has been compiled with.
+///
+pub fn declare(library: &BytecodeLibrary) -> Result<Declaration<'_>, Error> {
+    let scope = Rc::new(RefCell::new(Scope::new(None)));
+
+    let mut unique_ids = Vec::with_capacity(library.symbols.len());
+    for symbol in library.symbols.iter() {
+        if !symbol.is_version_valid() {
+            return Err(Error::LibrarySymbolVersionMismatch {
+                library: library.name.to_owned(),
+                symbol: symbol.name.to_owned(),
+                expected: symbol.fingerprint(),
+                found: symbol.version.to_owned(),
+            });
+        }
+
+        let arguments = symbol
+            .arguments
+            .iter()
+            .map(|(name, r#type)| from_data_type(r#type).map(|r#type| (name.to_owned(), r#type)))
+            .collect::<Option<Vec<(String, Type)>>>();
+        let (arguments, output) = match (arguments, from_data_type(&symbol.output)) {
+            (Some(arguments), Some(output)) => (arguments, output),
+            _ => {
+                return Err(Error::LibrarySymbolUnsupported {
+                    library: library.name.to_owned(),
+                    symbol: symbol.signature(),
+                })
+            }
+        };
+
+        let unique_id = crate::semantic::element::r#type::unique_id(
+            &[library.name.to_owned(), symbol.name.to_owned()],
+            symbol.version.as_str(),
+        );
+        let r#type =
+            Type::new_user_defined_function(symbol.name.to_owned(), unique_id, arguments, output);
+        Scope::declare_type(
+            scope.clone(),
+            Identifier::new(Location::new_beginning(None), symbol.name.to_owned()),
+            r#type,
+        )
+        .map_err(|_error| Error::LibrarySymbolUnsupported {
+            library: library.name.to_owned(),
+            symbol: symbol.signature(),
+        })?;
+
+        unique_ids.push((unique_id, symbol));
+    }
+
+    Ok((scope, unique_ids))
+}
+
+fn is_exportable(r#type: &Type) -> bool {
+    match r#type {
+        Type::Unit => true,
+        Type::Boolean => true,
+        Type::IntegerUnsigned { .. } => true,
+        Type::IntegerSigned { .. } => true,
+        Type::Field => true,
+        Type::Array { r#type, .. } => is_exportable(r#type),
+        Type::Tuple { types } => types.iter().all(is_exportable),
+        _ => false,
+    }
+}
+
+fn into_data_type(r#type: &Type) -> DataType {
+    GeneratorType::try_from_semantic(r#type)
+        .map(Into::into)
+        .unwrap_or(DataType::Unit)
+}
+
+fn from_data_type(r#type: &DataType) -> Option<Type> {
+    match r#type {
+        DataType::Unit => Some(Type::Unit),
+        DataType::Scalar(ScalarType::Boolean) => Some(Type::Boolean),
+        DataType::Scalar(ScalarType::Field) => Some(Type::Field),
+        DataType::Scalar(ScalarType::Integer(integer_type)) if integer_type.is_signed => {
+            Some(Type::IntegerSigned {
+                bitlength: integer_type.bitlength,
+            })
+        }
+        DataType::Scalar(ScalarType::Integer(integer_type)) => Some(Type::IntegerUnsigned {
+            bitlength: integer_type.bitlength,
+        }),
+        DataType::Array(r#type, size) => {
+            from_data_type(r#type).map(|r#type| Type::array(r#type, *size))
+        }
+        DataType::Tuple(types) => types
+            .iter()
+            .map(from_data_type)
+            .collect::<Option<Vec<Type>>>()
+            .map(Type::tuple),
+        DataType::Enum | DataType::Struct(_) => None,
+    }
+}
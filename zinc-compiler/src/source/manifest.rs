@@ -0,0 +1,130 @@
+//!
+//! The project manifest.
+//!
+
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::path::Path;
+use std::path::PathBuf;
+
+use serde_derive::Deserialize;
+
+use crate::source::error::Error;
+
+///
+/// The `Zinc.toml` project manifest, which declares the libraries the project depends on.
+///
+/// ```toml
+/// [project]
+/// name = "merkle"
+/// version = "0.1.0"
+///
+/// [dependencies]
+/// hashes = { path = "../hashes" }
+/// utils = "0.2.0"
+/// merkle = { library = "../merkle/build/merkle.znlib", version = "0.1.0" }
+/// ```
+///
+#[derive(Debug, Deserialize)]
+pub struct Manifest {
+    pub project: Project,
+    #[serde(default)]
+    pub dependencies: BTreeMap<String, Dependency>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Project {
+    pub name: String,
+    pub version: String,
+}
+
+///
+/// The library dependency, which is either a published version looked up in the
+/// registry, a path relative to the manifest directory, or a precompiled library
+/// with an optional version requirement.
+///
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(untagged)]
+pub enum Dependency {
+    Version(String),
+    Detailed {
+        path: Option<PathBuf>,
+        version: Option<String>,
+        library: Option<PathBuf>,
+    },
+}
+
+///
+/// The resolved location of a dependency.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum Location {
+    Path(PathBuf),
+    Registry {
+        version: String,
+    },
+    Library {
+        path: PathBuf,
+        version: Option<String>,
+    },
+}
+
+impl Manifest {
+    pub const FILE_NAME: &'static str = "Zinc.toml";
+}
+
+impl Dependency {
+    ///
+    /// Checks that the dependency specifies exactly one of the path, the precompiled library,
+    /// or the version, which may only be combined with the library as its requirement.
+    ///
+    pub fn location(&self, name: &str) -> Result<Location, Error> {
+        match self {
+            Self::Version(version) => Ok(Location::Registry {
+                version: version.to_owned(),
+            }),
+            Self::Detailed {
+                path: Some(path),
+                version: None,
+                library: None,
+            } => Ok(Location::Path(path.to_owned())),
+            Self::Detailed {
+                path: None,
+                version: Some(version),
+                library: None,
+            } => Ok(Location::Registry {
+                version: version.to_owned(),
+            }),
+            Self::Detailed {
+                path: None,
+                version,
+                library: Some(path),
+            } => Ok(Location::Library {
+                path: path.to_owned(),
+                version: version.to_owned(),
+            }),
+            Self::Detailed { .. } => Err(Error::DependencyInvalid(name.to_owned())),
+        }
+    }
+}
+
+impl TryFrom<&Path> for Manifest {
+    type Error = Error;
+
+    ///
+    /// Reads the manifest at the `path`, which is either the manifest file or the
+    /// project directory containing it.
+    ///
+    fn try_from(path: &Path) -> Result<Self, Self::Error> {
+        let path = if path.is_dir() {
+            path.join(Self::FILE_NAME)
+        } else {
+            path.to_owned()
+        };
+
+        let text = std::fs::read_to_string(&path)
+            .map_err(|error| Error::ManifestReading(path.clone(), error))?;
+
+        toml::from_str(&text).map_err(|error| Error::ManifestParsing(path, error))
+    }
+}
@@ -0,0 +1,423 @@
+//!
+//! The multi-file project source.
+//!
+
+mod tests;
+
+pub mod error;
+pub mod linker;
+pub mod manifest;
+pub mod resolver;
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::convert::TryFrom;
+use std::path::Path;
+use std::path::PathBuf;
+use std::rc::Rc;
+
+use log::debug;
+
+use zinc_bytecode::Library as BytecodeLibrary;
+
+use crate::file::File;
+use crate::generator::bytecode::Bytecode;
+use crate::semantic::scope::Scope;
+
+use self::error::Error;
+use self::manifest::Manifest;
+use self::manifest::Project;
+use self::resolver::Kind as LibraryKind;
+use self::resolver::Library;
+use self::resolver::Resolver;
+
+///
+/// The ordered modules and the entry file, if found among them.
+///
+type Ordered = (Vec<(String, PathBuf)>, Option<PathBuf>);
+
+///
+/// The project source files, ordered so that every module is compiled after
+/// the modules it depends on.
+///
+/// The libraries declared in the project manifest are compiled before the project
+/// and brought into scope with the `mod` statement, like the project modules.
+///
+#[derive(Debug)]
+pub struct Source {
+    pub modules: Vec<(String, PathBuf)>,
+    pub entry: PathBuf,
+    pub libraries: Vec<Library>,
+    pub dependencies: Vec<String>,
+    pub project: Option<Project>,
+}
+
+impl Source {
+    pub const FILE_EXTENSION: &'static str = "zn";
+    pub const ENTRY_FILE_STEM: &'static str = "main";
+    pub const LIBRARY_FILE_STEM: &'static str = "lib";
+    pub const SOURCE_DIRECTORY: &'static str = "src";
+
+    pub const REGISTRY_PATH_VARIABLE: &'static str = "ZINC_REGISTRY_PATH";
+
+    ///
+    /// Orders the source files and their dependencies.
+    ///
+    /// The dependencies are the `mod` statements of a file, which refer to the files with
+    /// the same name in the same directory. Cyclic dependencies are not supported.
+    ///
+    pub fn try_from_paths(paths: Vec<PathBuf>) -> Result<Self, Error> {
+        let (modules, entry) = Self::order(paths, Self::ENTRY_FILE_STEM, &[])?;
+
+        Ok(Self {
+            modules,
+            entry: entry.ok_or(Error::EntryNotFound)?,
+            libraries: Vec::new(),
+            dependencies: Vec::new(),
+            project: None,
+        })
+    }
+
+    ///
+    /// Orders the source files like `try_from_paths`, resolving the libraries declared
+    /// in the manifest at `manifest_path`.
+    ///
+    /// The path dependencies are relative to the manifest directory, and the registry
+    /// dependencies are looked up at `<registry_path>/<name>/<version>`.
+    ///
+    pub fn try_from_manifest(
+        manifest_path: &Path,
+        paths: Vec<PathBuf>,
+        registry_path: PathBuf,
+    ) -> Result<Self, Error> {
+        Self::resolve(manifest_path, paths, registry_path, Self::ENTRY_FILE_STEM)
+    }
+
+    ///
+    /// Orders the library source files like `try_from_manifest`, with the `lib.zn` file
+    /// being the entry.
+    ///
+    pub fn try_library_from_manifest(
+        manifest_path: &Path,
+        paths: Vec<PathBuf>,
+        registry_path: PathBuf,
+    ) -> Result<Self, Error> {
+        Self::resolve(manifest_path, paths, registry_path, Self::LIBRARY_FILE_STEM)
+    }
+
+    ///
+    /// The registry path set with the `ZINC_REGISTRY_PATH` environment variable,
+    /// or `~/.zinc/registry` by default.
+    ///
+    pub fn default_registry_path() -> PathBuf {
+        match std::env::var_os(Self::REGISTRY_PATH_VARIABLE) {
+            Some(path) => PathBuf::from(path),
+            None => std::env::var_os("HOME")
+                .map(PathBuf::from)
+                .unwrap_or_default()
+                .join(".zinc")
+                .join("registry"),
+        }
+    }
+
+    ///
+    /// Collects the source files of the directory, including its subdirectories.
+    ///
+    pub fn try_from_directory(path: &PathBuf) -> Result<Self, Error> {
+        Self::try_from_paths(Self::collect(path)?)
+    }
+
+    ///
+    /// Compiles the modules and the entry into the `bytecode`.
+    ///
+    pub fn compile(self, bytecode: Rc<RefCell<Bytecode>>) -> Result<(), Error> {
+        let dependencies =
+            Self::compile_dependencies(self.libraries, self.dependencies, self.modules, &bytecode)?;
+
+        bytecode
+            .borrow_mut()
+            .start_new_file(self.entry.to_string_lossy().as_ref());
+
+        log::info!("Compiling {:?}", self.entry);
+        File::try_from(self.entry)
+            .map_err(Error::Compiler)?
+            .try_into_entry(bytecode, dependencies)
+            .map_err(Error::Compiler)?;
+
+        Ok(())
+    }
+
+    ///
+    /// Compiles the library modules and the `lib.zn` entry into the precompiled library,
+    /// which exports the functions declared in the entry.
+    ///
+    /// The library is optimized, so the `bytecode` optimization level must be set.
+    ///
+    pub fn compile_library(
+        self,
+        bytecode: Rc<RefCell<Bytecode>>,
+    ) -> Result<BytecodeLibrary, Error> {
+        let project = self.project.ok_or(Error::LibraryManifestNotFound)?;
+
+        let dependencies =
+            Self::compile_dependencies(self.libraries, self.dependencies, self.modules, &bytecode)?;
+        let scope =
+            Self::compile_module(project.name.clone(), self.entry, &bytecode, &dependencies)?;
+
+        let mut bytecode = Rc::try_unwrap(bytecode)
+            .expect(crate::PANIC_LAST_SHARED_REFERENCE)
+            .into_inner();
+        bytecode.optimize();
+
+        let scope = scope.borrow();
+        Ok(linker::export(
+            project.name,
+            project.version,
+            &scope,
+            bytecode,
+        ))
+    }
+
+    fn resolve(
+        manifest_path: &Path,
+        paths: Vec<PathBuf>,
+        registry_path: PathBuf,
+        entry_stem: &str,
+    ) -> Result<Self, Error> {
+        let manifest = Manifest::try_from(manifest_path)?;
+        let base = if manifest_path.is_dir() {
+            manifest_path.to_owned()
+        } else {
+            manifest_path
+                .parent()
+                .map(Path::to_path_buf)
+                .unwrap_or_default()
+        };
+
+        let libraries = Resolver::new(registry_path).resolve(&manifest, &base)?;
+        let dependencies: Vec<String> = manifest.dependencies.keys().cloned().collect();
+
+        let (modules, entry) = Self::order(paths, entry_stem, dependencies.as_slice())?;
+        let entry = match entry {
+            Some(entry) => entry,
+            None if entry_stem == Self::LIBRARY_FILE_STEM => {
+                return Err(Error::LibraryEntryNotFound(base))
+            }
+            None => return Err(Error::EntryNotFound),
+        };
+
+        Ok(Self {
+            modules,
+            entry,
+            libraries,
+            dependencies,
+            project: Some(manifest.project),
+        })
+    }
+
+    ///
+    /// Compiles or links the libraries and compiles the modules, returning the scopes
+    /// the entry depends on.
+    ///
+    fn compile_dependencies(
+        libraries: Vec<Library>,
+        direct: Vec<String>,
+        modules: Vec<(String, PathBuf)>,
+        bytecode: &Rc<RefCell<Bytecode>>,
+    ) -> Result<HashMap<String, Rc<RefCell<Scope>>>, Error> {
+        let mut scopes = HashMap::<String, Rc<RefCell<Scope>>>::new();
+        for library in libraries.into_iter() {
+            let scope = match library.kind {
+                LibraryKind::Source { modules, entry } => {
+                    log::info!("Compiling library {}", library.name);
+                    let mut dependencies = Self::select(&scopes, library.dependencies.as_slice());
+                    for (name, path) in modules.into_iter() {
+                        // The library name is prepended to keep the module items unique
+                        let analyzer_name = format!("{}::{}", library.name, name);
+                        let module =
+                            Self::compile_module(analyzer_name, path, bytecode, &dependencies)?;
+                        dependencies.insert(name, module);
+                    }
+
+                    Self::compile_module(library.name.clone(), entry, bytecode, &dependencies)?
+                }
+                LibraryKind::Artifact(artifact) => {
+                    log::info!("Linking library {}", library.name);
+                    linker::link(&artifact, bytecode)?
+                }
+            };
+            scopes.insert(library.name, scope);
+        }
+
+        let mut dependencies = Self::select(&scopes, direct.as_slice());
+        for (name, path) in modules.into_iter() {
+            let module = Self::compile_module(name.clone(), path, bytecode, &dependencies)?;
+            dependencies.insert(name, module);
+        }
+
+        Ok(dependencies)
+    }
+
+    fn compile_module(
+        name: String,
+        path: PathBuf,
+        bytecode: &Rc<RefCell<Bytecode>>,
+        dependencies: &HashMap<String, Rc<RefCell<Scope>>>,
+    ) -> Result<Rc<RefCell<Scope>>, Error> {
+        bytecode
+            .borrow_mut()
+            .start_new_file(path.to_string_lossy().as_ref());
+
+        log::info!("Compiling {:?}", path);
+        File::try_from(path)
+            .map_err(Error::Compiler)?
+            .try_into_module(name, bytecode.clone(), dependencies.clone())
+            .map_err(Error::Compiler)
+    }
+
+    fn select(
+        libraries: &HashMap<String, Rc<RefCell<Scope>>>,
+        names: &[String],
+    ) -> HashMap<String, Rc<RefCell<Scope>>> {
+        names
+            .iter()
+            .filter_map(|name| {
+                libraries
+                    .get(name)
+                    .map(|scope| (name.to_owned(), scope.clone()))
+            })
+            .collect()
+    }
+
+    ///
+    /// Orders the source files, splitting them into the modules and the file with
+    /// the `entry_stem` name, if any.
+    ///
+    /// The `external` names are the libraries, which are not looked up among the files.
+    ///
+    pub(crate) fn order(
+        paths: Vec<PathBuf>,
+        entry_stem: &str,
+        external: &[String],
+    ) -> Result<Ordered, Error> {
+        let mut sorted = VecDeque::<PathBuf>::new();
+        let mut temp_marks = Vec::<PathBuf>::new();
+
+        for path in paths.into_iter() {
+            Self::check_extension(&path)?;
+            Self::visit(path, external, &mut sorted, &mut temp_marks)?;
+        }
+
+        for path in sorted.iter() {
+            debug!("Ordered file: {}", path.display());
+        }
+
+        let mut modules = Vec::with_capacity(sorted.len());
+        let mut entry = None;
+        for path in sorted.into_iter() {
+            Self::check_extension(&path)?;
+            let stem = path
+                .file_stem()
+                .ok_or_else(|| Error::StemNotFound(path.clone()))?
+                .to_string_lossy()
+                .to_string();
+
+            if stem == entry_stem {
+                entry = Some(path);
+            } else {
+                modules.push((stem, path));
+            }
+        }
+
+        Ok((modules, entry))
+    }
+
+    // The files are sorted topologically with the depth-first search:
+    //
+    // function visit(node n)
+    //     if n has a permanent mark then
+    //         return
+    //     if n has a temporary mark then
+    //         stop   (not a DAG)
+    //
+    //     mark n with a temporary mark
+    //
+    //     for each node m with an edge from n to m do
+    //         visit(m)
+    //
+    //     remove temporary mark from n
+    //     mark n with a permanent mark
+    //     add n to the sorted list
+    fn visit(
+        path: PathBuf,
+        external: &[String],
+        sorted: &mut VecDeque<PathBuf>,
+        temp_marks: &mut Vec<PathBuf>,
+    ) -> Result<(), Error> {
+        debug!("Visiting module {}", path.display());
+        if sorted.contains(&path) {
+            debug!("Already sorted: {}", path.display());
+            return Ok(());
+        }
+
+        if temp_marks.contains(&path) {
+            return Err(Error::CyclicDependency(path));
+        }
+        temp_marks.push(path.clone());
+
+        let modules = File::try_from(path.clone())
+            .map_err(Error::Compiler)?
+            .find_modules()
+            .map_err(Error::Compiler)?;
+        debug!("Found # modules: {}", modules.len());
+
+        for module in modules.into_iter() {
+            if external.contains(&module) {
+                continue;
+            }
+
+            // The modules are expected next to the file depending on them.
+            let module_path = path.with_file_name(format!("{}.{}", module, Self::FILE_EXTENSION));
+            Self::visit(module_path, external, sorted, temp_marks)?;
+        }
+
+        if let Some(position) = temp_marks.iter().position(|mark| *mark == path) {
+            temp_marks.remove(position);
+        }
+
+        debug!("Adding to sorted list: {}", path.display());
+        sorted.push_back(path);
+
+        Ok(())
+    }
+
+    pub(crate) fn collect(path: &PathBuf) -> Result<Vec<PathBuf>, Error> {
+        let mut paths = Vec::new();
+        let directory = std::fs::read_dir(path).map_err(Error::Reading)?;
+        for entry in directory.into_iter() {
+            let entry_path = entry.map_err(Error::Reading)?.path();
+            if entry_path.is_dir() {
+                paths.extend(Self::collect(&entry_path)?);
+            } else if entry_path
+                .extension()
+                .map(|extension| extension == Self::FILE_EXTENSION)
+                .unwrap_or_default()
+            {
+                paths.push(entry_path);
+            }
+        }
+        paths.sort();
+        Ok(paths)
+    }
+
+    fn check_extension(path: &Path) -> Result<(), Error> {
+        let extension = path
+            .extension()
+            .ok_or_else(|| Error::ExtensionNotFound(path.to_owned()))?;
+        if extension != Self::FILE_EXTENSION {
+            return Err(Error::ExtensionInvalid(path.to_owned()));
+        }
+        Ok(())
+    }
+}
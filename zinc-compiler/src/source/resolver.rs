@@ -0,0 +1,177 @@
+//!
+//! The library dependency resolver.
+//!
+
+use std::convert::TryFrom;
+use std::path::Path;
+use std::path::PathBuf;
+
+use log::debug;
+
+use zinc_bytecode::Library as BytecodeLibrary;
+
+use crate::source::error::Error;
+use crate::source::manifest::Dependency;
+use crate::source::manifest::Location;
+use crate::source::manifest::Manifest;
+use crate::source::Source;
+
+///
+/// The library the project depends on, with its own dependencies.
+///
+#[derive(Debug)]
+pub struct Library {
+    pub name: String,
+    pub path: PathBuf,
+    pub kind: Kind,
+    pub dependencies: Vec<String>,
+}
+
+///
+/// The library sources or the precompiled library.
+///
+#[derive(Debug)]
+pub enum Kind {
+    /// The library sources are located in the `src` directory next to its manifest,
+    /// with the `lib.zn` file being the library root.
+    Source {
+        modules: Vec<(String, PathBuf)>,
+        entry: PathBuf,
+    },
+    /// The precompiled library already contains its own dependencies.
+    Artifact(BytecodeLibrary),
+}
+
+///
+/// Resolves the dependencies of a project into the list of libraries, ordered so
+/// that every library is compiled after the libraries it depends on.
+///
+/// A library required by several dependents is only compiled once, so all of them
+/// must resolve it to the same location.
+///
+pub struct Resolver {
+    registry_path: PathBuf,
+    libraries: Vec<Library>,
+    temp_marks: Vec<String>,
+}
+
+impl Resolver {
+    pub fn new(registry_path: PathBuf) -> Self {
+        Self {
+            registry_path,
+            libraries: Vec::new(),
+            temp_marks: Vec::new(),
+        }
+    }
+
+    pub fn resolve(mut self, manifest: &Manifest, base: &Path) -> Result<Vec<Library>, Error> {
+        for (name, dependency) in manifest.dependencies.iter() {
+            self.visit(name, dependency, base)?;
+        }
+
+        Ok(self.libraries)
+    }
+
+    // The libraries are sorted topologically in the same way as the modules.
+    fn visit(&mut self, name: &str, dependency: &Dependency, base: &Path) -> Result<(), Error> {
+        let location = dependency.location(name)?;
+        let path = match location {
+            Location::Path(ref path) => base.join(path),
+            Location::Registry { ref version } => self.registry_path.join(name).join(version),
+            Location::Library { ref path, .. } => base.join(path),
+        };
+        let path = path
+            .canonicalize()
+            .map_err(|_error| Error::DependencyNotFound {
+                name: name.to_owned(),
+                path: path.clone(),
+            })?;
+        debug!("Visiting library {} at {}", name, path.display());
+
+        if let Some(library) = self.libraries.iter().find(|library| library.name == name) {
+            if library.path != path {
+                return Err(Error::DependencyConflict {
+                    name: name.to_owned(),
+                    first: library.path.clone(),
+                    second: path,
+                });
+            }
+            return Ok(());
+        }
+
+        if let Location::Library { version, .. } = location {
+            let library = Self::read_library(name, &path, version)?;
+            debug!("Adding precompiled library: {}", name);
+            self.libraries.push(Library {
+                name: name.to_owned(),
+                path,
+                kind: Kind::Artifact(library),
+                dependencies: Vec::new(),
+            });
+            return Ok(());
+        }
+
+        if self.temp_marks.iter().any(|mark| mark == name) {
+            return Err(Error::CyclicDependency(path));
+        }
+        self.temp_marks.push(name.to_owned());
+
+        let manifest = Manifest::try_from(path.as_path())?;
+        debug!(
+            "Found library {} v{}",
+            manifest.project.name, manifest.project.version
+        );
+        for (dependency_name, dependency) in manifest.dependencies.iter() {
+            self.visit(dependency_name, dependency, &path)?;
+        }
+        let dependencies: Vec<String> = manifest.dependencies.keys().cloned().collect();
+
+        self.temp_marks.retain(|mark| mark != name);
+
+        let paths = Source::collect(&path.join(Source::SOURCE_DIRECTORY))?;
+        let (modules, entry) =
+            Source::order(paths, Source::LIBRARY_FILE_STEM, dependencies.as_slice())?;
+        let entry = entry.ok_or_else(|| Error::LibraryEntryNotFound(path.clone()))?;
+
+        debug!("Adding library: {}", name);
+        self.libraries.push(Library {
+            name: name.to_owned(),
+            path,
+            kind: Kind::Source { modules, entry },
+            dependencies,
+        });
+
+        Ok(())
+    }
+
+    fn read_library(
+        name: &str,
+        path: &Path,
+        required_version: Option<String>,
+    ) -> Result<BytecodeLibrary, Error> {
+        let bytes =
+            std::fs::read(path).map_err(|error| Error::LibraryReading(path.to_owned(), error))?;
+        let library = BytecodeLibrary::from_bytes(bytes.as_slice())
+            .map_err(|error| Error::LibraryDecoding(path.to_owned(), error))?;
+
+        if library.name != name {
+            return Err(Error::LibraryNameMismatch {
+                path: path.to_owned(),
+                expected: name.to_owned(),
+                found: library.name,
+            });
+        }
+
+        if let Some(version) = required_version {
+            if library.version != version {
+                return Err(Error::LibraryVersionMismatch {
+                    name: name.to_owned(),
+                    expected: version,
+                    found: library.version,
+                });
+            }
+        }
+
+        Ok(library)
+    }
+}
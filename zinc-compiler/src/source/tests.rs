@@ -0,0 +1,435 @@
+//!
+//! The multi-file project source tests.
+//!
+
+#![cfg(test)]
+
+use std::cell::RefCell;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+use std::rc::Rc;
+
+use zinc_bytecode::data::types::DataType;
+use zinc_bytecode::data::types::ScalarType;
+use zinc_bytecode::Library as BytecodeLibrary;
+
+use crate::generator::bytecode::Bytecode;
+use crate::source::error::Error;
+use crate::source::Source;
+
+///
+/// Creates the project directory with the `files` in the system temporary directory.
+///
+fn project(name: &str, files: &[(&str, &str)]) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("zinc-source-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&path);
+    fs::create_dir_all(&path).expect("Temporary directory");
+    for (file_name, code) in files.iter() {
+        let file_path = path.join(file_name);
+        if let Some(parent) = file_path.parent() {
+            fs::create_dir_all(parent).expect("Temporary directory");
+        }
+        fs::write(file_path, code).expect("Temporary file");
+    }
+    path
+}
+
+#[test]
+fn ok_dependencies_ordered() {
+    let path = project(
+        "ordered",
+        &[
+            ("main.zn", "mod geometry;\nfn main() {}\n"),
+            ("geometry.zn", "mod util;\nfn area() {}\n"),
+            ("util.zn", "fn double() {}\n"),
+        ],
+    );
+
+    let result = Source::try_from_directory(&path).expect("Valid project");
+
+    let modules: Vec<&str> = result
+        .modules
+        .iter()
+        .map(|(name, _path)| name.as_str())
+        .collect();
+    assert_eq!(modules, vec!["util", "geometry"]);
+    assert_eq!(result.entry, path.join("main.zn"));
+
+    let _ = fs::remove_dir_all(&path);
+}
+
+#[test]
+fn error_entry_not_found() {
+    let path = project("entry", &[("util.zn", "fn double() {}\n")]);
+
+    let result = Source::try_from_directory(&path);
+
+    match result {
+        Err(Error::EntryNotFound) => {}
+        result => panic!("Expected the entry error, found {:?}", result),
+    }
+
+    let _ = fs::remove_dir_all(&path);
+}
+
+#[test]
+fn error_cyclic_dependency() {
+    let path = project(
+        "cyclic",
+        &[
+            ("main.zn", "mod util;\nfn main() {}\n"),
+            ("util.zn", "mod main;\nfn double() {}\n"),
+        ],
+    );
+
+    let result = Source::try_from_directory(&path);
+
+    match result {
+        Err(Error::CyclicDependency(cycle_path)) => assert_eq!(cycle_path, path.join("main.zn")),
+        result => panic!("Expected the cyclic dependency error, found {:?}", result),
+    }
+
+    let _ = fs::remove_dir_all(&path);
+}
+
+///
+/// Resolves the `app` project in the `path` workspace, using its `registry` directory.
+///
+fn resolve(path: &Path) -> Result<Source, Error> {
+    let app = path.join("app");
+    Source::try_from_manifest(
+        &app,
+        Source::collect(&app.join(Source::SOURCE_DIRECTORY))?,
+        path.join("registry"),
+    )
+}
+
+fn library_names(source: &Source) -> Vec<&str> {
+    source
+        .libraries
+        .iter()
+        .map(|library| library.name.as_str())
+        .collect()
+}
+
+#[test]
+fn ok_path_dependency() {
+    let path = project(
+        "path-dependency",
+        &[
+            (
+                "app/Zinc.toml",
+                "[project]\nname = 'app'\nversion = '0.1.0'\n\n[dependencies]\nmath = { path = '../math' }\n",
+            ),
+            ("app/src/main.zn", "mod math;\nmod util;\n\nfn main(value: u8) -> u8 {\n    util::double(math::square(value))\n}\n"),
+            ("app/src/util.zn", "fn double(value: u8) -> u8 {\n    value * 2\n}\n"),
+            (
+                "math/Zinc.toml",
+                "[project]\nname = 'math'\nversion = '0.1.0'\n\n[dependencies]\nbase = { path = '../base' }\n",
+            ),
+            ("math/src/lib.zn", "mod base;\nmod util;\n\nfn square(value: u8) -> u8 {\n    util::multiply(value, value) + base::zero()\n}\n"),
+            ("math/src/util.zn", "fn multiply(a: u8, b: u8) -> u8 {\n    a * b\n}\n"),
+            ("base/Zinc.toml", "[project]\nname = 'base'\nversion = '0.1.0'\n"),
+            ("base/src/lib.zn", "fn zero() -> u8 {\n    0\n}\n"),
+        ],
+    );
+
+    let source = resolve(&path).expect("Valid project");
+
+    assert_eq!(library_names(&source), vec!["base", "math"]);
+    assert_eq!(source.dependencies, vec!["math".to_owned()]);
+    let modules: Vec<&str> = source
+        .modules
+        .iter()
+        .map(|(name, _path)| name.as_str())
+        .collect();
+    assert_eq!(modules, vec!["util"]);
+
+    source
+        .compile(Rc::new(RefCell::new(Bytecode::new())))
+        .expect("Valid project");
+
+    let _ = fs::remove_dir_all(&path);
+}
+
+#[test]
+fn ok_registry_dependency() {
+    let path = project(
+        "registry-dependency",
+        &[
+            (
+                "app/Zinc.toml",
+                "[project]\nname = 'app'\nversion = '0.1.0'\n\n[dependencies]\nbase = '0.2.0'\n",
+            ),
+            (
+                "app/src/main.zn",
+                "mod base;\n\nfn main() -> u8 {\n    base::zero()\n}\n",
+            ),
+            (
+                "registry/base/0.2.0/Zinc.toml",
+                "[project]\nname = 'base'\nversion = '0.2.0'\n",
+            ),
+            (
+                "registry/base/0.2.0/src/lib.zn",
+                "fn zero() -> u8 {\n    0\n}\n",
+            ),
+        ],
+    );
+
+    let source = resolve(&path).expect("Valid project");
+
+    assert_eq!(library_names(&source), vec!["base"]);
+    assert_eq!(
+        source.libraries[0].path,
+        path.join("registry/base/0.2.0")
+            .canonicalize()
+            .expect("Valid path")
+    );
+
+    source
+        .compile(Rc::new(RefCell::new(Bytecode::new())))
+        .expect("Valid project");
+
+    let _ = fs::remove_dir_all(&path);
+}
+
+#[test]
+fn ok_shared_dependency() {
+    let path = project(
+        "shared-dependency",
+        &[
+            (
+                "app/Zinc.toml",
+                "[project]\nname = 'app'\nversion = '0.1.0'\n\n[dependencies]\nleft = { path = '../left' }\nright = { path = '../right' }\n",
+            ),
+            ("app/src/main.zn", "mod left;\nmod right;\n\nfn main() -> u8 {\n    left::one() + right::one()\n}\n"),
+            (
+                "left/Zinc.toml",
+                "[project]\nname = 'left'\nversion = '0.1.0'\n\n[dependencies]\nbase = { path = '../base' }\n",
+            ),
+            ("left/src/lib.zn", "mod base;\n\nfn one() -> u8 {\n    base::zero() + 1\n}\n"),
+            (
+                "right/Zinc.toml",
+                "[project]\nname = 'right'\nversion = '0.1.0'\n\n[dependencies]\nbase = { path = '../base' }\n",
+            ),
+            ("right/src/lib.zn", "mod base;\n\nfn one() -> u8 {\n    base::zero() + 1\n}\n"),
+            ("base/Zinc.toml", "[project]\nname = 'base'\nversion = '0.1.0'\n"),
+            ("base/src/lib.zn", "fn zero() -> u8 {\n    0\n}\n"),
+        ],
+    );
+
+    let source = resolve(&path).expect("Valid project");
+
+    assert_eq!(library_names(&source), vec!["base", "left", "right"]);
+
+    source
+        .compile(Rc::new(RefCell::new(Bytecode::new())))
+        .expect("Valid project");
+
+    let _ = fs::remove_dir_all(&path);
+}
+
+#[test]
+fn error_dependency_conflict() {
+    let path = project(
+        "dependency-conflict",
+        &[
+            (
+                "app/Zinc.toml",
+                "[project]\nname = 'app'\nversion = '0.1.0'\n\n[dependencies]\nbase = '0.2.0'\nmath = { path = '../math' }\n",
+            ),
+            ("app/src/main.zn", "fn main() {}\n"),
+            (
+                "math/Zinc.toml",
+                "[project]\nname = 'math'\nversion = '0.1.0'\n\n[dependencies]\nbase = { path = '../base' }\n",
+            ),
+            ("math/src/lib.zn", "fn one() -> u8 {\n    1\n}\n"),
+            ("base/Zinc.toml", "[project]\nname = 'base'\nversion = '0.1.0'\n"),
+            ("base/src/lib.zn", "fn zero() -> u8 {\n    0\n}\n"),
+            ("registry/base/0.2.0/Zinc.toml", "[project]\nname = 'base'\nversion = '0.2.0'\n"),
+            ("registry/base/0.2.0/src/lib.zn", "fn zero() -> u8 {\n    0\n}\n"),
+        ],
+    );
+
+    let result = resolve(&path);
+
+    match result {
+        Err(Error::DependencyConflict { name, .. }) => assert_eq!(name, "base"),
+        result => panic!("Expected the dependency conflict error, found {:?}", result),
+    }
+
+    let _ = fs::remove_dir_all(&path);
+}
+
+#[test]
+fn error_dependency_invalid() {
+    let path = project(
+        "dependency-invalid",
+        &[
+            (
+                "app/Zinc.toml",
+                "[project]\nname = 'app'\nversion = '0.1.0'\n\n[dependencies]\nbase = { path = '../base', version = '0.1.0' }\n",
+            ),
+            ("app/src/main.zn", "fn main() {}\n"),
+        ],
+    );
+
+    let result = resolve(&path);
+
+    match result {
+        Err(Error::DependencyInvalid(name)) => assert_eq!(name, "base"),
+        result => panic!("Expected the invalid dependency error, found {:?}", result),
+    }
+
+    let _ = fs::remove_dir_all(&path);
+}
+
+#[test]
+fn error_dependency_not_found() {
+    let path = project(
+        "dependency-not-found",
+        &[
+            (
+                "app/Zinc.toml",
+                "[project]\nname = 'app'\nversion = '0.1.0'\n\n[dependencies]\nbase = '0.3.0'\n",
+            ),
+            ("app/src/main.zn", "fn main() {}\n"),
+        ],
+    );
+
+    let result = resolve(&path);
+
+    match result {
+        Err(Error::DependencyNotFound { name, .. }) => assert_eq!(name, "base"),
+        result => panic!(
+            "Expected the dependency not found error, found {:?}",
+            result
+        ),
+    }
+
+    let _ = fs::remove_dir_all(&path);
+}
+
+///
+/// Compiles the `math` library of the `path` workspace into `math.znlib`.
+///
+fn compile_library(path: &Path) -> BytecodeLibrary {
+    let library = path.join("math");
+    let source = Source::try_library_from_manifest(
+        &library,
+        Source::collect(&library.join(Source::SOURCE_DIRECTORY)).expect("Valid library"),
+        path.join("registry"),
+    )
+    .expect("Valid library");
+
+    let library = source
+        .compile_library(Rc::new(RefCell::new(Bytecode::new())))
+        .expect("Valid library");
+    fs::write(path.join("math.znlib"), library.to_bytes()).expect("Temporary file");
+    library
+}
+
+static LIBRARY_MATH_FILES: [(&str, &str); 4] = [
+    (
+        "math/Zinc.toml",
+        "[project]\nname = 'math'\nversion = '0.1.0'\n",
+    ),
+    (
+        "math/src/lib.zn",
+        "mod util;\n\nstruct Point {\n    x: u8,\n    y: u8,\n}\n\nfn square(value: u8) -> u8 {\n    util::multiply(value, value)\n}\n\nfn origin() -> Point {\n    Point { x: 0, y: 0 }\n}\n",
+    ),
+    (
+        "math/src/util.zn",
+        "fn multiply(a: u8, b: u8) -> u8 {\n    a * b\n}\n",
+    ),
+    (
+        "app/src/main.zn",
+        "mod math;\n\nfn main(value: u8) -> u8 {\n    math::square(value)\n}\n",
+    ),
+];
+
+#[test]
+fn ok_library_linked() {
+    let mut files = LIBRARY_MATH_FILES.to_vec();
+    files.push((
+        "app/Zinc.toml",
+        "[project]\nname = 'app'\nversion = '0.1.0'\n\n[dependencies]\nmath = { library = '../math.znlib', version = '0.1.0' }\n",
+    ));
+    let path = project("library-linked", files.as_slice());
+
+    let library = compile_library(&path);
+    let symbols: Vec<&str> = library
+        .symbols
+        .iter()
+        .map(|symbol| symbol.name.as_str())
+        .collect();
+    assert_eq!(symbols, vec!["square"]);
+
+    let source = resolve(&path).expect("Valid project");
+    assert_eq!(library_names(&source), vec!["math"]);
+
+    source
+        .compile(Rc::new(RefCell::new(Bytecode::new())))
+        .expect("Valid project");
+
+    let _ = fs::remove_dir_all(&path);
+}
+
+#[test]
+fn error_library_version_mismatch() {
+    let mut files = LIBRARY_MATH_FILES.to_vec();
+    files.push((
+        "app/Zinc.toml",
+        "[project]\nname = 'app'\nversion = '0.1.0'\n\n[dependencies]\nmath = { library = '../math.znlib', version = '0.2.0' }\n",
+    ));
+    let path = project("library-version-mismatch", files.as_slice());
+
+    compile_library(&path);
+    let result = resolve(&path);
+
+    match result {
+        Err(Error::LibraryVersionMismatch {
+            name,
+            expected,
+            found,
+        }) => {
+            assert_eq!(name, "math");
+            assert_eq!(expected, "0.2.0");
+            assert_eq!(found, "0.1.0");
+        }
+        result => panic!("Expected the library version error, found {:?}", result),
+    }
+
+    let _ = fs::remove_dir_all(&path);
+}
+
+#[test]
+fn error_library_symbol_version_mismatch() {
+    let mut files = LIBRARY_MATH_FILES.to_vec();
+    files.push((
+        "app/Zinc.toml",
+        "[project]\nname = 'app'\nversion = '0.1.0'\n\n[dependencies]\nmath = { library = '../math.znlib' }\n",
+    ));
+    let path = project("library-symbol-version-mismatch", files.as_slice());
+
+    let mut library = compile_library(&path);
+    library.symbols[0].arguments[0].1 = DataType::Scalar(ScalarType::Field);
+    fs::write(path.join("math.znlib"), library.to_bytes()).expect("Temporary file");
+
+    let result = resolve(&path)
+        .expect("Valid project")
+        .compile(Rc::new(RefCell::new(Bytecode::new())));
+
+    match result {
+        Err(Error::LibrarySymbolVersionMismatch {
+            library, symbol, ..
+        }) => {
+            assert_eq!(library, "math");
+            assert_eq!(symbol, "square");
+        }
+        result => panic!("Expected the symbol version error, found {:?}", result),
+    }
+
+    let _ = fs::remove_dir_all(&path);
+}
@@ -65,6 +65,10 @@ pub enum Error {
         location: Location,
         found: Lexeme,
     },
+    NestingTooDeep {
+        location: Location,
+        limit: usize,
+    },
 }
 
 impl Error {
@@ -176,6 +180,10 @@ impl Error {
         Self::ExpectedMatchPattern { location, found }
     }
 
+    pub fn nesting_too_deep(location: Location, limit: usize) -> Self {
+        Self::NestingTooDeep { location, limit }
+    }
+
     pub fn format_one_of(lexemes: &[&'static str]) -> String {
         lexemes
             .iter()
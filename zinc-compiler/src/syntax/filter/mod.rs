@@ -0,0 +1,274 @@
+//!
+//! The conditional compilation filter.
+//!
+
+mod tests;
+
+use std::collections::HashSet;
+use std::sync::RwLock;
+
+use lazy_static::lazy_static;
+
+use crate::syntax::tree::attribute::Attribute;
+use crate::syntax::tree::expression::array::variant::Variant as ArrayExpressionVariant;
+use crate::syntax::tree::expression::block::Expression as BlockExpression;
+use crate::syntax::tree::expression::tree::node::operand::Operand as ExpressionOperand;
+use crate::syntax::tree::expression::tree::node::Node as ExpressionTreeNode;
+use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
+use crate::syntax::tree::statement::local_fn::Statement as FunctionLocalStatement;
+use crate::syntax::tree::statement::local_impl::Statement as ImplementationLocalStatement;
+use crate::syntax::tree::statement::local_mod::Statement as ModuleLocalStatement;
+use crate::syntax::tree::statement::r#fn::Statement as FnStatement;
+use crate::syntax::tree::Tree as SyntaxTree;
+
+lazy_static! {
+    pub static ref FEATURES: RwLock<HashSet<String>> = RwLock::new(HashSet::new());
+}
+
+///
+/// The filter removes the statements whose `#[cfg(...)]` predicates are not satisfied
+/// by the enabled features, so the semantic analyzer never sees them.
+///
+/// A predicate is satisfied if all of its features are enabled, e.g. `#[cfg(test, debug)]`
+/// requires both `test` and `debug`.
+///
+/// The `#[test]` functions are only compiled with the `test` feature, as if they were
+/// marked with `#[cfg(test)]`.
+///
+pub struct Filter {
+    features: HashSet<String>,
+}
+
+impl Default for Filter {
+    fn default() -> Self {
+        Self::new(FEATURES.read().expect(crate::PANIC_MUTEX_SYNC).clone())
+    }
+}
+
+impl Filter {
+    pub const FEATURE_TEST: &'static str = "test";
+
+    pub fn new(features: HashSet<String>) -> Self {
+        Self { features }
+    }
+
+    ///
+    /// Enables the features for all the files compiled afterwards.
+    ///
+    pub fn enable_features<I>(features: I)
+    where
+        I: IntoIterator<Item = String>,
+    {
+        FEATURES
+            .write()
+            .expect(crate::PANIC_MUTEX_SYNC)
+            .extend(features);
+    }
+
+    ///
+    /// Removes the disabled statements from the syntax tree.
+    ///
+    pub fn filter(&self, mut tree: SyntaxTree) -> SyntaxTree {
+        tree.statements = tree
+            .statements
+            .into_iter()
+            .filter_map(|statement| self.local_mod(statement))
+            .collect();
+        tree
+    }
+
+    fn local_mod(&self, statement: ModuleLocalStatement) -> Option<ModuleLocalStatement> {
+        match statement {
+            ModuleLocalStatement::Cfg(attribute, statement) => {
+                if self.is_enabled(&attribute) {
+                    self.local_mod(*statement)
+                } else {
+                    None
+                }
+            }
+            ModuleLocalStatement::Const(mut statement) => {
+                self.expression(&mut statement.expression);
+                Some(ModuleLocalStatement::Const(statement))
+            }
+            ModuleLocalStatement::Fn(statement) => {
+                self.r#fn(statement).map(ModuleLocalStatement::Fn)
+            }
+            ModuleLocalStatement::Impl(mut statement) => {
+                statement.statements = statement
+                    .statements
+                    .into_iter()
+                    .filter_map(|statement| self.local_impl(statement))
+                    .collect();
+                Some(ModuleLocalStatement::Impl(statement))
+            }
+            statement => Some(statement),
+        }
+    }
+
+    fn local_impl(
+        &self,
+        statement: ImplementationLocalStatement,
+    ) -> Option<ImplementationLocalStatement> {
+        match statement {
+            ImplementationLocalStatement::Cfg(attribute, statement) => {
+                if self.is_enabled(&attribute) {
+                    self.local_impl(*statement)
+                } else {
+                    None
+                }
+            }
+            ImplementationLocalStatement::Const(mut statement) => {
+                self.expression(&mut statement.expression);
+                Some(ImplementationLocalStatement::Const(statement))
+            }
+            ImplementationLocalStatement::Fn(statement) => {
+                self.r#fn(statement).map(ImplementationLocalStatement::Fn)
+            }
+            statement => Some(statement),
+        }
+    }
+
+    fn local_fn(&self, statement: FunctionLocalStatement) -> Option<FunctionLocalStatement> {
+        match statement {
+            FunctionLocalStatement::Cfg(attribute, statement) => {
+                if self.is_enabled(&attribute) {
+                    self.local_fn(*statement)
+                } else {
+                    None
+                }
+            }
+            FunctionLocalStatement::Let(mut statement) => {
+                self.expression(&mut statement.expression);
+                Some(FunctionLocalStatement::Let(statement))
+            }
+            FunctionLocalStatement::Const(mut statement) => {
+                self.expression(&mut statement.expression);
+                Some(FunctionLocalStatement::Const(statement))
+            }
+            FunctionLocalStatement::For(mut statement) => {
+                self.expression(&mut statement.bounds_expression);
+                if let Some(condition) = statement.while_condition.as_mut() {
+                    self.expression(condition);
+                }
+                self.block(&mut statement.block);
+                Some(FunctionLocalStatement::For(statement))
+            }
+            FunctionLocalStatement::Expression(mut expression) => {
+                self.expression(&mut expression);
+                Some(FunctionLocalStatement::Expression(expression))
+            }
+            statement => Some(statement),
+        }
+    }
+
+    ///
+    /// The `#[cfg(...)]` attributes may also follow the other function attributes,
+    /// in which case they are parsed as a part of the function statement.
+    ///
+    fn r#fn(&self, mut statement: FnStatement) -> Option<FnStatement> {
+        let (conditions, attributes): (Vec<Attribute>, Vec<Attribute>) = statement
+            .attributes
+            .into_iter()
+            .partition(|attribute| attribute.is_cfg());
+        if !conditions
+            .iter()
+            .all(|condition| self.is_enabled(condition))
+        {
+            return None;
+        }
+        if attributes.iter().any(Attribute::is_test)
+            && !self.features.contains(Self::FEATURE_TEST)
+        {
+            return None;
+        }
+
+        statement.attributes = attributes;
+        self.block(&mut statement.body);
+        Some(statement)
+    }
+
+    fn block(&self, block: &mut BlockExpression) {
+        block.statements = std::mem::take(&mut block.statements)
+            .into_iter()
+            .filter_map(|statement| self.local_fn(statement))
+            .collect();
+        if let Some(expression) = block.expression.as_mut() {
+            self.expression(expression);
+        }
+    }
+
+    fn expression(&self, tree: &mut ExpressionTree) {
+        if let Some(left) = tree.left.as_mut() {
+            self.expression(left);
+        }
+        if let Some(right) = tree.right.as_mut() {
+            self.expression(right);
+        }
+
+        let operand = match tree.value.as_mut() {
+            ExpressionTreeNode::Operand(operand) => operand,
+            ExpressionTreeNode::Operator(_) => return,
+        };
+        match operand {
+            ExpressionOperand::Block(block) => self.block(block),
+            ExpressionOperand::Conditional(conditional) => {
+                self.expression(&mut conditional.condition);
+                self.block(&mut conditional.main_block);
+                if let Some(block) = conditional.else_block.as_mut() {
+                    self.block(block);
+                }
+            }
+            ExpressionOperand::Match(r#match) => {
+                self.expression(&mut r#match.scrutinee);
+                for (_pattern, expression) in r#match.branches.iter_mut() {
+                    self.expression(expression);
+                }
+            }
+            ExpressionOperand::Array(array) => match &mut array.variant {
+                ArrayExpressionVariant::List { elements } => {
+                    for element in elements.iter_mut() {
+                        self.expression(element);
+                    }
+                }
+                ArrayExpressionVariant::Repeated {
+                    expression,
+                    size_expression,
+                } => {
+                    self.expression(expression);
+                    self.expression(size_expression);
+                }
+                ArrayExpressionVariant::Comprehension {
+                    expression,
+                    bounds_expression,
+                    ..
+                } => {
+                    self.expression(expression);
+                    self.expression(bounds_expression);
+                }
+            },
+            ExpressionOperand::Tuple(tuple) => {
+                for element in tuple.elements.iter_mut() {
+                    self.expression(element);
+                }
+            }
+            ExpressionOperand::List(list) => {
+                for element in list.elements.iter_mut() {
+                    self.expression(element);
+                }
+            }
+            ExpressionOperand::Structure(structure) => {
+                for (_identifier, expression) in structure.fields.iter_mut() {
+                    self.expression(expression);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn is_enabled(&self, attribute: &Attribute) -> bool {
+        attribute
+            .arguments
+            .iter()
+            .all(|argument| self.features.contains(argument.name.as_str()))
+    }
+}
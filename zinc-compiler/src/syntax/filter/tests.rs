@@ -0,0 +1,188 @@
+//!
+//! The conditional compilation filter tests.
+//!
+
+#![cfg(test)]
+
+use std::collections::HashSet;
+
+use crate::syntax::filter::Filter;
+use crate::syntax::parser::Parser;
+use crate::syntax::tree::statement::local_impl::Statement as ImplementationLocalStatement;
+use crate::syntax::tree::statement::local_mod::Statement as ModuleLocalStatement;
+use crate::syntax::tree::Tree as SyntaxTree;
+
+fn filter(input: &str, features: &[&str]) -> SyntaxTree {
+    let tree = Parser::default()
+        .parse(input, None)
+        .expect(crate::semantic::tests::PANIC_TEST_DATA);
+    let features: HashSet<String> = features.iter().map(|name| (*name).to_owned()).collect();
+    Filter::new(features).filter(tree)
+}
+
+fn function_names(tree: &SyntaxTree) -> Vec<String> {
+    tree.statements
+        .iter()
+        .filter_map(|statement| match statement {
+            ModuleLocalStatement::Fn(statement) => Some(statement.identifier.name.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn ok_module_statement_disabled() {
+    let input = r#"
+#[cfg(test)]
+fn helper() {}
+
+#[cfg(test)]
+const VALUE: u8 = 42;
+
+fn main() {}
+"#;
+
+    let tree = filter(input, &[]);
+
+    assert_eq!(tree.statements.len(), 1);
+    assert_eq!(function_names(&tree), vec!["main".to_owned()]);
+}
+
+#[test]
+fn ok_module_statement_enabled() {
+    let input = r#"
+#[cfg(test)]
+fn helper() {}
+
+fn main() {}
+"#;
+
+    let tree = filter(input, &["test"]);
+
+    assert_eq!(
+        function_names(&tree),
+        vec!["helper".to_owned(), "main".to_owned()]
+    );
+}
+
+#[test]
+fn ok_all_features_required() {
+    let input = r#"
+#[cfg(test, debug)]
+fn helper() {}
+
+fn main() {}
+"#;
+
+    assert_eq!(
+        function_names(&filter(input, &["test"])),
+        vec!["main".to_owned()]
+    );
+    assert_eq!(
+        function_names(&filter(input, &["test", "debug"])),
+        vec!["helper".to_owned(), "main".to_owned()]
+    );
+}
+
+#[test]
+fn ok_function_statement_disabled() {
+    let input = r#"
+fn main(value: u8) -> u8 {
+    #[cfg(debug)]
+    assert!(value < 42);
+    if value > 10 {
+        #[cfg(debug)]
+        let copy = value;
+        value
+    } else {
+        0
+    }
+}
+"#;
+
+    let tree = filter(input, &[]);
+
+    let body = match tree.statements.first() {
+        Some(ModuleLocalStatement::Fn(statement)) => &statement.body,
+        _ => panic!("{}", crate::semantic::tests::PANIC_TEST_DATA),
+    };
+    assert!(body.statements.is_empty());
+
+    let enabled = filter(input, &["debug"]);
+    let body = match enabled.statements.first() {
+        Some(ModuleLocalStatement::Fn(statement)) => &statement.body,
+        _ => panic!("{}", crate::semantic::tests::PANIC_TEST_DATA),
+    };
+    assert_eq!(body.statements.len(), 1);
+}
+
+#[test]
+fn ok_cfg_after_other_attributes() {
+    let input = r#"
+#[inline]
+#[cfg(test)]
+fn helper() {}
+
+fn main() {}
+"#;
+
+    assert_eq!(function_names(&filter(input, &[])), vec!["main".to_owned()]);
+
+    let tree = filter(input, &["test"]);
+    match tree.statements.first() {
+        Some(ModuleLocalStatement::Fn(statement)) => {
+            assert_eq!(statement.attributes.len(), 1);
+            assert!(!statement.attributes[0].is_cfg());
+        }
+        _ => panic!("{}", crate::semantic::tests::PANIC_TEST_DATA),
+    }
+}
+
+#[test]
+fn ok_implementation_statement_disabled() {
+    let input = r#"
+struct Data {
+    value: u8,
+}
+
+impl Data {
+    #[cfg(test)]
+    fn helper(self) -> u8 { self.value }
+
+    fn get(self) -> u8 { self.value }
+}
+
+fn main() {}
+"#;
+
+    let tree = filter(input, &[]);
+
+    match tree.statements.get(1) {
+        Some(ModuleLocalStatement::Impl(statement)) => {
+            assert_eq!(statement.statements.len(), 1);
+            match statement.statements.first() {
+                Some(ImplementationLocalStatement::Fn(statement)) => {
+                    assert_eq!(statement.identifier.name, "get")
+                }
+                _ => panic!("{}", crate::semantic::tests::PANIC_TEST_DATA),
+            }
+        }
+        _ => panic!("{}", crate::semantic::tests::PANIC_TEST_DATA),
+    }
+}
+
+#[test]
+fn ok_test_function_requires_test_feature() {
+    let input = r#"
+#[test]
+fn it_works() {}
+
+fn main() {}
+"#;
+
+    assert_eq!(function_names(&filter(input, &[])), vec!["main".to_owned()]);
+    assert_eq!(
+        function_names(&filter(input, &["test"])),
+        vec!["it_works".to_owned(), "main".to_owned()]
+    );
+}
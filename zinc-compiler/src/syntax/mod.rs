@@ -3,5 +3,6 @@
 //!
 
 pub mod error;
+pub mod filter;
 pub mod parser;
 pub mod tree;
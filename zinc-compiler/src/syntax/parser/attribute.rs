@@ -0,0 +1,293 @@
+//!
+//! The attribute parser.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::error::Error;
+use crate::lexical::stream::TokenStream;
+use crate::lexical::token::lexeme::symbol::Symbol;
+use crate::lexical::token::lexeme::Lexeme;
+use crate::lexical::token::Token;
+use crate::syntax::error::Error as SyntaxError;
+use crate::syntax::tree::attribute::builder::Builder as AttributeBuilder;
+use crate::syntax::tree::attribute::Attribute;
+use crate::syntax::tree::identifier::Identifier;
+
+static HINT_EXPECTED_IDENTIFIER: &str = "H0001";
+static HINT_EXPECTED_PREDICATE: &str = "H0024";
+
+#[derive(Debug, Clone, Copy)]
+pub enum State {
+    NumberSign,
+    BracketSquareLeft,
+    Identifier,
+    ParenthesisLeftOrBracketSquareRight,
+    ArgumentOrParenthesisRight,
+    CommaOrParenthesisRight,
+    BracketSquareRight,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        State::NumberSign
+    }
+}
+
+#[derive(Default)]
+pub struct Parser {
+    state: State,
+    builder: AttributeBuilder,
+    is_cfg: bool,
+    has_arguments: bool,
+}
+
+impl Parser {
+    ///
+    /// Parses an attribute.
+    ///
+    /// '#[inline]'
+    /// '#[inline(never)]'
+    /// '#[cfg(test)]'
+    ///
+    pub fn parse(
+        mut self,
+        stream: Rc<RefCell<TokenStream>>,
+        mut initial: Option<Token>,
+    ) -> Result<(Attribute, Option<Token>), Error> {
+        loop {
+            match self.state {
+                State::NumberSign => {
+                    match crate::syntax::parser::take_or_next(initial.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::NumberSign),
+                            location,
+                        } => {
+                            self.builder.set_location(location);
+                            self.state = State::BracketSquareLeft;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["#"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::BracketSquareLeft => {
+                    match crate::syntax::parser::take_or_next(None, stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::BracketSquareLeft),
+                            ..
+                        } => self.state = State::Identifier,
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["["],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::Identifier => {
+                    match crate::syntax::parser::take_or_next(None, stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Identifier(identifier),
+                            location,
+                        } => {
+                            let identifier = Identifier::new(location, identifier.inner);
+                            self.is_cfg = identifier.name == Attribute::CFG;
+                            self.builder.set_identifier(identifier);
+                            self.state = State::ParenthesisLeftOrBracketSquareRight;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_identifier(
+                                location,
+                                lexeme,
+                                Some(HINT_EXPECTED_IDENTIFIER),
+                            )));
+                        }
+                    }
+                }
+                State::ParenthesisLeftOrBracketSquareRight => {
+                    match crate::syntax::parser::take_or_next(None, stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisLeft),
+                            ..
+                        } => self.state = State::ArgumentOrParenthesisRight,
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::BracketSquareRight),
+                            ..
+                        } if !self.is_cfg => return Ok((self.builder.finish(), None)),
+                        Token { lexeme, location } if self.is_cfg => {
+                            return Err(Error::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["("],
+                                lexeme,
+                                Some(HINT_EXPECTED_PREDICATE),
+                            )));
+                        }
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["(", "]"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::ArgumentOrParenthesisRight => {
+                    match crate::syntax::parser::take_or_next(None, stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Identifier(identifier),
+                            location,
+                        } => {
+                            let identifier = Identifier::new(location, identifier.inner);
+                            self.builder.push_argument(identifier);
+                            self.has_arguments = true;
+                            self.state = State::CommaOrParenthesisRight;
+                        }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisRight),
+                            ..
+                        } if !self.is_cfg || self.has_arguments => {
+                            self.state = State::BracketSquareRight
+                        }
+                        Token { lexeme, location } if self.is_cfg => {
+                            return Err(Error::Syntax(SyntaxError::expected_identifier(
+                                location,
+                                lexeme,
+                                Some(HINT_EXPECTED_PREDICATE),
+                            )));
+                        }
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_identifier(
+                                location, lexeme, None,
+                            )));
+                        }
+                    }
+                }
+                State::CommaOrParenthesisRight => {
+                    match crate::syntax::parser::take_or_next(None, stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Comma),
+                            ..
+                        } => self.state = State::ArgumentOrParenthesisRight,
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisRight),
+                            ..
+                        } => self.state = State::BracketSquareRight,
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec![",", ")"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::BracketSquareRight => {
+                    return match crate::syntax::parser::take_or_next(None, stream)? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::BracketSquareRight),
+                            ..
+                        } => Ok((self.builder.finish(), None)),
+                        Token { lexeme, location } => Err(Error::Syntax(
+                            SyntaxError::expected_one_of(location, vec!["]"], lexeme, None),
+                        )),
+                    };
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::Parser;
+    use crate::error::Error;
+    use crate::lexical::stream::TokenStream;
+    use crate::lexical::token::lexeme::keyword::Keyword;
+    use crate::lexical::token::lexeme::symbol::Symbol;
+    use crate::lexical::token::lexeme::Lexeme;
+    use crate::lexical::token::location::Location;
+    use crate::syntax::error::Error as SyntaxError;
+    use crate::syntax::tree::attribute::Attribute;
+    use crate::syntax::tree::identifier::Identifier;
+
+    #[test]
+    fn ok_identifier() {
+        let input = r#"#[inline]"#;
+
+        let expected = Ok((
+            Attribute::new(
+                Location::new(1, 1),
+                Identifier::new(Location::new(1, 3), "inline".to_owned()),
+                vec![],
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_arguments() {
+        let input = r#"#[inline(never)]"#;
+
+        let expected = Ok((
+            Attribute::new(
+                Location::new(1, 1),
+                Identifier::new(Location::new(1, 3), "inline".to_owned()),
+                vec![Identifier::new(Location::new(1, 10), "never".to_owned())],
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_bracket_square_right() {
+        let input = r#"#[inline fn"#;
+
+        let expected = Err(Error::Syntax(SyntaxError::expected_one_of(
+            Location::new(1, 10),
+            vec!["(", "]"],
+            Lexeme::Keyword(Keyword::Fn),
+            None,
+        )));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_cfg_expected_predicate() {
+        let input = r#"#[cfg()]"#;
+
+        let expected = Err(Error::Syntax(SyntaxError::expected_identifier(
+            Location::new(1, 7),
+            Lexeme::Symbol(Symbol::ParenthesisRight),
+            Some(super::HINT_EXPECTED_PREDICATE),
+        )));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+}
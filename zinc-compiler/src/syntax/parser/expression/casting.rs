@@ -10,7 +10,8 @@ use crate::lexical::stream::TokenStream;
 use crate::lexical::token::lexeme::symbol::Symbol;
 use crate::lexical::token::lexeme::Lexeme;
 use crate::lexical::token::Token;
-use crate::syntax::parser::expression::access::Parser as AccessOperandParser;
+use crate::syntax::parser::expression::power::Parser as PowerOperandParser;
+use crate::syntax::parser::nesting::Nesting;
 use crate::syntax::tree::expression::tree::builder::Builder as ExpressionTreeBuilder;
 use crate::syntax::tree::expression::tree::node::operator::Operator as ExpressionOperator;
 use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
@@ -23,12 +24,13 @@ pub struct Parser {
 impl Parser {
     ///
     /// Parses a casting expression operand, which is
-    /// a lower precedence unary logical NOT, bitwise NOT or negation, or binary access
+    /// a lower precedence unary logical NOT, bitwise NOT or negation, or binary exponentiation
     /// operator expression.
     ///
     /// '-42'
     /// '~0b101010'
     /// '!true'
+    /// '2 ** 8'
     /// 'foo(bar, 42, true)[42][0..5].4.value
     ///
     pub fn parse(
@@ -41,6 +43,7 @@ impl Parser {
                 lexeme: Lexeme::Symbol(Symbol::ExclamationMark),
                 location,
             } => {
+                let _nesting = Nesting::enter(location)?;
                 let (expression, next) = Self::default().parse(stream, None)?;
                 self.builder.eat(expression);
                 self.builder.eat_operator(ExpressionOperator::Not, location);
@@ -50,6 +53,7 @@ impl Parser {
                 lexeme: Lexeme::Symbol(Symbol::Tilde),
                 location,
             } => {
+                let _nesting = Nesting::enter(location)?;
                 let (expression, next) = Self::default().parse(stream, None)?;
                 self.builder.eat(expression);
                 self.builder
@@ -60,6 +64,7 @@ impl Parser {
                 lexeme: Lexeme::Symbol(Symbol::Minus),
                 location,
             } => {
+                let _nesting = Nesting::enter(location)?;
                 let (expression, next) = Self::default().parse(stream, None)?;
                 self.builder.eat(expression);
                 self.builder
@@ -68,7 +73,7 @@ impl Parser {
             }
             token => {
                 let (expression, next) =
-                    AccessOperandParser::default().parse(stream, Some(token))?;
+                    PowerOperandParser::default().parse(stream, Some(token))?;
                 self.builder.eat(expression);
                 Ok((self.builder.finish(), next))
             }
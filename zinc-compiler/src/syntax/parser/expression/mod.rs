@@ -15,6 +15,7 @@ pub mod comparison;
 pub mod mul_div_rem;
 pub mod or;
 pub mod path;
+pub mod power;
 pub mod range;
 pub mod terminal;
 pub mod xor;
@@ -28,6 +29,7 @@ use crate::lexical::token::lexeme::symbol::Symbol;
 use crate::lexical::token::lexeme::Lexeme;
 use crate::lexical::token::Token;
 use crate::syntax::parser::expression::assignment::Parser as AssignmentOperandParser;
+use crate::syntax::parser::nesting::Nesting;
 use crate::syntax::tree::expression::tree::builder::Builder as ExpressionTreeBuilder;
 use crate::syntax::tree::expression::tree::node::operator::Operator as ExpressionOperator;
 use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
@@ -63,6 +65,10 @@ impl Parser {
         stream: Rc<RefCell<TokenStream>>,
         mut initial: Option<Token>,
     ) -> Result<(ExpressionTree, Option<Token>), Error> {
+        let token = crate::syntax::parser::take_or_next(initial.take(), stream.clone())?;
+        let _nesting = Nesting::enter(token.location)?;
+        initial = Some(token);
+
         loop {
             match self.state {
                 State::AssignmentFirstOperand => {
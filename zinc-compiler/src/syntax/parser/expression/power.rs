@@ -0,0 +1,159 @@
+//!
+//! The power operand parser.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::error::Error;
+use crate::lexical::stream::TokenStream;
+use crate::lexical::token::lexeme::symbol::Symbol;
+use crate::lexical::token::lexeme::Lexeme;
+use crate::lexical::token::Token;
+use crate::syntax::parser::expression::access::Parser as AccessOperandParser;
+use crate::syntax::parser::expression::casting::Parser as CastingOperandParser;
+use crate::syntax::parser::nesting::Nesting;
+use crate::syntax::tree::expression::tree::builder::Builder as ExpressionTreeBuilder;
+use crate::syntax::tree::expression::tree::node::operator::Operator as ExpressionOperator;
+use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
+
+#[derive(Default)]
+pub struct Parser {
+    builder: ExpressionTreeBuilder,
+}
+
+impl Parser {
+    ///
+    /// Parses a unary operator expression operand, which is
+    /// a lower precedence binary exponentiation operator expression.
+    ///
+    /// The exponent is parsed as a unary operator expression, so the operator is
+    /// right-associative, and `-2 ** 2` is parsed as `-(2 ** 2)`.
+    ///
+    /// '2 ** 8'
+    ///
+    pub fn parse(
+        mut self,
+        stream: Rc<RefCell<TokenStream>>,
+        initial: Option<Token>,
+    ) -> Result<(ExpressionTree, Option<Token>), Error> {
+        let (expression, next) = AccessOperandParser::default().parse(stream.clone(), initial)?;
+        self.builder.eat(expression);
+
+        match crate::syntax::parser::take_or_next(next, stream.clone())? {
+            Token {
+                lexeme: Lexeme::Symbol(Symbol::DoubleAsterisk),
+                location,
+            } => {
+                let _nesting = Nesting::enter(location)?;
+                self.builder
+                    .eat_operator(ExpressionOperator::Exponentiation, location);
+                let (expression, next) = CastingOperandParser::default().parse(stream, None)?;
+                self.builder.eat(expression);
+                Ok((self.builder.finish(), next))
+            }
+            token => Ok((self.builder.finish(), Some(token))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::Parser;
+    use crate::lexical::stream::TokenStream;
+    use crate::lexical::token::lexeme::literal::integer::Integer as LexicalIntegerLiteral;
+    use crate::lexical::token::lexeme::Lexeme;
+    use crate::lexical::token::location::Location;
+    use crate::lexical::token::Token;
+    use crate::syntax::tree::expression::tree::node::operand::Operand as ExpressionOperand;
+    use crate::syntax::tree::expression::tree::node::operator::Operator as ExpressionOperator;
+    use crate::syntax::tree::expression::tree::node::Node as ExpressionTreeNode;
+    use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
+    use crate::syntax::tree::literal::integer::Literal as IntegerLiteral;
+
+    #[test]
+    fn ok() {
+        let input = r#"2 ** 8"#;
+
+        let expected = Ok((
+            ExpressionTree::new_with_leaves(
+                Location::new(1, 3),
+                ExpressionTreeNode::operator(ExpressionOperator::Exponentiation),
+                Some(ExpressionTree::new(
+                    Location::new(1, 1),
+                    ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
+                        IntegerLiteral::new(
+                            Location::new(1, 1),
+                            LexicalIntegerLiteral::new_decimal("2".to_owned()),
+                        ),
+                    )),
+                )),
+                Some(ExpressionTree::new(
+                    Location::new(1, 6),
+                    ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
+                        IntegerLiteral::new(
+                            Location::new(1, 6),
+                            LexicalIntegerLiteral::new_decimal("8".to_owned()),
+                        ),
+                    )),
+                )),
+            ),
+            Some(Token::new(Lexeme::Eof, Location::new(1, 7))),
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_right_associative() {
+        let input = r#"2 ** 3 ** 2"#;
+
+        let expected = Ok((
+            ExpressionTree::new_with_leaves(
+                Location::new(1, 3),
+                ExpressionTreeNode::operator(ExpressionOperator::Exponentiation),
+                Some(ExpressionTree::new(
+                    Location::new(1, 1),
+                    ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
+                        IntegerLiteral::new(
+                            Location::new(1, 1),
+                            LexicalIntegerLiteral::new_decimal("2".to_owned()),
+                        ),
+                    )),
+                )),
+                Some(ExpressionTree::new_with_leaves(
+                    Location::new(1, 8),
+                    ExpressionTreeNode::operator(ExpressionOperator::Exponentiation),
+                    Some(ExpressionTree::new(
+                        Location::new(1, 6),
+                        ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
+                            IntegerLiteral::new(
+                                Location::new(1, 6),
+                                LexicalIntegerLiteral::new_decimal("3".to_owned()),
+                            ),
+                        )),
+                    )),
+                    Some(ExpressionTree::new(
+                        Location::new(1, 11),
+                        ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
+                            IntegerLiteral::new(
+                                Location::new(1, 11),
+                                LexicalIntegerLiteral::new_decimal("2".to_owned()),
+                            ),
+                        )),
+                    )),
+                )),
+            ),
+            Some(Token::new(Lexeme::Eof, Location::new(1, 12))),
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+}
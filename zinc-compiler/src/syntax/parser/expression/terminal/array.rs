@@ -7,6 +7,7 @@ use std::rc::Rc;
 
 use crate::error::Error;
 use crate::lexical::stream::TokenStream;
+use crate::lexical::token::lexeme::keyword::Keyword;
 use crate::lexical::token::lexeme::symbol::Symbol;
 use crate::lexical::token::lexeme::Lexeme;
 use crate::lexical::token::Token;
@@ -14,6 +15,9 @@ use crate::syntax::error::Error as SyntaxError;
 use crate::syntax::parser::expression::Parser as ExpressionParser;
 use crate::syntax::tree::expression::array::builder::Builder as ArrayExpressionBuilder;
 use crate::syntax::tree::expression::array::Expression as ArrayExpression;
+use crate::syntax::tree::identifier::Identifier;
+
+static HINT_EXPECTED_INDEX_IDENTIFIER: &str = "H0028";
 
 #[derive(Debug, Clone, Copy)]
 pub enum State {
@@ -21,8 +25,11 @@ pub enum State {
     FirstExpressionOrBracketSquareRight,
     ExpressionOrBracketSquareRight,
     CommaOrBracketSquareRight,
-    CommaOrSemicolonOrBracketSquareRight,
+    CommaOrSemicolonOrKeywordForOrBracketSquareRight,
     SizeExpression,
+    IndexIdentifier,
+    KeywordIn,
+    BoundsExpression,
     BracketSquareRight,
 }
 
@@ -44,6 +51,8 @@ impl Parser {
     /// Parses an array literal.
     ///
     /// '[1, 2, 3]'
+    /// '[0; 10]'
+    /// '[i * 2 for i in 0..10]'
     ///
     pub fn parse(
         mut self,
@@ -84,7 +93,7 @@ impl Parser {
                                 ExpressionParser::default().parse(stream.clone(), Some(token))?;
                             self.next = next;
                             self.builder.push_expression(expression);
-                            self.state = State::CommaOrSemicolonOrBracketSquareRight;
+                            self.state = State::CommaOrSemicolonOrKeywordForOrBracketSquareRight;
                         }
                     }
                 }
@@ -129,7 +138,7 @@ impl Parser {
                         }
                     }
                 }
-                State::CommaOrSemicolonOrBracketSquareRight => {
+                State::CommaOrSemicolonOrKeywordForOrBracketSquareRight => {
                     match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
                         Token {
                             lexeme: Lexeme::Symbol(Symbol::Comma),
@@ -143,6 +152,12 @@ impl Parser {
                         } => {
                             self.state = State::SizeExpression;
                         }
+                        Token {
+                            lexeme: Lexeme::Keyword(Keyword::For),
+                            ..
+                        } => {
+                            self.state = State::IndexIdentifier;
+                        }
                         Token {
                             lexeme: Lexeme::Symbol(Symbol::BracketSquareRight),
                             ..
@@ -152,7 +167,7 @@ impl Parser {
                         Token { lexeme, location } => {
                             return Err(Error::Syntax(SyntaxError::expected_one_of(
                                 location,
-                                vec![",", ";", "]"],
+                                vec![",", ";", "for", "]"],
                                 lexeme,
                                 None,
                             )));
@@ -167,6 +182,51 @@ impl Parser {
                     self.builder.set_size_expression(expression);
                     self.state = State::BracketSquareRight;
                 }
+                State::IndexIdentifier => {
+                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Identifier(identifier),
+                            location,
+                        } => {
+                            let identifier = Identifier::new(location, identifier.inner);
+                            self.builder.set_index_identifier(identifier);
+                            self.state = State::KeywordIn;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_identifier(
+                                location,
+                                lexeme,
+                                Some(HINT_EXPECTED_INDEX_IDENTIFIER),
+                            )));
+                        }
+                    }
+                }
+                State::KeywordIn => {
+                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Keyword(Keyword::In),
+                            ..
+                        } => {
+                            self.state = State::BoundsExpression;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["in"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::BoundsExpression => {
+                    let (expression, next) =
+                        ExpressionParser::default().parse(stream.clone(), self.next.take())?;
+
+                    self.next = next;
+                    self.builder.set_bounds_expression(expression);
+                    self.state = State::BracketSquareRight;
+                }
                 State::BracketSquareRight => {
                     return match crate::syntax::parser::take_or_next(self.next.take(), stream)? {
                         Token {
@@ -198,15 +258,19 @@ mod tests {
     use super::Error;
     use super::Parser;
     use crate::lexical::stream::TokenStream;
+    use crate::lexical::token::lexeme::keyword::Keyword;
     use crate::lexical::token::lexeme::literal::integer::Integer as LexicalIntegerLiteral;
+    use crate::lexical::token::lexeme::literal::Literal as LexicalLiteral;
     use crate::lexical::token::lexeme::symbol::Symbol;
     use crate::lexical::token::lexeme::Lexeme;
     use crate::lexical::token::location::Location;
     use crate::syntax::error::Error as SyntaxError;
     use crate::syntax::tree::expression::array::Expression as ArrayExpression;
     use crate::syntax::tree::expression::tree::node::operand::Operand as ExpressionOperand;
+    use crate::syntax::tree::expression::tree::node::operator::Operator as ExpressionOperator;
     use crate::syntax::tree::expression::tree::node::Node as ExpressionTreeNode;
     use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
+    use crate::syntax::tree::identifier::Identifier;
     use crate::syntax::tree::literal::integer::Literal as IntegerLiteral;
 
     #[test]
@@ -325,12 +389,58 @@ mod tests {
     }
 
     #[test]
-    fn error_expected_comma_or_semicolon_or_bracket_square_right() {
+    fn ok_comprehension() {
+        let input = r#"[i for i in 0..4]"#;
+
+        let expected = Ok((
+            ArrayExpression::new_comprehension(
+                Location::new(1, 1),
+                ExpressionTree::new(
+                    Location::new(1, 2),
+                    ExpressionTreeNode::operand(ExpressionOperand::Identifier(Identifier::new(
+                        Location::new(1, 2),
+                        "i".to_owned(),
+                    ))),
+                ),
+                Identifier::new(Location::new(1, 8), "i".to_owned()),
+                ExpressionTree::new_with_leaves(
+                    Location::new(1, 14),
+                    ExpressionTreeNode::operator(ExpressionOperator::Range),
+                    Some(ExpressionTree::new(
+                        Location::new(1, 13),
+                        ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
+                            IntegerLiteral::new(
+                                Location::new(1, 13),
+                                LexicalIntegerLiteral::new_decimal("0".to_owned()),
+                            ),
+                        )),
+                    )),
+                    Some(ExpressionTree::new(
+                        Location::new(1, 16),
+                        ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
+                            IntegerLiteral::new(
+                                Location::new(1, 16),
+                                LexicalIntegerLiteral::new_decimal("4".to_owned()),
+                            ),
+                        )),
+                    )),
+                ),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_comma_or_semicolon_or_keyword_for_or_bracket_square_right() {
         let input = r#"[42)"#;
 
         let expected: Result<_, Error> = Err(Error::Syntax(SyntaxError::expected_one_of(
             Location::new(1, 4),
-            vec![",", ";", "]"],
+            vec![",", ";", "for", "]"],
             Lexeme::Symbol(Symbol::ParenthesisRight),
             None,
         )));
@@ -356,6 +466,39 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn error_expected_index_identifier() {
+        let input = r#"[42 for in 0..4]"#;
+
+        let expected: Result<_, Error> = Err(Error::Syntax(SyntaxError::expected_identifier(
+            Location::new(1, 9),
+            Lexeme::Keyword(Keyword::In),
+            Some(super::HINT_EXPECTED_INDEX_IDENTIFIER),
+        )));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_keyword_in() {
+        let input = r#"[42 for i 0..4]"#;
+
+        let expected: Result<_, Error> = Err(Error::Syntax(SyntaxError::expected_one_of(
+            Location::new(1, 11),
+            vec!["in"],
+            Lexeme::Literal(LexicalLiteral::Integer(LexicalIntegerLiteral::new_decimal(
+                "0".to_owned(),
+            ))),
+            None,
+        )));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn error_expected_bracket_square_right() {
         let input = r#"[42; 8)"#;
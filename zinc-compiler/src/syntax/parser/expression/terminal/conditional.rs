@@ -14,6 +14,8 @@ use crate::lexical::token::Token;
 use crate::syntax::error::Error as SyntaxError;
 use crate::syntax::parser::expression::terminal::block::Parser as BlockExpressionParser;
 use crate::syntax::parser::expression::Parser as ExpressionParser;
+use crate::syntax::parser::nesting::Nesting;
+use crate::syntax::parser::pattern_match::Parser as MatchPatternParser;
 use crate::syntax::tree::expression::block::Expression as BlockExpression;
 use crate::syntax::tree::expression::conditional::builder::Builder as ConditionalExpressionBuilder;
 use crate::syntax::tree::expression::conditional::Expression as ConditionalExpression;
@@ -24,6 +26,9 @@ use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
 #[derive(Debug, Clone, Copy)]
 pub enum State {
     KeywordIf,
+    KeywordLetOrCondition,
+    Pattern,
+    Equals,
     Condition,
     MainBlock,
     ElseKeywordOrEnd,
@@ -55,6 +60,14 @@ impl Parser {
     /// }
     /// '
     ///
+    /// '
+    /// if let Color::Red = color {
+    ///     1
+    /// } else {
+    ///     0
+    /// }
+    /// '
+    ///
     pub fn parse(
         mut self,
         stream: Rc<RefCell<TokenStream>>,
@@ -69,7 +82,7 @@ impl Parser {
                             location,
                         } => {
                             self.builder.set_location(location);
-                            self.state = State::Condition;
+                            self.state = State::KeywordLetOrCondition;
                         }
                         Token { lexeme, location } => {
                             return Err(Error::Syntax(SyntaxError::expected_one_of(
@@ -81,6 +94,41 @@ impl Parser {
                         }
                     }
                 }
+                State::KeywordLetOrCondition => {
+                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Keyword(Keyword::Let),
+                            ..
+                        } => self.state = State::Pattern,
+                        token => {
+                            self.next = Some(token);
+                            self.state = State::Condition;
+                        }
+                    }
+                }
+                State::Pattern => {
+                    let (pattern, next) =
+                        MatchPatternParser::default().parse(stream.clone(), None)?;
+                    self.next = next;
+                    self.builder.set_pattern(pattern);
+                    self.state = State::Equals;
+                }
+                State::Equals => {
+                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Equals),
+                            ..
+                        } => self.state = State::Condition,
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["="],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
                 State::Condition => {
                     let (expression, next) =
                         ExpressionParser::default().parse(stream.clone(), self.next.take())?;
@@ -115,6 +163,7 @@ impl Parser {
                             lexeme: Lexeme::Keyword(Keyword::If),
                             ..
                         } => {
+                            let _nesting = Nesting::enter(token.location)?;
                             let (expression, next) = Self::default().parse(stream, Some(token))?;
                             let block = BlockExpression::new(
                                 expression.location,
@@ -159,19 +208,24 @@ mod tests {
     use super::Error;
     use super::Parser;
     use crate::lexical::stream::TokenStream;
+    use crate::lexical::token::lexeme::identifier::Identifier as LexicalIdentifier;
     use crate::lexical::token::lexeme::literal::boolean::Boolean as LexicalBooleanLiteral;
     use crate::lexical::token::lexeme::literal::integer::Integer as LexicalIntegerLiteral;
     use crate::lexical::token::lexeme::symbol::Symbol;
     use crate::lexical::token::lexeme::Lexeme;
     use crate::lexical::token::location::Location;
+    use crate::lexical::token::Token;
     use crate::syntax::error::Error as SyntaxError;
     use crate::syntax::tree::expression::block::Expression as BlockExpression;
     use crate::syntax::tree::expression::conditional::Expression as ConditionalExpression;
     use crate::syntax::tree::expression::tree::node::operand::Operand as ExpressionOperand;
     use crate::syntax::tree::expression::tree::node::Node as ExpressionTreeNode;
     use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
+    use crate::syntax::tree::identifier::Identifier;
     use crate::syntax::tree::literal::boolean::Literal as BooleanLiteral;
     use crate::syntax::tree::literal::integer::Literal as IntegerLiteral;
+    use crate::syntax::tree::pattern_match::variant::Variant as MatchPatternVariant;
+    use crate::syntax::tree::pattern_match::Pattern as MatchPattern;
 
     #[test]
     fn ok_nested() {
@@ -255,6 +309,66 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn ok_let() {
+        let input = r#"if let 42 = value { 1 }"#;
+
+        let expected = Ok((
+            ConditionalExpression::new_let(
+                Location::new(1, 1),
+                MatchPattern::new(
+                    Location::new(1, 8),
+                    MatchPatternVariant::new_integer_literal(IntegerLiteral::new(
+                        Location::new(1, 8),
+                        LexicalIntegerLiteral::new_decimal("42".to_owned()),
+                    )),
+                ),
+                ExpressionTree::new(
+                    Location::new(1, 13),
+                    ExpressionTreeNode::operand(ExpressionOperand::Identifier(Identifier::new(
+                        Location::new(1, 13),
+                        "value".to_owned(),
+                    ))),
+                ),
+                BlockExpression::new(
+                    Location::new(1, 19),
+                    vec![],
+                    Some(ExpressionTree::new(
+                        Location::new(1, 21),
+                        ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
+                            IntegerLiteral::new(
+                                Location::new(1, 21),
+                                LexicalIntegerLiteral::new_decimal("1".to_owned()),
+                            ),
+                        )),
+                    )),
+                ),
+                None,
+            ),
+            Some(Token::new(Lexeme::Eof, Location::new(1, 24))),
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_equals() {
+        let input = r#"if let 42 value { 1 }"#;
+
+        let expected: Result<_, Error> = Err(Error::Syntax(SyntaxError::expected_one_of(
+            Location::new(1, 11),
+            vec!["="],
+            Lexeme::Identifier(LexicalIdentifier::new("value".to_owned())),
+            None,
+        )));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn error_expected_bracket_square_right() {
         let input = r#"if true { 42 } else ("#;
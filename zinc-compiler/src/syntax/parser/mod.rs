@@ -2,9 +2,11 @@
 //! The syntax parser.
 //!
 
+pub mod attribute;
 pub mod expression;
 pub mod field;
 pub mod field_list;
+pub mod nesting;
 pub mod pattern_binding;
 pub mod pattern_binding_list;
 pub mod pattern_match;
@@ -0,0 +1,57 @@
+//!
+//! The syntax parser nesting guard.
+//!
+
+use std::cell::Cell;
+
+use crate::error::Error;
+use crate::lexical::token::location::Location;
+use crate::syntax::error::Error as SyntaxError;
+
+thread_local! {
+    static DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+///
+/// The guard of a recursive parser call.
+///
+/// The expression and type parsers are recursive descent, so a deeply nested input,
+/// e.g. thousands of parentheses, would overflow the stack. Each recursive parser holds
+/// the guard while parsing its nested part, and the parsing fails with an error if
+/// there are more than `LIMIT` guards held at the same time.
+///
+pub struct Nesting;
+
+impl Nesting {
+    /// The maximal nesting depth of the expressions and types.
+    pub const LIMIT: usize = 128;
+
+    ///
+    /// Enters a nested expression or type at `location`.
+    ///
+    pub fn enter(location: Location) -> Result<Self, Error> {
+        let is_entered = DEPTH.with(|depth| {
+            if depth.get() >= Self::LIMIT {
+                return false;
+            }
+
+            depth.set(depth.get() + 1);
+            true
+        });
+
+        if is_entered {
+            Ok(Self)
+        } else {
+            Err(Error::Syntax(SyntaxError::nesting_too_deep(
+                location,
+                Self::LIMIT,
+            )))
+        }
+    }
+}
+
+impl Drop for Nesting {
+    fn drop(&mut self) {
+        DEPTH.with(|depth| depth.set(depth.get() - 1));
+    }
+}
@@ -17,11 +17,11 @@ use crate::syntax::tree::identifier::Identifier;
 use crate::syntax::tree::pattern_binding::builder::Builder as BindingPatternBuilder;
 use crate::syntax::tree::pattern_binding::Pattern as BindingPattern;
 
-static HINT_EXPECTED_TYPE: &str =
-    "function argument must have a type, e.g. `fn sum(a: u8, b: u8) {}`";
+static HINT_EXPECTED_TYPE: &str = "H0006";
 
 #[derive(Debug, Clone, Copy)]
 pub enum State {
+    PubOrNext,
     MutOrNext,
     Binding,
     Colon,
@@ -30,7 +30,7 @@ pub enum State {
 
 impl Default for State {
     fn default() -> Self {
-        State::MutOrNext
+        State::PubOrNext
     }
 }
 
@@ -47,6 +47,7 @@ impl Parser {
     ///
     /// 'a: u8'
     /// 'mut a: u8'
+    /// 'pub a: u8'
     /// '_: u8'
     /// 'self'
     /// 'mut self'
@@ -58,18 +59,38 @@ impl Parser {
     ) -> Result<(BindingPattern, Option<Token>), Error> {
         loop {
             match self.state {
-                State::MutOrNext => {
+                State::PubOrNext => {
                     match crate::syntax::parser::take_or_next(initial.take(), stream.clone())? {
                         Token {
-                            lexeme: Lexeme::Keyword(Keyword::Mut),
+                            lexeme: Lexeme::Keyword(Keyword::Pub),
                             location,
                         } => {
                             self.builder.set_location(location);
+                            self.builder.set_is_public();
+                            self.state = State::MutOrNext;
+                        }
+                        token => {
+                            self.next = Some(token);
+                            self.state = State::MutOrNext;
+                        }
+                    }
+                }
+                State::MutOrNext => {
+                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Keyword(Keyword::Mut),
+                            location,
+                        } => {
+                            if !self.builder.is_public() {
+                                self.builder.set_location(location);
+                            }
                             self.builder.set_is_mutable();
                             self.state = State::Binding;
                         }
                         token => {
-                            self.builder.set_location(token.location);
+                            if !self.builder.is_public() {
+                                self.builder.set_location(token.location);
+                            }
                             self.next = Some(token);
                             self.state = State::Binding;
                         }
@@ -195,6 +216,26 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn ok_binding_public_mutable() {
+        let input = r#"pub mut value: u8"#;
+
+        let mut pattern = BindingPattern::new(
+            Location::new(1, 1),
+            BindingPatternVariant::new_binding(
+                Identifier::new(Location::new(1, 9), "value".to_owned()),
+                true,
+            ),
+            Type::new(Location::new(1, 16), TypeVariant::integer_unsigned(8)),
+        );
+        pattern.is_public = true;
+        let expected = Ok((pattern, None));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn ok_wildcard() {
         let input = r#"_: u8"#;
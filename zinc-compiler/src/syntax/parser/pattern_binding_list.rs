@@ -38,6 +38,7 @@ impl Parser {
     /// Parses a binding pattern list.
     ///
     /// 'mut a: u8, b: field, c: (bool, bool)'
+    /// 'pub a: field, b: [u8; 64]'
     ///
     pub fn parse(
         mut self,
@@ -50,6 +51,12 @@ impl Parser {
                     match crate::syntax::parser::take_or_next(initial.take(), stream.clone())? {
                         token
                         @
+                        Token {
+                            lexeme: Lexeme::Keyword(Keyword::Pub),
+                            ..
+                        }
+                        | token
+                        @
                         Token {
                             lexeme: Lexeme::Keyword(Keyword::Mut),
                             ..
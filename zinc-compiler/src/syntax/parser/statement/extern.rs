@@ -0,0 +1,295 @@
+//!
+//! The extern statement parser.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::error::Error;
+use crate::lexical::stream::TokenStream;
+use crate::lexical::token::lexeme::keyword::Keyword;
+use crate::lexical::token::lexeme::literal::Literal;
+use crate::lexical::token::lexeme::symbol::Symbol;
+use crate::lexical::token::lexeme::Lexeme;
+use crate::lexical::token::Token;
+use crate::syntax::error::Error as SyntaxError;
+use crate::syntax::parser::pattern_binding_list::Parser as BindingPatternListParser;
+use crate::syntax::parser::r#type::Parser as TypeParser;
+use crate::syntax::tree::identifier::Identifier;
+use crate::syntax::tree::statement::r#extern::builder::Builder as ExternStatementBuilder;
+use crate::syntax::tree::statement::r#extern::Statement as ExternStatement;
+
+static HINT_EXPECTED_ABI: &str = "H0027";
+static HINT_EXPECTED_IDENTIFIER: &str = "H0011";
+static HINT_EXPECTED_ARGUMENT_LIST: &str = "H0012";
+
+#[derive(Debug, Clone, Copy)]
+pub enum State {
+    KeywordExtern,
+    Abi,
+    KeywordFn,
+    Identifier,
+    ParenthesisLeft,
+    ArgumentBindingList,
+    ParenthesisRight,
+    ArrowOrSemicolon,
+    ReturnType,
+    Semicolon,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        State::KeywordExtern
+    }
+}
+
+#[derive(Default)]
+pub struct Parser {
+    state: State,
+    builder: ExternStatementBuilder,
+    next: Option<Token>,
+}
+
+impl Parser {
+    ///
+    /// Parses an 'extern' statement, which declares a function without a body.
+    ///
+    /// 'extern "host" fn lookup(x: u64) -> u64;'
+    ///
+    pub fn parse(
+        mut self,
+        stream: Rc<RefCell<TokenStream>>,
+        mut initial: Option<Token>,
+    ) -> Result<(ExternStatement, Option<Token>), Error> {
+        loop {
+            match self.state {
+                State::KeywordExtern => {
+                    match crate::syntax::parser::take_or_next(initial.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Keyword(Keyword::Extern),
+                            location,
+                        } => {
+                            self.builder.set_location(location);
+                            self.state = State::Abi;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["extern"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::Abi => {
+                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Literal(Literal::String(abi)),
+                            ..
+                        } => {
+                            self.builder.set_abi(abi.into());
+                            self.state = State::KeywordFn;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["\"host\""],
+                                lexeme,
+                                Some(HINT_EXPECTED_ABI),
+                            )));
+                        }
+                    }
+                }
+                State::KeywordFn => {
+                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Keyword(Keyword::Fn),
+                            ..
+                        } => self.state = State::Identifier,
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["fn"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::Identifier => {
+                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Identifier(identifier),
+                            location,
+                        } => {
+                            let identifier = Identifier::new(location, identifier.inner);
+                            self.builder.set_identifier(identifier);
+                            self.state = State::ParenthesisLeft;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_identifier(
+                                location,
+                                lexeme,
+                                Some(HINT_EXPECTED_IDENTIFIER),
+                            )));
+                        }
+                    }
+                }
+                State::ParenthesisLeft => {
+                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisLeft),
+                            ..
+                        } => self.state = State::ArgumentBindingList,
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["("],
+                                lexeme,
+                                Some(HINT_EXPECTED_ARGUMENT_LIST),
+                            )));
+                        }
+                    }
+                }
+                State::ArgumentBindingList => {
+                    let (argument_bindings, next) =
+                        BindingPatternListParser::default().parse(stream.clone(), None)?;
+                    self.builder.set_argument_bindings(argument_bindings);
+                    self.next = next;
+                    self.state = State::ParenthesisRight;
+                }
+                State::ParenthesisRight => {
+                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisRight),
+                            ..
+                        } => self.state = State::ArrowOrSemicolon,
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec![",", ")"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::ArrowOrSemicolon => {
+                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::MinusGreater),
+                            ..
+                        } => self.state = State::ReturnType,
+                        token => {
+                            self.next = Some(token);
+                            self.state = State::Semicolon;
+                        }
+                    }
+                }
+                State::ReturnType => {
+                    let (r#type, next) = TypeParser::default().parse(stream.clone(), None)?;
+                    self.next = next;
+                    self.builder.set_return_type(r#type);
+                    self.state = State::Semicolon;
+                }
+                State::Semicolon => {
+                    return match crate::syntax::parser::take_or_next(self.next.take(), stream)? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Semicolon),
+                            ..
+                        } => Ok((self.builder.finish(), None)),
+                        Token { lexeme, location } => {
+                            Err(Error::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec![";"],
+                                lexeme,
+                                None,
+                            )))
+                        }
+                    };
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::Parser;
+    use crate::error::Error;
+    use crate::lexical::stream::TokenStream;
+    use crate::lexical::token::lexeme::keyword::Keyword;
+    use crate::lexical::token::lexeme::symbol::Symbol;
+    use crate::lexical::token::lexeme::Lexeme;
+    use crate::lexical::token::location::Location;
+    use crate::syntax::error::Error as SyntaxError;
+    use crate::syntax::tree::identifier::Identifier;
+    use crate::syntax::tree::pattern_binding::variant::Variant as BindingPatternVariant;
+    use crate::syntax::tree::pattern_binding::Pattern as BindingPattern;
+    use crate::syntax::tree::r#type::variant::Variant as TypeVariant;
+    use crate::syntax::tree::r#type::Type;
+    use crate::syntax::tree::statement::r#extern::Statement as ExternStatement;
+
+    #[test]
+    fn ok() {
+        let input = r#"extern "host" fn lookup(x: u64) -> u64;"#;
+
+        let expected = Ok((
+            ExternStatement::new(
+                Location::new(1, 1),
+                "host".to_owned(),
+                Identifier::new(Location::new(1, 18), "lookup".to_owned()),
+                vec![BindingPattern::new(
+                    Location::new(1, 25),
+                    BindingPatternVariant::new_binding(
+                        Identifier::new(Location::new(1, 25), "x".to_owned()),
+                        false,
+                    ),
+                    Type::new(Location::new(1, 28), TypeVariant::integer_unsigned(64)),
+                )],
+                Some(Type::new(Location::new(1, 36), TypeVariant::integer_unsigned(64))),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_abi() {
+        let input = r#"extern fn lookup(x: u64) -> u64;"#;
+
+        let expected = Err(Error::Syntax(SyntaxError::expected_one_of(
+            Location::new(1, 8),
+            vec!["\"host\""],
+            Lexeme::Keyword(Keyword::Fn),
+            Some(super::HINT_EXPECTED_ABI),
+        )));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_semicolon() {
+        let input = r#"extern "host" fn lookup(x: u64) -> u64 {}"#;
+
+        let expected = Err(Error::Syntax(SyntaxError::expected_one_of(
+            Location::new(1, 40),
+            vec![";"],
+            Lexeme::Symbol(Symbol::BracketCurlyLeft),
+            None,
+        )));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+}
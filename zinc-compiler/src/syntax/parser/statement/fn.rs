@@ -12,22 +12,28 @@ use crate::lexical::token::lexeme::symbol::Symbol;
 use crate::lexical::token::lexeme::Lexeme;
 use crate::lexical::token::Token;
 use crate::syntax::error::Error as SyntaxError;
+use crate::syntax::parser::attribute::Parser as AttributeParser;
 use crate::syntax::parser::expression::terminal::block::Parser as BlockExpressionParser;
+use crate::syntax::parser::field::Parser as FieldParser;
 use crate::syntax::parser::pattern_binding_list::Parser as BindingPatternListParser;
 use crate::syntax::parser::r#type::Parser as TypeParser;
+use crate::syntax::tree::attribute::Attribute;
 use crate::syntax::tree::identifier::Identifier;
 use crate::syntax::tree::statement::r#fn::builder::Builder as FnStatementBuilder;
 use crate::syntax::tree::statement::r#fn::Statement as FnStatement;
 
-static HINT_EXPECTED_IDENTIFIER: &str =
-    "function must have an identifier, e.g. `fn sum(...) { ... }`";
-static HINT_EXPECTED_ARGUMENT_LIST: &str =
-    "function must have the argument list, e.g. `fn sum(a: u8, b: u8) { ... }`";
+static HINT_EXPECTED_IDENTIFIER: &str = "H0011";
+static HINT_EXPECTED_ARGUMENT_LIST: &str = "H0012";
+static HINT_EXPECTED_CONST_PARAMETER: &str = "H0029";
 
 #[derive(Debug, Clone, Copy)]
 pub enum State {
     KeywordFn,
     Identifier,
+    LesserOrParenthesisLeft,
+    KeywordConst,
+    KeywordConstOrGreater,
+    CommaOrGreater,
     ParenthesisLeft,
     ArgumentBindingList,
     ParenthesisRight,
@@ -50,15 +56,33 @@ pub struct Parser {
 }
 
 impl Parser {
+    ///
+    /// Initializes a parser with the attributes already parsed by the enclosing statement parser.
+    ///
+    pub fn new_with_attributes(attributes: Vec<Attribute>) -> Self {
+        let mut parser = Self::default();
+        for attribute in attributes.into_iter() {
+            parser.builder.push_attribute(attribute);
+        }
+        parser
+    }
+
     ///
     /// Parses an 'fn' statement.
     ///
     /// '
+    /// #[inline]
     /// fn sum(a: u8, b: u8) -> u8 {
     ///     a + b
     /// }
     /// '
     ///
+    /// '
+    /// fn first<const N: u64>(array: [u8; N]) -> u8 {
+    ///     array[0]
+    /// }
+    /// '
+    ///
     pub fn parse(
         mut self,
         stream: Rc<RefCell<TokenStream>>,
@@ -68,6 +92,17 @@ impl Parser {
             match self.state {
                 State::KeywordFn => {
                     match crate::syntax::parser::take_or_next(initial.take(), stream.clone())? {
+                        token
+                        @
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::NumberSign),
+                            ..
+                        } => {
+                            let (attribute, next) =
+                                AttributeParser::default().parse(stream.clone(), Some(token))?;
+                            self.builder.push_attribute(attribute);
+                            initial = next;
+                        }
                         Token {
                             lexeme: Lexeme::Keyword(Keyword::Fn),
                             location,
@@ -93,7 +128,7 @@ impl Parser {
                         } => {
                             let identifier = Identifier::new(location, identifier.inner);
                             self.builder.set_identifier(identifier);
-                            self.state = State::ParenthesisLeft;
+                            self.state = State::LesserOrParenthesisLeft;
                         }
                         Token { lexeme, location } => {
                             return Err(Error::Syntax(SyntaxError::expected_identifier(
@@ -104,6 +139,74 @@ impl Parser {
                         }
                     }
                 }
+                State::LesserOrParenthesisLeft => {
+                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Lesser),
+                            ..
+                        } => self.state = State::KeywordConst,
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisLeft),
+                            ..
+                        } => self.state = State::ArgumentBindingList,
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["<", "("],
+                                lexeme,
+                                Some(HINT_EXPECTED_ARGUMENT_LIST),
+                            )));
+                        }
+                    }
+                }
+                State::KeywordConst | State::KeywordConstOrGreater => {
+                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Keyword(Keyword::Const),
+                            ..
+                        } => {
+                            let (parameter, next) =
+                                FieldParser::default().parse(stream.clone(), None)?;
+                            self.builder.push_const_parameter(parameter);
+                            self.next = next;
+                            self.state = State::CommaOrGreater;
+                        }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Greater),
+                            ..
+                        } if matches!(self.state, State::KeywordConstOrGreater) => {
+                            self.state = State::ParenthesisLeft
+                        }
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["const"],
+                                lexeme,
+                                Some(HINT_EXPECTED_CONST_PARAMETER),
+                            )));
+                        }
+                    }
+                }
+                State::CommaOrGreater => {
+                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Comma),
+                            ..
+                        } => self.state = State::KeywordConstOrGreater,
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Greater),
+                            ..
+                        } => self.state = State::ParenthesisLeft,
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec![",", ">"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
                 State::ParenthesisLeft => {
                     match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
                         Token {
@@ -181,11 +284,14 @@ mod tests {
     use super::Parser;
     use crate::error::Error;
     use crate::lexical::stream::TokenStream;
+    use crate::lexical::token::lexeme::identifier::Identifier as LexicalIdentifier;
+    use crate::lexical::token::lexeme::keyword::Keyword;
     use crate::lexical::token::lexeme::symbol::Symbol;
     use crate::lexical::token::lexeme::Lexeme;
     use crate::lexical::token::location::Location;
     use crate::syntax::error::Error as SyntaxError;
     use crate::syntax::tree::expression::block::Expression as BlockExpression;
+    use crate::syntax::tree::field::Field;
     use crate::syntax::tree::identifier::Identifier;
     use crate::syntax::tree::pattern_binding::variant::Variant as BindingPatternVariant;
     use crate::syntax::tree::pattern_binding::Pattern as BindingPattern;
@@ -201,6 +307,7 @@ mod tests {
             FnStatement::new(
                 Location::new(1, 1),
                 Identifier::new(Location::new(1, 4), "f".to_owned()),
+                vec![],
                 vec![BindingPattern::new(
                     Location::new(1, 6),
                     BindingPatternVariant::new_binding(
@@ -211,6 +318,7 @@ mod tests {
                 )],
                 None,
                 BlockExpression::new(Location::new(1, 16), vec![], None),
+                vec![],
             ),
             None,
         ));
@@ -228,6 +336,7 @@ mod tests {
             FnStatement::new(
                 Location::new(1, 1),
                 Identifier::new(Location::new(1, 4), "f".to_owned()),
+                vec![],
                 vec![BindingPattern::new(
                     Location::new(1, 6),
                     BindingPatternVariant::new_binding(
@@ -238,6 +347,47 @@ mod tests {
                 )],
                 Some(Type::new(Location::new(1, 19), TypeVariant::field())),
                 BlockExpression::new(Location::new(1, 25), vec![], None),
+                vec![],
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_const_parameters() {
+        let input = r#"fn f<const N: u64, const M: u8>(a: field) {}"#;
+
+        let expected = Ok((
+            FnStatement::new(
+                Location::new(1, 1),
+                Identifier::new(Location::new(1, 4), "f".to_owned()),
+                vec![
+                    Field::new(
+                        Location::new(1, 12),
+                        Identifier::new(Location::new(1, 12), "N".to_owned()),
+                        Type::new(Location::new(1, 15), TypeVariant::integer_unsigned(64)),
+                    ),
+                    Field::new(
+                        Location::new(1, 26),
+                        Identifier::new(Location::new(1, 26), "M".to_owned()),
+                        Type::new(Location::new(1, 29), TypeVariant::integer_unsigned(8)),
+                    ),
+                ],
+                vec![BindingPattern::new(
+                    Location::new(1, 33),
+                    BindingPatternVariant::new_binding(
+                        Identifier::new(Location::new(1, 33), "a".to_owned()),
+                        false,
+                    ),
+                    Type::new(Location::new(1, 36), TypeVariant::field()),
+                )],
+                None,
+                BlockExpression::new(Location::new(1, 43), vec![], None),
+                vec![],
             ),
             None,
         ));
@@ -262,13 +412,45 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn error_expected_keyword_const() {
+        let input = r#"fn f<N: u64>(a: field) {}"#;
+
+        let expected = Err(Error::Syntax(SyntaxError::expected_one_of(
+            Location::new(1, 6),
+            vec!["const"],
+            Lexeme::Identifier(LexicalIdentifier::new("N".to_owned())),
+            Some(super::HINT_EXPECTED_CONST_PARAMETER),
+        )));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_comma_or_greater() {
+        let input = r#"fn f<const N: u64 const M: u64>(a: field) {}"#;
+
+        let expected = Err(Error::Syntax(SyntaxError::expected_one_of(
+            Location::new(1, 19),
+            vec![",", ">"],
+            Lexeme::Keyword(Keyword::Const),
+            None,
+        )));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn error_expected_parenthesis_left() {
         let input = r#"fn sort -> field {}"#;
 
         let expected = Err(Error::Syntax(SyntaxError::expected_one_of(
             Location::new(1, 9),
-            vec!["("],
+            vec!["<", "("],
             Lexeme::Symbol(Symbol::MinusGreater),
             Some(super::HINT_EXPECTED_ARGUMENT_LIST),
         )));
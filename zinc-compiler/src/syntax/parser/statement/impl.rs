@@ -17,8 +17,7 @@ use crate::syntax::tree::identifier::Identifier;
 use crate::syntax::tree::statement::r#impl::builder::Builder as ImplStatementBuilder;
 use crate::syntax::tree::statement::r#impl::Statement as ImplStatement;
 
-static HINT_EXPECTED_IDENTIFIER: &str =
-    "type implementation must have an identifier, e.g. `impl Data { ... }`";
+static HINT_EXPECTED_IDENTIFIER: &str = "H0014";
 
 #[derive(Debug, Clone, Copy)]
 pub enum State {
@@ -295,6 +294,7 @@ mod tests {
                 vec![ImplementationLocalStatement::Fn(FnStatement::new(
                     Location::new(3, 9),
                     Identifier::new(Location::new(3, 12), "f".to_owned()),
+                    vec![],
                     vec![BindingPattern::new(
                         Location::new(3, 14),
                         BindingPatternVariant::new_binding(
@@ -305,6 +305,7 @@ mod tests {
                     )],
                     Some(Type::new(Location::new(3, 27), TypeVariant::field())),
                     BlockExpression::new(Location::new(3, 33), vec![], None),
+                    vec![],
                 ))],
             ),
             None,
@@ -335,6 +336,7 @@ mod tests {
                     ImplementationLocalStatement::Fn(FnStatement::new(
                         Location::new(3, 9),
                         Identifier::new(Location::new(3, 12), "f1".to_owned()),
+                        vec![],
                         vec![BindingPattern::new(
                             Location::new(3, 15),
                             BindingPatternVariant::new_binding(
@@ -345,10 +347,12 @@ mod tests {
                         )],
                         Some(Type::new(Location::new(3, 28), TypeVariant::field())),
                         BlockExpression::new(Location::new(3, 34), vec![], None),
+                        vec![],
                     )),
                     ImplementationLocalStatement::Fn(FnStatement::new(
                         Location::new(5, 9),
                         Identifier::new(Location::new(5, 12), "f2".to_owned()),
+                        vec![],
                         vec![BindingPattern::new(
                             Location::new(5, 15),
                             BindingPatternVariant::new_binding(
@@ -359,10 +363,12 @@ mod tests {
                         )],
                         Some(Type::new(Location::new(5, 28), TypeVariant::field())),
                         BlockExpression::new(Location::new(5, 34), vec![], None),
+                        vec![],
                     )),
                     ImplementationLocalStatement::Fn(FnStatement::new(
                         Location::new(7, 9),
                         Identifier::new(Location::new(7, 12), "f3".to_owned()),
+                        vec![],
                         vec![BindingPattern::new(
                             Location::new(7, 15),
                             BindingPatternVariant::new_binding(
@@ -373,6 +379,7 @@ mod tests {
                         )],
                         Some(Type::new(Location::new(7, 28), TypeVariant::field())),
                         BlockExpression::new(Location::new(7, 34), vec![], None),
+                        vec![],
                     )),
                 ],
             ),
@@ -416,6 +423,7 @@ mod tests {
                     ImplementationLocalStatement::Fn(FnStatement::new(
                         Location::new(5, 9),
                         Identifier::new(Location::new(5, 12), "f".to_owned()),
+                        vec![],
                         vec![BindingPattern::new(
                             Location::new(5, 14),
                             BindingPatternVariant::new_binding(
@@ -426,6 +434,7 @@ mod tests {
                         )],
                         Some(Type::new(Location::new(5, 27), TypeVariant::field())),
                         BlockExpression::new(Location::new(5, 33), vec![], None),
+                        vec![],
                     )),
                 ],
             ),
@@ -505,6 +514,7 @@ mod tests {
                     ImplementationLocalStatement::Fn(FnStatement::new(
                         Location::new(9, 9),
                         Identifier::new(Location::new(9, 12), "f1".to_owned()),
+                        vec![],
                         vec![BindingPattern::new(
                             Location::new(9, 15),
                             BindingPatternVariant::new_binding(
@@ -515,10 +525,12 @@ mod tests {
                         )],
                         Some(Type::new(Location::new(9, 28), TypeVariant::field())),
                         BlockExpression::new(Location::new(9, 34), vec![], None),
+                        vec![],
                     )),
                     ImplementationLocalStatement::Fn(FnStatement::new(
                         Location::new(11, 9),
                         Identifier::new(Location::new(11, 12), "f2".to_owned()),
+                        vec![],
                         vec![BindingPattern::new(
                             Location::new(11, 15),
                             BindingPatternVariant::new_binding(
@@ -529,10 +541,12 @@ mod tests {
                         )],
                         Some(Type::new(Location::new(11, 28), TypeVariant::field())),
                         BlockExpression::new(Location::new(11, 34), vec![], None),
+                        vec![],
                     )),
                     ImplementationLocalStatement::Fn(FnStatement::new(
                         Location::new(13, 9),
                         Identifier::new(Location::new(13, 12), "f3".to_owned()),
+                        vec![],
                         vec![BindingPattern::new(
                             Location::new(13, 15),
                             BindingPatternVariant::new_binding(
@@ -543,6 +557,7 @@ mod tests {
                         )],
                         Some(Type::new(Location::new(13, 28), TypeVariant::field())),
                         BlockExpression::new(Location::new(13, 34), vec![], None),
+                        vec![],
                     )),
                 ],
             ),
@@ -7,16 +7,23 @@ use std::rc::Rc;
 
 use crate::error::Error;
 use crate::lexical::stream::TokenStream;
+use crate::lexical::token::lexeme::identifier::Identifier as LexicalIdentifier;
 use crate::lexical::token::lexeme::keyword::Keyword;
 use crate::lexical::token::lexeme::symbol::Symbol;
 use crate::lexical::token::lexeme::Lexeme;
 use crate::lexical::token::Token;
+use crate::syntax::error::Error as SyntaxError;
+use crate::syntax::parser::attribute::Parser as AttributeParser;
 use crate::syntax::parser::expression::Parser as ExpressionParser;
 use crate::syntax::parser::statement::r#const::Parser as ConstStatementParser;
 use crate::syntax::parser::statement::r#for::Parser as ForStatementParser;
 use crate::syntax::parser::statement::r#let::Parser as LetStatementParser;
+use crate::syntax::tree::attribute::Attribute;
 use crate::syntax::tree::statement::local_fn::Statement as FunctionLocalStatement;
 
+static HINT_ONLY_CFG_ATTRIBUTES: &str = "H0025";
+static HINT_EXPECTED_SEMICOLON: &str = "H0026";
+
 #[derive(Default)]
 pub struct Parser {
     next: Option<Token>,
@@ -65,6 +72,42 @@ impl Parser {
                 self.next = next;
                 FunctionLocalStatement::For(statement)
             }
+            token
+            @
+            Token {
+                lexeme: Lexeme::Symbol(Symbol::NumberSign),
+                ..
+            } => {
+                let (attribute, next) =
+                    AttributeParser::default().parse(stream.clone(), Some(token))?;
+                if !attribute.is_cfg() {
+                    return Err(Error::Syntax(SyntaxError::expected_one_of(
+                        attribute.identifier.location,
+                        vec![Attribute::CFG],
+                        Lexeme::Identifier(LexicalIdentifier::new(attribute.identifier.name)),
+                        Some(HINT_ONLY_CFG_ATTRIBUTES),
+                    )));
+                }
+
+                let (statement, next, is_unterminated) =
+                    Self::default().parse(stream.clone(), next)?;
+                if is_unterminated {
+                    let Token { lexeme, location } =
+                        crate::syntax::parser::take_or_next(next, stream)?;
+                    return Err(Error::Syntax(SyntaxError::expected_one_of(
+                        location,
+                        vec![";"],
+                        lexeme,
+                        Some(HINT_EXPECTED_SEMICOLON),
+                    )));
+                }
+
+                return Ok((
+                    FunctionLocalStatement::Cfg(attribute, Box::new(statement)),
+                    next,
+                    false,
+                ));
+            }
             Token {
                 lexeme: Lexeme::Symbol(Symbol::Semicolon),
                 location,
@@ -97,11 +140,15 @@ mod tests {
     use std::rc::Rc;
 
     use super::Parser;
+    use crate::error::Error;
     use crate::lexical::stream::TokenStream;
+    use crate::lexical::token::lexeme::identifier::Identifier as LexicalIdentifier;
     use crate::lexical::token::lexeme::literal::integer::Integer as LexicalIntegerLiteral;
+    use crate::lexical::token::lexeme::symbol::Symbol;
     use crate::lexical::token::lexeme::Lexeme;
     use crate::lexical::token::location::Location;
     use crate::lexical::token::Token;
+    use crate::syntax::error::Error as SyntaxError;
     use crate::syntax::tree::expression::block::Expression as BlockExpression;
     use crate::syntax::tree::expression::tree::node::operand::Operand as ExpressionOperand;
     use crate::syntax::tree::expression::tree::node::Node as ExpressionTreeNode;
@@ -174,4 +221,36 @@ mod tests {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn error_attribute_expected_cfg() {
+        let input = r#"#[inline] let a = 42;"#;
+
+        let expected = Err(Error::Syntax(SyntaxError::expected_one_of(
+            Location::new(1, 3),
+            vec!["cfg"],
+            Lexeme::Identifier(LexicalIdentifier::new("inline".to_owned())),
+            Some(super::HINT_ONLY_CFG_ATTRIBUTES),
+        )));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_cfg_expected_semicolon() {
+        let input = r#"#[cfg(debug)] 42 }"#;
+
+        let expected = Err(Error::Syntax(SyntaxError::expected_one_of(
+            Location::new(1, 18),
+            vec![";"],
+            Lexeme::Symbol(Symbol::BracketCurlyRight),
+            Some(super::HINT_EXPECTED_SEMICOLON),
+        )));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
 }
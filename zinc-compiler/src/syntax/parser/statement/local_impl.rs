@@ -12,12 +12,12 @@ use crate::lexical::token::lexeme::symbol::Symbol;
 use crate::lexical::token::lexeme::Lexeme;
 use crate::lexical::token::Token;
 use crate::syntax::error::Error as SyntaxError;
+use crate::syntax::parser::attribute::Parser as AttributeParser;
 use crate::syntax::parser::statement::r#const::Parser as ConstStatementParser;
 use crate::syntax::parser::statement::r#fn::Parser as FnStatementParser;
 use crate::syntax::tree::statement::local_impl::Statement as ImplementationLocalStatement;
 
-static HINT_ONLY_SOME_STATEMENTS: &str =
-    "only constants and functions may be declared within a type implementation";
+static HINT_ONLY_SOME_STATEMENTS: &str = "H0017";
 
 #[derive(Default)]
 pub struct Parser {}
@@ -48,6 +48,31 @@ impl Parser {
             } => FnStatementParser::default()
                 .parse(stream, Some(token))
                 .map(|(statement, next)| (ImplementationLocalStatement::Fn(statement), next)),
+            token
+            @
+            Token {
+                lexeme: Lexeme::Symbol(Symbol::NumberSign),
+                ..
+            } => {
+                let (attribute, next) =
+                    AttributeParser::default().parse(stream.clone(), Some(token))?;
+                if attribute.is_cfg() {
+                    Self::default()
+                        .parse(stream, next)
+                        .map(|(statement, next)| {
+                            (
+                                ImplementationLocalStatement::Cfg(attribute, Box::new(statement)),
+                                next,
+                            )
+                        })
+                } else {
+                    FnStatementParser::new_with_attributes(vec![attribute])
+                        .parse(stream, next)
+                        .map(|(statement, next)| {
+                            (ImplementationLocalStatement::Fn(statement), next)
+                        })
+                }
+            }
             Token {
                 lexeme: Lexeme::Symbol(Symbol::Semicolon),
                 location,
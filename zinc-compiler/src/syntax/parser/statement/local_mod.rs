@@ -12,9 +12,11 @@ use crate::lexical::token::lexeme::symbol::Symbol;
 use crate::lexical::token::lexeme::Lexeme;
 use crate::lexical::token::Token;
 use crate::syntax::error::Error as SyntaxError;
+use crate::syntax::parser::attribute::Parser as AttributeParser;
 use crate::syntax::parser::statement::module::Parser as ModStatementParser;
 use crate::syntax::parser::statement::r#const::Parser as ConstStatementParser;
 use crate::syntax::parser::statement::r#enum::Parser as EnumStatementParser;
+use crate::syntax::parser::statement::r#extern::Parser as ExternStatementParser;
 use crate::syntax::parser::statement::r#fn::Parser as FnStatementParser;
 use crate::syntax::parser::statement::r#impl::Parser as ImplStatementParser;
 use crate::syntax::parser::statement::r#struct::Parser as StructStatementParser;
@@ -22,8 +24,7 @@ use crate::syntax::parser::statement::r#type::Parser as TypeStatementParser;
 use crate::syntax::parser::statement::r#use::Parser as UseStatementParser;
 use crate::syntax::tree::statement::local_mod::Statement as ModuleLocalStatement;
 
-static HINT_ONLY_SOME_STATEMENTS: &str =
-    "only constants, types, functions, and type implementations may be declared at the module root";
+static HINT_ONLY_SOME_STATEMENTS: &str = "H0018";
 
 #[derive(Default)]
 pub struct Parser {}
@@ -80,6 +81,37 @@ impl Parser {
                 .map(|(statement, next)| (ModuleLocalStatement::Fn(statement), next)),
             token
             @
+            Token {
+                lexeme: Lexeme::Keyword(Keyword::Extern),
+                ..
+            } => ExternStatementParser::default()
+                .parse(stream, Some(token))
+                .map(|(statement, next)| (ModuleLocalStatement::Extern(statement), next)),
+            token
+            @
+            Token {
+                lexeme: Lexeme::Symbol(Symbol::NumberSign),
+                ..
+            } => {
+                let (attribute, next) =
+                    AttributeParser::default().parse(stream.clone(), Some(token))?;
+                if attribute.is_cfg() {
+                    Self::default()
+                        .parse(stream, next)
+                        .map(|(statement, next)| {
+                            (
+                                ModuleLocalStatement::Cfg(attribute, Box::new(statement)),
+                                next,
+                            )
+                        })
+                } else {
+                    FnStatementParser::new_with_attributes(vec![attribute])
+                        .parse(stream, next)
+                        .map(|(statement, next)| (ModuleLocalStatement::Fn(statement), next))
+                }
+            }
+            token
+            @
             Token {
                 lexeme: Lexeme::Keyword(Keyword::Mod),
                 ..
@@ -109,7 +141,7 @@ impl Parser {
             Token { lexeme, location } => Err(Error::Syntax(SyntaxError::expected_one_of(
                 location,
                 vec![
-                    "type", "struct", "enum", "fn", "mod", "use", "impl", "const",
+                    "type", "struct", "enum", "fn", "extern", "mod", "use", "impl", "const",
                 ],
                 lexeme,
                 Some(HINT_ONLY_SOME_STATEMENTS),
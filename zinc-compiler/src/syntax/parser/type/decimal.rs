@@ -0,0 +1,248 @@
+//!
+//! The decimal type parser.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::error::Error;
+use crate::lexical::stream::TokenStream;
+use crate::lexical::token::lexeme::keyword::Keyword;
+use crate::lexical::token::lexeme::literal::Literal as LexicalLiteral;
+use crate::lexical::token::lexeme::symbol::Symbol;
+use crate::lexical::token::lexeme::Lexeme;
+use crate::lexical::token::Token;
+use crate::syntax::error::Error as SyntaxError;
+use crate::syntax::tree::literal::integer::Literal as IntegerLiteral;
+use crate::syntax::tree::r#type::builder::Builder as TypeBuilder;
+use crate::syntax::tree::r#type::Type;
+
+#[derive(Debug, Clone, Copy)]
+pub enum State {
+    KeywordDecimal,
+    Lesser,
+    IntegerBits,
+    Comma,
+    FractionBits,
+    Greater,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        State::KeywordDecimal
+    }
+}
+
+#[derive(Default)]
+pub struct Parser {
+    state: State,
+    builder: TypeBuilder,
+}
+
+impl Parser {
+    ///
+    /// Parses a decimal type literal.
+    ///
+    /// 'Decimal<64, 32>'
+    ///
+    /// The bitlengths are integer literals, since an expression would take the closing `>`
+    /// as the comparison operator.
+    ///
+    pub fn parse(
+        mut self,
+        stream: Rc<RefCell<TokenStream>>,
+        mut initial: Option<Token>,
+    ) -> Result<(Type, Option<Token>), Error> {
+        loop {
+            match self.state {
+                State::KeywordDecimal => {
+                    match crate::syntax::parser::take_or_next(initial.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Keyword(Keyword::Decimal),
+                            location,
+                        } => {
+                            self.builder.set_location(location);
+                            self.state = State::Lesser;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["Decimal"],
+                                lexeme,
+                                None,
+                            )))
+                        }
+                    }
+                }
+                State::Lesser => match crate::syntax::parser::take_or_next(None, stream.clone())? {
+                    Token {
+                        lexeme: Lexeme::Symbol(Symbol::Lesser),
+                        ..
+                    } => {
+                        self.state = State::IntegerBits;
+                    }
+                    Token { lexeme, location } => {
+                        return Err(Error::Syntax(SyntaxError::expected_one_of(
+                            location,
+                            vec!["<"],
+                            lexeme,
+                            None,
+                        )))
+                    }
+                },
+                State::IntegerBits => {
+                    match crate::syntax::parser::take_or_next(None, stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Literal(LexicalLiteral::Integer(literal)),
+                            location,
+                        } => {
+                            self.builder
+                                .set_decimal_integer_bits(IntegerLiteral::new(location, literal));
+                            self.state = State::Comma;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_integer_literal(
+                                location, lexeme,
+                            )))
+                        }
+                    }
+                }
+                State::Comma => match crate::syntax::parser::take_or_next(None, stream.clone())? {
+                    Token {
+                        lexeme: Lexeme::Symbol(Symbol::Comma),
+                        ..
+                    } => {
+                        self.state = State::FractionBits;
+                    }
+                    Token { lexeme, location } => {
+                        return Err(Error::Syntax(SyntaxError::expected_one_of(
+                            location,
+                            vec![","],
+                            lexeme,
+                            None,
+                        )))
+                    }
+                },
+                State::FractionBits => {
+                    match crate::syntax::parser::take_or_next(None, stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Literal(LexicalLiteral::Integer(literal)),
+                            location,
+                        } => {
+                            self.builder
+                                .set_decimal_fraction_bits(IntegerLiteral::new(location, literal));
+                            self.state = State::Greater;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_integer_literal(
+                                location, lexeme,
+                            )))
+                        }
+                    }
+                }
+                State::Greater => {
+                    return match crate::syntax::parser::take_or_next(None, stream)? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Greater),
+                            ..
+                        } => Ok((self.builder.finish(), None)),
+                        Token { lexeme, location } => Err(Error::Syntax(
+                            SyntaxError::expected_one_of(location, vec![">"], lexeme, None),
+                        )),
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::Parser;
+    use crate::error::Error;
+    use crate::lexical::stream::TokenStream;
+    use crate::lexical::token::lexeme::identifier::Identifier as LexicalIdentifier;
+    use crate::lexical::token::lexeme::literal::integer::Integer as LexicalIntegerLiteral;
+    use crate::lexical::token::lexeme::symbol::Symbol;
+    use crate::lexical::token::lexeme::Lexeme;
+    use crate::lexical::token::location::Location;
+    use crate::syntax::error::Error as SyntaxError;
+    use crate::syntax::tree::literal::integer::Literal as IntegerLiteral;
+    use crate::syntax::tree::r#type::variant::Variant as TypeVariant;
+    use crate::syntax::tree::r#type::Type;
+
+    #[test]
+    fn ok() {
+        let input = r#"Decimal<64, 32>"#;
+
+        let expected = Ok((
+            Type::new(
+                Location::new(1, 1),
+                TypeVariant::decimal(
+                    IntegerLiteral::new(
+                        Location::new(1, 9),
+                        LexicalIntegerLiteral::new_decimal("64".to_owned()),
+                    ),
+                    IntegerLiteral::new(
+                        Location::new(1, 13),
+                        LexicalIntegerLiteral::new_decimal("32".to_owned()),
+                    ),
+                ),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_lesser() {
+        let input = r#"Decimal[64, 32]"#;
+
+        let expected = Err(Error::Syntax(SyntaxError::expected_one_of(
+            Location::new(1, 8),
+            vec!["<"],
+            Lexeme::Symbol(Symbol::BracketSquareLeft),
+            None,
+        )));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_integer_literal() {
+        let input = r#"Decimal<BITS, 32>"#;
+
+        let expected = Err(Error::Syntax(SyntaxError::expected_integer_literal(
+            Location::new(1, 9),
+            Lexeme::Identifier(LexicalIdentifier::new("BITS".to_owned())),
+        )));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_greater() {
+        let input = r#"Decimal<64, 32;"#;
+
+        let expected = Err(Error::Syntax(SyntaxError::expected_one_of(
+            Location::new(1, 15),
+            vec![">"],
+            Lexeme::Symbol(Symbol::Semicolon),
+            None,
+        )));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+}
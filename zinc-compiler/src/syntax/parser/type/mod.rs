@@ -3,6 +3,7 @@
 //!
 
 pub mod array;
+pub mod decimal;
 pub mod path;
 pub mod tuple;
 
@@ -16,10 +17,12 @@ use crate::lexical::token::lexeme::symbol::Symbol;
 use crate::lexical::token::lexeme::Lexeme;
 use crate::lexical::token::Token;
 use crate::syntax::error::Error as SyntaxError;
+use crate::syntax::parser::nesting::Nesting;
 use crate::syntax::tree::r#type::builder::Builder as TypeBuilder;
 use crate::syntax::tree::r#type::Type;
 
 use self::array::Parser as ArrayParser;
+use self::decimal::Parser as DecimalParser;
 use self::path::Parser as PathParser;
 use self::tuple::Parser as TupleParser;
 
@@ -34,6 +37,7 @@ impl Parser {
     ///
     /// 'bool'
     /// '[u8; 16]'
+    /// 'Decimal<64, 32>'
     /// '(u8, field, bool)'
     /// 'Path::To::Type`
     ///
@@ -42,7 +46,10 @@ impl Parser {
         stream: Rc<RefCell<TokenStream>>,
         mut initial: Option<Token>,
     ) -> Result<(Type, Option<Token>), Error> {
-        match crate::syntax::parser::take_or_next(initial.take(), stream.clone())? {
+        let token = crate::syntax::parser::take_or_next(initial.take(), stream.clone())?;
+        let _nesting = Nesting::enter(token.location)?;
+
+        match token {
             token
             @
             Token {
@@ -73,6 +80,8 @@ impl Parser {
                     self.builder.set_keyword(keyword);
                     Ok((self.builder.finish(), None))
                 }
+                keyword @ Keyword::Decimal => DecimalParser::default()
+                    .parse(stream, Some(Token::new(Lexeme::Keyword(keyword), location))),
                 _ => Err(Error::Syntax(SyntaxError::expected_type(
                     location,
                     Lexeme::Keyword(keyword),
@@ -0,0 +1,40 @@
+//!
+//! The attribute builder.
+//!
+
+use crate::lexical::token::location::Location;
+use crate::syntax::tree::attribute::Attribute;
+use crate::syntax::tree::identifier::Identifier;
+
+#[derive(Default)]
+pub struct Builder {
+    location: Option<Location>,
+    identifier: Option<Identifier>,
+    arguments: Vec<Identifier>,
+}
+
+impl Builder {
+    pub fn set_location(&mut self, value: Location) {
+        self.location = Some(value);
+    }
+
+    pub fn set_identifier(&mut self, value: Identifier) {
+        self.identifier = Some(value);
+    }
+
+    pub fn push_argument(&mut self, value: Identifier) {
+        self.arguments.push(value);
+    }
+
+    pub fn finish(mut self) -> Attribute {
+        Attribute::new(
+            self.location
+                .take()
+                .unwrap_or_else(|| panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "location")),
+            self.identifier.take().unwrap_or_else(|| {
+                panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "identifier")
+            }),
+            self.arguments,
+        )
+    }
+}
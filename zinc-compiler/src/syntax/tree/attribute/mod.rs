@@ -0,0 +1,63 @@
+//!
+//! The attribute.
+//!
+
+pub mod builder;
+
+use std::fmt;
+
+use crate::lexical::token::location::Location;
+use crate::syntax::tree::identifier::Identifier;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Attribute {
+    pub location: Location,
+    pub identifier: Identifier,
+    pub arguments: Vec<Identifier>,
+}
+
+impl Attribute {
+    pub const CFG: &'static str = "cfg";
+    pub const TEST: &'static str = "test";
+
+    pub fn new(location: Location, identifier: Identifier, arguments: Vec<Identifier>) -> Self {
+        Self {
+            location,
+            identifier,
+            arguments,
+        }
+    }
+
+    ///
+    /// Checks if the attribute is a conditional compilation one, e.g. `#[cfg(test)]`.
+    ///
+    pub fn is_cfg(&self) -> bool {
+        self.identifier.name == Self::CFG
+    }
+
+    ///
+    /// Checks if the attribute marks a unit test function, i.e. `#[test]`.
+    ///
+    pub fn is_test(&self) -> bool {
+        self.identifier.name == Self::TEST && self.arguments.is_empty()
+    }
+}
+
+impl fmt::Display for Attribute {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.arguments.is_empty() {
+            write!(f, "#[{}]", self.identifier.name)
+        } else {
+            write!(
+                f,
+                "#[{}({})]",
+                self.identifier.name,
+                self.arguments
+                    .iter()
+                    .map(|argument| argument.name.as_str())
+                    .collect::<Vec<&str>>()
+                    .join(", ")
+            )
+        }
+    }
+}
@@ -5,12 +5,15 @@
 use crate::lexical::token::location::Location;
 use crate::syntax::tree::expression::array::Expression as ArrayExpression;
 use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
+use crate::syntax::tree::identifier::Identifier;
 
 #[derive(Default)]
 pub struct Builder {
     location: Option<Location>,
     elements: Vec<ExpressionTree>,
     size_expression: Option<ExpressionTree>,
+    index_identifier: Option<Identifier>,
+    bounds_expression: Option<ExpressionTree>,
 }
 
 impl Builder {
@@ -26,12 +29,37 @@ impl Builder {
         self.size_expression = Some(value);
     }
 
+    pub fn set_index_identifier(&mut self, value: Identifier) {
+        self.index_identifier = Some(value);
+    }
+
+    pub fn set_bounds_expression(&mut self, value: ExpressionTree) {
+        self.bounds_expression = Some(value);
+    }
+
     pub fn finish(mut self) -> ArrayExpression {
         let location = self
             .location
             .take()
             .unwrap_or_else(|| panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "location"));
 
+        if let Some(index_identifier) = self.index_identifier.take() {
+            return ArrayExpression::new_comprehension(
+                location,
+                self.elements.pop().unwrap_or_else(|| {
+                    panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "expression")
+                }),
+                index_identifier,
+                self.bounds_expression.take().unwrap_or_else(|| {
+                    panic!(
+                        "{}{}",
+                        crate::PANIC_BUILDER_REQUIRES_VALUE,
+                        "bounds expression"
+                    )
+                }),
+            );
+        }
+
         match self.size_expression.take() {
             Some(size_expression) => ArrayExpression::new_repeated(
                 location,
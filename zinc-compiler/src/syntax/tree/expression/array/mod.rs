@@ -7,6 +7,7 @@ pub mod variant;
 
 use crate::lexical::token::location::Location;
 use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
+use crate::syntax::tree::identifier::Identifier;
 
 use self::variant::Variant;
 
@@ -34,4 +35,16 @@ impl Expression {
             variant: Variant::new_repeated(expression, size_expression),
         }
     }
+
+    pub fn new_comprehension(
+        location: Location,
+        expression: ExpressionTree,
+        index_identifier: Identifier,
+        bounds_expression: ExpressionTree,
+    ) -> Self {
+        Self {
+            location,
+            variant: Variant::new_comprehension(expression, index_identifier, bounds_expression),
+        }
+    }
 }
@@ -3,6 +3,7 @@
 //!
 
 use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
+use crate::syntax::tree::identifier::Identifier;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Variant {
@@ -13,6 +14,11 @@ pub enum Variant {
         expression: ExpressionTree,
         size_expression: ExpressionTree,
     },
+    Comprehension {
+        expression: ExpressionTree,
+        index_identifier: Identifier,
+        bounds_expression: ExpressionTree,
+    },
 }
 
 impl Variant {
@@ -26,4 +32,16 @@ impl Variant {
             size_expression,
         }
     }
+
+    pub fn new_comprehension(
+        expression: ExpressionTree,
+        index_identifier: Identifier,
+        bounds_expression: ExpressionTree,
+    ) -> Self {
+        Self::Comprehension {
+            expression,
+            index_identifier,
+            bounds_expression,
+        }
+    }
 }
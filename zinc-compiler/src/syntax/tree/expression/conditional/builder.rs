@@ -6,10 +6,12 @@ use crate::lexical::token::location::Location;
 use crate::syntax::tree::expression::block::Expression as BlockExpression;
 use crate::syntax::tree::expression::conditional::Expression as ConditionalExpression;
 use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
+use crate::syntax::tree::pattern_match::Pattern as MatchPattern;
 
 #[derive(Default)]
 pub struct Builder {
     location: Option<Location>,
+    pattern: Option<MatchPattern>,
     condition: Option<ExpressionTree>,
     main_block: Option<BlockExpression>,
     else_block: Option<BlockExpression>,
@@ -20,6 +22,10 @@ impl Builder {
         self.location = Some(value);
     }
 
+    pub fn set_pattern(&mut self, value: MatchPattern) {
+        self.pattern = Some(value);
+    }
+
     pub fn set_condition(&mut self, value: ExpressionTree) {
         self.condition = Some(value);
     }
@@ -33,17 +39,30 @@ impl Builder {
     }
 
     pub fn finish(mut self) -> ConditionalExpression {
-        ConditionalExpression::new(
-            self.location
-                .take()
-                .unwrap_or_else(|| panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "location")),
-            self.condition.take().unwrap_or_else(|| {
-                panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "condition")
-            }),
-            self.main_block.take().unwrap_or_else(|| {
-                panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "main block")
-            }),
-            self.else_block.take(),
-        )
+        let location = self
+            .location
+            .take()
+            .unwrap_or_else(|| panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "location"));
+        let condition = self
+            .condition
+            .take()
+            .unwrap_or_else(|| panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "condition"));
+        let main_block = self
+            .main_block
+            .take()
+            .unwrap_or_else(|| panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "main block"));
+
+        match self.pattern.take() {
+            Some(pattern) => ConditionalExpression::new_let(
+                location,
+                pattern,
+                condition,
+                main_block,
+                self.else_block.take(),
+            ),
+            None => {
+                ConditionalExpression::new(location, condition, main_block, self.else_block.take())
+            }
+        }
     }
 }
@@ -7,10 +7,13 @@ pub mod builder;
 use crate::lexical::token::location::Location;
 use crate::syntax::tree::expression::block::Expression as BlockExpression;
 use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
+use crate::syntax::tree::pattern_match::Pattern as MatchPattern;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Expression {
     pub location: Location,
+    /// The `if let` pattern, which is matched against the condition expression.
+    pub pattern: Option<MatchPattern>,
     pub condition: Box<ExpressionTree>,
     pub main_block: BlockExpression,
     pub else_block: Option<BlockExpression>,
@@ -25,6 +28,23 @@ impl Expression {
     ) -> Self {
         Self {
             location,
+            pattern: None,
+            condition: Box::new(condition),
+            main_block,
+            else_block,
+        }
+    }
+
+    pub fn new_let(
+        location: Location,
+        pattern: MatchPattern,
+        condition: ExpressionTree,
+        main_block: BlockExpression,
+        else_block: Option<BlockExpression>,
+    ) -> Self {
+        Self {
+            location,
+            pattern: Some(pattern),
             condition: Box::new(condition),
             main_block,
             else_block,
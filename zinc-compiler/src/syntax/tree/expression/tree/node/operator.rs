@@ -47,6 +47,7 @@ pub enum Operator {
     Multiplication,
     Division,
     Remainder,
+    Exponentiation,
 
     // type casting
     Casting,
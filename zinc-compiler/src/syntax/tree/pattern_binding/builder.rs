@@ -19,6 +19,7 @@ pub struct Builder {
     identifier: Option<Identifier>,
     r#type: Option<Type>,
     is_mutable: bool,
+    is_public: bool,
     wildcard: bool,
     is_self_alias: bool,
     self_location: Option<Location>,
@@ -41,6 +42,14 @@ impl Builder {
         self.is_mutable = true;
     }
 
+    pub fn set_is_public(&mut self) {
+        self.is_public = true;
+    }
+
+    pub fn is_public(&self) -> bool {
+        self.is_public
+    }
+
     pub fn set_is_wildcard(&mut self) {
         self.wildcard = true;
     }
@@ -104,6 +113,8 @@ impl Builder {
             );
         };
 
-        BindingPattern::new(location, variant, r#type)
+        let mut pattern = BindingPattern::new(location, variant, r#type);
+        pattern.is_public = self.is_public;
+        pattern
     }
 }
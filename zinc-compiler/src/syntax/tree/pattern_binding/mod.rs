@@ -15,6 +15,8 @@ pub struct Pattern {
     pub location: Location,
     pub variant: Variant,
     pub r#type: Type,
+    /// Set if the `main` function argument is a public input of the circuit.
+    pub is_public: bool,
 }
 
 impl Pattern {
@@ -23,6 +25,7 @@ impl Pattern {
             location,
             variant,
             r#type,
+            is_public: false,
         }
     }
 }
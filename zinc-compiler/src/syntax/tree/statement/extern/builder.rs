@@ -0,0 +1,58 @@
+//!
+//! The extern statement builder.
+//!
+
+use crate::lexical::token::location::Location;
+use crate::syntax::tree::identifier::Identifier;
+use crate::syntax::tree::pattern_binding::Pattern as BindingPattern;
+use crate::syntax::tree::r#type::Type;
+use crate::syntax::tree::statement::r#extern::Statement as ExternStatement;
+
+#[derive(Default)]
+pub struct Builder {
+    location: Option<Location>,
+    abi: Option<String>,
+    identifier: Option<Identifier>,
+    argument_bindings: Vec<BindingPattern>,
+    return_type: Option<Type>,
+}
+
+impl Builder {
+    pub fn set_location(&mut self, value: Location) {
+        self.location = Some(value);
+    }
+
+    pub fn set_abi(&mut self, value: String) {
+        self.abi = Some(value);
+    }
+
+    pub fn set_identifier(&mut self, value: Identifier) {
+        self.identifier = Some(value);
+    }
+
+    pub fn set_argument_bindings(&mut self, value: Vec<BindingPattern>) {
+        self.argument_bindings = value;
+    }
+
+    pub fn set_return_type(&mut self, value: Type) {
+        self.return_type = Some(value);
+    }
+
+    pub fn finish(mut self) -> ExternStatement {
+        let location = self
+            .location
+            .take()
+            .unwrap_or_else(|| panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "location"));
+        ExternStatement::new(
+            location,
+            self.abi
+                .take()
+                .unwrap_or_else(|| panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "abi")),
+            self.identifier.take().unwrap_or_else(|| {
+                panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "identifier")
+            }),
+            self.argument_bindings,
+            self.return_type.take(),
+        )
+    }
+}
@@ -0,0 +1,37 @@
+//!
+//! The extern statement.
+//!
+
+pub mod builder;
+
+use crate::lexical::token::location::Location;
+use crate::syntax::tree::identifier::Identifier;
+use crate::syntax::tree::pattern_binding::Pattern as BindingPattern;
+use crate::syntax::tree::r#type::Type;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Statement {
+    pub location: Location,
+    pub abi: String,
+    pub identifier: Identifier,
+    pub argument_bindings: Vec<BindingPattern>,
+    pub return_type: Option<Type>,
+}
+
+impl Statement {
+    pub fn new(
+        location: Location,
+        abi: String,
+        identifier: Identifier,
+        argument_bindings: Vec<BindingPattern>,
+        return_type: Option<Type>,
+    ) -> Self {
+        Self {
+            location,
+            abi,
+            identifier,
+            argument_bindings,
+            return_type,
+        }
+    }
+}
@@ -3,7 +3,9 @@
 //!
 
 use crate::lexical::token::location::Location;
+use crate::syntax::tree::attribute::Attribute;
 use crate::syntax::tree::expression::block::Expression as BlockExpression;
+use crate::syntax::tree::field::Field;
 use crate::syntax::tree::identifier::Identifier;
 use crate::syntax::tree::pattern_binding::Pattern as BindingPattern;
 use crate::syntax::tree::r#type::Type;
@@ -13,9 +15,11 @@ use crate::syntax::tree::statement::r#fn::Statement as FnStatement;
 pub struct Builder {
     location: Option<Location>,
     identifier: Option<Identifier>,
+    const_parameters: Vec<Field>,
     argument_bindings: Vec<BindingPattern>,
     return_type: Option<Type>,
     body: Option<BlockExpression>,
+    attributes: Vec<Attribute>,
 }
 
 impl Builder {
@@ -27,6 +31,10 @@ impl Builder {
         self.identifier = Some(value);
     }
 
+    pub fn push_const_parameter(&mut self, value: Field) {
+        self.const_parameters.push(value);
+    }
+
     pub fn set_argument_bindings(&mut self, value: Vec<BindingPattern>) {
         self.argument_bindings = value;
     }
@@ -39,6 +47,10 @@ impl Builder {
         self.body = Some(value);
     }
 
+    pub fn push_attribute(&mut self, value: Attribute) {
+        self.attributes.push(value);
+    }
+
     pub fn finish(mut self) -> FnStatement {
         let location = self
             .location
@@ -49,11 +61,13 @@ impl Builder {
             self.identifier.take().unwrap_or_else(|| {
                 panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "identifier")
             }),
+            self.const_parameters,
             self.argument_bindings,
             self.return_type.take(),
             self.body
                 .take()
                 .unwrap_or_else(|| panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "body")),
+            self.attributes,
         )
     }
 }
@@ -5,7 +5,9 @@
 pub mod builder;
 
 use crate::lexical::token::location::Location;
+use crate::syntax::tree::attribute::Attribute;
 use crate::syntax::tree::expression::block::Expression as BlockExpression;
+use crate::syntax::tree::field::Field;
 use crate::syntax::tree::identifier::Identifier;
 use crate::syntax::tree::pattern_binding::Pattern as BindingPattern;
 use crate::syntax::tree::r#type::Type;
@@ -14,25 +16,38 @@ use crate::syntax::tree::r#type::Type;
 pub struct Statement {
     pub location: Location,
     pub identifier: Identifier,
+    pub const_parameters: Vec<Field>,
     pub argument_bindings: Vec<BindingPattern>,
     pub return_type: Option<Type>,
     pub body: BlockExpression,
+    pub attributes: Vec<Attribute>,
 }
 
 impl Statement {
     pub fn new(
         location: Location,
         identifier: Identifier,
+        const_parameters: Vec<Field>,
         argument_bindings: Vec<BindingPattern>,
         return_type: Option<Type>,
         body: BlockExpression,
+        attributes: Vec<Attribute>,
     ) -> Self {
         Self {
             location,
             identifier,
+            const_parameters,
             argument_bindings,
             return_type,
             body,
+            attributes,
         }
     }
+
+    ///
+    /// Whether the function has const parameters and is instantiated for each set of them.
+    ///
+    pub fn is_generic(&self) -> bool {
+        !self.const_parameters.is_empty()
+    }
 }
@@ -3,6 +3,7 @@
 //!
 
 use crate::lexical::token::location::Location;
+use crate::syntax::tree::attribute::Attribute;
 use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
 use crate::syntax::tree::statement::r#const::Statement as ConstStatement;
 use crate::syntax::tree::statement::r#for::Statement as ForStatement;
@@ -14,6 +15,7 @@ pub enum Statement {
     Const(ConstStatement),
     For(ForStatement),
     Empty(Location),
+    Cfg(Attribute, Box<Self>),
     Expression(ExpressionTree),
 }
 
@@ -24,6 +26,7 @@ impl Statement {
             Self::Const(inner) => inner.location,
             Self::For(inner) => inner.location,
             Self::Empty(location) => *location,
+            Self::Cfg(attribute, _) => attribute.location,
             Self::Expression(inner) => inner.location,
         }
     }
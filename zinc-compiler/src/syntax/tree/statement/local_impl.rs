@@ -3,6 +3,7 @@
 //!
 
 use crate::lexical::token::location::Location;
+use crate::syntax::tree::attribute::Attribute;
 use crate::syntax::tree::statement::r#const::Statement as ConstStatement;
 use crate::syntax::tree::statement::r#fn::Statement as FnStatement;
 
@@ -11,6 +12,7 @@ pub enum Statement {
     Const(ConstStatement),
     Fn(FnStatement),
     Empty(Location),
+    Cfg(Attribute, Box<Self>),
 }
 
 impl Statement {
@@ -19,6 +21,7 @@ impl Statement {
             Self::Const(inner) => inner.location,
             Self::Fn(inner) => inner.location,
             Self::Empty(location) => *location,
+            Self::Cfg(attribute, _) => attribute.location,
         }
     }
 }
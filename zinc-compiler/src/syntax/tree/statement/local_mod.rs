@@ -3,9 +3,11 @@
 //!
 
 use crate::lexical::token::location::Location;
+use crate::syntax::tree::attribute::Attribute;
 use crate::syntax::tree::statement::module::Statement as ModStatement;
 use crate::syntax::tree::statement::r#const::Statement as ConstStatement;
 use crate::syntax::tree::statement::r#enum::Statement as EnumStatement;
+use crate::syntax::tree::statement::r#extern::Statement as ExternStatement;
 use crate::syntax::tree::statement::r#fn::Statement as FnStatement;
 use crate::syntax::tree::statement::r#impl::Statement as ImplStatement;
 use crate::syntax::tree::statement::r#struct::Statement as StructStatement;
@@ -19,10 +21,12 @@ pub enum Statement {
     Struct(StructStatement),
     Enum(EnumStatement),
     Fn(FnStatement),
+    Extern(ExternStatement),
     Mod(ModStatement),
     Use(UseStatement),
     Impl(ImplStatement),
     Empty(Location),
+    Cfg(Attribute, Box<Self>),
 }
 
 impl Statement {
@@ -33,10 +37,12 @@ impl Statement {
             Self::Struct(inner) => inner.location,
             Self::Enum(inner) => inner.location,
             Self::Fn(inner) => inner.location,
+            Self::Extern(inner) => inner.location,
             Self::Mod(inner) => inner.location,
             Self::Use(inner) => inner.location,
             Self::Impl(inner) => inner.location,
             Self::Empty(location) => *location,
+            Self::Cfg(attribute, _) => attribute.location,
         }
     }
 }
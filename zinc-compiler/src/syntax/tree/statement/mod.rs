@@ -4,6 +4,7 @@
 
 pub mod r#const;
 pub mod r#enum;
+pub mod r#extern;
 pub mod r#fn;
 pub mod r#for;
 pub mod r#impl;
@@ -5,6 +5,7 @@
 use crate::lexical::token::lexeme::keyword::Keyword;
 use crate::lexical::token::location::Location;
 use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
+use crate::syntax::tree::literal::integer::Literal as IntegerLiteral;
 use crate::syntax::tree::r#type::variant::Variant as TypeVariant;
 use crate::syntax::tree::r#type::Type;
 
@@ -13,6 +14,8 @@ pub struct Builder {
     location: Option<Location>,
     is_unit: bool,
     keyword: Option<Keyword>,
+    decimal_integer_bits: Option<IntegerLiteral>,
+    decimal_fraction_bits: Option<IntegerLiteral>,
     array_type_variant: Option<TypeVariant>,
     array_size: Option<ExpressionTree>,
     tuple_element_types: Vec<TypeVariant>,
@@ -34,6 +37,14 @@ impl Builder {
         self.keyword = Some(value);
     }
 
+    pub fn set_decimal_integer_bits(&mut self, value: IntegerLiteral) {
+        self.decimal_integer_bits = Some(value);
+    }
+
+    pub fn set_decimal_fraction_bits(&mut self, value: IntegerLiteral) {
+        self.decimal_fraction_bits = Some(value);
+    }
+
     pub fn set_array_type_variant(&mut self, value: TypeVariant) {
         self.array_type_variant = Some(value);
     }
@@ -71,6 +82,13 @@ impl Builder {
                 Keyword::Field => TypeVariant::field(),
                 keyword => panic!("{}{}", PANIC_BUILDER_TYPE_INVALID_KEYWORD, keyword),
             }
+        } else if let Some(integer_bits) = self.decimal_integer_bits.take() {
+            TypeVariant::decimal(
+                integer_bits,
+                self.decimal_fraction_bits.take().unwrap_or_else(|| {
+                    panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "fraction bits")
+                }),
+            )
         } else if let Some(array_type) = self.array_type_variant.take() {
             TypeVariant::array(
                 array_type,
@@ -3,6 +3,7 @@
 //!
 
 use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
+use crate::syntax::tree::literal::integer::Literal as IntegerLiteral;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Variant {
@@ -15,6 +16,10 @@ pub enum Variant {
         bitlength: usize,
     },
     Field,
+    Decimal {
+        integer_bits: IntegerLiteral,
+        fraction_bits: IntegerLiteral,
+    },
     Array {
         inner: Box<Self>,
         size: ExpressionTree,
@@ -56,6 +61,13 @@ impl Variant {
         Self::Field
     }
 
+    pub fn decimal(integer_bits: IntegerLiteral, fraction_bits: IntegerLiteral) -> Self {
+        Self::Decimal {
+            integer_bits,
+            fraction_bits,
+        }
+    }
+
     pub fn array(inner: Self, size: ExpressionTree) -> Self {
         Self::Array {
             inner: Box::new(inner),
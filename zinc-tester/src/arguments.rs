@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use structopt::StructOpt;
 
 #[derive(Debug, StructOpt)]
@@ -20,6 +22,93 @@ pub struct Arguments {
     )]
     pub proof_check: bool,
 
+    #[structopt(
+        short = "u",
+        long = "unit",
+        help = "Runs the `#[test]` functions of the test files instead of their cases."
+    )]
+    pub unit: bool,
+
+    #[structopt(
+        short = "s",
+        long = "snapshots",
+        help = "Compares the diagnostics of the broken source files with their snapshots."
+    )]
+    pub snapshots: bool,
+
+    #[structopt(
+        long = "update-snapshots",
+        help = "Writes the missing and mismatching diagnostic snapshots, implies `--snapshots`."
+    )]
+    pub update_snapshots: bool,
+
+    #[structopt(
+        short = "j",
+        long = "jobs",
+        help = "The number of worker threads, which defaults to the number of CPUs."
+    )]
+    pub jobs: Option<usize>,
+
+    #[structopt(
+        long = "timeout",
+        help = "The maximal case run time in seconds, after which the case is failed."
+    )]
+    pub timeout: Option<u64>,
+
+    #[structopt(
+        long = "coverage",
+        parse(from_os_str),
+        help = "Writes the source line coverage of the evaluated cases to the lcov file."
+    )]
+    pub coverage: Option<PathBuf>,
+
     #[structopt(short = "q", long = "quiet", help = "Doesn't show successful tests.")]
     pub quiet: bool,
+
+    #[structopt(
+        long = "diff-limit",
+        default_value = "10",
+        help = "The maximal number of differing output paths shown for a failed case."
+    )]
+    pub diff_limit: usize,
+
+    #[structopt(
+        long = "dump-mismatches",
+        parse(from_os_str),
+        help = "Writes the full expected and actual outputs of failed cases to the directory."
+    )]
+    pub dump_mismatches: Option<PathBuf>,
+
+    #[structopt(
+        long = "rerun-failed",
+        help = "Runs only the cases which failed during the last run."
+    )]
+    pub rerun_failed: bool,
+
+    #[structopt(
+        long = "changed",
+        help = "Runs only the test files which are new or have changed since the last run."
+    )]
+    pub changed: bool,
+
+    #[structopt(
+        long = "state-file",
+        parse(from_os_str),
+        help = "The last run state file, which defaults to `target/zinc-tester/<mode>-state.json`."
+    )]
+    pub state_file: Option<PathBuf>,
+
+    #[structopt(
+        long = "include-tags",
+        use_delimiter = true,
+        help = "Runs only the cases with any of the comma-separated tags."
+    )]
+    pub include_tags: Vec<String>,
+
+    #[structopt(
+        long = "exclude-tags",
+        use_delimiter = true,
+        help = "Skips the cases with any of the comma-separated tags."
+    )]
+    pub exclude_tags: Vec<String>,
 }
@@ -0,0 +1,78 @@
+//!
+//! The Zinc tester line coverage report.
+//!
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use zinc_bytecode::SourceMap;
+use zinc_vm::Coverage;
+
+///
+/// The numbers of executions of the source lines, collected from all the test runs.
+///
+/// A line is executed as many times as its most executed instruction, so the lines
+/// compiled into several instructions are not counted several times. The lines with
+/// instructions which have never been executed are reported with zero hits.
+///
+#[derive(Debug, Default)]
+pub struct CoverageReport {
+    pub files: BTreeMap<String, BTreeMap<usize, usize>>,
+}
+
+impl CoverageReport {
+    ///
+    /// Adds the instruction executions of a single run.
+    ///
+    /// The instructions without the file marker are attributed to the `default_file`,
+    /// e.g. the test file the program has been compiled from.
+    ///
+    pub fn add(&mut self, default_file: &str, source_map: &SourceMap, coverage: &Coverage) {
+        let mut lines = BTreeMap::<(String, usize), usize>::new();
+        for (address, hits) in coverage.hits.iter().enumerate() {
+            if let Some(location) = source_map.get(address) {
+                let file = location
+                    .file
+                    .clone()
+                    .unwrap_or_else(|| default_file.to_owned());
+                let line_hits = lines.entry((file, location.line)).or_default();
+                *line_hits = (*line_hits).max(*hits);
+            }
+        }
+
+        for ((file, line), hits) in lines.into_iter() {
+            *self.files.entry(file).or_default().entry(line).or_default() += hits;
+        }
+    }
+
+    ///
+    /// Writes the report in the `lcov` tracefile format, supported by the most
+    /// coverage viewers.
+    ///
+    pub fn write(&self, path: &PathBuf) -> Result<(), io::Error> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::write(path, self.to_lcov())
+    }
+
+    fn to_lcov(&self) -> String {
+        let mut result = String::new();
+        for (file, lines) in self.files.iter() {
+            result.push_str("TN:\n");
+            result.push_str(format!("SF:{}\n", file).as_str());
+            for (line, hits) in lines.iter() {
+                result.push_str(format!("DA:{},{}\n", line, hits).as_str());
+            }
+            result.push_str(format!("LF:{}\n", lines.len()).as_str());
+            result.push_str(
+                format!("LH:{}\n", lines.values().filter(|hits| **hits > 0).count()).as_str(),
+            );
+            result.push_str("end_of_record\n");
+        }
+        result
+    }
+}
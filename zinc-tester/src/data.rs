@@ -8,6 +8,10 @@ use failure::Fail;
 use serde_derive::Deserialize;
 use serde_json::Value as JsonValue;
 
+use zinc_vm::RuntimeError;
+
+use crate::property::Property;
+
 #[derive(Debug, Deserialize, PartialEq)]
 pub struct TestCase {
     pub case: String,
@@ -15,8 +19,25 @@ pub struct TestCase {
     pub should_panic: bool,
     #[serde(default)]
     pub ignore: bool,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
     pub input: JsonValue,
+    #[serde(default)]
     pub expect: JsonValue,
+    #[serde(default)]
+    pub expect_code: Option<u64>,
+    #[serde(default)]
+    pub property: Option<Property>,
+}
+
+impl TestCase {
+    ///
+    /// Checks if the `error` has the expected assertion error code, if the code is specified.
+    ///
+    pub fn is_error_expected(&self, error: &RuntimeError) -> bool {
+        self.expect_code.is_none() || self.expect_code == error.assertion_code()
+    }
 }
 
 #[derive(Debug, Deserialize, PartialEq)]
@@ -24,6 +45,23 @@ pub struct TestData {
     pub cases: Vec<TestCase>,
     #[serde(default)]
     pub ignore: bool,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+impl TestData {
+    ///
+    /// Leaves only the cases having any of the `include` tags, if they are specified,
+    /// and none of the `exclude` ones. The file tags apply to every case of the file.
+    ///
+    pub fn filter_tags(&mut self, include: &[String], exclude: &[String]) {
+        let file_tags = &self.tags;
+        self.cases.retain(|case| {
+            let has_tag = |tag: &String| file_tags.contains(tag) || case.tags.contains(tag);
+
+            (include.is_empty() || include.iter().any(has_tag)) && !exclude.iter().any(has_tag)
+        });
+    }
 }
 
 #[derive(Debug, Fail)]
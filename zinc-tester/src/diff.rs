@@ -0,0 +1,151 @@
+//!
+//! The Zinc tester expected/actual output difference.
+//!
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use colored::Colorize;
+use serde_json::Value as JsonValue;
+
+///
+/// A single mismatch between the expected and actual outputs.
+///
+/// The path is written in the Zinc access notation, e.g. `.balances[2].amount`,
+/// and the missing sides are `None`.
+///
+#[derive(Debug, PartialEq)]
+pub struct Difference {
+    pub path: String,
+    pub expected: Option<JsonValue>,
+    pub actual: Option<JsonValue>,
+}
+
+impl Difference {
+    ///
+    /// Collects the differing leaf paths of two JSON values.
+    ///
+    pub fn all(expected: &JsonValue, actual: &JsonValue) -> Vec<Self> {
+        let mut differences = Vec::new();
+        Self::collect(String::new(), expected, actual, &mut differences);
+        differences
+    }
+
+    fn collect(path: String, expected: &JsonValue, actual: &JsonValue, output: &mut Vec<Self>) {
+        match (expected, actual) {
+            (JsonValue::Object(expected), JsonValue::Object(actual)) => {
+                for (key, expected_value) in expected.iter() {
+                    let path = format!("{}.{}", path, key);
+                    match actual.get(key) {
+                        Some(actual_value) => {
+                            Self::collect(path, expected_value, actual_value, output)
+                        }
+                        None => output.push(Self::new(path, Some(expected_value), None)),
+                    }
+                }
+                for (key, actual_value) in actual.iter() {
+                    if !expected.contains_key(key) {
+                        let path = format!("{}.{}", path, key);
+                        output.push(Self::new(path, None, Some(actual_value)));
+                    }
+                }
+            }
+            (JsonValue::Array(expected), JsonValue::Array(actual)) => {
+                for index in 0..expected.len().max(actual.len()) {
+                    let path = format!("{}[{}]", path, index);
+                    match (expected.get(index), actual.get(index)) {
+                        (Some(expected), Some(actual)) => {
+                            Self::collect(path, expected, actual, output)
+                        }
+                        (expected, actual) => output.push(Self::new(path, expected, actual)),
+                    }
+                }
+            }
+            (expected, actual) if expected != actual => {
+                output.push(Self::new(path, Some(expected), Some(actual)))
+            }
+            _ => {}
+        }
+    }
+
+    fn new(path: String, expected: Option<&JsonValue>, actual: Option<&JsonValue>) -> Self {
+        Self {
+            path: if path.is_empty() {
+                "<root>".to_owned()
+            } else {
+                path
+            },
+            expected: expected.cloned(),
+            actual: actual.cloned(),
+        }
+    }
+}
+
+impl fmt::Display for Difference {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let side = |value: &Option<JsonValue>| match value {
+            Some(value) => value.to_string(),
+            None => "<missing>".to_owned(),
+        };
+
+        write!(
+            f,
+            "{}: expected {}, got {}",
+            self.path,
+            side(&self.expected).green(),
+            side(&self.actual).bright_red(),
+        )
+    }
+}
+
+///
+/// Formats the differences as an indented list, showing at most `limit` entries.
+///
+pub fn format(differences: &[Difference], limit: usize) -> String {
+    let mut result = String::new();
+    for difference in differences.iter().take(limit) {
+        result.push_str(&format!("\n    {}", difference));
+    }
+    if differences.len() > limit {
+        result.push_str(&format!(
+            "\n    ... and {} more difference(s)",
+            differences.len() - limit
+        ));
+    }
+    result
+}
+
+///
+/// Writes the full expected and actual outputs to `<directory>/<case>.{expected,actual}.json`.
+///
+/// Returns the path prefix of the written files.
+///
+pub fn dump(
+    directory: &PathBuf,
+    case_name: &str,
+    expected: &JsonValue,
+    actual: &JsonValue,
+) -> io::Result<PathBuf> {
+    fs::create_dir_all(directory)?;
+
+    let file_name = case_name.replace(|c: char| !c.is_ascii_alphanumeric() && c != '_', "_");
+    let prefix = directory.join(file_name);
+
+    let mut expected_path = prefix.clone();
+    expected_path.set_extension("expected.json");
+    fs::write(
+        expected_path,
+        serde_json::to_string_pretty(expected).expect("Always valid") + "\n",
+    )?;
+
+    let mut actual_path = prefix.clone();
+    actual_path.set_extension("actual.json");
+    fs::write(
+        actual_path,
+        serde_json::to_string_pretty(actual).expect("Always valid") + "\n",
+    )?;
+
+    Ok(prefix)
+}
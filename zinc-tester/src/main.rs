@@ -3,11 +3,17 @@
 //!
 
 mod arguments;
+mod coverage;
 mod data;
+mod diff;
 mod directory;
 mod file;
 mod program;
+mod project;
+mod property;
 mod runners;
+mod snapshot;
+mod state;
 
 use std::convert::TryFrom;
 use std::fmt;
@@ -16,43 +22,160 @@ use std::process;
 use std::str::FromStr;
 use std::sync::Arc;
 use std::sync::Mutex;
+use std::time::Duration;
 
 use colored::Colorize;
 use rayon::iter::IntoParallelIterator;
 use rayon::iter::ParallelIterator;
 use structopt::StructOpt;
 
+use zinc_compiler::Filter;
+
+use self::coverage::CoverageReport;
 use self::data::TestData;
 use self::directory::TestDirectory;
 use self::file::TestFile;
+use self::project::TestProject;
 use self::runners::EvaluationTestRunner;
+use self::runners::MismatchReporter;
+use self::runners::ProjectRunner;
 use self::runners::ProofCheckRunner;
+use self::runners::SnapshotRunner;
 use self::runners::TestRunner;
+use self::runners::UnitTestRunner;
+use self::snapshot::Snapshot;
+use self::state::State;
 
 const EXIT_CODE_SUCCESS: i32 = 0;
 const EXIT_CODE_FAILURE: i32 = 1;
 
 static TESTS_DIRECTORY: &str = "zinc-tester/tests/";
+static PROJECTS_DIRECTORY: &str = "zinc-tester/projects/";
+static SNAPSHOTS_DIRECTORY: &str = "zinc-tester/snapshots/";
+static STATE_FILE_EVALUATION: &str = "target/zinc-tester/evaluation-state.json";
+static STATE_FILE_PROOF_CHECK: &str = "target/zinc-tester/proof-check-state.json";
+static STATE_FILE_UNIT: &str = "target/zinc-tester/unit-state.json";
 
 static PANIC_TEST_DIRECTORY_INVALID: &str = "The test files directory must be valid";
+static PANIC_PROJECTS_DIRECTORY_INVALID: &str = "The test projects directory must be valid";
+static PANIC_SNAPSHOTS_DIRECTORY_INVALID: &str = "The snapshots directory must be valid";
 static PANIC_LAST_SHARED_REFERENCE: &str = "There are no other references at this point";
 static PANIC_MUTEX_SYNC: &str = "Mutexes never panic";
+static PANIC_THREAD_POOL_INITIALIZATION: &str = "The thread pool is initialized only once";
 
 fn main() {
     let args = arguments::Arguments::from_args();
+
+    if let Some(jobs) = args.jobs {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(jobs)
+            .build_global()
+            .expect(PANIC_THREAD_POOL_INITIALIZATION);
+    }
+    let timeout = args.timeout.map(Duration::from_secs);
+    let coverage = args
+        .coverage
+        .as_ref()
+        .filter(|_| !args.proof_check)
+        .map(|_| Arc::new(Mutex::new(CoverageReport::default())));
+
+    if args.snapshots || args.update_snapshots {
+        let runner = SnapshotRunner {
+            verbosity: args.verbosity,
+            update: args.update_snapshots,
+        };
+        exit(main_snapshots(runner));
+    }
+
+    let state_path = args.state_file.clone().unwrap_or_else(|| {
+        PathBuf::from(if args.proof_check {
+            STATE_FILE_PROOF_CHECK
+        } else if args.unit {
+            STATE_FILE_UNIT
+        } else {
+            STATE_FILE_EVALUATION
+        })
+    });
+    let mut state = State::load(&state_path).unwrap_or_else(|error| {
+        println!(
+            "[INTEGRATION] {} the last run state {:?} ({})",
+            "IGNORE".yellow(),
+            state_path,
+            error
+        );
+        State::default()
+    });
+    let selection = Selection {
+        rerun_failed: args.rerun_failed,
+        changed: args.changed,
+        include_tags: args.include_tags,
+        exclude_tags: args.exclude_tags,
+    };
+
     let result = if args.proof_check {
         let runner = ProofCheckRunner {
             verbosity: args.verbosity,
+            mismatch: MismatchReporter::new(args.diff_limit, args.dump_mismatches),
+            timeout,
+        };
+        main_inner(runner, &mut state, &selection)
+    } else if args.unit {
+        Filter::enable_features(vec![Filter::FEATURE_TEST.to_owned()]);
+        let runner = UnitTestRunner {
+            verbosity: args.verbosity,
+            timeout,
+            coverage: coverage.clone(),
         };
-        main_inner(runner)
+        main_inner(runner, &mut state, &selection)
     } else {
         let runner = EvaluationTestRunner {
             verbosity: args.verbosity,
+            mismatch: MismatchReporter::new(args.diff_limit, args.dump_mismatches.clone()),
+            timeout,
+            coverage: coverage.clone(),
         };
-        main_inner(runner)
+        let mut summary = main_inner(runner, &mut state, &selection);
+
+        if selection.is_full() {
+            let runner = ProjectRunner {
+                verbosity: args.verbosity,
+                mismatch: MismatchReporter::new(args.diff_limit, args.dump_mismatches),
+                timeout,
+                coverage: coverage.clone(),
+            };
+            summary = main_projects(runner, summary);
+        }
+
+        summary
     };
 
-    process::exit(match result {
+    if let Err(error) = state.save(&state_path) {
+        println!(
+            "[INTEGRATION] {} the last run state {:?} saving ({})",
+            "FAILED".bright_red(),
+            state_path,
+            error
+        );
+    }
+
+    if let (Some(path), Some(coverage)) = (args.coverage.as_ref(), coverage) {
+        let coverage = coverage.lock().expect(PANIC_MUTEX_SYNC);
+        match coverage.write(path) {
+            Ok(()) => println!("[INTEGRATION] The coverage written to {:?}", path),
+            Err(error) => println!(
+                "[INTEGRATION] {} the coverage {:?} writing ({})",
+                "FAILED".bright_red(),
+                path,
+                error
+            ),
+        }
+    }
+
+    exit(result)
+}
+
+fn exit(summary: Summary) -> ! {
+    process::exit(match summary {
         summary if summary.failed == 0 && summary.invalid == 0 => {
             println!(
                 "[{}] {} ({})",
@@ -74,7 +197,7 @@ fn main() {
     })
 }
 
-fn main_inner<R: TestRunner>(runner: R) -> Summary {
+fn main_inner<R: TestRunner>(runner: R, state: &mut State, selection: &Selection) -> Summary {
     println!(
         "[INTEGRATION] Started with {} worker threads",
         rayon::current_num_threads()
@@ -82,40 +205,192 @@ fn main_inner<R: TestRunner>(runner: R) -> Summary {
 
     let summary = Arc::new(Mutex::new(Summary::default()));
 
-    TestDirectory::new(&PathBuf::from(TESTS_DIRECTORY))
+    let last_state: &State = state;
+    let file_hashes = TestDirectory::new(&PathBuf::from(TESTS_DIRECTORY))
         .expect(PANIC_TEST_DIRECTORY_INVALID)
         .file_paths
         .into_par_iter()
-        .map(|test_file_path| {
+        .filter_map(|test_file_path| {
             let test_file = TestFile::try_from(&test_file_path)
                 .unwrap_or_else(|_| panic!("Test file {:?} is invalid", test_file_path));
-            let test_data = TestData::from_str(test_file.code.as_str())
+            let mut test_data = TestData::from_str(test_file.code.as_str())
                 .unwrap_or_else(|_| panic!("Test file {:?} case data is invalid", test_file_path));
 
+            let file_name = match test_file_path.strip_prefix(TESTS_DIRECTORY) {
+                Ok(path) => path,
+                Err(_error) => test_file_path.as_path(),
+            }
+            .to_string_lossy()
+            .to_string();
+            let hash = state::hash(test_file.code.as_str());
+
+            if !selection.select(last_state, &file_name, &hash, &mut test_data) {
+                return None;
+            }
+
             runner.run(&test_file_path, &test_file, &test_data, summary.clone());
+
+            Some((file_name, hash))
         })
-        .collect::<Vec<()>>();
+        .collect::<Vec<(String, String)>>();
 
-    Arc::try_unwrap(summary)
+    let summary = Arc::try_unwrap(summary)
         .expect(PANIC_LAST_SHARED_REFERENCE)
         .into_inner()
-        .expect(PANIC_LAST_SHARED_REFERENCE)
+        .expect(PANIC_LAST_SHARED_REFERENCE);
+
+    for (file_name, hash) in file_hashes.into_iter() {
+        let case_prefix = format!("{}::", file_name);
+        let failures = summary
+            .failures
+            .iter()
+            .filter(|failure| **failure == file_name || failure.starts_with(case_prefix.as_str()))
+            .cloned()
+            .collect();
+        state.update(file_name, hash, failures);
+    }
+
+    summary
+}
+
+///
+/// Runs the compiler diagnostic snapshots, which are not tracked in the last run state.
+///
+fn main_snapshots(runner: SnapshotRunner) -> Summary {
+    let summary = Mutex::new(Summary::default());
+
+    Snapshot::discover(&PathBuf::from(SNAPSHOTS_DIRECTORY))
+        .expect(PANIC_SNAPSHOTS_DIRECTORY_INVALID)
+        .into_par_iter()
+        .for_each(|source_path| runner.run(&source_path, &summary));
+
+    summary.into_inner().expect(PANIC_LAST_SHARED_REFERENCE)
+}
+
+///
+/// Runs the multi-file project fixtures, which are not tracked in the last run state.
+///
+fn main_projects(runner: ProjectRunner, summary: Summary) -> Summary {
+    let summary = Mutex::new(summary);
+
+    TestProject::discover(&PathBuf::from(PROJECTS_DIRECTORY))
+        .expect(PANIC_PROJECTS_DIRECTORY_INVALID)
+        .into_par_iter()
+        .for_each(|project_path| {
+            let project = TestProject::try_from(&project_path).unwrap_or_else(|error| {
+                panic!("Test project {:?} is invalid: {}", project_path, error)
+            });
+
+            runner.run(&project_path, &project, &summary);
+        });
+
+    summary.into_inner().expect(PANIC_LAST_SHARED_REFERENCE)
+}
+
+///
+/// The subset of the test cases to run, based on the tags and the last run state.
+///
+#[derive(Debug)]
+pub struct Selection {
+    /// Runs only the cases which failed during the last run.
+    pub rerun_failed: bool,
+    /// Runs only the files which are new or have changed since the last run.
+    pub changed: bool,
+    /// Runs only the cases with any of the tags, if not empty.
+    pub include_tags: Vec<String>,
+    /// Skips the cases with any of the tags.
+    pub exclude_tags: Vec<String>,
+}
+
+impl Selection {
+    ///
+    /// Checks if every test case is selected, e.g. no filters are set.
+    ///
+    pub fn is_full(&self) -> bool {
+        !self.rerun_failed
+            && !self.changed
+            && self.include_tags.is_empty()
+            && self.exclude_tags.is_empty()
+    }
+
+    ///
+    /// Leaves only the selected cases in `test_data`.
+    ///
+    /// Returns `false` if nothing is left to run in the file. If both last run options
+    /// are set, the changed files are run entirely, and the other ones only with the
+    /// failed cases. The tag filters are applied in any case.
+    ///
+    pub fn select(
+        &self,
+        state: &State,
+        file_name: &str,
+        hash: &str,
+        test_data: &mut TestData,
+    ) -> bool {
+        test_data.filter_tags(self.include_tags.as_slice(), self.exclude_tags.as_slice());
+        if test_data.cases.is_empty() {
+            return false;
+        }
+
+        if !self.rerun_failed && !self.changed {
+            return true;
+        }
+
+        if self.changed && state.is_changed(file_name, hash) {
+            return true;
+        }
+
+        if self.rerun_failed {
+            test_data.cases.retain(|test_case| {
+                state.has_failed(
+                    file_name,
+                    format!("{}::{}", file_name, test_case.case).as_str(),
+                )
+            });
+        } else {
+            test_data.cases.clear();
+        }
+
+        !test_data.cases.is_empty()
+    }
 }
 
 #[derive(Debug, Default)]
 pub struct Summary {
     pub passed: usize,
     pub failed: usize,
+    pub timed_out: usize,
     pub ignored: usize,
     pub invalid: usize,
+    pub failures: Vec<String>,
+}
+
+impl Summary {
+    pub fn fail(&mut self, name: String) {
+        self.failed += 1;
+        self.failures.push(name);
+    }
+
+    ///
+    /// Records the case exceeding the time limit, which is also counted as failed.
+    ///
+    pub fn time_out(&mut self, name: String) {
+        self.timed_out += 1;
+        self.fail(name);
+    }
+
+    pub fn invalidate(&mut self, name: String) {
+        self.invalid += 1;
+        self.failures.push(name);
+    }
 }
 
 impl fmt::Display for Summary {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(
-            f,
-            "{} passed, {} failed, {} ignored, {} invalid",
-            self.passed, self.failed, self.ignored, self.invalid
-        )
+        write!(f, "{} passed, {} failed", self.passed, self.failed)?;
+        if self.timed_out > 0 {
+            write!(f, " ({} timed out)", self.timed_out)?;
+        }
+        write!(f, ", {} ignored, {} invalid", self.ignored, self.invalid)
     }
 }
@@ -4,21 +4,26 @@
 
 use std::cell::RefCell;
 use std::collections::HashMap;
+use std::path::PathBuf;
 use std::rc::Rc;
 
 use failure::Fail;
 use serde_json::Value as JsonValue;
 
+use zinc_bytecode::artifact::Artifact;
 use zinc_bytecode::data::values::JsonValueError;
 use zinc_bytecode::data::values::Value;
 use zinc_bytecode::program::Program;
+use zinc_bytecode::SourceMap;
 use zinc_compiler::Bytecode;
 use zinc_compiler::EntryAnalyzer;
 use zinc_compiler::Parser;
+use zinc_compiler::Source;
 
 pub struct ProgramData {
     pub program: Program,
     pub input: Value,
+    pub source_map: SourceMap,
 }
 
 #[derive(Debug, Fail)]
@@ -33,14 +38,77 @@ pub enum Error {
 
 impl ProgramData {
     pub fn new(witness: &JsonValue, code: &str) -> Result<Self, Error> {
-        let program = Self::compile(code)?;
+        let artifact = Self::compile_artifact(code)?;
+        let source_map = artifact.source_map();
+        let program = artifact.program;
         let input =
             Value::from_typed_json(witness, &program.input).map_err(Error::JsonTypeValue)?;
 
-        Ok(Self { program, input })
+        Ok(Self {
+            program,
+            input,
+            source_map,
+        })
+    }
+
+    ///
+    /// Compiles the program and returns the programs running its `#[test]` functions.
+    ///
+    /// The `test` feature must be enabled beforehand, otherwise the test functions are
+    /// filtered out.
+    ///
+    pub fn compile_tests(code: &str) -> Result<Vec<(String, Program)>, Error> {
+        let mut bytecode = Self::compile_bytecode(code)?;
+
+        let mut programs = Vec::new();
+        for test in bytecode.test_manifest().tests.into_iter() {
+            let program = Program::from_bytes(bytecode.test_bytes(test.address).as_slice())
+                .map_err(Error::Program)?;
+            programs.push((test.name, program));
+        }
+
+        Ok(programs)
     }
 
     pub fn compile(code: &str) -> Result<Program, Error> {
+        Self::compile_artifact(code).map(|artifact| artifact.program)
+    }
+
+    ///
+    /// Compiles the program into the build artifact, as the compiler writes it.
+    ///
+    pub fn compile_artifact(code: &str) -> Result<Artifact, Error> {
+        let bytecode = Self::compile_bytecode(code)?;
+
+        let artifact =
+            Artifact::from_bytes(bytecode.into_bytes().as_slice()).map_err(Error::Program)?;
+
+        Ok(artifact)
+    }
+
+    ///
+    /// Compiles the multi-file project with the `main.zn` entry and its modules
+    /// found in the `source_path` directory.
+    ///
+    pub fn compile_project(source_path: &PathBuf) -> Result<Artifact, Error> {
+        let source = Source::try_from_directory(source_path)
+            .map_err(|error| Error::Compiler(error.to_string()))?;
+
+        let bytecode = Rc::new(RefCell::new(Bytecode::new()));
+        source
+            .compile(bytecode.clone())
+            .map_err(|error| Error::Compiler(error.to_string()))?;
+        let bytecode = Rc::try_unwrap(bytecode)
+            .expect(crate::PANIC_LAST_SHARED_REFERENCE)
+            .into_inner();
+
+        let artifact =
+            Artifact::from_bytes(bytecode.into_bytes().as_slice()).map_err(Error::Program)?;
+
+        Ok(artifact)
+    }
+
+    fn compile_bytecode(code: &str) -> Result<Bytecode, Error> {
         let lines = code.lines().collect::<Vec<&str>>();
 
         let syntax_tree = Parser::default()
@@ -54,14 +122,12 @@ impl ProgramData {
             .map_err(Error::Compiler)?;
 
         let bytecode = Rc::new(RefCell::new(Bytecode::new()));
+        bytecode.borrow_mut().hash_source(code);
         intermediate.write_all_to_bytecode(bytecode.clone());
         let bytecode = Rc::try_unwrap(bytecode)
             .expect(crate::PANIC_LAST_SHARED_REFERENCE)
             .into_inner();
 
-        let program =
-            Program::from_bytes(bytecode.into_bytes().as_slice()).map_err(Error::Program)?;
-
-        Ok(program)
+        Ok(bytecode)
     }
 }
@@ -0,0 +1,113 @@
+//!
+//! The Zinc tester multi-file project.
+//!
+
+use std::convert::TryFrom;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use failure::Fail;
+use serde_json::Value as JsonValue;
+
+///
+/// The project fixture, laid out like a Zargo project:
+///
+/// - `Zargo.toml`, the manifest marking the fixture directory
+/// - `src/`, the source files with `main.zn` and its modules
+/// - `cases/<case>/witness.json` and `cases/<case>/public-data.json`, the case input and output
+///
+#[derive(Debug, PartialEq)]
+pub struct TestProject {
+    pub source_path: PathBuf,
+    pub cases: Vec<ProjectCase>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ProjectCase {
+    pub case: String,
+    pub input: JsonValue,
+    pub expect: JsonValue,
+}
+
+#[derive(Debug, Fail)]
+pub enum Error {
+    #[fail(display = "directory {:?} reading: {}", _0, _1)]
+    Reading(PathBuf, io::Error),
+    #[fail(display = "file {:?} parsing: {}", _0, _1)]
+    Parsing(PathBuf, serde_json::Error),
+}
+
+static MANIFEST_FILE_NAME: &str = "Zargo.toml";
+static SOURCE_DIRECTORY_NAME: &str = "src";
+static CASES_DIRECTORY_NAME: &str = "cases";
+static WITNESS_FILE_NAME: &str = "witness.json";
+static PUBLIC_DATA_FILE_NAME: &str = "public-data.json";
+
+impl TestProject {
+    ///
+    /// Finds the project fixtures in the directory and its subdirectories.
+    ///
+    pub fn discover(path: &PathBuf) -> Result<Vec<PathBuf>, Error> {
+        if path.join(MANIFEST_FILE_NAME).is_file() {
+            return Ok(vec![path.to_owned()]);
+        }
+
+        let mut project_paths = Vec::new();
+        for entry in fs::read_dir(path).map_err(|error| Error::Reading(path.to_owned(), error))? {
+            let entry_path = entry
+                .map_err(|error| Error::Reading(path.to_owned(), error))?
+                .path();
+            if entry_path.is_dir() {
+                project_paths.extend(Self::discover(&entry_path)?);
+            }
+        }
+        project_paths.sort();
+
+        Ok(project_paths)
+    }
+
+    fn read_json(path: PathBuf) -> Result<JsonValue, Error> {
+        let string =
+            fs::read_to_string(&path).map_err(|error| Error::Reading(path.clone(), error))?;
+        serde_json::from_str(string.as_str()).map_err(|error| Error::Parsing(path, error))
+    }
+}
+
+impl TryFrom<&PathBuf> for TestProject {
+    type Error = Error;
+
+    fn try_from(path: &PathBuf) -> Result<Self, Self::Error> {
+        let cases_path = path.join(CASES_DIRECTORY_NAME);
+
+        let mut case_paths = Vec::new();
+        for entry in
+            fs::read_dir(&cases_path).map_err(|error| Error::Reading(cases_path.clone(), error))?
+        {
+            let entry_path = entry
+                .map_err(|error| Error::Reading(cases_path.clone(), error))?
+                .path();
+            if entry_path.is_dir() {
+                case_paths.push(entry_path);
+            }
+        }
+        case_paths.sort();
+
+        let mut cases = Vec::with_capacity(case_paths.len());
+        for case_path in case_paths.into_iter() {
+            cases.push(ProjectCase {
+                case: case_path
+                    .file_name()
+                    .map(|name| name.to_string_lossy().to_string())
+                    .unwrap_or_default(),
+                input: Self::read_json(case_path.join(WITNESS_FILE_NAME))?,
+                expect: Self::read_json(case_path.join(PUBLIC_DATA_FILE_NAME))?,
+            });
+        }
+
+        Ok(Self {
+            source_path: path.join(SOURCE_DIRECTORY_NAME),
+            cases,
+        })
+    }
+}
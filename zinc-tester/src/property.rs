@@ -0,0 +1,339 @@
+//!
+//! The Zinc tester property-based cases.
+//!
+
+use std::collections::BTreeMap;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use serde_derive::Deserialize;
+use serde_json::Map as JsonMap;
+use serde_json::Value as JsonValue;
+
+///
+/// The property-based case metadata.
+///
+/// The circuit is run with `runs` random inputs built with the argument strategies, and
+/// the property holds if none of the runs fails, e.g. all the assertions hold. If the
+/// `expect` field of the case is set, every run must also produce that output.
+///
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct Property {
+    #[serde(default = "Property::default_runs")]
+    pub runs: usize,
+    #[serde(default)]
+    pub seed: Option<u64>,
+    pub arguments: BTreeMap<String, Strategy>,
+}
+
+///
+/// The argument value strategy.
+///
+/// The integer bounds are inclusive decimal strings, like the case inputs.
+///
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Strategy {
+    Boolean,
+    Range { min: String, max: String },
+    Array { len: usize, element: Box<Strategy> },
+}
+
+///
+/// The generated argument value, which can be shrunk.
+///
+#[derive(Debug, Clone, PartialEq)]
+enum Sample {
+    Boolean(bool),
+    Integer { value: i128, min: i128, max: i128 },
+    Array(Vec<Sample>),
+}
+
+///
+/// The property check failure.
+///
+#[derive(Debug)]
+pub enum Error {
+    /// The strategy is invalid, e.g. its range is empty.
+    Strategy(String),
+    /// The property does not hold.
+    Counterexample(Counterexample),
+}
+
+///
+/// The failing input, shrunk as far as possible.
+///
+#[derive(Debug)]
+pub struct Counterexample {
+    pub seed: u64,
+    pub input: JsonValue,
+    pub error: String,
+    pub shrinks: usize,
+}
+
+///
+/// The SplitMix64 generator, which is enough for the input generation and lets the
+/// failing runs be reproduced with the reported seed.
+///
+struct Random {
+    state: u64,
+}
+
+impl Property {
+    /// The number of runs if not specified in the metadata.
+    const RUNS_DEFAULT: usize = 100;
+    /// The maximal number of runs spent on shrinking a failing input.
+    const SHRINK_RUNS_MAX: usize = 1000;
+
+    fn default_runs() -> usize {
+        Self::RUNS_DEFAULT
+    }
+
+    ///
+    /// Runs the property with the random inputs.
+    ///
+    /// The `run` callback takes the input JSON and returns the failure description, if any.
+    /// Fails with the smallest failing input found.
+    ///
+    pub fn check<F>(&self, mut run: F) -> Result<(), Error>
+    where
+        F: FnMut(&JsonValue) -> Result<(), String>,
+    {
+        let seed = self.seed.unwrap_or_else(|| {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|duration| duration.as_nanos() as u64)
+                .unwrap_or_default()
+        });
+        let mut random = Random::new(seed);
+
+        let strategies = self
+            .arguments
+            .iter()
+            .map(|(name, strategy)| Ok((name.to_owned(), strategy.bounds()?)))
+            .collect::<Result<Vec<(String, Bounds)>, String>>()
+            .map_err(Error::Strategy)?;
+
+        for _ in 0..self.runs {
+            let samples: Vec<(String, Sample)> = strategies
+                .iter()
+                .map(|(name, bounds)| (name.to_owned(), bounds.generate(&mut random)))
+                .collect();
+
+            if let Err(error) = run(&Self::input(samples.as_slice())) {
+                return Err(Error::Counterexample(Self::shrink(
+                    seed, samples, error, &mut run,
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Greedily replaces the values with the simpler ones while the run keeps failing.
+    ///
+    fn shrink<F>(
+        seed: u64,
+        mut samples: Vec<(String, Sample)>,
+        mut error: String,
+        run: &mut F,
+    ) -> Counterexample
+    where
+        F: FnMut(&JsonValue) -> Result<(), String>,
+    {
+        let mut runs = 0;
+        let mut shrinks = 0;
+
+        'progress: while runs < Self::SHRINK_RUNS_MAX {
+            for index in 0..samples.len() {
+                for candidate in samples[index].1.simplified().into_iter() {
+                    if runs >= Self::SHRINK_RUNS_MAX {
+                        break 'progress;
+                    }
+                    runs += 1;
+
+                    let original = std::mem::replace(&mut samples[index].1, candidate);
+                    match run(&Self::input(samples.as_slice())) {
+                        Err(candidate_error) => {
+                            error = candidate_error;
+                            shrinks += 1;
+                            continue 'progress;
+                        }
+                        Ok(()) => samples[index].1 = original,
+                    }
+                }
+            }
+            break;
+        }
+
+        Counterexample {
+            seed,
+            input: Self::input(samples.as_slice()),
+            error,
+            shrinks,
+        }
+    }
+
+    fn input(samples: &[(String, Sample)]) -> JsonValue {
+        let mut object = JsonMap::with_capacity(samples.len());
+        for (name, sample) in samples.iter() {
+            object.insert(name.to_owned(), sample.to_json());
+        }
+        JsonValue::Object(object)
+    }
+}
+
+///
+/// The strategy with the bounds parsed.
+///
+enum Bounds {
+    Boolean,
+    Range { min: i128, max: i128 },
+    Array { len: usize, element: Box<Bounds> },
+}
+
+impl Strategy {
+    fn bounds(&self) -> Result<Bounds, String> {
+        Ok(match self {
+            Self::Boolean => Bounds::Boolean,
+            Self::Range { min, max } => {
+                let parse = |value: &String| {
+                    value
+                        .parse::<i128>()
+                        .map_err(|_| format!("invalid range bound `{}`", value))
+                };
+                let (min, max) = (parse(min)?, parse(max)?);
+                if min > max {
+                    return Err(format!("empty range `{}..={}`", min, max));
+                }
+                Bounds::Range { min, max }
+            }
+            Self::Array { len, element } => Bounds::Array {
+                len: *len,
+                element: Box::new(element.bounds()?),
+            },
+        })
+    }
+}
+
+impl Bounds {
+    ///
+    /// Generates a value, preferring the range edges once in a while, since the overflow
+    /// and off-by-one bugs usually hide there.
+    ///
+    fn generate(&self, random: &mut Random) -> Sample {
+        match self {
+            Self::Boolean => Sample::Boolean(random.next() % 2 == 1),
+            Self::Range { min, max } => {
+                let edges = [*min, *max, Sample::origin(*min, *max)];
+                let value = if random.next() % 4 == 0 {
+                    edges[(random.next() % edges.len() as u64) as usize]
+                } else {
+                    let span = (*max as u128).wrapping_sub(*min as u128).wrapping_add(1);
+                    let offset = if span == 0 {
+                        random.next_u128()
+                    } else {
+                        random.next_u128() % span
+                    };
+                    (*min as u128).wrapping_add(offset) as i128
+                };
+                Sample::Integer {
+                    value,
+                    min: *min,
+                    max: *max,
+                }
+            }
+            Self::Array { len, element } => {
+                Sample::Array((0..*len).map(|_| element.generate(random)).collect())
+            }
+        }
+    }
+}
+
+impl Sample {
+    ///
+    /// The simplest value of the range, i.e. the closest to zero.
+    ///
+    fn origin(min: i128, max: i128) -> i128 {
+        if min > 0 {
+            min
+        } else if max < 0 {
+            max
+        } else {
+            0
+        }
+    }
+
+    ///
+    /// Returns the simpler variants of the value, the simplest first.
+    ///
+    fn simplified(&self) -> Vec<Self> {
+        match self {
+            Self::Boolean(true) => vec![Self::Boolean(false)],
+            Self::Boolean(false) => vec![],
+            Self::Integer { value, min, max } => {
+                let origin = Self::origin(*min, *max);
+                let mut values = vec![origin];
+                let halfway = origin + (*value - origin) / 2;
+                if halfway != origin {
+                    values.push(halfway);
+                }
+                let step = if *value > origin {
+                    *value - 1
+                } else {
+                    *value + 1
+                };
+                if *value != origin && step != origin && step != halfway {
+                    values.push(step);
+                }
+                values
+                    .into_iter()
+                    .filter(|candidate| candidate != value)
+                    .map(|candidate| Self::Integer {
+                        value: candidate,
+                        min: *min,
+                        max: *max,
+                    })
+                    .collect()
+            }
+            Self::Array(elements) => {
+                let mut variants = Vec::new();
+                for (index, element) in elements.iter().enumerate() {
+                    for candidate in element.simplified().into_iter() {
+                        let mut elements = elements.clone();
+                        elements[index] = candidate;
+                        variants.push(Self::Array(elements));
+                    }
+                }
+                variants
+            }
+        }
+    }
+
+    fn to_json(&self) -> JsonValue {
+        match self {
+            Self::Boolean(value) => JsonValue::Bool(*value),
+            Self::Integer { value, .. } => JsonValue::String(value.to_string()),
+            Self::Array(elements) => JsonValue::Array(elements.iter().map(Self::to_json).collect()),
+        }
+    }
+}
+
+impl Random {
+    fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    fn next(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut value = self.state;
+        value = (value ^ (value >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        value = (value ^ (value >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        value ^ (value >> 31)
+    }
+
+    fn next_u128(&mut self) -> u128 {
+        (u128::from(self.next()) << 64) | u128::from(self.next())
+    }
+}
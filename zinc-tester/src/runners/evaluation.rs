@@ -5,19 +5,31 @@
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::Mutex;
+use std::time::Duration;
 
 use colored::Colorize;
 
 use pairing::bn256::Bn256;
 
+use zinc_bytecode::data::values::Value;
+
+use crate::coverage::CoverageReport;
+use crate::data::TestCase;
 use crate::data::TestData;
 use crate::file::TestFile;
 use crate::program::ProgramData;
+use crate::property::Error as PropertyError;
+use crate::property::Property;
+use crate::runners;
+use crate::runners::MismatchReporter;
 use crate::runners::TestRunner;
 use crate::Summary;
 
 pub struct EvaluationTestRunner {
     pub verbosity: usize,
+    pub mismatch: MismatchReporter,
+    pub timeout: Option<Duration>,
+    pub coverage: Option<Arc<Mutex<CoverageReport>>>,
 }
 
 impl TestRunner for EvaluationTestRunner {
@@ -28,6 +40,7 @@ impl TestRunner for EvaluationTestRunner {
         test_data: &TestData,
         summary: Arc<Mutex<Summary>>,
     ) {
+        let source_file = test_file_path.to_string_lossy().to_string();
         let test_file_path = match test_file_path.strip_prefix(crate::TESTS_DIRECTORY) {
             Ok(path) => path,
             Err(_error) => test_file_path,
@@ -36,10 +49,25 @@ impl TestRunner for EvaluationTestRunner {
         for test_case in test_data.cases.iter() {
             let case_name = format!("{}::{}", test_file_path.to_string_lossy(), test_case.case);
 
+            if let Some(property) = test_case.property.as_ref() {
+                self.run_property(
+                    case_name.as_str(),
+                    test_file.code.as_str(),
+                    test_case,
+                    property,
+                    test_data.ignore || test_case.ignore,
+                    &summary,
+                );
+                continue;
+            }
+
             let program_data = match ProgramData::new(&test_case.input, test_file.code.as_str()) {
                 Ok(program_data) => program_data,
                 Err(error) => {
-                    summary.lock().expect(crate::PANIC_MUTEX_SYNC).invalid += 1;
+                    summary
+                        .lock()
+                        .expect(crate::PANIC_MUTEX_SYNC)
+                        .invalidate(case_name.clone());
                     println!(
                         "[INTEGRATION] {} {} ({})",
                         "INVALID".red(),
@@ -56,7 +84,40 @@ impl TestRunner for EvaluationTestRunner {
                 continue;
             }
 
-            match zinc_vm::run::<Bn256>(&program_data.program, &program_data.input) {
+            let ProgramData {
+                program,
+                input,
+                source_map,
+            } = program_data;
+            let source_map = self.coverage.as_ref().map(|_| source_map);
+            let is_coverage = self.coverage.is_some();
+            let (result, coverage) = match runners::run_with_timeout(self.timeout, move || {
+                runners::run_program(&program, &input, is_coverage)
+            }) {
+                Ok(result) => result,
+                Err(timeout) => {
+                    summary
+                        .lock()
+                        .expect(crate::PANIC_MUTEX_SYNC)
+                        .time_out(case_name.clone());
+                    println!(
+                        "[INTEGRATION] {} {} (timed out after {:?})",
+                        "FAILED".bright_red(),
+                        case_name,
+                        timeout
+                    );
+                    continue;
+                }
+            };
+
+            runners::record_coverage(
+                self.coverage.as_deref(),
+                source_file.as_str(),
+                source_map.as_ref(),
+                coverage.as_ref(),
+            );
+
+            match result {
                 Ok(output) => {
                     let output = output.to_json();
                     if test_case.expect == output {
@@ -66,7 +127,10 @@ impl TestRunner for EvaluationTestRunner {
                                 println!("[INTEGRATION] {} {}", "PASSED".green(), case_name);
                             }
                         } else {
-                            summary.lock().expect(crate::PANIC_MUTEX_SYNC).failed += 1;
+                            summary
+                                .lock()
+                                .expect(crate::PANIC_MUTEX_SYNC)
+                                .fail(case_name.clone());
                             println!(
                                 "[INTEGRATION] {} {} (should have panicked)",
                                 "FAILED".bright_red(),
@@ -74,18 +138,20 @@ impl TestRunner for EvaluationTestRunner {
                             );
                         }
                     } else {
-                        summary.lock().expect(crate::PANIC_MUTEX_SYNC).failed += 1;
+                        summary
+                            .lock()
+                            .expect(crate::PANIC_MUTEX_SYNC)
+                            .fail(case_name.clone());
                         println!(
-                            "[INTEGRATION] {} {} (expected {}, but got {})",
+                            "[INTEGRATION] {} {} {}",
                             "FAILED".bright_red(),
                             case_name,
-                            test_case.expect,
-                            output
+                            self.mismatch.report(&case_name, &test_case.expect, &output)
                         );
                     }
                 }
                 Err(error) => {
-                    if test_case.should_panic {
+                    if test_case.should_panic && test_case.is_error_expected(&error) {
                         summary.lock().expect(crate::PANIC_MUTEX_SYNC).passed += 1;
                         if self.verbosity > 0 {
                             println!(
@@ -95,7 +161,10 @@ impl TestRunner for EvaluationTestRunner {
                             );
                         }
                     } else {
-                        summary.lock().expect(crate::PANIC_MUTEX_SYNC).failed += 1;
+                        summary
+                            .lock()
+                            .expect(crate::PANIC_MUTEX_SYNC)
+                            .fail(case_name.clone());
                         println!(
                             "[INTEGRATION] {} {} ({})",
                             "FAILED".bright_red(),
@@ -108,3 +177,121 @@ impl TestRunner for EvaluationTestRunner {
         }
     }
 }
+
+impl EvaluationTestRunner {
+    ///
+    /// Runs the property-based case with the random inputs, reporting the shrunk
+    /// counterexample if the property does not hold.
+    ///
+    fn run_property(
+        &self,
+        case_name: &str,
+        code: &str,
+        test_case: &TestCase,
+        property: &Property,
+        is_ignored: bool,
+        summary: &Mutex<Summary>,
+    ) {
+        let program = match ProgramData::compile(code) {
+            Ok(program) => program,
+            Err(error) => {
+                summary
+                    .lock()
+                    .expect(crate::PANIC_MUTEX_SYNC)
+                    .invalidate(case_name.to_owned());
+                println!(
+                    "[INTEGRATION] {} {} ({})",
+                    "INVALID".red(),
+                    case_name,
+                    error
+                );
+                return;
+            }
+        };
+
+        if is_ignored {
+            summary.lock().expect(crate::PANIC_MUTEX_SYNC).ignored += 1;
+            println!("[INTEGRATION] {} {}", "IGNORE".yellow(), case_name);
+            return;
+        }
+
+        let checked = property.to_owned();
+        let expect = test_case.expect.to_owned();
+        let result = match runners::run_with_timeout(self.timeout, move || {
+            checked.check(|input| {
+                let input = Value::from_typed_json(input, &program.input)
+                    .map_err(|error| format!("input: {}", error))?;
+                match zinc_vm::run_checked::<Bn256>(&program, &input) {
+                    Ok(output) => {
+                        let output = output.to_json();
+                        if expect.is_null() || expect == output {
+                            Ok(())
+                        } else {
+                            Err(format!(
+                                "output mismatch: expected {}, found {}",
+                                expect, output
+                            ))
+                        }
+                    }
+                    Err(error) => Err(error.to_string()),
+                }
+            })
+        }) {
+            Ok(result) => result,
+            Err(timeout) => {
+                summary
+                    .lock()
+                    .expect(crate::PANIC_MUTEX_SYNC)
+                    .time_out(case_name.to_owned());
+                println!(
+                    "[INTEGRATION] {} {} (timed out after {:?})",
+                    "FAILED".bright_red(),
+                    case_name,
+                    timeout
+                );
+                return;
+            }
+        };
+
+        match result {
+            Ok(()) => {
+                summary.lock().expect(crate::PANIC_MUTEX_SYNC).passed += 1;
+                if self.verbosity > 0 {
+                    println!(
+                        "[INTEGRATION] {} {} ({} runs)",
+                        "PASSED".green(),
+                        case_name,
+                        property.runs
+                    );
+                }
+            }
+            Err(PropertyError::Strategy(error)) => {
+                summary
+                    .lock()
+                    .expect(crate::PANIC_MUTEX_SYNC)
+                    .invalidate(case_name.to_owned());
+                println!(
+                    "[INTEGRATION] {} {} (strategy: {})",
+                    "INVALID".red(),
+                    case_name,
+                    error
+                );
+            }
+            Err(PropertyError::Counterexample(counterexample)) => {
+                summary
+                    .lock()
+                    .expect(crate::PANIC_MUTEX_SYNC)
+                    .fail(case_name.to_owned());
+                println!(
+                    "[INTEGRATION] {} {} (seed {}, input {} after {} shrinks: {})",
+                    "FAILED".bright_red(),
+                    case_name,
+                    counterexample.seed,
+                    counterexample.input,
+                    counterexample.shrinks,
+                    counterexample.error
+                );
+            }
+        }
+    }
+}
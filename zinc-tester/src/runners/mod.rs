@@ -3,17 +3,40 @@
 //!
 
 mod evaluation;
+mod project;
 mod proof_check;
+mod snapshot;
+mod unit;
 
 pub use self::evaluation::EvaluationTestRunner;
+pub use self::project::ProjectRunner;
 pub use self::proof_check::ProofCheckRunner;
+pub use self::snapshot::SnapshotRunner;
+pub use self::unit::UnitTestRunner;
 pub use crate::Summary;
 
 use std::path::PathBuf;
+use std::sync::mpsc;
+use std::sync::mpsc::RecvTimeoutError;
 use std::sync::Arc;
 use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+use pairing::bn256::Bn256;
+use serde_json::Value as JsonValue;
+
+use zinc_bytecode::data::values::Value;
+use zinc_bytecode::Program;
+use zinc_bytecode::SourceMap;
+use zinc_vm::Coverage;
+use zinc_vm::RuntimeError;
+
+use crate::coverage::CoverageReport;
 
 use crate::data::TestData;
+use crate::diff;
+use crate::diff::Difference;
 use crate::file::TestFile;
 
 pub trait TestRunner: Sync + Send {
@@ -25,3 +48,112 @@ pub trait TestRunner: Sync + Send {
         summary: Arc<Mutex<Summary>>,
     );
 }
+
+///
+/// Describes the output mismatches of failed cases.
+///
+pub struct MismatchReporter {
+    pub limit: usize,
+    pub dump_directory: Option<PathBuf>,
+}
+
+impl MismatchReporter {
+    pub fn new(limit: usize, dump_directory: Option<PathBuf>) -> Self {
+        Self {
+            limit,
+            dump_directory,
+        }
+    }
+
+    ///
+    /// Returns the message listing the differing output paths, and the location
+    /// of the full outputs if they are dumped.
+    ///
+    pub fn report(&self, case_name: &str, expected: &JsonValue, actual: &JsonValue) -> String {
+        let differences = Difference::all(expected, actual);
+        let mut message = format!(
+            "(output mismatch at {} path(s)){}",
+            differences.len(),
+            diff::format(differences.as_slice(), self.limit)
+        );
+
+        if let Some(directory) = self.dump_directory.as_ref() {
+            match diff::dump(directory, case_name, expected, actual) {
+                Ok(prefix) => message.push_str(&format!(
+                    "\n    full outputs written to {}.{{expected,actual}}.json",
+                    prefix.to_string_lossy()
+                )),
+                Err(error) => {
+                    message.push_str(&format!("\n    full outputs writing failed: {}", error))
+                }
+            }
+        }
+
+        message
+    }
+}
+
+///
+/// Runs the case on a separate thread, giving up waiting for it after the `timeout`.
+///
+/// The threads cannot be killed, so the timed out case is left running detached until
+/// the tester process exits, but it does not hold the worker thread. Without the timeout,
+/// the case is run on the current thread.
+///
+pub fn run_with_timeout<T, F>(timeout: Option<Duration>, run: F) -> Result<T, Duration>
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    let timeout = match timeout {
+        Some(timeout) => timeout,
+        None => return Ok(run()),
+    };
+
+    let (sender, receiver) = mpsc::channel();
+    let handle = thread::spawn(move || {
+        let _ = sender.send(run());
+    });
+
+    match receiver.recv_timeout(timeout) {
+        Ok(result) => Ok(result),
+        Err(RecvTimeoutError::Timeout) => Err(timeout),
+        Err(RecvTimeoutError::Disconnected) => match handle.join() {
+            Err(payload) => std::panic::resume_unwind(payload),
+            Ok(()) => unreachable!("The result is always sent before the thread exits"),
+        },
+    }
+}
+
+///
+/// Runs the program in the VM, also counting the instruction executions if `is_coverage` is set.
+///
+pub fn run_program(
+    program: &Program,
+    input: &Value,
+    is_coverage: bool,
+) -> (Result<Value, RuntimeError>, Option<Coverage>) {
+    if is_coverage {
+        let (result, coverage) = zinc_vm::run_checked_with_coverage::<Bn256>(program, input);
+        (result, Some(coverage))
+    } else {
+        (zinc_vm::run_checked::<Bn256>(program, input), None)
+    }
+}
+
+///
+/// Adds the run instruction executions to the coverage report, if it is collected.
+///
+pub fn record_coverage(
+    report: Option<&Mutex<CoverageReport>>,
+    source_file: &str,
+    source_map: Option<&SourceMap>,
+    coverage: Option<&Coverage>,
+) {
+    if let (Some(report), Some(source_map), Some(coverage)) = (report, source_map, coverage) {
+        report
+            .lock()
+            .expect(crate::PANIC_MUTEX_SYNC)
+            .add(source_file, source_map, coverage);
+    }
+}
@@ -0,0 +1,146 @@
+//!
+//! The multi-file project runner.
+//!
+
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use colored::Colorize;
+
+use zinc_bytecode::data::values::Value;
+
+use crate::coverage::CoverageReport;
+use crate::program::ProgramData;
+use crate::project::TestProject;
+use crate::runners;
+use crate::runners::MismatchReporter;
+use crate::Summary;
+
+///
+/// Compiles the project once and runs its cases in the VM.
+///
+pub struct ProjectRunner {
+    pub verbosity: usize,
+    pub mismatch: MismatchReporter,
+    pub timeout: Option<Duration>,
+    pub coverage: Option<Arc<Mutex<CoverageReport>>>,
+}
+
+impl ProjectRunner {
+    pub fn run(&self, project_path: &PathBuf, project: &TestProject, summary: &Mutex<Summary>) {
+        let project_name = match project_path.strip_prefix(crate::PROJECTS_DIRECTORY) {
+            Ok(path) => path,
+            Err(_error) => project_path,
+        }
+        .to_string_lossy()
+        .to_string();
+
+        let (program, source_map) = match ProgramData::compile_project(&project.source_path) {
+            Ok(artifact) => {
+                let source_map = artifact.source_map();
+                (Arc::new(artifact.program), source_map)
+            }
+            Err(error) => {
+                summary
+                    .lock()
+                    .expect(crate::PANIC_MUTEX_SYNC)
+                    .invalidate(project_name.clone());
+                println!(
+                    "[INTEGRATION] {} {} ({})",
+                    "INVALID".red(),
+                    project_name,
+                    error
+                );
+                return;
+            }
+        };
+
+        for project_case in project.cases.iter() {
+            let case_name = format!("{}::{}", project_name, project_case.case);
+
+            let input = match Value::from_typed_json(&project_case.input, &program.input) {
+                Ok(input) => input,
+                Err(error) => {
+                    summary
+                        .lock()
+                        .expect(crate::PANIC_MUTEX_SYNC)
+                        .invalidate(case_name.clone());
+                    println!(
+                        "[INTEGRATION] {} {} (JSON type value: {})",
+                        "INVALID".red(),
+                        case_name,
+                        error
+                    );
+                    continue;
+                }
+            };
+
+            let case_program = program.clone();
+            let source_map = self.coverage.as_ref().map(|_| &source_map);
+            let is_coverage = self.coverage.is_some();
+            let (result, coverage) = match runners::run_with_timeout(self.timeout, move || {
+                runners::run_program(&case_program, &input, is_coverage)
+            }) {
+                Ok(result) => result,
+                Err(timeout) => {
+                    summary
+                        .lock()
+                        .expect(crate::PANIC_MUTEX_SYNC)
+                        .time_out(case_name.clone());
+                    println!(
+                        "[INTEGRATION] {} {} (timed out after {:?})",
+                        "FAILED".bright_red(),
+                        case_name,
+                        timeout
+                    );
+                    continue;
+                }
+            };
+
+            runners::record_coverage(
+                self.coverage.as_deref(),
+                project_name.as_str(),
+                source_map,
+                coverage.as_ref(),
+            );
+
+            match result {
+                Ok(output) => {
+                    let output = output.to_json();
+                    if project_case.expect == output {
+                        summary.lock().expect(crate::PANIC_MUTEX_SYNC).passed += 1;
+                        if self.verbosity > 0 {
+                            println!("[INTEGRATION] {} {}", "PASSED".green(), case_name);
+                        }
+                    } else {
+                        summary
+                            .lock()
+                            .expect(crate::PANIC_MUTEX_SYNC)
+                            .fail(case_name.clone());
+                        println!(
+                            "[INTEGRATION] {} {} {}",
+                            "FAILED".bright_red(),
+                            case_name,
+                            self.mismatch
+                                .report(&case_name, &project_case.expect, &output)
+                        );
+                    }
+                }
+                Err(error) => {
+                    summary
+                        .lock()
+                        .expect(crate::PANIC_MUTEX_SYNC)
+                        .fail(case_name.clone());
+                    println!(
+                        "[INTEGRATION] {} {} ({})",
+                        "FAILED".bright_red(),
+                        case_name,
+                        error
+                    );
+                }
+            }
+        }
+    }
+}
@@ -5,18 +5,29 @@
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::Mutex;
+use std::time::Duration;
 
 use colored::Colorize;
 use pairing::bn256::Bn256;
 
+use zinc_bytecode::data::values::Value;
+use zinc_bytecode::proof::Curve;
+use zinc_bytecode::proof::Proof as ProofEnvelope;
+use zinc_bytecode::proof::ProvingSystem;
+use zinc_vm::Proof;
+
 use crate::data::TestData;
 use crate::file::TestFile;
 use crate::program::ProgramData;
+use crate::runners;
+use crate::runners::MismatchReporter;
 use crate::runners::TestRunner;
 use crate::Summary;
 
 pub struct ProofCheckRunner {
     pub verbosity: usize,
+    pub mismatch: MismatchReporter,
+    pub timeout: Option<Duration>,
 }
 
 impl TestRunner for ProofCheckRunner {
@@ -27,10 +38,18 @@ impl TestRunner for ProofCheckRunner {
         test_data: &TestData,
         summary: Arc<Mutex<Summary>>,
     ) {
+        let test_file_path = match test_file_path.strip_prefix(crate::TESTS_DIRECTORY) {
+            Ok(path) => path,
+            Err(_error) => test_file_path,
+        };
+
         let program = match ProgramData::compile(test_file.code.as_str()) {
             Ok(program) => program,
             Err(error) => {
-                summary.lock().expect(crate::PANIC_MUTEX_SYNC).invalid += 1;
+                summary
+                    .lock()
+                    .expect(crate::PANIC_MUTEX_SYNC)
+                    .invalidate(test_file_path.to_string_lossy().to_string());
                 println!(
                     "[INTEGRATION] {} {} (compiler: {})",
                     "INVALID".red(),
@@ -42,9 +61,12 @@ impl TestRunner for ProofCheckRunner {
         };
 
         let params = match zinc_vm::setup::<Bn256>(&program) {
-            Ok(params) => params,
+            Ok(params) => Arc::new(params),
             Err(error) => {
-                summary.lock().expect(crate::PANIC_MUTEX_SYNC).invalid += 1;
+                summary
+                    .lock()
+                    .expect(crate::PANIC_MUTEX_SYNC)
+                    .invalidate(test_file_path.to_string_lossy().to_string());
                 println!(
                     "[INTEGRATION] {} {} (setup: {})",
                     "FAILED".red(),
@@ -55,18 +77,26 @@ impl TestRunner for ProofCheckRunner {
             }
         };
 
-        let test_file_path = match test_file_path.strip_prefix(crate::TESTS_DIRECTORY) {
-            Ok(path) => path,
-            Err(_error) => test_file_path,
-        };
-
         for test_case in test_data.cases.iter() {
             let case_name = format!("{}::{}", test_file_path.to_string_lossy(), test_case.case);
 
+            if test_case.property.is_some() {
+                summary.lock().expect(crate::PANIC_MUTEX_SYNC).ignored += 1;
+                println!(
+                    "[INTEGRATION] {} {} (property cases are evaluated only)",
+                    "IGNORE".yellow(),
+                    case_name
+                );
+                continue;
+            }
+
             let program_data = match ProgramData::new(&test_case.input, test_file.code.as_str()) {
                 Ok(program_data) => program_data,
                 Err(error) => {
-                    summary.lock().expect(crate::PANIC_MUTEX_SYNC).invalid += 1;
+                    summary
+                        .lock()
+                        .expect(crate::PANIC_MUTEX_SYNC)
+                        .invalidate(case_name.clone());
                     println!(
                         "[INTEGRATION] {} {} (input data: {})",
                         "INVALID".red(),
@@ -83,27 +113,52 @@ impl TestRunner for ProofCheckRunner {
                 continue;
             }
 
-            let (output, proof) = match zinc_vm::prove::<Bn256>(
-                &program_data.program,
-                &params,
-                &program_data.input,
-            ) {
-                Ok((output, proof)) => {
+            let ProgramData { program, input, .. } = program_data;
+            let case_params = params.clone();
+            let result = match runners::run_with_timeout(self.timeout, move || {
+                zinc_vm::prove::<Bn256>(&program, &case_params, &input).map(
+                    |(output, exposed, proof)| {
+                        let public_data = program.public_data(&input, exposed, output.clone());
+                        (output, public_data, proof)
+                    },
+                )
+            }) {
+                Ok(result) => result,
+                Err(timeout) => {
+                    summary
+                        .lock()
+                        .expect(crate::PANIC_MUTEX_SYNC)
+                        .time_out(case_name.clone());
+                    println!(
+                        "[INTEGRATION] {} {} (timed out after {:?})",
+                        "FAILED".bright_red(),
+                        case_name,
+                        timeout
+                    );
+                    continue;
+                }
+            };
+
+            let (public_data, proof) = match result {
+                Ok((output, public_data, proof)) => {
                     let output_json = output.to_json();
                     if test_case.expect != output_json {
-                        summary.lock().expect(crate::PANIC_MUTEX_SYNC).failed += 1;
+                        summary
+                            .lock()
+                            .expect(crate::PANIC_MUTEX_SYNC)
+                            .fail(case_name.clone());
                         println!(
-                            "[INTEGRATION] {} {} (expected {}, but got {})",
+                            "[INTEGRATION] {} {} {}",
                             "FAILED".bright_red(),
                             case_name,
-                            test_case.expect,
-                            output_json
+                            self.mismatch
+                                .report(&case_name, &test_case.expect, &output_json)
                         );
                     }
-                    (output, proof)
+                    (public_data, proof)
                 }
                 Err(error) => {
-                    if test_case.should_panic {
+                    if test_case.should_panic && test_case.is_error_expected(&error) {
                         summary.lock().expect(crate::PANIC_MUTEX_SYNC).passed += 1;
                         if self.verbosity > 0 {
                             println!(
@@ -113,7 +168,10 @@ impl TestRunner for ProofCheckRunner {
                             );
                         }
                     } else {
-                        summary.lock().expect(crate::PANIC_MUTEX_SYNC).failed += 1;
+                        summary
+                            .lock()
+                            .expect(crate::PANIC_MUTEX_SYNC)
+                            .fail(case_name.clone());
                         println!(
                             "[INTEGRATION] {} {} ({})",
                             "FAILED".bright_red(),
@@ -125,11 +183,31 @@ impl TestRunner for ProofCheckRunner {
                 }
             };
 
-            match zinc_vm::verify(&params.vk, &proof, &output) {
+            let proof = match Self::envelope_roundtrip(&proof, &public_data) {
+                Ok(proof) => proof,
+                Err(error) => {
+                    summary
+                        .lock()
+                        .expect(crate::PANIC_MUTEX_SYNC)
+                        .fail(case_name.clone());
+                    println!(
+                        "[INTEGRATION] {} {} (proof envelope: {})",
+                        "FAILED".bright_red(),
+                        case_name,
+                        error
+                    );
+                    continue;
+                }
+            };
+
+            match zinc_vm::verify(&params.vk, &proof, &public_data) {
                 Ok(success) => {
                     if success {
                     } else {
-                        summary.lock().expect(crate::PANIC_MUTEX_SYNC).failed += 1;
+                        summary
+                            .lock()
+                            .expect(crate::PANIC_MUTEX_SYNC)
+                            .fail(case_name.clone());
                         println!(
                             "[INTEGRATION] {} {} (verification failed)",
                             "FAILED".bright_red(),
@@ -138,7 +216,10 @@ impl TestRunner for ProofCheckRunner {
                     }
                 }
                 Err(error) => {
-                    summary.lock().expect(crate::PANIC_MUTEX_SYNC).failed += 1;
+                    summary
+                        .lock()
+                        .expect(crate::PANIC_MUTEX_SYNC)
+                        .fail(case_name.clone());
                     println!(
                         "[INTEGRATION] {} {} (verify: {})",
                         "FAILED".bright_red(),
@@ -150,3 +231,29 @@ impl TestRunner for ProofCheckRunner {
         }
     }
 }
+
+impl ProofCheckRunner {
+    ///
+    /// Passes the proof through the envelope hex encoding, which is used to
+    /// exchange proofs between the prover and the verifier.
+    ///
+    fn envelope_roundtrip(
+        proof: &Proof<Bn256>,
+        public_data: &Value,
+    ) -> Result<Proof<Bn256>, String> {
+        let mut bytes = Vec::new();
+        proof.write(&mut bytes).map_err(|error| error.to_string())?;
+
+        let public_inputs = public_data
+            .to_flat_values()
+            .into_iter()
+            .map(|value| value.to_string())
+            .collect();
+        let envelope =
+            ProofEnvelope::new(Curve::Bn256, ProvingSystem::Groth16, public_inputs, bytes);
+
+        let envelope = ProofEnvelope::from_hex(envelope.to_hex().as_str())
+            .map_err(|error| error.to_string())?;
+        Proof::read(envelope.data.as_slice()).map_err(|error| error.to_string())
+    }
+}
@@ -0,0 +1,132 @@
+//!
+//! The compiler diagnostic snapshot runner.
+//!
+
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use colored::Colorize;
+
+use crate::program::Error as ProgramError;
+use crate::program::ProgramData;
+use crate::snapshot;
+use crate::snapshot::Snapshot;
+use crate::Summary;
+
+///
+/// Compiles the broken source files and compares their diagnostics with the snapshots.
+///
+/// If `update` is set, the missing and mismatching snapshots are written instead of
+/// failing, which is how the snapshots are created and accepted after a message change.
+///
+pub struct SnapshotRunner {
+    pub verbosity: usize,
+    pub update: bool,
+}
+
+impl SnapshotRunner {
+    pub fn run(&self, source_path: &PathBuf, summary: &Mutex<Summary>) {
+        let file_name = match source_path.strip_prefix(crate::SNAPSHOTS_DIRECTORY) {
+            Ok(path) => path,
+            Err(_error) => source_path,
+        }
+        .to_string_lossy()
+        .to_string();
+
+        let snapshot = match Snapshot::read(source_path) {
+            Ok(snapshot) => snapshot,
+            Err(error) => {
+                summary
+                    .lock()
+                    .expect(crate::PANIC_MUTEX_SYNC)
+                    .invalidate(file_name.clone());
+                println!(
+                    "[INTEGRATION] {} {} ({})",
+                    "INVALID".red(),
+                    file_name,
+                    error
+                );
+                return;
+            }
+        };
+
+        let actual = match ProgramData::compile(snapshot.code.as_str()) {
+            Err(ProgramError::Compiler(diagnostic)) => snapshot::strip_colors(diagnostic.as_str()),
+            Err(error) => {
+                summary
+                    .lock()
+                    .expect(crate::PANIC_MUTEX_SYNC)
+                    .invalidate(file_name.clone());
+                println!(
+                    "[INTEGRATION] {} {} ({})",
+                    "INVALID".red(),
+                    file_name,
+                    error
+                );
+                return;
+            }
+            Ok(_program) => {
+                summary
+                    .lock()
+                    .expect(crate::PANIC_MUTEX_SYNC)
+                    .fail(file_name.clone());
+                println!(
+                    "[INTEGRATION] {} {} (compiled without errors)",
+                    "FAILED".bright_red(),
+                    file_name
+                );
+                return;
+            }
+        };
+
+        match snapshot.expected.as_ref() {
+            Some(expected) if *expected == actual => {
+                summary.lock().expect(crate::PANIC_MUTEX_SYNC).passed += 1;
+                if self.verbosity > 0 {
+                    println!("[INTEGRATION] {} {}", "PASSED".green(), file_name);
+                }
+            }
+            _ if self.update => match snapshot.update(actual.as_str()) {
+                Ok(()) => {
+                    summary.lock().expect(crate::PANIC_MUTEX_SYNC).passed += 1;
+                    println!("[INTEGRATION] {} {}", "UPDATED".cyan(), file_name);
+                }
+                Err(error) => {
+                    summary
+                        .lock()
+                        .expect(crate::PANIC_MUTEX_SYNC)
+                        .invalidate(file_name.clone());
+                    println!(
+                        "[INTEGRATION] {} {} ({})",
+                        "INVALID".red(),
+                        file_name,
+                        error
+                    );
+                }
+            },
+            Some(expected) => {
+                summary
+                    .lock()
+                    .expect(crate::PANIC_MUTEX_SYNC)
+                    .fail(file_name.clone());
+                println!(
+                    "[INTEGRATION] {} {} (diagnostic mismatch){}",
+                    "FAILED".bright_red(),
+                    file_name,
+                    snapshot::format_mismatch(expected.as_str(), actual.as_str())
+                );
+            }
+            None => {
+                summary
+                    .lock()
+                    .expect(crate::PANIC_MUTEX_SYNC)
+                    .fail(file_name.clone());
+                println!(
+                    "[INTEGRATION] {} {} (snapshot not found, run with `--update-snapshots`)",
+                    "FAILED".bright_red(),
+                    file_name
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,130 @@
+//!
+//! The `#[test]` function runner.
+//!
+
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use colored::Colorize;
+
+use zinc_bytecode::data::values::Value;
+use zinc_bytecode::SourceMap;
+
+use crate::coverage::CoverageReport;
+use crate::data::TestData;
+use crate::file::TestFile;
+use crate::program::ProgramData;
+use crate::runners;
+use crate::runners::TestRunner;
+use crate::Summary;
+
+///
+/// Runs every `#[test]` function of the test files in the VM.
+///
+/// A test passes if its function returns without errors, e.g. all its assertions hold.
+/// The file cases are not run, but the file is still skipped if it is ignored.
+///
+pub struct UnitTestRunner {
+    pub verbosity: usize,
+    pub timeout: Option<Duration>,
+    pub coverage: Option<Arc<Mutex<CoverageReport>>>,
+}
+
+impl TestRunner for UnitTestRunner {
+    fn run(
+        &self,
+        test_file_path: &PathBuf,
+        test_file: &TestFile,
+        test_data: &TestData,
+        summary: Arc<Mutex<Summary>>,
+    ) {
+        let source_file = test_file_path.to_string_lossy().to_string();
+        let test_file_path = match test_file_path.strip_prefix(crate::TESTS_DIRECTORY) {
+            Ok(path) => path,
+            Err(_error) => test_file_path,
+        };
+        let file_name = test_file_path.to_string_lossy().to_string();
+
+        let programs = match ProgramData::compile_tests(test_file.code.as_str()) {
+            Ok(programs) => programs,
+            Err(error) => {
+                summary
+                    .lock()
+                    .expect(crate::PANIC_MUTEX_SYNC)
+                    .invalidate(file_name.clone());
+                println!(
+                    "[INTEGRATION] {} {} ({})",
+                    "INVALID".red(),
+                    file_name,
+                    error
+                );
+                return;
+            }
+        };
+
+        for (test_name, program) in programs.into_iter() {
+            let case_name = format!("{}::{}", file_name, test_name);
+
+            if test_data.ignore {
+                summary.lock().expect(crate::PANIC_MUTEX_SYNC).ignored += 1;
+                println!("[INTEGRATION] {} {}", "IGNORE".yellow(), case_name);
+                continue;
+            }
+
+            let input = Value::default_from_type(&program.input);
+            let source_map = self
+                .coverage
+                .as_ref()
+                .map(|_| SourceMap::new(program.bytecode.as_slice()));
+            let is_coverage = self.coverage.is_some();
+            let (result, coverage) = match runners::run_with_timeout(self.timeout, move || {
+                runners::run_program(&program, &input, is_coverage)
+            }) {
+                Ok(result) => result,
+                Err(timeout) => {
+                    summary
+                        .lock()
+                        .expect(crate::PANIC_MUTEX_SYNC)
+                        .time_out(case_name.clone());
+                    println!(
+                        "[INTEGRATION] {} {} (timed out after {:?})",
+                        "FAILED".bright_red(),
+                        case_name,
+                        timeout
+                    );
+                    continue;
+                }
+            };
+
+            runners::record_coverage(
+                self.coverage.as_deref(),
+                source_file.as_str(),
+                source_map.as_ref(),
+                coverage.as_ref(),
+            );
+
+            match result {
+                Ok(_output) => {
+                    summary.lock().expect(crate::PANIC_MUTEX_SYNC).passed += 1;
+                    if self.verbosity > 0 {
+                        println!("[INTEGRATION] {} {}", "PASSED".green(), case_name);
+                    }
+                }
+                Err(error) => {
+                    summary
+                        .lock()
+                        .expect(crate::PANIC_MUTEX_SYNC)
+                        .fail(case_name.clone());
+                    println!(
+                        "[INTEGRATION] {} {} ({})",
+                        "FAILED".bright_red(),
+                        case_name,
+                        error
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,152 @@
+//!
+//! The Zinc tester compiler diagnostic snapshot.
+//!
+
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use failure::Fail;
+
+///
+/// The intentionally broken source file and its expected diagnostic.
+///
+/// The snapshot is stored next to the source file with the `snap` extension and
+/// contains the rendered compiler error without the terminal colors.
+///
+#[derive(Debug, PartialEq)]
+pub struct Snapshot {
+    pub code: String,
+    pub snapshot_path: PathBuf,
+    pub expected: Option<String>,
+}
+
+#[derive(Debug, Fail)]
+pub enum Error {
+    #[fail(display = "directory {:?} reading: {}", _0, _1)]
+    DirectoryReading(PathBuf, io::Error),
+    #[fail(display = "file {:?} reading: {}", _0, _1)]
+    Reading(PathBuf, io::Error),
+    #[fail(display = "file {:?} writing: {}", _0, _1)]
+    Writing(PathBuf, io::Error),
+}
+
+static SOURCE_FILE_EXTENSION: &str = "zn";
+static SNAPSHOT_FILE_EXTENSION: &str = "snap";
+
+impl Snapshot {
+    ///
+    /// Finds the source files in the directory and its subdirectories.
+    ///
+    pub fn discover(path: &PathBuf) -> Result<Vec<PathBuf>, Error> {
+        let mut file_paths = Vec::new();
+        for entry in
+            fs::read_dir(path).map_err(|error| Error::DirectoryReading(path.to_owned(), error))?
+        {
+            let entry_path = entry
+                .map_err(|error| Error::DirectoryReading(path.to_owned(), error))?
+                .path();
+            if entry_path.is_dir() {
+                file_paths.extend(Self::discover(&entry_path)?);
+            } else if entry_path
+                .extension()
+                .map(|extension| extension == SOURCE_FILE_EXTENSION)
+                .unwrap_or_default()
+            {
+                file_paths.push(entry_path);
+            }
+        }
+        file_paths.sort();
+
+        Ok(file_paths)
+    }
+
+    ///
+    /// Reads the source file and its snapshot, which may not exist yet.
+    ///
+    pub fn read(source_path: &PathBuf) -> Result<Self, Error> {
+        let code = fs::read_to_string(source_path)
+            .map_err(|error| Error::Reading(source_path.to_owned(), error))?;
+
+        let snapshot_path = source_path.with_extension(SNAPSHOT_FILE_EXTENSION);
+        let expected = if snapshot_path.exists() {
+            Some(
+                fs::read_to_string(&snapshot_path)
+                    .map_err(|error| Error::Reading(snapshot_path.clone(), error))?,
+            )
+        } else {
+            None
+        };
+
+        Ok(Self {
+            code,
+            snapshot_path,
+            expected,
+        })
+    }
+
+    ///
+    /// Replaces the snapshot with the `actual` diagnostic.
+    ///
+    pub fn update(&self, actual: &str) -> Result<(), Error> {
+        fs::write(&self.snapshot_path, actual)
+            .map_err(|error| Error::Writing(self.snapshot_path.clone(), error))
+    }
+}
+
+///
+/// Removes the ANSI escape sequences, e.g. the terminal colors, from the compiler output.
+///
+/// The trailing whitespace is also removed, so the snapshots are not affected by the
+/// padding of the colored spans.
+///
+pub fn strip_colors(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut characters = text.chars().peekable();
+    while let Some(character) = characters.next() {
+        if character != '\u{1b}' {
+            result.push(character);
+            continue;
+        }
+
+        if characters.peek() == Some(&'[') {
+            characters.next();
+            for terminator in characters.by_ref() {
+                if ('@'..='~').contains(&terminator) {
+                    break;
+                }
+            }
+        }
+    }
+
+    let mut lines: Vec<&str> = result.lines().map(str::trim_end).collect();
+    while lines.last() == Some(&"") {
+        lines.pop();
+    }
+    lines.join("\n") + "\n"
+}
+
+///
+/// Describes the differing lines of the expected and actual diagnostics.
+///
+pub fn format_mismatch(expected: &str, actual: &str) -> String {
+    let expected: Vec<&str> = expected.lines().collect();
+    let actual: Vec<&str> = actual.lines().collect();
+
+    let mut message = String::new();
+    for index in 0..expected.len().max(actual.len()) {
+        match (expected.get(index), actual.get(index)) {
+            (Some(expected), Some(actual)) if expected == actual => {}
+            (expected, actual) => {
+                message.push_str(format!("\n    line {}:", index + 1).as_str());
+                if let Some(expected) = expected {
+                    message.push_str(format!("\n      - {}", expected).as_str());
+                }
+                if let Some(actual) = actual {
+                    message.push_str(format!("\n      + {}", actual).as_str());
+                }
+            }
+        }
+    }
+    message
+}
@@ -0,0 +1,100 @@
+//!
+//! The Zinc tester last run state.
+//!
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use failure::Fail;
+use serde_derive::Deserialize;
+use serde_derive::Serialize;
+use sha2::Digest;
+use sha2::Sha256;
+
+///
+/// The per-file results of the previous runs, used to select the cases to rerun.
+///
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct State {
+    pub files: BTreeMap<String, FileState>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct FileState {
+    /// The SHA-256 hash of the file source code.
+    pub hash: String,
+    /// The failed case names, or the file name itself if the whole file failed.
+    pub failures: Vec<String>,
+}
+
+#[derive(Debug, Fail)]
+pub enum Error {
+    #[fail(display = "reading: {}", _0)]
+    Reading(io::Error),
+    #[fail(display = "writing: {}", _0)]
+    Writing(io::Error),
+    #[fail(display = "parsing: {}", _0)]
+    Parsing(serde_json::Error),
+}
+
+impl State {
+    ///
+    /// Loads the state, which is empty if the file does not exist yet.
+    ///
+    pub fn load(path: &PathBuf) -> Result<Self, Error> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let json = fs::read_to_string(path).map_err(Error::Reading)?;
+        serde_json::from_str(json.as_str()).map_err(Error::Parsing)
+    }
+
+    pub fn save(&self, path: &PathBuf) -> Result<(), Error> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(Error::Writing)?;
+        }
+
+        let json = serde_json::to_string_pretty(self).expect("Always valid");
+        fs::write(path, json + "\n").map_err(Error::Writing)
+    }
+
+    ///
+    /// Checks if the file is new or its source code has changed since the last run.
+    ///
+    pub fn is_changed(&self, file_name: &str, hash: &str) -> bool {
+        match self.files.get(file_name) {
+            Some(file) => file.hash != hash,
+            None => true,
+        }
+    }
+
+    ///
+    /// Checks if the case or its whole file failed during the last run.
+    ///
+    pub fn has_failed(&self, file_name: &str, case_name: &str) -> bool {
+        match self.files.get(file_name) {
+            Some(file) => file
+                .failures
+                .iter()
+                .any(|failure| failure == file_name || failure == case_name),
+            None => false,
+        }
+    }
+
+    ///
+    /// Replaces the file results with the ones of the current run.
+    ///
+    pub fn update(&mut self, file_name: String, hash: String, failures: Vec<String>) {
+        self.files.insert(file_name, FileState { hash, failures });
+    }
+}
+
+///
+/// Returns the hexadecimal SHA-256 hash of the file source code.
+///
+pub fn hash(code: &str) -> String {
+    format!("{:x}", Sha256::digest(code.as_bytes()))
+}
@@ -0,0 +1,56 @@
+//!
+//! The truncated division and remainder.
+//!
+
+use num_bigint::BigInt;
+use num_traits::Zero;
+
+///
+/// Truncated division of BigInt, which rounds the quotient towards zero, so the remainder
+/// has the sign of the nominator, as the Rust `/` and `%` operators do.
+///
+/// div_rem(9, 4) -> (2, 1)
+/// div_rem(9, -4) -> (-2, 1)
+/// div_rem(-9, 4) -> (-2, -1)
+/// div_rem(-9, -4) -> (2, -1)
+pub fn div_rem(nominator: &BigInt, denominator: &BigInt) -> Option<(BigInt, BigInt)> {
+    if denominator.is_zero() {
+        return None;
+    }
+
+    let div = nominator / denominator;
+    let rem = nominator - div.clone() * denominator;
+
+    Some((div, rem))
+}
+
+#[cfg(test)]
+mod test {
+    use num_bigint::BigInt;
+
+    use super::div_rem;
+
+    #[test]
+    fn test_div_rem() {
+        let (d, r) = div_rem(&BigInt::from(9), &BigInt::from(4)).unwrap();
+        assert_eq!(d, BigInt::from(2));
+        assert_eq!(r, BigInt::from(1));
+
+        let (d, r) = div_rem(&BigInt::from(-9), &BigInt::from(-4)).unwrap();
+        assert_eq!(d, BigInt::from(2));
+        assert_eq!(r, BigInt::from(-1));
+
+        let (d, r) = div_rem(&BigInt::from(-9), &BigInt::from(4)).unwrap();
+        assert_eq!(d, BigInt::from(-2));
+        assert_eq!(r, BigInt::from(-1));
+
+        let (d, r) = div_rem(&BigInt::from(9), &BigInt::from(-4)).unwrap();
+        assert_eq!(d, BigInt::from(-2));
+        assert_eq!(r, BigInt::from(1));
+    }
+
+    #[test]
+    fn test_div_rem_zero() {
+        assert!(div_rem(&BigInt::from(9), &BigInt::from(0)).is_none());
+    }
+}
@@ -0,0 +1,37 @@
+use crate::{Error, IoToError};
+use std::fs;
+use std::path::PathBuf;
+use structopt::StructOpt;
+use zinc_bytecode::assembly;
+use zinc_bytecode::Artifact;
+
+#[derive(Debug, StructOpt)]
+#[structopt(
+    name = "asm",
+    about = "Writes circuit's bytecode from assembly, e.g. to create a VM test program"
+)]
+pub struct AssembleCommand {
+    #[structopt(short = "i", long = "input", help = "Assembly file")]
+    pub input_path: PathBuf,
+
+    #[structopt(
+        short = "c",
+        long = "circuit",
+        help = "Circuit's bytecode file to write"
+    )]
+    pub circuit_path: PathBuf,
+}
+
+impl AssembleCommand {
+    pub fn execute(&self) -> Result<(), Error> {
+        let text = fs::read_to_string(&self.input_path)
+            .error_with_path(|| self.input_path.to_string_lossy())?;
+        let program = assembly::assemble(text.as_str())?;
+        let artifact = Artifact::new(program, Artifact::hash_sources(vec![text.as_str()]));
+
+        fs::write(&self.circuit_path, artifact.to_bytes())
+            .error_with_path(|| self.circuit_path.to_string_lossy())?;
+
+        Ok(())
+    }
+}
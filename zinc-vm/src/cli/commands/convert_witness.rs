@@ -0,0 +1,72 @@
+use crate::commands::read_program;
+use crate::{Error, IoToError};
+use std::fs;
+use std::path::PathBuf;
+use structopt::StructOpt;
+use zinc_bytecode::data::types::DataType;
+use zinc_bytecode::data::values::Value;
+use zinc_bytecode::data::witness::WitnessFormat;
+
+#[derive(Debug, StructOpt)]
+#[structopt(
+    name = "convert-witness",
+    about = "Converts a witness file between the JSON, CSV and binary formats"
+)]
+pub struct ConvertWitnessCommand {
+    #[structopt(short = "c", long = "circuit", help = "Circuit's bytecode file")]
+    pub circuit_path: PathBuf,
+
+    #[structopt(
+        short = "i",
+        long = "input",
+        help = "Witness file to read, the format is detected by the extension"
+    )]
+    pub input_path: PathBuf,
+
+    #[structopt(
+        short = "o",
+        long = "output",
+        help = "Witness file to write, the format is detected by the extension"
+    )]
+    pub output_path: PathBuf,
+}
+
+impl ConvertWitnessCommand {
+    pub fn execute(&self) -> Result<(), Error> {
+        let program = read_program(&self.circuit_path)?;
+
+        let witness = read_witness(&self.input_path, &program.input)?;
+
+        let bytes = match WitnessFormat::from_path(&self.output_path) {
+            WitnessFormat::Json => {
+                (serde_json::to_string_pretty(&witness.to_json())? + "\n").into_bytes()
+            }
+            WitnessFormat::Csv => witness.to_csv().into_bytes(),
+            WitnessFormat::Binary => witness.to_binary(&program.input),
+        };
+        fs::write(&self.output_path, bytes)
+            .error_with_path(|| self.output_path.to_string_lossy())?;
+
+        Ok(())
+    }
+}
+
+/// Reads the witness in the format detected by the file extension and checks it
+/// against the program input type.
+pub fn read_witness(path: &PathBuf, input_type: &DataType) -> Result<Value, Error> {
+    match WitnessFormat::from_path(path) {
+        WitnessFormat::Json => {
+            let text = fs::read_to_string(path).error_with_path(|| path.to_string_lossy())?;
+            let json = serde_json::from_str(&text)?;
+            Ok(Value::from_typed_json(&json, input_type)?)
+        }
+        WitnessFormat::Csv => {
+            let text = fs::read_to_string(path).error_with_path(|| path.to_string_lossy())?;
+            Ok(Value::from_csv(&text, input_type)?)
+        }
+        WitnessFormat::Binary => {
+            let bytes = fs::read(path).error_with_path(|| path.to_string_lossy())?;
+            Ok(Value::from_binary(bytes.as_slice(), input_type)?)
+        }
+    }
+}
@@ -1,10 +1,10 @@
+use crate::commands::convert_witness::read_witness;
+use crate::commands::read_program;
 use crate::{Error, IoToError};
 use pairing::bn256::Bn256;
 use std::fs;
 use std::path::PathBuf;
 use structopt::StructOpt;
-use zinc_bytecode::data::values::Value;
-use zinc_bytecode::program::Program;
 
 #[derive(Debug, StructOpt)]
 #[structopt(name = "debug", about = "Executes circuit with additional checks")]
@@ -12,7 +12,11 @@ pub struct DebugCommand {
     #[structopt(short = "c", long = "circuit", help = "Circuit's bytecode file")]
     pub circuit_path: PathBuf,
 
-    #[structopt(short = "i", long = "input", help = "Program's input file")]
+    #[structopt(
+        short = "i",
+        long = "input",
+        help = "Program's input file in the JSON, CSV or binary format"
+    )]
     pub input_path: PathBuf,
 
     #[structopt(short = "o", long = "output", help = "Program's output file")]
@@ -21,14 +25,9 @@ pub struct DebugCommand {
 
 impl DebugCommand {
     pub fn execute(&self) -> Result<(), Error> {
-        let bytes =
-            fs::read(&self.circuit_path).error_with_path(|| self.circuit_path.to_string_lossy())?;
-        let program = Program::from_bytes(bytes.as_slice()).map_err(Error::ProgramDecoding)?;
-
-        let input_text = fs::read_to_string(&self.input_path)
-            .error_with_path(|| self.input_path.to_string_lossy())?;
-        let json = serde_json::from_str(&input_text)?;
-        let input = Value::from_typed_json(&json, &program.input)?;
+        let program = read_program(&self.circuit_path)?;
+
+        let input = read_witness(&self.input_path, &program.input)?;
 
         let output = zinc_vm::debug::<Bn256>(&program, &input)?;
 
@@ -0,0 +1,37 @@
+use crate::commands::read_program;
+use crate::{Error, IoToError};
+use std::fs;
+use std::path::PathBuf;
+use structopt::StructOpt;
+use zinc_bytecode::assembly;
+
+#[derive(Debug, StructOpt)]
+#[structopt(
+    name = "disasm",
+    about = "Prints circuit's bytecode as assembly with the source code locations"
+)]
+pub struct DisassembleCommand {
+    #[structopt(short = "c", long = "circuit", help = "Circuit's bytecode file")]
+    pub circuit_path: PathBuf,
+
+    #[structopt(
+        short = "o",
+        long = "output",
+        help = "Assembly file to write, prints to the standard output if not set"
+    )]
+    pub output_path: Option<PathBuf>,
+}
+
+impl DisassembleCommand {
+    pub fn execute(&self) -> Result<(), Error> {
+        let program = read_program(&self.circuit_path)?;
+
+        let text = assembly::disassemble(&program);
+        match &self.output_path {
+            Some(path) => fs::write(path, text).error_with_path(|| path.to_string_lossy())?,
+            None => print!("{}", text),
+        }
+
+        Ok(())
+    }
+}
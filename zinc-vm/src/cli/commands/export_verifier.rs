@@ -0,0 +1,55 @@
+use crate::commands::verify::read_hex;
+use crate::{Error, IoToError};
+use franklin_crypto::bellman::groth16::VerifyingKey;
+use pairing::bn256::Bn256;
+use std::fs;
+use std::path::PathBuf;
+use structopt::StructOpt;
+
+#[derive(Debug, StructOpt)]
+#[structopt(
+    name = "export-verifier",
+    about = "Generates a Solidity verifier contract from the verifying key"
+)]
+pub struct ExportVerifierCommand {
+    #[structopt(
+        short = "k",
+        long = "verifying-key",
+        help = "Path to verifying key file"
+    )]
+    pub key_path: PathBuf,
+
+    #[structopt(short = "o", long = "output", help = "Solidity contract file to write")]
+    pub output_path: PathBuf,
+
+    #[structopt(long = "abi", help = "Contract ABI JSON file to write")]
+    pub abi_path: Option<PathBuf>,
+}
+
+impl ExportVerifierCommand {
+    pub fn execute(&self) -> Result<(), Error> {
+        // Read verification key
+        let key_file =
+            fs::File::open(&self.key_path).error_with_path(|| self.key_path.to_string_lossy())?;
+        let key_bytes = read_hex(
+            key_file,
+            &self.key_path.to_string_lossy(),
+            "verification key",
+        )?;
+        let key = VerifyingKey::<Bn256>::read(key_bytes.as_slice())
+            .error_with_path(|| self.key_path.to_string_lossy())?;
+
+        // Write contract
+        let source = zinc_vm::solidity::export_verifier(&key)?;
+        fs::write(&self.output_path, source)
+            .error_with_path(|| self.output_path.to_string_lossy())?;
+
+        // Write ABI
+        if let Some(abi_path) = &self.abi_path {
+            let abi_json = serde_json::to_string_pretty(&zinc_vm::solidity::verifier_abi())? + "\n";
+            fs::write(abi_path, abi_json).error_with_path(|| abi_path.to_string_lossy())?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,15 +1,27 @@
+mod assemble;
+mod convert_witness;
 mod debug;
+mod disassemble;
+mod export_verifier;
 mod prove;
 mod run;
 mod setup;
 mod verify;
 
+use self::assemble::AssembleCommand;
+use self::convert_witness::ConvertWitnessCommand;
 use self::debug::DebugCommand;
+use self::disassemble::DisassembleCommand;
+use self::export_verifier::ExportVerifierCommand;
 use self::prove::ProveCommand;
 use self::run::RunCommand;
 use self::setup::SetupCommand;
 use self::verify::VerifyCommand;
+use crate::{Error, IoToError};
+use std::fs;
+use std::path::PathBuf;
 use structopt::StructOpt;
+use zinc_bytecode::{Artifact, Program};
 
 #[derive(Debug, StructOpt)]
 #[structopt(name = "zvm", about = "Zinc Virtual Machine")]
@@ -32,4 +44,25 @@ pub enum Command {
     Setup(SetupCommand),
     Prove(ProveCommand),
     Verify(VerifyCommand),
+    ExportVerifier(ExportVerifierCommand),
+    Disasm(DisassembleCommand),
+    Asm(AssembleCommand),
+    ConvertWitness(ConvertWitnessCommand),
+}
+
+/// Reads the circuit artifact, migrating the programs written in the older formats.
+///
+/// The formats newer than the VM supports are rejected with the decoding error.
+pub fn read_program(path: &PathBuf) -> Result<Program, Error> {
+    let bytes = fs::read(path).error_with_path(|| path.to_string_lossy())?;
+    let artifact = Artifact::from_bytes(bytes.as_slice()).map_err(Error::ProgramDecoding)?;
+
+    if artifact.is_migrated() {
+        log::warn!(
+            "{} has been built by an older toolchain and is migrated on loading, rebuild it to upgrade",
+            path.to_string_lossy()
+        );
+    }
+
+    Ok(artifact.program)
 }
@@ -1,11 +1,12 @@
+use crate::commands::convert_witness::read_witness;
+use crate::commands::read_program;
 use crate::{Error, IoToError};
 use franklin_crypto::bellman::groth16::Parameters;
 use pairing::bn256::Bn256;
 use std::fs;
 use std::path::PathBuf;
 use structopt::StructOpt;
-use zinc_bytecode::data::values::Value;
-use zinc_bytecode::program::Program;
+use zinc_bytecode::proof::{Curve, Proof, ProvingSystem};
 
 #[derive(Debug, StructOpt)]
 #[structopt(name = "prove", about = "Executes circuit and prints program's output")]
@@ -16,19 +17,45 @@ pub struct ProveCommand {
     #[structopt(short = "k", long = "proving-key", help = "Proving key file")]
     pub proving_key_path: PathBuf,
 
-    #[structopt(short = "w", long = "witness", help = "File with witness values")]
+    #[structopt(
+        short = "w",
+        long = "witness",
+        help = "File with witness values in the JSON, CSV or binary format"
+    )]
     pub witness_path: PathBuf,
 
     #[structopt(short = "p", long = "public-data", help = "File with witness values")]
     pub pubdata_path: PathBuf,
+
+    #[structopt(
+        long = "circuit-hash",
+        help = "Circuit hash file written by setup, checked against the circuit"
+    )]
+    pub circuit_hash_path: Option<PathBuf>,
+
+    #[structopt(
+        long = "hex",
+        help = "Prints the proof envelope as a hex string instead of JSON"
+    )]
+    pub hex: bool,
 }
 
 impl ProveCommand {
     pub fn execute(&self) -> Result<(), Error> {
         // Read program
-        let bytes =
-            fs::read(&self.circuit_path).error_with_path(|| self.circuit_path.to_string_lossy())?;
-        let program = Program::from_bytes(bytes.as_slice()).map_err(Error::ProgramDecoding)?;
+        let program = read_program(&self.circuit_path)?;
+
+        // Check the keys are generated for the circuit
+        if let Some(circuit_hash_path) = &self.circuit_hash_path {
+            let expected = fs::read_to_string(circuit_hash_path)
+                .error_with_path(|| circuit_hash_path.to_string_lossy())?
+                .trim()
+                .to_owned();
+            let actual = program.hash();
+            if expected != actual {
+                return Err(Error::CircuitHashMismatch { expected, actual });
+            }
+        }
 
         // Read verifying key
         let file = fs::File::open(&self.proving_key_path)
@@ -37,12 +64,10 @@ impl ProveCommand {
             .error_with_path(|| self.proving_key_path.to_string_lossy())?;
 
         // Read witness
-        let witness_json = fs::read_to_string(&self.witness_path)
-            .error_with_path(|| self.witness_path.to_string_lossy())?;
-        let witness_value = serde_json::from_str(&witness_json)?;
-        let witness_struct = Value::from_typed_json(&witness_value, &program.input)?;
+        let witness_struct = read_witness(&self.witness_path, &program.input)?;
 
-        let (pubdata, proof) = zinc_vm::prove::<Bn256>(&program, &params, &witness_struct)?;
+        let (output, exposed, proof) = zinc_vm::prove::<Bn256>(&program, &params, &witness_struct)?;
+        let pubdata = program.public_data(&witness_struct, exposed, output);
 
         // Write pubdata
         let pubdata_json = serde_json::to_string_pretty(&pubdata.to_json())? + "\n";
@@ -53,8 +78,23 @@ impl ProveCommand {
         let mut proof_bytes = Vec::new();
         proof.write(&mut proof_bytes).expect("writing to vec");
 
-        let proof_hex = hex::encode(proof_bytes);
-        println!("{}", proof_hex);
+        let public_inputs = pubdata
+            .to_flat_values()
+            .into_iter()
+            .map(|value| value.to_string())
+            .collect();
+        let envelope = Proof::new(
+            Curve::Bn256,
+            ProvingSystem::Groth16,
+            public_inputs,
+            proof_bytes,
+        );
+
+        if self.hex {
+            println!("{}", envelope.to_hex());
+        } else {
+            println!("{}", envelope.to_json());
+        }
 
         Ok(())
     }
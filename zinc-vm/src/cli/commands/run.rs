@@ -1,10 +1,10 @@
+use crate::commands::convert_witness::read_witness;
+use crate::commands::read_program;
 use crate::{Error, IoToError};
 use pairing::bn256::Bn256;
 use std::fs;
 use std::path::PathBuf;
 use structopt::StructOpt;
-use zinc_bytecode::data::values::Value;
-use zinc_bytecode::program::Program;
 
 #[derive(Debug, StructOpt)]
 #[structopt(name = "run", about = "Executes circuit and prints program's output")]
@@ -12,25 +12,44 @@ pub struct RunCommand {
     #[structopt(short = "c", long = "circuit", help = "Circuit's bytecode file")]
     pub circuit_path: PathBuf,
 
-    #[structopt(short = "i", long = "input", help = "Program's input file")]
+    #[structopt(
+        short = "i",
+        long = "input",
+        help = "Program's input file in the JSON, CSV or binary format"
+    )]
     pub input_path: PathBuf,
 
     #[structopt(short = "o", long = "output", help = "Program's output file")]
     pub output_path: PathBuf,
+
+    #[structopt(
+        long = "check-constraints",
+        help = "Synthesizes the constraints and checks they are satisfied"
+    )]
+    pub check_constraints: bool,
+
+    #[structopt(
+        long = "memory-limit",
+        conflicts_with = "check-constraints",
+        help = "Maximal number of cells held by the VM stacks"
+    )]
+    pub memory_limit: Option<usize>,
 }
 
 impl RunCommand {
     pub fn execute(&self) -> Result<(), Error> {
-        let bytes =
-            fs::read(&self.circuit_path).error_with_path(|| self.circuit_path.to_string_lossy())?;
-        let program = Program::from_bytes(bytes.as_slice()).map_err(Error::ProgramDecoding)?;
-
-        let input_text = fs::read_to_string(&self.input_path)
-            .error_with_path(|| self.input_path.to_string_lossy())?;
-        let json = serde_json::from_str(&input_text)?;
-        let input = Value::from_typed_json(&json, &program.input)?;
-
-        let output = zinc_vm::run::<Bn256>(&program, &input)?;
+        let program = read_program(&self.circuit_path)?;
+
+        let input = read_witness(&self.input_path, &program.input)?;
+
+        let output = if self.check_constraints {
+            zinc_vm::run_checked::<Bn256>(&program, &input)?
+        } else {
+            let (output, memory_usage) =
+                zinc_vm::run_with_memory_limit::<Bn256>(&program, &input, self.memory_limit)?;
+            log::info!("Peak memory usage: {}", memory_usage);
+            output
+        };
 
         let output_json = serde_json::to_string_pretty(&output.to_json())? + "\n";
         fs::write(&self.output_path, &output_json)
@@ -1,9 +1,9 @@
+use crate::commands::read_program;
 use crate::{Error, IoToError};
 use pairing::bn256::Bn256;
 use std::fs;
 use std::path::PathBuf;
 use structopt::StructOpt;
-use zinc_bytecode::program::Program;
 
 #[derive(Debug, StructOpt)]
 #[structopt(
@@ -19,13 +19,14 @@ pub struct SetupCommand {
 
     #[structopt(short = "v", long = "verifying-key", help = "Params file to write")]
     pub verifying_key_path: PathBuf,
+
+    #[structopt(long = "circuit-hash", help = "Circuit hash file to write")]
+    pub circuit_hash_path: Option<PathBuf>,
 }
 
 impl SetupCommand {
     pub fn execute(&self) -> Result<(), Error> {
-        let bytes =
-            fs::read(&self.circuit_path).error_with_path(|| self.circuit_path.to_string_lossy())?;
-        let program = Program::from_bytes(bytes.as_slice()).map_err(Error::ProgramDecoding)?;
+        let program = read_program(&self.circuit_path)?;
 
         let params = zinc_vm::setup::<Bn256>(&program)?;
 
@@ -44,6 +45,11 @@ impl SetupCommand {
         fs::write(&self.verifying_key_path, vk_hex)
             .error_with_path(|| self.verifying_key_path.to_string_lossy())?;
 
+        if let Some(circuit_hash_path) = &self.circuit_hash_path {
+            fs::write(circuit_hash_path, program.hash() + "\n")
+                .error_with_path(|| circuit_hash_path.to_string_lossy())?;
+        }
+
         Ok(())
     }
 }
@@ -1,13 +1,15 @@
+use crate::commands::read_program;
 use crate::{Error, IoToError};
 use colored::Colorize;
 use franklin_crypto::bellman::groth16::{Proof, VerifyingKey};
 use pairing::bn256::Bn256;
 use std::fs;
+use std::io::Read;
 use std::path::PathBuf;
 use std::process::exit;
 use structopt::StructOpt;
 use zinc_bytecode::data::values::Value;
-use zinc_bytecode::Program;
+use zinc_bytecode::proof::Proof as ProofEnvelope;
 
 #[derive(Debug, StructOpt)]
 #[structopt(name = "verify", about = "Verifies the proof using verifying key")]
@@ -33,14 +35,16 @@ pub struct VerifyCommand {
 impl VerifyCommand {
     pub fn execute(&self) -> Result<(), Error> {
         // Read proof
-        let proof_bytes = read_hex(std::io::stdin(), "<stdin>", "proof")?;
+        let mut proof_text = String::new();
+        std::io::stdin()
+            .read_to_string(&mut proof_text)
+            .error_with_path(|| "<stdin>")?;
+        let envelope = proof_text.parse::<ProofEnvelope>()?;
         let proof =
-            Proof::<Bn256>::read(proof_bytes.as_slice()).error_with_path(|| "<proof data>")?;
+            Proof::<Bn256>::read(envelope.data.as_slice()).error_with_path(|| "<proof data>")?;
 
         // Read program
-        let bytes =
-            fs::read(&self.circuit_path).error_with_path(|| self.circuit_path.to_string_lossy())?;
-        let program = Program::from_bytes(bytes.as_slice()).map_err(Error::ProgramDecoding)?;
+        let program = read_program(&self.circuit_path)?;
 
         // Read verification key
         let key_file =
@@ -57,7 +61,15 @@ impl VerifyCommand {
         let output_text = fs::read_to_string(&self.public_data_path)
             .error_with_path(|| self.public_data_path.to_string_lossy())?;
         let output_value = serde_json::from_str(output_text.as_str())?;
-        let output_struct = Value::from_typed_json(&output_value, &program.output)?;
+        let output_struct = Value::from_typed_json(&output_value, &program.public_data_type())?;
+        let public_inputs = output_struct
+            .to_flat_values()
+            .into_iter()
+            .map(|value| value.to_string())
+            .collect::<Vec<String>>();
+        if public_inputs != envelope.public_inputs {
+            return Err(Error::PublicInputsMismatch);
+        }
 
         // Verify
         let verified = zinc_vm::verify(&key, &proof, &output_struct)?;
@@ -73,7 +85,7 @@ impl VerifyCommand {
     }
 }
 
-fn read_hex<R: std::io::Read>(
+pub fn read_hex<R: std::io::Read>(
     mut reader: R,
     path_hint: &str,
     context_hint: &str,
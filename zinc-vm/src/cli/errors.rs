@@ -1,6 +1,10 @@
 use failure::Fail;
 use std::io;
+use zinc_bytecode::assembly::AssemblyError;
 use zinc_bytecode::data::values::JsonValueError;
+use zinc_bytecode::data::witness::WitnessError;
+use zinc_bytecode::proof::ProofError;
+use zinc_vm::solidity::ExportError;
 use zinc_vm::{RuntimeError, VerificationError};
 
 use hex::FromHexError;
@@ -25,14 +29,35 @@ pub enum Error {
     )]
     JsonValue(JsonValueError),
 
+    #[fail(display = "invalid witness: {}", _0)]
+    Witness(WitnessError),
+
     #[fail(display = "failed to decode program: {}", _0)]
     ProgramDecoding(String),
 
+    #[fail(display = "failed to assemble program: {}", _0)]
+    Assembly(AssemblyError),
+
     #[fail(display = "failed to decode {} hex-code: {}", context, error)]
     HexDecoding {
         context: String,
         error: FromHexError,
     },
+
+    #[fail(display = "failed to decode proof: {}", _0)]
+    ProofDecoding(ProofError),
+
+    #[fail(display = "the proof public inputs do not match the public data")]
+    PublicInputsMismatch,
+
+    #[fail(display = "failed to export verifier: {}", _0)]
+    VerifierExport(ExportError),
+
+    #[fail(
+        display = "the keys are generated for another circuit (hash {}, but the circuit hash is {}), run setup again",
+        expected, actual
+    )]
+    CircuitHashMismatch { expected: String, actual: String },
 }
 
 impl From<RuntimeError> for Error {
@@ -41,12 +66,30 @@ impl From<RuntimeError> for Error {
     }
 }
 
+impl From<AssemblyError> for Error {
+    fn from(error: AssemblyError) -> Self {
+        Error::Assembly(error)
+    }
+}
+
 impl From<VerificationError> for Error {
     fn from(error: VerificationError) -> Self {
         Error::Verification(error)
     }
 }
 
+impl From<ProofError> for Error {
+    fn from(error: ProofError) -> Self {
+        Error::ProofDecoding(error)
+    }
+}
+
+impl From<ExportError> for Error {
+    fn from(error: ExportError) -> Self {
+        Error::VerifierExport(error)
+    }
+}
+
 impl From<serde_json::Error> for Error {
     fn from(error: serde_json::Error) -> Self {
         Error::JsonDecoding(error)
@@ -59,6 +102,12 @@ impl From<JsonValueError> for Error {
     }
 }
 
+impl From<WitnessError> for Error {
+    fn from(error: WitnessError) -> Self {
+        Error::Witness(error)
+    }
+}
+
 pub trait IoToError<T> {
     fn error_with_path<P, F>(self, path: F) -> Result<T, Error>
     where
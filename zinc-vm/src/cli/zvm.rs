@@ -18,6 +18,10 @@ fn main() {
         Command::Setup(command) => command.execute(),
         Command::Prove(command) => command.execute(),
         Command::Verify(command) => command.execute(),
+        Command::ExportVerifier(command) => command.execute(),
+        Command::Disasm(command) => command.execute(),
+        Command::Asm(command) => command.execute(),
+        Command::ConvertWitness(command) => command.execute(),
     };
 
     if let Err(error) = result {
@@ -0,0 +1,145 @@
+use franklin_crypto::bellman::{ConstraintSystem, LinearCombination, SynthesisError, Variable};
+
+use crate::Engine;
+
+/// The object-safe counterpart of `ConstraintSystem`, implemented for every constraint
+/// system, so the gadgets can be called through the trait objects.
+pub trait ErasedConstraintSystem<E: Engine> {
+    fn alloc_dyn(
+        &mut self,
+        annotation: String,
+        f: &mut dyn FnMut() -> Result<E::Fr, SynthesisError>,
+    ) -> Result<Variable, SynthesisError>;
+
+    fn alloc_input_dyn(
+        &mut self,
+        annotation: String,
+        f: &mut dyn FnMut() -> Result<E::Fr, SynthesisError>,
+    ) -> Result<Variable, SynthesisError>;
+
+    fn enforce_dyn(
+        &mut self,
+        annotation: String,
+        a: LinearCombination<E>,
+        b: LinearCombination<E>,
+        c: LinearCombination<E>,
+    );
+
+    fn push_namespace_dyn(&mut self, name: String);
+
+    fn pop_namespace_dyn(&mut self);
+}
+
+impl<E, CS> ErasedConstraintSystem<E> for CS
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    fn alloc_dyn(
+        &mut self,
+        annotation: String,
+        f: &mut dyn FnMut() -> Result<E::Fr, SynthesisError>,
+    ) -> Result<Variable, SynthesisError> {
+        self.alloc(|| annotation, || f())
+    }
+
+    fn alloc_input_dyn(
+        &mut self,
+        annotation: String,
+        f: &mut dyn FnMut() -> Result<E::Fr, SynthesisError>,
+    ) -> Result<Variable, SynthesisError> {
+        self.alloc_input(|| annotation, || f())
+    }
+
+    fn enforce_dyn(
+        &mut self,
+        annotation: String,
+        a: LinearCombination<E>,
+        b: LinearCombination<E>,
+        c: LinearCombination<E>,
+    ) {
+        self.enforce(|| annotation, |_| a, |_| b, |_| c)
+    }
+
+    fn push_namespace_dyn(&mut self, name: String) {
+        self.get_root().push_namespace(|| name)
+    }
+
+    fn pop_namespace_dyn(&mut self) {
+        self.get_root().pop_namespace()
+    }
+}
+
+/// The constraint system which erases the type of the VM one, so the extension gadgets
+/// do not depend on it.
+pub struct DynConstraintSystem<'a, E: Engine>(&'a mut dyn ErasedConstraintSystem<E>);
+
+impl<'a, E: Engine> DynConstraintSystem<'a, E> {
+    pub fn new(cs: &'a mut dyn ErasedConstraintSystem<E>) -> Self {
+        Self(cs)
+    }
+}
+
+impl<'a, E: Engine> ConstraintSystem<E> for DynConstraintSystem<'a, E> {
+    type Root = Self;
+
+    fn alloc<F, A, AR>(&mut self, annotation: A, f: F) -> Result<Variable, SynthesisError>
+    where
+        F: FnOnce() -> Result<E::Fr, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        let mut f = Some(f);
+        self.0
+            .alloc_dyn(annotation().into(), &mut || match f.take() {
+                Some(f) => f(),
+                None => Err(SynthesisError::AssignmentMissing),
+            })
+    }
+
+    fn alloc_input<F, A, AR>(&mut self, annotation: A, f: F) -> Result<Variable, SynthesisError>
+    where
+        F: FnOnce() -> Result<E::Fr, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        let mut f = Some(f);
+        self.0
+            .alloc_input_dyn(annotation().into(), &mut || match f.take() {
+                Some(f) => f(),
+                None => Err(SynthesisError::AssignmentMissing),
+            })
+    }
+
+    fn enforce<A, AR, LA, LB, LC>(&mut self, annotation: A, a: LA, b: LB, c: LC)
+    where
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+        LA: FnOnce(LinearCombination<E>) -> LinearCombination<E>,
+        LB: FnOnce(LinearCombination<E>) -> LinearCombination<E>,
+        LC: FnOnce(LinearCombination<E>) -> LinearCombination<E>,
+    {
+        self.0.enforce_dyn(
+            annotation().into(),
+            a(LinearCombination::zero()),
+            b(LinearCombination::zero()),
+            c(LinearCombination::zero()),
+        )
+    }
+
+    fn push_namespace<NR, N>(&mut self, name_fn: N)
+    where
+        NR: Into<String>,
+        N: FnOnce() -> NR,
+    {
+        self.0.push_namespace_dyn(name_fn().into())
+    }
+
+    fn pop_namespace(&mut self) {
+        self.0.pop_namespace_dyn()
+    }
+
+    fn get_root(&mut self) -> &mut Self::Root {
+        self
+    }
+}
@@ -1,9 +1,13 @@
 mod debug_cs;
 mod duplicate_removing_cs;
+mod dyn_cs;
 mod logging_cs;
 mod noop_cs;
+mod witness_cs;
 
 pub use debug_cs::*;
 pub use duplicate_removing_cs::*;
+pub use dyn_cs::*;
 pub use logging_cs::*;
 pub use noop_cs::*;
+pub use witness_cs::*;
@@ -0,0 +1,86 @@
+use std::marker::PhantomData;
+
+use franklin_crypto::bellman::{
+    ConstraintSystem, Index, LinearCombination, SynthesisError, Variable,
+};
+
+use crate::Engine;
+
+///
+/// The constraint system which only computes the witness.
+///
+/// The allocated values are kept by the gadgets, so the variables are only counted,
+/// and the constraints are neither built nor checked. The runtime errors are still
+/// detected, since the gadgets check the known values before enforcing constraints.
+///
+pub struct WitnessConstraintSystem<E: Engine> {
+    inputs_num: usize,
+    witness_num: usize,
+
+    _pd: PhantomData<E>,
+}
+
+impl<E: Engine> Default for WitnessConstraintSystem<E> {
+    fn default() -> Self {
+        Self {
+            inputs_num: 1,
+            witness_num: 0,
+            _pd: PhantomData,
+        }
+    }
+}
+
+impl<E: Engine> WitnessConstraintSystem<E> {
+    pub fn num_variables(&self) -> usize {
+        self.inputs_num + self.witness_num
+    }
+}
+
+impl<E: Engine> ConstraintSystem<E> for WitnessConstraintSystem<E> {
+    type Root = Self;
+
+    fn alloc<F, A, AR>(&mut self, _annotation: A, f: F) -> Result<Variable, SynthesisError>
+    where
+        F: FnOnce() -> Result<E::Fr, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        f()?;
+        self.witness_num += 1;
+        Ok(Variable::new_unchecked(Index::Aux(self.witness_num - 1)))
+    }
+
+    fn alloc_input<F, A, AR>(&mut self, _annotation: A, f: F) -> Result<Variable, SynthesisError>
+    where
+        F: FnOnce() -> Result<E::Fr, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        f()?;
+        self.inputs_num += 1;
+        Ok(Variable::new_unchecked(Index::Input(self.inputs_num - 1)))
+    }
+
+    fn enforce<A, AR, LA, LB, LC>(&mut self, _annotation: A, _a: LA, _b: LB, _c: LC)
+    where
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+        LA: FnOnce(LinearCombination<E>) -> LinearCombination<E>,
+        LB: FnOnce(LinearCombination<E>) -> LinearCombination<E>,
+        LC: FnOnce(LinearCombination<E>) -> LinearCombination<E>,
+    {
+    }
+
+    fn push_namespace<NR, N>(&mut self, _name_fn: N)
+    where
+        NR: Into<String>,
+        N: FnOnce() -> NR,
+    {
+    }
+
+    fn pop_namespace(&mut self) {}
+
+    fn get_root(&mut self) -> &mut Self::Root {
+        self
+    }
+}
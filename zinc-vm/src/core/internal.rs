@@ -28,6 +28,7 @@ pub trait InternalVM<E: Engine> {
     fn branch_end(&mut self) -> Result;
 
     fn exit(&mut self, values_count: usize) -> Result;
+    fn expose(&mut self, name: &str, values_count: usize) -> Result;
     fn call_native<F: NativeFunction<E>>(&mut self, function: F) -> Result;
 }
 
@@ -234,6 +235,24 @@ where
         Ok(())
     }
 
+    fn expose(&mut self, name: &str, values_count: usize) -> Result {
+        if self.state.conditions_stack.len() > 1 {
+            return Err(RuntimeError::ConditionalExpose(name.to_owned()));
+        }
+        if self.exposed.iter().any(|(exposed, _)| exposed == name) {
+            return Err(RuntimeError::DuplicateExpose(name.to_owned()));
+        }
+
+        let mut values = Vec::with_capacity(values_count);
+        for _ in 0..values_count {
+            values.push(self.pop()?.value()?);
+        }
+        values.reverse();
+        self.exposed.push((name.to_owned(), values));
+
+        Ok(())
+    }
+
     fn call_native<F: NativeFunction<E>>(&mut self, function: F) -> Result {
         let stack = &mut self.state.evaluation_stack;
         let cs = &mut self.cs.cs;
@@ -13,8 +13,9 @@ use crate::Engine;
 use colored::Colorize;
 use franklin_crypto::bellman::ConstraintSystem;
 use num_bigint::{BigInt, ToBigInt};
+use std::fmt;
 use std::marker::PhantomData;
-use zinc_bytecode::data::types as object_types;
+use zinc_bytecode::data::types::DataType;
 use zinc_bytecode::program::Program;
 use zinc_bytecode::{dispatch_instruction, Instruction, InstructionInfo};
 
@@ -48,12 +49,62 @@ impl<E: Engine, CS: ConstraintSystem<E>> CounterNamespace<E, CS> {
     }
 }
 
+/// The peak numbers of cells held by the VM stacks during execution.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct MemoryUsage {
+    pub evaluation_stack: usize,
+    pub data_stack: usize,
+    pub total: usize,
+}
+
+impl MemoryUsage {
+    fn update<E: Engine>(&mut self, state: &State<E>) -> usize {
+        let evaluation_stack = state.evaluation_stack.cells_count();
+        let data_stack = state.data_stack.cells_count();
+        let total = evaluation_stack + data_stack;
+
+        self.evaluation_stack = std::cmp::max(self.evaluation_stack, evaluation_stack);
+        self.data_stack = std::cmp::max(self.data_stack, data_stack);
+        self.total = std::cmp::max(self.total, total);
+
+        total
+    }
+}
+
+impl fmt::Display for MemoryUsage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} cells (evaluation stack: {}, data stack: {})",
+            self.total, self.evaluation_stack, self.data_stack
+        )
+    }
+}
+
+/// The numbers of executions of the bytecode instructions, indexed by the address.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Coverage {
+    pub hits: Vec<usize>,
+}
+
+impl Coverage {
+    pub fn new(size: usize) -> Self {
+        Self {
+            hits: vec![0; size],
+        }
+    }
+}
+
 pub struct VirtualMachine<E: Engine, CS: ConstraintSystem<E>> {
     pub(crate) debugging: bool,
     state: State<E>,
     cs: CounterNamespace<E, CS>,
     outputs: Vec<Scalar<E>>,
+    exposed: Vec<(String, Vec<Scalar<E>>)>,
     pub(crate) location: CodeLocation,
+    memory_limit: Option<usize>,
+    memory_usage: MemoryUsage,
+    coverage: Option<Coverage>,
 }
 
 impl<E: Engine, CS: ConstraintSystem<E>> VirtualMachine<E, CS> {
@@ -69,10 +120,32 @@ impl<E: Engine, CS: ConstraintSystem<E>> VirtualMachine<E, CS> {
             },
             cs: CounterNamespace::new(cs),
             outputs: vec![],
+            exposed: vec![],
             location: CodeLocation::new(),
+            memory_limit: None,
+            memory_usage: MemoryUsage::default(),
+            coverage: None,
         }
     }
 
+    /// Limits the number of cells held by the VM stacks at any moment of execution.
+    pub fn set_memory_limit(&mut self, limit: Option<usize>) {
+        self.memory_limit = limit;
+    }
+
+    pub fn memory_usage(&self) -> MemoryUsage {
+        self.memory_usage
+    }
+
+    /// Starts counting the executions of the instructions of the `program`.
+    pub fn enable_coverage(&mut self, program: &Program) {
+        self.coverage = Some(Coverage::new(program.bytecode.len()));
+    }
+
+    pub fn coverage(&self) -> Option<&Coverage> {
+        self.coverage.as_ref()
+    }
+
     pub fn constraint_system(&mut self) -> &mut CS {
         &mut self.cs.cs
     }
@@ -99,7 +172,7 @@ impl<E: Engine, CS: ConstraintSystem<E>> VirtualMachine<E, CS> {
             .constant_bigint(&1.into(), ScalarType::Boolean)?;
         self.condition_push(one)?;
 
-        self.init_root_frame(&program.input, inputs)?;
+        self.init_root_frame(program, inputs)?;
 
         let mut step = 0;
         while self.state.instruction_counter < program.bytecode.len() {
@@ -112,9 +185,15 @@ impl<E: Engine, CS: ConstraintSystem<E>> VirtualMachine<E, CS> {
                 self.state.instruction_counter,
                 dispatch_instruction!(instruction => instruction.to_assembly())
             );
+            if let Some(coverage) = self.coverage.as_mut() {
+                coverage.hits[self.state.instruction_counter] += 1;
+            }
             self.state.instruction_counter += 1;
             let result = dispatch_instruction!(instruction => instruction.execute(self));
-            if let Err(err) = result.and(check_cs(&self.cs.cs)) {
+            if let Err(err) = result
+                .and_then(|()| self.check_memory())
+                .and(check_cs(&self.cs.cs))
+            {
                 log::error!("{}\nat {}", err, self.location.to_string().blue());
                 return Err(err);
             }
@@ -125,19 +204,33 @@ impl<E: Engine, CS: ConstraintSystem<E>> VirtualMachine<E, CS> {
             step += 1;
         }
 
-        self.get_outputs()
+        self.get_outputs(program)
     }
 
+    fn check_memory(&mut self) -> Result<(), RuntimeError> {
+        let used = self.memory_usage.update(&self.state);
+        match self.memory_limit {
+            Some(limit) if used > limit => Err(RuntimeError::MemoryLimitExceeded { limit, used }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Allocates the program input, where the `pub` arguments of `main` are the public
+    /// inputs of the circuit, which precede the outputs.
     fn init_root_frame(
         &mut self,
-        input_type: &object_types::DataType,
+        program: &Program,
         inputs: Option<&[BigInt]>,
     ) -> Result<(), RuntimeError> {
         self.state
             .frames_stack
             .push(FunctionFrame::new(0, std::usize::MAX));
 
-        let types = data_type_into_scalar_types(&input_type);
+        let types = program
+            .input
+            .scalar_types()
+            .into_iter()
+            .zip(program.public_input_mask());
 
         // Convert Option<&[BigInt]> to iterator of Option<&BigInt> and zip with types.
         let value_type_pairs: Vec<_> = match inputs {
@@ -145,20 +238,39 @@ impl<E: Engine, CS: ConstraintSystem<E>> VirtualMachine<E, CS> {
             None => std::iter::repeat(None).zip(types).collect(),
         };
 
-        for (value, dtype) in value_type_pairs {
-            let variable = self.operations().allocate_witness(value, dtype)?;
+        for (value, (dtype, is_public)) in value_type_pairs {
+            let mut variable = self.operations().allocate_witness(value, dtype)?;
+            if is_public {
+                variable = self.operations().output(variable)?;
+            }
             self.push(Cell::Value(variable))?;
         }
 
         Ok(())
     }
 
-    fn get_outputs(&mut self) -> Result<Vec<Option<BigInt>>, RuntimeError> {
-        let outputs_fr: Vec<_> = self.outputs.iter().map(|f| (*f).clone()).collect();
+    /// Makes the exposed values and the outputs the public inputs of the circuit.
+    ///
+    /// The exposed values are taken in the order of the program bytecode, so the
+    /// public inputs do not depend on the order the functions are called in.
+    fn get_outputs(&mut self, program: &Program) -> Result<Vec<Option<BigInt>>, RuntimeError> {
+        let mut public_values = Vec::with_capacity(self.outputs.len());
+        if let DataType::Struct(fields) = program.exposed_type() {
+            for (name, _) in fields.into_iter() {
+                let position = self
+                    .exposed
+                    .iter()
+                    .position(|(exposed, _)| exposed == &name)
+                    .ok_or_else(|| RuntimeError::MissingExpose(name))?;
+                let (_, values) = self.exposed.remove(position);
+                public_values.extend(values);
+            }
+        }
+        public_values.extend(self.outputs.iter().cloned());
 
-        let mut outputs_bigint = Vec::with_capacity(outputs_fr.len());
-        for o in outputs_fr.into_iter() {
-            let e = self.operations().output(o.clone())?;
+        let mut outputs_bigint = Vec::with_capacity(public_values.len());
+        for o in public_values.into_iter() {
+            let e = self.operations().output(o)?;
             outputs_bigint.push(e.to_bigint());
         }
 
@@ -196,36 +308,3 @@ impl<E: Engine, CS: ConstraintSystem<E>> VirtualMachine<E, CS> {
             .ok_or_else(|| MalformedBytecode::StackUnderflow.into())
     }
 }
-
-fn data_type_into_scalar_types(dtype: &object_types::DataType) -> Vec<ScalarType> {
-    fn internal(types: &mut Vec<ScalarType>, dtype: &object_types::DataType) {
-        match dtype {
-            object_types::DataType::Unit => {}
-            object_types::DataType::Scalar(scalar_type) => {
-                types.push(*scalar_type);
-            }
-            object_types::DataType::Enum => {
-                types.push(ScalarType::Field);
-            }
-            object_types::DataType::Struct(fields) => {
-                for (_, t) in fields {
-                    internal(types, t);
-                }
-            }
-            object_types::DataType::Tuple(fields) => {
-                for t in fields {
-                    internal(types, t);
-                }
-            }
-            object_types::DataType::Array(t, size) => {
-                for _ in 0..*size {
-                    internal(types, t.as_ref());
-                }
-            }
-        }
-    }
-
-    let mut types = Vec::new();
-    internal(&mut types, dtype);
-    types
-}
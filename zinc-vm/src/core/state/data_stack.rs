@@ -94,6 +94,22 @@ impl<E: Engine> DataStack<E> {
         Ok(())
     }
 
+    /// The number of memory cells, including the ones saved by the open branches.
+    pub fn cells_count(&self) -> usize {
+        let saved: usize = self
+            .branches
+            .iter()
+            .map(|branch| match branch {
+                DataStackBranch::IfThen(delta) => delta.len(),
+                DataStackBranch::IfThenElse(then_delta, else_delta) => {
+                    then_delta.len() + else_delta.len()
+                }
+            })
+            .sum();
+
+        self.memory.len() + saved
+    }
+
     /// Create a new memory state branch
     pub fn fork(&mut self) {
         self.branches.push(DataStackBranch::new());
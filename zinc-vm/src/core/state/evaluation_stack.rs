@@ -44,6 +44,11 @@ impl<E: Engine> EvaluationStack<E> {
         self.stack.push(vec![]);
     }
 
+    /// The number of cells in all the branch frames.
+    pub fn cells_count(&self) -> usize {
+        self.stack.iter().map(Vec::len).sum()
+    }
+
     pub fn merge<CS>(&mut self, mut cs: CS, condition: &Scalar<E>) -> Result<(), RuntimeError>
     where
         CS: ConstraintSystem<E>,
@@ -2,6 +2,7 @@ use crate::gadgets::ScalarType;
 use failure::Fail;
 use franklin_crypto::bellman::SynthesisError;
 use num_bigint::BigInt;
+use zinc_bytecode::ValidationError;
 
 pub type Result<T = ()> = std::result::Result<T, RuntimeError>;
 
@@ -39,6 +40,9 @@ pub enum MalformedBytecode {
 
     #[fail(display = "conditional branches produced results of different sizes")]
     BranchStacksDoNotMatch,
+
+    #[fail(display = "{}", _0)]
+    Invalid(ValidationError),
 }
 
 #[derive(Debug, Fail)]
@@ -55,6 +59,9 @@ pub enum RuntimeError {
     #[fail(display = "assertion error: {}", _0)]
     AssertionError(String),
 
+    #[fail(display = "assertion error {}: {}", code, message)]
+    AssertionErrorWithCode { message: String, code: u64 },
+
     #[fail(
         display = "index out of bounds: expected index in range {}..{}, got {}",
         lower_bound, upper_bound, actual
@@ -97,6 +104,42 @@ pub enum RuntimeError {
 
     #[fail(display = "using witness as array index is not yet supported")]
     WitnessArrayIndex,
+
+    #[fail(
+        display = "memory limit exceeded: {} cells used, the limit is {}",
+        used, limit
+    )]
+    MemoryLimitExceeded { limit: usize, used: usize },
+
+    #[fail(display = "the public output \"{}\" is exposed under a condition", _0)]
+    ConditionalExpose(String),
+
+    #[fail(display = "the public output \"{}\" is exposed more than once", _0)]
+    DuplicateExpose(String),
+
+    #[fail(display = "the public output \"{}\" has not been exposed", _0)]
+    MissingExpose(String),
+
+    #[fail(display = "the extension `{}` is not registered", _0)]
+    UnknownExtension(String),
+
+    #[fail(display = "the host function `{}` is not registered", _0)]
+    UnknownHostFunction(String),
+
+    #[fail(display = "the host function `{}` failed: {}", identifier, message)]
+    HostFunctionFailed { identifier: String, message: String },
+}
+
+impl RuntimeError {
+    ///
+    /// Returns the error code of the failed assertion, if it has been specified.
+    ///
+    pub fn assertion_code(&self) -> Option<u64> {
+        match self {
+            RuntimeError::AssertionErrorWithCode { code, .. } => Some(*code),
+            _ => None,
+        }
+    }
 }
 
 impl From<SynthesisError> for RuntimeError {
@@ -111,6 +154,12 @@ impl From<MalformedBytecode> for RuntimeError {
     }
 }
 
+impl From<ValidationError> for RuntimeError {
+    fn from(error: ValidationError) -> Self {
+        RuntimeError::MalformedBytecode(MalformedBytecode::Invalid(error))
+    }
+}
+
 impl From<TypeSizeError> for RuntimeError {
     fn from(error: TypeSizeError) -> Self {
         RuntimeError::TypeSize(error)
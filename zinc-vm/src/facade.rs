@@ -1,23 +1,67 @@
+use std::collections::HashMap;
 use std::fmt::Debug;
+use std::sync::{Arc, RwLock};
 
 use bellman::groth16;
 use bellman::pairing::bn256::Bn256;
-use franklin_crypto::bellman::groth16::{Parameters, Proof, VerifyingKey};
+pub use franklin_crypto::bellman::groth16::{Parameters, Proof, VerifyingKey};
 use franklin_crypto::bellman::{Circuit, ConstraintSystem, SynthesisError};
 use num_bigint::BigInt;
 use rand::ThreadRng;
 
 use zinc_bytecode::program::Program;
+use zinc_bytecode::validate;
 
-use crate::constraint_systems::{DebugConstraintSystem, DuplicateRemovingCS};
+use crate::constraint_systems::{
+    DebugConstraintSystem, DuplicateRemovingCS, WitnessConstraintSystem,
+};
+pub use crate::core::{Coverage, MemoryUsage};
 use crate::core::VirtualMachine;
 pub use crate::errors::{MalformedBytecode, Result, RuntimeError, TypeSizeError};
 use crate::gadgets::utils::bigint_to_fr;
 use crate::Engine;
 use failure::Fail;
 use franklin_crypto::circuit::test::TestConstraintSystem;
+use lazy_static::lazy_static;
 use zinc_bytecode::data::values::Value;
 
+/// The host callback supplying the result of an `extern "host" fn` while generating
+/// the witness, e.g. from an off-circuit database.
+///
+/// It is called with the flattened arguments and must return the flattened result,
+/// which is allocated as a witness and range-checked in the circuit, so the callback
+/// is trusted only to be consistent with the constraints the circuit imposes on it.
+pub type HostFunction =
+    dyn Fn(&[BigInt]) -> std::result::Result<Vec<BigInt>, String> + Send + Sync;
+
+lazy_static! {
+    static ref HOST_FUNCTIONS: RwLock<HashMap<String, Arc<HostFunction>>> =
+        RwLock::new(HashMap::new());
+}
+
+/// Registers the callback resolving the calls to the `extern "host" fn` with the
+/// `identifier`, replacing the existing one if any.
+///
+/// The callbacks are only called while the witness values are known, so they are not
+/// required to run the setup.
+pub fn register_host_function<F>(identifier: &str, function: F)
+where
+    F: Fn(&[BigInt]) -> std::result::Result<Vec<BigInt>, String> + Send + Sync + 'static,
+{
+    HOST_FUNCTIONS
+        .write()
+        .expect("Mutexes never panic")
+        .insert(identifier.to_owned(), Arc::new(function));
+}
+
+pub(crate) fn host_function(identifier: &str) -> Option<Arc<HostFunction>> {
+    HOST_FUNCTIONS
+        .read()
+        .expect("Mutexes never panic")
+        .get(identifier)
+        .cloned()
+}
+
 struct VMCircuit<'a> {
     program: &'a Program,
     inputs: Option<&'a [BigInt]>,
@@ -37,10 +81,71 @@ impl<E: Engine> Circuit<E> for VMCircuit<'_> {
     }
 }
 
+/// Computes the program output without synthesizing the constraints.
+///
+/// The runtime errors are detected from the witness values, but the constraints
+/// satisfaction is not checked. Use `run_checked` or `debug` to check it as well.
 pub fn run<E: Engine>(program: &Program, inputs: &Value) -> Result<Value> {
+    run_with_memory_limit::<E>(program, inputs, None).map(|(value, _memory_usage)| value)
+}
+
+/// Computes the program output like `run`, also returning the peak memory usage.
+///
+/// Fails with `RuntimeError::MemoryLimitExceeded` if the VM stacks hold more than
+/// `memory_limit` cells at any moment of execution.
+pub fn run_with_memory_limit<E: Engine>(
+    program: &Program,
+    inputs: &Value,
+    memory_limit: Option<usize>,
+) -> Result<(Value, MemoryUsage)> {
+    validate(program)?;
+
+    let cs = WitnessConstraintSystem::<Bn256>::default();
+    let mut vm = VirtualMachine::new(cs, true);
+    vm.set_memory_limit(memory_limit);
+
+    let inputs_flat = inputs.to_flat_values();
+
+    let result = vm.run(program, Some(&inputs_flat), |_| {}, |_| Ok(()))?;
+
+    log::debug!("Variables: {}", vm.constraint_system().num_variables());
+
+    let (_exposed, value) = public_values(program, result)?;
+
+    Ok((value, vm.memory_usage()))
+}
+
+/// Computes the program output, checking that every constraint is satisfied.
+pub fn run_checked<E: Engine>(program: &Program, inputs: &Value) -> Result<Value> {
     let cs = DebugConstraintSystem::<Bn256>::default();
     let mut vm = VirtualMachine::new(cs, true);
 
+    run_checked_on(&mut vm, program, inputs)
+}
+
+/// Computes the program output like `run_checked`, also counting the executions of
+/// every instruction. The coverage is returned even if the program fails.
+pub fn run_checked_with_coverage<E: Engine>(
+    program: &Program,
+    inputs: &Value,
+) -> (Result<Value>, Coverage) {
+    let cs = DebugConstraintSystem::<Bn256>::default();
+    let mut vm = VirtualMachine::new(cs, true);
+    vm.enable_coverage(program);
+
+    let result = run_checked_on(&mut vm, program, inputs);
+    let coverage = vm.coverage().cloned().unwrap_or_default();
+
+    (result, coverage)
+}
+
+fn run_checked_on(
+    vm: &mut VirtualMachine<Bn256, DebugConstraintSystem<Bn256>>,
+    program: &Program,
+    inputs: &Value,
+) -> Result<Value> {
+    validate(program)?;
+
     let inputs_flat = inputs.to_flat_values();
 
     let mut num_constraints = 0;
@@ -66,22 +171,14 @@ pub fn run<E: Engine>(program: &Program, inputs: &Value) -> Result<Value> {
         return Err(RuntimeError::UnsatisfiedConstraint);
     }
 
-    let output_flat = result
-        .into_iter()
-        .map(|v| v.expect("`run` always computes witness"))
-        .collect::<Vec<_>>();
-
-    let value = Value::from_flat_values(&program.output, &output_flat).ok_or_else(|| {
-        TypeSizeError::Output {
-            expected: 0,
-            actual: 0,
-        }
-    })?;
+    let (_exposed, value) = public_values(program, result)?;
 
     Ok(value)
 }
 
 pub fn debug<E: Engine>(program: &Program, inputs: &Value) -> Result<Value> {
+    validate(program)?;
+
     let cs = TestConstraintSystem::<Bn256>::new();
     let mut vm = VirtualMachine::new(cs, true);
 
@@ -122,22 +219,14 @@ pub fn debug<E: Engine>(program: &Program, inputs: &Value) -> Result<Value> {
         ));
     }
 
-    let output_flat = result
-        .into_iter()
-        .map(|v| v.expect("`run` always computes witness"))
-        .collect::<Vec<_>>();
-
-    let value = Value::from_flat_values(&program.output, &output_flat).ok_or_else(|| {
-        TypeSizeError::Output {
-            expected: 0,
-            actual: 0,
-        }
-    })?;
+    let (_exposed, value) = public_values(program, result)?;
 
     Ok(value)
 }
 
 pub fn setup<E: Engine>(program: &Program) -> Result<Parameters<E>> {
+    validate(program)?;
+
     let rng = &mut rand::thread_rng();
     let mut result = None;
     let circuit = VMCircuit {
@@ -154,11 +243,15 @@ pub fn setup<E: Engine>(program: &Program) -> Result<Parameters<E>> {
     }
 }
 
+/// Computes the program output and proves it, also returning the values made public
+/// with `expose!`, which are passed to the verifier along with the output.
 pub fn prove<E: Engine>(
     program: &Program,
     params: &Parameters<E>,
     witness: &Value,
-) -> Result<(Value, Proof<E>)> {
+) -> Result<(Value, Value, Proof<E>)> {
+    validate(program)?;
+
     let rng = &mut rand::thread_rng();
 
     let witness_flat = witness.to_flat_values();
@@ -183,26 +276,41 @@ pub fn prove<E: Engine>(
         )),
         Some(res) => match res {
             Ok(values) => {
-                let output_flat: Vec<BigInt> = values
-                    .into_iter()
-                    .map(|v| v.expect("`prove` always computes witness"))
-                    .collect();
-
-                let value =
-                    Value::from_flat_values(&program.output, &output_flat).ok_or_else(|| {
-                        TypeSizeError::Output {
-                            expected: 0,
-                            actual: 0,
-                        }
-                    })?;
-
-                Ok((value, proof))
+                let (exposed, output) = public_values(program, values)?;
+
+                Ok((output, exposed, proof))
             }
             Err(err) => Err(err),
         },
     }
 }
 
+/// Splits the public values computed by the VM into the exposed values and the output.
+fn public_values(program: &Program, values: Vec<Option<BigInt>>) -> Result<(Value, Value)> {
+    let exposed_type = program.exposed_type();
+    let expected = exposed_type.size() + program.output.size();
+    if values.len() != expected {
+        return Err(TypeSizeError::Output {
+            expected,
+            actual: values.len(),
+        }
+        .into());
+    }
+
+    let values = values
+        .into_iter()
+        .map(|v| v.expect("`run` always computes witness"))
+        .collect::<Vec<_>>();
+    let (exposed_flat, output_flat) = values.split_at(exposed_type.size());
+
+    let exposed = Value::from_flat_values(&exposed_type, exposed_flat)
+        .expect("the value size is checked above");
+    let output = Value::from_flat_values(&program.output, output_flat)
+        .expect("the value size is checked above");
+
+    Ok((exposed, output))
+}
+
 #[derive(Debug, Fail)]
 pub enum VerificationError {
     #[fail(display = "value overflow: value {} is not in the field", _0)]
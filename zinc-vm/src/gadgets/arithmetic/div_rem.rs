@@ -1,11 +1,12 @@
 use crate::auto_const;
 use crate::gadgets::auto_const::prelude::*;
 use crate::gadgets::conditional_select::conditional_select;
-use crate::gadgets::{utils, Scalar, ScalarType};
+use crate::gadgets::{utils, Scalar, ScalarType, ScalarTypeExpectation};
 use crate::{gadgets, Engine, Result, RuntimeError};
 use franklin_crypto::bellman::ConstraintSystem;
 use franklin_crypto::circuit::Assignment;
 use zinc_utils::euclidean;
+use zinc_utils::truncated;
 
 pub fn div_rem_conditional<E, CS>(
     mut cs: CS,
@@ -29,7 +30,7 @@ where
 
 /// This is enforcing that `right` is not zero.
 pub fn div_rem_enforce<E, CS>(
-    mut cs: CS,
+    cs: CS,
     left: &Scalar<E>,
     right: &Scalar<E>,
 ) -> Result<(Scalar<E>, Scalar<E>)>
@@ -53,6 +54,25 @@ where
         remainder_value = utils::bigint_to_fr::<E>(&r);
     }
 
+    div_rem_witness_enforce(cs, nominator, denominator, quotient_value, remainder_value)
+}
+
+/// Enforces the quotient and remainder witness to be the Euclidean division result.
+///
+/// The remainder is compared with the denominator absolute value as a field element,
+/// whose canonical representation is non-negative, so `lt` alone enforces
+/// `0 <= rem < |denominator|`.
+fn div_rem_witness_enforce<E, CS>(
+    mut cs: CS,
+    nominator: &Scalar<E>,
+    denominator: &Scalar<E>,
+    quotient_value: Option<E::Fr>,
+    remainder_value: Option<E::Fr>,
+) -> Result<(Scalar<E>, Scalar<E>)>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
     let (quotient, remainder) = {
         let qutioent_var = cs.alloc(|| "qutioent", || quotient_value.grab())?;
 
@@ -79,14 +99,234 @@ where
         &remainder.as_field(),
         &abs_denominator.as_field(),
     )?;
-    let zero = Scalar::new_constant_int(0, remainder.get_type());
-    let ge = gadgets::ge(cs.namespace(|| "ge"), &remainder, &zero)?;
     cs.enforce(
         || "0 <= rem < |denominator|",
         |lc| lc + CS::one() - &lt.lc::<CS>(),
-        |lc| lc + CS::one() - &ge.lc::<CS>(),
+        |lc| lc + CS::one(),
         |lc| lc,
     );
 
     Ok((quotient, remainder))
 }
+
+/// The division and remainder of the `/` and `%` operators, which round the quotient
+/// towards zero, so the remainder has the sign of the nominator.
+pub fn div_rem_truncated_conditional<E, CS>(
+    mut cs: CS,
+    condition: &Scalar<E>,
+    left: &Scalar<E>,
+    right: &Scalar<E>,
+) -> Result<(Scalar<E>, Scalar<E>)>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    let denom = conditional_select(
+        cs.namespace(|| "select denominator"),
+        condition,
+        right,
+        &Scalar::new_constant_int(1, right.get_type()),
+    )?;
+
+    auto_const!(div_rem_truncated_enforce, cs, left, &denom)
+}
+
+/// This is enforcing that `right` is not zero.
+///
+/// The unsigned division is the Euclidean one. For the signed integers, the remainder is
+/// range-checked to the operand type, and enforced to be less than the denominator by
+/// absolute value and to be either zero or of the nominator sign, which makes the quotient
+/// unique.
+pub fn div_rem_truncated_enforce<E, CS>(
+    mut cs: CS,
+    left: &Scalar<E>,
+    right: &Scalar<E>,
+) -> Result<(Scalar<E>, Scalar<E>)>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    let scalar_type = ScalarType::expect_same(left.get_type(), right.get_type())?;
+    if !scalar_type.is_signed() {
+        return div_rem_enforce(cs, left, right);
+    }
+
+    let nominator = left;
+    let denominator = right;
+
+    let mut quotient_value: Option<E::Fr> = None;
+    let mut remainder_value: Option<E::Fr> = None;
+
+    if let (Some(nom), Some(denom)) = (nominator.get_value(), denominator.get_value()) {
+        let nom_bi = utils::fr_to_bigint(&nom, true);
+        let denom_bi = utils::fr_to_bigint(&denom, true);
+
+        let (q, r) = truncated::div_rem(&nom_bi, &denom_bi).ok_or(RuntimeError::DivisionByZero)?;
+
+        quotient_value = utils::bigint_to_fr::<E>(&q);
+        remainder_value = utils::bigint_to_fr::<E>(&r);
+    }
+
+    let quotient_var = cs.alloc(|| "quotient", || quotient_value.grab())?;
+    let remainder_var = cs.alloc(|| "remainder", || remainder_value.grab())?;
+
+    cs.enforce(
+        || "equality",
+        |lc| lc + quotient_var,
+        |lc| lc + &denominator.lc::<CS>(),
+        |lc| lc + &nominator.lc::<CS>() - remainder_var,
+    );
+
+    let quotient = Scalar::new_unchecked_variable(quotient_value, quotient_var, ScalarType::Field);
+    let remainder =
+        Scalar::new_unchecked_variable(remainder_value, remainder_var, ScalarType::Field);
+
+    // the remainder is a free witness, whose absolute value and sign are only meaningful
+    // within the operand type, so it is range-checked before they are computed
+    let remainder = gadgets::conditional_type_check(
+        cs.namespace(|| "remainder range"),
+        &Scalar::new_constant_bool(true),
+        &remainder,
+        scalar_type,
+    )?;
+
+    let abs_remainder = gadgets::abs(cs.namespace(|| "abs remainder"), &remainder)?;
+    let abs_denominator = gadgets::abs(cs.namespace(|| "abs denominator"), denominator)?;
+    let lt = gadgets::lt(
+        cs.namespace(|| "lt"),
+        &abs_remainder.as_field(),
+        &abs_denominator.as_field(),
+    )?;
+    cs.enforce(
+        || "|rem| < |denominator|",
+        |lc| lc + CS::one() - &lt.lc::<CS>(),
+        |lc| lc + CS::one(),
+        |lc| lc,
+    );
+
+    let zero = Scalar::new_constant_int(0, scalar_type);
+    let is_remainder_negative =
+        gadgets::lt(cs.namespace(|| "is remainder negative"), &remainder, &zero)?;
+    let is_nominator_negative =
+        gadgets::lt(cs.namespace(|| "is nominator negative"), nominator, &zero)?;
+    cs.enforce(
+        || "rem == 0 or sign(rem) == sign(nominator)",
+        |lc| lc + &is_remainder_negative.lc::<CS>() - &is_nominator_negative.lc::<CS>(),
+        |lc| lc + remainder_var,
+        |lc| lc,
+    );
+
+    Ok((quotient, remainder.as_field()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use franklin_crypto::circuit::test::TestConstraintSystem;
+    use num_bigint::BigInt;
+    use pairing::bn256::Bn256;
+
+    use zinc_bytecode::scalar::IntegerType;
+
+    fn variable<CS>(mut cs: CS, value: i64) -> Scalar<Bn256>
+    where
+        CS: ConstraintSystem<Bn256>,
+    {
+        let value = utils::bigint_to_fr::<Bn256>(&BigInt::from(value));
+        let variable = cs.alloc(|| "variable", || value.grab()).expect("variable");
+        Scalar::new_unchecked_variable(value, variable, IntegerType::I8.into())
+    }
+
+    #[test]
+    fn test_euclidean() {
+        let mut cs = TestConstraintSystem::<Bn256>::new();
+
+        let left = variable(cs.namespace(|| "left"), -7);
+        let right = variable(cs.namespace(|| "right"), 2);
+        let (quotient, remainder) =
+            div_rem_enforce(cs.namespace(|| "div_rem"), &left, &right).expect("div_rem");
+
+        assert_eq!(
+            utils::fr_to_bigint(&quotient.get_value().expect("quotient"), true),
+            BigInt::from(-4)
+        );
+        assert_eq!(
+            utils::fr_to_bigint(&remainder.get_value().expect("remainder"), true),
+            BigInt::from(1)
+        );
+        assert!(cs.is_satisfied());
+    }
+
+    #[test]
+    fn test_euclidean_remainder_out_of_range() {
+        // the forged quotients and remainders satisfy `quotient * 2 == -7 - remainder`
+        for (quotient, remainder) in [(-5, 3), (-3, -1)].iter() {
+            let mut cs = TestConstraintSystem::<Bn256>::new();
+
+            let left = variable(cs.namespace(|| "left"), -7);
+            let right = variable(cs.namespace(|| "right"), 2);
+            let forged = |value: i64| utils::bigint_to_fr::<Bn256>(&BigInt::from(value));
+            div_rem_witness_enforce(
+                cs.namespace(|| "div_rem"),
+                &left,
+                &right,
+                forged(*quotient),
+                forged(*remainder),
+            )
+            .expect("div_rem");
+
+            assert!(!cs.is_satisfied());
+            assert_eq!(
+                cs.which_is_unsatisfied(),
+                Some("div_rem/0 <= rem < |denominator|")
+            );
+        }
+    }
+
+    #[test]
+    fn test_truncated() {
+        let mut cs = TestConstraintSystem::<Bn256>::new();
+
+        let left = variable(cs.namespace(|| "left"), -7);
+        let right = variable(cs.namespace(|| "right"), 2);
+        let (quotient, remainder) =
+            div_rem_truncated_enforce(cs.namespace(|| "div_rem"), &left, &right).expect("div_rem");
+
+        assert_eq!(
+            utils::fr_to_bigint(&quotient.get_value().expect("quotient"), true),
+            BigInt::from(-3)
+        );
+        assert_eq!(
+            utils::fr_to_bigint(&remainder.get_value().expect("remainder"), true),
+            BigInt::from(-1)
+        );
+        assert!(cs.is_satisfied());
+    }
+
+    #[test]
+    fn test_truncated_remainder_out_of_range() {
+        let mut cs = TestConstraintSystem::<Bn256>::new();
+
+        let left = variable(cs.namespace(|| "left"), -7);
+        let right = variable(cs.namespace(|| "right"), 2);
+        div_rem_truncated_enforce(cs.namespace(|| "div_rem"), &left, &right).expect("div_rem");
+
+        // the forged quotient and remainder still satisfy `quotient * 2 == -7 - remainder`
+        let shift = BigInt::from(1) << 200;
+        let forged = |value: BigInt| utils::bigint_to_fr::<Bn256>(&value).expect("forged");
+        cs.set("div_rem/quotient", forged(BigInt::from(-3) - &shift));
+        cs.set(
+            "div_rem/remainder",
+            forged(BigInt::from(-1) + &shift + &shift),
+        );
+
+        assert!(!cs.is_satisfied());
+        let unsatisfied = cs.which_is_unsatisfied().expect("unsatisfied");
+        assert!(
+            unsatisfied.starts_with("div_rem/remainder range"),
+            "{}",
+            unsatisfied
+        );
+    }
+}
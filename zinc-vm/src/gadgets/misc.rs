@@ -351,8 +351,16 @@ where
         }
     }
 
-    pub fn array_set(
+    ///
+    /// Sets the array element at `index`.
+    ///
+    /// A witness index is lowered into a linear multiplexer, which conditionally selects
+    /// either the new value or the old one for every array element. Its bounds are only
+    /// enforced if `condition` is true, so an untaken branch may hold any index.
+    ///
+    pub fn conditional_array_set(
         &mut self,
+        condition: &Scalar<E>,
         array: &[Scalar<E>],
         index: Scalar<E>,
         value: Scalar<E>,
@@ -372,16 +380,20 @@ where
                 new_array[i] = value;
             }
             _ => {
-                return Err(RuntimeError::WitnessArrayIndex);
-                // let mut new_array = Vec::new();
-
-                // for (i, p) in array.iter().enumerate() {
-                //     let curr_index = Scalar::new_constant_int(i, ScalarType::Field);
-                //     let is_current_index = self.eq(curr_index, index.clone())?;
-                //     let cs = self.cs_namespace();
-                //     let value = gadgets::conditional_select(cs, &is_current_index, &value, p)?;
-                //     new_array.push(value);
-                // }
+                let mut cs = self.cs_namespace();
+                let length = Scalar::new_constant_bigint(&array.len().into(), index.get_type())?;
+                let lt = gadgets::comparison::lt(cs.namespace(|| "cs"), &index, &length)?;
+                let not_condition = gadgets::not(cs.namespace(|| "not"), condition)?;
+                mem::drop(cs);
+                let is_in_bounds = self.or(lt, not_condition)?;
+                self.assert(is_in_bounds, Some("index out of bounds"))?;
+
+                for (i, element) in new_array.iter_mut().enumerate() {
+                    let current_index = Scalar::new_constant_int(i, index.get_type());
+                    let is_current_index = self.eq(current_index, index.clone())?;
+                    let cs = self.cs_namespace();
+                    *element = gadgets::conditional_select(cs, &is_current_index, &value, element)?;
+                }
             }
         };
 
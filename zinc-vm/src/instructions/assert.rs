@@ -4,6 +4,8 @@ use self::franklin_crypto::bellman::ConstraintSystem;
 use crate::core::{InternalVM, VMInstruction};
 use crate::core::{RuntimeError, VirtualMachine};
 use crate::{gadgets, Engine};
+use num_bigint::ToBigInt;
+use zinc_bytecode::data::values::Value;
 use zinc_bytecode::instructions::Assert;
 
 impl<E, CS> VMInstruction<E, CS> for Assert
@@ -12,16 +14,47 @@ where
     CS: ConstraintSystem<E>,
 {
     fn execute(&self, vm: &mut VirtualMachine<E, CS>) -> Result<(), RuntimeError> {
+        let mut values = Vec::with_capacity(self.arg_types.len());
+        for arg_type in self.arg_types.iter().rev() {
+            let size = Value::default_from_type(arg_type).to_flat_values().len();
+            let mut flat = Vec::with_capacity(size);
+            for _ in 0..size {
+                flat.push(vm.pop()?.value()?.to_bigint());
+            }
+            flat.reverse();
+            let value = flat
+                .into_iter()
+                .collect::<Option<Vec<_>>>()
+                .map(|flat| Value::from_flat_values(arg_type, &flat).expect("value size is known"));
+            values.push(value);
+        }
+
         let value = vm.pop()?.value()?;
         let c = vm.condition_top()?;
         let cs = vm.constraint_system();
         let not_c = gadgets::not(cs.namespace(|| "not"), &c)?;
         let cond_value = vm.operations().or(value, not_c)?;
-        let message = match &self.message {
-            Some(m) => Some(m.as_str()),
-            None => None,
-        };
-        vm.operations().assert(cond_value, message)
+
+        let message = self.message.as_ref().map(|format| {
+            let mut buffer = format.clone();
+            for value in values.into_iter().rev() {
+                let json = match value {
+                    Some(value) => serde_json::to_string(&value.to_json()).expect("valid json"),
+                    None => "<unknown>".to_owned(),
+                };
+                buffer = buffer.replacen("{}", &json, 1);
+            }
+            buffer
+        });
+
+        vm.operations()
+            .assert(cond_value, message.as_ref().map(String::as_str))
+            .map_err(|error| match (error, self.code) {
+                (RuntimeError::AssertionError(message), Some(code)) => {
+                    RuntimeError::AssertionErrorWithCode { message, code }
+                }
+                (error, _) => error,
+            })
     }
 }
 
@@ -30,7 +63,8 @@ mod tests {
     use super::*;
     use crate::instructions::testing_utils::{TestingError, VMTestRunner};
 
-    use zinc_bytecode::scalar::ScalarType;
+    use zinc_bytecode::data::types::DataType;
+    use zinc_bytecode::scalar::{IntegerType, ScalarType};
     use zinc_bytecode::*;
 
     #[test]
@@ -64,4 +98,28 @@ mod tests {
             .add(EndIf)
             .test::<i32>(&[])
     }
+
+    #[test]
+    fn test_assert_formatted_with_code() {
+        let res = VMTestRunner::new()
+            .add(PushConst::new(0.into(), ScalarType::Boolean))
+            .add(PushConst::new(42.into(), IntegerType::U8.into()))
+            .add(Assert::new_formatted(
+                Some("value: {}".into()),
+                vec![DataType::Scalar(IntegerType::U8.into())],
+                Some(7),
+            ))
+            .test::<i32>(&[]);
+
+        match res {
+            Err(TestingError::RuntimeError(RuntimeError::AssertionErrorWithCode {
+                message,
+                code,
+            })) => {
+                assert_eq!(message, "value: 42");
+                assert_eq!(code, 7);
+            }
+            _ => panic!("Expected assertion error with code"),
+        }
+    }
 }
@@ -35,7 +35,7 @@ where
                 gadgets::mul(cs.namespace(|| "div"), &left, &inverse)?
             }
             ScalarType::Integer(_) => {
-                let (unchecked_div, _rem) = gadgets::div_rem_conditional(
+                let (unchecked_div, _rem) = gadgets::div_rem_truncated_conditional(
                     cs.namespace(|| "div_rem_conditional"),
                     &condition,
                     &left,
@@ -83,6 +83,6 @@ mod test {
             .add(PushConst::new((-9).into(), IntegerType::I8.into()))
             .add(PushConst::new((-4).into(), IntegerType::I8.into()))
             .add(Div)
-            .test(&[3, -3, -2, 2])
+            .test(&[2, -2, -2, 2])
     }
 }
@@ -20,8 +20,12 @@ where
         let condition = vm.condition_top()?;
         let cs = vm.constraint_system();
 
-        let (_div, unchecked_rem) =
-            gadgets::div_rem_conditional(cs.namespace(|| "div_rem"), &condition, &left, &right)?;
+        let (_div, unchecked_rem) = gadgets::div_rem_truncated_conditional(
+            cs.namespace(|| "div_rem"),
+            &condition,
+            &left,
+            &right,
+        )?;
 
         let rem = gadgets::conditional_type_check(
             cs.namespace(|| "type check"),
@@ -58,6 +62,6 @@ mod test {
             .add(PushConst::new((-9).into(), IntegerType::I8.into()))
             .add(PushConst::new((-4).into(), IntegerType::I8.into()))
             .add(Rem)
-            .test(&[3, 3, 1, 1])
+            .test(&[-1, -1, 1, 1])
     }
 }
@@ -48,6 +48,72 @@ where
             BuiltinIdentifier::ArrayPad => {
                 vm.call_native(stdlib::array::Pad::new(self.inputs_count)?)
             }
+            BuiltinIdentifier::ArraySort => {
+                vm.call_native(stdlib::array::Sort::new(self.inputs_count)?)
+            }
+            BuiltinIdentifier::EthAbiEncode => {
+                vm.call_native(stdlib::eth::AbiEncode::new(self.inputs_count)?)
+            }
+            BuiltinIdentifier::EthAbiDecode => {
+                vm.call_native(stdlib::eth::AbiDecode::new(self.inputs_count)?)
+            }
+            BuiltinIdentifier::MathOverflowingAdd => vm.call_native(
+                stdlib::math::Overflowing::new(stdlib::math::OverflowingOperation::Add),
+            ),
+            BuiltinIdentifier::MathOverflowingSub => vm.call_native(
+                stdlib::math::Overflowing::new(stdlib::math::OverflowingOperation::Sub),
+            ),
+            BuiltinIdentifier::MathOverflowingMul => vm.call_native(
+                stdlib::math::Overflowing::new(stdlib::math::OverflowingOperation::Mul),
+            ),
+            BuiltinIdentifier::MathAddMod32 | BuiltinIdentifier::MathAddMod64 => vm.call_native(
+                stdlib::math::Overflowing::new_wrapping(stdlib::math::OverflowingOperation::Add),
+            ),
+            BuiltinIdentifier::MathSubMod32 | BuiltinIdentifier::MathSubMod64 => vm.call_native(
+                stdlib::math::Overflowing::new_wrapping(stdlib::math::OverflowingOperation::Sub),
+            ),
+            BuiltinIdentifier::MathMulMod32 | BuiltinIdentifier::MathMulMod64 => vm.call_native(
+                stdlib::math::Overflowing::new_wrapping(stdlib::math::OverflowingOperation::Mul),
+            ),
+            BuiltinIdentifier::MathFixedMul => {
+                let condition = vm.condition_top()?;
+                vm.call_native(stdlib::math::Fixed::new(
+                    stdlib::math::FixedOperation::Mul,
+                    condition,
+                ))
+            }
+            BuiltinIdentifier::MathFixedDiv => {
+                let condition = vm.condition_top()?;
+                vm.call_native(stdlib::math::Fixed::new(
+                    stdlib::math::FixedOperation::Div,
+                    condition,
+                ))
+            }
+            BuiltinIdentifier::MathSelect => {
+                let condition = vm.condition_top()?;
+                vm.call_native(stdlib::math::Select::new(condition))
+            }
+            BuiltinIdentifier::MathSign => vm.call_native(stdlib::math::Sign),
+            BuiltinIdentifier::TableLookup => {
+                let condition = vm.condition_top()?;
+                vm.call_native(stdlib::table::Lookup::new(self.inputs_count, condition)?)
+            }
+            BuiltinIdentifier::AssertInRange => {
+                let condition = vm.condition_top()?;
+                vm.call_native(stdlib::assert_in_range::AssertInRange::new(condition))
+            }
+            BuiltinIdentifier::BitsCountOnes => {
+                vm.call_native(stdlib::bits::Count::new(stdlib::bits::CountOperation::Ones))
+            }
+            BuiltinIdentifier::BitsLeadingZeros => vm.call_native(stdlib::bits::Count::new(
+                stdlib::bits::CountOperation::LeadingZeros,
+            )),
+            BuiltinIdentifier::BitsRotateLeft => vm.call_native(stdlib::bits::Rotate::new(
+                stdlib::bits::RotateDirection::Left,
+            )),
+            BuiltinIdentifier::BitsRotateRight => vm.call_native(stdlib::bits::Rotate::new(
+                stdlib::bits::RotateDirection::Right,
+            )),
         }
     }
 }
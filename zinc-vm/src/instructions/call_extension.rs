@@ -0,0 +1,19 @@
+use crate::core::{InternalVM, RuntimeError, VMInstruction, VirtualMachine};
+use crate::stdlib::extension::Extension;
+use crate::Engine;
+use franklin_crypto::bellman::ConstraintSystem;
+use zinc_bytecode::instructions::CallExtension;
+
+impl<E, CS> VMInstruction<E, CS> for CallExtension
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    fn execute(&self, vm: &mut VirtualMachine<E, CS>) -> Result<(), RuntimeError> {
+        vm.call_native(Extension::new(
+            &self.identifier,
+            self.inputs_count,
+            self.outputs_count,
+        )?)
+    }
+}
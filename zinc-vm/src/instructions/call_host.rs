@@ -0,0 +1,65 @@
+use crate::core::{Cell, InternalVM, RuntimeError, VMInstruction, VirtualMachine};
+use crate::Engine;
+use franklin_crypto::bellman::ConstraintSystem;
+use num_bigint::{BigInt, ToBigInt};
+use num_traits::Zero;
+use zinc_bytecode::instructions::CallHost;
+
+impl<E, CS> VMInstruction<E, CS> for CallHost
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    fn execute(&self, vm: &mut VirtualMachine<E, CS>) -> Result<(), RuntimeError> {
+        let mut arguments = Vec::with_capacity(self.inputs_count);
+        for _ in 0..self.inputs_count {
+            arguments.push(vm.pop()?.value()?.to_bigint());
+        }
+        arguments.reverse();
+
+        let scalar_types = self.output.scalar_types();
+
+        // The host is only asked while generating the witness and in the taken branches,
+        // since the arguments are meaningless otherwise.
+        let is_taken = vm
+            .condition_top()?
+            .to_bigint()
+            .map(|condition| !condition.is_zero());
+        let arguments = arguments.into_iter().collect::<Option<Vec<BigInt>>>();
+        let outputs = match (arguments, is_taken) {
+            (Some(arguments), Some(true)) => {
+                let function = crate::facade::host_function(&self.identifier)
+                    .ok_or_else(|| RuntimeError::UnknownHostFunction(self.identifier.clone()))?;
+                let outputs = function(arguments.as_slice()).map_err(|message| {
+                    RuntimeError::HostFunctionFailed {
+                        identifier: self.identifier.clone(),
+                        message,
+                    }
+                })?;
+                if outputs.len() != scalar_types.len() {
+                    return Err(RuntimeError::HostFunctionFailed {
+                        identifier: self.identifier.clone(),
+                        message: format!(
+                            "returned {} values, but {} are expected",
+                            outputs.len(),
+                            scalar_types.len()
+                        ),
+                    });
+                }
+                outputs.into_iter().map(Some).collect()
+            }
+            (Some(_), _) => vec![Some(BigInt::zero()); scalar_types.len()],
+            (None, _) => vec![None; scalar_types.len()],
+        };
+
+        // The witness allocation checks the values are in range of their types.
+        for (value, scalar_type) in outputs.into_iter().zip(scalar_types) {
+            let scalar = vm
+                .operations()
+                .allocate_witness(value.as_ref(), scalar_type)?;
+            vm.push(Cell::Value(scalar))?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,14 @@
+use crate::core::{InternalVM, RuntimeError, VMInstruction, VirtualMachine};
+use crate::Engine;
+use franklin_crypto::bellman::ConstraintSystem;
+use zinc_bytecode::instructions::Expose;
+
+impl<E, CS> VMInstruction<E, CS> for Expose
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    fn execute(&self, vm: &mut VirtualMachine<E, CS>) -> Result<(), RuntimeError> {
+        vm.expose(&self.name, self.data_type.size())
+    }
+}
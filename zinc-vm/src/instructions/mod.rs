@@ -7,7 +7,10 @@ pub mod memory;
 
 pub mod assert;
 pub mod call_builtin;
+pub mod call_extension;
+pub mod call_host;
 pub mod cast;
 pub mod dbg;
+pub mod expose;
 pub mod markers;
 pub mod noop;
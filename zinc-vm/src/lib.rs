@@ -3,6 +3,7 @@ mod core;
 mod errors;
 pub mod gadgets;
 mod instructions;
+pub mod solidity;
 pub mod stdlib;
 
 #[cfg(test)]
@@ -0,0 +1,262 @@
+//!
+//! The Solidity verifier contract generator.
+//!
+//! The generated contract checks Groth16 proofs over the BN254 curve using the
+//! Ethereum `ecAdd`, `ecMul` and `ecPairing` precompiles (EIP-196, EIP-197).
+//!
+
+use failure::Fail;
+use franklin_crypto::bellman::groth16::VerifyingKey;
+use pairing::bn256::Bn256;
+use serde_json::json;
+
+const G1_UNCOMPRESSED_SIZE: usize = 64;
+const G2_UNCOMPRESSED_SIZE: usize = 128;
+const COORDINATE_SIZE: usize = 32;
+
+/// The serialization flags occupy the two most significant bits of the first byte.
+const COORDINATE_FLAGS_MASK: u8 = 0x3f;
+
+#[derive(Debug, Fail, PartialEq)]
+pub enum ExportError {
+    #[fail(
+        display = "verifying key is truncated: expected at least {} bytes, got {}",
+        expected, actual
+    )]
+    UnexpectedEnd { expected: usize, actual: usize },
+
+    #[fail(display = "verifying key has no input commitments")]
+    EmptyInputCommitments,
+}
+
+/// The affine G1 point coordinates as big-endian hexadecimal strings.
+#[derive(Debug, PartialEq)]
+pub struct G1Point {
+    pub x: String,
+    pub y: String,
+}
+
+/// The affine G2 point coordinates, imaginary part first as expected by EIP-197.
+#[derive(Debug, PartialEq)]
+pub struct G2Point {
+    pub x: [String; 2],
+    pub y: [String; 2],
+}
+
+/// The verifying key points used by the Solidity verifier.
+#[derive(Debug, PartialEq)]
+pub struct VerifierKey {
+    pub alpha: G1Point,
+    pub beta: G2Point,
+    pub gamma: G2Point,
+    pub delta: G2Point,
+    pub ic: Vec<G1Point>,
+}
+
+impl VerifierKey {
+    ///
+    /// Decodes the key from the `VerifyingKey::write` layout, which is
+    /// `alpha_g1, beta_g1, beta_g2, gamma_g2, delta_g1, delta_g2, ic_len: u32, ic...`
+    /// with every point in the uncompressed form.
+    ///
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ExportError> {
+        let mut reader = Reader::new(bytes);
+
+        let alpha = reader.g1()?;
+        let _beta_g1 = reader.g1()?;
+        let beta = reader.g2()?;
+        let gamma = reader.g2()?;
+        let _delta_g1 = reader.g1()?;
+        let delta = reader.g2()?;
+
+        let ic_length = reader.u32()? as usize;
+        if ic_length == 0 {
+            return Err(ExportError::EmptyInputCommitments);
+        }
+        let mut ic = Vec::with_capacity(ic_length);
+        for _ in 0..ic_length {
+            ic.push(reader.g1()?);
+        }
+
+        Ok(Self {
+            alpha,
+            beta,
+            gamma,
+            delta,
+            ic,
+        })
+    }
+
+    /// The number of public inputs accepted by the verifier.
+    pub fn inputs_count(&self) -> usize {
+        self.ic.len() - 1
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, offset: 0 }
+    }
+
+    fn take(&mut self, size: usize) -> Result<&'a [u8], ExportError> {
+        let end = self.offset + size;
+        if end > self.bytes.len() {
+            return Err(ExportError::UnexpectedEnd {
+                expected: end,
+                actual: self.bytes.len(),
+            });
+        }
+        let slice = &self.bytes[self.offset..end];
+        self.offset = end;
+        Ok(slice)
+    }
+
+    fn u32(&mut self) -> Result<u32, ExportError> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    fn coordinates(&mut self, size: usize) -> Result<Vec<String>, ExportError> {
+        let mut bytes = self.take(size)?.to_vec();
+        bytes[0] &= COORDINATE_FLAGS_MASK;
+        Ok(bytes
+            .chunks(COORDINATE_SIZE)
+            .map(|chunk| format!("0x{}", hex::encode(chunk)))
+            .collect())
+    }
+
+    fn g1(&mut self) -> Result<G1Point, ExportError> {
+        let mut coordinates = self.coordinates(G1_UNCOMPRESSED_SIZE)?.into_iter();
+        Ok(G1Point {
+            x: coordinates.next().expect("Always exists"),
+            y: coordinates.next().expect("Always exists"),
+        })
+    }
+
+    fn g2(&mut self) -> Result<G2Point, ExportError> {
+        let mut coordinates = self.coordinates(G2_UNCOMPRESSED_SIZE)?.into_iter();
+        let mut next = || coordinates.next().expect("Always exists");
+        Ok(G2Point {
+            x: [next(), next()],
+            y: [next(), next()],
+        })
+    }
+}
+
+///
+/// Generates the Solidity verifier contract source code for the verifying key.
+///
+pub fn export_verifier(key: &VerifyingKey<Bn256>) -> Result<String, ExportError> {
+    let mut bytes = Vec::new();
+    key.write(&mut bytes).expect("writing to vec");
+    let key = VerifierKey::from_bytes(bytes.as_slice())?;
+    Ok(verifier_source(&key))
+}
+
+///
+/// The ABI of the generated contract, which is the same for every verifying key.
+///
+pub fn verifier_abi() -> serde_json::Value {
+    json!([{
+        "type": "function",
+        "name": "verifyProof",
+        "stateMutability": "view",
+        "inputs": [
+            { "name": "a", "type": "uint256[2]", "internalType": "uint256[2]" },
+            { "name": "b", "type": "uint256[2][2]", "internalType": "uint256[2][2]" },
+            { "name": "c", "type": "uint256[2]", "internalType": "uint256[2]" },
+            { "name": "input", "type": "uint256[]", "internalType": "uint256[]" }
+        ],
+        "outputs": [
+            { "name": "", "type": "bool", "internalType": "bool" }
+        ]
+    }])
+}
+
+pub fn verifier_source(key: &VerifierKey) -> String {
+    let mut ic = String::new();
+    for (index, point) in key.ic.iter().enumerate() {
+        ic.push_str(&format!(
+            "        vk.ic[{}] = {};\n",
+            index,
+            g1_literal(point)
+        ));
+    }
+
+    format!(
+        include_str!("solidity/verifier.sol.template"),
+        alpha = g1_literal(&key.alpha),
+        beta = g2_literal(&key.beta),
+        gamma = g2_literal(&key.gamma),
+        delta = g2_literal(&key.delta),
+        ic_length = key.ic.len(),
+        ic = ic,
+    )
+}
+
+fn g1_literal(point: &G1Point) -> String {
+    format!("Pairing.G1Point({}, {})", point.x, point.y)
+}
+
+fn g2_literal(point: &G2Point) -> String {
+    format!(
+        "Pairing.G2Point([{}, {}], [{}, {}])",
+        point.x[0], point.x[1], point.y[0], point.y[1]
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn key_bytes(ic_length: u32) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend(vec![1u8; G1_UNCOMPRESSED_SIZE]);
+        bytes.extend(vec![2u8; G1_UNCOMPRESSED_SIZE]);
+        bytes.extend(vec![3u8; G2_UNCOMPRESSED_SIZE]);
+        bytes.extend(vec![4u8; G2_UNCOMPRESSED_SIZE]);
+        bytes.extend(vec![5u8; G1_UNCOMPRESSED_SIZE]);
+        bytes.extend(vec![6u8; G2_UNCOMPRESSED_SIZE]);
+        bytes.extend(&ic_length.to_be_bytes());
+        for _ in 0..ic_length {
+            bytes.extend(vec![7u8; G1_UNCOMPRESSED_SIZE]);
+        }
+        bytes
+    }
+
+    #[test]
+    fn test_from_bytes() {
+        let key = VerifierKey::from_bytes(key_bytes(3).as_slice()).expect("valid key");
+
+        assert_eq!(key.inputs_count(), 2);
+        assert_eq!(key.alpha.x, format!("0x{}", "01".repeat(COORDINATE_SIZE)));
+        assert_eq!(key.beta.y[1], format!("0x{}", "03".repeat(COORDINATE_SIZE)));
+        assert_eq!(
+            key.delta.x[0],
+            format!("0x{}", "06".repeat(COORDINATE_SIZE))
+        );
+
+        let source = verifier_source(&key);
+        assert!(source.contains("vk.ic = new Pairing.G1Point[](3);"));
+        assert!(source.contains("vk.ic[2] = Pairing.G1Point("));
+    }
+
+    #[test]
+    fn test_from_bytes_truncated() {
+        let mut bytes = key_bytes(2);
+        bytes.pop();
+
+        assert_eq!(
+            VerifierKey::from_bytes(bytes.as_slice()),
+            Err(ExportError::UnexpectedEnd {
+                expected: bytes.len() + 1,
+                actual: bytes.len(),
+            })
+        );
+    }
+}
@@ -6,3 +6,6 @@ pub use truncate::*;
 
 mod reverse;
 pub use reverse::*;
+
+mod sort;
+pub use sort::*;
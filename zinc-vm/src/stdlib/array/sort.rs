@@ -0,0 +1,91 @@
+use crate::core::EvaluationStack;
+use crate::gadgets::Scalar;
+use crate::stdlib::NativeFunction;
+use crate::{gadgets, Engine, Result};
+use bellman::ConstraintSystem;
+
+/// Sorts an array of integers or field elements in ascending order with the Batcher
+/// odd-even mergesort network.
+///
+/// The network is the same for any values and consists of `O(N * log^2(N))` comparators,
+/// each costing a comparison and two conditional selects. The arrays of a size not being
+/// a power of two are treated as padded with the maximal values, which never move, so
+/// the comparators touching the padding are omitted.
+pub struct Sort {
+    array_length: usize,
+}
+
+impl Sort {
+    pub fn new(inputs_count: usize) -> Result<Self> {
+        Ok(Self {
+            array_length: inputs_count,
+        })
+    }
+
+    /// Returns the index pairs compared by the network, in the order of their application.
+    fn comparators(&self) -> Vec<(usize, usize)> {
+        let length = self.array_length;
+        let mut comparators = Vec::new();
+
+        let mut p = 1;
+        while p < length {
+            let mut k = p;
+            while k >= 1 {
+                let mut j = k % p;
+                while j + k < length {
+                    for i in 0..k.min(length - j - k) {
+                        if (i + j) / (p * 2) == (i + j + k) / (p * 2) {
+                            comparators.push((i + j, i + j + k));
+                        }
+                    }
+                    j += k * 2;
+                }
+                k /= 2;
+            }
+            p *= 2;
+        }
+
+        comparators
+    }
+}
+
+impl<E: Engine> NativeFunction<E> for Sort {
+    fn execute<CS: ConstraintSystem<E>>(
+        &self,
+        mut cs: CS,
+        stack: &mut EvaluationStack<E>,
+    ) -> Result {
+        let mut array: Vec<Scalar<E>> = Vec::with_capacity(self.array_length);
+        for _ in 0..self.array_length {
+            array.push(stack.pop()?.value()?);
+        }
+        array.reverse();
+
+        for (index, (left, right)) in self.comparators().into_iter().enumerate() {
+            let mut cs = cs.namespace(|| format!("comparator {}", index));
+
+            let is_swapped = gadgets::lt(cs.namespace(|| "lt"), &array[right], &array[left])?;
+            let lesser = gadgets::conditional_select(
+                cs.namespace(|| "lesser"),
+                &is_swapped,
+                &array[right],
+                &array[left],
+            )?;
+            let greater = gadgets::conditional_select(
+                cs.namespace(|| "greater"),
+                &is_swapped,
+                &array[left],
+                &array[right],
+            )?;
+
+            array[left] = lesser;
+            array[right] = greater;
+        }
+
+        for value in array {
+            stack.push(value.into())?;
+        }
+
+        Ok(())
+    }
+}
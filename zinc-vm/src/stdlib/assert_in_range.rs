@@ -0,0 +1,99 @@
+use crate::core::EvaluationStack;
+use crate::gadgets::{utils, Scalar};
+use crate::stdlib::NativeFunction;
+use crate::{gadgets, Engine, MalformedBytecode, Result, RuntimeError};
+use bellman::ConstraintSystem;
+use ff::Field;
+use franklin_crypto::circuit::expression::Expression;
+use num_bigint::BigInt;
+
+/// Asserts `low <= value <= high` for the constant bounds by decomposing `value - low` into
+/// the `k` bits of the range width `high - low`.
+///
+/// The decomposition alone enforces the range if its width plus one is a power of two,
+/// whereas the other widths take the second decomposition of `value - low + 2^k - 1 - width`,
+/// which only fits into `k` bits if `value <= high`. In the unreachable branches the value is
+/// replaced with `low`, so an arbitrary value there never fails the proof.
+pub struct AssertInRange<E: Engine> {
+    condition: Scalar<E>,
+}
+
+impl<E: Engine> AssertInRange<E> {
+    pub fn new(condition: Scalar<E>) -> Self {
+        Self { condition }
+    }
+}
+
+impl<E: Engine> NativeFunction<E> for AssertInRange<E> {
+    fn execute<CS: ConstraintSystem<E>>(
+        &self,
+        mut cs: CS,
+        stack: &mut EvaluationStack<E>,
+    ) -> Result {
+        let high = stack.pop()?.value()?;
+        let low = stack.pop()?.value()?;
+        let value = stack.pop()?.value()?;
+
+        let is_signed = value.is_signed();
+        let low = utils::fr_to_bigint(&low.get_constant()?, is_signed);
+        let high = utils::fr_to_bigint(&high.get_constant()?, is_signed);
+        if low > high {
+            return Err(MalformedBytecode::InvalidArguments(format!(
+                "assert_in_range expects a non-empty range, got {}..={}",
+                low, high
+            ))
+            .into());
+        }
+
+        let is_executed = self.condition.get_value().map(|value| !value.is_zero());
+        if let (Some(true), Some(value)) = (is_executed, value.get_value()) {
+            let value = utils::fr_to_bigint(&value, is_signed);
+            if value < low || value > high {
+                return Err(RuntimeError::AssertionError(format!(
+                    "{} is out of range {}..={}",
+                    value, low, high
+                )));
+            }
+        }
+
+        if value.is_constant() {
+            return Ok(());
+        }
+
+        let low_fr = utils::bigint_to_fr::<E>(&low).expect("the bound is a field element");
+        let width = &high - &low;
+        let bits_count = width.bits();
+
+        let low_scalar = Scalar::new_constant_fr(low_fr, value.get_type());
+        let value = gadgets::conditional_select(
+            cs.namespace(|| "value"),
+            &self.condition,
+            &value,
+            &low_scalar,
+        )?;
+
+        if bits_count == 0 {
+            cs.enforce(
+                || "equality",
+                |lc| lc + &value.lc::<CS>() - (low_fr, CS::one()),
+                |lc| lc + CS::one(),
+                |lc| lc,
+            );
+            return Ok(());
+        }
+
+        let offset = value.to_expression::<CS>() - Expression::constant::<CS>(low_fr);
+        let _bits = offset.into_bits_le_fixed(cs.namespace(|| "lower bound"), bits_count)?;
+
+        let padding = (BigInt::from(1) << bits_count) - 1 - width;
+        if padding > BigInt::from(0) {
+            let padding_fr =
+                utils::bigint_to_fr::<E>(&padding).expect("the padding is a field element");
+            let offset = value.to_expression::<CS>() - Expression::constant::<CS>(low_fr)
+                + Expression::constant::<CS>(padding_fr);
+            let _bits = offset.into_bits_le_fixed(cs.namespace(|| "upper bound"), bits_count)?;
+        }
+
+        Ok(())
+    }
+}
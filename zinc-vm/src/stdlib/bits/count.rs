@@ -0,0 +1,78 @@
+use crate::core::EvaluationStack;
+use crate::gadgets::{IntegerType, Scalar, ScalarType};
+use crate::stdlib::NativeFunction;
+use crate::{gadgets, Engine, Result, RuntimeError};
+use bellman::ConstraintSystem;
+use franklin_crypto::circuit::expression::Expression;
+
+pub enum CountOperation {
+    Ones,
+    LeadingZeros,
+}
+
+/// Counts the bits of an unsigned integer, returning the number as `u8`.
+///
+/// Both operations sum the bits of the decomposition into a single variable. The leading
+/// zeros are counted as the number of the most significant bit prefixes being all zeros,
+/// which takes two more constraints per bit for the running conjunction.
+pub struct Count {
+    operation: CountOperation,
+}
+
+impl Count {
+    pub fn new(operation: CountOperation) -> Self {
+        Self { operation }
+    }
+}
+
+impl<E: Engine> NativeFunction<E> for Count {
+    fn execute<CS: ConstraintSystem<E>>(
+        &self,
+        mut cs: CS,
+        stack: &mut EvaluationStack<E>,
+    ) -> Result {
+        let value = stack.pop()?.value()?;
+
+        match value.get_type() {
+            ScalarType::Integer(integer_type) if !integer_type.is_signed => {}
+            scalar_type => {
+                return Err(RuntimeError::TypeError {
+                    expected: "unsigned integer type".to_owned(),
+                    actual: scalar_type.to_string(),
+                })
+            }
+        }
+
+        let bits = value.get_bits_le(cs.namespace(|| "bits"))?;
+
+        let counted = match self.operation {
+            CountOperation::Ones => bits,
+            CountOperation::LeadingZeros => {
+                let mut prefixes = Vec::with_capacity(bits.len());
+                let mut is_zero_prefix = Scalar::new_constant_bool(true);
+                for (index, bit) in bits.iter().enumerate().rev() {
+                    let is_zero = gadgets::not(cs.namespace(|| format!("not {}", index)), bit)?;
+                    is_zero_prefix = gadgets::and(
+                        cs.namespace(|| format!("prefix {}", index)),
+                        &is_zero_prefix,
+                        &is_zero,
+                    )?;
+                    prefixes.push(is_zero_prefix.clone());
+                }
+                prefixes
+            }
+        };
+
+        let sum = counted
+            .iter()
+            .fold(Expression::u64::<CS>(0), |sum, bit| {
+                sum + bit.to_expression::<CS>()
+            })
+            .into_number(cs.namespace(|| "sum"))?;
+        let result = Scalar::from(sum).with_type_unchecked(IntegerType::U8.into());
+
+        stack.push(result.into())?;
+
+        Ok(())
+    }
+}
@@ -9,3 +9,9 @@ pub use unsigned_from_bits::*;
 
 mod signed_from_bits;
 pub use signed_from_bits::*;
+
+mod count;
+pub use count::*;
+
+mod rotate;
+pub use rotate::*;
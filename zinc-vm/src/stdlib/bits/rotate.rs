@@ -0,0 +1,66 @@
+use crate::core::EvaluationStack;
+use crate::gadgets::{Scalar, ScalarType};
+use crate::stdlib::NativeFunction;
+use crate::{Engine, Result, RuntimeError};
+use bellman::ConstraintSystem;
+use franklin_crypto::circuit::num::AllocatedNum;
+
+pub enum RotateDirection {
+    Left,
+    Right,
+}
+
+/// Rotates the bits of an unsigned integer by a constant number of positions.
+///
+/// The rotation only permutes the bits of the decomposition, so it costs the decomposition
+/// and the packing of the permuted bits back into a single variable.
+pub struct Rotate {
+    direction: RotateDirection,
+}
+
+impl Rotate {
+    pub fn new(direction: RotateDirection) -> Self {
+        Self { direction }
+    }
+}
+
+impl<E: Engine> NativeFunction<E> for Rotate {
+    fn execute<CS: ConstraintSystem<E>>(
+        &self,
+        mut cs: CS,
+        stack: &mut EvaluationStack<E>,
+    ) -> Result {
+        let shift = stack.pop()?.value()?;
+        let value = stack.pop()?.value()?;
+
+        let scalar_type = value.get_type();
+        let bitlength = match scalar_type {
+            ScalarType::Integer(integer_type) if !integer_type.is_signed => integer_type.bitlength,
+            scalar_type => {
+                return Err(RuntimeError::TypeError {
+                    expected: "unsigned integer type".to_owned(),
+                    actual: scalar_type.to_string(),
+                })
+            }
+        };
+        let shift = shift.get_constant_usize()? % bitlength;
+
+        let mut bits = value
+            .to_expression::<CS>()
+            .into_bits_le_fixed(cs.namespace(|| "bits"), bitlength)?;
+
+        // the bits are little-endian, so the left rotation moves them to the end
+        match self.direction {
+            RotateDirection::Left => bits.rotate_right(shift),
+            RotateDirection::Right => bits.rotate_left(shift),
+        }
+
+        let num = AllocatedNum::pack_bits_to_element(cs.namespace(|| "pack"), &bits)?;
+        let result =
+            Scalar::new_unchecked_variable(num.get_value(), num.get_variable(), scalar_type);
+
+        stack.push(result.into())?;
+
+        Ok(())
+    }
+}
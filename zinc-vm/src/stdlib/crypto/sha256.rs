@@ -3,8 +3,14 @@ use crate::gadgets::Scalar;
 use crate::stdlib::NativeFunction;
 use crate::{Engine, MalformedBytecode, Result};
 use bellman::ConstraintSystem;
+use ff::Field;
 use franklin_crypto::circuit::sha256::sha256;
+use sha2::Digest;
 
+/// Computes the `sha256` digest of a bit array.
+///
+/// A preimage of constant bits is hashed natively, so the digest is a constant array taking
+/// no constraints, e.g. when it is used as a domain separator.
 pub struct Sha256 {
     message_length: usize,
 }
@@ -29,16 +35,37 @@ impl<E: Engine> NativeFunction<E> for Sha256 {
         mut cs: CS,
         stack: &mut EvaluationStack<E>,
     ) -> Result {
-        let mut bits = Vec::new();
-        for i in 0..self.message_length {
-            let bit = stack
-                .pop()?
-                .value()?
-                .to_boolean(cs.namespace(|| format!("bit {}", i)))?;
+        let mut scalars = Vec::with_capacity(self.message_length);
+        for _ in 0..self.message_length {
+            scalars.push(stack.pop()?.value()?);
+        }
+        scalars.reverse();
+
+        if scalars.iter().all(Scalar::is_constant) {
+            let mut preimage = Vec::with_capacity(self.message_length / 8);
+            for byte_bits in scalars.chunks(8) {
+                let mut byte = 0u8;
+                for bit in byte_bits {
+                    byte = (byte << 1) | (!bit.get_constant()?.is_zero()) as u8;
+                }
+                preimage.push(byte);
+            }
+
+            for byte in sha2::Sha256::digest(&preimage).iter() {
+                for index in (0..8).rev() {
+                    let bit = Scalar::new_constant_bool((byte >> index) & 1 == 1);
+                    stack.push(bit.into())?;
+                }
+            }
+
+            return Ok(());
+        }
 
+        let mut bits = Vec::with_capacity(self.message_length);
+        for (i, scalar) in scalars.into_iter().enumerate() {
+            let bit = scalar.to_boolean(cs.namespace(|| format!("bit {}", i)))?;
             bits.push(bit);
         }
-        bits.reverse();
 
         let digest_bits = sha256(cs.namespace(|| "sha256"), &bits)?;
 
@@ -0,0 +1,146 @@
+use crate::core::EvaluationStack;
+use crate::gadgets::{utils, IntegerType, Scalar, ScalarType};
+use crate::stdlib::eth::{ABI_WORD_BITS, ABI_WORD_BYTES};
+use crate::stdlib::NativeFunction;
+use crate::{Engine, MalformedBytecode, Result};
+use bellman::ConstraintSystem;
+use ff::PrimeField;
+use franklin_crypto::circuit::boolean::Boolean;
+use franklin_crypto::circuit::expression::Expression;
+use franklin_crypto::circuit::num::AllocatedNum;
+use num_bigint::BigInt;
+
+const BYTE_BITS: usize = 8;
+
+/// Decodes the 32-byte big-endian Solidity ABI words into the scalars.
+///
+/// The compiler passes a zero value of the decoded type after the bytes, whose scalars
+/// are only used as the types of the words. The padding of every word is checked to be
+/// the one written by `AbiEncode`, so each value has a single valid encoding.
+pub struct AbiDecode {
+    values_count: usize,
+}
+
+impl AbiDecode {
+    pub fn new(inputs_count: usize) -> Result<Self> {
+        if inputs_count % (ABI_WORD_BYTES + 1) == 0 {
+            Ok(Self {
+                values_count: inputs_count / (ABI_WORD_BYTES + 1),
+            })
+        } else {
+            Err(MalformedBytecode::InvalidArguments(format!(
+                "abi_decode: the input size must be a multiple of {}, got {}",
+                ABI_WORD_BYTES + 1,
+                inputs_count
+            ))
+            .into())
+        }
+    }
+}
+
+impl<E: Engine> NativeFunction<E> for AbiDecode {
+    fn execute<CS: ConstraintSystem<E>>(
+        &self,
+        mut cs: CS,
+        stack: &mut EvaluationStack<E>,
+    ) -> Result {
+        let mut types = Vec::with_capacity(self.values_count);
+        for _ in 0..self.values_count {
+            types.push(stack.pop()?.value()?.get_type());
+        }
+        types.reverse();
+
+        let mut bytes = Vec::with_capacity(self.values_count * ABI_WORD_BYTES);
+        for _ in 0..self.values_count * ABI_WORD_BYTES {
+            bytes.push(stack.pop()?.value()?);
+        }
+        bytes.reverse();
+
+        for (index, (scalar_type, word)) in types
+            .into_iter()
+            .zip(bytes.chunks(ABI_WORD_BYTES))
+            .enumerate()
+        {
+            // the most significant byte goes first
+            let mut bits = Vec::with_capacity(ABI_WORD_BITS);
+            for (byte_index, byte) in word.iter().enumerate().rev() {
+                bits.extend(byte.to_expression::<CS>().into_bits_le_fixed(
+                    cs.namespace(|| format!("word {} byte {}", index, byte_index)),
+                    BYTE_BITS,
+                )?);
+            }
+
+            let value = decode_word(
+                cs.namespace(|| format!("word {} value", index)),
+                scalar_type,
+                bits,
+            )?;
+            stack.push(value.into())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Checks the padding of the little-endian word bits and packs the value bits.
+fn decode_word<E, CS>(mut cs: CS, scalar_type: ScalarType, bits: Vec<Boolean>) -> Result<Scalar<E>>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    let (bitlength, padding) = match scalar_type {
+        ScalarType::Boolean => (1, Boolean::constant(false)),
+        ScalarType::Integer(IntegerType {
+            is_signed: false,
+            bitlength,
+        }) => (bitlength, Boolean::constant(false)),
+        ScalarType::Integer(IntegerType {
+            is_signed: true,
+            bitlength,
+        }) => (bitlength, bits[bitlength - 1].clone()),
+        ScalarType::Field => (E::Fr::NUM_BITS as usize, Boolean::constant(false)),
+    };
+
+    for (index, bit) in bits[bitlength..].iter().enumerate() {
+        Boolean::enforce_equal(cs.namespace(|| format!("padding {}", index)), bit, &padding)?;
+    }
+
+    let mut value_bits = Vec::from(&bits[..bitlength]);
+    if scalar_type.is_signed() {
+        // the two's complement is shifted to be non-negative, like in `SignedFromBits`
+        value_bits.push(padding.not());
+    }
+
+    let num =
+        AllocatedNum::pack_bits_to_element(cs.namespace(|| "pack_bits_to_element"), &value_bits)?;
+
+    let num = if scalar_type.is_signed() {
+        let base_value = BigInt::from(1) << bitlength;
+        let base = Expression::<E>::constant::<CS>(
+            utils::bigint_to_fr::<E>(&base_value).expect("length is too big"),
+        );
+        (Expression::from(&num) - base).into_number(cs.namespace(|| "signed"))?
+    } else {
+        num
+    };
+
+    if let ScalarType::Field = scalar_type {
+        // the words greater than the field modulus would be reduced, so the strict
+        // decomposition of the value must give the same bits
+        let strict_bits =
+            Expression::from(&num).into_bits_le_strict(cs.namespace(|| "into_bits_le_strict"))?;
+        for (index, (bit, strict_bit)) in bits.iter().zip(strict_bits.iter()).enumerate() {
+            Boolean::enforce_equal(
+                cs.namespace(|| format!("canonical {}", index)),
+                bit,
+                strict_bit,
+            )?;
+        }
+    }
+
+    Ok(Scalar::new_unchecked_variable(
+        num.get_value(),
+        num.get_variable(),
+        scalar_type,
+    ))
+}
@@ -0,0 +1,119 @@
+use crate::core::EvaluationStack;
+use crate::gadgets::{IntegerType, Scalar, ScalarType};
+use crate::stdlib::bits::signed_to_bits;
+use crate::stdlib::eth::ABI_WORD_BITS;
+use crate::stdlib::NativeFunction;
+use crate::{Engine, Result};
+use bellman::ConstraintSystem;
+use franklin_crypto::circuit::boolean::Boolean;
+use franklin_crypto::circuit::num::AllocatedNum;
+use num_bigint::BigInt;
+
+const BYTE_BITS: usize = 8;
+
+/// Encodes the scalars into the 32-byte big-endian Solidity ABI words.
+///
+/// The unsigned integers, fields, and booleans are padded with zeros, and the signed
+/// integers are sign-extended, as Solidity does for the `uintN`, `intN`, and `bool` types.
+pub struct AbiEncode {
+    values_count: usize,
+}
+
+impl AbiEncode {
+    pub fn new(inputs_count: usize) -> Result<Self> {
+        Ok(Self {
+            values_count: inputs_count,
+        })
+    }
+}
+
+impl<E: Engine> NativeFunction<E> for AbiEncode {
+    fn execute<CS: ConstraintSystem<E>>(
+        &self,
+        mut cs: CS,
+        stack: &mut EvaluationStack<E>,
+    ) -> Result {
+        let mut values = Vec::with_capacity(self.values_count);
+        for _ in 0..self.values_count {
+            values.push(stack.pop()?.value()?);
+        }
+        values.reverse();
+
+        for (index, value) in values.into_iter().enumerate() {
+            let bits = word_bits(cs.namespace(|| format!("word {} bits", index)), value)?;
+
+            // the most significant byte goes first
+            for (byte_index, byte_bits) in bits.chunks(BYTE_BITS).rev().enumerate() {
+                let byte = pack_byte(
+                    cs.namespace(|| format!("word {} byte {}", index, byte_index)),
+                    byte_bits,
+                )?;
+                stack.push(byte.into())?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the little-endian bits of the ABI word the scalar is encoded into.
+fn word_bits<E, CS>(mut cs: CS, value: Scalar<E>) -> Result<Vec<Boolean>>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    let expression = value.to_expression::<CS>();
+
+    let (mut bits, padding) = match value.get_type() {
+        ScalarType::Boolean => (
+            vec![value.to_boolean(cs.namespace(|| "to_boolean"))?],
+            Boolean::constant(false),
+        ),
+        ScalarType::Integer(IntegerType {
+            is_signed: false,
+            bitlength,
+        }) => (
+            expression.into_bits_le_fixed(cs.namespace(|| "into_bits_le_fixed"), bitlength)?,
+            Boolean::constant(false),
+        ),
+        ScalarType::Integer(IntegerType {
+            is_signed: true, ..
+        }) => {
+            let bits = signed_to_bits(cs.namespace(|| "signed_to_bits"), value)?;
+            let sign_bit = bits[bits.len() - 1].clone();
+            (bits, sign_bit)
+        }
+        ScalarType::Field => (
+            expression.into_bits_le_strict(cs.namespace(|| "into_bits_le_strict"))?,
+            Boolean::constant(false),
+        ),
+    };
+
+    bits.resize(ABI_WORD_BITS, padding);
+    Ok(bits)
+}
+
+/// Packs the little-endian bits into a byte, which is a constant if all the bits are.
+fn pack_byte<E, CS>(mut cs: CS, bits: &[Boolean]) -> Result<Scalar<E>>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    let constant = bits.iter().rev().try_fold(0u8, |byte, bit| match bit {
+        Boolean::Constant(value) => Some((byte << 1) | (*value as u8)),
+        _ => None,
+    });
+
+    match constant {
+        Some(byte) => Scalar::new_constant_bigint(&BigInt::from(byte), IntegerType::U8.into()),
+        None => {
+            let num =
+                AllocatedNum::pack_bits_to_element(cs.namespace(|| "pack_bits_to_element"), bits)?;
+            Ok(Scalar::new_unchecked_variable(
+                num.get_value(),
+                num.get_variable(),
+                IntegerType::U8.into(),
+            ))
+        }
+    }
+}
@@ -0,0 +1,11 @@
+/// The Solidity ABI word length in bits.
+pub const ABI_WORD_BITS: usize = 256;
+
+/// The Solidity ABI word length in bytes.
+pub const ABI_WORD_BYTES: usize = 32;
+
+mod abi_decode;
+pub use abi_decode::*;
+
+mod abi_encode;
+pub use abi_encode::*;
@@ -0,0 +1,99 @@
+use std::any::Any;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use bellman::ConstraintSystem;
+use lazy_static::lazy_static;
+
+use crate::constraint_systems::DynConstraintSystem;
+use crate::core::EvaluationStack;
+use crate::gadgets::Scalar;
+use crate::stdlib::NativeFunction;
+use crate::{Engine, MalformedBytecode, Result, RuntimeError};
+
+/// The native gadget implemented by a downstream crate, which is called from the
+/// circuits as the `std::ext` function with the same identifier.
+///
+/// The function signature must be declared to the compiler with `zinc_compiler::Extension`.
+pub trait NativeExtension<E: Engine>: Send + Sync {
+    /// Synthesizes the gadget for the flattened arguments, returning the flattened result.
+    fn execute(&self, cs: DynConstraintSystem<E>, inputs: Vec<Scalar<E>>)
+        -> Result<Vec<Scalar<E>>>;
+}
+
+/// The extensions by identifier, stored as `Arc<dyn NativeExtension<E>>`, since the
+/// engine is only known when they are called.
+type Extensions = HashMap<String, Box<dyn Any + Send + Sync>>;
+
+lazy_static! {
+    static ref EXTENSIONS: RwLock<Extensions> = RwLock::new(HashMap::new());
+}
+
+/// Registers the gadget called by the `call_extension` instructions with the
+/// `identifier`, replacing the existing one if any.
+pub fn register_extension<E: Engine>(identifier: &str, extension: Arc<dyn NativeExtension<E>>) {
+    EXTENSIONS
+        .write()
+        .expect("Mutexes never panic")
+        .insert(identifier.to_owned(), Box::new(extension));
+}
+
+/// Calls the extension, popping its arguments and pushing its result.
+pub struct Extension<E: Engine> {
+    identifier: String,
+    extension: Arc<dyn NativeExtension<E>>,
+    inputs_count: usize,
+    outputs_count: usize,
+}
+
+impl<E: Engine> Extension<E> {
+    pub fn new(identifier: &str, inputs_count: usize, outputs_count: usize) -> Result<Self> {
+        let extension = EXTENSIONS
+            .read()
+            .expect("Mutexes never panic")
+            .get(identifier)
+            .and_then(|extension| extension.downcast_ref::<Arc<dyn NativeExtension<E>>>())
+            .cloned()
+            .ok_or_else(|| RuntimeError::UnknownExtension(identifier.to_owned()))?;
+
+        Ok(Self {
+            identifier: identifier.to_owned(),
+            extension,
+            inputs_count,
+            outputs_count,
+        })
+    }
+}
+
+impl<E: Engine> NativeFunction<E> for Extension<E> {
+    fn execute<CS: ConstraintSystem<E>>(
+        &self,
+        mut cs: CS,
+        stack: &mut EvaluationStack<E>,
+    ) -> Result {
+        let mut inputs = Vec::with_capacity(self.inputs_count);
+        for _ in 0..self.inputs_count {
+            inputs.push(stack.pop()?.value()?);
+        }
+        inputs.reverse();
+
+        let outputs = self
+            .extension
+            .execute(DynConstraintSystem::new(&mut cs), inputs)?;
+        if outputs.len() != self.outputs_count {
+            return Err(MalformedBytecode::InvalidArguments(format!(
+                "extension `{}` returned {} values, but {} are expected",
+                self.identifier,
+                outputs.len(),
+                self.outputs_count
+            ))
+            .into());
+        }
+
+        for output in outputs.into_iter() {
+            stack.push(output.into())?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,159 @@
+use crate::core::EvaluationStack;
+use crate::gadgets::{Scalar, ScalarType, ScalarTypeExpectation};
+use crate::stdlib::NativeFunction;
+use crate::{gadgets, Engine, MalformedBytecode, Result, RuntimeError};
+use bellman::ConstraintSystem;
+use num_bigint::BigInt;
+
+/// The rounding modes in the order of the `std::math::Rounding` variant values.
+const ROUNDING_FLOOR: usize = 0;
+const ROUNDING_CEIL: usize = 1;
+const ROUNDING_NEAREST: usize = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FixedOperation {
+    Mul,
+    Div,
+}
+
+/// Multiplies or divides two fixed-point decimals represented as integers with `frac_bits`
+/// fractional bits.
+///
+/// The exact result is the quotient of the Euclidean division, e.g. `(a * b) / 2^frac_bits`,
+/// adjusted by one depending on the remainder, the divisor sign, and the rounding mode.
+/// The result type check is done under the current condition, so the unreachable branches
+/// never fail the proof.
+pub struct Fixed<E: Engine> {
+    operation: FixedOperation,
+    condition: Scalar<E>,
+}
+
+impl<E: Engine> Fixed<E> {
+    pub fn new(operation: FixedOperation, condition: Scalar<E>) -> Self {
+        Self {
+            operation,
+            condition,
+        }
+    }
+}
+
+impl<E: Engine> NativeFunction<E> for Fixed<E> {
+    fn execute<CS: ConstraintSystem<E>>(
+        &self,
+        mut cs: CS,
+        stack: &mut EvaluationStack<E>,
+    ) -> Result {
+        let rounding = stack.pop()?.value()?.get_constant_usize()?;
+        let frac_bits = stack.pop()?.value()?.get_constant_usize()?;
+        let right = stack.pop()?.value()?;
+        let left = stack.pop()?.value()?;
+
+        let scalar_type = ScalarType::expect_same(left.get_type(), right.get_type())?;
+        let integer_type = match scalar_type {
+            ScalarType::Integer(integer_type) => integer_type,
+            scalar_type => {
+                return Err(RuntimeError::TypeError {
+                    expected: "integer type".to_owned(),
+                    actual: scalar_type.to_string(),
+                })
+            }
+        };
+        if frac_bits > integer_type.bitlength {
+            return Err(MalformedBytecode::InvalidArguments(
+                "math::fixed: the fractional bits do not fit into the integer".into(),
+            )
+            .into());
+        }
+
+        let scale =
+            Scalar::new_constant_bigint(&(BigInt::from(1) << frac_bits), ScalarType::Field)?;
+        let (numerator, denominator) = match self.operation {
+            FixedOperation::Mul => (
+                gadgets::mul(cs.namespace(|| "product"), &left, &right)?,
+                scale,
+            ),
+            FixedOperation::Div => (
+                gadgets::mul(cs.namespace(|| "scaled"), &left, &scale)?,
+                right,
+            ),
+        };
+        let numerator = numerator.with_type_unchecked(scalar_type);
+
+        let (quotient, remainder) = gadgets::div_rem_conditional(
+            cs.namespace(|| "div_rem"),
+            &self.condition,
+            &numerator,
+            &denominator,
+        )?;
+
+        // the Euclidean remainder is non-negative, so the quotient is rounded towards
+        // the negative infinity if the divisor is positive, and otherwise towards the positive one
+        let zero = Scalar::new_constant_int(0, ScalarType::Field);
+        let is_inexact = gadgets::ne(cs.namespace(|| "is_inexact"), &remainder, &zero)?;
+        let is_negative = if integer_type.is_signed && self.operation == FixedOperation::Div {
+            gadgets::lt(
+                cs.namespace(|| "is_negative"),
+                &denominator,
+                &Scalar::new_constant_int(0, scalar_type),
+            )?
+        } else {
+            Scalar::new_constant_bool(false)
+        };
+        let is_positive = gadgets::not(cs.namespace(|| "is_positive"), &is_negative)?;
+
+        let (round_up, round_down) = match rounding {
+            ROUNDING_FLOOR => (
+                Scalar::new_constant_bool(false),
+                gadgets::and(cs.namespace(|| "round_down"), &is_negative, &is_inexact)?,
+            ),
+            ROUNDING_CEIL => (
+                gadgets::and(cs.namespace(|| "round_up"), &is_positive, &is_inexact)?,
+                Scalar::new_constant_bool(false),
+            ),
+            ROUNDING_NEAREST => {
+                // the ties are rounded towards the positive infinity
+                let abs_denominator = gadgets::abs(cs.namespace(|| "abs"), &denominator)?;
+                let twice_remainder =
+                    gadgets::add(cs.namespace(|| "twice_remainder"), &remainder, &remainder)?;
+                let is_half_or_more = gadgets::ge(
+                    cs.namespace(|| "is_half_or_more"),
+                    &twice_remainder,
+                    &abs_denominator.as_field(),
+                )?;
+                let is_more_than_half = gadgets::gt(
+                    cs.namespace(|| "is_more_than_half"),
+                    &twice_remainder,
+                    &abs_denominator.as_field(),
+                )?;
+                (
+                    gadgets::and(cs.namespace(|| "round_up"), &is_positive, &is_half_or_more)?,
+                    gadgets::and(
+                        cs.namespace(|| "round_down"),
+                        &is_negative,
+                        &is_more_than_half,
+                    )?,
+                )
+            }
+            rounding => {
+                return Err(MalformedBytecode::InvalidArguments(format!(
+                    "math::fixed: unknown rounding mode {}",
+                    rounding
+                ))
+                .into())
+            }
+        };
+
+        let result = gadgets::add(cs.namespace(|| "up"), &quotient, &round_up.as_field())?;
+        let result = gadgets::sub(cs.namespace(|| "down"), &result, &round_down.as_field())?;
+        let result = gadgets::conditional_type_check(
+            cs.namespace(|| "type check"),
+            &self.condition,
+            &result,
+            scalar_type,
+        )?;
+
+        stack.push(result.into())?;
+
+        Ok(())
+    }
+}
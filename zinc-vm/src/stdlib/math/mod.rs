@@ -0,0 +1,8 @@
+mod fixed;
+mod overflowing;
+mod select;
+mod sign;
+pub use fixed::*;
+pub use overflowing::*;
+pub use select::*;
+pub use sign::*;
@@ -0,0 +1,129 @@
+use crate::core::EvaluationStack;
+use crate::gadgets::{IntegerType, Scalar, ScalarType, ScalarTypeExpectation};
+use crate::stdlib::NativeFunction;
+use crate::{gadgets, Engine, Result, RuntimeError};
+use bellman::ConstraintSystem;
+use franklin_crypto::circuit::num::AllocatedNum;
+use num_bigint::BigInt;
+
+#[derive(Debug, Clone, Copy)]
+pub enum OverflowingOperation {
+    Add,
+    Sub,
+    Mul,
+}
+
+/// Performs the operation on two integers of the same type, returning the result wrapped
+/// around the type bounds and whether it has overflowed.
+///
+/// The exact result is shifted by a multiple of `2^N` to be non-negative and decomposed into
+/// bits. The lower `N` bits are the wrapped value, and the result has not overflowed only if
+/// the upper bits are equal to the multiple the result has been shifted by.
+///
+/// The wrapping variant returns only the wrapped value and skips the overflow check.
+pub struct Overflowing {
+    operation: OverflowingOperation,
+    is_wrapping: bool,
+}
+
+impl Overflowing {
+    pub fn new(operation: OverflowingOperation) -> Self {
+        Self {
+            operation,
+            is_wrapping: false,
+        }
+    }
+
+    pub fn new_wrapping(operation: OverflowingOperation) -> Self {
+        Self {
+            operation,
+            is_wrapping: true,
+        }
+    }
+
+    /// Returns the multiple of `2^bitlength` to shift the exact result by, and the number of
+    /// bits the shifted result is decomposed into.
+    fn bounds(&self, integer_type: IntegerType) -> (BigInt, usize) {
+        let bitlength = integer_type.bitlength;
+        match (self.operation, integer_type.is_signed) {
+            (OverflowingOperation::Add, false) => (BigInt::from(0), bitlength + 2),
+            (OverflowingOperation::Add, true) => (BigInt::from(1), bitlength + 2),
+            (OverflowingOperation::Sub, _) => (BigInt::from(1), bitlength + 2),
+            (OverflowingOperation::Mul, false) => (BigInt::from(0), bitlength * 2),
+            (OverflowingOperation::Mul, true) => {
+                (BigInt::from(1) << (bitlength - 2), bitlength * 2)
+            }
+        }
+    }
+}
+
+impl<E: Engine> NativeFunction<E> for Overflowing {
+    fn execute<CS: ConstraintSystem<E>>(
+        &self,
+        mut cs: CS,
+        stack: &mut EvaluationStack<E>,
+    ) -> Result {
+        let right = stack.pop()?.value()?;
+        let left = stack.pop()?.value()?;
+
+        let scalar_type = ScalarType::expect_same(left.get_type(), right.get_type())?;
+        let integer_type = match scalar_type {
+            ScalarType::Integer(integer_type) => integer_type,
+            scalar_type => {
+                return Err(RuntimeError::TypeError {
+                    expected: "integer type".to_owned(),
+                    actual: scalar_type.to_string(),
+                })
+            }
+        };
+        let bitlength = integer_type.bitlength;
+
+        let result = match self.operation {
+            OverflowingOperation::Add => gadgets::add(cs.namespace(|| "add"), &left, &right)?,
+            OverflowingOperation::Sub => gadgets::sub(cs.namespace(|| "sub"), &left, &right)?,
+            OverflowingOperation::Mul => gadgets::mul(cs.namespace(|| "mul"), &left, &right)?,
+        };
+
+        // the signed results are shifted by `2^(N-1)` to wrap them as the unsigned ones
+        let sign_offset = if integer_type.is_signed {
+            BigInt::from(1) << (bitlength - 1)
+        } else {
+            BigInt::from(0)
+        };
+        let (multiple, bits_count) = self.bounds(integer_type);
+        let offset = Scalar::new_constant_bigint(
+            &(&sign_offset + (&multiple << bitlength)),
+            ScalarType::Field,
+        )?;
+        let shifted = gadgets::add(cs.namespace(|| "shifted"), &result, &offset)?;
+
+        let bits = shifted
+            .to_expression::<CS>()
+            .into_bits_le_fixed(cs.namespace(|| "bits"), bits_count)?;
+
+        let lower =
+            AllocatedNum::pack_bits_to_element(cs.namespace(|| "lower"), &bits[..bitlength])?;
+        let sign_offset = Scalar::new_constant_bigint(&sign_offset, ScalarType::Field)?;
+        let wrapped = gadgets::sub(
+            cs.namespace(|| "wrapped"),
+            &Scalar::from(lower),
+            &sign_offset,
+        )?
+        .with_type_unchecked(scalar_type);
+
+        if self.is_wrapping {
+            stack.push(wrapped.into())?;
+            return Ok(());
+        }
+
+        let upper =
+            AllocatedNum::pack_bits_to_element(cs.namespace(|| "upper"), &bits[bitlength..])?;
+        let multiple = Scalar::new_constant_bigint(&multiple, ScalarType::Field)?;
+        let overflow = gadgets::ne(cs.namespace(|| "overflow"), &Scalar::from(upper), &multiple)?;
+
+        stack.push(wrapped.into())?;
+        stack.push(overflow.into())?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,104 @@
+use crate::core::EvaluationStack;
+use crate::gadgets::{Scalar, ScalarType};
+use crate::stdlib::NativeFunction;
+use crate::{gadgets, Engine, MalformedBytecode, Result, RuntimeError};
+use bellman::ConstraintSystem;
+
+/// The operations in the order of the `std::math` select function operation values.
+const OPERATION_MIN: usize = 0;
+const OPERATION_MAX: usize = 1;
+const OPERATION_CLAMP: usize = 2;
+const OPERATION_ABS: usize = 3;
+
+/// Computes `min`, `max`, `clamp`, or `abs` with a comparison and a conditional select
+/// per operand, which is cheaper than the branching user code with the same semantics.
+///
+/// The operation is the constant passed after the arguments.
+///
+/// The absolute value of the minimal signed integer does not fit into its type, so the result
+/// type check is done under the current condition, so the unreachable branches never fail
+/// the proof.
+pub struct Select<E: Engine> {
+    condition: Scalar<E>,
+}
+
+impl<E: Engine> Select<E> {
+    pub fn new(condition: Scalar<E>) -> Self {
+        Self { condition }
+    }
+}
+
+impl<E: Engine> NativeFunction<E> for Select<E> {
+    fn execute<CS: ConstraintSystem<E>>(
+        &self,
+        mut cs: CS,
+        stack: &mut EvaluationStack<E>,
+    ) -> Result {
+        let operation = stack.pop()?.value()?.get_constant_usize()?;
+        let result = match operation {
+            OPERATION_MIN => {
+                let right = stack.pop()?.value()?;
+                let left = stack.pop()?.value()?;
+
+                let is_less = gadgets::lt(cs.namespace(|| "lt"), &right, &left)?;
+                gadgets::conditional_select(cs.namespace(|| "select"), &is_less, &right, &left)?
+            }
+            OPERATION_MAX => {
+                let right = stack.pop()?.value()?;
+                let left = stack.pop()?.value()?;
+
+                let is_greater = gadgets::gt(cs.namespace(|| "gt"), &right, &left)?;
+                gadgets::conditional_select(cs.namespace(|| "select"), &is_greater, &right, &left)?
+            }
+            OPERATION_CLAMP => {
+                let high = stack.pop()?.value()?;
+                let low = stack.pop()?.value()?;
+                let value = stack.pop()?.value()?;
+
+                // the lower bound is checked last, so it wins if it is greater than the upper one
+                let is_greater = gadgets::gt(cs.namespace(|| "gt"), &value, &high)?;
+                let is_less = gadgets::lt(cs.namespace(|| "lt"), &value, &low)?;
+                let result = gadgets::conditional_select(
+                    cs.namespace(|| "select high"),
+                    &is_greater,
+                    &high,
+                    &value,
+                )?;
+                gadgets::conditional_select(cs.namespace(|| "select low"), &is_less, &low, &result)?
+            }
+            OPERATION_ABS => {
+                let value = stack.pop()?.value()?;
+
+                let scalar_type = value.get_type();
+                match scalar_type {
+                    ScalarType::Integer(integer_type) if integer_type.is_signed => {}
+                    scalar_type => {
+                        return Err(RuntimeError::TypeError {
+                            expected: "signed integer type".to_owned(),
+                            actual: scalar_type.to_string(),
+                        })
+                    }
+                }
+
+                let result = gadgets::abs(cs.namespace(|| "abs"), &value)?;
+                gadgets::conditional_type_check(
+                    cs.namespace(|| "type check"),
+                    &self.condition,
+                    &result,
+                    scalar_type,
+                )?
+            }
+            operation => {
+                return Err(MalformedBytecode::InvalidArguments(format!(
+                    "math::select: unknown operation {}",
+                    operation
+                ))
+                .into())
+            }
+        };
+
+        stack.push(result.into())?;
+
+        Ok(())
+    }
+}
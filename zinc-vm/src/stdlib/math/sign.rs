@@ -0,0 +1,46 @@
+use crate::core::EvaluationStack;
+use crate::gadgets::{IntegerType, Scalar, ScalarType};
+use crate::stdlib::NativeFunction;
+use crate::{gadgets, Engine, Result, RuntimeError};
+use bellman::ConstraintSystem;
+
+/// Returns `-1`, `0`, or `1` as `i8` depending on the sign of a signed integer.
+///
+/// The result is the difference of the `value > 0` and `value < 0` comparison bits, so it is
+/// always in range and needs no type check.
+pub struct Sign;
+
+impl<E: Engine> NativeFunction<E> for Sign {
+    fn execute<CS: ConstraintSystem<E>>(
+        &self,
+        mut cs: CS,
+        stack: &mut EvaluationStack<E>,
+    ) -> Result {
+        let value = stack.pop()?.value()?;
+
+        let scalar_type = value.get_type();
+        match scalar_type {
+            ScalarType::Integer(integer_type) if integer_type.is_signed => {}
+            scalar_type => {
+                return Err(RuntimeError::TypeError {
+                    expected: "signed integer type".to_owned(),
+                    actual: scalar_type.to_string(),
+                })
+            }
+        }
+
+        let zero = Scalar::new_constant_int(0, scalar_type);
+        let is_positive = gadgets::gt(cs.namespace(|| "gt"), &value, &zero)?;
+        let is_negative = gadgets::lt(cs.namespace(|| "lt"), &value, &zero)?;
+        let result = gadgets::sub(
+            cs.namespace(|| "sign"),
+            &is_positive.as_field(),
+            &is_negative.as_field(),
+        )?
+        .with_type_unchecked(IntegerType::I8.into());
+
+        stack.push(result.into())?;
+
+        Ok(())
+    }
+}
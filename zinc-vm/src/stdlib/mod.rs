@@ -1,7 +1,12 @@
 pub mod array;
+pub mod assert_in_range;
 pub mod bits;
 pub mod crypto;
+pub mod eth;
+pub mod extension;
 pub mod ff;
+pub mod math;
+pub mod table;
 
 use crate::core::EvaluationStack;
 use crate::{Engine, Result};
@@ -0,0 +1,120 @@
+use crate::core::EvaluationStack;
+use crate::gadgets::utils::math;
+use crate::gadgets::{utils, IntegerType, Scalar};
+use crate::stdlib::NativeFunction;
+use crate::{gadgets, Engine, MalformedBytecode, Result, RuntimeError};
+use bellman::ConstraintSystem;
+use ff::Field;
+use num_bigint::BigInt;
+use num_traits::ToPrimitive;
+
+/// Reads a table element at the index, which is decomposed into `log2(N)` bits selecting
+/// the element with a binary tree of conditional selects.
+///
+/// The bit decomposition itself enforces the index to be within a table of a power-of-two
+/// size, whereas the other sizes take an extra comparison. In the unreachable branches the
+/// index is replaced with zero, so an arbitrary value there never fails the proof.
+/// A constant index takes no constraints at all.
+pub struct Lookup<E: Engine> {
+    table_size: usize,
+    condition: Scalar<E>,
+}
+
+impl<E: Engine> Lookup<E> {
+    pub fn new(inputs_count: usize, condition: Scalar<E>) -> Result<Self> {
+        match inputs_count.checked_sub(1) {
+            Some(table_size) if table_size > 0 => Ok(Self {
+                table_size,
+                condition,
+            }),
+            _ => Err(MalformedBytecode::InvalidArguments(
+                "table::lookup expects at least 2 arguments".into(),
+            )
+            .into()),
+        }
+    }
+}
+
+impl<E: Engine> NativeFunction<E> for Lookup<E> {
+    fn execute<CS: ConstraintSystem<E>>(
+        &self,
+        mut cs: CS,
+        stack: &mut EvaluationStack<E>,
+    ) -> Result {
+        let index = stack.pop()?.value()?;
+
+        let mut table = Vec::with_capacity(self.table_size);
+        for _ in 0..self.table_size {
+            table.push(stack.pop()?.value()?);
+        }
+        table.reverse();
+
+        if index.is_constant() {
+            let index = index.get_constant_usize()?;
+            if index >= self.table_size {
+                return Err(RuntimeError::IndexOutOfBounds {
+                    lower_bound: 0,
+                    upper_bound: self.table_size,
+                    actual: index,
+                });
+            }
+
+            stack.push(table[index].clone().into())?;
+            return Ok(());
+        }
+
+        let is_executed = self.condition.get_value().map(|value| !value.is_zero());
+        if let (Some(true), Some(value)) = (is_executed, index.get_value()) {
+            let value = utils::fr_to_bigint(&value, false);
+            if value >= BigInt::from(self.table_size) {
+                return Err(RuntimeError::IndexOutOfBounds {
+                    lower_bound: 0,
+                    upper_bound: self.table_size,
+                    actual: value.to_usize().unwrap_or(usize::MAX),
+                });
+            }
+        }
+
+        let zero = Scalar::new_constant_int(0, index.get_type());
+        let index =
+            gadgets::conditional_select(cs.namespace(|| "index"), &self.condition, &index, &zero)?;
+
+        let bits_count = math::log2ceil(self.table_size);
+        let bits = index
+            .to_expression::<CS>()
+            .into_bits_le_fixed(cs.namespace(|| "bits"), bits_count)?;
+
+        if !self.table_size.is_power_of_two() {
+            // both the index and the size fit into `bits_count` bits here
+            let bounds_type = IntegerType {
+                is_signed: false,
+                bitlength: bits_count,
+            }
+            .into();
+            let length = Scalar::new_constant_int(self.table_size, bounds_type);
+            let is_in_range = gadgets::lt(
+                cs.namespace(|| "lt"),
+                &index.with_type_unchecked(bounds_type),
+                &length,
+            )?;
+            cs.enforce(
+                || "in range",
+                |lc| lc + &is_in_range.lc::<CS>(),
+                |lc| lc + CS::one(),
+                |lc| lc + CS::one(),
+            );
+        }
+
+        let bits_be = bits
+            .into_iter()
+            .rev()
+            .enumerate()
+            .map(|(i, bit)| Scalar::from_boolean(cs.namespace(|| format!("bit {}", i)), bit))
+            .collect::<Result<Vec<Scalar<E>>>>()?;
+        let result = gadgets::recursive_select(cs.namespace(|| "select"), &bits_be, &table)?;
+
+        stack.push(result.into())?;
+
+        Ok(())
+    }
+}
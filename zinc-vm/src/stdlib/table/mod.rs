@@ -0,0 +1,2 @@
+mod lookup;
+pub use self::lookup::*;
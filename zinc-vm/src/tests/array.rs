@@ -0,0 +1,109 @@
+use crate::{run_checked, RuntimeError};
+use num_bigint::BigInt;
+use pairing::bn256::Bn256;
+use zinc_bytecode::data::types::DataType;
+use zinc_bytecode::data::values::Value;
+use zinc_bytecode::instructions::*;
+use zinc_bytecode::scalar::IntegerType;
+use zinc_bytecode::{InstructionInfo, Program};
+
+/// The program storing `42` into the array `[10, 20, 30]` at its witness `u8` input index.
+fn program() -> Program {
+    let integer = DataType::Scalar(IntegerType::U8.into());
+    super::program(
+        integer.clone(),
+        DataType::Array(Box::new(integer), 3),
+        vec![
+            PushConst::new(10.into(), IntegerType::U8.into()).wrap(),
+            Store::new(1).wrap(),
+            PushConst::new(20.into(), IntegerType::U8.into()).wrap(),
+            Store::new(2).wrap(),
+            PushConst::new(30.into(), IntegerType::U8.into()).wrap(),
+            Store::new(3).wrap(),
+            Load::new(0).wrap(),
+            PushConst::new(42.into(), IntegerType::U8.into()).wrap(),
+            StoreByIndex::new(1, 3).wrap(),
+            Load::new(1).wrap(),
+            Load::new(2).wrap(),
+            Load::new(3).wrap(),
+            Return::new(3).wrap(),
+        ],
+    )
+}
+
+/// The program storing `42` into the array `[10, 20, 30]` at its witness `u8` input index,
+/// guarded with `if index < 3`.
+fn guarded_program() -> Program {
+    let integer = DataType::Scalar(IntegerType::U8.into());
+    super::program(
+        integer.clone(),
+        DataType::Array(Box::new(integer), 3),
+        vec![
+            PushConst::new(10.into(), IntegerType::U8.into()).wrap(),
+            Store::new(1).wrap(),
+            PushConst::new(20.into(), IntegerType::U8.into()).wrap(),
+            Store::new(2).wrap(),
+            PushConst::new(30.into(), IntegerType::U8.into()).wrap(),
+            Store::new(3).wrap(),
+            Load::new(0).wrap(),
+            PushConst::new(3.into(), IntegerType::U8.into()).wrap(),
+            Lt.wrap(),
+            If.wrap(),
+            Load::new(0).wrap(),
+            PushConst::new(42.into(), IntegerType::U8.into()).wrap(),
+            StoreByIndex::new(1, 3).wrap(),
+            EndIf.wrap(),
+            Load::new(1).wrap(),
+            Load::new(2).wrap(),
+            Load::new(3).wrap(),
+            Return::new(3).wrap(),
+        ],
+    )
+}
+
+fn input(index: u8) -> Value {
+    super::value(
+        &DataType::Scalar(IntegerType::U8.into()),
+        &[u64::from(index)],
+    )
+}
+
+#[test]
+fn store_by_witness_index_ok() {
+    for (index, expected) in [[42, 20, 30], [10, 42, 30], [10, 20, 42]]
+        .iter()
+        .enumerate()
+    {
+        let output =
+            run_checked::<Bn256>(&program(), &input(index as u8)).expect("program must succeed");
+
+        assert_eq!(
+            output.to_flat_values(),
+            expected
+                .iter()
+                .copied()
+                .map(BigInt::from)
+                .collect::<Vec<BigInt>>()
+        );
+    }
+}
+
+#[test]
+fn store_by_witness_index_out_of_bounds_fail() {
+    let res = run_checked::<Bn256>(&program(), &input(3));
+
+    match res.err().expect("expected index out of bounds error") {
+        RuntimeError::AssertionError(ref message) if message == "index out of bounds" => {}
+        err => panic!("expected index out of bounds error, got {:?} instead", err),
+    }
+}
+
+#[test]
+fn store_by_witness_index_out_of_bounds_untaken_branch_ok() {
+    let output = run_checked::<Bn256>(&guarded_program(), &input(3)).expect("program must succeed");
+
+    assert_eq!(
+        output.to_flat_values(),
+        vec![BigInt::from(10), BigInt::from(20), BigInt::from(30)]
+    );
+}
@@ -0,0 +1,44 @@
+use crate::{run_checked_with_coverage, RuntimeError};
+use pairing::bn256::Bn256;
+use zinc_bytecode::data::types::DataType;
+use zinc_bytecode::data::values::Value;
+use zinc_bytecode::instructions::*;
+use zinc_bytecode::scalar::{IntegerType, ScalarType};
+use zinc_bytecode::{InstructionInfo, Program};
+
+fn program(condition: bool) -> Program {
+    super::program(
+        DataType::Unit,
+        DataType::Unit,
+        vec![
+            Call::new(5, 0).wrap(),
+            Call::new(5, 0).wrap(),
+            Return::new(0).wrap(),
+            PushConst::new((condition as u8).into(), ScalarType::Boolean).wrap(),
+            Assert::new(None).wrap(),
+            Return::new(0).wrap(),
+            PushConst::new(2.into(), IntegerType::U8.into()).wrap(),
+            Pop::new(1).wrap(),
+            Return::new(0).wrap(),
+        ],
+    )
+}
+
+#[test]
+fn coverage_hits() {
+    let (result, coverage) = run_checked_with_coverage::<Bn256>(&program(true), &Value::Unit);
+
+    result.expect("program must succeed");
+    assert_eq!(coverage.hits, vec![1, 1, 1, 1, 1, 2, 2, 2, 0, 0, 0]);
+}
+
+#[test]
+fn coverage_on_failure() {
+    let (result, coverage) = run_checked_with_coverage::<Bn256>(&program(false), &Value::Unit);
+
+    match result.err().expect("expected assertion error") {
+        RuntimeError::AssertionError(_) => {}
+        err => panic!("expected assertion error, got {:?} instead", err),
+    }
+    assert_eq!(coverage.hits, vec![1, 0, 1, 0, 0, 1, 1, 0, 0, 0, 0]);
+}
@@ -0,0 +1,84 @@
+use crate::{run_checked, RuntimeError};
+use num_bigint::BigInt;
+use pairing::bn256::Bn256;
+use zinc_bytecode::data::types::DataType;
+use zinc_bytecode::data::values::Value;
+use zinc_bytecode::instructions::*;
+use zinc_bytecode::scalar::{IntegerType, ScalarType};
+use zinc_bytecode::{Instruction, InstructionInfo, Program};
+
+/// The program calling the `expose` function with the `main` body in between.
+fn program(main: Vec<Instruction>) -> Program {
+    let expose_address = 2 + main.len() + 2;
+
+    let mut bytecode: Vec<Instruction> = main
+        .into_iter()
+        .map(|instruction| match instruction {
+            Instruction::Call(_) => Call::new(expose_address, 0).wrap(),
+            instruction => instruction,
+        })
+        .collect();
+    bytecode.extend(vec![
+        PushConst::new(7.into(), IntegerType::U8.into()).wrap(),
+        Return::new(1).wrap(),
+        PushConst::new(5.into(), IntegerType::U8.into()).wrap(),
+        Expose::new("x".to_owned(), DataType::Scalar(IntegerType::U8.into())).wrap(),
+        Return::new(0).wrap(),
+    ]);
+
+    super::program(
+        DataType::Unit,
+        DataType::Scalar(IntegerType::U8.into()),
+        bytecode,
+    )
+}
+
+fn call() -> Instruction {
+    Call::new(0, 0).wrap()
+}
+
+#[test]
+fn expose_ok() {
+    let output =
+        run_checked::<Bn256>(&program(vec![call()]), &Value::Unit).expect("program must succeed");
+
+    assert_eq!(output.to_flat_values(), vec![BigInt::from(7)]);
+}
+
+#[test]
+fn expose_missing_fail() {
+    let res = run_checked::<Bn256>(&program(vec![]), &Value::Unit);
+
+    match res.err().expect("expected missing expose error") {
+        RuntimeError::MissingExpose(ref name) if name == "x" => {}
+        err => panic!("expected missing expose error, got {:?} instead", err),
+    }
+}
+
+#[test]
+fn expose_duplicate_fail() {
+    let res = run_checked::<Bn256>(&program(vec![call(), call()]), &Value::Unit);
+
+    match res.err().expect("expected duplicate expose error") {
+        RuntimeError::DuplicateExpose(ref name) if name == "x" => {}
+        err => panic!("expected duplicate expose error, got {:?} instead", err),
+    }
+}
+
+#[test]
+fn expose_conditional_fail() {
+    let res = run_checked::<Bn256>(
+        &program(vec![
+            PushConst::new(1.into(), ScalarType::Boolean).wrap(),
+            If.wrap(),
+            call(),
+            EndIf.wrap(),
+        ]),
+        &Value::Unit,
+    );
+
+    match res.err().expect("expected conditional expose error") {
+        RuntimeError::ConditionalExpose(ref name) if name == "x" => {}
+        err => panic!("expected conditional expose error, got {:?} instead", err),
+    }
+}
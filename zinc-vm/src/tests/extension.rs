@@ -0,0 +1,76 @@
+use crate::constraint_systems::DynConstraintSystem;
+use crate::gadgets::{self, Scalar};
+use crate::stdlib::extension::{register_extension, NativeExtension};
+use crate::{run_checked, Result, RuntimeError};
+use num_bigint::BigInt;
+use pairing::bn256::Bn256;
+use std::sync::Arc;
+use zinc_bytecode::data::types::DataType;
+use zinc_bytecode::data::values::Value;
+use zinc_bytecode::instructions::*;
+use zinc_bytecode::scalar::ScalarType;
+use zinc_bytecode::{InstructionInfo, Program};
+
+/// Squares every argument.
+struct Square;
+
+impl NativeExtension<Bn256> for Square {
+    fn execute(
+        &self,
+        mut cs: DynConstraintSystem<Bn256>,
+        inputs: Vec<Scalar<Bn256>>,
+    ) -> Result<Vec<Scalar<Bn256>>> {
+        inputs
+            .iter()
+            .enumerate()
+            .map(|(index, input)| {
+                gadgets::mul(cs.namespace(|| format!("square {}", index)), input, input)
+            })
+            .collect()
+    }
+}
+
+/// The program passing its input to the extension and returning the result.
+fn program(identifier: &str) -> Program {
+    let field = DataType::Scalar(ScalarType::Field);
+    super::program(
+        DataType::Array(Box::new(field.clone()), 2),
+        DataType::Array(Box::new(field), 2),
+        vec![
+            Load::new(0).wrap(),
+            Load::new(1).wrap(),
+            CallExtension::new(identifier.to_owned(), 2, 2).wrap(),
+            Return::new(2).wrap(),
+        ],
+    )
+}
+
+fn input() -> Value {
+    super::value(
+        &DataType::Array(Box::new(DataType::Scalar(ScalarType::Field)), 2),
+        &[3, 4],
+    )
+}
+
+#[test]
+fn extension_ok() {
+    register_extension::<Bn256>("test_square", Arc::new(Square));
+
+    let output =
+        run_checked::<Bn256>(&program("test_square"), &input()).expect("program must succeed");
+
+    assert_eq!(
+        output.to_flat_values(),
+        vec![BigInt::from(9), BigInt::from(16)]
+    );
+}
+
+#[test]
+fn extension_unknown_fail() {
+    let res = run_checked::<Bn256>(&program("test_missing"), &input());
+
+    match res.err().expect("expected unknown extension error") {
+        RuntimeError::UnknownExtension(ref identifier) if identifier == "test_missing" => {}
+        err => panic!("expected unknown extension error, got {:?} instead", err),
+    }
+}
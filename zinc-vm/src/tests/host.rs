@@ -0,0 +1,88 @@
+use crate::{register_host_function, run_checked, RuntimeError};
+use num_bigint::BigInt;
+use pairing::bn256::Bn256;
+use zinc_bytecode::data::types::DataType;
+use zinc_bytecode::data::values::Value;
+use zinc_bytecode::instructions::*;
+use zinc_bytecode::scalar::IntegerType;
+use zinc_bytecode::{InstructionInfo, Program};
+
+/// The program passing its `u8` input to the host function and returning the result.
+fn program(identifier: &str) -> Program {
+    let integer = DataType::Scalar(IntegerType::U8.into());
+    super::program(
+        integer.clone(),
+        integer.clone(),
+        vec![
+            Load::new(0).wrap(),
+            CallHost::new(identifier.to_owned(), 1, integer).wrap(),
+            Return::new(1).wrap(),
+        ],
+    )
+}
+
+fn input(value: u8) -> Value {
+    super::value(
+        &DataType::Scalar(IntegerType::U8.into()),
+        &[u64::from(value)],
+    )
+}
+
+fn register_double(identifier: &str) {
+    register_host_function(identifier, |arguments| {
+        Ok(arguments
+            .iter()
+            .map(|argument| argument * BigInt::from(2))
+            .collect())
+    });
+}
+
+#[test]
+fn host_ok() {
+    register_double("test_double");
+
+    let output =
+        run_checked::<Bn256>(&program("test_double"), &input(21)).expect("program must succeed");
+
+    assert_eq!(output.to_flat_values(), vec![BigInt::from(42)]);
+}
+
+#[test]
+fn host_out_of_range_fail() {
+    register_double("test_double_overflow");
+
+    let res = run_checked::<Bn256>(&program("test_double_overflow"), &input(200));
+
+    match res.err().expect("expected overflow error") {
+        RuntimeError::ValueOverflow { ref value, .. } if value == &BigInt::from(400) => {}
+        err => panic!("expected overflow error, got {:?} instead", err),
+    }
+}
+
+#[test]
+fn host_failed_fail() {
+    register_host_function("test_failing", |_arguments| Err("not found".to_owned()));
+
+    let res = run_checked::<Bn256>(&program("test_failing"), &input(1));
+
+    match res.err().expect("expected host function error") {
+        RuntimeError::HostFunctionFailed {
+            ref identifier,
+            ref message,
+        } if identifier == "test_failing" && message == "not found" => {}
+        err => panic!("expected host function error, got {:?} instead", err),
+    }
+}
+
+#[test]
+fn host_unknown_fail() {
+    let res = run_checked::<Bn256>(&program("test_missing"), &input(1));
+
+    match res.err().expect("expected unknown host function error") {
+        RuntimeError::UnknownHostFunction(ref identifier) if identifier == "test_missing" => {}
+        err => panic!(
+            "expected unknown host function error, got {:?} instead",
+            err
+        ),
+    }
+}
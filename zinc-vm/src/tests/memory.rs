@@ -0,0 +1,47 @@
+use crate::{run_with_memory_limit, MemoryUsage, RuntimeError};
+use pairing::bn256::Bn256;
+use zinc_bytecode::data::types::DataType;
+use zinc_bytecode::data::values::Value;
+use zinc_bytecode::instructions::*;
+use zinc_bytecode::scalar::IntegerType;
+use zinc_bytecode::{InstructionInfo, Program};
+
+fn program() -> Program {
+    super::program(
+        DataType::Unit,
+        DataType::Unit,
+        vec![
+            PushConst::new(1.into(), IntegerType::U8.into()).wrap(),
+            PushConst::new(2.into(), IntegerType::U8.into()).wrap(),
+            Store::new(0).wrap(),
+            PushConst::new(3.into(), IntegerType::U8.into()).wrap(),
+            Pop::new(2).wrap(),
+            Return::new(0).wrap(),
+        ],
+    )
+}
+
+#[test]
+fn memory_usage_peak() {
+    let (_output, memory_usage) = run_with_memory_limit::<Bn256>(&program(), &Value::Unit, None)
+        .expect("program must succeed");
+
+    assert_eq!(
+        memory_usage,
+        MemoryUsage {
+            evaluation_stack: 2,
+            data_stack: 1,
+            total: 3,
+        }
+    );
+}
+
+#[test]
+fn memory_limit_exceeded_fail() {
+    let res = run_with_memory_limit::<Bn256>(&program(), &Value::Unit, Some(2));
+
+    match res.err().expect("expected memory limit error") {
+        RuntimeError::MemoryLimitExceeded { limit: 2, used: 3 } => {}
+        err => panic!("expected memory limit error, got {:?} instead", err),
+    }
+}
@@ -1 +1,35 @@
+mod array;
+mod coverage;
+mod expose;
+mod extension;
+mod host;
+mod memory;
 mod overflow;
+
+use num_bigint::BigInt;
+use zinc_bytecode::data::types::DataType;
+use zinc_bytecode::data::values::Value;
+use zinc_bytecode::instructions::*;
+use zinc_bytecode::{Instruction, InstructionInfo, Program};
+
+/// The program calling the function at the address `2` with the input arguments and
+/// exiting with its result, like the compiled programs do.
+///
+/// The `bytecode` starts with the `main` function, so the arguments are stored at its
+/// data stack addresses from `0`.
+fn program(input: DataType, output: DataType, bytecode: Vec<Instruction>) -> Program {
+    let mut instructions = vec![
+        Call::new(2, input.size()).wrap(),
+        Exit::new(output.size()).wrap(),
+    ];
+    instructions.extend(bytecode);
+
+    Program::new(input, output, instructions)
+}
+
+/// The value of the `r#type` with the flattened `values`.
+fn value(r#type: &DataType, values: &[u64]) -> Value {
+    let values: Vec<BigInt> = values.iter().copied().map(BigInt::from).collect();
+
+    Value::from_flat_values(r#type, &values).expect("valid value")
+}